@@ -6,7 +6,7 @@ use std::{
     fs::{self, File, OpenOptions},
     io::{self, BufRead, BufReader, Write},
     iter::FromIterator,
-    path::{self, PathBuf},
+    path::{self, Path, PathBuf},
     process::Command,
 };
 
@@ -46,6 +46,11 @@ static CORE_MODULES: Lazy<HashSet<&'static str>> = Lazy::new(|| HashSet::from_it
     "videostab",
     "viz",
 ].iter().copied()));
+/// Modules that don't have a master header in the opencv2 dir, all of their headers are included instead
+static HEADERLESS_MODULES: &[&str] = &[
+    "datasets",
+    "reg",
+];
 static MODULES: OnceCell<Vec<(String, Vec<PathBuf>)>> = OnceCell::new();
 
 #[derive(Debug)]
@@ -347,26 +352,18 @@ fn get_modules(opencv_dir_as_string: &str) -> Result<&'static Vec<(String, Vec<P
         "flann",
         "gapi",
         "hal",
-        "hfs",
         "ippicv",
         "opencv",
         "opencv_modules",
-        "optflow",
-        "quality",
-        "rgbd",
-        "saliency",
-        "stereo",
-        "surface_matching",
-        "text",
         "tracking",
         "ximgproc",
     ].iter().map(|x| *x));
     let ignore_header_suffix = [
         ".inl.hpp",
+        "_inl.hpp",
         "_c.h",
     ];
     let ignore_header_substring = [
-        "stitching/detail/",
         "/superres/",
         "core/hal/intrin",
         "core/opencl/",
@@ -388,10 +385,28 @@ fn get_modules(opencv_dir_as_string: &str) -> Result<&'static Vec<(String, Vec<P
         PathBuf::from("dnn/layer.details.hpp"),
         PathBuf::from("hal.hpp"), // ?
         PathBuf::from("imgcodecs/ios.h"),
+        PathBuf::from("quality/quality_utils.hpp"), // internal helpers of the quality metrics
+        PathBuf::from("surface_matching/t_hash_int.hpp"), // internal hash table of the PPF3DDetector
         PathBuf::from("videoio/cap_ios.h"),
         PathBuf::from("viz/widget_accessor.hpp"), // wants to include vtk header
     ];
 
+    let module_files = |module: &str| {
+        glob(&format!("{}/{}/**/*.h*", opencv_dir_as_string, module)).unwrap()
+            .filter_map(|file| {
+                let path = file.expect("couldn't get path for file");
+                let path_str = path.to_string_lossy();
+                if !ignore_header_files.iter().any(|x| path.ends_with(x))
+                    && !ignore_header_suffix.iter().any(|&x| path_str.ends_with(x))
+                    && !ignore_header_substring.iter().any(|&x| path_str.contains(&x.replace('/', &path::MAIN_SEPARATOR.to_string()))) {
+                    Some(path)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
     let mut modules: Vec<(String, Vec<PathBuf>)> = glob(&format!("{}/*.hpp", opencv_dir_as_string))?
         .filter_map(|entry| {
             let entry = entry.unwrap();
@@ -400,23 +415,15 @@ fn get_modules(opencv_dir_as_string: &str) -> Result<&'static Vec<(String, Vec<P
                 None
             } else {
                 let mut files = vec![entry];
-                files.extend(
-                    glob(&format!("{}/{}/**/*.h*", opencv_dir_as_string, module)).unwrap()
-                        .filter_map(|file| {
-                            let path = file.expect("couldn't get path for file");
-                            let path_str = path.to_string_lossy();
-                            if !ignore_header_files.iter().any(|x| path.ends_with(x))
-                                && !ignore_header_suffix.iter().any(|&x| path_str.ends_with(x))
-                                && !ignore_header_substring.iter().any(|&x| path_str.contains(&x.replace('/', &path::MAIN_SEPARATOR.to_string()))) {
-                                Some(path)
-                            } else {
-                                None
-                            }
-                        })
-                );
+                files.extend(module_files(&module));
                 Some((module, files))
             }
         })
+        .chain(
+            HEADERLESS_MODULES.iter()
+                .filter(|&&module| !ignore_modules.contains(module) && Path::new(opencv_dir_as_string).join(module).is_dir())
+                .map(|&module| (module.to_string(), module_files(module)))
+        )
         .collect();
 
     let module_order = ["core"];
@@ -432,10 +439,29 @@ fn get_modules(opencv_dir_as_string: &str) -> Result<&'static Vec<(String, Vec<P
         PathBuf::from("core/persistence.hpp"),
         PathBuf::from("aruco/dictionary.hpp"),
         PathBuf::from("dnn/blob.hpp"),
+        // the mappers use the classes from these headers
+        PathBuf::from("reg/map.hpp"),
+        PathBuf::from("reg/mapaffine.hpp"),
+        PathBuf::from("reg/mapprojec.hpp"),
+        PathBuf::from("reg/mapshift.hpp"),
+        PathBuf::from("reg/mapper.hpp"),
         PathBuf::from("viz/types.hpp"),
         PathBuf::from("viz/widgets.hpp"),
+        // stitching.hpp uses the classes from these headers
+        PathBuf::from("stitching/detail/util.hpp"),
+        PathBuf::from("stitching/detail/camera.hpp"),
+        PathBuf::from("stitching/detail/matchers.hpp"),
+        PathBuf::from("stitching/detail/motion_estimators.hpp"),
+        PathBuf::from("stitching/detail/exposure_compensate.hpp"),
+        PathBuf::from("stitching/detail/seam_finders.hpp"),
+        PathBuf::from("stitching/detail/blenders.hpp"),
+        PathBuf::from("stitching/detail/timelapsers.hpp"),
+        PathBuf::from("stitching/detail/autocalib.hpp"),
+        PathBuf::from("stitching/detail/warpers.hpp"),
+        PathBuf::from("stitching/warpers.hpp"),
     ];
 
+    modules.sort_by(|(left, ..), (right, ..)| left.cmp(right));
     modules.sort_by_key(|(mod_name, ..)| module_order.iter().position(|&order_module| order_module == mod_name).unwrap_or_else(|| module_order.len()));
     for (.., file_list) in &mut modules {
         file_list.sort_by_key(|header| header_file_order.iter().position(|order_header| header.ends_with(order_header)).unwrap_or_else(|| header_file_order.len()));
@@ -562,6 +588,12 @@ fn build_wrapper(opencv_header_dir: &PathBuf) -> Result<()> {
             writeln!(&mut types, "#define HAVE_OPENCV_OCL true")?;
         }
         for m in modules {
+            if HEADERLESS_MODULES.contains(&m.0.as_str()) {
+                for file in &m.1 {
+                    writeln!(&mut types, "#include <{}>", file.strip_prefix(&opencv_header_dir)?.to_string_lossy().replace('\\', "/"))?;
+                }
+                continue;
+            }
             writeln!(&mut types, "#include <opencv2/{}.hpp>", m.0)?;
             match m.0.as_str() {
                 "core" => {
@@ -580,6 +612,10 @@ fn build_wrapper(opencv_header_dir: &PathBuf) -> Result<()> {
                 "face" => {
                     writeln!(&mut types, "#include <opencv2/{}/bif.hpp>", m.0)?;
                 }
+                "stitching" => {
+                    writeln!(&mut types, "#include <opencv2/{}/detail/autocalib.hpp>", m.0)?;
+                    writeln!(&mut types, "#include <opencv2/{}/detail/timelapsers.hpp>", m.0)?;
+                }
                 _ => ()
             }
         }
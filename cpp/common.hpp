@@ -1,3 +1,6 @@
+#include <string>
+#include <vector>
+
 #define CODE_CATCH(return_type, exc_type, code, msg) \
 catch (exc_type& e) { \
    return_type ret; \
@@ -23,3 +26,38 @@ catch (cv::Exception& e) { \
 }
 
 #define VEC_CATCH(return_type) CODE_CATCH(return_type, std::out_of_range, Error::Code::StsOutOfRange, "index out of bounds")
+
+namespace ocvrs {
+    // std::vector<std::string> arguments are passed from Rust as std::vector<cv::String>, the types only differ in
+    // OpenCV 3 where cv::String is a separate class, so the strings are copied for the duration of the call and the
+    // changes are copied back afterwards
+    template<typename S>
+    class StdStringVectorArg {
+    public:
+        explicit StdStringVectorArg(std::vector<S>* vec) : vec(vec) {
+            if (vec) {
+                std_vec.assign(vec->begin(), vec->end());
+            }
+        }
+        StdStringVectorArg(const StdStringVectorArg&) = delete;
+        StdStringVectorArg& operator=(const StdStringVectorArg&) = delete;
+
+        ~StdStringVectorArg() {
+            if (vec) {
+                vec->assign(std_vec.begin(), std_vec.end());
+            }
+        }
+
+        operator std::vector<std::string>*() {
+            return vec ? &std_vec : NULL;
+        }
+
+        operator std::vector<std::string>&() {
+            return std_vec;
+        }
+
+    private:
+        std::vector<S>* vec;
+        std::vector<std::string> std_vec;
+    };
+}
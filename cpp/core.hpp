@@ -0,0 +1,56 @@
+#include <mutex>
+#include <set>
+
+namespace ocvrs {
+    // gives access to the protected _InputArray::init() to reference the data of cv::Matx with arbitrary type and size
+    class MatxInputArray : public cv::_InputArray {
+    public:
+        MatxInputArray(int type, const void* data, cv::Size size) {
+            init(FIXED_TYPE + FIXED_SIZE + MATX + type + cv::ACCESS_READ, data, size);
+        }
+    };
+
+    // _InputArray owning the std::vector<std::vector<T>> copy of the nested Rust Vec. The element type is only known at
+    // runtime so the inner vectors store bytes, OpenCV accesses STD_VECTOR_VECTOR data the same way internally.
+    class VectorOfVectorInputArray : public cv::_InputArray {
+    public:
+        VectorOfVectorInputArray(int type, const void* const* data, const size_t* lens, size_t count) : vec(count) {
+            const size_t elem_size = CV_ELEM_SIZE(type);
+            for (size_t i = 0; i < count; i++) {
+                const uchar* start = static_cast<const uchar*>(data[i]);
+                vec[i].assign(start, start + lens[i] * elem_size);
+            }
+            init(FIXED_TYPE + STD_VECTOR_VECTOR + type + cv::ACCESS_READ, &vec);
+            std::lock_guard<std::mutex> lock(registry_mutex());
+            registry().insert(this);
+        }
+        VectorOfVectorInputArray(const VectorOfVectorInputArray&) = delete;
+        VectorOfVectorInputArray& operator=(const VectorOfVectorInputArray&) = delete;
+
+        // _InputArray doesn't have a virtual destructor so the owning instances are tracked to be deleted with the
+        // correct type, every _InputArray handed to Rust must be deleted through this function
+        static void delete_input_array(cv::_InputArray* arr) {
+            if (arr->kind() == STD_VECTOR_VECTOR) {
+                std::lock_guard<std::mutex> lock(registry_mutex());
+                if (registry().erase(arr) > 0) {
+                    delete static_cast<VectorOfVectorInputArray*>(arr);
+                    return;
+                }
+            }
+            delete arr;
+        }
+
+    private:
+        std::vector<std::vector<uchar> > vec;
+
+        static std::set<const cv::_InputArray*>& registry() {
+            static std::set<const cv::_InputArray*> out;
+            return out;
+        }
+
+        static std::mutex& registry_mutex() {
+            static std::mutex out;
+            return out;
+        }
+    };
+}
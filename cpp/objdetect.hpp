@@ -0,0 +1,30 @@
+namespace ocvrs {
+    typedef cv_return_value_void (*DetectorDetect)(void*, const cv::Mat*, std::vector<cv::Rect>*);
+    typedef void (*DetectorDrop)(void*);
+
+    // forwards the detection to the Rust CustomDetector, the Rust side is released together with this object
+    class RustDetector : public cv::DetectionBasedTracker::IDetector {
+    public:
+        RustDetector(void* detector, DetectorDetect detect_fn, DetectorDrop drop_fn) : detector(detector), detect_fn(detect_fn), drop_fn(drop_fn) {}
+        RustDetector(const RustDetector&) = delete;
+        RustDetector& operator=(const RustDetector&) = delete;
+
+        ~RustDetector() {
+            drop_fn(detector);
+        }
+
+        void detect(const cv::Mat& image, std::vector<cv::Rect>& objects) override {
+            cv_return_value_void ret = detect_fn(detector, &image, &objects);
+            if (ret.error_msg) {
+                std::string msg(ret.error_msg);
+                free(ret.error_msg);
+                CV_Error(ret.error_code, msg);
+            }
+        }
+
+    private:
+        void* detector;
+        DetectorDetect detect_fn;
+        DetectorDrop drop_fn;
+    };
+}
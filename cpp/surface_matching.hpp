@@ -0,0 +1,33 @@
+#include <cstring>
+
+namespace ocvrs {
+    // same layout as surface_matching::Pose3DValue on the Rust side
+    struct Pose3DValue {
+        double alpha;
+        double residual;
+        size_t model_index;
+        size_t num_votes;
+        double pose[16];
+        double angle;
+        double t[3];
+        double q[4];
+    };
+
+    // always declared because the cpp! closures are compiled regardless of the enabled crate features
+    inline void pose3DToValue(const void* instance, Pose3DValue* out) {
+#ifdef HAVE_OPENCV_SURFACE_MATCHING
+        const cv::ppf_match_3d::Pose3D* pose = static_cast<const cv::ppf_match_3d::Pose3D*>(instance);
+        out->alpha = pose->alpha;
+        out->residual = pose->residual;
+        out->model_index = pose->modelIndex;
+        out->num_votes = pose->numVotes;
+        out->angle = pose->angle;
+        // pose, t and q are plain arrays in OpenCV 3.2 and cv::Matx later, both store the elements contiguously
+        std::memcpy(out->pose, &pose->pose, sizeof(out->pose));
+        std::memcpy(out->t, &pose->t, sizeof(out->t));
+        std::memcpy(out->q, &pose->q, sizeof(out->q));
+#else
+        CV_Error(cv::Error::StsNotImplemented, "OpenCV is built without the surface_matching module");
+#endif
+    }
+}
@@ -0,0 +1,43 @@
+namespace ocvrs {
+    typedef cv_return_value_void (*ClassifierEval)(void*, const cv::Mat*, std::vector<int>*, std::vector<double>*);
+    typedef void (*ClassifierDrop)(void*);
+
+#ifdef HAVE_OPENCV_TEXT
+    // forwards the classification to the Rust CustomClassifier, the Rust side is released together with this object
+    class RustClassifierCallback : public cv::text::OCRHMMDecoder::ClassifierCallback {
+    public:
+        RustClassifierCallback(void* classifier, ClassifierEval eval_fn, ClassifierDrop drop_fn) : classifier(classifier), eval_fn(eval_fn), drop_fn(drop_fn) {}
+        RustClassifierCallback(const RustClassifierCallback&) = delete;
+        RustClassifierCallback& operator=(const RustClassifierCallback&) = delete;
+
+        ~RustClassifierCallback() {
+            drop_fn(classifier);
+        }
+
+        void eval(cv::InputArray image, std::vector<int>& out_class, std::vector<double>& out_confidence) override {
+            cv::Mat image_mat = image.getMat();
+            cv_return_value_void ret = eval_fn(classifier, &image_mat, &out_class, &out_confidence);
+            if (ret.error_msg) {
+                std::string msg(ret.error_msg);
+                free(ret.error_msg);
+                CV_Error(ret.error_code, msg);
+            }
+        }
+
+    private:
+        void* classifier;
+        ClassifierEval eval_fn;
+        ClassifierDrop drop_fn;
+    };
+#endif
+
+    // always declared because the cpp! closures are compiled regardless of the enabled crate features
+    inline void* newRustClassifierCallback(void* classifier, ClassifierEval eval_fn, ClassifierDrop drop_fn) {
+#ifdef HAVE_OPENCV_TEXT
+        return new cv::Ptr<cv::text::OCRHMMDecoder::ClassifierCallback>(new RustClassifierCallback(classifier, eval_fn, drop_fn));
+#else
+        drop_fn(classifier);
+        CV_Error(cv::Error::StsNotImplemented, "OpenCV is built without the text module");
+#endif
+    }
+}
@@ -128,7 +128,15 @@ decls_manual_pre = {
     ],
     "imgproc": [
         ("enum cv.InterpolationFlags", "", ["/Ghost"], []),
-    ]
+    ],
+    "surface_matching": [
+        ("class cv.ppf_match_3d.Pose3D", "", ["/Ghost"], []),
+        ("typedef cv.ppf_match_3d.Pose3DPtr", "Ptr<Pose3D>", [], []),
+    ],
+    "video": [
+        ("class cv.DenseOpticalFlow", ": cv::Algorithm", ["/Ghost", "/A"], []),
+        ("class cv.SparseOpticalFlow", ": cv::Algorithm", ["/Ghost", "/A"], []),
+    ],
 }
 
 # dict of decls to inject after doing header parsing
@@ -141,6 +149,22 @@ decls_manual_post = {
     "dnn": [
         ("cv.dnn.LayerParams.LayerParams", "", [], []),
     ],
+    "stitching": [  # implicit default constructors
+        ("cv.AffineWarper.AffineWarper", "", [], []),
+        ("cv.CylindricalWarper.CylindricalWarper", "", [], []),
+        ("cv.FisheyeWarper.FisheyeWarper", "", [], []),
+        ("cv.MercatorWarper.MercatorWarper", "", [], []),
+        ("cv.PlaneWarper.PlaneWarper", "", [], []),
+        ("cv.SphericalWarper.SphericalWarper", "", [], []),
+        ("cv.StereographicWarper.StereographicWarper", "", [], []),
+        ("cv.TransverseMercatorWarper.TransverseMercatorWarper", "", [], []),
+        ("cv.detail.AffineBasedEstimator.AffineBasedEstimator", "", [], []),
+        ("cv.detail.GainCompensator.GainCompensator", "", [], []),
+        ("cv.detail.ImageFeatures.ImageFeatures", "", [], []),
+        ("cv.detail.NoExposureCompensator.NoExposureCompensator", "", [], []),
+        ("cv.detail.NoSeamFinder.NoSeamFinder", "", [], []),
+        ("cv.detail.VoronoiSeamFinder.VoronoiSeamFinder", "", [], []),
+    ],
 }
 
 # dict of functions to rename or skip, key is FuncInfo.identifier, value is new name ("+" will be replaces by old name) or "-" to skip
@@ -168,6 +192,7 @@ func_rename = {
     "cv_findHomography__InputArray__InputArray_int_double__OutputArray_int_double": "+_ext",
     "cv_undistortPoints__InputArray__OutputArray__InputArray__InputArray__InputArray__InputArray_TermCriteria": "+_with_criteria",
     "cv_fisheye_projectPoints__InputArray__OutputArray__InputArray__InputArray__InputArray__InputArray_double__OutputArray": "fisheye_+",
+    "cv_fisheye_projectPoints__InputArray__OutputArray_Affine3d__InputArray__InputArray_double__OutputArray": "fisheye_project_points_affine",
     "cv_fisheye_stereoCalibrate__InputArray__InputArray__InputArray__InputOutputArray__InputOutputArray__InputOutputArray__InputOutputArray_Size__OutputArray__OutputArray_int_TermCriteria": "fisheye_+",
     "cv_fisheye_stereoRectify__InputArray__InputArray__InputArray__InputArray_Size__InputArray__InputArray__OutputArray__OutputArray__OutputArray__OutputArray__OutputArray_int_Size_double_double": "fisheye_+",
     "cv_fisheye_undistortImage__InputArray__OutputArray__InputArray__InputArray__InputArray_Size": "fisheye_+",
@@ -281,11 +306,16 @@ func_rename = {
     "cv_UMat_step_const": "mat_step",
     "cv_UMat_set_size_MatSize": "-",  # doesn't allow writing
     "cv_UMat_set_step_MatStep": "-",  # same as above
+    "cv_RNG_uniform_float_float": "+_f32",
+    "cv_RNG_uniform_double_double": "+_f64",
+    "cv_RNG_MT19937_uniform_float_float": "+_f32",
+    "cv_RNG_MT19937_uniform_double_double": "+_f64",
     "cv_UMat_create_int_int_int_UMatUsageFlags": "+_rows_cols",
     "cv_UMat_create_Size_int_UMatUsageFlags": "+_size",
     "cv_UMat_create_VectorOfint_int_UMatUsageFlags": "+_nd",
     "cv_UMat_create_int_const_int_X_int_UMatUsageFlags": "-",  # duplicate of cv_UMat_create_VectorOfint_int_UMatUsageFlags, but with pointers
     "cv_UMat_type_const": "typ",
+    "cv_viz_MouseEvent_type_const": "typ",
     "cv_UMat_copyTo_const__OutputArray__InputArray": "+_masked",
     "cv_UMat_copySize_UMat": "-",  # internal function
     "cv_merge_const_Mat_size_t__OutputArray": "-",  # duplicate of cv_merge_VectorOfMat_Mat, but with pointers
@@ -462,7 +492,9 @@ func_rename = {
     "cv_BOWKMeansTrainer_cluster_const_Mat": "new",
     "cv_BOWKMeansTrainer_BOWKMeansTrainer_int_TermCriteria_int_int": "new_with_criteria",
     "cv_BOWImgDescriptorExtractor_compute__InputArray_VectorOfKeyPoint__OutputArray_VectorOfVectorOfint_Mat": "+_desc",
-    "cv_DMatch_DMatch_int_int_int_float": "new_index",
+    "cv_DMatch_DMatch": "-",  # implemented natively in src/manual/core/keypoint.rs
+    "cv_DMatch_DMatch_int_int_float": "-",  # same as above
+    "cv_DMatch_DMatch_int_int_int_float": "-",  # same as above
     "cv_DescriptorMatcher_knnMatch_const__InputArray__InputArray_VectorOfVectorOfDMatch_int__InputArray_bool": "knn_train_matches",
     "cv_DescriptorMatcher_knnMatch__InputArray_VectorOfVectorOfDMatch_int__InputArray_bool": "knn_matches",
     "cv_DescriptorMatcher_match__InputArray_VectorOfDMatch__InputArray": "matches",
@@ -473,8 +505,9 @@ func_rename = {
     "cv_FAST__InputArray_VectorOfKeyPoint_int_bool_int": "FAST_with_type",  # 3.x only
     "cv_FAST__InputArray_VectorOfKeyPoint_int_bool_FastFeatureDetector_DetectorType": "FAST_with_type",
     "cv_Feature2D_detect__InputArray_VectorOfVectorOfKeyPoint__InputArray": "+_multiple",
-    "cv_KeyPoint_KeyPoint_Point2f_float_float_float_int_int": "new_point",
-    "cv_KeyPoint_KeyPoint_float_float_float_float_float_int_int": "new_coords",
+    "cv_KeyPoint_KeyPoint": "-",  # implemented natively in src/manual/core/keypoint.rs
+    "cv_KeyPoint_KeyPoint_Point2f_float_float_float_int_int": "-",  # same as above
+    "cv_KeyPoint_KeyPoint_float_float_float_float_float_int_int": "-",  # same as above
     "cv_KeyPoint_convert_VectorOfKeyPoint_VectorOfPoint2f_VectorOfint": "convert_from",
     "cv_KeyPoint_convert_VectorOfPoint2f_VectorOfKeyPoint_float_float_int_int": "convert_to",
     "cv_drawMatches__InputArray_VectorOfKeyPoint__InputArray_VectorOfKeyPoint_VectorOfVectorOfDMatch__InputOutputArray_Scalar_Scalar_VectorOfVectorOfchar_int": "+_vec",  # 3.x only
@@ -519,6 +552,7 @@ func_rename = {
     "cv_getAffineTransform_const_Point2f_X_const_Point2f_X": "+_slice",
     "cv_getPerspectiveTransform_const_Point2f_X_const_Point2f_X": "+_slice",  # 3.x only
     "cv_getPerspectiveTransform_const_Point2f_X_const_Point2f_X_int": "+_slice",  # 4.x only
+    "cv_getRotationMatrix2D__Point2f_double_double": "get_rotation_matrix_2d_matx",
 
     ### line_descriptor ###
     "cv_line_descriptor_LSDDetector_detect_const_VectorOfMat_VectorOfVectorOfKeyLine_int_int_VectorOfMat": "+_multiple",
@@ -551,10 +585,68 @@ func_rename = {
     "cv_MergeMertens_process__InputArray__OutputArray__InputArray__InputArray": "+_with_response",
     "cv_MergeRobertson_process__InputArray__OutputArray__InputArray__InputArray": "process_with_response",
 
+    ### quality ###
+    "cv_quality_QualityBRISQUE_compute__InputArray_String_String": "+_with_model",
+    "cv_quality_QualityGMSD_compute__InputArray__InputArray__OutputArray": "+_with_ref",
+    "cv_quality_QualityMSE_compute__InputArray__InputArray__OutputArray": "+_with_ref",
+    "cv_quality_QualityPSNR_compute__InputArray__InputArray__OutputArray_double": "+_with_ref",
+    "cv_quality_QualitySSIM_compute__InputArray__InputArray__OutputArray": "+_with_ref",
+
+    ### reg ###
+    "cv_reg_MapAffine_getLinTr_const__OutputArray": "+_to",
+    "cv_reg_MapAffine_getShift_const__OutputArray": "+_to",
+    "cv_reg_MapProjec_getProjTr_const__OutputArray": "+_to",
+    "cv_reg_MapShift_getShift_const__OutputArray": "+_to",
+
+    ### rgbd ###
+    "cv_linemod_Detector_numTemplates_const_String": "num_templates_for_class",
+    "cv_linemod_Modality_create_FileNode": "create_from_file_node",
+    "cv_rgbd_DepthCleaner_operator_call_const__InputArray__OutputArray": "apply",
+    "cv_rgbd_RgbdNormals_operator_call_const__InputArray__OutputArray": "apply",
+    "cv_rgbd_RgbdPlane_RgbdPlane_int_int_int_double_double_double_double": "new_with_params",  # 3.4+
+    "cv_rgbd_RgbdPlane_operator_call__InputArray__InputArray__OutputArray__OutputArray": "apply",
+    "cv_rgbd_RgbdPlane_operator_call__InputArray__OutputArray__OutputArray": "apply_without_normals",
+    "cv_rgbd_depthTo3d__InputArray__InputArray__OutputArray__InputArray": "depth_to_3d",
+    "cv_rgbd_depthTo3dSparse__InputArray__InputArray__InputArray__OutputArray": "depth_to_3d_sparse",
+
+    ### saliency ###
+    "cv_saliency_ObjectnessBING_getobjectnessValues": "get_objectness_values",
+
+    ### stereo ###
+    "cv_stereo_censusTransform_Mat_int_Mat_int": "+_single",
+    "cv_stereo_modifiedCensusTransform_Mat_int_Mat_int_int_Mat": "+_single",
+    "cv_stereo_starCensusTransform_Mat_int_Mat": "+_single",
+    "cv_stereo_symetricCensusTransform_Mat_int_Mat_int": "+_single",
+    "cv_stereo_imageMeanKernelSize_Mat_int_Mat": "-",  # not exported
+
     ### stitching ###
     "cv_Stitcher_composePanorama__InputArray__OutputArray": "+_images",
     "cv_Stitcher_stitch__InputArray__InputArray__OutputArray": "+_mask",
     "cv_Stitcher_stitch__InputArray_VectorOfVectorOfRect__OutputArray": "+_rois",
+    "cv_detail_Estimator_operator_call_VectorOfDetail_ImageFeatures_VectorOfDetail_MatchesInfo_VectorOfDetail_CameraParams": "apply",
+    "cv_detail_FeaturesFinder_operator_call__InputArray_Detail_ImageFeatures": "apply",  # 3.x only
+    "cv_detail_FeaturesFinder_operator_call__InputArray_Detail_ImageFeatures_VectorOfRect": "apply_rois",  # 3.x only
+    "cv_detail_FeaturesFinder_operator_call__InputArray_VectorOfDetail_ImageFeatures": "apply_multiple",  # 3.x only
+    "cv_detail_FeaturesFinder_operator_call__InputArray_VectorOfDetail_ImageFeatures_VectorOfVectorOfRect": "apply_multiple_rois",  # 3.x only
+    "cv_detail_FeaturesMatcher_operator_call_Detail_ImageFeatures_Detail_ImageFeatures_Detail_MatchesInfo": "apply",
+    "cv_detail_FeaturesMatcher_operator_call_VectorOfDetail_ImageFeatures_VectorOfDetail_MatchesInfo_UMat": "apply_with_mask",
+
+    ### surface_matching ###
+    "cv_ppf_match_3d_ICP_registerModelToScene_Mat_Mat_VectorOfPtrOfPose3D": "+_poses",
+    "cv_ppf_match_3d_PoseCluster3D_PoseCluster3D_PtrOfPose3D_int": "new_with_id",
+    "cv_ppf_match_3d_Pose3D_updatePose_Matx33d_Vec3d": "update_pose_rt",
+    "cv_ppf_match_3d_destroyFlann_void_X": "-",  # flann index is an opaque void*
+    "cv_ppf_match_3d_indexPCFlann_Mat": "-",
+    "cv_ppf_match_3d_queryPCFlann_void_X_Mat_Mat_Mat": "-",
+    "cv_ppf_match_3d_queryPCFlann_void_X_Mat_Mat_Mat_int": "-",
+
+    ### text ###
+    "cv_text_createERFilterNM1_PtrOfERFilter_Callback_int_float_float_float_bool_float": "create_er_filter_nm1",
+    "cv_text_createERFilterNM1_String_int_float_float_float_bool_float": "create_er_filter_nm1_from_file",  # 3.4+
+    "cv_text_createERFilterNM2_PtrOfERFilter_Callback_float": "create_er_filter_nm2",
+    "cv_text_createERFilterNM2_String_float": "create_er_filter_nm2_from_file",  # 3.4+
+    "cv_text_OCRBeamSearchDecoder_create_String_String__InputArray__InputArray_int_int": "create_from_file",  # 3.4+
+    "cv_text_OCRHMMDecoder_create_String_String__InputArray__InputArray_int_int": "create_from_file",  # 3.4+
 
     ### videoio ###
     "cv_VideoCapture_VideoCapture_int_int": "new_with_backend",
@@ -566,6 +658,13 @@ func_rename = {
     "cv_VideoWriter_VideoWriter_String_int_int_double_Size_bool": "new_with_backend",
     "cv_VideoWriter_open_String_int_int_double_Size_bool": "open_with_backend",
 
+    ### viz ###
+    "cv_viz_Camera_Camera_Matx33d_Size": "+_intrinsics",
+    "cv_viz_Camera_Camera_Matx44d_Size": "+_projection",
+    "cv_viz_WCameraPosition_WCameraPosition_Matx33d_double_Color": "+_intrinsics",
+    "cv_viz_WCameraPosition_WCameraPosition_Matx33d__InputArray_double_Color": "+_intrinsics_image",
+    "cv_viz_WTrajectoryFrustums_WTrajectoryFrustums__InputArray_Matx33d_double_Color": "+_intrinsics",
+
     ### videostab ###
     "cv_videostab_KeypointBasedMotionEstimator_estimate_Mat_Mat_bool_X": "+_mat",
 
@@ -583,7 +682,6 @@ class_ignore_list = (
     "Ipl.*",
     "cv::Mutex", "cv::softfloat", "cv::softdouble", "cv::float16_t",  # have corresponding Rust implementation
     "cv::Exception",
-    "cv::RNG.*",  # maybe
     "cv::SVD",
     "cv::MatAllocator",
     "cv::TLSDataContainer",
@@ -595,8 +693,16 @@ class_ignore_list = (
     ### features2d ###
     "cv::DrawMatchesFlags",  # dummy type only used to contain anonymous enum, 3.x only
 
+    ### stereo ###
+    "cv::stereo::Matching", "cv::stereo::MeanKernelIntegralImage",  # internal helpers of the binary matchers, not exported
+
+    ### surface_matching ###
+    "cv::ppf_match_3d::THash",  # internal to the PPF3DDetector
+
     ### stitching ###
     "cv::CylindricalWarperGpu", "cv::PlaneWarperGpu", "cv::SphericalWarperGpu",
+    "cv::detail::.*Gpu",  # only available with CUDA
+    "cv::detail::.*Projector(Base)?",  # internal to the warpers
 
     ### videostab ###
     "cv::videostab::DensePyrLkOptFlowEstimatorGpu",
@@ -655,6 +761,9 @@ func_unsafe_list = {
     "cv_UMat_create_VectorOfint_int_UMatUsageFlags",
     # allows passing arbitrary data
     "cv_Mat_set_data_uchar_X",
+    # returned header shares the data without borrowing the source, it can alias the BoxedRefMut views
+    "cv_Mat_Mat_Mat",
+    "cv_Mat_adjustROI_int_int_int_int",
     # no bounds checking
     "cv_Mat_ptr_int",
     "cv_Mat_ptr_const_int",
@@ -691,6 +800,19 @@ type_replace = {
     "Size_<double>": "Size2d",
     "Scalar_<double>": "Scalar",
     "cv::xfeatures2d::Feature2D": "cv::Feature2D",
+    "cv::optflow::DenseOpticalFlow": "cv::DenseOpticalFlow",
+    "cv::optflow::SparseOpticalFlow": "cv::SparseOpticalFlow",
+    "cv::Ptr<IDetector>": "Ptr<cv::DetectionBasedTracker::IDetector>",  # unqualified in DetectionBasedTracker constructor
+    "cv::Ptr<QuasiDenseStereo>": "Ptr<cv::stereo::QuasiDenseStereo>",  # unqualified in QuasiDenseStereo::create
+    "cv::Ptr<Map>": "Ptr<cv::reg::Map>",  # unqualified in the reg module
+    "cv::Matx<double, 2, 2>": "Matx22d",
+    "cv::Matx<double, 3, 3>": "Matx33d",
+    "cv::Vec<double, 2>": "Vec2d",
+    "cv::Matx<double22>": "Matx22d",  # 3.2 parser loses the commas
+    "cv::Matx<double33>": "Matx33d",
+    "cv::Vec<double2>": "Vec2d",
+    "vector <Point2f>": "std::vector<Point2f>",  # datasets
+    "vector <Point2d>": "std::vector<Point2d>",
 }
 
 # dict for handling primitives
@@ -720,6 +842,7 @@ primitives = {
     "uint": {"cpp_extern": "unsigned int", "rust_local": "u32"},
     "unsigned": {"cpp_extern": "unsigned int", "rust_local": "u32"},
     "unsigned int": {"cpp_extern": "unsigned int", "rust_local": "u32"},
+    "uint8_t": {"cpp_extern": "uint8_t", "rust_local": "u8"},
     "uint32_t": {"cpp_extern": "uint32_t", "rust_local": "u32"},
 
     "size_t": {"cpp_extern": "std::size_t", "rust_local": "size_t"},
@@ -783,6 +906,28 @@ func_manual = {
     "cv_Mat_at_const_const_int_X": {
         "rust_safe": _forward_const_rust_safe,
     },
+    # safe wrappers are implemented in src/manual/core/mat.rs to tie the lifetime of the Mat header to the parent Mat
+    "cv_Mat_Mat_Mat_Range_Range": {"rust_extern": "~", "cpp": "~"},
+    "cv_Mat_Mat_Mat_Rect": {"rust_extern": "~", "cpp": "~"},
+    "cv_Mat_Mat_Mat_VectorOfRange": {"rust_extern": "~", "cpp": "~"},
+    "cv_Mat_row_const_int": {"rust_extern": "~", "cpp": "~"},
+    "cv_Mat_col_const_int": {"rust_extern": "~", "cpp": "~"},
+    "cv_Mat_rowRange_const_int_int": {"rust_extern": "~", "cpp": "~"},
+    "cv_Mat_rowRange_const_Range": {"rust_extern": "~", "cpp": "~"},
+    "cv_Mat_colRange_const_int_int": {"rust_extern": "~", "cpp": "~"},
+    "cv_Mat_colRange_const_Range": {"rust_extern": "~", "cpp": "~"},
+    "cv_Mat_diag_const_int": {"rust_extern": "~", "cpp": "~"},
+    "cv_Mat_reshape_const_int_int": {"rust_extern": "~", "cpp": "~"},
+    "cv_Mat_reshape_const_int_VectorOfint": {"rust_extern": "~", "cpp": "~"},
+    # safe wrappers are implemented in src/manual/viz.rs to tie the callback lifetime to Viz3d
+    "cv_viz_Viz3d_registerKeyboardCallback_Viz3d_KeyboardCallback_void_X": {
+        "rust_extern": "~",
+        "cpp": "~",
+    },
+    "cv_viz_Viz3d_registerMouseCallback_Viz3d_MouseCallback_void_X": {
+        "rust_extern": "~",
+        "cpp": "~",
+    },
 }
 
 # dict of manual declaration for types
@@ -841,6 +986,25 @@ for s in (2, 3, 4, 6, 8):
         if t[0] != "l":
             _base_type_alias("core", "Vec{}{}".format(s, t[0]), "core::Vec{}<{}>".format(s, rust_local), t[1], ("data[{}]".format(s),))
 
+for m, n in ((1, 2), (1, 3), (1, 4), (1, 6), (2, 1), (3, 1), (4, 1), (6, 1), (2, 2), (2, 3), (3, 2), (3, 3), (3, 4), (4, 3), (4, 4), (6, 6)):
+    for t in (("f", "float"), ("d", "double")):
+        rust_local = primitives[t[1]]["rust_local"]
+        _base_type_alias("core", "Matx{}{}{}".format(m, n, t[0]), "core::Matx<{}, {}, {}>".format(rust_local, m, n), t[1], ("data[{}]".format(m * n),))
+
+for t in (("f", "float"), ("d", "double")):
+    _base_type_alias("core", "Affine3{}".format(t[0]), "core::Affine3<{}>".format(primitives[t[1]]["rust_local"]), t[1], ("data[16]",))
+
+# simple classes with Rust struct declared in src/manual/core, only the C++ side is generated
+type_manual["core"]["DMatch"] = {"cpp": "~"}
+type_manual["core"]["KeyPoint"] = {"cpp": "~"}
+# Drop implemented in src/manual/core/input_output_array.rs to also release the arrays owning the converted data
+type_manual["core"]["_InputArray"] = {"rust": "~"}
+
+# boxed classes with Drop implemented in src/manual/viz.rs to release the registered callbacks
+type_manual["viz"] = {
+    "Viz3d": {"rust": "~"},
+}
+
 # set of types that must be generated as traits, elements are typeids
 forced_class_trait = {
     "cv::Algorithm",
@@ -857,6 +1021,7 @@ forced_class_trait = {
 # set of types that must be generated as traits, elements are typeids
 forced_class_abstract = {
     "cv::dnn::BackendNode",
+    "cv::detail::BlocksCompensator",
 }
 
 # set of classes that must be forced to be non-simple, elements are declarations (decl[0])
@@ -867,6 +1032,11 @@ forced_non_simple = {
     "class cv.dnn.Model",
     "class cv.dnn.DetectionModel",
     "class cv.dnn.ClassificationModel",
+    "struct cv.detail.CameraParams",  # have Mat fields
+    "struct cv.detail.ImageFeatures",
+    "struct cv.detail.MatchesInfo",
+    "struct cv.linemod.Match",  # has String field
+    "struct cv.linemod.Template",  # has vector field
 }
 
 # dict of pointer arguments that need to be made into slices, the conversion only happens if an arg is a pointer
@@ -962,6 +1132,12 @@ enum_generate = {
     "cv::HOGDescriptor::HistogramNormType",
     "cv::DetectionBasedTracker::ObjectStatus",
 
+    ### optflow ###
+    "cv::optflow::GPCDescType",
+    "cv::optflow::InterpolationType",
+    "cv::optflow::SolverType",
+    "cv::optflow::SupportRegionType",
+
     ### stitching ###
     "cv::Stitcher::Mode",
     "cv::Stitcher::Status",
@@ -1004,6 +1180,30 @@ reserved_rename = {
     "loop": "_loop",
 }
 
+# dict of prefixes for Rust names of the types from namespaces that clash with the parent namespace
+# key: module name
+# value: dict of namespace -> prefix for the type name
+namespace_rust_prefix = {
+    "rgbd": {
+        "cv::dynafu": "Dynafu_",  # e.g. cv::dynafu::Params and cv::kinfu::Params
+        "cv::kinfu": "Kinfu_",
+    },
+    "stitching": {
+        "cv::detail": "Detail_",  # e.g. cv::detail::PlaneWarper and cv::PlaneWarper
+    },
+}
+
+# dict of Rust identifiers for the nested classes whose names clash with the classes from other namespaces, they're used
+# in the names of the smart pointer and vector types
+# key: class full name
+# value: identifier
+class_rust_safe_id = {
+    "cv::text::ERFilter::Callback": "ERFilter_Callback",  # e.g. cv::LMSolver::Callback
+    "cv::text::OCRHMMDecoder::ClassifierCallback": "OCRHMMDecoder_ClassifierCallback",
+    "cv::text::OCRBeamSearchDecoder::ClassifierCallback": "OCRBeamSearchDecoder_ClassifierCallback",
+    "cv::stereo::Match": "Stereo_Match",  # e.g. cv::linemod::Match
+}
+
 # list of modules that are imported into every other module so there is no need to reference them using full path, elements are module names
 static_modules = ("core", "sys", "types")
 
@@ -1020,10 +1220,12 @@ data_type_typeids = {
     "cv::Point3i", "cv::Point3f", "cv::Point3d",
     "cv::Size", "cv::Size2i", "cv::Size2f", "cv::Size2d",
     "cv::Rect", "cv::Rect2i", "cv::Rect2f", "cv::Rect2d",
+    "cv::Matx22f", "cv::Matx22d", "cv::Matx33f", "cv::Matx33d", "cv::Matx44f", "cv::Matx44d",
+    "cv::Affine3f", "cv::Affine3d",
 }
 
 
-def decl_patch(module, decl):
+def decl_patch(module, decl, opencv_version):
     force_slice_module_decls = force_slice.get(module)
     if force_slice_module_decls is not None:
         force_slice_fn_decls = force_slice_module_decls.get(decl[0])
@@ -1044,11 +1246,98 @@ def decl_patch(module, decl):
         # size() and step() of Mat and UMat should be const
         if decl[0] == "cv.Mat.size" or decl[0] == "cv.Mat.step" or decl[0] == "cv.UMat.size" or decl[0] == "cv.UMat.step":
             decl[2].append("/C")
+    elif module == "datasets":
+        # `load` of the loaders is `CV_OVERRIDE = 0` since 3.4 which is not detected as pure virtual, mark it explicitly
+        # so that the loaders are generated as traits like in 3.2
+        if re.match(r"cv\.datasets\.\w+\.load$", decl[0]) and "/A" not in decl[2]:
+            decl[2].append("/A")
     elif module == "dnn":
         # set method takes generic, force it to take DictValue wrapper
         if decl[0] == "cv.dnn.Dict.set":
             decl[1] = "DictValue&"
             decl[3][1][0] = "DictValue&"
+    elif module == "rgbd":
+        # kinfu and dynafu both declare Params, so the unqualified name must be resolved in the namespace of the declaration
+        for namespace in ("kinfu", "dynafu"):
+            if decl[0].startswith("cv.{}.".format(namespace)):
+                qualify = lambda typ: re.sub(r"(?<![\w:])Params\b", "cv::{}::Params".format(namespace), typ)
+                decl[1] = qualify(decl[1])
+                for arg in decl[3]:
+                    arg[0] = qualify(arg[0])
+    elif module == "saliency":
+        # resImWidth and resImHeight are marked with CV_PROP_RW, but they're protected, get/setImageWidth/Height are used instead
+        if decl[0] == "class cv.saliency.StaticSaliencySpectralResidual":
+            decl[3] = [prop for prop in decl[3] if prop[1] not in ("resImWidth", "resImHeight")]
+    elif module == "stereo":
+        # plain structs without CV_PROP fields, expose them as simple classes
+        if decl[0] == "struct cv.stereo.Match":
+            decl[2].append("/Simple")
+            decl[5] = ""  # doxygen group marker
+            decl[3] = [
+                ["Point2i", "p0", "", []],
+                ["Point2i", "p1", "", []],
+                ["float", "corr", "", []],
+            ]
+        elif decl[0] == "struct cv.stereo.PropagationParameters":
+            decl[2].append("/Simple")
+            decl[3] = [
+                ["int", "corrWinSizeX", "", []],
+                ["int", "corrWinSizeY", "", []],
+                ["int", "borderX", "", []],
+                ["int", "borderY", "", []],
+                ["float", "correlationThreshold", "", []],
+                ["float", "textrureThreshold", "", []],
+                ["int", "neighborhoodSize", "", []],
+                ["int", "disparityGradient", "", []],
+                ["int", "lkTemplateSize", "", []],
+                ["int", "lkPyrLvl", "", []],
+                ["int", "lkTermParam1", "", []],
+                ["float", "lkTermParam2", "", []],
+                ["float", "gftQualityThres", "", []],
+                ["int", "gftMinSeperationDist", "", []],
+                ["int", "gftMaxNumFeatures", "", []],
+            ]
+        elif decl[0] == "class cv.stereo.QuasiDenseStereo":
+            decl[3].append(["PropagationParameters", "Param", "", []])
+    elif module == "surface_matching":
+        # the fields of the pose are only marked with CV_PROP since OpenCV 4
+        if decl[0] == "class cv.ppf_match_3d.Pose3D" and "/Ghost" not in decl[2] and len(decl[3]) == 0:
+            if opencv_version.startswith("3.2"):
+                # pose, t and q are plain C arrays in 3.2 which can't be mapped, Pose3D::to_value() in src/manual/surface_matching.rs copies them
+                decl[3] = [
+                    ["double", "alpha", "", []],
+                    ["double", "residual", "", []],
+                    ["unsigned int", "modelIndex", "", []],
+                    ["unsigned int", "numVotes", "", []],
+                    ["double", "angle", "", []],
+                ]
+            else:
+                decl[3] = [
+                    ["double", "alpha", "", []],
+                    ["double", "residual", "", []],
+                    ["size_t", "modelIndex", "", []],
+                    ["size_t", "numVotes", "", []],
+                    ["Matx44d", "pose", "", []],
+                    ["double", "angle", "", []],
+                    ["Vec3d", "t", "", []],
+                    ["Vec4d", "q", "", []],
+                ]
+    elif module == "viz":
+        # event fields are public, but not marked with CV_PROP, expose them read-only
+        if decl[0] == "class cv.viz.KeyboardEvent" and len(decl[3]) == 0:
+            decl[3] = [
+                ["int", "action", "", ["/C"]],
+                ["String", "symbol", "", ["/C"]],
+                ["unsigned char", "code", "", ["/C"]],
+                ["int", "modifiers", "", ["/C"]],
+            ]
+        elif decl[0] == "class cv.viz.MouseEvent" and len(decl[3]) == 0:
+            decl[3] = [
+                ["int", "type", "", ["/C"]],
+                ["int", "button", "", ["/C"]],
+                ["Point", "pointer", "", ["/C"]],
+                ["int", "modifiers", "", ["/C"]],
+            ]
     elif module == "ml":
         # loadFromCSV is not parsed correctly due to default value being a comma
         if decl[0] == "cv.ml.TrainData.loadFromCSV" and len(decl[3]) == 8 and decl[3][6][0] == "'":
@@ -1088,6 +1377,26 @@ def bump_counter(name):
     return "{}{}".format(base_name, counter + 1)
 
 
+def qualify_name(namespace, name):
+    """
+    :type namespace: str
+    :type name: str
+    :rtype: str
+    """
+    if namespace:
+        return "{}::{}".format(namespace, name)
+    return name
+
+
+def rust_namespace_prefix(module, namespace):
+    """
+    :type module: str|None
+    :type namespace: str
+    :rtype: str
+    """
+    return namespace_rust_prefix.get(module, {}).get(namespace, "")
+
+
 def split_known_namespace(name, namespaces):
     """
     :type name: str
@@ -1178,6 +1487,9 @@ class ArgInfo:
     def is_output(self):
         return self.out in ("O", "IO")
 
+    def is_void_ptr(self):
+        return isinstance(self.type, RawPtrTypeInfo) and isinstance(self.type.inner, PrimitiveTypeInfo) and self.type.inner.cpptype == "void"
+
     def __repr__(self):
         return template("ARG $ctype$p $name=$defval").substitute(ctype=self.type,
                                                                   p=" *" if isinstance(self.type, RawPtrTypeInfo) else "",
@@ -1190,6 +1502,7 @@ class FuncInfo(GeneralInfo):
     KIND_FUNCTION    = "(function)"
     KIND_METHOD      = "(method)"
     KIND_METHOD_CONVERT = "(convertor method)"
+    KIND_METHOD_CALL = "(call operator)"
     KIND_CONSTRUCTOR = "(constructor)"
 
     TEMPLATES = {
@@ -1229,6 +1542,8 @@ class FuncInfo(GeneralInfo):
 
         "rust_safe_rv_by_ptr": template(""".map(|ptr| ${rv_rust_full} { ptr })"""),
 
+        "rust_safe_rv_vector": template(""".map(|ptr| unsafe { ${rv_rust_full}::from_raw_ptr(ptr) })"""),
+
         "rust_safe_rv_other": template(""""""),
 
         "rust_extern": template("""
@@ -1248,7 +1563,7 @@ class FuncInfo(GeneralInfo):
 
         self.is_ignored = False
         if self.classname and not self.classname.startswith("operator"):
-            self.ci = gen.get_class(self.classpath)
+            self.ci = gen.get_class(qualify_name(self.namespace, self.classpath))
             if not self.ci:
                 if self.classname == "std" or "<" in self.classname:
                     self.is_ignored = True
@@ -1276,7 +1591,10 @@ class FuncInfo(GeneralInfo):
                         self.kind = self.KIND_METHOD_CONVERT
                         self.type = out_type
                 if not operator_convert:
-                    self.kind = self.KIND_METHOD
+                    if re.match(r"operator\s*\(\)$", self.name):
+                        self.kind = self.KIND_METHOD_CALL
+                    else:
+                        self.kind = self.KIND_METHOD
                     self.type = gen.get_type_info(decl[1])
         else:
             self.kind = self.KIND_FUNCTION
@@ -1284,6 +1602,8 @@ class FuncInfo(GeneralInfo):
             self.type = gen.get_type_info(decl[1])
 
         self.identifier = self.fullname.replace("::", "_").replace(" ", "_")
+        if self.kind == self.KIND_METHOD_CALL:
+            self.identifier = re.sub(r"operator_\(\)$", "operator_call", self.identifier)
 
         self.is_ignored = self.is_ignored or "/H" in decl[2] or "/I" in decl[2]
 
@@ -1303,6 +1623,10 @@ class FuncInfo(GeneralInfo):
         self.args = []
         for arg in decl[3]:
             ai = ArgInfo(gen, arg)
+            if self.has_callback_arg and ai.name != "userdata" and ai.is_void_ptr() and isinstance(self.args[-1].type, CallbackTypeInfo) \
+                    and getattr(gen.get_callback(self.args[-1].type.typeid), "is_void", False):
+                # void* right after the callback is its user data, e.g. "cookie" in viz::Viz3d::registerKeyboardCallback
+                ai.name = ai.rsname = "userdata"
             if self.has_callback_arg and ai.name == "userdata":
                 has_userdata_arg = True
             while any(True for x in self.args if x.name == ai.name):
@@ -1329,7 +1653,7 @@ class FuncInfo(GeneralInfo):
             logging.info("ignore destructor %s %s in %s"%(self.kind, self.name, self.ci))
             self.is_ignored = True
 
-        if (self.name.startswith("operator") or self.fullname.startswith("operator ")) and self.kind != self.KIND_METHOD_CONVERT:
+        if (self.name.startswith("operator") or self.fullname.startswith("operator ")) and not self.is_mapped_operator():
             logging.info("ignore %s %s in %s"%(self.kind, self.name, self.ci))
             self.is_ignored = True
 
@@ -1344,6 +1668,15 @@ class FuncInfo(GeneralInfo):
             return tmpl
         return None
 
+    def is_mapped_operator(self):
+        """
+        Conversion operators are mapped automatically, call operators only when they're given a name in func_rename
+        :rtype: bool
+        """
+        if self.kind == self.KIND_METHOD_CONVERT:
+            return True
+        return self.kind == self.KIND_METHOD_CALL and func_rename.get(self.identifier, "-") != "-"
+
     def is_constructor(self):
         return self.kind == self.KIND_CONSTRUCTOR
 
@@ -1372,7 +1705,7 @@ class FuncInfo(GeneralInfo):
         if self.identifier in func_manual:
             return None
 
-        if self.name.startswith("operator") and self.kind != self.KIND_METHOD_CONVERT:
+        if self.name.startswith("operator") and not self.is_mapped_operator():
             return "can not map %s yet"%(self.name)
 
         if not self.rv_type():
@@ -1567,6 +1900,8 @@ class FuncInfo(GeneralInfo):
                 rv_rust = FuncInfo.TEMPLATES["rust_safe_rv_string"].substitute(template_vars)
             else:
                 rv_rust = FuncInfo.TEMPLATES["rust_safe_rv_string_mut"].substitute(template_vars)
+        elif isinstance(self.rv_type(), VectorTypeInfo):
+            rv_rust = FuncInfo.TEMPLATES["rust_safe_rv_vector"].substitute(template_vars)
         elif self.rv_type().is_by_ptr:
             rv_rust = FuncInfo.TEMPLATES["rust_safe_rv_by_ptr"].substitute(template_vars)
         elif isinstance(self.rv_type(), RawPtrTypeInfo):
@@ -1635,7 +1970,7 @@ class ClassInfo(GeneralInfo):
                 self.is_trait = True
                 self.is_abstract = True
         if self.classpath:
-            ci = self.gen.get_class(self.classpath)
+            ci = self.gen.get_class(qualify_name(self.namespace, self.classpath))
             if ci is not None and ci.is_ignored:
                 self.is_ignored = True
 
@@ -1802,8 +2137,8 @@ class TypedefInfo(GeneralInfo):
 class CallbackInfo(GeneralInfo):
     TEMPLATES = {
         "rust": template("""
-            ${doc_comment}pub type ${name} = dyn FnMut(${args}) + Send + Sync + 'static;
-            #[doc(hidden)] pub type ${name}Extern = Option<extern "C" fn(${extern_args})>;
+            ${doc_comment}pub type ${rust_local} = dyn FnMut(${args}) + Send + Sync + 'static;
+            #[doc(hidden)] pub type ${rust_local}Extern = Option<extern "C" fn(${extern_args})>;
         
         """),
     }
@@ -1817,6 +2152,9 @@ class CallbackInfo(GeneralInfo):
         GeneralInfo.__init__(self, gen, decl[0], namespaces)
         self.args = []
         self.is_ignored = False
+        # the return value is not forwarded from the Rust closure, so non-void callbacks (e.g. face::FN_FaceDetector) are
+        # only exposed as type aliases and can't be passed to the functions
+        self.is_void = decl[1] == "void"
         for arg in decl[3]:
             ai = ArgInfo(gen, arg)
             while any(True for x in self.args if x.name == ai.name):
@@ -1825,6 +2163,9 @@ class CallbackInfo(GeneralInfo):
             if ai.type.is_ignored:
                 self.is_ignored = True
             self.args.append(ai)
+        # unnamed trailing void* is the user data, e.g. in viz::Viz3d::KeyboardCallback
+        if self.is_void and len(self.args) > 0 and self.args[-1].name != "userdata" and self.args[-1].is_void_ptr():
+            self.args[-1].name = self.args[-1].rsname = "userdata"
 
         if len(decl) > 5:
             self.comment = decl[5]
@@ -1839,8 +2180,12 @@ class CallbackInfo(GeneralInfo):
                 return None
             extern_args.append(arg.type.rust_extern_arg_func_decl(arg.rsname, arg.is_output()))
             if arg.name != "userdata":
-                args.append(arg.type.rust_full)
+                if self.is_void:
+                    args.append(arg.type.rust_arg_func_decl(arg.rsname, arg.is_output()).split(": ", 1)[1])
+                else:
+                    args.append(arg.type.rust_full)
         return CallbackInfo.TEMPLATES["rust"].substitute(combine_dicts(self.__dict__, {
+            "rust_local": self.gen.get_type_info(self.fullname).rust_local,
             "doc_comment": self.gen.reformat_doc(self.comment),
             "args": ", ".join(args),
             "extern_args": ", ".join(extern_args),
@@ -1931,8 +2276,8 @@ class TypeInfo(object):
         self.cpptype = self.typeid
         self.c_safe_id = "XX"  # c safe type identifier used for file names and return wrappers
 
-        _, self.rust_local = split_known_namespace(self.typeid, gen.namespaces)
-        self.rust_local = self.rust_local.replace("::", "_")  # only the type name for Rust without module path
+        namespace, self.rust_local = split_known_namespace(self.typeid, gen.namespaces)
+        self.rust_local = rust_namespace_prefix(self.ci and self.ci.module, namespace) + self.rust_local.replace("::", "_")  # only the type name for Rust without module path
         self.rust_safe_id = self.rust_local  # rust safe type identifier used for file and function names
         self.rust_full = self.rust_local  # full module path (with modules/crate::) to Rust type
         self.rust_extern = ""  # Rust type used on the boundary between Rust and C (e.g. in return wrappers)
@@ -2341,6 +2686,7 @@ class SimpleClassTypeInfo(TypeInfo):
                 self.cpp_extern = "{}Wrapper".format(self.ci.name)
                 self.c_safe_id = self.cpp_extern
             self.rust_extern = self.rust_full
+            self.rust_safe_id = class_rust_safe_id.get(self.ci.fullname, self.rust_safe_id)
             self.is_copy = True
 
     def rust_arg_func_decl(self, var_name, is_output=False, attr_type=None):
@@ -2425,6 +2771,12 @@ class EnumTypeInfo(TypeInfo):
             self.rust_extern = self.rust_full
             self.is_copy = True
 
+    def rust_module(self):
+        # return wrappers of the enums from contrib modules must only be declared together with their module
+        if self.ei:
+            return self.ei.module
+        return super().rust_module()
+
     def __str__(self):
         return "{} (enum)".format(self.cpptype)
 
@@ -2443,7 +2795,7 @@ class BoxedClassTypeInfo(TypeInfo):
         self.cpp_extern = "void*"
         self.c_safe_id = "void_X"
         self.is_ignored = self.ci.is_ignored
-        self.rust_safe_id = self.ci.name
+        self.rust_safe_id = class_rust_safe_id.get(self.ci.fullname, rust_namespace_prefix(self.ci.module, self.ci.namespace) + self.ci.name)
 
     def is_input_array(self):
         return self.cpptype == "cv::_InputArray"
@@ -2489,144 +2841,108 @@ class BoxedClassTypeInfo(TypeInfo):
 
 class VectorTypeInfo(TypeInfo):
     TEMPLATES = {
-        "rust_common": template("""
-            pub struct ${rust_local} {
-                pub(crate) ptr: ${rust_extern}
-            }
+        "rust_alias": template("""
+            pub type ${rust_local} = core::Vector<${inner_rust_full}>;
             
             impl ${rust_local} {
-                #[inline(always)] pub fn as_raw_${rust_local}(&self) -> ${rust_extern} { self.ptr }
-                
+                #[inline(always)] pub fn as_raw_${rust_local}(&self) -> ${rust_extern} { self.as_raw_Vector() }
+            }
+            
+        """),
+
+        "rust_element": template("""
+            impl core::VectorElement for ${inner_rust_full} {
                 #[inline]
-                pub fn iter(&self) -> crate::templ::VectorRefIterator<Self> {
-                    crate::templ::VectorRefIterator::new(self)
+                fn extern_new() -> ${rust_extern} {
+                    cpp!(unsafe [] -> ${rust_extern} as "${cpp_extern}" {
+                        return new ${cpptype}();
+                    })
                 }
-            ${inherent_methods}}
             
-            impl Drop for ${rust_local} {
                 #[inline]
-                fn drop(&mut self) {
-                    let vec = self.as_raw_${rust_local}();
+                unsafe fn extern_delete(vec: ${rust_extern}) {
                     cpp!(unsafe [vec as "${cpptype}*"] {
                         delete vec;
                     })
                 }
-            }
-            
-            impl IntoIterator for ${rust_local} {
-                type Item = ${inner_rust_full};
-                type IntoIter = crate::templ::VectorIterator<Self>;
-            
-                #[inline]
-                fn into_iter(self) -> Self::IntoIter {
-                    Self::IntoIter::new(self)
-                }
-            }
-
-            impl<'i> IntoIterator for &'i ${rust_local} {
-                type Item = ${inner_rust_full};
-                type IntoIter = crate::templ::VectorRefIterator<'i, ${rust_local}>;
             
                 #[inline]
-                fn into_iter(self) -> Self::IntoIter {
-                    self.iter()
-                }
-            }
-
-            impl<'i> crate::templ::Vector<'i> for ${rust_local} {
-                type Storage = ${inner_rust_full};
-
-                #[inline]
-                fn new() -> Self {
-                    Self { ptr: cpp!(unsafe [] -> ${rust_extern} as "${cpp_extern}" {
-                        return new ${cpptype}();
-                    })}
-                }
-                
-                #[inline]
-                fn len(&self) -> size_t {
-                    let vec = self.as_raw_${rust_local}();
+                unsafe fn extern_len(vec: *const c_void) -> size_t {
                     cpp!(unsafe [vec as "const ${cpptype}*"] -> size_t as "size_t" {
                         return vec->size();
                     })
                 }
-
+            
                 #[inline]
-                fn is_empty(&self) -> bool {
-                    let vec = self.as_raw_${rust_local}();
+                unsafe fn extern_is_empty(vec: *const c_void) -> bool {
                     cpp!(unsafe [vec as "const ${cpptype}*"] -> bool as "bool" {
                         return vec->empty();
                     })
                 }
-
+            
                 #[inline]
-                fn capacity(&self) -> size_t {
-                    let vec = self.as_raw_${rust_local}();
+                unsafe fn extern_capacity(vec: *const c_void) -> size_t {
                     cpp!(unsafe [vec as "const ${cpptype}*"] -> size_t as "size_t" {
                         return vec->capacity();
                     })
                 }
-                
+            
                 #[inline]
-                fn shrink_to_fit(&mut self) {
-                    let vec = self.as_raw_${rust_local}();
+                unsafe fn extern_shrink_to_fit(vec: ${rust_extern}) {
                     cpp!(unsafe [vec as "${cpptype}*"] {
                         vec->shrink_to_fit();
                     })
-                }                
-
+                }
+            
                 #[inline]
-                fn reserve(&mut self, additional: size_t) {
-                    let vec = self.as_raw_${rust_local}();
+                unsafe fn extern_reserve(vec: ${rust_extern}, additional: size_t) {
                     cpp!(unsafe [vec as "${cpptype}*", additional as "size_t"] {
                         vec->reserve(vec->size() + additional);
                     })
                 }
-                
+            
                 #[inline]
-                fn remove(&mut self, index: size_t) -> Result<()> {
-                    crate::templ::Vector::<Storage=Self::Storage, Arg=Self::Arg>::index_check(index, self.len())?;
-                    let vec = self.as_raw_${rust_local}();
+                unsafe fn extern_remove(vec: ${rust_extern}, index: size_t) {
                     cpp!(unsafe [vec as "${cpptype}*", index as "size_t"] {
                         vec->erase(vec->begin() + index);
-                    });
-                    Ok(())
+                    })
                 }
-                
+            
                 #[inline]
-                fn swap(&mut self, index1: size_t, index2: size_t) -> Result<()> {
-                    let len = self.len();
-                    crate::templ::Vector::<Storage=Self::Storage, Arg=Self::Arg>::index_check(index1, len)?;
-                    crate::templ::Vector::<Storage=Self::Storage, Arg=Self::Arg>::index_check(index2, len)?;
-                    if index1 != index2 {
-                        let vec = self.as_raw_${rust_local}();
-                        cpp!(unsafe [vec as "${cpptype}*", index1 as "size_t", index2 as "size_t"] {
-                            swap((*vec)[index1], (*vec)[index2]);
-                        });
-                    }
-                    Ok(())
+                unsafe fn extern_swap(vec: ${rust_extern}, index1: size_t, index2: size_t) {
+                    cpp!(unsafe [vec as "${cpptype}*", index1 as "size_t", index2 as "size_t"] {
+                        swap((*vec)[index1], (*vec)[index2]);
+                    })
                 }
-                
+            
                 #[inline]
-                fn clear(&mut self) {
-                    let vec = self.as_raw_${rust_local}();
+                unsafe fn extern_clear(vec: ${rust_extern}) {
                     cpp!(unsafe [vec as "${cpptype}*"] {
                         vec->clear();
                     })
                 }
-                
-            ${vector_methods}}
+            ${element_methods}}
+            
+            impl<'i> core::VectorExtern<'i> for ${inner_rust_full} {
+            ${extern_methods}}
             
-            unsafe impl Send for ${rust_local} {}
-            ${impls}
         """),
 
-        "rust_methods_boxed": template("""
-            type Arg = ${inner_rust_full};
+        "rust_element_boxed": template("""
+            
+            #[inline]
+            unsafe fn extern_get(vec: *const c_void, index: size_t) -> Self {
+                ${inner_rust_full}::from_raw_ptr(cpp!(unsafe [vec as "const ${cpptype}*", index as "size_t"] -> ${rust_extern} as "${cpp_extern}" {
+                    return new ${inner_cpptype}((*vec)[index]);
+                }))
+            }
+        """),
 
+        "rust_extern_boxed": template("""
+            type Arg = ${inner_rust_full};
+            
             #[inline]
-            fn push(&mut self, val: Self::Arg) {
-                let vec = self.as_raw_${rust_local}();
+            unsafe fn extern_push(vec: ${rust_extern}, val: Self::Arg) {
                 let val = val.as_raw_${inner_rust_local}();
                 cpp!(unsafe [vec as "${cpptype}*", val as "${inner_cpptype}*"] {
                     vec->push_back(*val);
@@ -2634,62 +2950,37 @@ class VectorTypeInfo(TypeInfo):
             }
             
             #[inline]
-            fn insert(&mut self, index: size_t, val: Self::Arg) -> Result<()> {
-                crate::templ::Vector::<Storage=Self::Storage, Arg=Self::Arg>::index_check(index, self.len() + 1)?;
-                let vec = self.as_raw_${rust_local}();
+            unsafe fn extern_insert(vec: ${rust_extern}, index: size_t, val: Self::Arg) {
                 let val = val.as_raw_${inner_rust_local}();
                 cpp!(unsafe [vec as "${cpptype}*", index as "size_t", val as "${inner_cpptype}*"] {
                     vec->insert(vec->begin() + index, *val);
-                });
-                Ok(())
+                })
             }
             
             #[inline]
-            fn get(&self, index: size_t) -> Result<Self::Storage> {
-                let vec = self.as_raw_${rust_local}();
-                cpp!(unsafe [vec as "const ${cpptype}*", index as "size_t"] -> crate::sys::${return_wrapper_type} as "${return_wrapper_type}" {
-                    try {
-                        return { Error::Code::StsOk, NULL, new ${inner_cpptype}(vec->at(index)) };
-                    } VEC_CATCH(${return_wrapper_type})
-                }).into_result().map(|ptr| ${inner_rust_full} { ptr })
+            unsafe fn extern_set(vec: ${rust_extern}, index: size_t, val: Self::Arg) {
+                let val = val.as_raw_${inner_rust_local}();
+                cpp!(unsafe [vec as "${cpptype}*", index as "size_t", val as "${inner_cpptype}*"] {
+                    (*vec)[index] = *val;
+                })
             }
+        """),
 
-            #[inline]
-            unsafe fn get_unchecked(&self, index: size_t) -> Self::Storage {
-                let vec = self.as_raw_${rust_local}();
-                ${inner_rust_full} { ptr: cpp!(unsafe [vec as "const ${cpptype}*", index as "size_t"] -> ${rust_extern} as "${cpp_extern}" {
-                    return new ${inner_cpptype}((*vec)[index]);
-                })}
-            }
+        "rust_element_string": template("""
             
             #[inline]
-            fn set(&mut self, index: size_t, val: Self::Arg) -> Result<()> {
-                let vec = self.as_raw_${rust_local}();
-                let val = val.ptr;
-                cpp!(unsafe [vec as "${cpptype}*", index as "size_t", val as "${inner_cpptype}*"] -> crate::sys::cv_return_value_void as "cv_return_value_void" {
-                    try {
-                        vec->at(index) = *val;
-                        return { Error::Code::StsOk, NULL };
-                    } VEC_CATCH(cv_return_value_void)
-                }).into_result()
-            }
-            
-            #[inline]
-            unsafe fn set_unchecked(&mut self, index: size_t, val: Self::Arg) {
-                let vec = self.as_raw_${rust_local}();
-                let val = val.ptr;
-                cpp!(unsafe [vec as "${cpptype}*", index as "size_t", val as "${inner_cpptype}*"] {
-                    (*vec)[index] = *val;
-                })
+            unsafe fn extern_get(vec: *const c_void, index: size_t) -> Self {
+                ::std::ffi::CStr::from_ptr(cpp!(unsafe [vec as "const ${cpptype}*", index as "size_t"] -> ${inner_rust_extern} as "${inner_cpp_extern}" {
+                    return (*vec)[index].c_str();
+                })).to_string_lossy().into_owned()
             }
         """),
 
-        "rust_methods_string": template("""
+        "rust_extern_string": template("""
             type Arg = &'i str;
             
             #[inline]
-            fn push(&mut self, val: Self::Arg) {
-                let vec = self.as_raw_${rust_local}();
+            unsafe fn extern_push(vec: ${rust_extern}, val: Self::Arg) {
                 let val = ::std::ffi::CString::new(val).unwrap();
                 let val = val.as_ptr();
                 cpp!(unsafe [vec as "${cpptype}*", val as "${inner_cpp_extern}"] {
@@ -2698,137 +2989,115 @@ class VectorTypeInfo(TypeInfo):
             }
             
             #[inline]
-            fn insert(&mut self, index: size_t, val: Self::Arg) -> Result<()> {
-                crate::templ::Vector::<Storage=Self::Storage, Arg=Self::Arg>::index_check(index, self.len() + 1)?;
-                let vec = self.as_raw_${rust_local}();
+            unsafe fn extern_insert(vec: ${rust_extern}, index: size_t, val: Self::Arg) {
                 let val = ::std::ffi::CString::new(val).unwrap();
                 let val = val.as_ptr();
                 cpp!(unsafe [vec as "${cpptype}*", index as "size_t", val as "${inner_cpp_extern}"] {
                     vec->insert(vec->begin() + index, String(val));
-                });
-                Ok(())
+                })
             }
             
             #[inline]
-            fn get(&self, index: size_t) -> Result<Self::Storage> {
-                let vec = self.as_raw_${rust_local}();
-                cpp!(unsafe [vec as "const ${cpptype}*", index as "size_t"] -> crate::sys::${return_wrapper_type} as "${return_wrapper_type}" {
-                    try {
-                        return { Error::Code::StsOk, NULL, vec->at(index).c_str() };
-                    } VEC_CATCH(${return_wrapper_type})
-                }).into_result().map(|x| unsafe { ::std::ffi::CStr::from_ptr(x) }.to_string_lossy().into_owned())
-            }
-
-            #[inline]
-            unsafe fn get_unchecked(&self, index: size_t) -> Self::Storage {
-                let vec = self.as_raw_${rust_local}();
-                ::std::ffi::CStr::from_ptr(cpp!(unsafe [vec as "const ${cpptype}*", index as "size_t"] -> ${inner_rust_extern} as "${inner_cpp_extern}" {
-                    return (*vec)[index].c_str();
-                })).to_string_lossy().into_owned()
-            }
-
-            #[inline]
-            fn set(&mut self, index: size_t, val: Self::Arg) -> Result<()> {
-                let vec = self.as_raw_${rust_local}();
+            unsafe fn extern_set(vec: ${rust_extern}, index: size_t, val: Self::Arg) {
                 let val = ::std::ffi::CString::new(val).unwrap();
                 let val = val.as_ptr();
-                cpp!(unsafe [vec as "${cpptype}*", index as "size_t", val as "${inner_cpp_extern}"] -> crate::sys::cv_return_value_void as "cv_return_value_void" {
-                    try {
-                        vec->at(index) = String(val);
-                        return { Error::Code::StsOk, NULL };
-                    } VEC_CATCH(cv_return_value_void)
-                }).into_result()
+                cpp!(unsafe [vec as "${cpptype}*", index as "size_t", val as "${inner_cpp_extern}"] {
+                    (*vec)[index] = String(val);
+                })
             }
+        """),
 
+        "rust_element_non_boxed": template("""
+            
             #[inline]
-            unsafe fn set_unchecked(&mut self, index: size_t, val: Self::Arg) {
-                let vec = self.as_raw_${rust_local}();
-                let val = ::std::ffi::CString::new(val).unwrap();
-                let val = val.as_ptr();
-                cpp!(unsafe [vec as "${cpptype}*", index as "size_t", val as "${inner_cpp_extern}"] {
-                    (*vec)[index] = String(val);
+            unsafe fn extern_get(vec: *const c_void, index: size_t) -> Self {
+                cpp!(unsafe [vec as "const std::vector<${inner_cpp_extern}>*", index as "size_t"] -> ${inner_rust_extern} as "${inner_cpp_extern}" {
+                    return (*vec)[index];
                 })
             }
         """),
 
-        "rust_methods_non_boxed": template("""
+        "rust_extern_non_boxed": template("""
             type Arg = ${inner_rust_full};
-
+            
             #[inline]
-            fn push(&mut self, val: Self::Arg) {
-                let vec = self.as_raw_${rust_local}();
+            unsafe fn extern_push(vec: ${rust_extern}, val: Self::Arg) {
                 cpp!(unsafe [vec as "${cpptype}*", val as "${inner_cpptype}"] {
                     vec->push_back(val);
                 })
             }
             
             #[inline]
-            fn insert(&mut self, index: size_t, val: Self::Arg) -> Result<()> {
-                crate::templ::Vector::<Storage=Self::Storage, Arg=Self::Arg>::index_check(index, self.len() + 1)?;
-                let vec = self.as_raw_${rust_local}();
+            unsafe fn extern_insert(vec: ${rust_extern}, index: size_t, val: Self::Arg) {
                 cpp!(unsafe [vec as "${cpptype}*", index as "size_t", val as "${inner_cpptype}"] {
                     vec->insert(vec->begin() + index, val);
-                });
-                Ok(())
-            }
-            
-            #[inline]
-            fn get(&self, index: size_t) -> Result<Self::Storage> {
-                let vec = self.as_raw_${rust_local}();
-                cpp!(unsafe [vec as "const std::vector<${inner_cpp_extern}>*", index as "size_t"] -> crate::sys::${return_wrapper_type} as "${return_wrapper_type}" {
-                    try {
-                        return { Error::Code::StsOk, NULL, vec->at(index) };
-                    } VEC_CATCH(${return_wrapper_type})
-                }).into_result()
-            }
-
-            #[inline]
-            unsafe fn get_unchecked(&self, index: size_t) -> Self::Storage {
-                let vec = self.as_raw_${rust_local}();
-                cpp!(unsafe [vec as "const std::vector<${inner_cpp_extern}>*", index as "size_t"] -> ${inner_rust_extern} as "${inner_cpp_extern}" {
-                    return (*vec)[index];
                 })
             }
-
-            #[inline]
-            fn set(&mut self, index: size_t, val: Self::Arg) -> Result<()> {
-                let vec = self.as_raw_${rust_local}();
-                cpp!(unsafe [vec as "${cpptype}*", index as "size_t", val as "${inner_cpptype}"] -> crate::sys::cv_return_value_void as "cv_return_value_void" {
-                    try {
-                        vec->at(index) = val;
-                        return { Error::Code::StsOk, NULL };
-                    } VEC_CATCH(cv_return_value_void)
-                }).into_result()
-            }
-
+            
             #[inline]
-            unsafe fn set_unchecked(&mut self, index: size_t, val: Self::Arg) {
-                let vec = self.as_raw_${rust_local}();
+            unsafe fn extern_set(vec: ${rust_extern}, index: size_t, val: Self::Arg) {
                 cpp!(unsafe [vec as "${cpptype}*", index as "size_t", val as "${inner_cpptype}"] {
                     (*vec)[index] = val;
                 })
             }
         """),
 
-        "rust_methods_copy_non_bool": template("""
+        "rust_element_vector": template("""
+            impl core::VectorElementRef for ${inner_rust_full} {
+                #[inline]
+                unsafe fn extern_get_ref(vec: *const c_void, index: size_t) -> ${rust_extern} {
+                    cpp!(unsafe [vec as "const ${cpptype}*", index as "size_t"] -> ${rust_extern} as "${cpp_extern}" {
+                        return const_cast<${inner_cpptype}*>(&(*vec)[index]);
+                    })
+                }
+            }
+            
+        """),
 
+        "rust_element_copy_non_bool": template("""
+            
+            #[inline]
+            fn convert_to_vec(v: &core::Vector<Self>) -> Option<Vec<Self>> {
+                Some(v.as_slice().to_vec())
+            }
+            
             #[inline]
-            fn to_vec(&self) -> Vec<Self::Storage> {
-                self.to_slice().to_vec()
+            fn as_pod_slice(v: &core::Vector<Self>) -> Option<&[Self]> {
+                Some(v.as_slice())
             }
         """),
 
-        "rust_inherent_copy_non_bool": template("""
-
-            pub fn to_slice(&self) -> &[${inner_rust_full}] {
-                unsafe {
-                    let vec = self.as_raw_${rust_local}();
-                    let data = cpp!(unsafe [vec as "${cpptype}*"] -> *const ${inner_rust_full} as "${cpp_extern}*" {
-                        return reinterpret_cast<${cpp_extern}*>(vec->data());
-                    });
-                    ::std::slice::from_raw_parts(data, crate::templ::Vector::len(self))
+        "rust_element_pod": template("""
+            unsafe impl core::VectorElementPod for ${inner_rust_full} {
+                #[inline]
+                unsafe fn extern_data(vec: *const c_void) -> *const Self {
+                    cpp!(unsafe [vec as "const ${cpptype}*"] -> *const ${inner_rust_full} as "const ${inner_cpptype}*" {
+                        return vec->data();
+                    })
+                }
+            
+                #[inline]
+                unsafe fn extern_data_mut(vec: ${rust_extern}) -> *mut Self {
+                    cpp!(unsafe [vec as "${cpptype}*"] -> *mut ${inner_rust_full} as "${inner_cpptype}*" {
+                        return vec->data();
+                    })
+                }
+            
+                #[inline]
+                unsafe fn extern_from_slice(data: *const Self, len: size_t) -> ${rust_extern} {
+                    cpp!(unsafe [data as "const ${inner_cpptype}*", len as "size_t"] -> ${rust_extern} as "${cpp_extern}" {
+                        return new ${cpptype}(data, data + len);
+                    })
+                }
+            
+                #[inline]
+                unsafe fn extern_extend_from_slice(vec: ${rust_extern}, data: *const Self, len: size_t) {
+                    cpp!(unsafe [vec as "${cpptype}*", data as "const ${inner_cpptype}*", len as "size_t"] {
+                        vec->insert(vec->end(), data, data + len);
+                    })
                 }
             }
+            
         """),
 
         "input_output_array": template("""
@@ -2923,30 +3192,53 @@ class VectorTypeInfo(TypeInfo):
             "inner_rust_extern": self.inner.rust_extern,
             "inner_rust_local": self.inner.rust_local,
             "inner_rust_full": self.inner.rust_full,
-            "return_wrapper_type": self.inner.rust_cpp_return_wrapper_type(),
         })
-        vector_methods = ""
-        inherent_methods = ""
+        element_methods = ""
+        extern_methods = ""
         impls = ""
+        is_pod = False
         if self.inner.is_by_ptr:
-            vector_methods += VectorTypeInfo.TEMPLATES["rust_methods_boxed"].substitute(template_vars)
+            element_methods += VectorTypeInfo.TEMPLATES["rust_element_boxed"].substitute(template_vars)
+            extern_methods += VectorTypeInfo.TEMPLATES["rust_extern_boxed"].substitute(template_vars)
         elif isinstance(self.inner, StringTypeInfo):
-            vector_methods += VectorTypeInfo.TEMPLATES["rust_methods_string"].substitute(template_vars)
+            element_methods += VectorTypeInfo.TEMPLATES["rust_element_string"].substitute(template_vars)
+            extern_methods += VectorTypeInfo.TEMPLATES["rust_extern_string"].substitute(template_vars)
         else:
-            vector_methods += VectorTypeInfo.TEMPLATES["rust_methods_non_boxed"].substitute(template_vars)
-            if self.inner.is_copy and self.inner.typeid != "bool":
-                vector_methods += VectorTypeInfo.TEMPLATES["rust_methods_copy_non_bool"].substitute(template_vars)
-                inherent_methods += VectorTypeInfo.TEMPLATES["rust_inherent_copy_non_bool"].substitute(template_vars)
+            element_methods += VectorTypeInfo.TEMPLATES["rust_element_non_boxed"].substitute(template_vars)
+            extern_methods += VectorTypeInfo.TEMPLATES["rust_extern_non_boxed"].substitute(template_vars)
+            # std::vector<bool> is bit-packed so it can't be viewed as a slice
+            is_pod = self.inner.is_copy and self.inner.typeid != "bool"
+        if is_pod:
+            element_methods += VectorTypeInfo.TEMPLATES["rust_element_copy_non_bool"].substitute(template_vars)
+            impls += VectorTypeInfo.TEMPLATES["rust_element_pod"].substitute(template_vars)
+        if isinstance(self.inner, VectorTypeInfo):
+            impls += VectorTypeInfo.TEMPLATES["rust_element_vector"].substitute(template_vars)
         if self.inner.typeid in data_type_typeids or isinstance(self.inner, VectorTypeInfo) and self.inner.inner.typeid in data_type_typeids:
             # if "inner" not in self.inner.__dict__ or (self.inner.inner is not None and self.inner.inner.typeid != "bool"):
             impls += VectorTypeInfo.TEMPLATES["input_output_array"].substitute(template_vars)
 
         self.inner.gen_return_wrappers(self.gen.cpp_dir, self.gen.rust_dir)
-        write_exc("{}/{}-{}.type.rs".format(self.gen.rust_dir, self.rust_module(), self.rust_safe_id), lambda f: f.write(VectorTypeInfo.TEMPLATES["rust_common"].substitute(combine_dicts(template_vars, {
-            "vector_methods": indent(vector_methods),
-            "inherent_methods": indent(inherent_methods),
-            "impls": impls,
-        }))))
+        write_exc("{}/{}-{}.type.rs".format(self.gen.rust_dir, self.rust_module(), self.rust_safe_id), lambda f: f.write(
+            VectorTypeInfo.TEMPLATES["rust_alias"].substitute(template_vars)
+        ))
+        # several C++ types can map to the same Rust element type (e.g. String and std::string), Vector<T> must
+        # only have one implementation of the element traits so they're keyed by the Rust type
+        element_id = re.sub(r"\W", "_", self.inner.rust_full)
+        write_exc("{}/{}-Vector-{}.type.rs".format(self.gen.rust_dir, self.rust_module(), element_id), lambda f: f.write(
+            VectorTypeInfo.TEMPLATES["rust_element"].substitute(combine_dicts(template_vars, {
+                "element_methods": indent(element_methods),
+                "extern_methods": indent(extern_methods),
+            })) + impls
+        ))
+
+    def is_std_string_vector(self):
+        return isinstance(self.inner, StringTypeInfo) and self.inner.is_std
+
+    def cpp_arg_func_call(self, var_name, is_output=False):
+        if self.is_std_string_vector():
+            # shares the Rust type with std::vector<cv::String>, see cpp/common.hpp
+            return "ocvrs::StdStringVectorArg<String>(reinterpret_cast<{}*>({}))".format(self.cpptype, var_name)
+        return super().cpp_arg_func_call(var_name, is_output)
 
     def __str__(self):
         return "Vector[%s]" % (self.inner)
@@ -2992,6 +3284,20 @@ class SmartPtrTypeInfo(TypeInfo):
             
         """),
 
+        "rust_from": template("""
+            impl From<${derived_rust_full}> for ${rust_local} {
+                /// Moves the object into a new smart pointer
+                fn from(val: ${derived_rust_full}) -> Self {
+                    let val = std::mem::ManuallyDrop::new(val);
+                    let ptr = val.ptr;
+                    unsafe { Self::from_raw_ptr(cpp!([ptr as "${derived_cpp_type}*"] -> ${rust_extern} as "${cpp_extern}" {
+                        return new cv::Ptr<${inner_cpp_type}>(ptr);
+                    })) }
+                }
+            }
+            
+        """),
+
         "rust_deref": template("""
             impl ${rust_local} {
                 #[inline(always)] fn get_inner(&self) -> ${rust_extern} {
@@ -3093,6 +3399,21 @@ class SmartPtrTypeInfo(TypeInfo):
 
         write_exc("{}/{}-{}.type.rs".format(self.gen.rust_dir, self.rust_module(), self.rust_safe_id), write_rust)
 
+        # conversions from the classes of the current module go to the module's types so that every module adds its own
+        if not isinstance(self.inner, PrimitiveTypeInfo) and self.inner.ci is not None:
+            derived = self.gen.derived_classes(self.inner.ci.fullname, self.gen.module)
+            if derived:
+                def write_rust_from(f):
+                    for ci in derived:
+                        typ = ci.type_info()
+                        f.write(SmartPtrTypeInfo.TEMPLATES["rust_from"].substitute(combine_dicts(self.__dict__, {
+                            "derived_rust_full": typ.rust_full,
+                            "derived_cpp_type": typ.cpptype,
+                            "inner_cpp_type": self.inner.cpptype,
+                        })))
+
+                write_exc("{}/{}-{}-from.type.rs".format(self.gen.rust_dir, self.gen.module, self.rust_safe_id), write_rust_from)
+
     def __str__(self):
         return "SmartPtr[%s]" % (self.inner)
 
@@ -3174,7 +3495,7 @@ class RawPtrTypeInfo(TypeInfo):
         return super().rust_arg_func_call(var_name, is_output)
 
     def cpp_arg_func_call(self, var_name, is_output=False):
-        if isinstance(self.inner, PrimitiveTypeInfo):
+        if isinstance(self.inner, PrimitiveTypeInfo) or isinstance(self.inner, VectorTypeInfo) and self.inner.is_std_string_vector():
             return self.inner.cpp_arg_func_call(var_name, is_output)
         if self.is_by_ptr:
             return "reinterpret_cast<{}*>({})".format(self.cpptype, var_name)
@@ -3280,7 +3601,7 @@ def parse_type(gen, typeid):
             ci = get_class_type_info(actual, is_const)
             if ci:
                 return ci
-            return parse_type(gen, actual)
+            return parse_type(gen, "{}{}{}".format("const " if is_const else "", actual, "&" if is_by_ref else ""))
     return UnknownTypeInfo(gen, full_typeid)
 
 #
@@ -3367,13 +3688,19 @@ class RustWrapperGenerator(object):
                 ${doc_comment}pub struct ${rust_local} {
                     #[doc(hidden)] pub(crate) ptr: *mut c_void
                 }
-                
+
+            """),
+
+            "rust_drop": template("""
                 impl Drop for ${rust_local} {
                     fn drop(&mut self) {
                         unsafe { sys::cv_${rust_local}_delete(self.ptr) };
                     }
                 }
-                
+
+            """),
+
+            "rust_raw": template("""
                 impl ${rust_local} {
                     #[inline(always)] pub fn as_raw_${rust_local}(&self) -> *mut c_void { self.ptr }
                     
@@ -3443,6 +3770,7 @@ class RustWrapperGenerator(object):
         self.generated_functions = []
         self.func_names = set()
         self.opencv_version = "0.0.0"
+        self.module = ""
 
     def get_class(self, classname):
         """
@@ -3467,7 +3795,8 @@ class RustWrapperGenerator(object):
         :rtype: TypeInfo
         """
         typeid = typeid.strip()
-        if typeid not in self.type_infos:
+        # unknown types are parsed again because the class can be declared after its first usage (e.g. cv::RNG)
+        if typeid not in self.type_infos or isinstance(self.type_infos[typeid], UnknownTypeInfo):
             self.type_infos[typeid] = parse_type(self, typeid)
         return self.type_infos[typeid]
 
@@ -3502,7 +3831,7 @@ class RustWrapperGenerator(object):
         return None
 
     def add_decl(self, module, decl):
-        decl = decl_patch(module, decl)
+        decl = decl_patch(module, decl, self.opencv_version)
         if decl[0] == "cv.String.String" or decl[0] == 'cv.Exception.~Exception':
             return
         if decl[0] == "cv.Algorithm":
@@ -3566,7 +3895,8 @@ class RustWrapperGenerator(object):
         # register
         if item.is_ignored():
             logging.info('ignored: %s', item)
-        elif not self.get_const(item.name):
+        # constants from different classes can share the short name (e.g. FileNode::UNIFORM and RNG::UNIFORM)
+        elif not any(c.rustname == item.rustname for c in self.consts):
             self.consts.append(item)
         return item
 
@@ -3609,6 +3939,7 @@ class RustWrapperGenerator(object):
         :type rust_dir: str
         :return:
         """
+        self.module = module
         self.cpp_dir = cpp_dir
         self.rust_dir = rust_dir
         self.opencv_version = opencv_version
@@ -3621,6 +3952,11 @@ class RustWrapperGenerator(object):
         for m, decls in decls_manual_pre.items():
             for decl in decls:
                 logging.info("\n--- Manual ---\n%s", pformat(decl, 4))
+                # headers are not parsed yet, so the namespace of the declaration must be made known explicitly to get the same
+                # Rust name as the real declaration (e.g. cv::ppf_match_3d::Pose3D)
+                name = decl[0].split(" ", 1)[-1]
+                if "." in name:
+                    self.namespaces.add(name.rsplit(".", 1)[0].replace(".", "::"))
                 self.add_decl(m, decl)
 
         for hdr in srcfiles:
@@ -3630,7 +3966,9 @@ class RustWrapperGenerator(object):
             logging.info("\n\n=============== Header: %s ================\n\n", hdr)
             logging.info("Namespaces: %s", sorted(parser.namespaces))
             logging.info("Comment: %s", parser.module_comment)
-            includes.append('#include "' + hdr + '"')
+            # master header is already included through common_opencv.h and some of them (e.g. hfs.hpp) lack include guards
+            if os.path.basename(hdr) != "{}.hpp".format(module):
+                includes.append('#include "' + hdr + '"')
             for decl in decls:
                 logging.info("\n--- Incoming ---\n%s", pformat(decl, 4))
                 self.add_decl(module, decl)
@@ -3780,7 +4118,8 @@ class RustWrapperGenerator(object):
         # to call it from rust code.
         # If duplicate functions have the same call arguments, we skip duplicate function.
         rust_func_name = fi.r_name()
-        classname = "" if fi.kind == fi.KIND_FUNCTION else fi.classpath
+        # classes with the same name from different namespaces must not affect each other (e.g. cv::PlaneWarper and cv::detail::PlaneWarper)
+        classname = "" if fi.kind == fi.KIND_FUNCTION else qualify_name(fi.namespace, fi.classpath)
         renamed = False
         while classname + '::' + rust_func_name in self.func_names:
             rust_func_name = bump_counter(rust_func_name)
@@ -3856,6 +4195,11 @@ class RustWrapperGenerator(object):
         self.moduleSafeRust.write(RustWrapperGenerator.TEMPLATES["boxed"]["rust"].substitute(combine_dicts(typ.__dict__, {
             "doc_comment": self.reformat_doc(ci.comment)
         })))
+        templ = ci.get_manual_declaration_tpl("rust_drop")
+        if templ is None:
+            templ = RustWrapperGenerator.TEMPLATES["boxed"]["rust_drop"]
+        self.moduleSafeRust.write(templ.substitute(typ.__dict__))
+        self.moduleSafeRust.write(RustWrapperGenerator.TEMPLATES["boxed"]["rust_raw"].substitute(typ.__dict__))
 
         bases = self.all_bases(ci.fullname)
         if ci.is_trait:
@@ -3877,10 +4221,28 @@ class RustWrapperGenerator(object):
         ci = self.get_class(name)
         if ci is not None:
             for b in ci.bases:
+                b = type_replace.get(b, b)
                 bases.add(b)
                 bases = bases.union(self.all_bases(b))
         return bases
 
+    def derived_classes(self, name, module):
+        """
+        Returns the non-abstract boxed classes of module that can be moved into a smart pointer to class name
+        :type name: str
+        :type module: str
+        :rtype: list[ClassInfo]
+        """
+        out = []
+        for ci in sorted(self.classes.values(), key=lambda ci: ci.fullname):
+            if ci.module != module or ci.is_ignored or ci.is_ghost or ci.is_simple or ci.is_abstract:
+                continue
+            if ci.module in type_manual and ci.name in type_manual[ci.module]:  # manual Drop may hold extra state
+                continue
+            if classes_equal(ci.fullname, name) or any(classes_equal(x, name) for x in self.all_bases(ci.fullname)):
+                out.append(ci)
+        return out
+
     def gen_class(self, ci):
         """
         :type ci: ClassInfo
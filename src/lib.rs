@@ -8,7 +8,7 @@ pub use error::{Error, Result};
 pub use crate::opencv::hub::*;
 
 #[macro_use]
-mod templ;
+pub mod templ;
 
 mod opencv;
 mod manual;
@@ -17,9 +17,10 @@ mod error;
 
 pub mod prelude {
     pub use crate::{
-        core::{DataType, Mat},
-        templ::Vector,
+        core::{DataType, Mat, VectorTrait},
     };
+    #[deprecated = "Use VectorTrait"]
+    pub use crate::core::VectorTrait as Vector;
 }
 
 pub(crate) mod mod_prelude {
@@ -43,4 +44,8 @@ cpp! {{
     #include "cpp/common.hpp"
     #include "../types.h"
     #include "../return_types.h"
+    #include "cpp/core.hpp"
+    #include "cpp/objdetect.hpp"
+    #include "cpp/surface_matching.hpp"
+    #include "cpp/text.hpp"
 }}
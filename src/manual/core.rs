@@ -1,17 +1,22 @@
 pub use CV_MAKETYPE as CV_MAKE_TYPE;
 
+pub use self::affine3::*;
+pub use self::boxed_ref::*;
 pub use self::input_output_array::*;
+pub use self::keypoint::*;
 pub use self::mat::*;
+pub use self::matx::*;
 pub use self::point::*;
 pub use self::point3::*;
 pub use self::rect::*;
 pub use self::size::*;
 pub use self::vec::*;
+pub use self::vector::*;
 
 macro_rules! valid_types {
-    ($trait: ident, $($rust_type: ty),+) => {
+    ($trait: ident $(: $bound: path)?, $($rust_type: ty),+) => {
         /// This sealed trait is implemented for types that are valid to use in corresponding context
-        pub trait $trait: ::num::traits::NumAssign + PartialOrd + Default + Copy + private::Sealed {}
+        pub trait $trait: ::num::traits::NumAssign + PartialOrd + Default + Copy + $($bound +)? private::Sealed {}
 
         mod private {
             pub trait Sealed {}
@@ -24,13 +29,23 @@ macro_rules! valid_types {
     };
 }
 
+mod affine3;
+mod boxed_ref;
 mod input_output_array;
+mod keypoint;
 mod mat;
+mod matx;
+#[cfg(feature = "rayon")]
+mod par_rows;
 mod point;
 mod point3;
 mod rect;
+mod rng;
+#[cfg(feature = "rand")]
+mod rng_core;
 mod size;
 mod vec;
+mod vector;
 
 #[inline(always)]
 pub const fn CV_MAT_DEPTH(flags: i32) -> i32 {
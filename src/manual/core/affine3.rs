@@ -0,0 +1,134 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::{
+    calib3d,
+    core::{self, DataType, Mat, Matx, ValidMatxType, ValidVecType, Vec3},
+    Result,
+};
+
+/// Affine transform stored as a 4x4 matrix, corresponds to the C++ `cv::Affine3` template
+///
+/// It's mostly used to describe the poses of the cameras and objects, e.g. for the `viz` widgets. The multiplication
+/// of two transforms applies the right one first, the same way as in OpenCV.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Affine3<T: ValidMatxType> {
+    pub matrix: Matx<T, 4, 4>,
+}
+
+impl<T: ValidMatxType + ValidVecType> Affine3<T> {
+    pub fn identity() -> Self {
+        Self { matrix: Matx::eye() }
+    }
+
+    /// Creates the transform from a linear part (usually rotation) and a translation
+    pub fn new(rotation: Matx<T, 3, 3>, translation: Vec3<T>) -> Self {
+        let mut out = Self::identity();
+        out.set_rotation(rotation);
+        out.set_translation(translation);
+        out
+    }
+
+    /// Linear part of the transform, it's the rotation matrix for the rigid transforms
+    pub fn rotation(&self) -> Matx<T, 3, 3> {
+        let mut out = Matx::zeros();
+        for (out_row, row) in out.iter_mut().zip(self.matrix.iter()) {
+            out_row.copy_from_slice(&row[..3]);
+        }
+        out
+    }
+
+    pub fn set_rotation(&mut self, rotation: Matx<T, 3, 3>) {
+        for (row, rot_row) in self.matrix.iter_mut().zip(rotation.iter()) {
+            row[..3].copy_from_slice(rot_row);
+        }
+    }
+
+    pub fn translation(&self) -> Vec3<T> {
+        let mut out = Vec3::all(T::zero());
+        for (x, row) in out.iter_mut().zip(self.matrix.iter()) {
+            *x = row[3];
+        }
+        out
+    }
+
+    pub fn set_translation(&mut self, translation: Vec3<T>) {
+        for (row, &x) in self.matrix.iter_mut().zip(translation.iter()) {
+            row[3] = x;
+        }
+    }
+
+    /// Inverse transform, `None` if the linear part is singular
+    pub fn inv(&self) -> Option<Self> {
+        let rotation = self.rotation().inv()?;
+        let translation = -Matx::from(rotation * self.translation());
+        Some(Self::new(rotation, translation.into()))
+    }
+
+    /// Applies the transform to the point
+    pub fn transform_point(&self, point: Vec3<T>) -> Vec3<T> {
+        (Matx::from(self.rotation() * point) + Matx::from(self.translation())).into()
+    }
+}
+
+impl<T: ValidMatxType + ValidVecType + DataType> Affine3<T> {
+    /// Creates the rigid transform from a rotation vector and a translation vector, e.g. as returned by
+    /// `calib3d::solve_pnp()`, the rotation vector is converted using `calib3d::rodrigues()`
+    pub fn from_rvec_tvec(rvec: Vec3<T>, tvec: Vec3<T>) -> Result<Self> {
+        let mut rotation_mat = Mat::default()?;
+        calib3d::rodrigues(&Matx::from(rvec), &mut rotation_mat, &mut core::no_array()?)?;
+        let mut rotation = Matx::zeros();
+        for (i, row) in rotation.iter_mut().enumerate() {
+            for (j, x) in row.iter_mut().enumerate() {
+                *x = *rotation_mat.at_2d::<T>(i as i32, j as i32)?;
+            }
+        }
+        Ok(Self::new(rotation, tvec))
+    }
+
+    /// Rotation vector of the linear part converted using `calib3d::rodrigues()`
+    pub fn rvec(&self) -> Result<Vec3<T>> {
+        let mut rvec = Mat::default()?;
+        calib3d::rodrigues(&self.rotation(), &mut rvec, &mut core::no_array()?)?;
+        let mut out = Vec3::all(T::zero());
+        for (i, x) in out.iter_mut().enumerate() {
+            *x = *rvec.at::<T>(i as i32)?;
+        }
+        Ok(out)
+    }
+}
+
+impl<T: ValidMatxType + ValidVecType> Default for Affine3<T> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T: ValidMatxType> From<Matx<T, 4, 4>> for Affine3<T> {
+    fn from(matrix: Matx<T, 4, 4>) -> Self {
+        Self { matrix }
+    }
+}
+
+/// Composition of the transforms, `rhs` is applied first
+impl<T: ValidMatxType> Mul for Affine3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self { matrix: self.matrix * rhs.matrix }
+    }
+}
+
+impl<T: ValidMatxType> MulAssign for Affine3<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: ValidMatxType + ValidVecType> Mul<Vec3<T>> for Affine3<T> {
+    type Output = Vec3<T>;
+
+    fn mul(self, rhs: Vec3<T>) -> Self::Output {
+        self.transform_point(rhs)
+    }
+}
@@ -0,0 +1,219 @@
+use std::{
+    fmt,
+    marker::PhantomData,
+    ops::Deref,
+};
+
+use crate::{
+    core::{
+        _InputArray,
+        _InputOutputArray,
+        _OutputArray,
+        DataType,
+        Mat,
+        Point,
+        Range,
+        Rect,
+        Scalar,
+        ToInputArray,
+        ToInputOutputArray,
+        ToOutputArray,
+    },
+    Result,
+    types,
+};
+
+/// Wrapper for the boxed object that shares the data with another object, it ties its lifetime to the borrow
+/// of that object.
+///
+/// It derefs to the wrapped type, but explicitly doesn't allow its mutation. It's returned by the functions
+/// like `Mat::roi()` which create the `Mat` headers pointing to the data of the parent `Mat`.
+pub struct BoxedRef<'r, T> {
+    reference: T,
+    referenced_object: PhantomData<&'r T>,
+}
+
+impl<T> From<T> for BoxedRef<'_, T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self { reference: value, referenced_object: PhantomData }
+    }
+}
+
+impl<T> Deref for BoxedRef<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.reference
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for BoxedRef<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.reference, f)
+    }
+}
+
+impl<T: ToInputArray> ToInputArray for BoxedRef<'_, T> {
+    #[inline]
+    fn input_array(&self) -> Result<_InputArray> {
+        self.reference.input_array()
+    }
+}
+
+impl<T: ToInputArray> ToInputArray for &BoxedRef<'_, T> {
+    #[inline]
+    fn input_array(&self) -> Result<_InputArray> {
+        (*self).input_array()
+    }
+}
+
+/// Mutable version of [BoxedRef], it exclusively borrows the referenced object, so there can be only one
+/// mutable view into a particular part of the data at a time.
+///
+/// It derefs to the wrapped type only immutably. Giving out `&mut T` would allow replacing the wrapped header
+/// (e.g. with `mem::swap()`) or moving it outside of the view (e.g. with `Mat::adjust_roi()`), so the methods that
+/// modify only the data within the view are forwarded instead.
+pub struct BoxedRefMut<'r, T> {
+    reference: T,
+    referenced_object: PhantomData<&'r mut T>,
+}
+
+impl<T> From<T> for BoxedRefMut<'_, T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self { reference: value, referenced_object: PhantomData }
+    }
+}
+
+impl<T> Deref for BoxedRefMut<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.reference
+    }
+}
+
+macro_rules! forward_mut {
+    ($(#[$attr: meta])* $name: ident$(<$gen: ident: $bound: path>)?($($arg: ident: $typ: ty),*) -> $ret: ty) => {
+        $(#[$attr])*
+        #[inline]
+        pub fn $name$(<$gen: $bound>)?(&mut self, $($arg: $typ),*) -> $ret {
+            self.reference.$name($($arg),*)
+        }
+    };
+}
+
+/// Methods of `Mat` that modify the data, but not the header
+impl BoxedRefMut<'_, Mat> {
+    forward_mut!(set(s: Scalar) -> Result<()>);
+    forward_mut!(at_mut<T: DataType>(i0: i32) -> Result<&mut T>);
+    forward_mut!(at_2d_mut<T: DataType>(row: i32, col: i32) -> Result<&mut T>);
+    forward_mut!(at_3d_mut<T: DataType>(i0: i32, i1: i32, i2: i32) -> Result<&mut T>);
+    forward_mut!(at_nd_mut<T: DataType>(idx: &[i32]) -> Result<&mut T>);
+    forward_mut!(at_pt_mut<T: DataType>(pt: Point) -> Result<&mut T>);
+    forward_mut!(at_row_mut<T: DataType>(row: i32) -> Result<&mut [T]>);
+    forward_mut!(row_mut(y: i32) -> Result<BoxedRefMut<'_, Mat>>);
+    forward_mut!(col_mut(x: i32) -> Result<BoxedRefMut<'_, Mat>>);
+    forward_mut!(row_bounds_mut(startrow: i32, endrow: i32) -> Result<BoxedRefMut<'_, Mat>>);
+    forward_mut!(row_range_mut(r: &Range) -> Result<BoxedRefMut<'_, Mat>>);
+    forward_mut!(col_bounds_mut(startcol: i32, endcol: i32) -> Result<BoxedRefMut<'_, Mat>>);
+    forward_mut!(col_range_mut(r: &Range) -> Result<BoxedRefMut<'_, Mat>>);
+    forward_mut!(diag_mut(d: i32) -> Result<BoxedRefMut<'_, Mat>>);
+    forward_mut!(reshape_mut(cn: i32, rows: i32) -> Result<BoxedRefMut<'_, Mat>>);
+    #[cfg(not(feature = "opencv-32"))]
+    forward_mut!(reshape_nd_mut(cn: i32, newshape: &types::VectorOfint) -> Result<BoxedRefMut<'_, Mat>>);
+    forward_mut!(split_rows_mut(row: i32) -> Result<(BoxedRefMut<'_, Mat>, BoxedRefMut<'_, Mat>)>);
+    forward_mut!(split_cols_mut(col: i32) -> Result<(BoxedRefMut<'_, Mat>, BoxedRefMut<'_, Mat>)>);
+    forward_mut!(rois_mut(rois: &[Rect]) -> Result<Vec<BoxedRefMut<'_, Mat>>>);
+
+    /// See `Mat::set_to()`, the returned header is dropped because it would alias the view
+    #[inline]
+    pub fn set_to(&mut self, value: &dyn ToInputArray, mask: &dyn ToInputArray) -> Result<()> {
+        self.reference.set_to(value, mask).map(drop)
+    }
+
+    forward_mut!(data_typed_mut<T: DataType>() -> Result<&mut [T]>);
+
+    /// See `Mat::roi_mut()`
+    #[inline]
+    pub fn roi_mut(&mut self, roi: Rect) -> Result<BoxedRefMut<'_, Mat>> {
+        Mat::roi_mut(&mut self.reference, roi)
+    }
+
+    /// See `Mat::rowscols_mut()`
+    #[inline]
+    pub fn rowscols_mut(&mut self, row_range: &Range, col_range: &Range) -> Result<BoxedRefMut<'_, Mat>> {
+        Mat::rowscols_mut(&mut self.reference, row_range, col_range)
+    }
+
+    /// See `Mat::ranges_mut()`
+    #[inline]
+    pub fn ranges_mut(&mut self, ranges: &types::VectorOfRange) -> Result<BoxedRefMut<'_, Mat>> {
+        Mat::ranges_mut(&mut self.reference, ranges)
+    }
+
+    /// See `Mat::par_rows_mut()`
+    #[cfg(feature = "rayon")]
+    #[inline]
+    pub fn par_rows_mut<'m, T: DataType + Send + 'm>(&'m mut self) -> Result<impl rayon::iter::IndexedParallelIterator<Item=&'m mut [T]>> {
+        self.reference.par_rows_mut()
+    }
+
+    /// See `Mat::par_chunks_mut()`
+    #[cfg(feature = "rayon")]
+    #[inline]
+    pub fn par_chunks_mut<'m, T: DataType + Send + 'm>(&'m mut self, rows_per_chunk: usize) -> Result<impl rayon::iter::IndexedParallelIterator<Item=Vec<&'m mut [T]>>> {
+        self.reference.par_chunks_mut(rows_per_chunk)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for BoxedRefMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.reference, f)
+    }
+}
+
+impl<T: ToInputArray> ToInputArray for BoxedRefMut<'_, T> {
+    #[inline]
+    fn input_array(&self) -> Result<_InputArray> {
+        self.reference.input_array()
+    }
+}
+
+impl<T: ToInputArray> ToInputArray for &BoxedRefMut<'_, T> {
+    #[inline]
+    fn input_array(&self) -> Result<_InputArray> {
+        (*self).input_array()
+    }
+}
+
+impl<T: ToOutputArray> ToOutputArray for BoxedRefMut<'_, T> {
+    #[inline]
+    fn output_array(&mut self) -> Result<_OutputArray> {
+        self.reference.output_array()
+    }
+}
+
+impl<T: ToOutputArray> ToOutputArray for &mut BoxedRefMut<'_, T> {
+    #[inline]
+    fn output_array(&mut self) -> Result<_OutputArray> {
+        (*self).output_array()
+    }
+}
+
+impl<T: ToInputOutputArray> ToInputOutputArray for BoxedRefMut<'_, T> {
+    #[inline]
+    fn input_output_array(&mut self) -> Result<_InputOutputArray> {
+        self.reference.input_output_array()
+    }
+}
+
+impl<T: ToInputOutputArray> ToInputOutputArray for &mut BoxedRefMut<'_, T> {
+    #[inline]
+    fn input_output_array(&mut self) -> Result<_InputOutputArray> {
+        (*self).input_output_array()
+    }
+}
@@ -1,9 +1,24 @@
+use std::ffi::c_void;
+
+use libc::size_t;
+
 use crate::{
-    core::{_InputArray, _InputOutputArray, _InputOutputArrayTrait, _OutputArray},
+    core::{_InputArray, _InputOutputArray, _InputOutputArrayTrait, _OutputArray, DataType},
     Result,
     sys,
 };
 
+impl Drop for _InputArray {
+    fn drop(&mut self) {
+        let ptr = self.ptr;
+        unsafe {
+            cpp!([ptr as "cv::_InputArray*"] {
+                ocvrs::VectorOfVectorInputArray::delete_input_array(ptr);
+            })
+        }
+    }
+}
+
 /// Trait to serve as a replacement for `InputArray` in C++ OpenCV
 ///
 /// You can pass references to the types implementing this trait everywhere where OpenCV API expects
@@ -28,6 +43,34 @@ impl ToInputArray for &f64 {
     }
 }
 
+/// Allows passing e.g. contours as `Vec<Vec<Point>>` to the functions expecting `InputArrayOfArrays`
+///
+/// The data is copied to the `std::vector<std::vector<T>>` owned by the resulting `_InputArray`.
+impl<T: DataType> ToInputArray for Vec<Vec<T>> {
+    #[inline]
+    fn input_array(&self) -> Result<_InputArray> {
+        self.as_slice().input_array()
+    }
+}
+
+/// Same as the implementation for `Vec<Vec<T>>`
+impl<T: DataType> ToInputArray for &[Vec<T>] {
+    fn input_array(&self) -> Result<_InputArray> {
+        let data = self.iter().map(|x| x.as_ptr() as *const c_void).collect::<Vec<_>>();
+        let lens = self.iter().map(|x| x.len()).collect::<Vec<size_t>>();
+        let data = data.as_ptr();
+        let lens = lens.as_ptr();
+        let count = self.len();
+        let typ = T::typ();
+        cpp!(unsafe [typ as "int", data as "const void* const*", lens as "const size_t*", count as "size_t"] -> sys::cv_return_value_const_void_X as "cv_return_value_const_void_X" {
+            try {
+                return { Error::Code::StsOk, NULL, static_cast<cv::_InputArray*>(new ocvrs::VectorOfVectorInputArray(typ, data, lens, count)) };
+            } CVRS_CATCH(cv_return_value_const_void_X)
+        }).into_result()
+            .map(|ptr| _InputArray { ptr })
+    }
+}
+
 /// Trait to serve as a replacement for `OutputArray` in C++ OpenCV
 ///
 /// You can pass reference to the type implementing this trait everywhere where OpenCV API expects
@@ -0,0 +1,72 @@
+use crate::core::Point2f;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+/// [docs.opencv.org](https://docs.opencv.org/master/d2/d29/classcv_1_1KeyPoint.html)
+pub struct KeyPoint {
+    /// coordinates of the keypoint
+    pub pt: Point2f,
+    /// diameter of the meaningful keypoint neighborhood
+    pub size: f32,
+    /// computed orientation of the keypoint (-1 if not applicable), it's in [0,360) degrees and measured
+    /// relative to image coordinate system, ie in clockwise
+    pub angle: f32,
+    /// the response by which the most strong keypoints have been selected, can be used for the further
+    /// sorting or subsampling
+    pub response: f32,
+    /// octave (pyramid layer) from which the keypoint has been extracted
+    pub octave: i32,
+    /// object class (if the keypoints need to be clustered by an object they belong to)
+    pub class_id: i32,
+}
+
+impl KeyPoint {
+    #[inline]
+    pub fn new_point(pt: Point2f, size: f32, angle: f32, response: f32, octave: i32, class_id: i32) -> Self {
+        Self { pt, size, angle, response, octave, class_id }
+    }
+
+    #[inline]
+    pub fn new_coords(x: f32, y: f32, size: f32, angle: f32, response: f32, octave: i32, class_id: i32) -> Self {
+        Self::new_point(Point2f::new(x, y), size, angle, response, octave, class_id)
+    }
+}
+
+impl Default for KeyPoint {
+    #[inline]
+    fn default() -> Self {
+        Self::new_point(Point2f::default(), 0., -1., 0., 0, -1)
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+/// [docs.opencv.org](https://docs.opencv.org/master/d4/de0/classcv_1_1DMatch.html)
+pub struct DMatch {
+    /// query descriptor index
+    pub query_idx: i32,
+    /// train descriptor index
+    pub train_idx: i32,
+    /// train image index
+    pub img_idx: i32,
+    pub distance: f32,
+}
+
+impl DMatch {
+    #[inline]
+    pub fn new(query_idx: i32, train_idx: i32, distance: f32) -> Self {
+        Self::new_index(query_idx, train_idx, -1, distance)
+    }
+
+    #[inline]
+    pub fn new_index(query_idx: i32, train_idx: i32, img_idx: i32, distance: f32) -> Self {
+        Self { query_idx, train_idx, img_idx, distance }
+    }
+}
+
+impl Default for DMatch {
+    #[inline]
+    fn default() -> Self {
+        Self::new(-1, -1, f32::MAX)
+    }
+}
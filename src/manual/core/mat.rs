@@ -12,12 +12,16 @@ use crate::{
         _InputArray,
         _InputOutputArray,
         _OutputArray,
+        BoxedRef,
+        BoxedRefMut,
         Mat,
         MatConstIterator,
         MatExpr,
         MatSize,
         MatStep,
         Point,
+        Range,
+        Rect,
         Scalar,
         ToInputArray,
         ToInputOutputArray,
@@ -27,6 +31,7 @@ use crate::{
     Error,
     Result,
     sys,
+    types,
 };
 
 /// This sealed trait is implemented for types that are valid to use as Mat elements
@@ -112,6 +117,34 @@ data_type!(core::Rect2i, core::CV_32S, 4);
 data_type!(core::Rect2f, core::CV_32F, 4);
 data_type!(core::Rect2d, core::CV_64F, 4);
 
+// matx
+impl<T: core::ValidMatxType + DataType, const M: usize, const N: usize> DataType for core::Matx<T, M, N> {
+    #[inline(always)]
+    fn depth() -> i32 { T::depth() }
+
+    #[inline(always)]
+    fn channels() -> i32 { (M * N) as i32 }
+
+    #[inline(always)]
+    fn typ() -> i32 { core::CV_MAKETYPE(Self::depth(), Self::channels()) }
+}
+
+impl<T: core::ValidMatxType + DataType, const M: usize, const N: usize> private::Sealed for core::Matx<T, M, N> {}
+
+// affine3
+impl<T: core::ValidMatxType + DataType> DataType for core::Affine3<T> {
+    #[inline(always)]
+    fn depth() -> i32 { T::depth() }
+
+    #[inline(always)]
+    fn channels() -> i32 { 16 }
+
+    #[inline(always)]
+    fn typ() -> i32 { core::CV_MAKETYPE(Self::depth(), Self::channels()) }
+}
+
+impl<T: core::ValidMatxType + DataType> private::Sealed for core::Affine3<T> {}
+
 #[inline(always)]
 fn convert_ptr<T>(r: &u8) -> &T {
     unsafe { &*(r as *const _ as *const T) }
@@ -137,11 +170,11 @@ fn match_format<T: DataType>(mat_type: i32) -> Result<()> {
 
 impl Mat {
     #[inline(always)]
-    fn match_format<T: DataType>(&self) -> Result<()> {
+    pub(crate) fn match_format<T: DataType>(&self) -> Result<()> {
         match_format::<T>(self.typ()?)
     }
 
-    fn match_dims(&self, dims: usize) -> Result<()> {
+    pub(crate) fn match_dims(&self, dims: usize) -> Result<()> {
         let mat_dims = self.dims()? as usize;
         if mat_dims == dims {
             Ok(())
@@ -150,6 +183,14 @@ impl Mat {
         }
     }
 
+    fn match_continuous(&self) -> Result<()> {
+        if self.is_continuous()? {
+            Ok(())
+        } else {
+            Err(Error::new(core::StsBadArg, "Data of a non-continuous Mat can't be accessed as a single slice, use at_row() instead".to_string()))
+        }
+    }
+
     fn match_indices(&self, idx: &[i32]) -> Result<()> {
         let size = self.mat_size()?;
         self.match_dims(idx.len())?;
@@ -423,12 +464,17 @@ impl Mat {
             .and_then(|x| unsafe { x.as_ref() }.ok_or_else(|| Error::new(core::StsNullPtr, "Function returned Null pointer".to_string())))
     }
 
+    /// Returns the whole data of a continuous `Mat` as a slice, fails for a non-continuous one (e.g. a view created by
+    /// `Mat::roi()`)
     pub fn data_typed<T: DataType>(&self) -> Result<&[T]> {
         self.match_format::<T>()
             .and_then(|_| unsafe { self.data_typed_unchecked() })
     }
 
+    /// Like `Mat::data_typed()` but performs no type checks, it still fails for a non-continuous `Mat` because its
+    /// data is interleaved with the data outside of it
     pub unsafe fn data_typed_unchecked<T: DataType>(&self) -> Result<&[T]> {
+        self.match_continuous()?;
         let total = self.total()?;
         self.data().map(|x| slice::from_raw_parts(x as *const _ as *const _, total))
     }
@@ -438,7 +484,9 @@ impl Mat {
         unsafe { self.data_typed_mut_unchecked() }
     }
 
+    /// Like `Mat::data_typed_mut()` but performs no type checks, see `Mat::data_typed_unchecked()`
     pub unsafe fn data_typed_mut_unchecked<T: DataType>(&mut self) -> Result<&mut [T]> {
+        self.match_continuous()?;
         let total = self.total()?;
         self.data_mut().map(|x| slice::from_raw_parts_mut(x as *mut _ as *mut _, total))
     }
@@ -476,6 +524,138 @@ impl Mat {
     }
 }
 
+macro_rules! mat_view {
+    ($(#[$attr: meta])* $name: ident, $name_mut: ident, $extern: ident($($arg: ident: $typ: ty => $call: expr),*)) => {
+        $(#[$attr])*
+        pub fn $name(&self, $($arg: $typ),*) -> Result<BoxedRef<'_, Mat>> {
+            unsafe { sys::$extern(self.as_raw_Mat(), $($call),*) }.into_result().map(|ptr| Mat { ptr }.into())
+        }
+
+        /// Mutable version of the method without `_mut` suffix, the returned header borrows the `Mat` mutably
+        pub fn $name_mut(&mut self, $($arg: $typ),*) -> Result<BoxedRefMut<'_, Mat>> {
+            unsafe { sys::$extern(self.as_raw_Mat(), $($call),*) }.into_result().map(|ptr| Mat { ptr }.into())
+        }
+    };
+}
+
+/// Methods creating the headers for a part of the `Mat` data, no data is copied, the returned headers borrow the
+/// parent `Mat` so that it can't be modified or dropped while they are alive
+impl Mat {
+    /// Creates a matrix header for the region of interest `roi` of `m`
+    pub fn roi(m: &Mat, roi: Rect) -> Result<BoxedRef<'_, Mat>> {
+        unsafe { sys::cv_Mat_Mat_Mat_Rect(m.as_raw_Mat(), roi) }.into_result().map(|ptr| Mat { ptr }.into())
+    }
+
+    /// Like `Mat::roi()`, but the returned header borrows `m` mutably and allows modification of the data
+    pub fn roi_mut(m: &mut Mat, roi: Rect) -> Result<BoxedRefMut<'_, Mat>> {
+        unsafe { sys::cv_Mat_Mat_Mat_Rect(m.as_raw_Mat(), roi) }.into_result().map(|ptr| Mat { ptr }.into())
+    }
+
+    /// Creates a matrix header for the intersection of `row_range` rows and `col_range` columns of `m`, use
+    /// `Range::all()` to take all rows or columns
+    pub fn rowscols<'m>(m: &'m Mat, row_range: &Range, col_range: &Range) -> Result<BoxedRef<'m, Mat>> {
+        unsafe { sys::cv_Mat_Mat_Mat_Range_Range(m.as_raw_Mat(), row_range.as_raw_Range(), col_range.as_raw_Range()) }.into_result().map(|ptr| Mat { ptr }.into())
+    }
+
+    /// Like `Mat::rowscols()`, but the returned header borrows `m` mutably and allows modification of the data
+    pub fn rowscols_mut<'m>(m: &'m mut Mat, row_range: &Range, col_range: &Range) -> Result<BoxedRefMut<'m, Mat>> {
+        unsafe { sys::cv_Mat_Mat_Mat_Range_Range(m.as_raw_Mat(), row_range.as_raw_Range(), col_range.as_raw_Range()) }.into_result().map(|ptr| Mat { ptr }.into())
+    }
+
+    /// Creates a matrix header for the selected `ranges` of `m` along each dimension
+    pub fn ranges<'m>(m: &'m Mat, ranges: &types::VectorOfRange) -> Result<BoxedRef<'m, Mat>> {
+        unsafe { sys::cv_Mat_Mat_Mat_VectorOfRange(m.as_raw_Mat(), ranges.as_raw_VectorOfRange()) }.into_result().map(|ptr| Mat { ptr }.into())
+    }
+
+    /// Like `Mat::ranges()`, but the returned header borrows `m` mutably and allows modification of the data
+    pub fn ranges_mut<'m>(m: &'m mut Mat, ranges: &types::VectorOfRange) -> Result<BoxedRefMut<'m, Mat>> {
+        unsafe { sys::cv_Mat_Mat_Mat_VectorOfRange(m.as_raw_Mat(), ranges.as_raw_VectorOfRange()) }.into_result().map(|ptr| Mat { ptr }.into())
+    }
+
+    mat_view!(
+        /// Creates a matrix header for the specified matrix row
+        row, row_mut, cv_Mat_row_const_int(y: i32 => y)
+    );
+
+    mat_view!(
+        /// Creates a matrix header for the specified matrix column
+        col, col_mut, cv_Mat_col_const_int(x: i32 => x)
+    );
+
+    mat_view!(
+        /// Creates a matrix header for the rows from inclusive `startrow` to exclusive `endrow`
+        row_bounds, row_bounds_mut, cv_Mat_rowRange_const_int_int(startrow: i32 => startrow, endrow: i32 => endrow)
+    );
+
+    mat_view!(
+        /// Creates a matrix header for the row span `r`
+        row_range, row_range_mut, cv_Mat_rowRange_const_Range(r: &Range => r.as_raw_Range())
+    );
+
+    mat_view!(
+        /// Creates a matrix header for the columns from inclusive `startcol` to exclusive `endcol`
+        col_bounds, col_bounds_mut, cv_Mat_colRange_const_int_int(startcol: i32 => startcol, endcol: i32 => endcol)
+    );
+
+    mat_view!(
+        /// Creates a matrix header for the column span `r`
+        col_range, col_range_mut, cv_Mat_colRange_const_Range(r: &Range => r.as_raw_Range())
+    );
+
+    mat_view!(
+        /// Creates a single-column matrix header for the diagonal `d`, `d = 0` is the main diagonal, `d < 0` is
+        /// below and `d > 0` is above it
+        diag, diag_mut, cv_Mat_diag_const_int(d: i32 => d)
+    );
+
+    mat_view!(
+        /// Creates a matrix header with a different shape and/or number of channels for the data of a 2D matrix,
+        /// `cn = 0` keeps the number of channels and `rows = 0` keeps the number of rows
+        reshape, reshape_mut, cv_Mat_reshape_const_int_int(cn: i32 => cn, rows: i32 => rows)
+    );
+
+    #[cfg(not(feature = "opencv-32"))]
+    mat_view!(
+        /// Like `Mat::reshape()`, but the new shape is given by the sizes of all dimensions in `newshape`
+        reshape_nd, reshape_nd_mut, cv_Mat_reshape_const_int_VectorOfint(cn: i32 => cn, newshape: &types::VectorOfint => newshape.as_raw_VectorOfint())
+    );
+
+    /// Splits the matrix into two mutable headers, the first one contains rows `0..row` and the second one
+    /// rows `row..rows()`
+    ///
+    /// Because the headers don't overlap they can be modified independently, e.g. from different threads.
+    pub fn split_rows_mut(&mut self, row: i32) -> Result<(BoxedRefMut<'_, Mat>, BoxedRefMut<'_, Mat>)> {
+        let rows = self.rows()?;
+        let me = self.as_raw_Mat();
+        let first = unsafe { sys::cv_Mat_rowRange_const_int_int(me, 0, row) }.into_result().map(|ptr| Mat { ptr })?;
+        let second = unsafe { sys::cv_Mat_rowRange_const_int_int(me, row, rows) }.into_result().map(|ptr| Mat { ptr })?;
+        Ok((first.into(), second.into()))
+    }
+
+    /// Splits the matrix into two mutable headers, the first one contains columns `0..col` and the second one
+    /// columns `col..cols()`, see `Mat::split_rows_mut()`
+    pub fn split_cols_mut(&mut self, col: i32) -> Result<(BoxedRefMut<'_, Mat>, BoxedRefMut<'_, Mat>)> {
+        let cols = self.cols()?;
+        let me = self.as_raw_Mat();
+        let first = unsafe { sys::cv_Mat_colRange_const_int_int(me, 0, col) }.into_result().map(|ptr| Mat { ptr })?;
+        let second = unsafe { sys::cv_Mat_colRange_const_int_int(me, col, cols) }.into_result().map(|ptr| Mat { ptr })?;
+        Ok((first.into(), second.into()))
+    }
+
+    /// Creates mutable headers for several regions of interest at once, fails if any of the `rois` overlap
+    pub fn rois_mut(&mut self, rois: &[Rect]) -> Result<Vec<BoxedRefMut<'_, Mat>>> {
+        for (i, &a) in rois.iter().enumerate() {
+            if let Some(&b) = rois[i + 1..].iter().find(|&&b| !(a & b).empty()) {
+                return Err(Error::new(core::StsBadArg, format!("Regions of interest: {:?} and {:?} overlap", a, b)));
+            }
+        }
+        let me = self.as_raw_Mat();
+        rois.iter()
+            .map(|&roi| unsafe { sys::cv_Mat_Mat_Mat_Rect(me, roi) }.into_result().map(|ptr| Mat { ptr }.into()))
+            .collect()
+    }
+}
+
 impl ToInputArray for Mat {
     #[inline]
     fn input_array(&self) -> Result<_InputArray> {
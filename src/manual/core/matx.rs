@@ -0,0 +1,346 @@
+use std::{
+    cmp::Ordering,
+    ffi::c_void,
+    ops::{Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{
+    core::{_InputArray, DataType, ToInputArray, Vec2, Vec3, Vec4, Vec6},
+    Result,
+    sys,
+};
+
+pub use self::matx::ValidMatxType;
+
+// additional module needed because valid_types! introduces module named "private"
+mod matx {
+    valid_types!(ValidMatxType: ::num::traits::Float, f32, f64);
+}
+
+/// Fixed-size matrix of `M` rows and `N` columns stored by rows, corresponds to the C++ `cv::Matx` template
+///
+/// It's passed to OpenCV by value and doesn't allocate, so it's a cheaper alternative to `Mat` for the small
+/// matrices like camera intrinsics or poses.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Matx<T: ValidMatxType, const M: usize, const N: usize>(pub [[T; N]; M]);
+
+impl<T: ValidMatxType, const M: usize, const N: usize> Matx<T, M, N> {
+    pub const ROWS: usize = M;
+    pub const COLS: usize = N;
+
+    pub fn all(v0: T) -> Self {
+        Self([[v0; N]; M])
+    }
+
+    pub fn zeros() -> Self {
+        Self::all(T::zero())
+    }
+
+    pub fn ones() -> Self {
+        Self::all(T::one())
+    }
+
+    /// Matrix with ones on the main diagonal and zeros elsewhere
+    pub fn eye() -> Self {
+        let mut out = Self::zeros();
+        for i in 0..M.min(N) {
+            out.0[i][i] = T::one();
+        }
+        out
+    }
+
+    pub fn transpose(&self) -> Matx<T, N, M> {
+        let mut out = Matx::<T, N, M>::zeros();
+        for (i, row) in self.0.iter().enumerate() {
+            for (j, &x) in row.iter().enumerate() {
+                out.0[j][i] = x;
+            }
+        }
+        out
+    }
+
+    /// Element-wise multiplication
+    pub fn mul_elem(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a * b)
+    }
+
+    /// Element-wise division
+    pub fn div_elem(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a / b)
+    }
+
+    /// Sum of the element-wise products
+    pub fn dot(&self, other: &Self) -> T {
+        self.iter().flatten()
+            .zip(other.iter().flatten())
+            .fold(T::zero(), |acc, (&a, &b)| acc + a * b)
+    }
+
+    fn map_elem(&self, f: impl Fn(T) -> T) -> Self {
+        let mut out = *self;
+        out.iter_mut().flatten().for_each(|x| *x = f(*x));
+        out
+    }
+
+    fn zip_with(&self, other: &Self, f: impl Fn(T, T) -> T) -> Self {
+        let mut out = *self;
+        out.iter_mut().flatten()
+            .zip(other.iter().flatten())
+            .for_each(|(x, &y)| *x = f(*x, y));
+        out
+    }
+}
+
+impl<T: ValidMatxType, const M: usize> Matx<T, M, M> {
+    /// Matrix with `d` on the main diagonal and zeros elsewhere
+    pub fn diag(d: [T; M]) -> Self {
+        let mut out = Self::zeros();
+        for (i, &x) in d.iter().enumerate() {
+            out.0[i][i] = x;
+        }
+        out
+    }
+
+    pub fn trace(&self) -> T {
+        (0..M).fold(T::zero(), |acc, i| acc + self.0[i][i])
+    }
+
+    /// Determinant calculated using LU decomposition with partial pivoting, zero if the matrix is singular within
+    /// the tolerance described in `Matx::inv()`
+    pub fn determinant(&self) -> T {
+        let mut a = self.0;
+        let tolerance = singular_tolerance(&a);
+        let mut out = T::one();
+        for col in 0..M {
+            let pivot = match pivot_row(&a, col, tolerance) {
+                Some(pivot) => pivot,
+                None => return T::zero(),
+            };
+            if pivot != col {
+                a.swap(pivot, col);
+                out = T::zero() - out;
+            }
+            out *= a[col][col];
+            for row in col + 1..M {
+                let factor = a[row][col] / a[col][col];
+                for k in col..M {
+                    let v = a[col][k];
+                    a[row][k] -= factor * v;
+                }
+            }
+        }
+        out
+    }
+
+    /// Inverse matrix calculated using Gauss-Jordan elimination with partial pivoting, `None` if the matrix is
+    /// singular
+    ///
+    /// Like with `DECOMP_LU` in OpenCV the matrix is considered singular when a pivot is negligible, here it's when
+    /// it's not larger than `M * T::epsilon()` times the largest absolute element of the matrix.
+    pub fn inv(&self) -> Option<Self> {
+        let mut a = self.0;
+        let tolerance = singular_tolerance(&a);
+        let mut out = Self::eye().0;
+        for col in 0..M {
+            let pivot = pivot_row(&a, col, tolerance)?;
+            a.swap(pivot, col);
+            out.swap(pivot, col);
+            let p = a[col][col];
+            for k in 0..M {
+                a[col][k] /= p;
+                out[col][k] /= p;
+            }
+            for row in (0..M).filter(|&row| row != col) {
+                let factor = a[row][col];
+                if factor != T::zero() {
+                    for k in 0..M {
+                        let (v, v_out) = (a[col][k], out[col][k]);
+                        a[row][k] -= factor * v;
+                        out[row][k] -= factor * v_out;
+                    }
+                }
+            }
+        }
+        Some(Self(out))
+    }
+}
+
+/// Pivots with the absolute value up to the returned one are treated as zero
+fn singular_tolerance<T: ValidMatxType, const M: usize>(a: &[[T; M]; M]) -> T {
+    let max = a.iter().flatten().fold(T::zero(), |acc, x| acc.max(x.abs()));
+    max * T::from(M).unwrap_or_else(T::one) * T::epsilon()
+}
+
+/// Returns the row at or below `col` with the largest absolute value in column `col`, `None` if that value is not
+/// larger than `tolerance`
+fn pivot_row<T: ValidMatxType, const M: usize>(a: &[[T; M]; M], col: usize, tolerance: T) -> Option<usize> {
+    (col..M)
+        .max_by(|&x, &y| a[x][col].abs().partial_cmp(&a[y][col].abs()).unwrap_or(Ordering::Equal))
+        .filter(|&row| a[row][col].abs() > tolerance)
+}
+
+impl<T: ValidMatxType, const M: usize, const N: usize> Default for Matx<T, M, N> {
+    fn default() -> Self {
+        Self::zeros()
+    }
+}
+
+impl<T: ValidMatxType, const M: usize, const N: usize> From<[[T; N]; M]> for Matx<T, M, N> {
+    fn from(s: [[T; N]; M]) -> Self {
+        Self(s)
+    }
+}
+
+impl<T: ValidMatxType, const M: usize, const N: usize> Deref for Matx<T, M, N> {
+    type Target = [[T; N]; M];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: ValidMatxType, const M: usize, const N: usize> DerefMut for Matx<T, M, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: ValidMatxType, const M: usize, const N: usize> Add for Matx<T, M, N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip_with(&rhs, |a, b| a + b)
+    }
+}
+
+impl<T: ValidMatxType, const M: usize, const N: usize> AddAssign for Matx<T, M, N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: ValidMatxType, const M: usize, const N: usize> Sub for Matx<T, M, N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip_with(&rhs, |a, b| a - b)
+    }
+}
+
+impl<T: ValidMatxType, const M: usize, const N: usize> SubAssign for Matx<T, M, N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: ValidMatxType, const M: usize, const N: usize> Neg for Matx<T, M, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.map_elem(|x| T::zero() - x)
+    }
+}
+
+impl<T: ValidMatxType, const M: usize, const N: usize> Mul<T> for Matx<T, M, N> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map_elem(|x| x * rhs)
+    }
+}
+
+impl<T: ValidMatxType, const M: usize, const N: usize> MulAssign<T> for Matx<T, M, N> {
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: ValidMatxType, const M: usize, const N: usize> Div<T> for Matx<T, M, N> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        self.map_elem(|x| x / rhs)
+    }
+}
+
+impl<T: ValidMatxType, const M: usize, const N: usize> DivAssign<T> for Matx<T, M, N> {
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
+    }
+}
+
+/// Matrix multiplication
+impl<T: ValidMatxType, const M: usize, const N: usize, const P: usize> Mul<Matx<T, N, P>> for Matx<T, M, N> {
+    type Output = Matx<T, M, P>;
+
+    fn mul(self, rhs: Matx<T, N, P>) -> Self::Output {
+        let mut out = Matx::<T, M, P>::zeros();
+        for i in 0..M {
+            for j in 0..P {
+                out.0[i][j] = (0..N).fold(T::zero(), |acc, k| acc + self.0[i][k] * rhs.0[k][j]);
+            }
+        }
+        out
+    }
+}
+
+macro_rules! matx_vec_impl {
+    ($vec: ident, $count: expr) => {
+        impl<T: ValidMatxType + crate::core::ValidVecType> From<$vec<T>> for Matx<T, $count, 1> {
+            fn from(s: $vec<T>) -> Self {
+                let mut out = Self::zeros();
+                for (row, &x) in out.iter_mut().zip(s.iter()) {
+                    row[0] = x;
+                }
+                out
+            }
+        }
+
+        impl<T: ValidMatxType + crate::core::ValidVecType> From<Matx<T, $count, 1>> for $vec<T> {
+            fn from(s: Matx<T, $count, 1>) -> Self {
+                let mut out = Self::all(T::zero());
+                for (x, row) in out.iter_mut().zip(s.iter()) {
+                    *x = row[0];
+                }
+                out
+            }
+        }
+
+        /// Multiplication by a column vector
+        impl<T: ValidMatxType + crate::core::ValidVecType> Mul<$vec<T>> for Matx<T, $count, $count> {
+            type Output = $vec<T>;
+
+            fn mul(self, rhs: $vec<T>) -> Self::Output {
+                (self * Matx::from(rhs)).into()
+            }
+        }
+    };
+}
+
+matx_vec_impl!(Vec2, 2);
+matx_vec_impl!(Vec3, 3);
+matx_vec_impl!(Vec4, 4);
+matx_vec_impl!(Vec6, 6);
+
+impl<T: ValidMatxType + DataType, const M: usize, const N: usize> ToInputArray for Matx<T, M, N> {
+    fn input_array(&self) -> Result<_InputArray> {
+        let me = self as *const _ as *const c_void;
+        let typ = T::typ();
+        let rows = M as i32;
+        let cols = N as i32;
+        cpp!(unsafe [me as "const void*", typ as "int", rows as "int", cols as "int"] -> sys::cv_return_value_const_void_X as "cv_return_value_void_X" {
+            try {
+                return { Error::Code::StsOk, NULL, new _InputArray(ocvrs::MatxInputArray(typ, me, cv::Size(cols, rows))) };
+            } CVRS_CATCH(cv_return_value_void_X)
+        }).into_result()
+            .map(|ptr| _InputArray { ptr })
+    }
+}
+
+impl<T: ValidMatxType + DataType, const M: usize, const N: usize> ToInputArray for &Matx<T, M, N> {
+    #[inline]
+    fn input_array(&self) -> Result<_InputArray> {
+        (*self).input_array()
+    }
+}
@@ -0,0 +1,42 @@
+use std::slice;
+
+use rayon::prelude::*;
+
+use crate::{
+    core::{self, DataType, Mat},
+    Error,
+    Result,
+};
+
+impl Mat {
+    /// Collects the mutable slices of all rows, they don't overlap so they can be processed independently
+    fn rows_mut<T: DataType>(&mut self) -> Result<Vec<&mut [T]>> {
+        self.match_format::<T>()?;
+        self.match_dims(2)?;
+        let width = self.cols()? as usize;
+        (0..self.rows()?)
+            .map(|row| {
+                let ptr = unsafe { self.ptr_mut(row) }? as *mut u8 as *mut T;
+                Ok(unsafe { slice::from_raw_parts_mut(ptr, width) })
+            })
+            .collect()
+    }
+
+    /// Returns a parallel iterator over the writeable rows of a 2-dimensional `Mat`
+    ///
+    /// Works for both continuous and non-continuous (e.g. `Mat::roi_mut()`) matrices.
+    pub fn par_rows_mut<'m, T: DataType + Send + 'm>(&'m mut self) -> Result<impl IndexedParallelIterator<Item=&'m mut [T]>> {
+        self.rows_mut().map(|rows| rows.into_par_iter())
+    }
+
+    /// Returns a parallel iterator over the groups of `rows_per_chunk` writeable rows of a 2-dimensional `Mat`,
+    /// the last group can contain less rows
+    ///
+    /// Use it instead of `Mat::par_rows_mut()` when processing a single row is too cheap to be worth a separate task.
+    pub fn par_chunks_mut<'m, T: DataType + Send + 'm>(&'m mut self, rows_per_chunk: usize) -> Result<impl IndexedParallelIterator<Item=Vec<&'m mut [T]>>> {
+        if rows_per_chunk == 0 {
+            return Err(Error::new(core::StsBadArg, "Number of rows per chunk must be positive".to_string()));
+        }
+        self.rows_mut().map(|rows| rows.into_par_iter().chunks(rows_per_chunk))
+    }
+}
@@ -0,0 +1,19 @@
+use crate::core::RNG;
+
+impl RNG {
+    /// Current 64-bit state of the generator, it can be used to restore the random stream later with
+    /// `RNG::set_state()` or `RNG::new()`
+    pub fn state(&self) -> u64 {
+        let me = self.as_raw_RNG();
+        cpp!(unsafe [me as "const cv::RNG*"] -> u64 as "uint64_t" {
+            return me->state;
+        })
+    }
+
+    pub fn set_state(&mut self, state: u64) {
+        let me = self.as_raw_RNG();
+        cpp!(unsafe [me as "cv::RNG*", state as "uint64_t"] {
+            me->state = state;
+        })
+    }
+}
@@ -0,0 +1,39 @@
+use rand_core::{Error, impls, RngCore};
+
+use crate::core::{RNG, RNG_MT19937};
+
+macro_rules! rng_core_impl {
+    ($type: ty) => {
+        /// Allows using the OpenCV generator with the `rand` crate ecosystem, so both OpenCV and Rust code can
+        /// draw from the same seeded stream
+        ///
+        /// `RngCore` has no way to report errors from the infallible methods, so `next_u32()`, `next_u64()` and
+        /// `fill_bytes()` panic if the OpenCV call fails, use `try_fill_bytes()` to get that failure as an error.
+        impl RngCore for $type {
+            /// # Panics
+            /// If OpenCV fails to generate the number, see `try_fill_bytes()` for the fallible alternative
+            fn next_u32(&mut self) -> u32 {
+                self.next().expect("Can't get the next random number")
+            }
+
+            fn next_u64(&mut self) -> u64 {
+                impls::next_u64_via_u32(self)
+            }
+
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                impls::fill_bytes_via_next(self, dest)
+            }
+
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+                for chunk in dest.chunks_mut(4) {
+                    let next = self.next().map_err(Error::new)?.to_le_bytes();
+                    chunk.copy_from_slice(&next[..chunk.len()]);
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+rng_core_impl!(RNG);
+rng_core_impl!(RNG_MT19937);
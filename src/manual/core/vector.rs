@@ -0,0 +1,660 @@
+use std::{
+    ffi::c_void,
+    fmt,
+    iter::{FromIterator, FusedIterator},
+    marker::PhantomData,
+    mem::ManuallyDrop,
+    ops::Deref,
+    ptr,
+    slice,
+};
+
+use libc::size_t;
+
+use crate::{
+    core,
+    Error,
+    Result,
+};
+
+/// Wrapper for C++ `std::vector`
+///
+/// The element type must implement `VectorElement`, it's done by the generated code for every type
+/// that has a `std::vector` instantiation in OpenCV API. The older `types::VectorOf*` names are kept
+/// as type aliases, e.g. `types::VectorOfPoint2f` is `Vector<Point2f>`.
+///
+/// Vectors of POD element types (primitives, `Point`, `Rect`, `Vec4i`, `KeyPoint`, ...) implement
+/// `VectorElementPod` and can be additionally viewed as Rust slices and converted from and to `Vec` with
+/// a single copy.
+pub struct Vector<T: VectorElement> {
+    ptr: *mut c_void,
+    _d: PhantomData<T>,
+}
+
+impl<T: VectorElement> Vector<T> {
+    /// Create a Vector from the raw pointer to C++ `std::vector` of the matching type, takes ownership
+    #[inline]
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr, _d: PhantomData }
+    }
+
+    #[inline(always)]
+    #[allow(non_snake_case)]
+    pub fn as_raw_Vector(&self) -> *mut c_void {
+        self.ptr
+    }
+
+    /// Create a Vector from iterator, same as `std::iter::FromIterator::from_iter()` but doesn't
+    /// require the trait import
+    #[inline]
+    pub fn from_iter<'i>(s: impl IntoIterator<Item=<T as VectorExtern<'i>>::Arg>) -> Self {
+        <Self as FromIterator<_>>::from_iter(s)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> VectorRefIterator<'_, T> {
+        VectorRefIterator::new(self)
+    }
+}
+
+impl<T: VectorElementPod> Vector<T> {
+    /// Create a new Vector copying the contents of the slice
+    #[inline]
+    pub fn from_slice(s: &[T]) -> Self {
+        unsafe { Self::from_raw_ptr(T::extern_from_slice(s.as_ptr(), s.len())) }
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        let len = self.len();
+        if len == 0 {
+            return &[];
+        }
+        unsafe { slice::from_raw_parts(T::extern_data(self.ptr), len) }
+    }
+
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let len = self.len();
+        if len == 0 {
+            return &mut [];
+        }
+        unsafe { slice::from_raw_parts_mut(T::extern_data_mut(self.ptr), len) }
+    }
+
+    /// Same as `as_slice()`
+    #[deprecated = "Use as_slice()"]
+    #[inline]
+    pub fn to_slice(&self) -> &[T] {
+        self.as_slice()
+    }
+
+    /// Append the contents of the slice to the end of the Vector
+    #[inline]
+    pub fn extend_from_slice(&mut self, s: &[T]) {
+        unsafe { T::extern_extend_from_slice(self.ptr, s.as_ptr(), s.len()) }
+    }
+}
+
+impl<T: VectorElement> Vector<Vector<T>> where Vector<T>: VectorElementRef {
+    /// Borrow the inner Vector at the specified `index` without copying it
+    #[inline]
+    pub fn get_ref(&self, index: size_t) -> Result<VectorRef<'_, T>> {
+        index_check(index, self.len())?;
+        Ok(unsafe { self.get_ref_unchecked(index) })
+    }
+
+    /// Same as `get_ref()` but without bounds checking
+    #[inline]
+    pub unsafe fn get_ref_unchecked(&self, index: size_t) -> VectorRef<'_, T> {
+        VectorRef::new(<Vector<T>>::extern_get_ref(self.ptr, index))
+    }
+
+    /// Iterator over borrowed inner Vectors
+    #[inline]
+    pub fn iter_ref(&self) -> impl ExactSizeIterator<Item=VectorRef<'_, T>> + '_ {
+        (0..self.len()).map(move |i| unsafe { self.get_ref_unchecked(i) })
+    }
+}
+
+impl<T: VectorElementPod> Vector<Vector<T>> where Vector<T>: VectorElementRef {
+    /// Create a Vector of Vectors copying the contents of the slices
+    pub fn from_slices<S: AsRef<[T]>>(s: &[S]) -> Self {
+        let mut out = Self::with_capacity(s.len());
+        s.iter().for_each(|x| out.push(Vector::from_slice(x.as_ref())));
+        out
+    }
+
+    /// Convert to Rust `Vec` of `Vec`s, inner Vectors are copied directly to the resulting `Vec`s
+    #[inline]
+    pub fn to_vec_of_vec(&self) -> Vec<Vec<T>> {
+        self.iter_ref().map(|v| v.as_slice().to_vec()).collect()
+    }
+}
+
+impl<T: VectorElementPod> From<&[Vec<T>]> for Vector<Vector<T>> where Vector<T>: VectorElementRef {
+    #[inline]
+    fn from(s: &[Vec<T>]) -> Self {
+        Self::from_slices(s)
+    }
+}
+
+impl<T: VectorElementPod> From<Vec<Vec<T>>> for Vector<Vector<T>> where Vector<T>: VectorElementRef {
+    #[inline]
+    fn from(s: Vec<Vec<T>>) -> Self {
+        Self::from_slices(&s)
+    }
+}
+
+impl<T: VectorElementPod> From<Vector<Vector<T>>> for Vec<Vec<T>> where Vector<T>: VectorElementRef {
+    #[inline]
+    fn from(s: Vector<Vector<T>>) -> Self {
+        s.to_vec_of_vec()
+    }
+}
+
+impl<T: VectorElement> Drop for Vector<T> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { T::extern_delete(self.ptr) }
+    }
+}
+
+unsafe impl<T: VectorElement + Send> Send for Vector<T> {}
+
+impl<T: VectorElement> Default for Vector<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: VectorElement> IntoIterator for Vector<T> {
+    type Item = T;
+    type IntoIter = VectorIterator<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        VectorIterator::new(self)
+    }
+}
+
+impl<'v, T: VectorElement> IntoIterator for &'v Vector<T> {
+    type Item = T;
+    type IntoIter = VectorRefIterator<'v, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'i, T: VectorElement> FromIterator<<T as VectorExtern<'i>>::Arg> for Vector<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item=<T as VectorExtern<'i>>::Arg>>(s: I) -> Self {
+        let s = s.into_iter();
+        let mut out = Self::with_capacity(s.size_hint().0);
+        s.for_each(|x| out.push(x));
+        out
+    }
+}
+
+impl<T: VectorElementPod> Extend<T> for Vector<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item=T>>(&mut self, s: I) {
+        let s = s.into_iter();
+        self.reserve(s.size_hint().0);
+        s.for_each(|x| self.push(x));
+    }
+}
+
+impl<'a, T: VectorElementPod> Extend<&'a T> for Vector<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item=&'a T>>(&mut self, s: I) {
+        self.extend(s.into_iter().copied())
+    }
+}
+
+impl<T: VectorElementPod> From<Vec<T>> for Vector<T> {
+    #[inline]
+    fn from(s: Vec<T>) -> Self {
+        Self::from_slice(&s)
+    }
+}
+
+impl<T: VectorElementPod> From<&[T]> for Vector<T> {
+    #[inline]
+    fn from(s: &[T]) -> Self {
+        Self::from_slice(s)
+    }
+}
+
+impl<T: VectorElementPod> From<Vector<T>> for Vec<T> {
+    #[inline]
+    fn from(s: Vector<T>) -> Self {
+        s.as_slice().to_vec()
+    }
+}
+
+impl<T: VectorElementPod> AsRef<[T]> for Vector<T> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: VectorElementPod> AsMut<[T]> for Vector<T> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: VectorElementPod> Clone for Vector<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::from_slice(self.as_slice())
+    }
+}
+
+impl<T: VectorElementPod + PartialEq> PartialEq for Vector<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: VectorElementPod + fmt::Debug> fmt::Debug for Vector<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+/// Element type of the `Vector`
+///
+/// Implemented by the generated code, `extern_*` functions are the glue to the corresponding C++
+/// `std::vector` instantiation and are not meant to be called directly.
+pub trait VectorElement: for<'i> VectorExtern<'i> + Sized {
+    #[doc(hidden)] fn extern_new() -> *mut c_void;
+    #[doc(hidden)] unsafe fn extern_delete(vec: *mut c_void);
+    #[doc(hidden)] unsafe fn extern_len(vec: *const c_void) -> size_t;
+    #[doc(hidden)] unsafe fn extern_is_empty(vec: *const c_void) -> bool;
+    #[doc(hidden)] unsafe fn extern_capacity(vec: *const c_void) -> size_t;
+    #[doc(hidden)] unsafe fn extern_shrink_to_fit(vec: *mut c_void);
+    #[doc(hidden)] unsafe fn extern_reserve(vec: *mut c_void, additional: size_t);
+    #[doc(hidden)] unsafe fn extern_remove(vec: *mut c_void, index: size_t);
+    #[doc(hidden)] unsafe fn extern_swap(vec: *mut c_void, index1: size_t, index2: size_t);
+    #[doc(hidden)] unsafe fn extern_clear(vec: *mut c_void);
+    #[doc(hidden)] unsafe fn extern_get(vec: *const c_void, index: size_t) -> Self;
+
+    /// Copy the whole Vector in one go, `None` if the element type doesn't support that
+    #[doc(hidden)]
+    #[inline]
+    fn convert_to_vec(_v: &Vector<Self>) -> Option<Vec<Self>> {
+        None
+    }
+
+    /// View the whole Vector as a slice, `Some` only for `VectorElementPod` types, it lets the iterators read the
+    /// elements directly instead of calling `extern_get()` for each of them
+    #[doc(hidden)]
+    #[inline]
+    fn as_pod_slice(_v: &Vector<Self>) -> Option<&[Self]> {
+        None
+    }
+}
+
+/// Part of `VectorElement` that depends on the type of the argument accepted when adding elements
+///
+/// It's the element type itself for most vectors, but e.g. `&str` for `Vector<String>`.
+pub trait VectorExtern<'i> {
+    type Arg;
+    #[doc(hidden)] unsafe fn extern_push(vec: *mut c_void, val: Self::Arg);
+    #[doc(hidden)] unsafe fn extern_insert(vec: *mut c_void, index: size_t, val: Self::Arg);
+    #[doc(hidden)] unsafe fn extern_set(vec: *mut c_void, index: size_t, val: Self::Arg);
+}
+
+/// `VectorElement` with the same memory layout in Rust and C++ that can be accessed directly
+///
+/// Unsafe because the implementor guarantees that the contiguous storage of the C++ `std::vector` can
+/// be viewed as a Rust slice of this type.
+pub unsafe trait VectorElementPod: VectorElement + for<'i> VectorExtern<'i, Arg=Self> + Copy {
+    #[doc(hidden)] unsafe fn extern_data(vec: *const c_void) -> *const Self;
+    #[doc(hidden)] unsafe fn extern_data_mut(vec: *mut c_void) -> *mut Self;
+    #[doc(hidden)] unsafe fn extern_from_slice(data: *const Self, len: size_t) -> *mut c_void;
+    #[doc(hidden)] unsafe fn extern_extend_from_slice(vec: *mut c_void, data: *const Self, len: size_t);
+}
+
+/// `VectorElement` that can be borrowed in place from the containing C++ `std::vector`
+///
+/// Implemented by the generated code for `Vector`s that are themselves elements of a `Vector`.
+pub trait VectorElementRef: VectorElement + for<'i> VectorExtern<'i, Arg=Self> {
+    #[doc(hidden)] unsafe fn extern_get_ref(vec: *const c_void, index: size_t) -> *mut c_void;
+}
+
+/// `Vector` borrowed from the containing `Vector<Vector<T>>`, see `Vector::get_ref()`
+pub struct VectorRef<'v, T: VectorElement> {
+    inner: ManuallyDrop<Vector<T>>,
+    owner: PhantomData<&'v Vector<T>>,
+}
+
+impl<T: VectorElement> VectorRef<'_, T> {
+    #[inline]
+    unsafe fn new(ptr: *mut c_void) -> Self {
+        Self { inner: ManuallyDrop::new(Vector::from_raw_ptr(ptr)), owner: PhantomData }
+    }
+}
+
+impl<'v, T: VectorElementPod> VectorRef<'v, T> {
+    /// Same as `Vector::as_slice()`, but the slice borrows from the containing `Vector` instead of `self`
+    #[inline]
+    pub fn as_slice(&self) -> &'v [T] {
+        let len = self.inner.len();
+        if len == 0 {
+            return &[];
+        }
+        unsafe { slice::from_raw_parts(T::extern_data(self.inner.ptr), len) }
+    }
+}
+
+impl<T: VectorElement> Deref for VectorRef<'_, T> {
+    type Target = Vector<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T: VectorElementPod + fmt::Debug> fmt::Debug for VectorRef<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+/// Common interface for all C++ vector types
+///
+/// You'll need to import this trait to use any of the vector methods, usually imported as part of
+/// the prelude. It's implemented for every `Vector<T>` so generic code only needs the
+/// `T: VectorElement` bound.
+pub trait VectorTrait<'i> {
+    type Storage;
+    type Arg;
+
+    /// Create a new Vector
+    fn new() -> Self where Self: Sized;
+
+    /// Create a Vector from iterator
+    #[deprecated = "Use std::iter::FromIterator::from_iter() or Iterator::collect()"]
+    #[inline]
+    fn from_iter(s: impl IntoIterator<Item=Self::Arg>) -> Self where Self: Sized {
+        let s = s.into_iter();
+        let mut out = Self::with_capacity(s.size_hint().0);
+        s.for_each(|x| out.push(x));
+        out
+    }
+
+    /// Create a Vector with pre-defined capacity
+    #[inline]
+    fn with_capacity(capacity: size_t) -> Self where Self: Sized {
+        let mut out = Self::new();
+        out.reserve(capacity);
+        out
+    }
+
+    /// Return Vector length
+    fn len(&self) -> size_t;
+
+    /// Return true if Vector is empty
+    fn is_empty(&self) -> bool;
+
+    /// Return Vector current capacity
+    fn capacity(&self) -> size_t;
+
+    /// Free extra capacity
+    fn shrink_to_fit(&mut self);
+
+    /// Reserve capacity for `additional` new elements
+    fn reserve(&mut self, additional: size_t);
+
+    /// Remove all elements
+    fn clear(&mut self);
+
+    /// Add new element
+    fn push(&mut self, val: Self::Arg);
+
+    /// Insert a new element at the specified `index`
+    fn insert(&mut self, index: size_t, val: Self::Arg) -> Result<()>;
+
+    /// Remove the element at the specified `index`
+    fn remove(&mut self, index: size_t) -> Result<()>;
+
+    /// Swaps 2 elements in the Vector
+    fn swap(&mut self, index1: size_t, index2: size_t) -> Result<()>;
+
+    /// Get element at the specified `index`
+    fn get(&self, index: size_t) -> Result<Self::Storage>;
+
+    /// Same as `get()` but without bounds checking
+    unsafe fn get_unchecked(&self, index: size_t) -> Self::Storage;
+
+    /// Set element at the specified `index`
+    fn set(&mut self, index: size_t, val: Self::Arg) -> Result<()>;
+
+    /// Same as `set()` but without bounds checking
+    unsafe fn set_unchecked(&mut self, index: size_t, val: Self::Arg);
+
+    /// Convert to Rust `Vec`
+    fn to_vec(&self) -> Vec<Self::Storage>;
+}
+
+#[inline(always)]
+fn index_check(index: size_t, len: size_t) -> Result<()> {
+    if index >= len {
+        Err(Error::new(core::StsOutOfRange, format!("Index: {} out of bounds: 0..{}", index, len)))
+    } else {
+        Ok(())
+    }
+}
+
+impl<'i, T: VectorElement> VectorTrait<'i> for Vector<T> {
+    type Storage = T;
+    type Arg = <T as VectorExtern<'i>>::Arg;
+
+    #[inline]
+    fn new() -> Self {
+        unsafe { Self::from_raw_ptr(T::extern_new()) }
+    }
+
+    #[inline]
+    fn len(&self) -> size_t {
+        unsafe { T::extern_len(self.ptr) }
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        unsafe { T::extern_is_empty(self.ptr) }
+    }
+
+    #[inline]
+    fn capacity(&self) -> size_t {
+        unsafe { T::extern_capacity(self.ptr) }
+    }
+
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+        unsafe { T::extern_shrink_to_fit(self.ptr) }
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional: size_t) {
+        unsafe { T::extern_reserve(self.ptr, additional) }
+    }
+
+    #[inline]
+    fn clear(&mut self) {
+        unsafe { T::extern_clear(self.ptr) }
+    }
+
+    #[inline]
+    fn push(&mut self, val: Self::Arg) {
+        unsafe { T::extern_push(self.ptr, val) }
+    }
+
+    #[inline]
+    fn insert(&mut self, index: size_t, val: Self::Arg) -> Result<()> {
+        index_check(index, self.len() + 1)?;
+        unsafe { T::extern_insert(self.ptr, index, val) };
+        Ok(())
+    }
+
+    #[inline]
+    fn remove(&mut self, index: size_t) -> Result<()> {
+        index_check(index, self.len())?;
+        unsafe { T::extern_remove(self.ptr, index) };
+        Ok(())
+    }
+
+    #[inline]
+    fn swap(&mut self, index1: size_t, index2: size_t) -> Result<()> {
+        let len = self.len();
+        index_check(index1, len)?;
+        index_check(index2, len)?;
+        if index1 != index2 {
+            unsafe { T::extern_swap(self.ptr, index1, index2) };
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn get(&self, index: size_t) -> Result<T> {
+        index_check(index, self.len())?;
+        Ok(unsafe { self.get_unchecked(index) })
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: size_t) -> T {
+        T::extern_get(self.ptr, index)
+    }
+
+    #[inline]
+    fn set(&mut self, index: size_t, val: Self::Arg) -> Result<()> {
+        index_check(index, self.len())?;
+        unsafe { self.set_unchecked(index, val) };
+        Ok(())
+    }
+
+    #[inline]
+    unsafe fn set_unchecked(&mut self, index: size_t, val: Self::Arg) {
+        T::extern_set(self.ptr, index, val)
+    }
+
+    #[inline]
+    fn to_vec(&self) -> Vec<T> {
+        T::convert_to_vec(self).unwrap_or_else(|| self.iter().collect())
+    }
+}
+
+pub struct VectorIterator<T: VectorElement> {
+    vec: Vector<T>,
+    /// data of the POD Vector, it's not modified while the iterator owns it
+    data: Option<*const T>,
+    i: size_t,
+    len: size_t,
+}
+
+impl<T: VectorElement> VectorIterator<T> {
+    pub fn new(vec: Vector<T>) -> Self {
+        let len = vec.len();
+        let data = T::as_pod_slice(&vec).map(<[T]>::as_ptr);
+        Self { vec, data, i: 0, len }
+    }
+}
+
+unsafe impl<T: VectorElement + Send> Send for VectorIterator<T> {}
+
+impl<T: VectorElement> Iterator for VectorIterator<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i < self.len {
+            // POD elements are Copy, so reading them leaves the Vector intact
+            let out = match self.data {
+                Some(data) => unsafe { ptr::read(data.add(self.i)) },
+                None => unsafe { self.vec.get_unchecked(self.i) },
+            };
+            self.i += 1;
+            Some(out)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.i = self.i.saturating_add(n).min(self.len);
+        self.next()
+    }
+}
+
+impl<T: VectorElement> ExactSizeIterator for VectorIterator<T> {
+    fn len(&self) -> usize {
+        self.len - self.i
+    }
+}
+
+impl<T: VectorElement> FusedIterator for VectorIterator<T> {}
+
+pub struct VectorRefIterator<'v, T: VectorElement> {
+    vec: &'v Vector<T>,
+    data: Option<&'v [T]>,
+    i: size_t,
+    len: size_t,
+}
+
+impl<'v, T: VectorElement> VectorRefIterator<'v, T> {
+    pub fn new(vec: &'v Vector<T>) -> Self {
+        Self { vec, data: T::as_pod_slice(vec), i: 0, len: vec.len() }
+    }
+}
+
+impl<T: VectorElement> Iterator for VectorRefIterator<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i < self.len {
+            // POD elements are Copy, so reading them leaves the Vector intact
+            let out = match self.data {
+                Some(data) => unsafe { ptr::read(&data[self.i]) },
+                None => unsafe { self.vec.get_unchecked(self.i) },
+            };
+            self.i += 1;
+            Some(out)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.i = self.i.saturating_add(n).min(self.len);
+        self.next()
+    }
+}
+
+impl<T: VectorElement> ExactSizeIterator for VectorRefIterator<'_, T> {
+    fn len(&self) -> usize {
+        self.len - self.i
+    }
+}
+
+impl<T: VectorElement> FusedIterator for VectorRefIterator<'_, T> {}
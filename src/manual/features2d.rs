@@ -0,0 +1,241 @@
+use std::fmt;
+
+use crate::{
+    calib3d,
+    core::{self, Mat, Point2f},
+    Error,
+    features2d::{BFMatcher, DescriptorMatcher, Feature2DTrait, ORB},
+    prelude::*,
+    Result,
+    types::{PtrOfORB, VectorOfKeyPoint, VectorOfPoint2f, VectorOfVectorOfDMatch},
+};
+
+/// Geometric model that `ImageMatcher` estimates from the matched point pairs
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum MatchModel {
+    /// Perspective transformation (3x3) mapping points of the first image onto the second one,
+    /// estimated with `calib3d::find_homography()`, needs at least 4 matches
+    Homography { ransac_reproj_threshold: f64 },
+    /// Fundamental matrix (3x3) relating the two views, estimated with `calib3d::find_fundamental_mat()`,
+    /// needs at least 8 matches
+    Fundamental { ransac_reproj_threshold: f64, confidence: f64 },
+}
+
+impl MatchModel {
+    /// Minimum number of point pairs required to estimate the model
+    #[inline]
+    pub fn min_matches(&self) -> usize {
+        match self {
+            MatchModel::Homography { .. } => 4,
+            MatchModel::Fundamental { .. } => 8,
+        }
+    }
+}
+
+impl Default for MatchModel {
+    fn default() -> Self {
+        MatchModel::Homography { ransac_reproj_threshold: 3. }
+    }
+}
+
+/// Error returned by `ImageMatcher::match_images()`
+#[derive(Debug)]
+pub enum MatchError {
+    /// Image number `image` (1 or 2) is empty
+    EmptyImage { image: usize },
+    /// Ratio set by `ImageMatcher::with_ratio()` is out of range `(0, 1]`
+    InvalidRatio(f32),
+    /// Image number `image` (1 or 2) doesn't have enough keypoints to estimate the model
+    TooFewKeypoints { image: usize, found: usize, required: usize },
+    /// Not enough matches passed the ratio test to estimate the model
+    TooFewMatches { found: usize, required: usize },
+    /// Estimation of the model from `matches` point pairs didn't converge to a solution
+    NoConvergence { model: MatchModel, matches: usize },
+    /// Error reported by OpenCV
+    OpenCv(Error),
+}
+
+impl fmt::Display for MatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MatchError::EmptyImage { image } => write!(f, "Input image: {} is empty", image),
+            MatchError::InvalidRatio(ratio) => write!(f, "Ratio: {} out of range: (0, 1]", ratio),
+            MatchError::TooFewKeypoints { image, found, required } => write!(f, "Found only: {} keypoints in image: {}, at least: {} required", found, image, required),
+            MatchError::TooFewMatches { found, required } => write!(f, "Found only: {} good matches, at least: {} required", found, required),
+            MatchError::NoConvergence { model, matches } => write!(f, "Unable to estimate the {:?} from: {} matches", model, matches),
+            MatchError::OpenCv(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for MatchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MatchError::OpenCv(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Error> for MatchError {
+    #[inline]
+    fn from(e: Error) -> Self {
+        MatchError::OpenCv(e)
+    }
+}
+
+/// Result of `ImageMatcher::match_images()`
+///
+/// `points1[i]` in the first image corresponds to `points2[i]` in the second one, `inliers[i]` tells
+/// whether that pair agrees with the estimated `model`.
+#[derive(Debug)]
+pub struct ImageMatches {
+    pub points1: Vec<Point2f>,
+    pub points2: Vec<Point2f>,
+    pub inliers: Vec<bool>,
+    /// 3x3 `CV_64F` matrix, either homography or fundamental matrix depending on the `MatchModel`
+    pub model: Mat,
+}
+
+impl ImageMatches {
+    /// Number of point pairs that agree with the estimated model
+    #[inline]
+    pub fn inlier_count(&self) -> usize {
+        self.inliers.iter().filter(|&&x| x).count()
+    }
+
+    /// Iterator over the point pairs that agree with the estimated model
+    pub fn inlier_pairs(&self) -> impl Iterator<Item=(Point2f, Point2f)> + '_ {
+        self.points1.iter()
+            .zip(self.points2.iter())
+            .zip(self.inliers.iter())
+            .filter(|(_, &inlier)| inlier)
+            .map(|((&p1, &p2), _)| (p1, p2))
+    }
+}
+
+/// High-level pipeline matching features between two images
+///
+/// Chains keypoint detection and description, k-nearest neighbour matching with the ratio test by D.
+/// Lowe and robust estimation of the geometric model relating the images. Degenerate inputs are reported
+/// with the corresponding `MatchError` variant.
+pub struct ImageMatcher<D: Feature2DTrait, M: DescriptorMatcher> {
+    detector: D,
+    matcher: M,
+    ratio: f32,
+    model: MatchModel,
+}
+
+impl ImageMatcher<PtrOfORB, BFMatcher> {
+    /// Creates a matcher using ORB features with the Hamming distance brute-force matcher
+    pub fn orb(n_features: i32) -> Result<Self> {
+        let mut detector = <dyn ORB>::default()?;
+        detector.set_max_features(n_features)?;
+        let matcher = BFMatcher::new(core::NORM_HAMMING, false)?;
+        Ok(Self::new(detector, matcher))
+    }
+}
+
+impl<D: Feature2DTrait, M: DescriptorMatcher> ImageMatcher<D, M> {
+    /// Creates a matcher with the default ratio of 0.75 and homography model
+    pub fn new(detector: D, matcher: M) -> Self {
+        Self { detector, matcher, ratio: 0.75, model: MatchModel::default() }
+    }
+
+    /// Sets the maximum ratio between the distances to the best and the second best match for the match to
+    /// be accepted, must be in range `(0, 1]`
+    pub fn with_ratio(mut self, ratio: f32) -> Self {
+        self.ratio = ratio;
+        self
+    }
+
+    /// Sets the geometric model to estimate
+    pub fn with_model(mut self, model: MatchModel) -> Self {
+        self.model = model;
+        self
+    }
+
+    #[inline]
+    pub fn detector(&mut self) -> &mut D {
+        &mut self.detector
+    }
+
+    #[inline]
+    pub fn matcher(&mut self) -> &mut M {
+        &mut self.matcher
+    }
+
+    /// Detects and describes keypoints of the image
+    pub fn detect_and_compute(&mut self, img: &Mat) -> Result<(VectorOfKeyPoint, Mat)> {
+        if img.empty()? {
+            return Err(Error::new(core::StsBadArg, "Input image is empty".to_string()));
+        }
+        let mut keypoints = VectorOfKeyPoint::new();
+        let mut descriptors = Mat::default()?;
+        self.detector.detect_and_compute(img, &Mat::default()?, &mut keypoints, &mut descriptors, false)?;
+        Ok((keypoints, descriptors))
+    }
+
+    /// Matches `img1` against `img2` and estimates the model relating them
+    ///
+    /// Only the queries with at least 2 nearest neighbours can pass the ratio test, a match without the
+    /// second neighbour (e.g. when `img2` has a single keypoint) is always rejected.
+    pub fn match_images(&mut self, img1: &Mat, img2: &Mat) -> std::result::Result<ImageMatches, MatchError> {
+        if !(self.ratio > 0. && self.ratio <= 1.) {
+            return Err(MatchError::InvalidRatio(self.ratio));
+        }
+        for (i, img) in [img1, img2].iter().enumerate() {
+            if img.empty()? {
+                return Err(MatchError::EmptyImage { image: i + 1 });
+            }
+        }
+        let min_matches = self.model.min_matches();
+        let (keypoints1, descriptors1) = self.detect_and_compute(img1)?;
+        let (keypoints2, descriptors2) = self.detect_and_compute(img2)?;
+        for (i, keypoints) in [&keypoints1, &keypoints2].iter().enumerate() {
+            if keypoints.len() < min_matches {
+                return Err(MatchError::TooFewKeypoints { image: i + 1, found: keypoints.len(), required: min_matches });
+            }
+        }
+
+        let mut knn_matches = VectorOfVectorOfDMatch::new();
+        self.matcher.knn_train_matches(&descriptors1, &descriptors2, &mut knn_matches, 2, &Mat::default()?, false)?;
+        let keypoints1 = keypoints1.as_slice();
+        let keypoints2 = keypoints2.as_slice();
+        let mut points1 = Vec::with_capacity(knn_matches.len());
+        let mut points2 = Vec::with_capacity(knn_matches.len());
+        for pair in &knn_matches {
+            let pair = pair.as_slice();
+            if let [best, second, ..] = pair {
+                if best.distance < self.ratio * second.distance {
+                    points1.push(keypoints1[best.query_idx as usize].pt);
+                    points2.push(keypoints2[best.train_idx as usize].pt);
+                }
+            }
+        }
+        if points1.len() < min_matches {
+            return Err(MatchError::TooFewMatches { found: points1.len(), required: min_matches });
+        }
+
+        let src = VectorOfPoint2f::from_slice(&points1);
+        let dst = VectorOfPoint2f::from_slice(&points2);
+        let mut mask = Mat::default()?;
+        let model = match self.model {
+            MatchModel::Homography { ransac_reproj_threshold } => {
+                calib3d::find_homography(&src, &dst, &mut mask, calib3d::RANSAC, ransac_reproj_threshold)?
+            }
+            MatchModel::Fundamental { ransac_reproj_threshold, confidence } => {
+                calib3d::find_fundamental_mat(&src, &dst, &mut mask, calib3d::FM_RANSAC, ransac_reproj_threshold, confidence)?
+            }
+        };
+        if model.empty()? || model.size()? != core::Size::new(3, 3) {
+            return Err(MatchError::NoConvergence { model: self.model, matches: points1.len() });
+        }
+        let inliers = if mask.empty()? {
+            vec![true; points1.len()]
+        } else {
+            mask.data_typed::<u8>()?.iter().map(|&x| x != 0).collect()
+        };
+        Ok(ImageMatches { points1, points2, inliers, model })
+    }
+}
@@ -0,0 +1,8 @@
+pub use self::encode::*;
+pub use self::info::*;
+pub use self::pages::*;
+
+mod encode;
+mod info;
+mod pages;
+mod tiff;
@@ -0,0 +1,454 @@
+use std::{ffi::OsStr, ops::RangeInclusive, path::Path};
+
+use crate::{
+    core::{self, _InputArrayTrait, ToInputArray, VectorTrait},
+    Error,
+    imgcodecs,
+    Result,
+    types::{VectorOfint, VectorOfuchar},
+};
+
+/// Typed encoder options of a single image format for [imencode_with] and [imwrite_with]
+pub trait EncodeParams {
+    /// Lowercase file extensions (without the dot) of the format the options apply to
+    fn extensions(&self) -> &'static [&'static str];
+
+    /// Checks that the options and their combination are supported by the format for an image of the specified depth
+    /// and number of channels
+    fn validate(&self, depth: i32, channels: i32) -> Result<()>;
+
+    /// Converts the options to the interleaved `IMWRITE_*` key/value list accepted by OpenCV
+    fn to_params(&self) -> VectorOfint;
+}
+
+fn check_range(name: &str, value: i32, range: RangeInclusive<i32>) -> Result<()> {
+    if range.contains(&value) {
+        Ok(())
+    } else {
+        Err(Error::new(core::StsOutOfRange, format!("{}: {} is out of range {}..={}", name, value, range.start(), range.end())))
+    }
+}
+
+fn push_param(params: &mut VectorOfint, key: i32, value: Option<i32>) {
+    if let Some(value) = value {
+        params.push(key);
+        params.push(value);
+    }
+}
+
+fn prepare_params(ext: &str, img: &dyn ToInputArray, params: &dyn EncodeParams) -> Result<VectorOfint> {
+    let ext_lower = ext.trim_start_matches('.').to_lowercase();
+    if !params.extensions().contains(&ext_lower.as_str()) {
+        return Err(Error::new(core::StsBadArg, format!("Encoder parameters for: {} can't be used with extension: {}", params.extensions().join(", "), ext)));
+    }
+    let img = img.input_array()?;
+    params.validate(img.depth(-1)?, img.channels(-1)?)?;
+    Ok(params.to_params())
+}
+
+/// Same as `imencode()`, but takes typed encoder options and returns the encoded image
+///
+/// The options are validated against the format derived from `ext` before any encoding.
+///
+/// ## Parameters
+/// * ext: File extension that defines the output format, e.g. ".jpg".
+/// * img: Image to be written.
+/// * params: Encoder options of the format.
+pub fn imencode_with(ext: &str, img: &dyn ToInputArray, params: &dyn EncodeParams) -> Result<Vec<u8>> {
+    let params = prepare_params(ext, img, params)?;
+    let mut buf = VectorOfuchar::new();
+    if imgcodecs::imencode(ext, img, &mut buf, &params)? {
+        Ok(buf.into())
+    } else {
+        Err(Error::new(core::StsError, format!("Can't encode image with extension: {}", ext)))
+    }
+}
+
+/// Same as `imwrite()`, but takes typed encoder options
+///
+/// The options are validated against the format derived from the extension of `filename` before any encoding.
+///
+/// ## Parameters
+/// * filename: Name of the file.
+/// * img: Image to be saved.
+/// * params: Encoder options of the format.
+pub fn imwrite_with(filename: &str, img: &dyn ToInputArray, params: &dyn EncodeParams) -> Result<bool> {
+    let ext = Path::new(filename).extension().and_then(OsStr::to_str).unwrap_or("");
+    let params = prepare_params(ext, img, params)?;
+    imgcodecs::imwrite(filename, img, &params)
+}
+
+/// JPEG encoder options
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct JpegParams {
+    quality: Option<i32>,
+    progressive: Option<bool>,
+    optimize: Option<bool>,
+    restart_interval: Option<i32>,
+    luma_quality: Option<i32>,
+    chroma_quality: Option<i32>,
+}
+
+impl JpegParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Quality from 0 to 100 (the higher is the better), default is 95
+    pub fn quality(mut self, quality: i32) -> Self {
+        self.quality = Some(quality);
+        self
+    }
+
+    /// Enable progressive encoding, default is false
+    pub fn progressive(mut self, progressive: bool) -> Self {
+        self.progressive = Some(progressive);
+        self
+    }
+
+    /// Optimize Huffman tables, default is false
+    pub fn optimize(mut self, optimize: bool) -> Self {
+        self.optimize = Some(optimize);
+        self
+    }
+
+    /// Restart interval from 0 to 65535, default is 0 (no restart)
+    pub fn restart_interval(mut self, interval: i32) -> Self {
+        self.restart_interval = Some(interval);
+        self
+    }
+
+    /// Separate luma and chroma quality levels from 0 to 100, they're only applied together
+    pub fn luma_chroma_quality(mut self, luma_quality: i32, chroma_quality: i32) -> Self {
+        self.luma_quality = Some(luma_quality);
+        self.chroma_quality = Some(chroma_quality);
+        self
+    }
+}
+
+impl EncodeParams for JpegParams {
+    fn extensions(&self) -> &'static [&'static str] {
+        &["jpg", "jpeg", "jpe"]
+    }
+
+    fn validate(&self, _depth: i32, _channels: i32) -> Result<()> {
+        if let Some(quality) = self.quality {
+            check_range("JPEG quality", quality, 0..=100)?;
+        }
+        if let Some(interval) = self.restart_interval {
+            check_range("JPEG restart interval", interval, 0..=65535)?;
+        }
+        if let Some(quality) = self.luma_quality {
+            check_range("JPEG luma quality", quality, 0..=100)?;
+        }
+        if let Some(quality) = self.chroma_quality {
+            check_range("JPEG chroma quality", quality, 0..=100)?;
+        }
+        Ok(())
+    }
+
+    fn to_params(&self) -> VectorOfint {
+        let mut out = VectorOfint::new();
+        push_param(&mut out, imgcodecs::IMWRITE_JPEG_QUALITY, self.quality);
+        push_param(&mut out, imgcodecs::IMWRITE_JPEG_PROGRESSIVE, self.progressive.map(i32::from));
+        push_param(&mut out, imgcodecs::IMWRITE_JPEG_OPTIMIZE, self.optimize.map(i32::from));
+        push_param(&mut out, imgcodecs::IMWRITE_JPEG_RST_INTERVAL, self.restart_interval);
+        push_param(&mut out, imgcodecs::IMWRITE_JPEG_LUMA_QUALITY, self.luma_quality);
+        push_param(&mut out, imgcodecs::IMWRITE_JPEG_CHROMA_QUALITY, self.chroma_quality);
+        out
+    }
+}
+
+/// Compression strategy of the PNG encoder, see zlib documentation for details
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PngStrategy {
+    /// For normal data
+    Default = imgcodecs::IMWRITE_PNG_STRATEGY_DEFAULT as isize,
+    /// For data produced by a filter (or predictor)
+    Filtered = imgcodecs::IMWRITE_PNG_STRATEGY_FILTERED as isize,
+    /// Force Huffman encoding only (no string match)
+    HuffmanOnly = imgcodecs::IMWRITE_PNG_STRATEGY_HUFFMAN_ONLY as isize,
+    /// Limit match distances to one (run-length encoding)
+    Rle = imgcodecs::IMWRITE_PNG_STRATEGY_RLE as isize,
+    /// Prevent the use of dynamic Huffman codes
+    Fixed = imgcodecs::IMWRITE_PNG_STRATEGY_FIXED as isize,
+}
+
+/// PNG encoder options
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PngParams {
+    compression: Option<i32>,
+    strategy: Option<PngStrategy>,
+    bilevel: Option<bool>,
+}
+
+impl PngParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compression level from 0 to 9 (a higher value means a smaller size and longer compression time), default is 1
+    pub fn compression(mut self, compression: i32) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Compression strategy, default is `PngStrategy::Rle`
+    pub fn strategy(mut self, strategy: PngStrategy) -> Self {
+        self.strategy = Some(strategy);
+        self
+    }
+
+    /// Write a binary level (1 bit per pixel) image, default is false
+    pub fn bilevel(mut self, bilevel: bool) -> Self {
+        self.bilevel = Some(bilevel);
+        self
+    }
+}
+
+impl EncodeParams for PngParams {
+    fn extensions(&self) -> &'static [&'static str] {
+        &["png"]
+    }
+
+    fn validate(&self, depth: i32, channels: i32) -> Result<()> {
+        if let Some(compression) = self.compression {
+            check_range("PNG compression", compression, 0..=9)?;
+        }
+        if self.bilevel == Some(true) && (depth != core::CV_8U || channels != 1) {
+            return Err(Error::new(core::StsBadArg, "PNG bilevel image must be 8-bit single channel".to_string()));
+        }
+        Ok(())
+    }
+
+    fn to_params(&self) -> VectorOfint {
+        let mut out = VectorOfint::new();
+        // OpenCV resets the strategy when it gets the compression, so the strategy must come after it
+        push_param(&mut out, imgcodecs::IMWRITE_PNG_COMPRESSION, self.compression);
+        push_param(&mut out, imgcodecs::IMWRITE_PNG_STRATEGY, self.strategy.map(|s| s as i32));
+        push_param(&mut out, imgcodecs::IMWRITE_PNG_BILEVEL, self.bilevel.map(i32::from));
+        out
+    }
+}
+
+/// WebP encoder options
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WebpParams {
+    quality: Option<i32>,
+}
+
+impl WebpParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lossy compression with quality from 1 to 100 (the higher is the better)
+    pub fn quality(mut self, quality: i32) -> Self {
+        self.quality = Some(quality);
+        self
+    }
+
+    /// Lossless compression, the default
+    pub fn lossless(mut self) -> Self {
+        self.quality = Some(101);
+        self
+    }
+}
+
+impl EncodeParams for WebpParams {
+    fn extensions(&self) -> &'static [&'static str] {
+        &["webp"]
+    }
+
+    fn validate(&self, _depth: i32, _channels: i32) -> Result<()> {
+        match self.quality {
+            Some(101) | None => Ok(()),
+            Some(quality) => check_range("WebP quality", quality, 1..=100),
+        }
+    }
+
+    fn to_params(&self) -> VectorOfint {
+        let mut out = VectorOfint::new();
+        push_param(&mut out, imgcodecs::IMWRITE_WEBP_QUALITY, self.quality);
+        out
+    }
+}
+
+/// Compression scheme of the TIFF encoder
+#[cfg(not(feature = "opencv-32"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TiffCompression {
+    None = 1,
+    Lzw = 5,
+    Deflate = 8,
+    PackBits = 32773,
+}
+
+/// Unit of the TIFF resolution
+#[cfg(not(feature = "opencv-32"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TiffResolutionUnit {
+    None = 1,
+    Inch = 2,
+    Centimeter = 3,
+}
+
+/// TIFF encoder options
+#[cfg(not(feature = "opencv-32"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TiffParams {
+    compression: Option<TiffCompression>,
+    resolution: Option<(TiffResolutionUnit, i32, i32)>,
+}
+
+#[cfg(not(feature = "opencv-32"))]
+impl TiffParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compression scheme, default is `TiffCompression::Lzw`
+    pub fn compression(mut self, compression: TiffCompression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Resolution in the X and Y directions, both are positive numbers of pixels per `unit`
+    pub fn resolution(mut self, unit: TiffResolutionUnit, x_dpi: i32, y_dpi: i32) -> Self {
+        self.resolution = Some((unit, x_dpi, y_dpi));
+        self
+    }
+}
+
+#[cfg(not(feature = "opencv-32"))]
+impl EncodeParams for TiffParams {
+    fn extensions(&self) -> &'static [&'static str] {
+        &["tif", "tiff"]
+    }
+
+    fn validate(&self, _depth: i32, _channels: i32) -> Result<()> {
+        if let Some((_, x_dpi, y_dpi)) = self.resolution {
+            check_range("TIFF X resolution", x_dpi, 1..=i32::max_value())?;
+            check_range("TIFF Y resolution", y_dpi, 1..=i32::max_value())?;
+        }
+        Ok(())
+    }
+
+    fn to_params(&self) -> VectorOfint {
+        let mut out = VectorOfint::new();
+        push_param(&mut out, imgcodecs::IMWRITE_TIFF_COMPRESSION, self.compression.map(|c| c as i32));
+        if let Some((unit, x_dpi, y_dpi)) = self.resolution {
+            push_param(&mut out, imgcodecs::IMWRITE_TIFF_RESUNIT, Some(unit as i32));
+            push_param(&mut out, imgcodecs::IMWRITE_TIFF_XDPI, Some(x_dpi));
+            push_param(&mut out, imgcodecs::IMWRITE_TIFF_YDPI, Some(y_dpi));
+        }
+        out
+    }
+}
+
+/// Tuple type of the PAM encoder
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PamTupleType {
+    Null = imgcodecs::IMWRITE_PAM_FORMAT_NULL as isize,
+    BlackAndWhite = imgcodecs::IMWRITE_PAM_FORMAT_BLACKANDWHITE as isize,
+    Grayscale = imgcodecs::IMWRITE_PAM_FORMAT_GRAYSCALE as isize,
+    GrayscaleAlpha = imgcodecs::IMWRITE_PAM_FORMAT_GRAYSCALE_ALPHA as isize,
+    Rgb = imgcodecs::IMWRITE_PAM_FORMAT_RGB as isize,
+    RgbAlpha = imgcodecs::IMWRITE_PAM_FORMAT_RGB_ALPHA as isize,
+}
+
+/// PAM encoder options
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PamParams {
+    tuple_type: Option<PamTupleType>,
+}
+
+impl PamParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tuple type written to the header, by default it's derived from the number of channels
+    pub fn tuple_type(mut self, tuple_type: PamTupleType) -> Self {
+        self.tuple_type = Some(tuple_type);
+        self
+    }
+}
+
+impl EncodeParams for PamParams {
+    fn extensions(&self) -> &'static [&'static str] {
+        &["pam"]
+    }
+
+    fn validate(&self, _depth: i32, channels: i32) -> Result<()> {
+        let expected_channels = match self.tuple_type {
+            None | Some(PamTupleType::Null) => return Ok(()),
+            Some(PamTupleType::BlackAndWhite) | Some(PamTupleType::Grayscale) => 1,
+            Some(PamTupleType::GrayscaleAlpha) => 2,
+            Some(PamTupleType::Rgb) => 3,
+            Some(PamTupleType::RgbAlpha) => 4,
+        };
+        if channels == expected_channels {
+            Ok(())
+        } else {
+            Err(Error::new(core::StsBadArg, format!("PAM tuple type: {:?} requires {} channel(s), image has: {}", self.tuple_type, expected_channels, channels)))
+        }
+    }
+
+    fn to_params(&self) -> VectorOfint {
+        let mut out = VectorOfint::new();
+        push_param(&mut out, imgcodecs::IMWRITE_PAM_TUPLETYPE, self.tuple_type.map(|t| t as i32));
+        out
+    }
+}
+
+/// Pixel type stored by the OpenEXR encoder
+#[cfg(not(feature = "opencv-32"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExrType {
+    /// 16-bit floating point
+    Half = imgcodecs::IMWRITE_EXR_TYPE_HALF as isize,
+    /// 32-bit floating point
+    Float = imgcodecs::IMWRITE_EXR_TYPE_FLOAT as isize,
+}
+
+/// OpenEXR encoder options
+#[cfg(not(feature = "opencv-32"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ExrParams {
+    typ: Option<ExrType>,
+}
+
+#[cfg(not(feature = "opencv-32"))]
+impl ExrParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pixel type, default is `ExrType::Float`
+    pub fn typ(mut self, typ: ExrType) -> Self {
+        self.typ = Some(typ);
+        self
+    }
+}
+
+#[cfg(not(feature = "opencv-32"))]
+impl EncodeParams for ExrParams {
+    fn extensions(&self) -> &'static [&'static str] {
+        &["exr"]
+    }
+
+    fn validate(&self, depth: i32, _channels: i32) -> Result<()> {
+        // the pixel type is only applied to floating point data, other depths are left for OpenCV to check
+        if self.typ.is_some() && depth != core::CV_32F {
+            return Err(Error::new(core::StsBadArg, "OpenEXR pixel type requires a 32-bit floating point image".to_string()));
+        }
+        Ok(())
+    }
+
+    fn to_params(&self) -> VectorOfint {
+        let mut out = VectorOfint::new();
+        push_param(&mut out, imgcodecs::IMWRITE_EXR_TYPE, self.typ.map(|t| t as i32));
+        out
+    }
+}
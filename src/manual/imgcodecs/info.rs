@@ -0,0 +1,383 @@
+use std::{convert::{TryFrom, TryInto}, fs, str};
+
+use crate::{
+    core,
+    Error,
+    imgcodecs,
+    Result,
+};
+#[cfg(not(feature = "opencv-4"))]
+use crate::{core::{Mat, VectorTrait}, types::{VectorOfint, VectorOfuchar}};
+
+use super::tiff::{self, TiffReader};
+
+const TAG_IMAGE_WIDTH: u16 = 256;
+const TAG_IMAGE_LENGTH: u16 = 257;
+const TAG_BITS_PER_SAMPLE: u16 = 258;
+const TAG_ORIENTATION: u16 = 274;
+const TAG_SAMPLES_PER_PIXEL: u16 = 277;
+
+/// Image format detected by its signature
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageFormat {
+    Bmp,
+    Jpeg,
+    Png,
+    Tiff,
+    WebP,
+    /// Portable bitmap, graymap or pixmap (PBM, PGM, PPM)
+    Pxm,
+    /// Portable arbitrary map
+    Pam,
+}
+
+/// EXIF orientation, it names the corner of the stored image that must be displayed at the top left and the rows
+/// of which must be displayed at the top
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExifOrientation {
+    /// Upright, no transformation needed
+    TopLeft = 1,
+    /// Mirrored horizontally
+    TopRight = 2,
+    /// Rotated by 180°
+    BottomRight = 3,
+    /// Mirrored vertically
+    BottomLeft = 4,
+    /// Mirrored along the top-left to bottom-right diagonal
+    LeftTop = 5,
+    /// Rotated by 90° clockwise
+    RightTop = 6,
+    /// Mirrored along the top-right to bottom-left diagonal
+    RightBottom = 7,
+    /// Rotated by 90° counter-clockwise
+    LeftBottom = 8,
+}
+
+impl ExifOrientation {
+    fn from_tag(value: u32) -> Option<Self> {
+        Some(match value {
+            1 => ExifOrientation::TopLeft,
+            2 => ExifOrientation::TopRight,
+            3 => ExifOrientation::BottomRight,
+            4 => ExifOrientation::BottomLeft,
+            5 => ExifOrientation::LeftTop,
+            6 => ExifOrientation::RightTop,
+            7 => ExifOrientation::RightBottom,
+            8 => ExifOrientation::LeftBottom,
+            _ => return None,
+        })
+    }
+}
+
+/// Image properties read from the header, see [imdecode_info] and [imread_info]
+///
+/// The values describe the image as it's stored in the file, they can differ from the properties of the `Mat`
+/// returned by `imdecode()` depending on the `IMREAD_*` flags. `width` and `height` are those of the first page
+/// and don't take `orientation` into account.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ImageInfo {
+    pub format: ImageFormat,
+    pub width: i32,
+    pub height: i32,
+    /// Number of channels (samples per pixel)
+    pub channels: i32,
+    /// Number of bits per channel
+    pub bit_depth: i32,
+    /// Number of pages, more than 1 only for multi-page TIFF images
+    pub page_count: usize,
+    /// EXIF orientation of JPEG and TIFF images, `None` if it's not specified. Unless `IMREAD_IGNORE_ORIENTATION` is
+    /// passed, `imread()` and `imdecode()` apply it to the decoded image.
+    pub orientation: Option<ExifOrientation>,
+}
+
+impl ImageInfo {
+    fn new(format: ImageFormat, width: u32, height: u32, channels: u32, bit_depth: u32) -> Result<Self> {
+        let to_i32 = |value: u32, name: &str| i32::try_from(value)
+            .map_err(|_| Error::new(core::StsOutOfRange, format!("Image {}: {} is out of range", name, value)));
+        Ok(Self {
+            format,
+            width: to_i32(width, "width")?,
+            height: to_i32(height, "height")?,
+            channels: to_i32(channels, "channels")?,
+            bit_depth: to_i32(bit_depth, "bit depth")?,
+            page_count: 1,
+            orientation: None,
+        })
+    }
+}
+
+/// Reads the image properties from the header of the encoded image in `buf` without decoding the pixel data
+///
+/// Supported formats are BMP, JPEG, PNG, TIFF, WebP, PBM/PGM/PPM and PAM.
+///
+/// Fails with `StsUnsupportedFormat` if OpenCV is built without the decoder for the detected format.
+pub fn imdecode_info(buf: &[u8]) -> Result<ImageInfo> {
+    let out = if buf.starts_with(b"\xFF\xD8\xFF") {
+        jpeg_info(buf)
+    } else if buf.starts_with(b"\x89PNG\r\n\x1A\n") {
+        png_info(buf)
+    } else if let Some(tiff) = TiffReader::new(buf) {
+        tiff_info(&tiff)
+    } else if buf.starts_with(b"BM") {
+        bmp_info(buf)
+    } else if buf.starts_with(b"RIFF") && buf.get(8..12) == Some(b"WEBP") {
+        webp_info(buf)
+    } else if buf.starts_with(b"P7") {
+        pam_info(buf)
+    } else if buf.len() > 2 && buf[0] == b'P' && (b'1'..=b'6').contains(&buf[1]) {
+        pxm_info(buf)
+    } else {
+        Err(Error::new(core::StsUnsupportedFormat, "Unknown or unsupported image format".to_string()))
+    }?;
+    if !have_decoder(out.format)? {
+        return Err(Error::new(core::StsUnsupportedFormat, format!("OpenCV is built without the {:?} decoder", out.format)));
+    }
+    Ok(out)
+}
+
+impl ImageFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Bmp => ".bmp",
+            ImageFormat::Jpeg => ".jpg",
+            ImageFormat::Png => ".png",
+            ImageFormat::Tiff => ".tiff",
+            ImageFormat::WebP => ".webp",
+            ImageFormat::Pxm => ".ppm",
+            ImageFormat::Pam => ".pam",
+        }
+    }
+}
+
+/// Checks whether OpenCV can decode the `format`
+///
+/// There is no API to query the decoders for the in-memory data, but OpenCV always registers the decoder and the
+/// encoder of a format together, so the encoder is checked instead.
+#[cfg(feature = "opencv-4")]
+fn have_decoder(format: ImageFormat) -> Result<bool> {
+    imgcodecs::have_image_writer(format.extension())
+}
+
+/// Same as above, `have_image_writer()` is not available so a single pixel is encoded instead
+#[cfg(not(feature = "opencv-4"))]
+fn have_decoder(format: ImageFormat) -> Result<bool> {
+    let pixel = Mat::new_rows_cols_with_default(1, 1, core::CV_8UC3, core::Scalar::all(0.))?;
+    let mut buf = VectorOfuchar::new();
+    Ok(imgcodecs::imencode(format.extension(), &pixel, &mut buf, &VectorOfint::new()).unwrap_or(false))
+}
+
+/// Reads the image properties from the header of the image file without decoding the pixel data, see [imdecode_info]
+///
+/// On OpenCV 4 fails early if there is no decoder for the file as reported by `have_image_reader()`.
+pub fn imread_info(filename: &str) -> Result<ImageInfo> {
+    #[cfg(feature = "opencv-4")]
+    if !imgcodecs::have_image_reader(filename)? {
+        return Err(Error::new(core::StsUnsupportedFormat, format!("No image decoder for file: {}", filename)));
+    }
+    let buf = fs::read(filename)
+        .map_err(|e| Error::new(core::StsError, format!("Can't read file: {}, error: {}", filename, e)))?;
+    imdecode_info(&buf)
+}
+
+fn truncated(format: &str) -> Error {
+    Error::new(core::StsParseError, format!("Truncated or malformed {} image", format))
+}
+
+fn be_u16(buf: &[u8], pos: usize) -> Option<u16> {
+    buf.get(pos..pos + 2).map(|b| u16::from_be_bytes(b.try_into().expect("Slice has 2 bytes")))
+}
+
+fn be_u32(buf: &[u8], pos: usize) -> Option<u32> {
+    buf.get(pos..pos + 4).map(|b| u32::from_be_bytes(b.try_into().expect("Slice has 4 bytes")))
+}
+
+fn le_u16(buf: &[u8], pos: usize) -> Option<u16> {
+    buf.get(pos..pos + 2).map(|b| u16::from_le_bytes(b.try_into().expect("Slice has 2 bytes")))
+}
+
+fn le_u32(buf: &[u8], pos: usize) -> Option<u32> {
+    buf.get(pos..pos + 4).map(|b| u32::from_le_bytes(b.try_into().expect("Slice has 4 bytes")))
+}
+
+fn jpeg_info(buf: &[u8]) -> Result<ImageInfo> {
+    let mut orientation = None;
+    let mut pos = 2;
+    loop {
+        // markers can be preceded by any number of fill bytes
+        while buf.get(pos) == Some(&0xFF) && buf.get(pos + 1) == Some(&0xFF) {
+            pos += 1;
+        }
+        if buf.get(pos) != Some(&0xFF) {
+            return Err(truncated("JPEG"));
+        }
+        let marker = *buf.get(pos + 1).ok_or_else(|| truncated("JPEG"))?;
+        pos += 2;
+        match marker {
+            // standalone markers without a payload
+            0x01 | 0xD0..=0xD7 => continue,
+            // start of scan or end of image come after the frame header
+            0xD9 | 0xDA => return Err(truncated("JPEG")),
+            _ => {}
+        }
+        let len = usize::from(be_u16(buf, pos).ok_or_else(|| truncated("JPEG"))?);
+        let segment = buf.get(pos + 2..pos + len).ok_or_else(|| truncated("JPEG"))?;
+        match marker {
+            0xE1 if segment.starts_with(b"Exif\0\0") => {
+                orientation = TiffReader::new(&segment[6..]).and_then(|exif| {
+                    let directory = exif.u32(4)?;
+                    exif.tag(directory, TAG_ORIENTATION)
+                }).and_then(ExifOrientation::from_tag);
+            }
+            // start of frame, except DHT (0xC4), JPG (0xC8) and DAC (0xCC)
+            0xC0..=0xCF if marker != 0xC4 && marker != 0xC8 && marker != 0xCC => {
+                if segment.len() < 6 {
+                    return Err(truncated("JPEG"));
+                }
+                let height = be_u16(segment, 1).expect("Length is checked");
+                let width = be_u16(segment, 3).expect("Length is checked");
+                let mut out = ImageInfo::new(ImageFormat::Jpeg, width.into(), height.into(), segment[5].into(), segment[0].into())?;
+                out.orientation = orientation;
+                return Ok(out);
+            }
+            _ => {}
+        }
+        pos += len;
+    }
+}
+
+fn png_info(buf: &[u8]) -> Result<ImageInfo> {
+    if buf.get(12..16) != Some(b"IHDR") || buf.len() < 26 {
+        return Err(truncated("PNG"));
+    }
+    let width = be_u32(buf, 16).expect("Length is checked");
+    let height = be_u32(buf, 20).expect("Length is checked");
+    let channels = match buf[25] {
+        0 => 1, // grayscale
+        2 => 3, // RGB
+        3 => 3, // palette
+        4 => 2, // grayscale with alpha
+        6 => 4, // RGBA
+        _ => return Err(truncated("PNG")),
+    };
+    ImageInfo::new(ImageFormat::Png, width, height, channels, buf[24].into())
+}
+
+fn tiff_info(tiff: &TiffReader) -> Result<ImageInfo> {
+    let directories = tiff.directories()?;
+    let first = *directories.first().ok_or_else(tiff::malformed)?;
+    let width = tiff.tag(first, TAG_IMAGE_WIDTH).ok_or_else(tiff::malformed)?;
+    let height = tiff.tag(first, TAG_IMAGE_LENGTH).ok_or_else(tiff::malformed)?;
+    let channels = tiff.tag(first, TAG_SAMPLES_PER_PIXEL).unwrap_or(1);
+    let bit_depth = tiff.tag(first, TAG_BITS_PER_SAMPLE).unwrap_or(1);
+    let mut out = ImageInfo::new(ImageFormat::Tiff, width, height, channels, bit_depth)?;
+    out.page_count = directories.len();
+    out.orientation = tiff.tag(first, TAG_ORIENTATION).and_then(ExifOrientation::from_tag);
+    Ok(out)
+}
+
+fn bmp_info(buf: &[u8]) -> Result<ImageInfo> {
+    let header_size = le_u32(buf, 14).ok_or_else(|| truncated("BMP"))?;
+    let (width, height, bpp) = if header_size == 12 {
+        // OS/2 BITMAPCOREHEADER
+        (le_u16(buf, 18).map(u32::from), le_u16(buf, 20).map(u32::from), le_u16(buf, 24))
+    } else {
+        // negative height means top-down row order
+        (le_u32(buf, 18), le_u32(buf, 22).map(|h| (h as i32).abs() as u32), le_u16(buf, 28))
+    };
+    let (width, height, bpp) = match (width, height, bpp) {
+        (Some(width), Some(height), Some(bpp)) => (width, height, bpp),
+        _ => return Err(truncated("BMP")),
+    };
+    let channels = match bpp {
+        32 => 4,
+        // images with up to 8 bits per pixel are stored with a color palette, OpenCV decodes them as grayscale if all
+        // of its entries are gray
+        1 | 4 | 8 => {
+            let (entry_size, used) = if header_size == 12 {
+                (3, 0)
+            } else {
+                (4, le_u32(buf, 46).ok_or_else(|| truncated("BMP"))?)
+            };
+            let count = if used == 0 || used > 1 << bpp { 1 << bpp } else { used as usize };
+            let palette = buf.get((header_size as usize).saturating_add(14)..)
+                .and_then(|palette| palette.get(..count * entry_size))
+                .ok_or_else(|| truncated("BMP"))?;
+            if palette.chunks(entry_size).all(|entry| entry[0] == entry[1] && entry[1] == entry[2]) { 1 } else { 3 }
+        }
+        _ => 3,
+    };
+    ImageInfo::new(ImageFormat::Bmp, width, height, channels, 8)
+}
+
+fn webp_info(buf: &[u8]) -> Result<ImageInfo> {
+    let (width, height, alpha) = match buf.get(12..16) {
+        Some(b"VP8 ") if buf.get(23..26) == Some(b"\x9D\x01\x2A") => {
+            let width = le_u16(buf, 26).ok_or_else(|| truncated("WebP"))? & 0x3FFF;
+            let height = le_u16(buf, 28).ok_or_else(|| truncated("WebP"))? & 0x3FFF;
+            (u32::from(width), u32::from(height), false)
+        }
+        Some(b"VP8L") if buf.get(20) == Some(&0x2F) => {
+            let bits = le_u32(buf, 21).ok_or_else(|| truncated("WebP"))?;
+            ((bits & 0x3FFF) + 1, ((bits >> 14) & 0x3FFF) + 1, (bits >> 28) & 1 == 1)
+        }
+        Some(b"VP8X") => {
+            let flags = *buf.get(20).ok_or_else(|| truncated("WebP"))?;
+            let width = le_u32(buf, 24).ok_or_else(|| truncated("WebP"))? & 0xFF_FFFF;
+            let height = le_u32(buf, 27).ok_or_else(|| truncated("WebP"))? & 0xFF_FFFF;
+            (width + 1, height + 1, flags & 0x10 != 0)
+        }
+        _ => return Err(truncated("WebP")),
+    };
+    ImageInfo::new(ImageFormat::WebP, width, height, if alpha { 4 } else { 3 }, 8)
+}
+
+/// Splits the header of a PBM/PGM/PPM image into whitespace separated tokens, comments are skipped
+fn pxm_tokens(buf: &[u8]) -> impl Iterator<Item=&[u8]> {
+    buf.split(|&b| b == b'\n')
+        .map(|line| line.splitn(2, |&b| b == b'#').next().unwrap_or(line))
+        .flat_map(|line| line.split(|b| b.is_ascii_whitespace()))
+        .filter(|token| !token.is_empty())
+}
+
+fn parse_u32(token: Option<&[u8]>, format: &str) -> Result<u32> {
+    token.and_then(|token| str::from_utf8(token).ok())
+        .and_then(|token| token.parse().ok())
+        .ok_or_else(|| truncated(format))
+}
+
+fn bit_depth_from_maxval(maxval: u32) -> u32 {
+    if maxval < 256 { 8 } else { 16 }
+}
+
+fn pxm_info(buf: &[u8]) -> Result<ImageInfo> {
+    let mut tokens = pxm_tokens(buf).skip(1);
+    let width = parse_u32(tokens.next(), "PxM")?;
+    let height = parse_u32(tokens.next(), "PxM")?;
+    let (channels, bit_depth) = match buf[1] {
+        b'1' | b'4' => (1, 1),
+        b'2' | b'5' => (1, bit_depth_from_maxval(parse_u32(tokens.next(), "PxM")?)),
+        _ => (3, bit_depth_from_maxval(parse_u32(tokens.next(), "PxM")?)),
+    };
+    ImageInfo::new(ImageFormat::Pxm, width, height, channels, bit_depth)
+}
+
+fn pam_info(buf: &[u8]) -> Result<ImageInfo> {
+    let (mut width, mut height, mut channels, mut maxval) = (None, None, None, None);
+    let mut tokens = pxm_tokens(buf).skip(1);
+    while let Some(token) = tokens.next() {
+        match token {
+            b"WIDTH" => width = Some(parse_u32(tokens.next(), "PAM")?),
+            b"HEIGHT" => height = Some(parse_u32(tokens.next(), "PAM")?),
+            b"DEPTH" => channels = Some(parse_u32(tokens.next(), "PAM")?),
+            b"MAXVAL" => maxval = Some(parse_u32(tokens.next(), "PAM")?),
+            b"ENDHDR" => break,
+            _ => {}
+        }
+    }
+    match (width, height, channels, maxval) {
+        (Some(width), Some(height), Some(channels), Some(maxval)) => {
+            ImageInfo::new(ImageFormat::Pam, width, height, channels, bit_depth_from_maxval(maxval))
+        }
+        _ => Err(truncated("PAM")),
+    }
+}
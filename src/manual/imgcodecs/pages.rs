@@ -0,0 +1,126 @@
+use std::{fs, iter::FusedIterator, ops::Range};
+
+use crate::{
+    core::{self, Mat, VectorTrait},
+    Error,
+    imgcodecs,
+    Result,
+    types::{VectorOfMat, VectorOfuchar},
+};
+
+use super::tiff::TiffReader;
+
+/// Lazy iterator over the pages of an image, created by [imread_pages] or [imdecode_pages]
+///
+/// The encoded image is kept in memory and every page is decoded only when it's requested. Only TIFF images can have
+/// more than one page, any other format supported by `imdecode()` is returned as a single page. BigTIFF files are
+/// not supported.
+pub struct ImagePages {
+    buf: VectorOfuchar,
+    flags: i32,
+    big_endian: bool,
+    /// offsets of TIFF image file directories, `None` for a single page image of a different format
+    directories: Option<Vec<u32>>,
+    pages: Range<usize>,
+}
+
+impl ImagePages {
+    fn new(buf: VectorOfuchar, flags: i32) -> Result<Self> {
+        let tiff = TiffReader::new(buf.as_slice());
+        let big_endian = tiff.as_ref().map_or(false, TiffReader::is_big_endian);
+        let directories = tiff.map(|tiff| tiff.directories()).transpose()?;
+        let page_count = directories.as_ref().map_or(1, |dirs| dirs.len());
+        Ok(Self { buf, flags, big_endian, directories, pages: 0..page_count })
+    }
+
+    fn decode(&mut self, page: usize) -> Result<Mat> {
+        if let Some(directories) = &self.directories {
+            // OpenCV decodes only the first directory of a TIFF image and its per-page decoder (`ImageDecoder::nextPage()`)
+            // is internal to imgcodecs, so the header is pointed to the requested directory instead
+            let offset = if self.big_endian { directories[page].to_be_bytes() } else { directories[page].to_le_bytes() };
+            self.buf.as_mut_slice()[4..8].copy_from_slice(&offset);
+        }
+        let out = imgcodecs::imdecode(&self.buf, self.flags)?;
+        if out.empty()? {
+            Err(Error::new(core::StsError, format!("Can't decode image page: {}", page)))
+        } else {
+            Ok(out)
+        }
+    }
+}
+
+impl Iterator for ImagePages {
+    type Item = Result<Mat>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pages.next().map(|page| self.decode(page))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.pages.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.pages.nth(n).map(|page| self.decode(page))
+    }
+}
+
+impl DoubleEndedIterator for ImagePages {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.pages.next_back().map(|page| self.decode(page))
+    }
+}
+
+impl ExactSizeIterator for ImagePages {}
+
+impl FusedIterator for ImagePages {}
+
+/// Lazily reads the pages of a multi-page image from a file, see [ImagePages]
+///
+/// ## Parameters
+/// * filename: Name of file to be loaded.
+/// * flags: Flag that can take values of cv::ImreadModes, default with cv::IMREAD_ANYCOLOR.
+pub fn imread_pages(filename: &str, flags: i32) -> Result<ImagePages> {
+    let buf = fs::read(filename)
+        .map_err(|e| Error::new(core::StsError, format!("Can't read file: {}, error: {}", filename, e)))?;
+    ImagePages::new(VectorOfuchar::from(buf), flags)
+}
+
+/// Lazily decodes the pages of a multi-page image from a memory buffer, see [ImagePages]
+///
+/// ## Parameters
+/// * buf: Input array of bytes.
+/// * flags: Flag that can take values of cv::ImreadModes, default with cv::IMREAD_ANYCOLOR.
+pub fn imdecode_pages(buf: &[u8], flags: i32) -> Result<ImagePages> {
+    ImagePages::new(VectorOfuchar::from_slice(buf), flags)
+}
+
+/// Decodes a multi-page image from a memory buffer, in-memory counterpart of `imreadmulti()`
+///
+/// Unlike `imreadmulti()` the errors of decoding are returned instead of false, false means there were no pages.
+///
+/// ## Parameters
+/// * buf: Input array of bytes.
+/// * mats: A vector of Mat objects holding each page, if more than one.
+/// * flags: Flag that can take values of cv::ImreadModes, default with cv::IMREAD_ANYCOLOR.
+pub fn imdecode_multi(buf: &[u8], mats: &mut VectorOfMat, flags: i32) -> Result<bool> {
+    let mut has_pages = false;
+    for page in imdecode_pages(buf, flags)? {
+        mats.push(page?);
+        has_pages = true;
+    }
+    Ok(has_pages)
+}
+
+/// Saves the images to the specified file as the pages of a single multi-page image
+///
+/// Only TIFF supports storing multiple pages, for other formats it returns false.
+///
+/// ## Parameters
+/// * filename: Name of the file.
+/// * mats: Images to be saved, one per page.
+/// * params: Format-specific parameters, see `imwrite()`.
+#[cfg(not(feature = "opencv-32"))]
+pub fn imwrite_multi(filename: &str, mats: &VectorOfMat, params: &crate::types::VectorOfint) -> Result<bool> {
+    imgcodecs::imwrite(filename, mats, params)
+}
@@ -0,0 +1,85 @@
+use std::convert::TryInto;
+
+use crate::{
+    core,
+    Error,
+    Result,
+};
+
+const TIFF_LE: &[u8] = b"II*\0";
+const TIFF_BE: &[u8] = b"MM\0*";
+
+const TYPE_SHORT: u16 = 3;
+const TYPE_LONG: u16 = 4;
+
+/// Minimal reader of the TIFF structure (also used by EXIF), BigTIFF is not supported
+pub struct TiffReader<'b> {
+    buf: &'b [u8],
+    big_endian: bool,
+}
+
+impl<'b> TiffReader<'b> {
+    /// Returns `None` if `buf` doesn't start with a TIFF header
+    pub fn new(buf: &'b [u8]) -> Option<Self> {
+        let header = buf.get(..4)?;
+        if header == TIFF_LE || header == TIFF_BE {
+            Some(Self { buf, big_endian: header == TIFF_BE })
+        } else {
+            None
+        }
+    }
+
+    pub fn is_big_endian(&self) -> bool {
+        self.big_endian
+    }
+
+    pub fn u16(&self, pos: usize) -> Option<u16> {
+        let b = self.buf.get(pos..pos.checked_add(2)?)?.try_into().expect("Slice has 2 bytes");
+        Some(if self.big_endian { u16::from_be_bytes(b) } else { u16::from_le_bytes(b) })
+    }
+
+    pub fn u32(&self, pos: usize) -> Option<u32> {
+        let b = self.buf.get(pos..pos.checked_add(4)?)?.try_into().expect("Slice has 4 bytes");
+        Some(if self.big_endian { u32::from_be_bytes(b) } else { u32::from_le_bytes(b) })
+    }
+
+    /// Returns the offsets of all image file directories
+    pub fn directories(&self) -> Result<Vec<u32>> {
+        let mut out = vec![];
+        let mut offset = self.u32(4).ok_or_else(malformed)?;
+        while offset != 0 {
+            // prevents looping on the directory chains pointing back
+            if out.contains(&offset) {
+                return Err(malformed());
+            }
+            out.push(offset);
+            let entry_count = self.u16(offset as usize).ok_or_else(malformed)?;
+            offset = self.u32(offset as usize + 2 + usize::from(entry_count) * 12).ok_or_else(malformed)?;
+        }
+        Ok(out)
+    }
+
+    /// Returns the first value of a SHORT or LONG `tag` in the directory at `directory` offset
+    pub fn tag(&self, directory: u32, tag: u16) -> Option<u32> {
+        let directory = directory as usize;
+        let entry_count = self.u16(directory)?;
+        (0..usize::from(entry_count))
+            .map(|i| directory + 2 + i * 12)
+            .find(|&entry| self.u16(entry) == Some(tag))
+            .and_then(|entry| {
+                let count = self.u32(entry + 4)?;
+                let (size, read): (u32, fn(&Self, usize) -> Option<u32>) = match self.u16(entry + 2)? {
+                    TYPE_SHORT => (2, |r, pos| r.u16(pos).map(u32::from)),
+                    TYPE_LONG => (4, Self::u32),
+                    _ => return None,
+                };
+                // values that don't fit into 4 bytes are stored at the offset
+                let pos = if count.checked_mul(size)? <= 4 { entry + 8 } else { self.u32(entry + 8)? as usize };
+                read(self, pos)
+            })
+    }
+}
+
+pub fn malformed() -> Error {
+    Error::new(core::StsParseError, "Malformed TIFF image".to_string())
+}
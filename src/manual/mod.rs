@@ -1,5 +1,15 @@
 pub mod core;
 #[cfg(any(not(feature = "opencv-32"), feature = "contrib"))]
 pub mod dnn;
+pub mod features2d;
+pub mod imgcodecs;
+pub mod objdetect;
+#[cfg(feature = "contrib")]
+pub mod surface_matching;
 pub mod sys;
+#[cfg(feature = "contrib")]
+pub mod text;
 pub mod types;
+pub mod videoio;
+#[cfg(not(feature = "opencv-32"))]
+pub mod viz;
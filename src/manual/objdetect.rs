@@ -0,0 +1,59 @@
+use std::{
+    mem::ManuallyDrop,
+    os::raw::c_void,
+};
+
+use crate::{
+    core::Mat,
+    Result,
+    sys,
+    types::{PtrOfIDetector, VectorOfRect},
+};
+
+/// Object detector implemented in Rust, convert it with [PtrOfIDetector::from_detector] to pass it to
+/// `DetectionBasedTracker::new()`.
+///
+/// The main detector is called from the detection thread started by `DetectionBasedTracker::run()` and the tracking
+/// detector from the thread calling `DetectionBasedTracker::process()`, hence the `Send` requirement.
+pub trait CustomDetector: Send {
+    /// Detects objects in `image` and stores their locations into `objects`
+    fn detect(&mut self, image: &Mat, objects: &mut VectorOfRect) -> Result<()>;
+}
+
+impl<F: FnMut(&Mat, &mut VectorOfRect) -> Result<()> + Send> CustomDetector for F {
+    #[inline]
+    fn detect(&mut self, image: &Mat, objects: &mut VectorOfRect) -> Result<()> {
+        self(image, objects)
+    }
+}
+
+// called by ocvrs::RustDetector from cpp/objdetect.hpp
+extern "C" fn detect_trampoline(detector: *mut c_void, image: *mut c_void, objects: *mut c_void) -> sys::cv_return_value_void {
+    // the image and the objects are owned by OpenCV, they must not be deleted
+    let image = ManuallyDrop::new(unsafe { Mat::from_raw_ptr(image) });
+    let mut objects = ManuallyDrop::new(unsafe { VectorOfRect::from_raw_ptr(objects) });
+    unsafe { sys::call_callback::<dyn CustomDetector>(detector, "Rust detector", |detector| detector.detect(&image, &mut objects)) }
+}
+
+extern "C" fn drop_trampoline(detector: *mut c_void) {
+    unsafe { sys::drop_callback::<dyn CustomDetector>(detector) }
+}
+
+impl PtrOfIDetector {
+    /// Wraps a detector implemented in Rust to be used with `DetectionBasedTracker::new()`
+    ///
+    /// Errors returned by the detector and panics inside of it are converted to OpenCV exceptions. When raised by the
+    /// tracking detector they're returned from `DetectionBasedTracker::process()`, when raised by the main detector
+    /// OpenCV logs them and stops the detection thread.
+    pub fn from_detector(detector: impl CustomDetector + 'static) -> Result<Self> {
+        // the same PtrOfIDetector can be passed as both the main and the tracking detector, the lock serializes the calls
+        let detector = sys::box_callback(Box::new(detector) as Box<dyn CustomDetector>);
+        let detect_fn = detect_trampoline as extern "C" fn(*mut c_void, *mut c_void, *mut c_void) -> sys::cv_return_value_void;
+        let drop_fn = drop_trampoline as extern "C" fn(*mut c_void);
+        cpp!(unsafe [detector as "void*", detect_fn as "ocvrs::DetectorDetect", drop_fn as "ocvrs::DetectorDrop"] -> sys::cv_return_value_void_X as "cv_return_value_void_X" {
+            try {
+                return { Error::Code::StsOk, NULL, new cv::Ptr<cv::DetectionBasedTracker::IDetector>(new ocvrs::RustDetector(detector, detect_fn, drop_fn)) };
+            } CVRS_CATCH(cv_return_value_void_X)
+        }).into_result().map(|ptr| unsafe { Self::from_raw_ptr(ptr) })
+    }
+}
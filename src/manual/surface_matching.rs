@@ -0,0 +1,42 @@
+use std::mem::MaybeUninit;
+
+use crate::{
+    core::{Matx44d, Vec3d, Vec4d},
+    Result,
+    surface_matching::Pose3D,
+    sys,
+};
+
+/// Copy of the fields of [Pose3D], see [Pose3D::to_value]
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Pose3DValue {
+    pub alpha: f64,
+    pub residual: f64,
+    pub model_index: usize,
+    pub num_votes: usize,
+    /// Rigid transformation as a homogeneous matrix
+    pub pose: Matx44d,
+    pub angle: f64,
+    /// Translation part of `pose`
+    pub t: Vec3d,
+    /// Rotation part of `pose` as a quaternion
+    pub q: Vec4d,
+}
+
+impl Pose3D {
+    /// Copies all the fields of the pose at once, unlike the getters it also gives access to `pose`, `t` and `q` on
+    /// OpenCV 3.2
+    pub fn to_value(&self) -> Result<Pose3DValue> {
+        let me = self.as_raw_Pose3D();
+        let mut out = MaybeUninit::<Pose3DValue>::uninit();
+        let out_ptr = out.as_mut_ptr();
+        cpp!(unsafe [me as "const void*", out_ptr as "ocvrs::Pose3DValue*"] -> sys::cv_return_value_void as "cv_return_value_void" {
+            try {
+                ocvrs::pose3DToValue(me, out_ptr);
+                return { Error::Code::StsOk, NULL };
+            } CVRS_CATCH(cv_return_value_void)
+        }).into_result()
+            .map(|_| unsafe { out.assume_init() })
+    }
+}
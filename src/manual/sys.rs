@@ -1,7 +1,12 @@
-use std::os::raw::c_char;
+use std::any::Any;
+use std::os::raw::{c_char, c_void};
 use std::marker::PhantomData;
+use std::ffi::CString;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::sync::Mutex;
 
-use crate::{Error, Result};
+use crate::{core, Error, Result};
 
 #[repr(C)]
 pub struct cv_return_value<S, O=S> {
@@ -21,3 +26,55 @@ impl<S: Into<O>, O> cv_return_value<S, O> {
         }
     }
 }
+
+impl<O> cv_return_value<crate::types::Unit, O> {
+    /// Converts the result of a Rust callback for returning to C++, the error message is allocated with `malloc`
+    pub fn from_result(res: Result<()>) -> Self {
+        let (error_code, error_msg) = match res {
+            Ok(()) => (0, ptr::null_mut()),
+            Err(e) => {
+                let msg = CString::new(e.message.replace('\0', "")).expect("NUL bytes were removed");
+                (e.code, unsafe { ::libc::strdup(msg.as_ptr()) })
+            }
+        };
+        Self { error_code, error_msg, result: crate::types::Unit([]), _p: PhantomData }
+    }
+}
+
+/// Extracts the message from the payload of a panic caught in a Rust callback
+pub(crate) fn panic_message(panic: &(dyn Any + Send)) -> &str {
+    if let Some(msg) = panic.downcast_ref::<&str>() {
+        msg
+    } else if let Some(msg) = panic.downcast_ref::<String>() {
+        msg
+    } else {
+        "unknown panic"
+    }
+}
+
+/// Boxes a Rust callback to be passed to C++ as the user data of its trampolines, see [call_callback] and
+/// [drop_callback]
+///
+/// The C++ object holding the callback can be shared between threads, the lock serializes the calls.
+pub(crate) fn box_callback<T: ?Sized>(callback: Box<T>) -> *mut c_void {
+    Box::into_raw(Box::new(Mutex::new(callback))) as *mut c_void
+}
+
+/// Calls the callback boxed by [box_callback] from a trampoline, `name` describes the callback in the error messages
+///
+/// Unwinding into C++ is undefined behavior, so a panic is returned as an error and poisons the callback, the
+/// following calls fail without calling it. `callback` must be returned by [box_callback] for the same `T`.
+pub(crate) unsafe fn call_callback<T: ?Sized>(callback: *mut c_void, name: &str, call: impl FnOnce(&mut T) -> Result<()>) -> cv_return_value<crate::types::Unit, ()> {
+    let callback = &*(callback as *const Mutex<Box<T>>);
+    let res = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut callback = callback.lock()
+            .map_err(|_| Error::new(core::StsError, format!("{} was poisoned by an earlier panic", name)))?;
+        call(&mut **callback)
+    })).unwrap_or_else(|e| Err(Error::new(core::StsError, format!("{} panicked: {}", name, panic_message(&*e)))));
+    cv_return_value::from_result(res)
+}
+
+/// Releases the callback boxed by [box_callback] for the same `T`, called by the destructor of the C++ object holding it
+pub(crate) unsafe fn drop_callback<T: ?Sized>(callback: *mut c_void) {
+    let _ = panic::catch_unwind(|| drop(Box::from_raw(callback as *mut Mutex<Box<T>>)));
+}
@@ -0,0 +1,60 @@
+use std::{
+    mem::ManuallyDrop,
+    os::raw::c_void,
+};
+
+use crate::{
+    core::Mat,
+    Result,
+    sys,
+    types::{PtrOfOCRHMMDecoder_ClassifierCallback, VectorOfdouble, VectorOfint},
+};
+
+/// Character classifier implemented in Rust, convert it with
+/// [PtrOfOCRHMMDecoder_ClassifierCallback::from_classifier] to pass it to `OCRHMMDecoder::create()`.
+pub trait CustomClassifier: Send {
+    /// Classifies the single character in `image`, the candidate class ids go to `out_class` and their probabilities
+    /// to `out_confidence`
+    fn eval(&mut self, image: &Mat, out_class: &mut VectorOfint, out_confidence: &mut VectorOfdouble) -> Result<()>;
+}
+
+impl<F: FnMut(&Mat, &mut VectorOfint, &mut VectorOfdouble) -> Result<()> + Send> CustomClassifier for F {
+    #[inline]
+    fn eval(&mut self, image: &Mat, out_class: &mut VectorOfint, out_confidence: &mut VectorOfdouble) -> Result<()> {
+        self(image, out_class, out_confidence)
+    }
+}
+
+// called by ocvrs::RustClassifierCallback from cpp/text.hpp
+extern "C" fn eval_trampoline(classifier: *mut c_void, image: *mut c_void, out_class: *mut c_void, out_confidence: *mut c_void) -> sys::cv_return_value_void {
+    // the arguments are owned by OpenCV, they must not be deleted
+    let image = ManuallyDrop::new(unsafe { Mat::from_raw_ptr(image) });
+    let mut out_class = ManuallyDrop::new(unsafe { VectorOfint::from_raw_ptr(out_class) });
+    let mut out_confidence = ManuallyDrop::new(unsafe { VectorOfdouble::from_raw_ptr(out_confidence) });
+    unsafe {
+        sys::call_callback::<dyn CustomClassifier>(classifier, "Rust classifier", |classifier| {
+            classifier.eval(&image, &mut out_class, &mut out_confidence)
+        })
+    }
+}
+
+extern "C" fn drop_trampoline(classifier: *mut c_void) {
+    unsafe { sys::drop_callback::<dyn CustomClassifier>(classifier) }
+}
+
+impl PtrOfOCRHMMDecoder_ClassifierCallback {
+    /// Wraps a character classifier implemented in Rust to be used with `OCRHMMDecoder::create()`
+    ///
+    /// Errors returned by the classifier and panics inside of it are converted to OpenCV exceptions and returned from
+    /// the `run` methods of the decoder.
+    pub fn from_classifier(classifier: impl CustomClassifier + 'static) -> Result<Self> {
+        let classifier = sys::box_callback(Box::new(classifier) as Box<dyn CustomClassifier>);
+        let eval_fn = eval_trampoline as extern "C" fn(*mut c_void, *mut c_void, *mut c_void, *mut c_void) -> sys::cv_return_value_void;
+        let drop_fn = drop_trampoline as extern "C" fn(*mut c_void);
+        cpp!(unsafe [classifier as "void*", eval_fn as "ocvrs::ClassifierEval", drop_fn as "ocvrs::ClassifierDrop"] -> sys::cv_return_value_void_X as "cv_return_value_void_X" {
+            try {
+                return { Error::Code::StsOk, NULL, ocvrs::newRustClassifierCallback(classifier, eval_fn, drop_fn) };
+            } CVRS_CATCH(cv_return_value_void_X)
+        }).into_result().map(|ptr| unsafe { Self::from_raw_ptr(ptr) })
+    }
+}
@@ -0,0 +1,9 @@
+#[cfg(feature = "opencv-4")]
+pub use self::capture_group::*;
+#[cfg(feature = "futures")]
+pub use self::frame_stream::*;
+
+#[cfg(feature = "opencv-4")]
+mod capture_group;
+#[cfg(feature = "futures")]
+mod frame_stream;
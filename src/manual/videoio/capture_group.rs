@@ -0,0 +1,160 @@
+use crate::{
+    core::{self, Mat, VectorTrait},
+    Error,
+    Result,
+    types::{VectorOfint, VectorOfVideoCapture},
+    videoio::{self, VideoCapture},
+};
+
+/// Outcome of [CaptureGroup::wait_synced]
+#[derive(Debug)]
+pub enum SyncedFrames {
+    /// Matched `(frame, timestamp)` pairs in the order of captures
+    Frames(Vec<(Mat, f64)>),
+    /// A single wait timed out, the frames that haven't been matched yet are kept for the next call
+    Timeout,
+    /// The capture with this index has no more frames
+    EndOfStream(usize),
+}
+
+/// Several captures that are waited on together with `VideoCapture::wait_any()`
+///
+/// The group keeps the captures in a `VectorOfVideoCapture` for waiting and a copy of each one for retrieving the
+/// frames, both copies share the same underlying stream. OpenCV implements `wait_any()` only for some backends
+/// (e.g. V4L), for the others (e.g. video files) every capture is grabbed in turn instead.
+pub struct CaptureGroup {
+    streams: VectorOfVideoCapture,
+    captures: Vec<VideoCapture>,
+    ready_index: VectorOfint,
+    pending: Vec<Option<(Mat, f64)>>,
+}
+
+impl CaptureGroup {
+    /// Creates a group from the opened `captures`, the index of a capture in the group is its index in `captures`
+    pub fn new(captures: Vec<VideoCapture>) -> Result<Self> {
+        if captures.is_empty() {
+            return Err(Error::new(core::StsBadArg, "Capture group needs at least one capture".to_string()));
+        }
+        let len = captures.len();
+        let mut streams = VectorOfVideoCapture::with_capacity(len);
+        for capture in captures {
+            streams.push(capture);
+        }
+        let captures = streams.iter().collect();
+        Ok(Self {
+            streams,
+            captures,
+            ready_index: VectorOfint::new(),
+            pending: (0..len).map(|_| None).collect(),
+        })
+    }
+
+    /// Number of captures in the group
+    pub fn len(&self) -> usize {
+        self.captures.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.captures.is_empty()
+    }
+
+    /// Captures of the group for querying and setting their properties
+    pub fn captures(&self) -> &[VideoCapture] {
+        &self.captures
+    }
+
+    pub fn captures_mut(&mut self) -> &mut [VideoCapture] {
+        &mut self.captures
+    }
+
+    /// Dissolves the group and returns the captures in their original order
+    pub fn into_captures(self) -> Vec<VideoCapture> {
+        self.captures
+    }
+
+    /// Waits until at least one of the captures has a frame and returns all grabbed frames as `(index, frame, timestamp)`
+    ///
+    /// The timestamp is the `CAP_PROP_POS_MSEC` of the capture after retrieving the frame. Captures that reached the
+    /// end of the stream are left out, an empty result means the timeout expired.
+    ///
+    /// ## Parameters
+    /// * timeout_ns: number of nanoseconds to wait, 0 waits indefinitely
+    pub fn wait(&mut self, timeout_ns: i64) -> Result<Vec<(usize, Mat, f64)>> {
+        Ok(self.wait_frames(timeout_ns)?.into_iter()
+            .filter_map(|(index, frame)| frame.map(|(frame, timestamp)| (index, frame, timestamp)))
+            .collect())
+    }
+
+    /// Waits until every capture has a frame and the timestamps of those frames are within `tolerance_ms` of each
+    /// other
+    ///
+    /// Frames that are too old to be matched with the newest frames of other captures are discarded. Frames that
+    /// haven't been matched yet are kept for the next call. [SyncedFrames::Timeout] is returned when any single wait
+    /// times out and [SyncedFrames::EndOfStream] when any capture runs out of frames.
+    ///
+    /// ## Parameters
+    /// * tolerance_ms: maximum difference between the timestamps (`CAP_PROP_POS_MSEC`) of the matched frames
+    /// * timeout_ns: number of nanoseconds for each wait, 0 waits indefinitely
+    pub fn wait_synced(&mut self, tolerance_ms: f64, timeout_ns: i64) -> Result<SyncedFrames> {
+        loop {
+            let frames = self.wait_frames(timeout_ns)?;
+            if frames.is_empty() {
+                return Ok(SyncedFrames::Timeout);
+            }
+            for (index, frame) in frames {
+                match frame {
+                    Some(frame) => self.pending[index] = Some(frame),
+                    None => return Ok(SyncedFrames::EndOfStream(index)),
+                }
+            }
+            let timestamps = self.pending.iter()
+                .map(|frame| frame.as_ref().map(|&(_, timestamp)| timestamp))
+                .collect::<Option<Vec<_>>>();
+            if let Some(timestamps) = timestamps {
+                let newest = timestamps.iter().cloned().fold(f64::MIN, f64::max);
+                let oldest = timestamps.iter().cloned().fold(f64::MAX, f64::min);
+                if newest - oldest <= tolerance_ms {
+                    return Ok(SyncedFrames::Frames(self.pending.iter_mut().filter_map(Option::take).collect()));
+                }
+                for frame in &mut self.pending {
+                    if frame.as_ref().map_or(false, |&(_, timestamp)| newest - timestamp > tolerance_ms) {
+                        *frame = None;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the ready captures with their `(frame, timestamp)` or `None` for the captures at the end of the stream
+    fn wait_frames(&mut self, timeout_ns: i64) -> Result<Vec<(usize, Option<(Mat, f64)>)>> {
+        self.ready_index.clear();
+        let grabbed = match VideoCapture::wait_any(&self.streams, &mut self.ready_index, timeout_ns) {
+            Ok(true) => None,
+            Ok(false) => return Ok(vec![]),
+            Err(Error { code: core::StsNotImplemented, .. }) => {
+                // backend can't wait, so its frames are always ready
+                let mut grabbed = Vec::with_capacity(self.captures.len());
+                for (index, capture) in self.captures.iter_mut().enumerate() {
+                    self.ready_index.push(index as i32);
+                    grabbed.push(capture.grab()?);
+                }
+                Some(grabbed)
+            }
+            Err(e) => return Err(e),
+        };
+        let mut out = Vec::with_capacity(self.ready_index.len());
+        for index in &self.ready_index {
+            let index = index as usize;
+            let capture = self.captures.get_mut(index)
+                .ok_or_else(|| Error::new(core::StsOutOfRange, format!("Ready index: {} is out of bounds of the capture group", index)))?;
+            let mut frame = Mat::default()?;
+            if grabbed.as_ref().map_or(true, |grabbed| grabbed[index]) && capture.retrieve(&mut frame, 0)? {
+                let timestamp = capture.get(videoio::CAP_PROP_POS_MSEC)?;
+                out.push((index, Some((frame, timestamp))));
+            } else {
+                out.push((index, None));
+            }
+        }
+        Ok(out)
+    }
+}
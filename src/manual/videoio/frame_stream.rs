@@ -0,0 +1,137 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    task::{Context, Poll, Waker},
+    thread::{self, JoinHandle},
+};
+
+use futures_core::Stream;
+
+use crate::{
+    core::{self, Mat},
+    Error,
+    Result,
+    videoio::VideoCapture,
+};
+
+/// What to do with a newly grabbed frame when the queue of a [FrameStream] is full
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FrameDropPolicy {
+    /// Discard the oldest queued frame, the consumer always gets the most recent frames
+    DropOldest,
+    /// Discard the newly grabbed frame, the consumer gets the queued frames first
+    DropNewest,
+}
+
+struct Queue {
+    frames: VecDeque<Result<Mat>>,
+    finished: bool,
+    waker: Option<Waker>,
+}
+
+struct Shared {
+    queue: Mutex<Queue>,
+    stop: AtomicBool,
+}
+
+/// `Stream` of the frames read from a `VideoCapture` on a dedicated grabbing thread
+///
+/// The stream ends when the capture runs out of frames. A read error is yielded as the last item of the stream.
+/// Dropping the stream stops the grabbing thread, it waits for the read in progress to finish.
+pub struct FrameStream {
+    shared: Arc<Shared>,
+    thread: Option<JoinHandle<VideoCapture>>,
+}
+
+impl FrameStream {
+    /// Starts reading the frames from `capture`, at most `capacity` frames are queued and `policy` decides which
+    /// frames get dropped when the consumer doesn't keep up
+    pub fn new(capture: VideoCapture, capacity: usize, policy: FrameDropPolicy) -> Result<Self> {
+        if capacity == 0 {
+            return Err(Error::new(core::StsBadArg, "Frame queue capacity must be at least 1".to_string()));
+        }
+        let shared = Arc::new(Shared {
+            queue: Mutex::new(Queue { frames: VecDeque::with_capacity(capacity), finished: false, waker: None }),
+            stop: AtomicBool::new(false),
+        });
+        let thread = thread::Builder::new()
+            .name("opencv-frame-grabber".to_string())
+            .spawn({
+                let shared = Arc::clone(&shared);
+                move || grab_frames(capture, &shared, capacity, policy)
+            })
+            .map_err(|e| Error::new(core::StsError, format!("Can't start frame grabbing thread: {}", e)))?;
+        Ok(Self { shared, thread: Some(thread) })
+    }
+
+    /// Stops the grabbing thread and returns the capture, the queued frames are discarded
+    pub fn stop(mut self) -> Result<VideoCapture> {
+        self.join().expect("Grabbing thread is joined only once")
+    }
+
+    fn join(&mut self) -> Option<Result<VideoCapture>> {
+        self.shared.stop.store(true, Ordering::SeqCst);
+        self.thread.take().map(|thread| thread.join()
+            .map_err(|_| Error::new(core::StsError, "Frame grabbing thread panicked".to_string()))
+        )
+    }
+}
+
+impl Drop for FrameStream {
+    fn drop(&mut self) {
+        let _ = self.join();
+    }
+}
+
+impl Stream for FrameStream {
+    type Item = Result<Mat>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let mut queue = self.shared.queue.lock().unwrap();
+        if let Some(frame) = queue.frames.pop_front() {
+            Poll::Ready(Some(frame))
+        } else if queue.finished {
+            Poll::Ready(None)
+        } else {
+            queue.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+fn grab_frames(mut capture: VideoCapture, shared: &Shared, capacity: usize, policy: FrameDropPolicy) -> VideoCapture {
+    while !shared.stop.load(Ordering::SeqCst) {
+        let frame = Mat::default()
+            .and_then(|mut frame| capture.read(&mut frame).map(|grabbed| if grabbed { Some(frame) } else { None }))
+            .transpose();
+        let finished = if let Some(Ok(_)) = frame { false } else { true };
+        let waker = {
+            let mut queue = shared.queue.lock().unwrap();
+            match frame {
+                // errors are always queued so that the consumer sees why the stream ended
+                Some(frame @ Ok(_)) if queue.frames.len() >= capacity => {
+                    if policy == FrameDropPolicy::DropOldest {
+                        queue.frames.pop_front();
+                        queue.frames.push_back(frame);
+                    }
+                }
+                Some(frame) => queue.frames.push_back(frame),
+                None => {}
+            }
+            queue.finished = finished;
+            queue.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+        if finished {
+            break;
+        }
+    }
+    capture
+}
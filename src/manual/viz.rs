@@ -0,0 +1,125 @@
+use std::{
+    collections::HashMap,
+    mem::ManuallyDrop,
+    os::raw::c_void,
+    panic::{self, AssertUnwindSafe},
+    ptr,
+    sync::{Arc, Mutex},
+};
+
+use once_cell::sync::Lazy;
+
+use crate::{
+    Result,
+    sys,
+    viz::{KeyboardEvent, MouseEvent, Viz3d, Viz3d_KeyboardCallback, Viz3d_MouseCallback},
+};
+
+struct Registration<T: ?Sized> {
+    window_name: String,
+    callback: Arc<Mutex<Box<T>>>,
+}
+
+/// Registered callbacks keyed by the address of the `Viz3d` that registered them, the same address is passed to OpenCV
+/// as the user data
+type CallbackStore<T> = Lazy<Mutex<HashMap<usize, Registration<T>>>>;
+
+static KEYBOARD_CALLBACKS: CallbackStore<Viz3d_KeyboardCallback> = Lazy::new(|| Mutex::new(HashMap::new()));
+static MOUSE_CALLBACKS: CallbackStore<Viz3d_MouseCallback> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Calls the callback registered with `userdata`
+///
+/// The store lock is released before the call, so the callback can register other callbacks and drop `Viz3d`s. The
+/// callback can't report errors to OpenCV and unwinding into C++ is undefined behavior, so a panic is caught after the
+/// panic hook has printed it and the callback is skipped from then on. Reentrant calls, e.g. from `spin_once()` inside
+/// the callback, are skipped too.
+fn call_callback<T: ?Sized>(store: &CallbackStore<T>, userdata: *mut c_void, call: impl FnOnce(&mut T)) {
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+        let callback = store.lock().unwrap().get(&(userdata as usize)).map(|reg| Arc::clone(&reg.callback));
+        if let Some(callback) = callback {
+            if let Ok(mut callback) = callback.try_lock() {
+                call(&mut **callback);
+            }
+        }
+    }));
+}
+
+extern "C" fn keyboard_trampoline(event: *mut c_void, userdata: *mut c_void) {
+    // the event is owned by OpenCV, it must not be deleted
+    let event = ManuallyDrop::new(unsafe { KeyboardEvent::from_raw_ptr(event) });
+    call_callback(&KEYBOARD_CALLBACKS, userdata, |callback| callback(&event));
+}
+
+extern "C" fn mouse_trampoline(event: *mut c_void, userdata: *mut c_void) {
+    let event = ManuallyDrop::new(unsafe { MouseEvent::from_raw_ptr(event) });
+    call_callback(&MOUSE_CALLBACKS, userdata, |callback| callback(&event));
+}
+
+/// Stores or removes the callback of `viz`, returns true if the callback is set
+fn store_callback<T: ?Sized>(store: &CallbackStore<T>, viz: &Viz3d, callback: Option<Box<T>>) -> Result<bool> {
+    let key = viz.as_raw_Viz3d() as usize;
+    let window_name = viz.get_window_name()?;
+    let is_set = callback.is_some();
+    let removed = {
+        let mut store = store.lock().unwrap();
+        // OpenCV keeps one callback per window, so this replaces the callbacks registered through other handles to the same window
+        let replaced = store.iter()
+            .filter(|(&k, reg)| k == key || reg.window_name == window_name)
+            .map(|(&k, _)| k)
+            .collect::<Vec<_>>();
+        let removed = replaced.into_iter().filter_map(|k| store.remove(&k)).collect::<Vec<_>>();
+        if let Some(callback) = callback {
+            store.insert(key, Registration { window_name, callback: Arc::new(Mutex::new(callback)) });
+        }
+        removed
+    };
+    // closures are dropped outside of the lock
+    drop(removed);
+    Ok(is_set)
+}
+
+impl Viz3d {
+    /// Sets keyboard handler, passing `None` removes the current one.
+    ///
+    /// The callback is called from [spin](#method.spin) and [spin_once](#method.spin_once). The window keeps only one
+    /// keyboard callback, so registering through a copy of this `Viz3d` replaces it. The callback is unregistered and
+    /// released when the `Viz3d` that registered it is dropped. A callback that panics is not called again.
+    pub fn register_keyboard_callback(&mut self, callback: Option<Box<Viz3d_KeyboardCallback>>) -> Result<()> {
+        let trampoline = if store_callback(&KEYBOARD_CALLBACKS, self, callback)? {
+            Some(keyboard_trampoline as _)
+        } else {
+            None
+        };
+        unsafe { sys::cv_viz_Viz3d_registerKeyboardCallback_Viz3d_KeyboardCallback_void_X(self.as_raw_Viz3d(), trampoline, self.as_raw_Viz3d()) }.into_result()
+    }
+
+    /// Sets mouse handler, passing `None` removes the current one.
+    ///
+    /// See [register_keyboard_callback](#method.register_keyboard_callback) for the callback lifetime.
+    pub fn register_mouse_callback(&mut self, callback: Option<Box<Viz3d_MouseCallback>>) -> Result<()> {
+        let trampoline = if store_callback(&MOUSE_CALLBACKS, self, callback)? {
+            Some(mouse_trampoline as _)
+        } else {
+            None
+        };
+        unsafe { sys::cv_viz_Viz3d_registerMouseCallback_Viz3d_MouseCallback_void_X(self.as_raw_Viz3d(), trampoline, self.as_raw_Viz3d()) }.into_result()
+    }
+}
+
+impl Drop for Viz3d {
+    fn drop(&mut self) {
+        let key = self.as_raw_Viz3d() as usize;
+        // the window can outlive this handle, so unregister in OpenCV before releasing the closures
+        if KEYBOARD_CALLBACKS.lock().unwrap().contains_key(&key) {
+            unsafe { sys::cv_viz_Viz3d_registerKeyboardCallback_Viz3d_KeyboardCallback_void_X(self.ptr, None, ptr::null_mut()) };
+            let removed = KEYBOARD_CALLBACKS.lock().unwrap().remove(&key);
+            drop(removed);
+        }
+        if MOUSE_CALLBACKS.lock().unwrap().contains_key(&key) {
+            unsafe { sys::cv_viz_Viz3d_registerMouseCallback_Viz3d_MouseCallback_void_X(self.ptr, None, ptr::null_mut()) };
+            let removed = MOUSE_CALLBACKS.lock().unwrap().remove(&key);
+            drop(removed);
+        }
+        unsafe { sys::cv_Viz3d_delete(self.ptr) };
+    }
+}
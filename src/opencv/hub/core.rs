@@ -333,6 +333,8 @@ pub const FileNode_INT: i32 = 1;
 pub const FileNode_MAP: i32 = 5;
 /// the node has a name (i.e. it is element of a mapping).
 pub const FileNode_NAMED: i32 = 32;
+/// empty node
+pub const FileNode_NONE: i32 = 0;
 /// floating-point number
 pub const FileNode_REAL: i32 = 2;
 /// sequence
@@ -341,6 +343,7 @@ pub const FileNode_SEQ: i32 = 4;
 pub const FileNode_STR: i32 = 3;
 /// synonym for STR
 pub const FileNode_STRING: i32 = 3;
+pub const FileNode_TYPE_MASK: i32 = 7;
 /// if set, means that all the collection elements are numbers of the same type (real's or int's).
 pub const FileNode_UNIFORM: i32 = 8;
 /// value, open the file for appending
@@ -451,8 +454,13 @@ pub const PCA_DATA_AS_ROW: i32 = 0;
 pub const PCA_USE_AVG: i32 = 2;
 pub const Param_ALGORITHM: i32 = 6;
 pub const Param_BOOLEAN: i32 = 1;
+pub const Param_FLOAT: i32 = 7;
+pub const Param_INT: i32 = 0;
+pub const Param_MAT: i32 = 4;
 pub const Param_MAT_VECTOR: i32 = 5;
+pub const Param_REAL: i32 = 2;
 pub const Param_SCALAR: i32 = 12;
+pub const Param_STRING: i32 = 3;
 pub const Param_UCHAR: i32 = 11;
 pub const Param_UINT64: i32 = 9;
 pub const Param_UNSIGNED_INT: i32 = 8;
@@ -465,6 +473,7 @@ pub const REDUCE_MIN: i32 = 3;
 /// the output is the sum of all rows/columns of the matrix.
 pub const REDUCE_SUM: i32 = 0;
 pub const RNG_NORMAL: i32 = 1;
+pub const RNG_UNIFORM: i32 = 0;
 /// Rotate 180 degrees clockwise
 pub const ROTATE_180: i32 = 1;
 /// Rotate 90 degrees clockwise
@@ -492,6 +501,7 @@ pub const SVD_MODIFY_A: i32 = 1;
 pub const SVD_NO_UV: i32 = 2;
 pub const SparseMat_HASH_BIT: i32 = 0x80000000;
 pub const SparseMat_HASH_SCALE: i32 = 0x5bd1e995;
+pub const SparseMat_MAGIC_VAL: i32 = 0x42FD0000;
 pub const SparseMat_MAX_DIM: i32 = 32;
 /// assertion failed
 pub const StsAssert: i32 = -215;
@@ -576,6 +586,11 @@ pub const UMatData_HOST_COPY_OBSOLETE: i32 = 2;
 pub const UMatData_TEMP_COPIED_UMAT: i32 = 24;
 pub const UMatData_TEMP_UMAT: i32 = 8;
 pub const UMatData_USER_ALLOCATED: i32 = 32;
+pub const UMat_AUTO_STEP: i32 = 0;
+pub const UMat_DEPTH_MASK: i32 = 7;
+pub const UMat_MAGIC_MASK: i32 = 0xFFFF0000;
+pub const UMat_MAGIC_VAL: i32 = 0x42FF0000;
+pub const UMat_TYPE_MASK: i32 = 0x00000FFF;
 pub const USAGE_ALLOCATE_DEVICE_MEMORY: i32 = 1 << 1;
 pub const USAGE_ALLOCATE_HOST_MEMORY: i32 = 1 << 0;
 pub const USAGE_ALLOCATE_SHARED_MEMORY: i32 = 1 << 2;
@@ -711,6 +726,40 @@ pub enum _OutputArray_DepthMask {
     DEPTH_MASK_FLT = _OutputArray_DEPTH_MASK_FLT as isize,
 }
 
+pub type Affine3d = core::Affine3<f64>;
+pub type Affine3f = core::Affine3<f32>;
+pub type Matx66d = core::Matx<f64, 6, 6>;
+pub type Matx66f = core::Matx<f32, 6, 6>;
+pub type Matx44d = core::Matx<f64, 4, 4>;
+pub type Matx44f = core::Matx<f32, 4, 4>;
+pub type Matx43d = core::Matx<f64, 4, 3>;
+pub type Matx43f = core::Matx<f32, 4, 3>;
+pub type Matx34d = core::Matx<f64, 3, 4>;
+pub type Matx34f = core::Matx<f32, 3, 4>;
+pub type Matx33d = core::Matx<f64, 3, 3>;
+pub type Matx33f = core::Matx<f32, 3, 3>;
+pub type Matx32d = core::Matx<f64, 3, 2>;
+pub type Matx32f = core::Matx<f32, 3, 2>;
+pub type Matx23d = core::Matx<f64, 2, 3>;
+pub type Matx23f = core::Matx<f32, 2, 3>;
+pub type Matx22d = core::Matx<f64, 2, 2>;
+pub type Matx22f = core::Matx<f32, 2, 2>;
+pub type Matx61d = core::Matx<f64, 6, 1>;
+pub type Matx61f = core::Matx<f32, 6, 1>;
+pub type Matx41d = core::Matx<f64, 4, 1>;
+pub type Matx41f = core::Matx<f32, 4, 1>;
+pub type Matx31d = core::Matx<f64, 3, 1>;
+pub type Matx31f = core::Matx<f32, 3, 1>;
+pub type Matx21d = core::Matx<f64, 2, 1>;
+pub type Matx21f = core::Matx<f32, 2, 1>;
+pub type Matx16d = core::Matx<f64, 1, 6>;
+pub type Matx16f = core::Matx<f32, 1, 6>;
+pub type Matx14d = core::Matx<f64, 1, 4>;
+pub type Matx14f = core::Matx<f32, 1, 4>;
+pub type Matx13d = core::Matx<f64, 1, 3>;
+pub type Matx13f = core::Matx<f32, 1, 3>;
+pub type Matx12d = core::Matx<f64, 1, 2>;
+pub type Matx12f = core::Matx<f32, 1, 2>;
 pub type Vec8i = core::Vec8<i32>;
 pub type Vec6d = core::Vec6<f64>;
 pub type Vec6f = core::Vec6<f32>;
@@ -751,40 +800,6 @@ pub type Vec2w = core::Vec2<u16>;
 pub type Vec2s = core::Vec2<i16>;
 pub type Vec2b = core::Vec2<u8>;
 pub type Scalar = core::Scalar_<f64>;
-/// Class for matching keypoint descriptors
-///
-/// query descriptor index, train descriptor index, train image index, and distance between
-/// descriptors.
-#[repr(C)]
-#[derive(Copy, Clone, Debug, PartialEq)]
-pub struct DMatch {
-    pub query_idx: i32,
-    pub train_idx: i32,
-    pub img_idx: i32,
-    pub distance: f32,
-}
-
-/// Data structure for salient point detectors.
-///
-/// The class instance stores a keypoint, i.e. a point feature found by one of many available keypoint
-/// detectors, such as Harris corner detector, #FAST, %StarDetector, %SURF, %SIFT etc.
-///
-/// The keypoint is characterized by the 2D position, scale (proportional to the diameter of the
-/// neighborhood that needs to be taken into account), orientation and some other parameters. The
-/// keypoint neighborhood is then analyzed by another algorithm that builds a descriptor (usually
-/// represented as a feature vector). The keypoints representing the same object in different images
-/// can then be matched using %KDTree or another method.
-#[repr(C)]
-#[derive(Copy, Clone, Debug, PartialEq)]
-pub struct KeyPoint {
-    pub pt: core::Point2f,
-    pub size: f32,
-    pub angle: f32,
-    pub response: f32,
-    pub octave: i32,
-    pub class_id: i32,
-}
-
 /// struct returned by cv::moments
 ///
 /// The spatial moments ![inline formula](https://latex.codecogs.com/png.latex?%5Ctexttt%7BMoments%3A%3Am%7D_%7Bji%7D) are computed as:
@@ -3715,6 +3730,10 @@ pub fn have_svm() -> Result<bool> {
     unsafe { sys::cv_ocl_haveSVM() }.into_result()
 }
 
+pub fn initialize_context_from_handle(ctx: &mut core::Context, platform: &mut c_void, context: &mut c_void, device: &mut c_void) -> Result<()> {
+    unsafe { sys::cv_ocl_initializeContextFromHandle_Context_void_X_void_X_void_X(ctx.as_raw_Context(), platform, context, device) }.into_result()
+}
+
 ///
 /// ## C++ default parameters
 /// * ddepth: -1
@@ -4833,6 +4852,19 @@ pub fn convert_to_va_surface(display: &mut c_void, src: &dyn core::ToInputArray,
     unsafe { sys::cv_va_intel_convertToVASurface_void_X__InputArray_unsigned_int_Size(display, src.as_raw__InputArray(), surface, size) }.into_result()
 }
 
+/// Creates OpenCL context from VA.
+/// ## Parameters
+/// * display: - VADisplay for which CL interop should be established.
+/// * tryInterop: - try to set up for interoperability, if true; set up for use slow copy if false.
+/// ## Returns
+/// Returns reference to OpenCL Context
+///
+/// ## C++ default parameters
+/// * try_interop: true
+pub fn initialize_context_from_va(display: &mut c_void, try_interop: bool) -> Result<core::Context> {
+    unsafe { sys::cv_va_intel_ocl_initializeContextFromVA_void_X_bool(display, try_interop) }.into_result().map(|ptr| core::Context { ptr })
+}
+
 /// Applies vertical concatenation to given matrices.
 ///
 /// The function vertically concatenates two or more cv::Mat matrices (with the same number of cols).
@@ -5545,21 +5577,6 @@ impl ConjGradSolver {
     
 }
 
-impl DMatch {
-    pub fn default() -> Result<core::DMatch> {
-        unsafe { sys::cv_DMatch_DMatch() }.into_result()
-    }
-    
-    pub fn new(_query_idx: i32, _train_idx: i32, _distance: f32) -> Result<core::DMatch> {
-        unsafe { sys::cv_DMatch_DMatch_int_int_float(_query_idx, _train_idx, _distance) }.into_result()
-    }
-    
-    pub fn new_index(_query_idx: i32, _train_idx: i32, _img_idx: i32, _distance: f32) -> Result<core::DMatch> {
-        unsafe { sys::cv_DMatch_DMatch_int_int_int_float(_query_idx, _train_idx, _img_idx, _distance) }.into_result()
-    }
-    
-}
-
 // Generating impl for trait core::DownhillSolver
 /// This class is used to perform the non-linear non-constrained minimization of a function,
 ///
@@ -5714,7 +5731,7 @@ impl FileNode {
     /// ## Returns
     /// Keys of a mapping node.
     pub fn keys(&self) -> Result<types::VectorOfString> {
-        unsafe { sys::cv_FileNode_keys_const(self.as_raw_FileNode()) }.into_result().map(|ptr| types::VectorOfString { ptr })
+        unsafe { sys::cv_FileNode_keys_const(self.as_raw_FileNode()) }.into_result().map(|ptr| unsafe { types::VectorOfString::from_raw_ptr(ptr) })
     }
     
     /// Returns type of the node.
@@ -5887,6 +5904,31 @@ impl FileNodeIterator {
 unsafe impl Send for FileNodeIterator {}
 
 impl FileNodeIterator {
+    /// The constructors.
+    ///
+    /// These constructors are used to create a default iterator, set it to specific element in a file node
+    /// or construct it from another iterator.
+    pub fn default() -> Result<core::FileNodeIterator> {
+        unsafe { sys::cv_FileNodeIterator_FileNodeIterator() }.into_result().map(|ptr| core::FileNodeIterator { ptr })
+    }
+    
+    /// ## Parameters
+    /// * node: File node - the collection to iterate over;
+    /// it can be a scalar (equivalent to 1-element collection) or "none" (equivalent to empty collection).
+    /// * seekEnd: - true if iterator needs to be set after the last element of the node;
+    /// that is:
+    /// node.begin() => FileNodeIterator(node, false)
+    /// node.end() => FileNodeIterator(node, true)
+    pub fn new(node: &core::FileNode, seek_end: bool) -> Result<core::FileNodeIterator> {
+        unsafe { sys::cv_FileNodeIterator_FileNodeIterator_FileNode_bool(node.as_raw_FileNode(), seek_end) }.into_result().map(|ptr| core::FileNodeIterator { ptr })
+    }
+    
+    /// ## Parameters
+    /// * it: Iterator to be used as initialization for the created iterator.
+    pub fn copy(it: &core::FileNodeIterator) -> Result<core::FileNodeIterator> {
+        unsafe { sys::cv_FileNodeIterator_FileNodeIterator_FileNodeIterator(it.as_raw_FileNodeIterator()) }.into_result().map(|ptr| core::FileNodeIterator { ptr })
+    }
+    
     /// Reads node elements to the buffer with the specified format.
     ///
     /// Usually it is more convenient to use operator `>>` instead of this method.
@@ -6197,46 +6239,6 @@ impl Hamming {
 unsafe impl Send for Hamming {}
 
 impl KeyPoint {
-    /// the default constructor
-    pub fn default() -> Result<core::KeyPoint> {
-        unsafe { sys::cv_KeyPoint_KeyPoint() }.into_result()
-    }
-    
-    /// ## Parameters
-    /// * _pt: x & y coordinates of the keypoint
-    /// * _size: keypoint diameter
-    /// * _angle: keypoint orientation
-    /// * _response: keypoint detector response on the keypoint (that is, strength of the keypoint)
-    /// * _octave: pyramid octave in which the keypoint has been detected
-    /// * _class_id: object id
-    ///
-    /// ## C++ default parameters
-    /// * _angle: -1
-    /// * _response: 0
-    /// * _octave: 0
-    /// * _class_id: -1
-    pub fn new_point(_pt: core::Point2f, _size: f32, _angle: f32, _response: f32, _octave: i32, _class_id: i32) -> Result<core::KeyPoint> {
-        unsafe { sys::cv_KeyPoint_KeyPoint_Point2f_float_float_float_int_int(_pt, _size, _angle, _response, _octave, _class_id) }.into_result()
-    }
-    
-    /// ## Parameters
-    /// * x: x-coordinate of the keypoint
-    /// * y: y-coordinate of the keypoint
-    /// * _size: keypoint diameter
-    /// * _angle: keypoint orientation
-    /// * _response: keypoint detector response on the keypoint (that is, strength of the keypoint)
-    /// * _octave: pyramid octave in which the keypoint has been detected
-    /// * _class_id: object id
-    ///
-    /// ## C++ default parameters
-    /// * _angle: -1
-    /// * _response: 0
-    /// * _octave: 0
-    /// * _class_id: -1
-    pub fn new_coords(x: f32, y: f32, _size: f32, _angle: f32, _response: f32, _octave: i32, _class_id: i32) -> Result<core::KeyPoint> {
-        unsafe { sys::cv_KeyPoint_KeyPoint_float_float_float_float_float_int_int(x, y, _size, _angle, _response, _octave, _class_id) }.into_result()
-    }
-    
     pub fn hash(self) -> Result<size_t> {
         unsafe { sys::cv_KeyPoint_hash_const(self) }.into_result()
     }
@@ -6764,8 +6766,8 @@ impl Mat {
     /// associated with it. The reference counter, if any, is incremented. So, when you modify the matrix
     /// formed using such a constructor, you also modify the corresponding elements of m . If you want to
     /// have an independent copy of the sub-array, use Mat::clone() .
-    pub fn copy(m: &core::Mat) -> Result<core::Mat> {
-        unsafe { sys::cv_Mat_Mat_Mat(m.as_raw_Mat()) }.into_result().map(|ptr| core::Mat { ptr })
+    pub unsafe fn copy(m: &core::Mat) -> Result<core::Mat> {
+        { sys::cv_Mat_Mat_Mat(m.as_raw_Mat()) }.into_result().map(|ptr| core::Mat { ptr })
     }
     
     /// ## Parameters
@@ -6826,44 +6828,6 @@ impl Mat {
         unsafe { sys::cv_Mat_Mat_VectorOfint_int_void_X_const_size_t_X(sizes.as_raw_VectorOfint(), _type, data, steps.as_ptr()) }.into_result().map(|ptr| core::Mat { ptr })
     }
     
-    /// ## Parameters
-    /// * m: Array that (as a whole or partly) is assigned to the constructed matrix. No data is copied
-    /// by these constructors. Instead, the header pointing to m data or its sub-array is constructed and
-    /// associated with it. The reference counter, if any, is incremented. So, when you modify the matrix
-    /// formed using such a constructor, you also modify the corresponding elements of m . If you want to
-    /// have an independent copy of the sub-array, use Mat::clone() .
-    /// * rowRange: Range of the m rows to take. As usual, the range start is inclusive and the range
-    /// end is exclusive. Use Range::all() to take all the rows.
-    /// * colRange: Range of the m columns to take. Use Range::all() to take all the columns.
-    ///
-    /// ## C++ default parameters
-    /// * col_range: Range::all()
-    pub fn rowscols(m: &core::Mat, row_range: &core::Range, col_range: &core::Range) -> Result<core::Mat> {
-        unsafe { sys::cv_Mat_Mat_Mat_Range_Range(m.as_raw_Mat(), row_range.as_raw_Range(), col_range.as_raw_Range()) }.into_result().map(|ptr| core::Mat { ptr })
-    }
-    
-    /// ## Parameters
-    /// * m: Array that (as a whole or partly) is assigned to the constructed matrix. No data is copied
-    /// by these constructors. Instead, the header pointing to m data or its sub-array is constructed and
-    /// associated with it. The reference counter, if any, is incremented. So, when you modify the matrix
-    /// formed using such a constructor, you also modify the corresponding elements of m . If you want to
-    /// have an independent copy of the sub-array, use Mat::clone() .
-    /// * roi: Region of interest.
-    pub fn roi(m: &core::Mat, roi: core::Rect) -> Result<core::Mat> {
-        unsafe { sys::cv_Mat_Mat_Mat_Rect(m.as_raw_Mat(), roi) }.into_result().map(|ptr| core::Mat { ptr })
-    }
-    
-    /// ## Parameters
-    /// * m: Array that (as a whole or partly) is assigned to the constructed matrix. No data is copied
-    /// by these constructors. Instead, the header pointing to m data or its sub-array is constructed and
-    /// associated with it. The reference counter, if any, is incremented. So, when you modify the matrix
-    /// formed using such a constructor, you also modify the corresponding elements of m . If you want to
-    /// have an independent copy of the sub-array, use Mat::clone() .
-    /// * ranges: Array of selected ranges of m along each dimensionality.
-    pub fn ranges(m: &core::Mat, ranges: &types::VectorOfRange) -> Result<core::Mat> {
-        unsafe { sys::cv_Mat_Mat_Mat_VectorOfRange(m.as_raw_Mat(), ranges.as_raw_VectorOfRange()) }.into_result().map(|ptr| core::Mat { ptr })
-    }
-    
     /// retrieve UMat from Mat
     ///
     /// ## C++ default parameters
@@ -6872,134 +6836,6 @@ impl Mat {
         unsafe { sys::cv_Mat_getUMat_const_AccessFlag_UMatUsageFlags(self.as_raw_Mat(), access_flags, usage_flags) }.into_result().map(|ptr| core::UMat { ptr })
     }
     
-    /// Creates a matrix header for the specified matrix row.
-    ///
-    /// The method makes a new header for the specified matrix row and returns it. This is an O(1)
-    /// operation, regardless of the matrix size. The underlying data of the new matrix is shared with the
-    /// original matrix. Here is the example of one of the classical basic matrix processing operations,
-    /// axpy, used by LU and many other algorithms:
-    /// ```ignore
-    /// inline void matrix_axpy(Mat& A, int i, int j, double alpha)
-    /// {
-    /// A.row(i) += A.row(j)*alpha;
-    /// }
-    /// ```
-    ///
-    ///
-    /// Note: In the current implementation, the following code does not work as expected:
-    /// ```ignore
-    /// Mat A;
-    /// ...
-    /// A.row(i) = A.row(j); // will not work
-    /// ```
-    ///
-    /// This happens because A.row(i) forms a temporary header that is further assigned to another header.
-    /// Remember that each of these operations is O(1), that is, no data is copied. Thus, the above
-    /// assignment is not true if you may have expected the j-th row to be copied to the i-th row. To
-    /// achieve that, you should either turn this simple assignment into an expression or use the
-    /// Mat::copyTo method:
-    /// ```ignore
-    /// Mat A;
-    /// ...
-    /// // works, but looks a bit obscure.
-    /// A.row(i) = A.row(j) + 0;
-    /// // this is a bit longer, but the recommended method.
-    /// A.row(j).copyTo(A.row(i));
-    /// ```
-    ///
-    /// ## Parameters
-    /// * y: A 0-based row index.
-    pub fn row(&self, y: i32) -> Result<core::Mat> {
-        unsafe { sys::cv_Mat_row_const_int(self.as_raw_Mat(), y) }.into_result().map(|ptr| core::Mat { ptr })
-    }
-    
-    /// Creates a matrix header for the specified matrix column.
-    ///
-    /// The method makes a new header for the specified matrix column and returns it. This is an O(1)
-    /// operation, regardless of the matrix size. The underlying data of the new matrix is shared with the
-    /// original matrix. See also the Mat::row description.
-    /// ## Parameters
-    /// * x: A 0-based column index.
-    pub fn col(&self, x: i32) -> Result<core::Mat> {
-        unsafe { sys::cv_Mat_col_const_int(self.as_raw_Mat(), x) }.into_result().map(|ptr| core::Mat { ptr })
-    }
-    
-    /// Creates a matrix header for the specified row span.
-    ///
-    /// The method makes a new header for the specified row span of the matrix. Similarly to Mat::row and
-    /// Mat::col , this is an O(1) operation.
-    /// ## Parameters
-    /// * startrow: An inclusive 0-based start index of the row span.
-    /// * endrow: An exclusive 0-based ending index of the row span.
-    pub fn row_bounds(&self, startrow: i32, endrow: i32) -> Result<core::Mat> {
-        unsafe { sys::cv_Mat_rowRange_const_int_int(self.as_raw_Mat(), startrow, endrow) }.into_result().map(|ptr| core::Mat { ptr })
-    }
-    
-    /// ## Parameters
-    /// * r: Range structure containing both the start and the end indices.
-    pub fn row_range(&self, r: &core::Range) -> Result<core::Mat> {
-        unsafe { sys::cv_Mat_rowRange_const_Range(self.as_raw_Mat(), r.as_raw_Range()) }.into_result().map(|ptr| core::Mat { ptr })
-    }
-    
-    /// Creates a matrix header for the specified column span.
-    ///
-    /// The method makes a new header for the specified column span of the matrix. Similarly to Mat::row and
-    /// Mat::col , this is an O(1) operation.
-    /// ## Parameters
-    /// * startcol: An inclusive 0-based start index of the column span.
-    /// * endcol: An exclusive 0-based ending index of the column span.
-    pub fn col_bounds(&self, startcol: i32, endcol: i32) -> Result<core::Mat> {
-        unsafe { sys::cv_Mat_colRange_const_int_int(self.as_raw_Mat(), startcol, endcol) }.into_result().map(|ptr| core::Mat { ptr })
-    }
-    
-    /// ## Parameters
-    /// * r: Range structure containing both the start and the end indices.
-    pub fn col_range(&self, r: &core::Range) -> Result<core::Mat> {
-        unsafe { sys::cv_Mat_colRange_const_Range(self.as_raw_Mat(), r.as_raw_Range()) }.into_result().map(|ptr| core::Mat { ptr })
-    }
-    
-    /// Extracts a diagonal from a matrix
-    ///
-    /// The method makes a new header for the specified matrix diagonal. The new matrix is represented as a
-    /// single-column matrix. Similarly to Mat::row and Mat::col, this is an O(1) operation.
-    /// ## Parameters
-    /// * d: index of the diagonal, with the following values:
-    /// - `d=0` is the main diagonal.
-    /// - `d<0` is a diagonal from the lower half. For example, d=-1 means the diagonal is set
-    /// immediately below the main one.
-    /// - `d>0` is a diagonal from the upper half. For example, d=1 means the diagonal is set
-    /// immediately above the main one.
-    /// For example:
-    /// ```ignore
-    /// Mat m = (Mat_<int>(3,3) <<
-    /// 1,2,3,
-    /// 4,5,6,
-    /// 7,8,9);
-    /// Mat d0 = m.diag(0);
-    /// Mat d1 = m.diag(1);
-    /// Mat d_1 = m.diag(-1);
-    /// ```
-    ///
-    /// The resulting matrices are
-    /// ```ignore
-    /// d0 =
-    /// [1;
-    /// 5;
-    /// 9]
-    /// d1 =
-    /// [2;
-    /// 6]
-    /// d_1 =
-    /// [4;
-    /// 8]
-    /// ```
-    ///
-    /// ## C++ default parameters
-    /// * d: 0
-    pub fn diag(&self, d: i32) -> Result<core::Mat> {
-        unsafe { sys::cv_Mat_diag_const_int(self.as_raw_Mat(), d) }.into_result().map(|ptr| core::Mat { ptr })
-    }
-    
     /// creates a diagonal matrix
     ///
     /// The method creates a square diagonal matrix from specified main diagonal.
@@ -7100,42 +6936,6 @@ impl Mat {
         unsafe { sys::cv_Mat_setTo__InputArray__InputArray(self.as_raw_Mat(), value.as_raw__InputArray(), mask.as_raw__InputArray()) }.into_result().map(|ptr| core::Mat { ptr })
     }
     
-    /// Changes the shape and/or the number of channels of a 2D matrix without copying the data.
-    ///
-    /// The method makes a new matrix header for \*this elements. The new matrix may have a different size
-    /// and/or different number of channels. Any combination is possible if:
-    /// *   No extra elements are included into the new matrix and no elements are excluded. Consequently,
-    /// the product rows\*cols\*channels() must stay the same after the transformation.
-    /// *   No data is copied. That is, this is an O(1) operation. Consequently, if you change the number of
-    /// rows, or the operation changes the indices of elements row in some other way, the matrix must be
-    /// continuous. See Mat::isContinuous .
-    ///
-    /// For example, if there is a set of 3D points stored as an STL vector, and you want to represent the
-    /// points as a 3xN matrix, do the following:
-    /// ```ignore
-    /// std::vector<Point3f> vec;
-    /// ...
-    /// Mat pointMat = Mat(vec). // convert vector to Mat, O(1) operation
-    /// reshape(1). // make Nx3 1-channel matrix out of Nx1 3-channel.
-    /// // Also, an O(1) operation
-    /// t(); // finally, transpose the Nx3 matrix.
-    /// // This involves copying all the elements
-    /// ```
-    ///
-    /// ## Parameters
-    /// * cn: New number of channels. If the parameter is 0, the number of channels remains the same.
-    /// * rows: New number of rows. If the parameter is 0, the number of rows remains the same.
-    ///
-    /// ## C++ default parameters
-    /// * rows: 0
-    pub fn reshape(&self, cn: i32, rows: i32) -> Result<core::Mat> {
-        unsafe { sys::cv_Mat_reshape_const_int_int(self.as_raw_Mat(), cn, rows) }.into_result().map(|ptr| core::Mat { ptr })
-    }
-    
-    pub fn reshape_nd(&self, cn: i32, newshape: &types::VectorOfint) -> Result<core::Mat> {
-        unsafe { sys::cv_Mat_reshape_const_int_VectorOfint(self.as_raw_Mat(), cn, newshape.as_raw_VectorOfint()) }.into_result().map(|ptr| core::Mat { ptr })
-    }
-    
     /// Transposes a matrix.
     ///
     /// The method performs matrix transposition by means of matrix expressions. It does not perform the
@@ -7495,8 +7295,8 @@ impl Mat {
     /// * dright: Shift of the right submatrix boundary to the right.
     /// ## See also
     /// copyMakeBorder
-    pub fn adjust_roi(&mut self, dtop: i32, dbottom: i32, dleft: i32, dright: i32) -> Result<core::Mat> {
-        unsafe { sys::cv_Mat_adjustROI_int_int_int_int(self.as_raw_Mat(), dtop, dbottom, dleft, dright) }.into_result().map(|ptr| core::Mat { ptr })
+    pub unsafe fn adjust_roi(&mut self, dtop: i32, dbottom: i32, dleft: i32, dright: i32) -> Result<core::Mat> {
+        { sys::cv_Mat_adjustROI_int_int_int_int(self.as_raw_Mat(), dtop, dbottom, dleft, dright) }.into_result().map(|ptr| core::Mat { ptr })
     }
     
     /// Reports whether the matrix is continuous or not.
@@ -8906,6 +8706,281 @@ impl core::ParallelLoopBody for ParallelLoopBodyLambdaWrapper {
     #[inline(always)] fn as_raw_ParallelLoopBody(&self) -> *mut c_void { self.ptr }
 }
 
+// boxed class cv::RNG
+/// Random Number Generator
+///
+/// Random number generator. It encapsulates the state (currently, a 64-bit
+/// integer) and has methods to return scalar random values and to fill
+/// arrays with random values. Currently it supports uniform and Gaussian
+/// (normal) distributions. The generator uses Multiply-With-Carry
+/// algorithm, introduced by G. Marsaglia (
+/// <http://en.wikipedia.org/wiki/Multiply-with-carry> ).
+/// Gaussian-distribution random numbers are generated using the Ziggurat
+/// algorithm ( <http://en.wikipedia.org/wiki/Ziggurat_algorithm> ),
+/// introduced by G. Marsaglia and W. W. Tsang.
+pub struct RNG {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for RNG {
+    fn drop(&mut self) {
+        unsafe { sys::cv_RNG_delete(self.ptr) };
+    }
+}
+
+impl RNG {
+    #[inline(always)] pub fn as_raw_RNG(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for RNG {}
+
+impl RNG {
+    /// constructor
+    ///
+    /// These are the RNG constructors. The first form sets the state to some
+    /// pre-defined value, equal to 2\*\*32-1 in the current implementation. The
+    /// second form sets the state to the specified value. If you passed state=0
+    /// , the constructor uses the above default value instead to avoid the
+    /// singular random number sequence, consisting of all zeros.
+    pub fn default() -> Result<core::RNG> {
+        unsafe { sys::cv_RNG_RNG() }.into_result().map(|ptr| core::RNG { ptr })
+    }
+    
+    /// ## Parameters
+    /// * state: 64-bit value used to initialize the RNG.
+    pub fn new(state: u64) -> Result<core::RNG> {
+        unsafe { sys::cv_RNG_RNG_uint64(state) }.into_result().map(|ptr| core::RNG { ptr })
+    }
+    
+    /// The method updates the state using the MWC algorithm and returns the
+    /// next 32-bit random number.
+    pub fn next(&mut self) -> Result<u32> {
+        unsafe { sys::cv_RNG_next(self.as_raw_RNG()) }.into_result()
+    }
+    
+    /// Each of the methods updates the state using the MWC algorithm and
+    /// returns the next random number of the specified type. In case of integer
+    /// types, the returned number is from the available value range for the
+    /// specified type. In case of floating-point types, the returned value is
+    /// from [0,1) range.
+    pub fn to_uchar(&mut self) -> Result<u8> {
+        unsafe { sys::cv_RNG_operator_uchar(self.as_raw_RNG()) }.into_result()
+    }
+    
+    pub fn to_schar(&mut self) -> Result<i8> {
+        unsafe { sys::cv_RNG_operator_schar(self.as_raw_RNG()) }.into_result()
+    }
+    
+    pub fn to_ushort(&mut self) -> Result<u16> {
+        unsafe { sys::cv_RNG_operator_ushort(self.as_raw_RNG()) }.into_result()
+    }
+    
+    pub fn to_short(&mut self) -> Result<i16> {
+        unsafe { sys::cv_RNG_operator_short(self.as_raw_RNG()) }.into_result()
+    }
+    
+    pub fn to_unsigned(&mut self) -> Result<u32> {
+        unsafe { sys::cv_RNG_operator_unsigned(self.as_raw_RNG()) }.into_result()
+    }
+    
+    pub fn to_int(&mut self) -> Result<i32> {
+        unsafe { sys::cv_RNG_operator_int(self.as_raw_RNG()) }.into_result()
+    }
+    
+    pub fn to_float(&mut self) -> Result<f32> {
+        unsafe { sys::cv_RNG_operator_float(self.as_raw_RNG()) }.into_result()
+    }
+    
+    pub fn to_double(&mut self) -> Result<f64> {
+        unsafe { sys::cv_RNG_operator_double(self.as_raw_RNG()) }.into_result()
+    }
+    
+    /// returns uniformly distributed integer random number from [a,b) range
+    ///
+    /// The methods transform the state using the MWC algorithm and return the
+    /// next uniformly-distributed random number of the specified type, deduced
+    /// from the input parameter type, from the range [a, b) . There is a nuance
+    /// illustrated by the following sample:
+    ///
+    /// ```ignore
+    /// RNG rng;
+    ///
+    /// // always produces 0
+    /// double a = rng.uniform(0, 1);
+    ///
+    /// // produces double from [0, 1)
+    /// double a1 = rng.uniform((double)0, (double)1);
+    ///
+    /// // produces float from [0, 1)
+    /// float b = rng.uniform(0.f, 1.f);
+    ///
+    /// // produces double from [0, 1)
+    /// double c = rng.uniform(0., 1.);
+    ///
+    /// // may cause compiler error because of ambiguity:
+    /// //  RNG::uniform(0, (int)0.999999)? or RNG::uniform((double)0, 0.99999)?
+    /// double d = rng.uniform(0, 0.999999);
+    /// ```
+    ///
+    ///
+    /// The compiler does not take into account the type of the variable to
+    /// which you assign the result of RNG::uniform . The only thing that
+    /// matters to the compiler is the type of a and b parameters. So, if you
+    /// want a floating-point random number, but the range boundaries are
+    /// integer numbers, either put dots in the end, if they are constants, or
+    /// use explicit type cast operators, as in the a1 initialization above.
+    /// ## Parameters
+    /// * a: lower inclusive boundary of the returned random number.
+    /// * b: upper non-inclusive boundary of the returned random number.
+    pub fn uniform(&mut self, a: i32, b: i32) -> Result<i32> {
+        unsafe { sys::cv_RNG_uniform_int_int(self.as_raw_RNG(), a, b) }.into_result()
+    }
+    
+    pub fn uniform_f32(&mut self, a: f32, b: f32) -> Result<f32> {
+        unsafe { sys::cv_RNG_uniform_float_float(self.as_raw_RNG(), a, b) }.into_result()
+    }
+    
+    pub fn uniform_f64(&mut self, a: f64, b: f64) -> Result<f64> {
+        unsafe { sys::cv_RNG_uniform_double_double(self.as_raw_RNG(), a, b) }.into_result()
+    }
+    
+    /// Fills arrays with random numbers.
+    ///
+    /// ## Parameters
+    /// * mat: 2D or N-dimensional matrix; currently matrices with more than
+    /// 4 channels are not supported by the methods, use Mat::reshape as a
+    /// possible workaround.
+    /// * distType: distribution type, RNG::UNIFORM or RNG::NORMAL.
+    /// * a: first distribution parameter; in case of the uniform
+    /// distribution, this is an inclusive lower boundary, in case of the normal
+    /// distribution, this is a mean value.
+    /// * b: second distribution parameter; in case of the uniform
+    /// distribution, this is a non-inclusive upper boundary, in case of the
+    /// normal distribution, this is a standard deviation (diagonal of the
+    /// standard deviation matrix or the full standard deviation matrix).
+    /// * saturateRange: pre-saturation flag; for uniform distribution only;
+    /// if true, the method will first convert a and b to the acceptable value
+    /// range (according to the mat datatype) and then will generate uniformly
+    /// distributed random numbers within the range [saturate(a), saturate(b)),
+    /// if saturateRange=false, the method will generate uniformly distributed
+    /// random numbers in the original range [a, b) and then will saturate them,
+    /// it means, for example, that
+    /// <tt>theRNG().fill(mat_8u, RNG::UNIFORM, -DBL_MAX, DBL_MAX)</tt> will likely
+    /// produce array mostly filled with 0's and 255's, since the range (0, 255)
+    /// is significantly smaller than [-DBL_MAX, DBL_MAX).
+    ///
+    /// Each of the methods fills the matrix with the random values from the
+    /// specified distribution. As the new numbers are generated, the RNG state
+    /// is updated accordingly. In case of multiple-channel images, every
+    /// channel is filled independently, which means that RNG cannot generate
+    /// samples from the multi-dimensional Gaussian distribution with
+    /// non-diagonal covariance matrix directly. To do that, the method
+    /// generates samples from multi-dimensional standard Gaussian distribution
+    /// with zero mean and identity covariation matrix, and then transforms them
+    /// using transform to get samples from the specified Gaussian distribution.
+    ///
+    /// ## C++ default parameters
+    /// * saturate_range: false
+    pub fn fill(&mut self, mat: &mut dyn core::ToInputOutputArray, dist_type: i32, a: &dyn core::ToInputArray, b: &dyn core::ToInputArray, saturate_range: bool) -> Result<()> {
+        input_output_array_arg!(mat);
+        input_array_arg!(a);
+        input_array_arg!(b);
+        unsafe { sys::cv_RNG_fill__InputOutputArray_int__InputArray__InputArray_bool(self.as_raw_RNG(), mat.as_raw__InputOutputArray(), dist_type, a.as_raw__InputArray(), b.as_raw__InputArray(), saturate_range) }.into_result()
+    }
+    
+    /// Returns the next random number sampled from the Gaussian distribution
+    /// ## Parameters
+    /// * sigma: standard deviation of the distribution.
+    ///
+    /// The method transforms the state using the MWC algorithm and returns the
+    /// next random number from the Gaussian distribution N(0,sigma) . That is,
+    /// the mean value of the returned random numbers is zero and the standard
+    /// deviation is the specified sigma .
+    pub fn gaussian(&mut self, sigma: f64) -> Result<f64> {
+        unsafe { sys::cv_RNG_gaussian_double(self.as_raw_RNG(), sigma) }.into_result()
+    }
+    
+}
+
+// boxed class cv::RNG_MT19937
+/// Mersenne Twister random number generator
+///
+/// Inspired by http://www.math.sci.hiroshima-u.ac.jp/~m-mat/MT/MT2002/CODES/mt19937ar.c
+/// @todo document
+pub struct RNG_MT19937 {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for RNG_MT19937 {
+    fn drop(&mut self) {
+        unsafe { sys::cv_RNG_MT19937_delete(self.ptr) };
+    }
+}
+
+impl RNG_MT19937 {
+    #[inline(always)] pub fn as_raw_RNG_MT19937(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for RNG_MT19937 {}
+
+impl RNG_MT19937 {
+    pub fn default() -> Result<core::RNG_MT19937> {
+        unsafe { sys::cv_RNG_MT19937_RNG_MT19937() }.into_result().map(|ptr| core::RNG_MT19937 { ptr })
+    }
+    
+    pub fn new(s: u32) -> Result<core::RNG_MT19937> {
+        unsafe { sys::cv_RNG_MT19937_RNG_MT19937_unsigned(s) }.into_result().map(|ptr| core::RNG_MT19937 { ptr })
+    }
+    
+    pub fn seed(&mut self, s: u32) -> Result<()> {
+        unsafe { sys::cv_RNG_MT19937_seed_unsigned(self.as_raw_RNG_MT19937(), s) }.into_result()
+    }
+    
+    pub fn next(&mut self) -> Result<u32> {
+        unsafe { sys::cv_RNG_MT19937_next(self.as_raw_RNG_MT19937()) }.into_result()
+    }
+    
+    pub fn to_int(&mut self) -> Result<i32> {
+        unsafe { sys::cv_RNG_MT19937_operator_int(self.as_raw_RNG_MT19937()) }.into_result()
+    }
+    
+    pub fn to_unsigned(&mut self) -> Result<u32> {
+        unsafe { sys::cv_RNG_MT19937_operator_unsigned(self.as_raw_RNG_MT19937()) }.into_result()
+    }
+    
+    pub fn to_float(&mut self) -> Result<f32> {
+        unsafe { sys::cv_RNG_MT19937_operator_float(self.as_raw_RNG_MT19937()) }.into_result()
+    }
+    
+    pub fn to_double(&mut self) -> Result<f64> {
+        unsafe { sys::cv_RNG_MT19937_operator_double(self.as_raw_RNG_MT19937()) }.into_result()
+    }
+    
+    /// returns uniformly distributed integer random number from [a,b) range
+    pub fn uniform(&mut self, a: i32, b: i32) -> Result<i32> {
+        unsafe { sys::cv_RNG_MT19937_uniform_int_int(self.as_raw_RNG_MT19937(), a, b) }.into_result()
+    }
+    
+    /// returns uniformly distributed floating-point random number from [a,b) range
+    pub fn uniform_f32(&mut self, a: f32, b: f32) -> Result<f32> {
+        unsafe { sys::cv_RNG_MT19937_uniform_float_float(self.as_raw_RNG_MT19937(), a, b) }.into_result()
+    }
+    
+    /// returns uniformly distributed double-precision floating-point random number from [a,b) range
+    pub fn uniform_f64(&mut self, a: f64, b: f64) -> Result<f64> {
+        unsafe { sys::cv_RNG_MT19937_uniform_double_double(self.as_raw_RNG_MT19937(), a, b) }.into_result()
+    }
+    
+}
+
 // boxed class cv::Range
 /// Template class specifying a continuous subsequence (slice) of a sequence.
 ///
@@ -9539,6 +9614,24 @@ impl core::SparseMatConstIteratorTrait for SparseMatConstIterator {
     #[inline(always)] fn as_raw_SparseMatConstIterator(&self) -> *mut c_void { self.ptr }
 }
 
+impl SparseMatConstIterator {
+    /// the default constructor
+    pub fn default() -> Result<core::SparseMatConstIterator> {
+        unsafe { sys::cv_SparseMatConstIterator_SparseMatConstIterator() }.into_result().map(|ptr| core::SparseMatConstIterator { ptr })
+    }
+    
+    /// the full constructor setting the iterator to the first sparse matrix element
+    pub fn new(_m: &core::SparseMat) -> Result<core::SparseMatConstIterator> {
+        unsafe { sys::cv_SparseMatConstIterator_SparseMatConstIterator_const_SparseMat(_m.as_raw_SparseMat()) }.into_result().map(|ptr| core::SparseMatConstIterator { ptr })
+    }
+    
+    /// the copy constructor
+    pub fn copy(it: &dyn core::SparseMatConstIteratorTrait) -> Result<core::SparseMatConstIterator> {
+        unsafe { sys::cv_SparseMatConstIterator_SparseMatConstIterator_SparseMatConstIterator(it.as_raw_SparseMatConstIterator()) }.into_result().map(|ptr| core::SparseMatConstIterator { ptr })
+    }
+    
+}
+
 // boxed class cv::SparseMatIterator
 /// Read-write Sparse Matrix Iterator
 ///
@@ -9569,6 +9662,26 @@ impl core::SparseMatConstIteratorTrait for SparseMatIterator {
 }
 
 impl SparseMatIterator {
+    /// the default constructor
+    pub fn default() -> Result<core::SparseMatIterator> {
+        unsafe { sys::cv_SparseMatIterator_SparseMatIterator() }.into_result().map(|ptr| core::SparseMatIterator { ptr })
+    }
+    
+    /// the full constructor setting the iterator to the first sparse matrix element
+    pub fn new(_m: &mut core::SparseMat) -> Result<core::SparseMatIterator> {
+        unsafe { sys::cv_SparseMatIterator_SparseMatIterator_SparseMat(_m.as_raw_SparseMat()) }.into_result().map(|ptr| core::SparseMatIterator { ptr })
+    }
+    
+    /// the full constructor setting the iterator to the specified sparse matrix element
+    pub fn new_1(_m: &mut core::SparseMat, idx: &i32) -> Result<core::SparseMatIterator> {
+        unsafe { sys::cv_SparseMatIterator_SparseMatIterator_SparseMat_const_int_X(_m.as_raw_SparseMat(), idx) }.into_result().map(|ptr| core::SparseMatIterator { ptr })
+    }
+    
+    /// the copy constructor
+    pub fn copy(it: &core::SparseMatIterator) -> Result<core::SparseMatIterator> {
+        unsafe { sys::cv_SparseMatIterator_SparseMatIterator_SparseMatIterator(it.as_raw_SparseMatIterator()) }.into_result().map(|ptr| core::SparseMatIterator { ptr })
+    }
+    
     /// returns pointer to the current sparse matrix node. it.node->idx is the index of the current element (do not modify it!)
     pub fn node(&self) -> Result<core::SparseMat_Node> {
         unsafe { sys::cv_SparseMatIterator_node_const(self.as_raw_SparseMatIterator()) }.into_result().map(|ptr| core::SparseMat_Node { ptr })
@@ -10635,12 +10748,6 @@ pub struct _InputArray {
     #[doc(hidden)] pub(crate) ptr: *mut c_void
 }
 
-impl Drop for _InputArray {
-    fn drop(&mut self) {
-        unsafe { sys::cv__InputArray_delete(self.ptr) };
-    }
-}
-
 impl _InputArray {
     #[inline(always)] pub fn as_raw__InputArray(&self) -> *mut c_void { self.ptr }
 
@@ -11150,6 +11257,13 @@ impl Context {
         unsafe { sys::cv_ocl_Context_device_const_size_t(self.as_raw_Context(), idx) }.into_result().map(|ptr| core::Device { ptr })
     }
     
+    ///
+    /// ## C++ default parameters
+    /// * initialize: true
+    pub fn get_default(initialize: bool) -> Result<core::Context> {
+        unsafe { sys::cv_ocl_Context_getDefault_bool(initialize) }.into_result().map(|ptr| core::Context { ptr })
+    }
+    
     pub fn ptr(&self) -> Result<&mut c_void> {
         unsafe { sys::cv_ocl_Context_ptr_const(self.as_raw_Context()) }.into_result().and_then(|x| unsafe { x.as_mut() }.ok_or_else(|| Error::new(core::StsNullPtr, "Function returned Null pointer".to_string())))
     }
@@ -11892,6 +12006,14 @@ impl Program {
 unsafe impl Send for Program {}
 
 impl Program {
+    pub fn default() -> Result<core::Program> {
+        unsafe { sys::cv_ocl_Program_Program() }.into_result().map(|ptr| core::Program { ptr })
+    }
+    
+    pub fn copy(prog: &core::Program) -> Result<core::Program> {
+        unsafe { sys::cv_ocl_Program_Program_Program(prog.as_raw_Program()) }.into_result().map(|ptr| core::Program { ptr })
+    }
+    
     pub fn ptr(&self) -> Result<&mut c_void> {
         unsafe { sys::cv_ocl_Program_ptr_const(self.as_raw_Program()) }.into_result().and_then(|x| unsafe { x.as_mut() }.ok_or_else(|| Error::new(core::StsNullPtr, "Function returned Null pointer".to_string())))
     }
@@ -11972,6 +12094,10 @@ impl ProgramSource {
         unsafe { sys::cv_ocl_ProgramSource_ProgramSource_String(prog.as_ptr()) }.into_result().map(|ptr| core::ProgramSource { ptr })
     }
     
+    pub fn copy(prog: &core::ProgramSource) -> Result<core::ProgramSource> {
+        unsafe { sys::cv_ocl_ProgramSource_ProgramSource_ProgramSource(prog.as_raw_ProgramSource()) }.into_result().map(|ptr| core::ProgramSource { ptr })
+    }
+    
     pub fn source(&self) -> Result<String> {
         unsafe { sys::cv_ocl_ProgramSource_source_const(self.as_raw_ProgramSource()) }.into_result().map(crate::templ::receive_string)
     }
@@ -12230,6 +12356,8 @@ pub const Device_TYPE_IGPU: i32 = 0x20004; // 131076
 pub const FileStorage_WRITE_BASE64: i32 = 0x41; // 65
 pub const Mat_CONTINUOUS_FLAG: i32 = 0x4000; // 16384
 pub const Mat_SUBMATRIX_FLAG: i32 = 0x8000; // 32768
+pub const UMat_CONTINUOUS_FLAG: i32 = 0x4000; // 16384
+pub const UMat_SUBMATRIX_FLAG: i32 = 0x8000; // 32768
 pub const _InputArray_CUDA_GPU_MAT: i32 = 0x90000; // 589824
 pub const _InputArray_CUDA_HOST_MEM: i32 = 0x80000; // 524288
 pub const _InputArray_EXPR: i32 = 0x60000; // 393216
@@ -508,28 +508,28 @@ pub trait DTrees: crate::ml::StatModel {
     
     /// Returns indices of root nodes
     fn get_roots(&self) -> Result<types::VectorOfint> {
-        unsafe { sys::cv_ml_DTrees_getRoots_const(self.as_raw_DTrees()) }.into_result().map(|ptr| types::VectorOfint { ptr })
+        unsafe { sys::cv_ml_DTrees_getRoots_const(self.as_raw_DTrees()) }.into_result().map(|ptr| unsafe { types::VectorOfint::from_raw_ptr(ptr) })
     }
     
     /// Returns all the nodes
     ///
     /// all the node indices are indices in the returned vector
     fn get_nodes(&self) -> Result<types::VectorOfNode> {
-        unsafe { sys::cv_ml_DTrees_getNodes_const(self.as_raw_DTrees()) }.into_result().map(|ptr| types::VectorOfNode { ptr })
+        unsafe { sys::cv_ml_DTrees_getNodes_const(self.as_raw_DTrees()) }.into_result().map(|ptr| unsafe { types::VectorOfNode::from_raw_ptr(ptr) })
     }
     
     /// Returns all the splits
     ///
     /// all the split indices are indices in the returned vector
     fn get_splits(&self) -> Result<types::VectorOfSplit> {
-        unsafe { sys::cv_ml_DTrees_getSplits_const(self.as_raw_DTrees()) }.into_result().map(|ptr| types::VectorOfSplit { ptr })
+        unsafe { sys::cv_ml_DTrees_getSplits_const(self.as_raw_DTrees()) }.into_result().map(|ptr| unsafe { types::VectorOfSplit::from_raw_ptr(ptr) })
     }
     
     /// Returns all the bitsets for categorical splits
     ///
     /// Split::subsetOfs is an offset in the returned vector
     fn get_subsets(&self) -> Result<types::VectorOfint> {
-        unsafe { sys::cv_ml_DTrees_getSubsets_const(self.as_raw_DTrees()) }.into_result().map(|ptr| types::VectorOfint { ptr })
+        unsafe { sys::cv_ml_DTrees_getSubsets_const(self.as_raw_DTrees()) }.into_result().map(|ptr| unsafe { types::VectorOfint::from_raw_ptr(ptr) })
     }
     
 }
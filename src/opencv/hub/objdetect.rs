@@ -457,6 +457,10 @@ impl DetectionBasedTracker {
 unsafe impl Send for DetectionBasedTracker {}
 
 impl DetectionBasedTracker {
+    pub fn new(main_detector: &types::PtrOfIDetector, tracking_detector: &types::PtrOfIDetector, params: &crate::objdetect::DetectionBasedTracker_Parameters) -> Result<crate::objdetect::DetectionBasedTracker> {
+        unsafe { sys::cv_DetectionBasedTracker_DetectionBasedTracker_PtrOfIDetector_PtrOfIDetector_Parameters(main_detector.as_raw_PtrOfIDetector(), tracking_detector.as_raw_PtrOfIDetector(), params.as_raw_DetectionBasedTracker_Parameters()) }.into_result().map(|ptr| crate::objdetect::DetectionBasedTracker { ptr })
+    }
+    
     pub fn run(&mut self) -> Result<bool> {
         unsafe { sys::cv_DetectionBasedTracker_run(self.as_raw_DetectionBasedTracker()) }.into_result()
     }
@@ -753,7 +757,7 @@ impl HOGDescriptor {
     
     /// coefficients for the linear SVM classifier.
     pub fn svm_detector(&mut self) -> Result<types::VectorOffloat> {
-        unsafe { sys::cv_HOGDescriptor_svmDetector(self.as_raw_HOGDescriptor()) }.into_result().map(|ptr| types::VectorOffloat { ptr })
+        unsafe { sys::cv_HOGDescriptor_svmDetector(self.as_raw_HOGDescriptor()) }.into_result().map(|ptr| unsafe { types::VectorOffloat::from_raw_ptr(ptr) })
     }
     
     /// coefficients for the linear SVM classifier.
@@ -1032,12 +1036,12 @@ impl HOGDescriptor {
     
     /// Returns coefficients of the classifier trained for people detection (for 64x128 windows).
     pub fn get_default_people_detector() -> Result<types::VectorOffloat> {
-        unsafe { sys::cv_HOGDescriptor_getDefaultPeopleDetector() }.into_result().map(|ptr| types::VectorOffloat { ptr })
+        unsafe { sys::cv_HOGDescriptor_getDefaultPeopleDetector() }.into_result().map(|ptr| unsafe { types::VectorOffloat::from_raw_ptr(ptr) })
     }
     
     /// Returns coefficients of the classifier trained for people detection (for 48x96 windows).
     pub fn get_daimler_people_detector() -> Result<types::VectorOffloat> {
-        unsafe { sys::cv_HOGDescriptor_getDaimlerPeopleDetector() }.into_result().map(|ptr| types::VectorOffloat { ptr })
+        unsafe { sys::cv_HOGDescriptor_getDaimlerPeopleDetector() }.into_result().map(|ptr| unsafe { types::VectorOffloat::from_raw_ptr(ptr) })
     }
     
     /// evaluate specified ROI and return confidence value for each location
@@ -1208,3 +1212,4 @@ impl SimilarRects {
     
 }
 
+pub use crate::manual::objdetect::*;
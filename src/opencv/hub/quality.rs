@@ -0,0 +1,440 @@
+//! # Image Quality Analysis (IQA) API
+use crate::{mod_prelude::*, core, sys, types};
+use crate::core::{_InputArrayTrait, _OutputArrayTrait};
+
+
+// boxed class cv::quality::QualityBRISQUE
+/// BRISQUE (Blind/Referenceless Image Spatial Quality Evaluator) is a No Reference Image Quality Assessment (NR-IQA) algorithm.
+///
+/// BRISQUE computes a score based on extracting Natural Scene Statistics (https://en.wikipedia.org/wiki/Scene_statistics)
+/// and calculating feature vectors. See Mittal et al. [Mittal2](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Mittal2) for original paper and original implementation [Mittal2_software](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Mittal2_software) .
+///
+/// A trained model is provided in the /samples/ directory and is trained on the LIVE-R2 database [Sheikh](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Sheikh) as in the original implementation.
+/// When evaluated against the TID2008 database [Ponomarenko](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Ponomarenko) , the SROCC is -0.8424 versus the SROCC of -0.8354 in the original implementation.
+/// C++ code for the BRISQUE LIVE-R2 trainer and TID2008 evaluator are also provided in the /samples/ directory.
+pub struct QualityBRISQUE {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for QualityBRISQUE {
+    fn drop(&mut self) {
+        unsafe { sys::cv_QualityBRISQUE_delete(self.ptr) };
+    }
+}
+
+impl QualityBRISQUE {
+    #[inline(always)] pub fn as_raw_QualityBRISQUE(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for QualityBRISQUE {}
+
+impl core::AlgorithmTrait for QualityBRISQUE {
+    #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::quality::QualityBase for QualityBRISQUE {
+    #[inline(always)] fn as_raw_QualityBase(&self) -> *mut c_void { self.ptr }
+}
+
+impl QualityBRISQUE {
+    /// Computes BRISQUE quality score for input image
+    /// ## Parameters
+    /// * img: Image for which to compute quality
+    /// ## Returns
+    /// cv::Scalar with the score in the first element.  The score ranges from 0 (best quality) to 100 (worst quality)
+    pub fn compute(&mut self, img: &dyn core::ToInputArray) -> Result<core::Scalar> {
+        input_array_arg!(img);
+        unsafe { sys::cv_quality_QualityBRISQUE_compute__InputArray(self.as_raw_QualityBRISQUE(), img.as_raw__InputArray()) }.into_result()
+    }
+    
+    /// Create an object which calculates quality
+    /// ## Parameters
+    /// * model_file_path: cv::String which contains a path to the BRISQUE model data, eg. /path/to/brisque_model_live.yml
+    /// * range_file_path: cv::String which contains a path to the BRISQUE range data, eg. /path/to/brisque_range_live.yml
+    pub fn create(model_file_path: &str, range_file_path: &str) -> Result<types::PtrOfQualityBRISQUE> {
+        string_arg!(model_file_path);
+        string_arg!(range_file_path);
+        unsafe { sys::cv_quality_QualityBRISQUE_create_String_String(model_file_path.as_ptr(), range_file_path.as_ptr()) }.into_result().map(|ptr| types::PtrOfQualityBRISQUE { ptr })
+    }
+    
+    /// static method for computing quality
+    /// ## Parameters
+    /// * img: image for which to compute quality
+    /// * model_file_path: cv::String which contains a path to the BRISQUE model data, eg. /path/to/brisque_model_live.yml
+    /// * range_file_path: cv::String which contains a path to the BRISQUE range data, eg. /path/to/brisque_range_live.yml
+    /// ## Returns
+    /// cv::Scalar with the score in the first element.  The score ranges from 0 (best quality) to 100 (worst quality)
+    pub fn compute_with_model(img: &dyn core::ToInputArray, model_file_path: &str, range_file_path: &str) -> Result<core::Scalar> {
+        input_array_arg!(img);
+        string_arg!(model_file_path);
+        string_arg!(range_file_path);
+        unsafe { sys::cv_quality_QualityBRISQUE_compute__InputArray_String_String(img.as_raw__InputArray(), model_file_path.as_ptr(), range_file_path.as_ptr()) }.into_result()
+    }
+    
+    /// static method for computing image features used by the BRISQUE algorithm
+    /// ## Parameters
+    /// * img: image (BGR(A) or grayscale) for which to compute features
+    /// * features: output row vector of features to cv::Mat or cv::UMat
+    pub fn compute_features(img: &dyn core::ToInputArray, features: &mut dyn core::ToOutputArray) -> Result<()> {
+        input_array_arg!(img);
+        output_array_arg!(features);
+        unsafe { sys::cv_quality_QualityBRISQUE_computeFeatures__InputArray__OutputArray(img.as_raw__InputArray(), features.as_raw__OutputArray()) }.into_result()
+    }
+    
+}
+
+// Generating impl for trait crate::quality::QualityBase
+
+pub trait QualityBase: core::AlgorithmTrait {
+    fn as_raw_QualityBase(&self) -> *mut c_void;
+    /// Compute quality score per channel with the per-channel score in each element of the resulting cv::Scalar.  See specific algorithm for interpreting result scores
+    /// ## Parameters
+    /// * img: comparison image, or image to evalute for no-reference quality algorithms
+    fn compute(&mut self, img: &dyn core::ToInputArray) -> Result<core::Scalar> {
+        input_array_arg!(img);
+        unsafe { sys::cv_quality_QualityBase_compute__InputArray(self.as_raw_QualityBase(), img.as_raw__InputArray()) }.into_result()
+    }
+    
+    /// Returns output quality map that was generated during computation, if supported by the algorithm
+    fn get_quality_map(&self, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+        output_array_arg!(dst);
+        unsafe { sys::cv_quality_QualityBase_getQualityMap_const__OutputArray(self.as_raw_QualityBase(), dst.as_raw__OutputArray()) }.into_result()
+    }
+    
+    /// Implements Algorithm::clear()
+    fn clear(&mut self) -> Result<()> {
+        unsafe { sys::cv_quality_QualityBase_clear(self.as_raw_QualityBase()) }.into_result()
+    }
+    
+    /// Implements Algorithm::empty()
+    fn empty(&self) -> Result<bool> {
+        unsafe { sys::cv_quality_QualityBase_empty_const(self.as_raw_QualityBase()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::quality::QualityGMSD
+/// Full reference GMSD algorithm
+/// http://www4.comp.polyu.edu.hk/~cslzhang/IQA/GMSD/GMSD.htm
+pub struct QualityGMSD {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for QualityGMSD {
+    fn drop(&mut self) {
+        unsafe { sys::cv_QualityGMSD_delete(self.ptr) };
+    }
+}
+
+impl QualityGMSD {
+    #[inline(always)] pub fn as_raw_QualityGMSD(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for QualityGMSD {}
+
+impl core::AlgorithmTrait for QualityGMSD {
+    #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::quality::QualityBase for QualityGMSD {
+    #[inline(always)] fn as_raw_QualityBase(&self) -> *mut c_void { self.ptr }
+}
+
+impl QualityGMSD {
+    /// Compute GMSD
+    /// ## Parameters
+    /// * cmp: comparison image
+    /// ## Returns
+    /// cv::Scalar with per-channel quality value.  Values range from 0 (worst) to 1 (best)
+    pub fn compute(&mut self, cmp: &dyn core::ToInputArray) -> Result<core::Scalar> {
+        input_array_arg!(cmp);
+        unsafe { sys::cv_quality_QualityGMSD_compute__InputArray(self.as_raw_QualityGMSD(), cmp.as_raw__InputArray()) }.into_result()
+    }
+    
+    /// Implements Algorithm::empty()
+    pub fn empty(&self) -> Result<bool> {
+        unsafe { sys::cv_quality_QualityGMSD_empty_const(self.as_raw_QualityGMSD()) }.into_result()
+    }
+    
+    /// Implements Algorithm::clear()
+    pub fn clear(&mut self) -> Result<()> {
+        unsafe { sys::cv_quality_QualityGMSD_clear(self.as_raw_QualityGMSD()) }.into_result()
+    }
+    
+    /// Create an object which calculates image quality
+    /// ## Parameters
+    /// * ref: reference image
+    pub fn create(_ref: &dyn core::ToInputArray) -> Result<types::PtrOfQualityGMSD> {
+        input_array_arg!(_ref);
+        unsafe { sys::cv_quality_QualityGMSD_create__InputArray(_ref.as_raw__InputArray()) }.into_result().map(|ptr| types::PtrOfQualityGMSD { ptr })
+    }
+    
+    /// static method for computing quality
+    /// ## Parameters
+    /// * ref: reference image
+    /// * cmp: comparison image
+    /// * qualityMap: output quality map, or cv::noArray()
+    /// ## Returns
+    /// cv::Scalar with per-channel quality value.  Values range from 0 (worst) to 1 (best)
+    pub fn compute_with_ref(_ref: &dyn core::ToInputArray, cmp: &dyn core::ToInputArray, quality_map: &mut dyn core::ToOutputArray) -> Result<core::Scalar> {
+        input_array_arg!(_ref);
+        input_array_arg!(cmp);
+        output_array_arg!(quality_map);
+        unsafe { sys::cv_quality_QualityGMSD_compute__InputArray__InputArray__OutputArray(_ref.as_raw__InputArray(), cmp.as_raw__InputArray(), quality_map.as_raw__OutputArray()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::quality::QualityMSE
+/// Full reference mean square error algorithm  https://en.wikipedia.org/wiki/Mean_squared_error
+pub struct QualityMSE {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for QualityMSE {
+    fn drop(&mut self) {
+        unsafe { sys::cv_QualityMSE_delete(self.ptr) };
+    }
+}
+
+impl QualityMSE {
+    #[inline(always)] pub fn as_raw_QualityMSE(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for QualityMSE {}
+
+impl core::AlgorithmTrait for QualityMSE {
+    #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::quality::QualityBase for QualityMSE {
+    #[inline(always)] fn as_raw_QualityBase(&self) -> *mut c_void { self.ptr }
+}
+
+impl QualityMSE {
+    /// Computes MSE for reference images supplied in class constructor and provided comparison images
+    /// ## Parameters
+    /// * cmpImgs: Comparison image(s)
+    /// ## Returns
+    /// cv::Scalar with per-channel quality values.  Values range from 0 (best) to potentially max float (worst)
+    pub fn compute(&mut self, cmp_imgs: &dyn core::ToInputArray) -> Result<core::Scalar> {
+        input_array_arg!(cmp_imgs);
+        unsafe { sys::cv_quality_QualityMSE_compute__InputArray(self.as_raw_QualityMSE(), cmp_imgs.as_raw__InputArray()) }.into_result()
+    }
+    
+    /// Implements Algorithm::empty()
+    pub fn empty(&self) -> Result<bool> {
+        unsafe { sys::cv_quality_QualityMSE_empty_const(self.as_raw_QualityMSE()) }.into_result()
+    }
+    
+    /// Implements Algorithm::clear()
+    pub fn clear(&mut self) -> Result<()> {
+        unsafe { sys::cv_quality_QualityMSE_clear(self.as_raw_QualityMSE()) }.into_result()
+    }
+    
+    /// Create an object which calculates quality
+    /// ## Parameters
+    /// * ref: input image to use as the reference for comparison
+    pub fn create(_ref: &dyn core::ToInputArray) -> Result<types::PtrOfQualityMSE> {
+        input_array_arg!(_ref);
+        unsafe { sys::cv_quality_QualityMSE_create__InputArray(_ref.as_raw__InputArray()) }.into_result().map(|ptr| types::PtrOfQualityMSE { ptr })
+    }
+    
+    /// static method for computing quality
+    /// ## Parameters
+    /// * ref: reference image
+    /// * cmp: comparison image=
+    /// * qualityMap: output quality map, or cv::noArray()
+    /// ## Returns
+    /// cv::Scalar with per-channel quality values.  Values range from 0 (best) to max float (worst)
+    pub fn compute_with_ref(_ref: &dyn core::ToInputArray, cmp: &dyn core::ToInputArray, quality_map: &mut dyn core::ToOutputArray) -> Result<core::Scalar> {
+        input_array_arg!(_ref);
+        input_array_arg!(cmp);
+        output_array_arg!(quality_map);
+        unsafe { sys::cv_quality_QualityMSE_compute__InputArray__InputArray__OutputArray(_ref.as_raw__InputArray(), cmp.as_raw__InputArray(), quality_map.as_raw__OutputArray()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::quality::QualityPSNR
+/// Full reference peak signal to noise ratio (PSNR) algorithm  https://en.wikipedia.org/wiki/Peak_signal-to-noise_ratio
+pub struct QualityPSNR {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for QualityPSNR {
+    fn drop(&mut self) {
+        unsafe { sys::cv_QualityPSNR_delete(self.ptr) };
+    }
+}
+
+impl QualityPSNR {
+    #[inline(always)] pub fn as_raw_QualityPSNR(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for QualityPSNR {}
+
+impl core::AlgorithmTrait for QualityPSNR {
+    #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::quality::QualityBase for QualityPSNR {
+    #[inline(always)] fn as_raw_QualityBase(&self) -> *mut c_void { self.ptr }
+}
+
+impl QualityPSNR {
+    /// Create an object which calculates quality
+    /// ## Parameters
+    /// * ref: input image to use as the source for comparison
+    /// * maxPixelValue: maximum per-channel value for any individual pixel; eg 255 for uint8 image
+    ///
+    /// ## C++ default parameters
+    /// * max_pixel_value: QualityPSNR::MAX_PIXEL_VALUE_DEFAULT
+    pub fn create(_ref: &dyn core::ToInputArray, max_pixel_value: f64) -> Result<types::PtrOfQualityPSNR> {
+        input_array_arg!(_ref);
+        unsafe { sys::cv_quality_QualityPSNR_create__InputArray_double(_ref.as_raw__InputArray(), max_pixel_value) }.into_result().map(|ptr| types::PtrOfQualityPSNR { ptr })
+    }
+    
+    /// Compute the PSNR
+    /// ## Parameters
+    /// * cmp: Comparison image
+    /// ## Returns
+    /// Per-channel PSNR value, or std::numeric_limits<double>::infinity() if the MSE between the two images == 0
+    pub fn compute(&mut self, cmp: &dyn core::ToInputArray) -> Result<core::Scalar> {
+        input_array_arg!(cmp);
+        unsafe { sys::cv_quality_QualityPSNR_compute__InputArray(self.as_raw_QualityPSNR(), cmp.as_raw__InputArray()) }.into_result()
+    }
+    
+    /// Implements Algorithm::empty()
+    pub fn empty(&self) -> Result<bool> {
+        unsafe { sys::cv_quality_QualityPSNR_empty_const(self.as_raw_QualityPSNR()) }.into_result()
+    }
+    
+    /// Implements Algorithm::clear()
+    pub fn clear(&mut self) -> Result<()> {
+        unsafe { sys::cv_quality_QualityPSNR_clear(self.as_raw_QualityPSNR()) }.into_result()
+    }
+    
+    /// static method for computing quality
+    /// ## Parameters
+    /// * ref: reference image
+    /// * cmp: comparison image
+    /// * qualityMap: output quality map, or cv::noArray()
+    /// * maxPixelValue: maximum per-channel value for any individual pixel; eg 255 for uint8 image
+    /// ## Returns
+    /// PSNR value, or std::numeric_limits<double>::infinity() if the MSE between the two images == 0
+    ///
+    /// ## C++ default parameters
+    /// * max_pixel_value: QualityPSNR::MAX_PIXEL_VALUE_DEFAULT
+    pub fn compute_with_ref(_ref: &dyn core::ToInputArray, cmp: &dyn core::ToInputArray, quality_map: &mut dyn core::ToOutputArray, max_pixel_value: f64) -> Result<core::Scalar> {
+        input_array_arg!(_ref);
+        input_array_arg!(cmp);
+        output_array_arg!(quality_map);
+        unsafe { sys::cv_quality_QualityPSNR_compute__InputArray__InputArray__OutputArray_double(_ref.as_raw__InputArray(), cmp.as_raw__InputArray(), quality_map.as_raw__OutputArray(), max_pixel_value) }.into_result()
+    }
+    
+    /// return the maximum pixel value used for PSNR computation
+    pub fn get_max_pixel_value(&self) -> Result<f64> {
+        unsafe { sys::cv_quality_QualityPSNR_getMaxPixelValue_const(self.as_raw_QualityPSNR()) }.into_result()
+    }
+    
+    /// sets the maximum pixel value used for PSNR computation
+    /// ## Parameters
+    /// * val: Maximum pixel value
+    pub fn set_max_pixel_value(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_quality_QualityPSNR_setMaxPixelValue_double(self.as_raw_QualityPSNR(), val) }.into_result()
+    }
+    
+}
+
+// boxed class cv::quality::QualitySSIM
+/// Full reference structural similarity algorithm  https://en.wikipedia.org/wiki/Structural_similarity
+pub struct QualitySSIM {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for QualitySSIM {
+    fn drop(&mut self) {
+        unsafe { sys::cv_QualitySSIM_delete(self.ptr) };
+    }
+}
+
+impl QualitySSIM {
+    #[inline(always)] pub fn as_raw_QualitySSIM(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for QualitySSIM {}
+
+impl core::AlgorithmTrait for QualitySSIM {
+    #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::quality::QualityBase for QualitySSIM {
+    #[inline(always)] fn as_raw_QualityBase(&self) -> *mut c_void { self.ptr }
+}
+
+impl QualitySSIM {
+    /// Computes SSIM
+    /// ## Parameters
+    /// * cmp: Comparison image
+    /// ## Returns
+    /// cv::Scalar with per-channel quality values.  Values range from 0 (worst) to 1 (best)
+    pub fn compute(&mut self, cmp: &dyn core::ToInputArray) -> Result<core::Scalar> {
+        input_array_arg!(cmp);
+        unsafe { sys::cv_quality_QualitySSIM_compute__InputArray(self.as_raw_QualitySSIM(), cmp.as_raw__InputArray()) }.into_result()
+    }
+    
+    /// Implements Algorithm::empty()
+    pub fn empty(&self) -> Result<bool> {
+        unsafe { sys::cv_quality_QualitySSIM_empty_const(self.as_raw_QualitySSIM()) }.into_result()
+    }
+    
+    /// Implements Algorithm::clear()
+    pub fn clear(&mut self) -> Result<()> {
+        unsafe { sys::cv_quality_QualitySSIM_clear(self.as_raw_QualitySSIM()) }.into_result()
+    }
+    
+    /// Create an object which calculates quality
+    /// ## Parameters
+    /// * ref: input image to use as the reference image for comparison
+    pub fn create(_ref: &dyn core::ToInputArray) -> Result<types::PtrOfQualitySSIM> {
+        input_array_arg!(_ref);
+        unsafe { sys::cv_quality_QualitySSIM_create__InputArray(_ref.as_raw__InputArray()) }.into_result().map(|ptr| types::PtrOfQualitySSIM { ptr })
+    }
+    
+    /// static method for computing quality
+    /// ## Parameters
+    /// * ref: reference image
+    /// * cmp: comparison image
+    /// * qualityMap: output quality map, or cv::noArray()
+    /// ## Returns
+    /// cv::Scalar with per-channel quality values.  Values range from 0 (worst) to 1 (best)
+    pub fn compute_with_ref(_ref: &dyn core::ToInputArray, cmp: &dyn core::ToInputArray, quality_map: &mut dyn core::ToOutputArray) -> Result<core::Scalar> {
+        input_array_arg!(_ref);
+        input_array_arg!(cmp);
+        output_array_arg!(quality_map);
+        unsafe { sys::cv_quality_QualitySSIM_compute__InputArray__InputArray__OutputArray(_ref.as_raw__InputArray(), cmp.as_raw__InputArray(), quality_map.as_raw__OutputArray()) }.into_result()
+    }
+    
+}
+
@@ -0,0 +1,2831 @@
+//! # RGB-Depth Processing
+use crate::{mod_prelude::*, core, sys, types};
+use crate::core::{_InputArrayTrait, _OutputArrayTrait};
+
+pub const DepthCleaner_DEPTH_CLEANER_NIL: i32 = 0;
+pub const OdometryFrame_CACHE_DST: i32 = 2;
+pub const OdometryFrame_CACHE_SRC: i32 = 1;
+pub const Odometry_RIGID_BODY_MOTION: i32 = 4;
+pub const Odometry_ROTATION: i32 = 1;
+pub const Odometry_TRANSLATION: i32 = 2;
+pub const RgbdNormals_RGBD_NORMALS_METHOD_FALS: i32 = 0;
+pub const RgbdNormals_RGBD_NORMALS_METHOD_LINEMOD: i32 = 1;
+pub const RgbdNormals_RGBD_NORMALS_METHOD_SRI: i32 = 2;
+pub const RgbdPlane_RGBD_PLANE_METHOD_DEFAULT: i32 = 0;
+
+/// \brief Discriminant feature described by its location and label.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Feature {
+    pub x: i32,
+    pub y: i32,
+    pub label: i32,
+}
+
+/// \brief Debug function to colormap a quantized image for viewing.
+pub fn colormap(quantized: &core::Mat, dst: &mut core::Mat) -> Result<()> {
+    unsafe { sys::cv_linemod_colormap_Mat_Mat(quantized.as_raw_Mat(), dst.as_raw_Mat()) }.into_result()
+}
+
+/// \brief Debug function to draw linemod features
+/// ## Parameters
+/// * img:
+/// * templates: see @ref Detector::addTemplate
+/// * tl: template bbox top-left offset see @ref Detector::addTemplate
+/// * size: marker size see @ref cv::drawMarker
+///
+/// ## C++ default parameters
+/// * size: 10
+pub fn draw_features(img: &mut dyn core::ToInputOutputArray, templates: &types::VectorOfTemplate, tl: core::Point2i, size: i32) -> Result<()> {
+    input_output_array_arg!(img);
+    unsafe { sys::cv_linemod_drawFeatures__InputOutputArray_VectorOfTemplate_Point2i_int(img.as_raw__InputOutputArray(), templates.as_raw_VectorOfTemplate(), tl, size) }.into_result()
+}
+
+/// \brief Factory function for detector using LINE algorithm with color gradients.
+///
+/// Default parameter settings suitable for VGA images.
+pub fn get_default_line() -> Result<types::PtrOfDetector> {
+    unsafe { sys::cv_linemod_getDefaultLINE() }.into_result().map(|ptr| types::PtrOfDetector { ptr })
+}
+
+/// \brief Factory function for detector using LINE-MOD algorithm with color gradients
+/// and depth normals.
+///
+/// Default parameter settings suitable for VGA images.
+pub fn get_default_linemod() -> Result<types::PtrOfDetector> {
+    unsafe { sys::cv_linemod_getDefaultLINEMOD() }.into_result().map(|ptr| types::PtrOfDetector { ptr })
+}
+
+/// ## Parameters
+/// * depth: the depth image
+/// * in_K:
+/// * in_points: the list of xy coordinates
+/// * points3d: the resulting 3d points
+pub fn depth_to_3d_sparse(depth: &dyn core::ToInputArray, in_k: &dyn core::ToInputArray, in_points: &dyn core::ToInputArray, points3d: &mut dyn core::ToOutputArray) -> Result<()> {
+    input_array_arg!(depth);
+    input_array_arg!(in_k);
+    input_array_arg!(in_points);
+    output_array_arg!(points3d);
+    unsafe { sys::cv_rgbd_depthTo3dSparse__InputArray__InputArray__InputArray__OutputArray(depth.as_raw__InputArray(), in_k.as_raw__InputArray(), in_points.as_raw__InputArray(), points3d.as_raw__OutputArray()) }.into_result()
+}
+
+/// Converts a depth image to an organized set of 3d points.
+/// The coordinate system is x pointing left, y down and z away from the camera
+/// ## Parameters
+/// * depth: the depth image (if given as short int CV_U, it is assumed to be the depth in millimeters
+///              (as done with the Microsoft Kinect), otherwise, if given as CV_32F or CV_64F, it is assumed in meters)
+/// * K: The calibration matrix
+/// * points3d: the resulting 3d points. They are of depth the same as `depth` if it is CV_32F or CV_64F, and the
+///        depth of `K` if `depth` is of depth CV_U
+/// * mask: the mask of the points to consider (can be empty)
+///
+/// ## C++ default parameters
+/// * mask: noArray()
+pub fn depth_to_3d(depth: &dyn core::ToInputArray, k: &dyn core::ToInputArray, points3d: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray) -> Result<()> {
+    input_array_arg!(depth);
+    input_array_arg!(k);
+    output_array_arg!(points3d);
+    input_array_arg!(mask);
+    unsafe { sys::cv_rgbd_depthTo3d__InputArray__InputArray__OutputArray__InputArray(depth.as_raw__InputArray(), k.as_raw__InputArray(), points3d.as_raw__OutputArray(), mask.as_raw__InputArray()) }.into_result()
+}
+
+pub fn is_valid_depth(depth: &f64) -> Result<bool> {
+    unsafe { sys::cv_rgbd_isValidDepth_double(depth) }.into_result()
+}
+
+/// Checks if the value is a valid depth. For CV_16U or CV_16S, the convention is to be invalid if it is
+/// a limit. For a float/double, we just check if it is a NaN
+/// ## Parameters
+/// * depth: the depth to check for validity
+pub fn is_valid_depth_1(depth: &f32) -> Result<bool> {
+    unsafe { sys::cv_rgbd_isValidDepth_float(depth) }.into_result()
+}
+
+pub fn is_valid_depth_2(depth: &i32) -> Result<bool> {
+    unsafe { sys::cv_rgbd_isValidDepth_int(depth) }.into_result()
+}
+
+pub fn is_valid_depth_3(depth: &u32) -> Result<bool> {
+    unsafe { sys::cv_rgbd_isValidDepth_unsigned_int(depth) }.into_result()
+}
+
+/// Registers depth data to an external camera
+/// Registration is performed by creating a depth cloud, transforming the cloud by
+/// the rigid body transformation between the cameras, and then projecting the
+/// transformed points into the RGB camera.
+///
+/// uv_rgb = K_rgb * [R | t] * z * inv(K_ir) * uv_ir
+///
+/// Currently does not check for negative depth values.
+///
+/// ## Parameters
+/// * unregisteredCameraMatrix: the camera matrix of the depth camera
+/// * registeredCameraMatrix: the camera matrix of the external camera
+/// * registeredDistCoeffs: the distortion coefficients of the external camera
+/// * Rt: the rigid body transform between the cameras. Transforms points from depth camera frame to external camera frame.
+/// * unregisteredDepth: the input depth data
+/// * outputImagePlaneSize: the image plane dimensions of the external camera (width, height)
+/// * registeredDepth: the result of transforming the depth into the external camera
+/// * depthDilation: whether or not the depth is dilated to avoid holes and occlusion errors (optional)
+///
+/// ## C++ default parameters
+/// * depth_dilation: false
+pub fn register_depth(unregistered_camera_matrix: &dyn core::ToInputArray, registered_camera_matrix: &dyn core::ToInputArray, registered_dist_coeffs: &dyn core::ToInputArray, rt: &dyn core::ToInputArray, unregistered_depth: &dyn core::ToInputArray, output_image_plane_size: core::Size, registered_depth: &mut dyn core::ToOutputArray, depth_dilation: bool) -> Result<()> {
+    input_array_arg!(unregistered_camera_matrix);
+    input_array_arg!(registered_camera_matrix);
+    input_array_arg!(registered_dist_coeffs);
+    input_array_arg!(rt);
+    input_array_arg!(unregistered_depth);
+    output_array_arg!(registered_depth);
+    unsafe { sys::cv_rgbd_registerDepth__InputArray__InputArray__InputArray__InputArray__InputArray_Size__OutputArray_bool(unregistered_camera_matrix.as_raw__InputArray(), registered_camera_matrix.as_raw__InputArray(), registered_dist_coeffs.as_raw__InputArray(), rt.as_raw__InputArray(), unregistered_depth.as_raw__InputArray(), output_image_plane_size, registered_depth.as_raw__OutputArray(), depth_dilation) }.into_result()
+}
+
+/// If the input image is of type CV_16UC1 (like the Kinect one), the image is converted to floats, divided
+/// by 1000 to get a depth in meters, and the values 0 are converted to std::numeric_limits<float>::quiet_NaN()
+/// Otherwise, the image is simply converted to floats
+/// ## Parameters
+/// * in: the depth image (if given as short int CV_U, it is assumed to be the depth in millimeters
+///              (as done with the Microsoft Kinect), it is assumed in meters)
+/// * depth: the desired output depth (floats or double)
+/// * out: The rescaled float depth image
+pub fn rescale_depth(_in: &dyn core::ToInputArray, depth: i32, out: &mut dyn core::ToOutputArray) -> Result<()> {
+    input_array_arg!(_in);
+    output_array_arg!(out);
+    unsafe { sys::cv_rgbd_rescaleDepth__InputArray_int__OutputArray(_in.as_raw__InputArray(), depth, out.as_raw__OutputArray()) }.into_result()
+}
+
+/// Warp the image: compute 3d points from the depth, transform them using given transformation,
+/// then project color point cloud to an image plane.
+/// This function can be used to visualize results of the Odometry algorithm.
+/// ## Parameters
+/// * image: The image (of CV_8UC1 or CV_8UC3 type)
+/// * depth: The depth (of type used in depthTo3d fuction)
+/// * mask: The mask of used pixels (of CV_8UC1), it can be empty
+/// * Rt: The transformation that will be applied to the 3d points computed from the depth
+/// * cameraMatrix: Camera matrix
+/// * distCoeff: Distortion coefficients
+/// * warpedImage: The warped image.
+/// * warpedDepth: The warped depth.
+/// * warpedMask: The warped mask.
+///
+/// ## C++ default parameters
+/// * warped_depth: noArray()
+/// * warped_mask: noArray()
+pub fn warp_frame(image: &core::Mat, depth: &core::Mat, mask: &core::Mat, rt: &core::Mat, camera_matrix: &core::Mat, dist_coeff: &core::Mat, warped_image: &mut dyn core::ToOutputArray, warped_depth: &mut dyn core::ToOutputArray, warped_mask: &mut dyn core::ToOutputArray) -> Result<()> {
+    output_array_arg!(warped_image);
+    output_array_arg!(warped_depth);
+    output_array_arg!(warped_mask);
+    unsafe { sys::cv_rgbd_warpFrame_Mat_Mat_Mat_Mat_Mat_Mat__OutputArray__OutputArray__OutputArray(image.as_raw_Mat(), depth.as_raw_Mat(), mask.as_raw_Mat(), rt.as_raw_Mat(), camera_matrix.as_raw_Mat(), dist_coeff.as_raw_Mat(), warped_image.as_raw__OutputArray(), warped_depth.as_raw__OutputArray(), warped_mask.as_raw__OutputArray()) }.into_result()
+}
+
+// Generating impl for trait crate::rgbd::Dynafu_DynaFu
+/// DynamicFusion implementation
+///
+/// This class implements a 3d reconstruction algorithm as described in [dynamicfusion](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_dynamicfusion).
+///
+/// It takes a sequence of depth images taken from depth sensor
+/// (or any depth images source such as stereo camera matching algorithm or even raymarching renderer).
+/// The output can be obtained as a vector of points and their normals
+/// or can be Phong-rendered from given camera pose.
+///
+/// It extends the KinectFusion algorithm to handle non-rigidly deforming scenes by maintaining a sparse
+/// set of nodes covering the geometry such that each node contains a warp to transform it from a canonical
+/// space to the live frame.
+///
+/// An internal representation of a model is a voxel cuboid that keeps TSDF values
+/// which are a sort of distances to the surface (for details read the [kinectfusion](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_kinectfusion) article about TSDF).
+/// There is no interface to that representation yet.
+///
+/// Note that DynamicFusion is based on the KinectFusion algorithm which is patented and its use may be
+/// restricted by the list of patents mentioned in README.md file in this module directory.
+///
+/// That's why you need to set the OPENCV_ENABLE_NONFREE option in CMake to use DynamicFusion.
+pub trait Dynafu_DynaFu {
+    fn as_raw_Dynafu_DynaFu(&self) -> *mut c_void;
+    /// Get current parameters
+    fn get_params(&self) -> Result<crate::rgbd::Dynafu_Params> {
+        unsafe { sys::cv_dynafu_DynaFu_getParams_const(self.as_raw_Dynafu_DynaFu()) }.into_result().map(|ptr| crate::rgbd::Dynafu_Params { ptr })
+    }
+    
+    /// Renders a volume into an image
+    ///
+    /// Renders a 0-surface of TSDF using Phong shading into a CV_8UC4 Mat.
+    /// Light pose is fixed in DynaFu params.
+    ///
+    /// ## Parameters
+    /// * image: resulting image
+    /// * cameraPose: pose of camera to render from. If empty then render from current pose
+    /// which is a last frame camera pose.
+    ///
+    /// ## C++ default parameters
+    /// * camera_pose: Matx44f::eye()
+    fn render(&self, image: &mut dyn core::ToOutputArray, camera_pose: core::Matx44f) -> Result<()> {
+        output_array_arg!(image);
+        unsafe { sys::cv_dynafu_DynaFu_render_const__OutputArray_Matx44f(self.as_raw_Dynafu_DynaFu(), image.as_raw__OutputArray(), camera_pose) }.into_result()
+    }
+    
+    /// Gets points and normals of current 3d mesh
+    ///
+    /// The order of normals corresponds to order of points.
+    /// The order of points is undefined.
+    ///
+    /// ## Parameters
+    /// * points: vector of points which are 4-float vectors
+    /// * normals: vector of normals which are 4-float vectors
+    fn get_cloud(&self, points: &mut dyn core::ToOutputArray, normals: &mut dyn core::ToOutputArray) -> Result<()> {
+        output_array_arg!(points);
+        output_array_arg!(normals);
+        unsafe { sys::cv_dynafu_DynaFu_getCloud_const__OutputArray__OutputArray(self.as_raw_Dynafu_DynaFu(), points.as_raw__OutputArray(), normals.as_raw__OutputArray()) }.into_result()
+    }
+    
+    /// Gets points of current 3d mesh
+    ///
+    /// The order of points is undefined.
+    ///
+    /// ## Parameters
+    /// * points: vector of points which are 4-float vectors
+    fn get_points(&self, points: &mut dyn core::ToOutputArray) -> Result<()> {
+        output_array_arg!(points);
+        unsafe { sys::cv_dynafu_DynaFu_getPoints_const__OutputArray(self.as_raw_Dynafu_DynaFu(), points.as_raw__OutputArray()) }.into_result()
+    }
+    
+    /// Calculates normals for given points
+    /// ## Parameters
+    /// * points: input vector of points which are 4-float vectors
+    /// * normals: output vector of corresponding normals which are 4-float vectors
+    fn get_normals(&self, points: &dyn core::ToInputArray, normals: &mut dyn core::ToOutputArray) -> Result<()> {
+        input_array_arg!(points);
+        output_array_arg!(normals);
+        unsafe { sys::cv_dynafu_DynaFu_getNormals_const__InputArray__OutputArray(self.as_raw_Dynafu_DynaFu(), points.as_raw__InputArray(), normals.as_raw__OutputArray()) }.into_result()
+    }
+    
+    /// Resets the algorithm
+    ///
+    /// Clears current model and resets a pose.
+    fn reset(&mut self) -> Result<()> {
+        unsafe { sys::cv_dynafu_DynaFu_reset(self.as_raw_Dynafu_DynaFu()) }.into_result()
+    }
+    
+    /// Get current pose in voxel space
+    fn get_pose(&self) -> Result<core::Affine3f> {
+        unsafe { sys::cv_dynafu_DynaFu_getPose_const(self.as_raw_Dynafu_DynaFu()) }.into_result()
+    }
+    
+    /// Process next depth frame
+    ///
+    /// Integrates depth into voxel space with respect to its ICP-calculated pose.
+    /// Input image is converted to CV_32F internally if has another type.
+    ///
+    /// ## Parameters
+    /// * depth: one-channel image which size and depth scale is described in algorithm's parameters
+    /// ## Returns
+    /// true if succeeded to align new frame with current scene, false if opposite
+    fn update(&mut self, depth: &dyn core::ToInputArray) -> Result<bool> {
+        input_array_arg!(depth);
+        unsafe { sys::cv_dynafu_DynaFu_update__InputArray(self.as_raw_Dynafu_DynaFu(), depth.as_raw__InputArray()) }.into_result()
+    }
+    
+    fn get_nodes_pos(&self) -> Result<types::VectorOfPoint3f> {
+        unsafe { sys::cv_dynafu_DynaFu_getNodesPos_const(self.as_raw_Dynafu_DynaFu()) }.into_result().map(|ptr| unsafe { types::VectorOfPoint3f::from_raw_ptr(ptr) })
+    }
+    
+    fn march_cubes(&self, vertices: &mut dyn core::ToOutputArray, edges: &mut dyn core::ToOutputArray) -> Result<()> {
+        output_array_arg!(vertices);
+        output_array_arg!(edges);
+        unsafe { sys::cv_dynafu_DynaFu_marchCubes_const__OutputArray__OutputArray(self.as_raw_Dynafu_DynaFu(), vertices.as_raw__OutputArray(), edges.as_raw__OutputArray()) }.into_result()
+    }
+    
+    ///
+    /// ## C++ default parameters
+    /// * warp: true
+    fn render_surface(&mut self, depth_image: &mut dyn core::ToOutputArray, vert_image: &mut dyn core::ToOutputArray, norm_image: &mut dyn core::ToOutputArray, warp: bool) -> Result<()> {
+        output_array_arg!(depth_image);
+        output_array_arg!(vert_image);
+        output_array_arg!(norm_image);
+        unsafe { sys::cv_dynafu_DynaFu_renderSurface__OutputArray__OutputArray__OutputArray_bool(self.as_raw_Dynafu_DynaFu(), depth_image.as_raw__OutputArray(), vert_image.as_raw__OutputArray(), norm_image.as_raw__OutputArray(), warp) }.into_result()
+    }
+    
+}
+
+impl dyn Dynafu_DynaFu + '_ {
+    pub fn create(_params: &types::PtrOfDynafu_Params) -> Result<types::PtrOfDynafu_DynaFu> {
+        unsafe { sys::cv_dynafu_DynaFu_create_PtrOfDynafu_Params(_params.as_raw_PtrOfDynafu_Params()) }.into_result().map(|ptr| types::PtrOfDynafu_DynaFu { ptr })
+    }
+    
+}
+
+// boxed class cv::dynafu::Params
+pub struct Dynafu_Params {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Dynafu_Params {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Dynafu_Params_delete(self.ptr) };
+    }
+}
+
+impl Dynafu_Params {
+    #[inline(always)] pub fn as_raw_Dynafu_Params(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Dynafu_Params {}
+
+impl Dynafu_Params {
+    /// frame size in pixels
+    pub fn frame_size(&self) -> Result<core::Size> {
+        unsafe { sys::cv_dynafu_Params_frameSize_const(self.as_raw_Dynafu_Params()) }.into_result()
+    }
+    
+    /// frame size in pixels
+    pub fn set_frame_size(&mut self, val: core::Size) -> Result<()> {
+        unsafe { sys::cv_dynafu_Params_set_frameSize_Size(self.as_raw_Dynafu_Params(), val) }.into_result()
+    }
+    
+    /// camera intrinsics
+    pub fn intr(&self) -> Result<core::Matx33f> {
+        unsafe { sys::cv_dynafu_Params_intr_const(self.as_raw_Dynafu_Params()) }.into_result()
+    }
+    
+    /// camera intrinsics
+    pub fn set_intr(&mut self, val: core::Matx33f) -> Result<()> {
+        unsafe { sys::cv_dynafu_Params_set_intr_Matx33f(self.as_raw_Dynafu_Params(), val) }.into_result()
+    }
+    
+    /// pre-scale per 1 meter for input values
+    ///
+    /// Typical values are:
+    /// 5000 per 1 meter for the 16-bit PNG files of TUM database
+    /// 1000 per 1 meter for Kinect 2 device
+    /// 1 per 1 meter for the 32-bit float images in the ROS bag files
+    pub fn depth_factor(&self) -> Result<f32> {
+        unsafe { sys::cv_dynafu_Params_depthFactor_const(self.as_raw_Dynafu_Params()) }.into_result()
+    }
+    
+    /// pre-scale per 1 meter for input values
+    ///
+    /// Typical values are:
+    /// 5000 per 1 meter for the 16-bit PNG files of TUM database
+    /// 1000 per 1 meter for Kinect 2 device
+    /// 1 per 1 meter for the 32-bit float images in the ROS bag files
+    pub fn set_depth_factor(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_dynafu_Params_set_depthFactor_float(self.as_raw_Dynafu_Params(), val) }.into_result()
+    }
+    
+    /// Depth sigma in meters for bilateral smooth
+    pub fn bilateral_sigma_depth(&self) -> Result<f32> {
+        unsafe { sys::cv_dynafu_Params_bilateral_sigma_depth_const(self.as_raw_Dynafu_Params()) }.into_result()
+    }
+    
+    /// Depth sigma in meters for bilateral smooth
+    pub fn set_bilateral_sigma_depth(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_dynafu_Params_set_bilateral_sigma_depth_float(self.as_raw_Dynafu_Params(), val) }.into_result()
+    }
+    
+    /// Spatial sigma in pixels for bilateral smooth
+    pub fn bilateral_sigma_spatial(&self) -> Result<f32> {
+        unsafe { sys::cv_dynafu_Params_bilateral_sigma_spatial_const(self.as_raw_Dynafu_Params()) }.into_result()
+    }
+    
+    /// Spatial sigma in pixels for bilateral smooth
+    pub fn set_bilateral_sigma_spatial(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_dynafu_Params_set_bilateral_sigma_spatial_float(self.as_raw_Dynafu_Params(), val) }.into_result()
+    }
+    
+    /// Kernel size in pixels for bilateral smooth
+    pub fn bilateral_kernel_size(&self) -> Result<i32> {
+        unsafe { sys::cv_dynafu_Params_bilateral_kernel_size_const(self.as_raw_Dynafu_Params()) }.into_result()
+    }
+    
+    /// Kernel size in pixels for bilateral smooth
+    pub fn set_bilateral_kernel_size(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_dynafu_Params_set_bilateral_kernel_size_int(self.as_raw_Dynafu_Params(), val) }.into_result()
+    }
+    
+    /// Number of pyramid levels for ICP
+    pub fn pyramid_levels(&self) -> Result<i32> {
+        unsafe { sys::cv_dynafu_Params_pyramidLevels_const(self.as_raw_Dynafu_Params()) }.into_result()
+    }
+    
+    /// Number of pyramid levels for ICP
+    pub fn set_pyramid_levels(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_dynafu_Params_set_pyramidLevels_int(self.as_raw_Dynafu_Params(), val) }.into_result()
+    }
+    
+    /// Resolution of voxel space
+    ///
+    /// Number of voxels in each dimension.
+    pub fn volume_dims(&self) -> Result<core::Vec3i> {
+        unsafe { sys::cv_dynafu_Params_volumeDims_const(self.as_raw_Dynafu_Params()) }.into_result()
+    }
+    
+    /// Resolution of voxel space
+    ///
+    /// Number of voxels in each dimension.
+    pub fn set_volume_dims(&mut self, val: core::Vec3i) -> Result<()> {
+        unsafe { sys::cv_dynafu_Params_set_volumeDims_Vec3i(self.as_raw_Dynafu_Params(), val) }.into_result()
+    }
+    
+    /// Size of voxel in meters
+    pub fn voxel_size(&self) -> Result<f32> {
+        unsafe { sys::cv_dynafu_Params_voxelSize_const(self.as_raw_Dynafu_Params()) }.into_result()
+    }
+    
+    /// Size of voxel in meters
+    pub fn set_voxel_size(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_dynafu_Params_set_voxelSize_float(self.as_raw_Dynafu_Params(), val) }.into_result()
+    }
+    
+    /// Minimal camera movement in meters
+    ///
+    /// Integrate new depth frame only if camera movement exceeds this value.
+    pub fn tsdf_min_camera_movement(&self) -> Result<f32> {
+        unsafe { sys::cv_dynafu_Params_tsdf_min_camera_movement_const(self.as_raw_Dynafu_Params()) }.into_result()
+    }
+    
+    /// Minimal camera movement in meters
+    ///
+    /// Integrate new depth frame only if camera movement exceeds this value.
+    pub fn set_tsdf_min_camera_movement(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_dynafu_Params_set_tsdf_min_camera_movement_float(self.as_raw_Dynafu_Params(), val) }.into_result()
+    }
+    
+    /// distance to truncate in meters
+    ///
+    /// Distances to surface that exceed this value will be truncated to 1.0.
+    pub fn tsdf_trunc_dist(&self) -> Result<f32> {
+        unsafe { sys::cv_dynafu_Params_tsdf_trunc_dist_const(self.as_raw_Dynafu_Params()) }.into_result()
+    }
+    
+    /// distance to truncate in meters
+    ///
+    /// Distances to surface that exceed this value will be truncated to 1.0.
+    pub fn set_tsdf_trunc_dist(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_dynafu_Params_set_tsdf_trunc_dist_float(self.as_raw_Dynafu_Params(), val) }.into_result()
+    }
+    
+    /// max number of frames per voxel
+    ///
+    /// Each voxel keeps running average of distances no longer than this value.
+    pub fn tsdf_max_weight(&self) -> Result<i32> {
+        unsafe { sys::cv_dynafu_Params_tsdf_max_weight_const(self.as_raw_Dynafu_Params()) }.into_result()
+    }
+    
+    /// max number of frames per voxel
+    ///
+    /// Each voxel keeps running average of distances no longer than this value.
+    pub fn set_tsdf_max_weight(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_dynafu_Params_set_tsdf_max_weight_int(self.as_raw_Dynafu_Params(), val) }.into_result()
+    }
+    
+    /// A length of one raycast step
+    ///
+    /// How much voxel sizes we skip each raycast step
+    pub fn raycast_step_factor(&self) -> Result<f32> {
+        unsafe { sys::cv_dynafu_Params_raycast_step_factor_const(self.as_raw_Dynafu_Params()) }.into_result()
+    }
+    
+    /// A length of one raycast step
+    ///
+    /// How much voxel sizes we skip each raycast step
+    pub fn set_raycast_step_factor(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_dynafu_Params_set_raycast_step_factor_float(self.as_raw_Dynafu_Params(), val) }.into_result()
+    }
+    
+    /// light pose for rendering in meters
+    pub fn light_pose(&self) -> Result<core::Vec3f> {
+        unsafe { sys::cv_dynafu_Params_lightPose_const(self.as_raw_Dynafu_Params()) }.into_result()
+    }
+    
+    /// light pose for rendering in meters
+    pub fn set_light_pose(&mut self, val: core::Vec3f) -> Result<()> {
+        unsafe { sys::cv_dynafu_Params_set_lightPose_Vec3f(self.as_raw_Dynafu_Params(), val) }.into_result()
+    }
+    
+    /// distance theshold for ICP in meters
+    pub fn icp_dist_thresh(&self) -> Result<f32> {
+        unsafe { sys::cv_dynafu_Params_icpDistThresh_const(self.as_raw_Dynafu_Params()) }.into_result()
+    }
+    
+    /// distance theshold for ICP in meters
+    pub fn set_icp_dist_thresh(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_dynafu_Params_set_icpDistThresh_float(self.as_raw_Dynafu_Params(), val) }.into_result()
+    }
+    
+    /// angle threshold for ICP in radians
+    pub fn icp_angle_thresh(&self) -> Result<f32> {
+        unsafe { sys::cv_dynafu_Params_icpAngleThresh_const(self.as_raw_Dynafu_Params()) }.into_result()
+    }
+    
+    /// angle threshold for ICP in radians
+    pub fn set_icp_angle_thresh(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_dynafu_Params_set_icpAngleThresh_float(self.as_raw_Dynafu_Params(), val) }.into_result()
+    }
+    
+    /// Threshold for depth truncation in meters
+    ///
+    /// All depth values beyond this threshold will be set to zero
+    pub fn truncate_threshold(&self) -> Result<f32> {
+        unsafe { sys::cv_dynafu_Params_truncateThreshold_const(self.as_raw_Dynafu_Params()) }.into_result()
+    }
+    
+    /// Threshold for depth truncation in meters
+    ///
+    /// All depth values beyond this threshold will be set to zero
+    pub fn set_truncate_threshold(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_dynafu_Params_set_truncateThreshold_float(self.as_raw_Dynafu_Params(), val) }.into_result()
+    }
+    
+    /// Default parameters
+    /// A set of parameters which provides better model quality, can be very slow.
+    pub fn default_params() -> Result<types::PtrOfDynafu_Params> {
+        unsafe { sys::cv_dynafu_Params_defaultParams() }.into_result().map(|ptr| types::PtrOfDynafu_Params { ptr })
+    }
+    
+    /// Coarse parameters
+    /// A set of parameters which provides better speed, can fail to match frames
+    /// in case of rapid sensor motion.
+    pub fn coarse_params() -> Result<types::PtrOfDynafu_Params> {
+        unsafe { sys::cv_dynafu_Params_coarseParams() }.into_result().map(|ptr| types::PtrOfDynafu_Params { ptr })
+    }
+    
+}
+
+// Generating impl for trait crate::rgbd::Kinfu_KinFu
+/// KinectFusion implementation
+///
+/// This class implements a 3d reconstruction algorithm described in
+/// [kinectfusion](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_kinectfusion) paper.
+///
+/// It takes a sequence of depth images taken from depth sensor
+/// (or any depth images source such as stereo camera matching algorithm or even raymarching renderer).
+/// The output can be obtained as a vector of points and their normals
+/// or can be Phong-rendered from given camera pose.
+///
+/// An internal representation of a model is a voxel cuboid that keeps TSDF values
+/// which are a sort of distances to the surface (for details read the [kinectfusion](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_kinectfusion) article about TSDF).
+/// There is no interface to that representation yet.
+///
+/// KinFu uses OpenCL acceleration automatically if available.
+/// To enable or disable it explicitly use cv::setUseOptimized() or cv::ocl::setUseOpenCL().
+///
+/// This implementation is based on [kinfu-remake](https://github.com/Nerei/kinfu_remake).
+///
+/// Note that the KinectFusion algorithm was patented and its use may be restricted by
+/// the list of patents mentioned in README.md file in this module directory.
+///
+/// That's why you need to set the OPENCV_ENABLE_NONFREE option in CMake to use KinectFusion.
+pub trait Kinfu_KinFu {
+    fn as_raw_Kinfu_KinFu(&self) -> *mut c_void;
+    /// Get current parameters
+    fn get_params(&self) -> Result<crate::rgbd::Kinfu_Params> {
+        unsafe { sys::cv_kinfu_KinFu_getParams_const(self.as_raw_Kinfu_KinFu()) }.into_result().map(|ptr| crate::rgbd::Kinfu_Params { ptr })
+    }
+    
+    /// Renders a volume into an image
+    ///
+    /// Renders a 0-surface of TSDF using Phong shading into a CV_8UC4 Mat.
+    /// Light pose is fixed in KinFu params.
+    ///
+    /// ## Parameters
+    /// * image: resulting image
+    /// * cameraPose: pose of camera to render from. If empty then render from current pose
+    /// which is a last frame camera pose.
+    ///
+    /// ## C++ default parameters
+    /// * camera_pose: Matx44f::eye()
+    fn render(&self, image: &mut dyn core::ToOutputArray, camera_pose: core::Matx44f) -> Result<()> {
+        output_array_arg!(image);
+        unsafe { sys::cv_kinfu_KinFu_render_const__OutputArray_Matx44f(self.as_raw_Kinfu_KinFu(), image.as_raw__OutputArray(), camera_pose) }.into_result()
+    }
+    
+    /// Gets points and normals of current 3d mesh
+    ///
+    /// The order of normals corresponds to order of points.
+    /// The order of points is undefined.
+    ///
+    /// ## Parameters
+    /// * points: vector of points which are 4-float vectors
+    /// * normals: vector of normals which are 4-float vectors
+    fn get_cloud(&self, points: &mut dyn core::ToOutputArray, normals: &mut dyn core::ToOutputArray) -> Result<()> {
+        output_array_arg!(points);
+        output_array_arg!(normals);
+        unsafe { sys::cv_kinfu_KinFu_getCloud_const__OutputArray__OutputArray(self.as_raw_Kinfu_KinFu(), points.as_raw__OutputArray(), normals.as_raw__OutputArray()) }.into_result()
+    }
+    
+    /// Gets points of current 3d mesh
+    ///
+    /// The order of points is undefined.
+    ///
+    /// ## Parameters
+    /// * points: vector of points which are 4-float vectors
+    fn get_points(&self, points: &mut dyn core::ToOutputArray) -> Result<()> {
+        output_array_arg!(points);
+        unsafe { sys::cv_kinfu_KinFu_getPoints_const__OutputArray(self.as_raw_Kinfu_KinFu(), points.as_raw__OutputArray()) }.into_result()
+    }
+    
+    /// Calculates normals for given points
+    /// ## Parameters
+    /// * points: input vector of points which are 4-float vectors
+    /// * normals: output vector of corresponding normals which are 4-float vectors
+    fn get_normals(&self, points: &dyn core::ToInputArray, normals: &mut dyn core::ToOutputArray) -> Result<()> {
+        input_array_arg!(points);
+        output_array_arg!(normals);
+        unsafe { sys::cv_kinfu_KinFu_getNormals_const__InputArray__OutputArray(self.as_raw_Kinfu_KinFu(), points.as_raw__InputArray(), normals.as_raw__OutputArray()) }.into_result()
+    }
+    
+    /// Resets the algorithm
+    ///
+    /// Clears current model and resets a pose.
+    fn reset(&mut self) -> Result<()> {
+        unsafe { sys::cv_kinfu_KinFu_reset(self.as_raw_Kinfu_KinFu()) }.into_result()
+    }
+    
+    /// Get current pose in voxel space
+    fn get_pose(&self) -> Result<core::Affine3f> {
+        unsafe { sys::cv_kinfu_KinFu_getPose_const(self.as_raw_Kinfu_KinFu()) }.into_result()
+    }
+    
+    /// Process next depth frame
+    ///
+    /// Integrates depth into voxel space with respect to its ICP-calculated pose.
+    /// Input image is converted to CV_32F internally if has another type.
+    ///
+    /// ## Parameters
+    /// * depth: one-channel image which size and depth scale is described in algorithm's parameters
+    /// ## Returns
+    /// true if succeeded to align new frame with current scene, false if opposite
+    fn update(&mut self, depth: &dyn core::ToInputArray) -> Result<bool> {
+        input_array_arg!(depth);
+        unsafe { sys::cv_kinfu_KinFu_update__InputArray(self.as_raw_Kinfu_KinFu(), depth.as_raw__InputArray()) }.into_result()
+    }
+    
+}
+
+impl dyn Kinfu_KinFu + '_ {
+    pub fn create(_params: &types::PtrOfKinfu_Params) -> Result<types::PtrOfKinfu_KinFu> {
+        unsafe { sys::cv_kinfu_KinFu_create_PtrOfKinfu_Params(_params.as_raw_PtrOfKinfu_Params()) }.into_result().map(|ptr| types::PtrOfKinfu_KinFu { ptr })
+    }
+    
+}
+
+// boxed class cv::kinfu::Params
+pub struct Kinfu_Params {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Kinfu_Params {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Kinfu_Params_delete(self.ptr) };
+    }
+}
+
+impl Kinfu_Params {
+    #[inline(always)] pub fn as_raw_Kinfu_Params(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Kinfu_Params {}
+
+impl Kinfu_Params {
+    /// frame size in pixels
+    pub fn frame_size(&self) -> Result<core::Size> {
+        unsafe { sys::cv_kinfu_Params_frameSize_const(self.as_raw_Kinfu_Params()) }.into_result()
+    }
+    
+    /// frame size in pixels
+    pub fn set_frame_size(&mut self, val: core::Size) -> Result<()> {
+        unsafe { sys::cv_kinfu_Params_set_frameSize_Size(self.as_raw_Kinfu_Params(), val) }.into_result()
+    }
+    
+    /// camera intrinsics
+    pub fn intr(&self) -> Result<core::Matx33f> {
+        unsafe { sys::cv_kinfu_Params_intr_const(self.as_raw_Kinfu_Params()) }.into_result()
+    }
+    
+    /// camera intrinsics
+    pub fn set_intr(&mut self, val: core::Matx33f) -> Result<()> {
+        unsafe { sys::cv_kinfu_Params_set_intr_Matx33f(self.as_raw_Kinfu_Params(), val) }.into_result()
+    }
+    
+    /// pre-scale per 1 meter for input values
+    ///
+    /// Typical values are:
+    /// 5000 per 1 meter for the 16-bit PNG files of TUM database
+    /// 1000 per 1 meter for Kinect 2 device
+    /// 1 per 1 meter for the 32-bit float images in the ROS bag files
+    pub fn depth_factor(&self) -> Result<f32> {
+        unsafe { sys::cv_kinfu_Params_depthFactor_const(self.as_raw_Kinfu_Params()) }.into_result()
+    }
+    
+    /// pre-scale per 1 meter for input values
+    ///
+    /// Typical values are:
+    /// 5000 per 1 meter for the 16-bit PNG files of TUM database
+    /// 1000 per 1 meter for Kinect 2 device
+    /// 1 per 1 meter for the 32-bit float images in the ROS bag files
+    pub fn set_depth_factor(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_kinfu_Params_set_depthFactor_float(self.as_raw_Kinfu_Params(), val) }.into_result()
+    }
+    
+    /// Depth sigma in meters for bilateral smooth
+    pub fn bilateral_sigma_depth(&self) -> Result<f32> {
+        unsafe { sys::cv_kinfu_Params_bilateral_sigma_depth_const(self.as_raw_Kinfu_Params()) }.into_result()
+    }
+    
+    /// Depth sigma in meters for bilateral smooth
+    pub fn set_bilateral_sigma_depth(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_kinfu_Params_set_bilateral_sigma_depth_float(self.as_raw_Kinfu_Params(), val) }.into_result()
+    }
+    
+    /// Spatial sigma in pixels for bilateral smooth
+    pub fn bilateral_sigma_spatial(&self) -> Result<f32> {
+        unsafe { sys::cv_kinfu_Params_bilateral_sigma_spatial_const(self.as_raw_Kinfu_Params()) }.into_result()
+    }
+    
+    /// Spatial sigma in pixels for bilateral smooth
+    pub fn set_bilateral_sigma_spatial(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_kinfu_Params_set_bilateral_sigma_spatial_float(self.as_raw_Kinfu_Params(), val) }.into_result()
+    }
+    
+    /// Kernel size in pixels for bilateral smooth
+    pub fn bilateral_kernel_size(&self) -> Result<i32> {
+        unsafe { sys::cv_kinfu_Params_bilateral_kernel_size_const(self.as_raw_Kinfu_Params()) }.into_result()
+    }
+    
+    /// Kernel size in pixels for bilateral smooth
+    pub fn set_bilateral_kernel_size(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_kinfu_Params_set_bilateral_kernel_size_int(self.as_raw_Kinfu_Params(), val) }.into_result()
+    }
+    
+    /// Number of pyramid levels for ICP
+    pub fn pyramid_levels(&self) -> Result<i32> {
+        unsafe { sys::cv_kinfu_Params_pyramidLevels_const(self.as_raw_Kinfu_Params()) }.into_result()
+    }
+    
+    /// Number of pyramid levels for ICP
+    pub fn set_pyramid_levels(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_kinfu_Params_set_pyramidLevels_int(self.as_raw_Kinfu_Params(), val) }.into_result()
+    }
+    
+    /// Resolution of voxel space
+    ///
+    /// Number of voxels in each dimension.
+    pub fn volume_dims(&self) -> Result<core::Vec3i> {
+        unsafe { sys::cv_kinfu_Params_volumeDims_const(self.as_raw_Kinfu_Params()) }.into_result()
+    }
+    
+    /// Resolution of voxel space
+    ///
+    /// Number of voxels in each dimension.
+    pub fn set_volume_dims(&mut self, val: core::Vec3i) -> Result<()> {
+        unsafe { sys::cv_kinfu_Params_set_volumeDims_Vec3i(self.as_raw_Kinfu_Params(), val) }.into_result()
+    }
+    
+    /// Size of voxel in meters
+    pub fn voxel_size(&self) -> Result<f32> {
+        unsafe { sys::cv_kinfu_Params_voxelSize_const(self.as_raw_Kinfu_Params()) }.into_result()
+    }
+    
+    /// Size of voxel in meters
+    pub fn set_voxel_size(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_kinfu_Params_set_voxelSize_float(self.as_raw_Kinfu_Params(), val) }.into_result()
+    }
+    
+    /// Minimal camera movement in meters
+    ///
+    /// Integrate new depth frame only if camera movement exceeds this value.
+    pub fn tsdf_min_camera_movement(&self) -> Result<f32> {
+        unsafe { sys::cv_kinfu_Params_tsdf_min_camera_movement_const(self.as_raw_Kinfu_Params()) }.into_result()
+    }
+    
+    /// Minimal camera movement in meters
+    ///
+    /// Integrate new depth frame only if camera movement exceeds this value.
+    pub fn set_tsdf_min_camera_movement(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_kinfu_Params_set_tsdf_min_camera_movement_float(self.as_raw_Kinfu_Params(), val) }.into_result()
+    }
+    
+    /// distance to truncate in meters
+    ///
+    /// Distances to surface that exceed this value will be truncated to 1.0.
+    pub fn tsdf_trunc_dist(&self) -> Result<f32> {
+        unsafe { sys::cv_kinfu_Params_tsdf_trunc_dist_const(self.as_raw_Kinfu_Params()) }.into_result()
+    }
+    
+    /// distance to truncate in meters
+    ///
+    /// Distances to surface that exceed this value will be truncated to 1.0.
+    pub fn set_tsdf_trunc_dist(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_kinfu_Params_set_tsdf_trunc_dist_float(self.as_raw_Kinfu_Params(), val) }.into_result()
+    }
+    
+    /// max number of frames per voxel
+    ///
+    /// Each voxel keeps running average of distances no longer than this value.
+    pub fn tsdf_max_weight(&self) -> Result<i32> {
+        unsafe { sys::cv_kinfu_Params_tsdf_max_weight_const(self.as_raw_Kinfu_Params()) }.into_result()
+    }
+    
+    /// max number of frames per voxel
+    ///
+    /// Each voxel keeps running average of distances no longer than this value.
+    pub fn set_tsdf_max_weight(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_kinfu_Params_set_tsdf_max_weight_int(self.as_raw_Kinfu_Params(), val) }.into_result()
+    }
+    
+    /// A length of one raycast step
+    ///
+    /// How much voxel sizes we skip each raycast step
+    pub fn raycast_step_factor(&self) -> Result<f32> {
+        unsafe { sys::cv_kinfu_Params_raycast_step_factor_const(self.as_raw_Kinfu_Params()) }.into_result()
+    }
+    
+    /// A length of one raycast step
+    ///
+    /// How much voxel sizes we skip each raycast step
+    pub fn set_raycast_step_factor(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_kinfu_Params_set_raycast_step_factor_float(self.as_raw_Kinfu_Params(), val) }.into_result()
+    }
+    
+    /// light pose for rendering in meters
+    pub fn light_pose(&self) -> Result<core::Vec3f> {
+        unsafe { sys::cv_kinfu_Params_lightPose_const(self.as_raw_Kinfu_Params()) }.into_result()
+    }
+    
+    /// light pose for rendering in meters
+    pub fn set_light_pose(&mut self, val: core::Vec3f) -> Result<()> {
+        unsafe { sys::cv_kinfu_Params_set_lightPose_Vec3f(self.as_raw_Kinfu_Params(), val) }.into_result()
+    }
+    
+    /// distance theshold for ICP in meters
+    pub fn icp_dist_thresh(&self) -> Result<f32> {
+        unsafe { sys::cv_kinfu_Params_icpDistThresh_const(self.as_raw_Kinfu_Params()) }.into_result()
+    }
+    
+    /// distance theshold for ICP in meters
+    pub fn set_icp_dist_thresh(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_kinfu_Params_set_icpDistThresh_float(self.as_raw_Kinfu_Params(), val) }.into_result()
+    }
+    
+    /// angle threshold for ICP in radians
+    pub fn icp_angle_thresh(&self) -> Result<f32> {
+        unsafe { sys::cv_kinfu_Params_icpAngleThresh_const(self.as_raw_Kinfu_Params()) }.into_result()
+    }
+    
+    /// angle threshold for ICP in radians
+    pub fn set_icp_angle_thresh(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_kinfu_Params_set_icpAngleThresh_float(self.as_raw_Kinfu_Params(), val) }.into_result()
+    }
+    
+    /// Threshold for depth truncation in meters
+    ///
+    /// All depth values beyond this threshold will be set to zero
+    pub fn truncate_threshold(&self) -> Result<f32> {
+        unsafe { sys::cv_kinfu_Params_truncateThreshold_const(self.as_raw_Kinfu_Params()) }.into_result()
+    }
+    
+    /// Threshold for depth truncation in meters
+    ///
+    /// All depth values beyond this threshold will be set to zero
+    pub fn set_truncate_threshold(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_kinfu_Params_set_truncateThreshold_float(self.as_raw_Kinfu_Params(), val) }.into_result()
+    }
+    
+    /// Default parameters
+    /// A set of parameters which provides better model quality, can be very slow.
+    pub fn default_params() -> Result<types::PtrOfKinfu_Params> {
+        unsafe { sys::cv_kinfu_Params_defaultParams() }.into_result().map(|ptr| types::PtrOfKinfu_Params { ptr })
+    }
+    
+    /// Coarse parameters
+    /// A set of parameters which provides better speed, can fail to match frames
+    /// in case of rapid sensor motion.
+    pub fn coarse_params() -> Result<types::PtrOfKinfu_Params> {
+        unsafe { sys::cv_kinfu_Params_coarseParams() }.into_result().map(|ptr| types::PtrOfKinfu_Params { ptr })
+    }
+    
+}
+
+// boxed class cv::linemod::ColorGradient
+/// \brief Modality that computes quantized gradient orientations from a color image.
+pub struct ColorGradient {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for ColorGradient {
+    fn drop(&mut self) {
+        unsafe { sys::cv_ColorGradient_delete(self.ptr) };
+    }
+}
+
+impl ColorGradient {
+    #[inline(always)] pub fn as_raw_ColorGradient(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for ColorGradient {}
+
+impl crate::rgbd::Modality for ColorGradient {
+    #[inline(always)] fn as_raw_Modality(&self) -> *mut c_void { self.ptr }
+}
+
+impl ColorGradient {
+    pub fn weak_threshold(&self) -> Result<f32> {
+        unsafe { sys::cv_linemod_ColorGradient_weak_threshold_const(self.as_raw_ColorGradient()) }.into_result()
+    }
+    
+    pub fn set_weak_threshold(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_linemod_ColorGradient_set_weak_threshold_float(self.as_raw_ColorGradient(), val) }.into_result()
+    }
+    
+    pub fn num_features(&self) -> Result<size_t> {
+        unsafe { sys::cv_linemod_ColorGradient_num_features_const(self.as_raw_ColorGradient()) }.into_result()
+    }
+    
+    pub fn set_num_features(&mut self, val: size_t) -> Result<()> {
+        unsafe { sys::cv_linemod_ColorGradient_set_num_features_size_t(self.as_raw_ColorGradient(), val) }.into_result()
+    }
+    
+    pub fn strong_threshold(&self) -> Result<f32> {
+        unsafe { sys::cv_linemod_ColorGradient_strong_threshold_const(self.as_raw_ColorGradient()) }.into_result()
+    }
+    
+    pub fn set_strong_threshold(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_linemod_ColorGradient_set_strong_threshold_float(self.as_raw_ColorGradient(), val) }.into_result()
+    }
+    
+    /// \brief Default constructor. Uses reasonable default parameter values.
+    pub fn default() -> Result<crate::rgbd::ColorGradient> {
+        unsafe { sys::cv_linemod_ColorGradient_ColorGradient() }.into_result().map(|ptr| crate::rgbd::ColorGradient { ptr })
+    }
+    
+    /// \brief Constructor.
+    ///
+    /// \param weak_threshold   When quantizing, discard gradients with magnitude less than this.
+    /// \param num_features     How many features a template must contain.
+    /// \param strong_threshold Consider as candidate features only gradients whose norms are
+    ///                         larger than this.
+    pub fn new(weak_threshold: f32, num_features: size_t, strong_threshold: f32) -> Result<crate::rgbd::ColorGradient> {
+        unsafe { sys::cv_linemod_ColorGradient_ColorGradient_float_size_t_float(weak_threshold, num_features, strong_threshold) }.into_result().map(|ptr| crate::rgbd::ColorGradient { ptr })
+    }
+    
+    pub fn create(weak_threshold: f32, num_features: size_t, strong_threshold: f32) -> Result<types::PtrOfColorGradient> {
+        unsafe { sys::cv_linemod_ColorGradient_create_float_size_t_float(weak_threshold, num_features, strong_threshold) }.into_result().map(|ptr| types::PtrOfColorGradient { ptr })
+    }
+    
+    pub fn name(&self) -> Result<String> {
+        unsafe { sys::cv_linemod_ColorGradient_name_const(self.as_raw_ColorGradient()) }.into_result().map(crate::templ::receive_string_mut)
+    }
+    
+    pub fn read(&mut self, _fn: &core::FileNode) -> Result<()> {
+        unsafe { sys::cv_linemod_ColorGradient_read_FileNode(self.as_raw_ColorGradient(), _fn.as_raw_FileNode()) }.into_result()
+    }
+    
+    pub fn write(&self, fs: &mut core::FileStorage) -> Result<()> {
+        unsafe { sys::cv_linemod_ColorGradient_write_const_FileStorage(self.as_raw_ColorGradient(), fs.as_raw_FileStorage()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::linemod::DepthNormal
+/// \brief Modality that computes quantized surface normals from a dense depth map.
+pub struct DepthNormal {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for DepthNormal {
+    fn drop(&mut self) {
+        unsafe { sys::cv_DepthNormal_delete(self.ptr) };
+    }
+}
+
+impl DepthNormal {
+    #[inline(always)] pub fn as_raw_DepthNormal(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for DepthNormal {}
+
+impl crate::rgbd::Modality for DepthNormal {
+    #[inline(always)] fn as_raw_Modality(&self) -> *mut c_void { self.ptr }
+}
+
+impl DepthNormal {
+    pub fn distance_threshold(&self) -> Result<i32> {
+        unsafe { sys::cv_linemod_DepthNormal_distance_threshold_const(self.as_raw_DepthNormal()) }.into_result()
+    }
+    
+    pub fn set_distance_threshold(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_linemod_DepthNormal_set_distance_threshold_int(self.as_raw_DepthNormal(), val) }.into_result()
+    }
+    
+    pub fn difference_threshold(&self) -> Result<i32> {
+        unsafe { sys::cv_linemod_DepthNormal_difference_threshold_const(self.as_raw_DepthNormal()) }.into_result()
+    }
+    
+    pub fn set_difference_threshold(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_linemod_DepthNormal_set_difference_threshold_int(self.as_raw_DepthNormal(), val) }.into_result()
+    }
+    
+    pub fn num_features(&self) -> Result<size_t> {
+        unsafe { sys::cv_linemod_DepthNormal_num_features_const(self.as_raw_DepthNormal()) }.into_result()
+    }
+    
+    pub fn set_num_features(&mut self, val: size_t) -> Result<()> {
+        unsafe { sys::cv_linemod_DepthNormal_set_num_features_size_t(self.as_raw_DepthNormal(), val) }.into_result()
+    }
+    
+    pub fn extract_threshold(&self) -> Result<i32> {
+        unsafe { sys::cv_linemod_DepthNormal_extract_threshold_const(self.as_raw_DepthNormal()) }.into_result()
+    }
+    
+    pub fn set_extract_threshold(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_linemod_DepthNormal_set_extract_threshold_int(self.as_raw_DepthNormal(), val) }.into_result()
+    }
+    
+    /// \brief Default constructor. Uses reasonable default parameter values.
+    pub fn default() -> Result<crate::rgbd::DepthNormal> {
+        unsafe { sys::cv_linemod_DepthNormal_DepthNormal() }.into_result().map(|ptr| crate::rgbd::DepthNormal { ptr })
+    }
+    
+    /// \brief Constructor.
+    ///
+    /// \param distance_threshold   Ignore pixels beyond this distance.
+    /// \param difference_threshold When computing normals, ignore contributions of pixels whose
+    ///                             depth difference with the central pixel is above this threshold.
+    /// \param num_features         How many features a template must contain.
+    /// \param extract_threshold    Consider as candidate feature only if there are no differing
+    ///                             orientations within a distance of extract_threshold.
+    pub fn new(distance_threshold: i32, difference_threshold: i32, num_features: size_t, extract_threshold: i32) -> Result<crate::rgbd::DepthNormal> {
+        unsafe { sys::cv_linemod_DepthNormal_DepthNormal_int_int_size_t_int(distance_threshold, difference_threshold, num_features, extract_threshold) }.into_result().map(|ptr| crate::rgbd::DepthNormal { ptr })
+    }
+    
+    pub fn create(distance_threshold: i32, difference_threshold: i32, num_features: size_t, extract_threshold: i32) -> Result<types::PtrOfDepthNormal> {
+        unsafe { sys::cv_linemod_DepthNormal_create_int_int_size_t_int(distance_threshold, difference_threshold, num_features, extract_threshold) }.into_result().map(|ptr| types::PtrOfDepthNormal { ptr })
+    }
+    
+    pub fn name(&self) -> Result<String> {
+        unsafe { sys::cv_linemod_DepthNormal_name_const(self.as_raw_DepthNormal()) }.into_result().map(crate::templ::receive_string_mut)
+    }
+    
+    pub fn read(&mut self, _fn: &core::FileNode) -> Result<()> {
+        unsafe { sys::cv_linemod_DepthNormal_read_FileNode(self.as_raw_DepthNormal(), _fn.as_raw_FileNode()) }.into_result()
+    }
+    
+    pub fn write(&self, fs: &mut core::FileStorage) -> Result<()> {
+        unsafe { sys::cv_linemod_DepthNormal_write_const_FileStorage(self.as_raw_DepthNormal(), fs.as_raw_FileStorage()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::linemod::Detector
+/// \brief Object detector using the LINE template matching algorithm with any set of
+/// modalities.
+pub struct Detector {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detector {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detector_delete(self.ptr) };
+    }
+}
+
+impl Detector {
+    #[inline(always)] pub fn as_raw_Detector(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detector {}
+
+impl Detector {
+    /// \brief Empty constructor, initialize with read().
+    pub fn default() -> Result<crate::rgbd::Detector> {
+        unsafe { sys::cv_linemod_Detector_Detector() }.into_result().map(|ptr| crate::rgbd::Detector { ptr })
+    }
+    
+    /// \brief Constructor.
+    ///
+    /// \param modalities       Modalities to use (color gradients, depth normals, ...).
+    /// \param T_pyramid        Value of the sampling step T at each pyramid level. The
+    ///                         number of pyramid levels is T_pyramid.size().
+    pub fn new(modalities: &types::VectorOfPtrOfModality, t_pyramid: &types::VectorOfint) -> Result<crate::rgbd::Detector> {
+        unsafe { sys::cv_linemod_Detector_Detector_VectorOfPtrOfModality_VectorOfint(modalities.as_raw_VectorOfPtrOfModality(), t_pyramid.as_raw_VectorOfint()) }.into_result().map(|ptr| crate::rgbd::Detector { ptr })
+    }
+    
+    /// \brief Detect objects by template matching.
+    ///
+    /// Matches globally at the lowest pyramid level, then refines locally stepping up the pyramid.
+    ///
+    /// \param      sources   Source images, one for each modality.
+    /// \param      threshold Similarity threshold, a percentage between 0 and 100.
+    /// \param[out] matches   Template matches, sorted by similarity score.
+    /// \param      class_ids If non-empty, only search for the desired object classes.
+    /// \param[out] quantized_images Optionally return vector<Mat> of quantized images.
+    /// \param      masks     The masks for consideration during matching. The masks should be CV_8UC1
+    ///                       where 255 represents a valid pixel.  If non-empty, the vector must be
+    ///                       the same size as sources.  Each element must be
+    ///                       empty or the same size as its corresponding source.
+    ///
+    /// ## C++ default parameters
+    /// * class_ids: std::vector<String>()
+    /// * quantized_images: noArray()
+    /// * masks: std::vector<Mat>()
+    pub fn _match(&self, sources: &types::VectorOfMat, threshold: f32, matches: &mut types::VectorOfMatch, class_ids: &types::VectorOfString, quantized_images: &mut dyn core::ToOutputArray, masks: &types::VectorOfMat) -> Result<()> {
+        output_array_arg!(quantized_images);
+        unsafe { sys::cv_linemod_Detector_match_const_VectorOfMat_float_VectorOfMatch_VectorOfString__OutputArray_VectorOfMat(self.as_raw_Detector(), sources.as_raw_VectorOfMat(), threshold, matches.as_raw_VectorOfMatch(), class_ids.as_raw_VectorOfString(), quantized_images.as_raw__OutputArray(), masks.as_raw_VectorOfMat()) }.into_result()
+    }
+    
+    /// \brief Add new object template.
+    ///
+    /// \param      sources      Source images, one for each modality.
+    /// \param      class_id     Object class ID.
+    /// \param      object_mask  Mask separating object from background.
+    /// \param[out] bounding_box Optionally return bounding box of the extracted features.
+    ///
+    /// \return Template ID, or -1 if failed to extract a valid template.
+    ///
+    /// ## C++ default parameters
+    /// * bounding_box: NULL
+    pub fn add_template(&mut self, sources: &types::VectorOfMat, class_id: &str, object_mask: &core::Mat, bounding_box: &mut core::Rect) -> Result<i32> {
+        string_arg!(class_id);
+        unsafe { sys::cv_linemod_Detector_addTemplate_VectorOfMat_String_Mat_Rect_X(self.as_raw_Detector(), sources.as_raw_VectorOfMat(), class_id.as_ptr(), object_mask.as_raw_Mat(), bounding_box) }.into_result()
+    }
+    
+    /// \brief Add a new object template computed by external means.
+    pub fn add_synthetic_template(&mut self, templates: &types::VectorOfTemplate, class_id: &str) -> Result<i32> {
+        string_arg!(class_id);
+        unsafe { sys::cv_linemod_Detector_addSyntheticTemplate_VectorOfTemplate_String(self.as_raw_Detector(), templates.as_raw_VectorOfTemplate(), class_id.as_ptr()) }.into_result()
+    }
+    
+    /// \brief Get the modalities used by this detector.
+    ///
+    /// You are not permitted to add/remove modalities, but you may dynamic_cast them to
+    /// tweak parameters.
+    pub fn get_modalities(&self) -> Result<types::VectorOfPtrOfModality> {
+        unsafe { sys::cv_linemod_Detector_getModalities_const(self.as_raw_Detector()) }.into_result().map(|ptr| unsafe { types::VectorOfPtrOfModality::from_raw_ptr(ptr) })
+    }
+    
+    /// \brief Get sampling step T at pyramid_level.
+    pub fn get_t(&self, pyramid_level: i32) -> Result<i32> {
+        unsafe { sys::cv_linemod_Detector_getT_const_int(self.as_raw_Detector(), pyramid_level) }.into_result()
+    }
+    
+    /// \brief Get number of pyramid levels used by this detector.
+    pub fn pyramid_levels(&self) -> Result<i32> {
+        unsafe { sys::cv_linemod_Detector_pyramidLevels_const(self.as_raw_Detector()) }.into_result()
+    }
+    
+    /// \brief Get the template pyramid identified by template_id.
+    ///
+    /// For example, with 2 modalities (Gradient, Normal) and two pyramid levels
+    /// (L0, L1), the order is (GradientL0, NormalL0, GradientL1, NormalL1).
+    pub fn get_templates(&self, class_id: &str, template_id: i32) -> Result<types::VectorOfTemplate> {
+        string_arg!(class_id);
+        unsafe { sys::cv_linemod_Detector_getTemplates_const_String_int(self.as_raw_Detector(), class_id.as_ptr(), template_id) }.into_result().map(|ptr| unsafe { types::VectorOfTemplate::from_raw_ptr(ptr) })
+    }
+    
+    pub fn num_templates(&self) -> Result<i32> {
+        unsafe { sys::cv_linemod_Detector_numTemplates_const(self.as_raw_Detector()) }.into_result()
+    }
+    
+    pub fn num_templates_for_class(&self, class_id: &str) -> Result<i32> {
+        string_arg!(class_id);
+        unsafe { sys::cv_linemod_Detector_numTemplates_const_String(self.as_raw_Detector(), class_id.as_ptr()) }.into_result()
+    }
+    
+    pub fn num_classes(&self) -> Result<i32> {
+        unsafe { sys::cv_linemod_Detector_numClasses_const(self.as_raw_Detector()) }.into_result()
+    }
+    
+    pub fn class_ids(&self) -> Result<types::VectorOfString> {
+        unsafe { sys::cv_linemod_Detector_classIds_const(self.as_raw_Detector()) }.into_result().map(|ptr| unsafe { types::VectorOfString::from_raw_ptr(ptr) })
+    }
+    
+    pub fn read(&mut self, _fn: &core::FileNode) -> Result<()> {
+        unsafe { sys::cv_linemod_Detector_read_FileNode(self.as_raw_Detector(), _fn.as_raw_FileNode()) }.into_result()
+    }
+    
+    pub fn write(&self, fs: &mut core::FileStorage) -> Result<()> {
+        unsafe { sys::cv_linemod_Detector_write_const_FileStorage(self.as_raw_Detector(), fs.as_raw_FileStorage()) }.into_result()
+    }
+    
+    ///
+    /// ## C++ default parameters
+    /// * class_id_override: ""
+    pub fn read_class(&mut self, _fn: &core::FileNode, class_id_override: &str) -> Result<String> {
+        string_arg!(class_id_override);
+        unsafe { sys::cv_linemod_Detector_readClass_FileNode_String(self.as_raw_Detector(), _fn.as_raw_FileNode(), class_id_override.as_ptr()) }.into_result().map(crate::templ::receive_string_mut)
+    }
+    
+    pub fn write_class(&self, class_id: &str, fs: &mut core::FileStorage) -> Result<()> {
+        string_arg!(class_id);
+        unsafe { sys::cv_linemod_Detector_writeClass_const_String_FileStorage(self.as_raw_Detector(), class_id.as_ptr(), fs.as_raw_FileStorage()) }.into_result()
+    }
+    
+    ///
+    /// ## C++ default parameters
+    /// * format: "templates_%s.yml.gz"
+    pub fn read_classes(&mut self, class_ids: &types::VectorOfString, format: &str) -> Result<()> {
+        string_arg!(format);
+        unsafe { sys::cv_linemod_Detector_readClasses_VectorOfString_String(self.as_raw_Detector(), class_ids.as_raw_VectorOfString(), format.as_ptr()) }.into_result()
+    }
+    
+    ///
+    /// ## C++ default parameters
+    /// * format: "templates_%s.yml.gz"
+    pub fn write_classes(&self, format: &str) -> Result<()> {
+        string_arg!(format);
+        unsafe { sys::cv_linemod_Detector_writeClasses_const_String(self.as_raw_Detector(), format.as_ptr()) }.into_result()
+    }
+    
+}
+
+impl Feature {
+    pub fn default() -> Result<crate::rgbd::Feature> {
+        unsafe { sys::cv_linemod_Feature_Feature() }.into_result()
+    }
+    
+    pub fn new(x: i32, y: i32, label: i32) -> Result<crate::rgbd::Feature> {
+        unsafe { sys::cv_linemod_Feature_Feature_int_int_int(x, y, label) }.into_result()
+    }
+    
+    pub fn read(self, _fn: &core::FileNode) -> Result<()> {
+        unsafe { sys::cv_linemod_Feature_read_FileNode(self, _fn.as_raw_FileNode()) }.into_result()
+    }
+    
+    pub fn write(self, fs: &mut core::FileStorage) -> Result<()> {
+        unsafe { sys::cv_linemod_Feature_write_const_FileStorage(self, fs.as_raw_FileStorage()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::linemod::Match
+/// \brief Represents a successful template match.
+pub struct Match {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Match {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Match_delete(self.ptr) };
+    }
+}
+
+impl Match {
+    #[inline(always)] pub fn as_raw_Match(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Match {}
+
+impl Match {
+    pub fn x(&self) -> Result<i32> {
+        unsafe { sys::cv_linemod_Match_x_const(self.as_raw_Match()) }.into_result()
+    }
+    
+    pub fn set_x(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_linemod_Match_set_x_int(self.as_raw_Match(), val) }.into_result()
+    }
+    
+    pub fn y(&self) -> Result<i32> {
+        unsafe { sys::cv_linemod_Match_y_const(self.as_raw_Match()) }.into_result()
+    }
+    
+    pub fn set_y(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_linemod_Match_set_y_int(self.as_raw_Match(), val) }.into_result()
+    }
+    
+    pub fn similarity(&self) -> Result<f32> {
+        unsafe { sys::cv_linemod_Match_similarity_const(self.as_raw_Match()) }.into_result()
+    }
+    
+    pub fn set_similarity(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_linemod_Match_set_similarity_float(self.as_raw_Match(), val) }.into_result()
+    }
+    
+    pub fn class_id(&mut self) -> Result<String> {
+        unsafe { sys::cv_linemod_Match_class_id(self.as_raw_Match()) }.into_result().map(crate::templ::receive_string_mut)
+    }
+    
+    pub fn set_class_id(&mut self, val: &str) -> Result<()> {
+        string_arg!(mut val);
+        unsafe { sys::cv_linemod_Match_set_class_id_String(self.as_raw_Match(), val.as_ptr() as _) }.into_result()
+    }
+    
+    pub fn template_id(&self) -> Result<i32> {
+        unsafe { sys::cv_linemod_Match_template_id_const(self.as_raw_Match()) }.into_result()
+    }
+    
+    pub fn set_template_id(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_linemod_Match_set_template_id_int(self.as_raw_Match(), val) }.into_result()
+    }
+    
+    pub fn default() -> Result<crate::rgbd::Match> {
+        unsafe { sys::cv_linemod_Match_Match() }.into_result().map(|ptr| crate::rgbd::Match { ptr })
+    }
+    
+    pub fn new(x: i32, y: i32, similarity: f32, class_id: &str, template_id: i32) -> Result<crate::rgbd::Match> {
+        string_arg!(class_id);
+        unsafe { sys::cv_linemod_Match_Match_int_int_float_String_int(x, y, similarity, class_id.as_ptr(), template_id) }.into_result().map(|ptr| crate::rgbd::Match { ptr })
+    }
+    
+}
+
+// Generating impl for trait crate::rgbd::Modality
+/// \brief Interface for modalities that plug into the LINE template matching representation.
+///
+/// \todo Max response, to allow optimization of summing (255/MAX) features as uint8
+pub trait Modality {
+    fn as_raw_Modality(&self) -> *mut c_void;
+    /// \brief Form a quantized image pyramid from a source image.
+    ///
+    /// \param[in] src  The source image. Type depends on the modality.
+    /// \param[in] mask Optional mask. If not empty, unmasked pixels are set to zero
+    ///     in quantized image and cannot be extracted as features.
+    ///
+    /// ## C++ default parameters
+    /// * mask: Mat()
+    fn process(&self, src: &core::Mat, mask: &core::Mat) -> Result<types::PtrOfQuantizedPyramid> {
+        unsafe { sys::cv_linemod_Modality_process_const_Mat_Mat(self.as_raw_Modality(), src.as_raw_Mat(), mask.as_raw_Mat()) }.into_result().map(|ptr| types::PtrOfQuantizedPyramid { ptr })
+    }
+    
+    fn name(&self) -> Result<String> {
+        unsafe { sys::cv_linemod_Modality_name_const(self.as_raw_Modality()) }.into_result().map(crate::templ::receive_string_mut)
+    }
+    
+    fn read(&mut self, _fn: &core::FileNode) -> Result<()> {
+        unsafe { sys::cv_linemod_Modality_read_FileNode(self.as_raw_Modality(), _fn.as_raw_FileNode()) }.into_result()
+    }
+    
+    fn write(&self, fs: &mut core::FileStorage) -> Result<()> {
+        unsafe { sys::cv_linemod_Modality_write_const_FileStorage(self.as_raw_Modality(), fs.as_raw_FileStorage()) }.into_result()
+    }
+    
+}
+
+impl dyn Modality + '_ {
+    /// \brief Create modality by name.
+    ///
+    /// The following modality types are supported:
+    /// - "ColorGradient"
+    /// - "DepthNormal"
+    pub fn create(modality_type: &str) -> Result<types::PtrOfModality> {
+        string_arg!(modality_type);
+        unsafe { sys::cv_linemod_Modality_create_String(modality_type.as_ptr()) }.into_result().map(|ptr| types::PtrOfModality { ptr })
+    }
+    
+    /// \brief Load a modality from file.
+    pub fn create_from_file_node(_fn: &core::FileNode) -> Result<types::PtrOfModality> {
+        unsafe { sys::cv_linemod_Modality_create_FileNode(_fn.as_raw_FileNode()) }.into_result().map(|ptr| types::PtrOfModality { ptr })
+    }
+    
+}
+
+// Generating impl for trait crate::rgbd::QuantizedPyramid
+/// \brief Represents a modality operating over an image pyramid.
+pub trait QuantizedPyramid {
+    fn as_raw_QuantizedPyramid(&self) -> *mut c_void;
+    /// \brief Compute quantized image at current pyramid level for online detection.
+    ///
+    /// \param[out] dst The destination 8-bit image. For each pixel at most one bit is set,
+    ///     representing its classification.
+    fn quantize(&self, dst: &mut core::Mat) -> Result<()> {
+        unsafe { sys::cv_linemod_QuantizedPyramid_quantize_const_Mat(self.as_raw_QuantizedPyramid(), dst.as_raw_Mat()) }.into_result()
+    }
+    
+    /// \brief Extract most discriminant features at current pyramid level to form a new template.
+    ///
+    /// \param[out] templ The new template.
+    fn extract_template(&self, templ: &mut crate::rgbd::Template) -> Result<bool> {
+        unsafe { sys::cv_linemod_QuantizedPyramid_extractTemplate_const_Template(self.as_raw_QuantizedPyramid(), templ.as_raw_Template()) }.into_result()
+    }
+    
+    /// \brief Go to the next pyramid level.
+    ///
+    /// \todo Allow pyramid scale factor other than 2
+    fn pyr_down(&mut self) -> Result<()> {
+        unsafe { sys::cv_linemod_QuantizedPyramid_pyrDown(self.as_raw_QuantizedPyramid()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::linemod::Template
+pub struct Template {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Template {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Template_delete(self.ptr) };
+    }
+}
+
+impl Template {
+    #[inline(always)] pub fn as_raw_Template(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Template {}
+
+impl Template {
+    pub fn width(&self) -> Result<i32> {
+        unsafe { sys::cv_linemod_Template_width_const(self.as_raw_Template()) }.into_result()
+    }
+    
+    pub fn set_width(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_linemod_Template_set_width_int(self.as_raw_Template(), val) }.into_result()
+    }
+    
+    pub fn height(&self) -> Result<i32> {
+        unsafe { sys::cv_linemod_Template_height_const(self.as_raw_Template()) }.into_result()
+    }
+    
+    pub fn set_height(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_linemod_Template_set_height_int(self.as_raw_Template(), val) }.into_result()
+    }
+    
+    pub fn pyramid_level(&self) -> Result<i32> {
+        unsafe { sys::cv_linemod_Template_pyramid_level_const(self.as_raw_Template()) }.into_result()
+    }
+    
+    pub fn set_pyramid_level(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_linemod_Template_set_pyramid_level_int(self.as_raw_Template(), val) }.into_result()
+    }
+    
+    pub fn read(&mut self, _fn: &core::FileNode) -> Result<()> {
+        unsafe { sys::cv_linemod_Template_read_FileNode(self.as_raw_Template(), _fn.as_raw_FileNode()) }.into_result()
+    }
+    
+    pub fn write(&self, fs: &mut core::FileStorage) -> Result<()> {
+        unsafe { sys::cv_linemod_Template_write_const_FileStorage(self.as_raw_Template(), fs.as_raw_FileStorage()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::rgbd::DepthCleaner
+/// Object that can clean a noisy depth image
+pub struct DepthCleaner {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for DepthCleaner {
+    fn drop(&mut self) {
+        unsafe { sys::cv_DepthCleaner_delete(self.ptr) };
+    }
+}
+
+impl DepthCleaner {
+    #[inline(always)] pub fn as_raw_DepthCleaner(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for DepthCleaner {}
+
+impl core::AlgorithmTrait for DepthCleaner {
+    #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
+}
+
+impl DepthCleaner {
+    pub fn default() -> Result<crate::rgbd::DepthCleaner> {
+        unsafe { sys::cv_rgbd_DepthCleaner_DepthCleaner() }.into_result().map(|ptr| crate::rgbd::DepthCleaner { ptr })
+    }
+    
+    /// Constructor
+    /// ## Parameters
+    /// * depth: the depth of the normals (only CV_32F or CV_64F)
+    /// * window_size: the window size to compute the normals: can only be 1,3,5 or 7
+    /// * method: one of the methods to use: RGBD_NORMALS_METHOD_SRI, RGBD_NORMALS_METHOD_FALS
+    ///
+    /// ## C++ default parameters
+    /// * window_size: 5
+    /// * method: DepthCleaner::DEPTH_CLEANER_NIL
+    pub fn new(depth: i32, window_size: i32, method: i32) -> Result<crate::rgbd::DepthCleaner> {
+        unsafe { sys::cv_rgbd_DepthCleaner_DepthCleaner_int_int_int(depth, window_size, method) }.into_result().map(|ptr| crate::rgbd::DepthCleaner { ptr })
+    }
+    
+    ///
+    /// ## C++ default parameters
+    /// * window_size: 5
+    /// * method: DepthCleaner::DEPTH_CLEANER_NIL
+    pub fn create(depth: i32, window_size: i32, method: i32) -> Result<types::PtrOfDepthCleaner> {
+        unsafe { sys::cv_rgbd_DepthCleaner_create_int_int_int(depth, window_size, method) }.into_result().map(|ptr| types::PtrOfDepthCleaner { ptr })
+    }
+    
+    /// Given a set of 3d points in a depth image, compute the normals at each point.
+    /// ## Parameters
+    /// * points: a rows x cols x 3 matrix of CV_32F/CV64F or a rows x cols x 1 CV_U16S
+    /// * depth: a rows x cols matrix of the cleaned up depth
+    pub fn apply(&self, points: &dyn core::ToInputArray, depth: &mut dyn core::ToOutputArray) -> Result<()> {
+        input_array_arg!(points);
+        output_array_arg!(depth);
+        unsafe { sys::cv_rgbd_DepthCleaner_operator_call_const__InputArray__OutputArray(self.as_raw_DepthCleaner(), points.as_raw__InputArray(), depth.as_raw__OutputArray()) }.into_result()
+    }
+    
+    /// Initializes some data that is cached for later computation
+    /// If that function is not called, it will be called the first time normals are computed
+    pub fn initialize(&self) -> Result<()> {
+        unsafe { sys::cv_rgbd_DepthCleaner_initialize_const(self.as_raw_DepthCleaner()) }.into_result()
+    }
+    
+    pub fn get_window_size(&self) -> Result<i32> {
+        unsafe { sys::cv_rgbd_DepthCleaner_getWindowSize_const(self.as_raw_DepthCleaner()) }.into_result()
+    }
+    
+    pub fn set_window_size(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_rgbd_DepthCleaner_setWindowSize_int(self.as_raw_DepthCleaner(), val) }.into_result()
+    }
+    
+    pub fn get_depth(&self) -> Result<i32> {
+        unsafe { sys::cv_rgbd_DepthCleaner_getDepth_const(self.as_raw_DepthCleaner()) }.into_result()
+    }
+    
+    pub fn set_depth(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_rgbd_DepthCleaner_setDepth_int(self.as_raw_DepthCleaner(), val) }.into_result()
+    }
+    
+    pub fn get_method(&self) -> Result<i32> {
+        unsafe { sys::cv_rgbd_DepthCleaner_getMethod_const(self.as_raw_DepthCleaner()) }.into_result()
+    }
+    
+    pub fn set_method(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_rgbd_DepthCleaner_setMethod_int(self.as_raw_DepthCleaner(), val) }.into_result()
+    }
+    
+}
+
+// boxed class cv::rgbd::FastICPOdometry
+/// A faster version of ICPOdometry which is used in KinectFusion implementation
+/// Partial list of differences:
+/// - Works in parallel
+/// - Written in universal intrinsics
+/// - Filters points by angle
+/// - Interpolates points and normals
+/// - Doesn't use masks or min/max depth filtering
+/// - Doesn't use random subsets of points
+/// - Supports only Rt transform type
+/// - Supports only 4-float vectors as input type
+pub struct FastICPOdometry {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for FastICPOdometry {
+    fn drop(&mut self) {
+        unsafe { sys::cv_FastICPOdometry_delete(self.ptr) };
+    }
+}
+
+impl FastICPOdometry {
+    #[inline(always)] pub fn as_raw_FastICPOdometry(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for FastICPOdometry {}
+
+impl core::AlgorithmTrait for FastICPOdometry {
+    #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::rgbd::Odometry for FastICPOdometry {
+    #[inline(always)] fn as_raw_Odometry(&self) -> *mut c_void { self.ptr }
+}
+
+impl FastICPOdometry {
+    pub fn default() -> Result<crate::rgbd::FastICPOdometry> {
+        unsafe { sys::cv_rgbd_FastICPOdometry_FastICPOdometry() }.into_result().map(|ptr| crate::rgbd::FastICPOdometry { ptr })
+    }
+    
+    /// Constructor.
+    /// ## Parameters
+    /// * cameraMatrix: Camera matrix
+    /// * maxDistDiff: Correspondences between pixels of two given frames will be filtered out
+    ///      if their depth difference is larger than maxDepthDiff
+    /// * angleThreshold: Correspondence will be filtered out
+    ///      if an angle between their normals is bigger than threshold
+    /// * sigmaDepth: Depth sigma in meters for bilateral smooth
+    /// * sigmaSpatial: Spatial sigma in pixels for bilateral smooth
+    /// * kernelSize: Kernel size in pixels for bilateral smooth
+    /// * iterCounts: Count of iterations on each pyramid level
+    ///
+    /// ## C++ default parameters
+    /// * max_dist_diff: Odometry::DEFAULT_MAX_DEPTH_DIFF()
+    /// * angle_threshold: (float)(30. * CV_PI / 180.)
+    /// * sigma_depth: 0.04f
+    /// * sigma_spatial: 4.5f
+    /// * kernel_size: 7
+    /// * iter_counts: std::vector<int>()
+    pub fn new(camera_matrix: &core::Mat, max_dist_diff: f32, angle_threshold: f32, sigma_depth: f32, sigma_spatial: f32, kernel_size: i32, iter_counts: &types::VectorOfint) -> Result<crate::rgbd::FastICPOdometry> {
+        unsafe { sys::cv_rgbd_FastICPOdometry_FastICPOdometry_Mat_float_float_float_float_int_VectorOfint(camera_matrix.as_raw_Mat(), max_dist_diff, angle_threshold, sigma_depth, sigma_spatial, kernel_size, iter_counts.as_raw_VectorOfint()) }.into_result().map(|ptr| crate::rgbd::FastICPOdometry { ptr })
+    }
+    
+    ///
+    /// ## C++ default parameters
+    /// * max_dist_diff: Odometry::DEFAULT_MAX_DEPTH_DIFF()
+    /// * angle_threshold: (float)(30. * CV_PI / 180.)
+    /// * sigma_depth: 0.04f
+    /// * sigma_spatial: 4.5f
+    /// * kernel_size: 7
+    /// * iter_counts: std::vector<int>()
+    pub fn create(camera_matrix: &core::Mat, max_dist_diff: f32, angle_threshold: f32, sigma_depth: f32, sigma_spatial: f32, kernel_size: i32, iter_counts: &types::VectorOfint) -> Result<types::PtrOfFastICPOdometry> {
+        unsafe { sys::cv_rgbd_FastICPOdometry_create_Mat_float_float_float_float_int_VectorOfint(camera_matrix.as_raw_Mat(), max_dist_diff, angle_threshold, sigma_depth, sigma_spatial, kernel_size, iter_counts.as_raw_VectorOfint()) }.into_result().map(|ptr| types::PtrOfFastICPOdometry { ptr })
+    }
+    
+    pub fn prepare_frame_cache(&self, frame: &mut types::PtrOfOdometryFrame, cache_type: i32) -> Result<core::Size> {
+        unsafe { sys::cv_rgbd_FastICPOdometry_prepareFrameCache_const_PtrOfOdometryFrame_int(self.as_raw_FastICPOdometry(), frame.as_raw_PtrOfOdometryFrame(), cache_type) }.into_result()
+    }
+    
+    pub fn get_camera_matrix(&self) -> Result<core::Mat> {
+        unsafe { sys::cv_rgbd_FastICPOdometry_getCameraMatrix_const(self.as_raw_FastICPOdometry()) }.into_result().map(|ptr| core::Mat { ptr })
+    }
+    
+    pub fn set_camera_matrix(&mut self, val: &core::Mat) -> Result<()> {
+        unsafe { sys::cv_rgbd_FastICPOdometry_setCameraMatrix_Mat(self.as_raw_FastICPOdometry(), val.as_raw_Mat()) }.into_result()
+    }
+    
+    pub fn get_max_dist_diff(&self) -> Result<f64> {
+        unsafe { sys::cv_rgbd_FastICPOdometry_getMaxDistDiff_const(self.as_raw_FastICPOdometry()) }.into_result()
+    }
+    
+    pub fn set_max_dist_diff(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_rgbd_FastICPOdometry_setMaxDistDiff_float(self.as_raw_FastICPOdometry(), val) }.into_result()
+    }
+    
+    pub fn get_angle_threshold(&self) -> Result<f32> {
+        unsafe { sys::cv_rgbd_FastICPOdometry_getAngleThreshold_const(self.as_raw_FastICPOdometry()) }.into_result()
+    }
+    
+    pub fn set_angle_threshold(&mut self, f: f32) -> Result<()> {
+        unsafe { sys::cv_rgbd_FastICPOdometry_setAngleThreshold_float(self.as_raw_FastICPOdometry(), f) }.into_result()
+    }
+    
+    pub fn get_sigma_depth(&self) -> Result<f32> {
+        unsafe { sys::cv_rgbd_FastICPOdometry_getSigmaDepth_const(self.as_raw_FastICPOdometry()) }.into_result()
+    }
+    
+    pub fn set_sigma_depth(&mut self, f: f32) -> Result<()> {
+        unsafe { sys::cv_rgbd_FastICPOdometry_setSigmaDepth_float(self.as_raw_FastICPOdometry(), f) }.into_result()
+    }
+    
+    pub fn get_sigma_spatial(&self) -> Result<f32> {
+        unsafe { sys::cv_rgbd_FastICPOdometry_getSigmaSpatial_const(self.as_raw_FastICPOdometry()) }.into_result()
+    }
+    
+    pub fn set_sigma_spatial(&mut self, f: f32) -> Result<()> {
+        unsafe { sys::cv_rgbd_FastICPOdometry_setSigmaSpatial_float(self.as_raw_FastICPOdometry(), f) }.into_result()
+    }
+    
+    pub fn get_kernel_size(&self) -> Result<i32> {
+        unsafe { sys::cv_rgbd_FastICPOdometry_getKernelSize_const(self.as_raw_FastICPOdometry()) }.into_result()
+    }
+    
+    pub fn set_kernel_size(&mut self, f: i32) -> Result<()> {
+        unsafe { sys::cv_rgbd_FastICPOdometry_setKernelSize_int(self.as_raw_FastICPOdometry(), f) }.into_result()
+    }
+    
+    pub fn get_iteration_counts(&self) -> Result<core::Mat> {
+        unsafe { sys::cv_rgbd_FastICPOdometry_getIterationCounts_const(self.as_raw_FastICPOdometry()) }.into_result().map(|ptr| core::Mat { ptr })
+    }
+    
+    pub fn set_iteration_counts(&mut self, val: &core::Mat) -> Result<()> {
+        unsafe { sys::cv_rgbd_FastICPOdometry_setIterationCounts_Mat(self.as_raw_FastICPOdometry(), val.as_raw_Mat()) }.into_result()
+    }
+    
+    pub fn get_transform_type(&self) -> Result<i32> {
+        unsafe { sys::cv_rgbd_FastICPOdometry_getTransformType_const(self.as_raw_FastICPOdometry()) }.into_result()
+    }
+    
+    pub fn set_transform_type(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_rgbd_FastICPOdometry_setTransformType_int(self.as_raw_FastICPOdometry(), val) }.into_result()
+    }
+    
+}
+
+// boxed class cv::rgbd::ICPOdometry
+/// Odometry based on the paper "KinectFusion: Real-Time Dense Surface Mapping and Tracking",
+/// Richard A. Newcombe, Andrew Fitzgibbon, at al, SIGGRAPH, 2011.
+pub struct ICPOdometry {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for ICPOdometry {
+    fn drop(&mut self) {
+        unsafe { sys::cv_ICPOdometry_delete(self.ptr) };
+    }
+}
+
+impl ICPOdometry {
+    #[inline(always)] pub fn as_raw_ICPOdometry(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for ICPOdometry {}
+
+impl core::AlgorithmTrait for ICPOdometry {
+    #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::rgbd::Odometry for ICPOdometry {
+    #[inline(always)] fn as_raw_Odometry(&self) -> *mut c_void { self.ptr }
+}
+
+impl ICPOdometry {
+    pub fn default() -> Result<crate::rgbd::ICPOdometry> {
+        unsafe { sys::cv_rgbd_ICPOdometry_ICPOdometry() }.into_result().map(|ptr| crate::rgbd::ICPOdometry { ptr })
+    }
+    
+    /// Constructor.
+    /// ## Parameters
+    /// * cameraMatrix: Camera matrix
+    /// * minDepth: Pixels with depth less than minDepth will not be used
+    /// * maxDepth: Pixels with depth larger than maxDepth will not be used
+    /// * maxDepthDiff: Correspondences between pixels of two given frames will be filtered out
+    ///      if their depth difference is larger than maxDepthDiff
+    /// * maxPointsPart: The method uses a random pixels subset of size frameWidth x frameHeight x pointsPart
+    /// * iterCounts: Count of iterations on each pyramid level.
+    /// * transformType: Class of trasformation
+    ///
+    /// ## C++ default parameters
+    /// * min_depth: Odometry::DEFAULT_MIN_DEPTH()
+    /// * max_depth: Odometry::DEFAULT_MAX_DEPTH()
+    /// * max_depth_diff: Odometry::DEFAULT_MAX_DEPTH_DIFF()
+    /// * max_points_part: Odometry::DEFAULT_MAX_POINTS_PART()
+    /// * iter_counts: std::vector<int>()
+    /// * transform_type: Odometry::RIGID_BODY_MOTION
+    pub fn new(camera_matrix: &core::Mat, min_depth: f32, max_depth: f32, max_depth_diff: f32, max_points_part: f32, iter_counts: &types::VectorOfint, transform_type: i32) -> Result<crate::rgbd::ICPOdometry> {
+        unsafe { sys::cv_rgbd_ICPOdometry_ICPOdometry_Mat_float_float_float_float_VectorOfint_int(camera_matrix.as_raw_Mat(), min_depth, max_depth, max_depth_diff, max_points_part, iter_counts.as_raw_VectorOfint(), transform_type) }.into_result().map(|ptr| crate::rgbd::ICPOdometry { ptr })
+    }
+    
+    ///
+    /// ## C++ default parameters
+    /// * camera_matrix: Mat()
+    /// * min_depth: Odometry::DEFAULT_MIN_DEPTH()
+    /// * max_depth: Odometry::DEFAULT_MAX_DEPTH()
+    /// * max_depth_diff: Odometry::DEFAULT_MAX_DEPTH_DIFF()
+    /// * max_points_part: Odometry::DEFAULT_MAX_POINTS_PART()
+    /// * iter_counts: std::vector<int>()
+    /// * transform_type: Odometry::RIGID_BODY_MOTION
+    pub fn create(camera_matrix: &core::Mat, min_depth: f32, max_depth: f32, max_depth_diff: f32, max_points_part: f32, iter_counts: &types::VectorOfint, transform_type: i32) -> Result<types::PtrOfICPOdometry> {
+        unsafe { sys::cv_rgbd_ICPOdometry_create_Mat_float_float_float_float_VectorOfint_int(camera_matrix.as_raw_Mat(), min_depth, max_depth, max_depth_diff, max_points_part, iter_counts.as_raw_VectorOfint(), transform_type) }.into_result().map(|ptr| types::PtrOfICPOdometry { ptr })
+    }
+    
+    pub fn prepare_frame_cache(&self, frame: &mut types::PtrOfOdometryFrame, cache_type: i32) -> Result<core::Size> {
+        unsafe { sys::cv_rgbd_ICPOdometry_prepareFrameCache_const_PtrOfOdometryFrame_int(self.as_raw_ICPOdometry(), frame.as_raw_PtrOfOdometryFrame(), cache_type) }.into_result()
+    }
+    
+    pub fn get_camera_matrix(&self) -> Result<core::Mat> {
+        unsafe { sys::cv_rgbd_ICPOdometry_getCameraMatrix_const(self.as_raw_ICPOdometry()) }.into_result().map(|ptr| core::Mat { ptr })
+    }
+    
+    pub fn set_camera_matrix(&mut self, val: &core::Mat) -> Result<()> {
+        unsafe { sys::cv_rgbd_ICPOdometry_setCameraMatrix_Mat(self.as_raw_ICPOdometry(), val.as_raw_Mat()) }.into_result()
+    }
+    
+    pub fn get_min_depth(&self) -> Result<f64> {
+        unsafe { sys::cv_rgbd_ICPOdometry_getMinDepth_const(self.as_raw_ICPOdometry()) }.into_result()
+    }
+    
+    pub fn set_min_depth(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_rgbd_ICPOdometry_setMinDepth_double(self.as_raw_ICPOdometry(), val) }.into_result()
+    }
+    
+    pub fn get_max_depth(&self) -> Result<f64> {
+        unsafe { sys::cv_rgbd_ICPOdometry_getMaxDepth_const(self.as_raw_ICPOdometry()) }.into_result()
+    }
+    
+    pub fn set_max_depth(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_rgbd_ICPOdometry_setMaxDepth_double(self.as_raw_ICPOdometry(), val) }.into_result()
+    }
+    
+    pub fn get_max_depth_diff(&self) -> Result<f64> {
+        unsafe { sys::cv_rgbd_ICPOdometry_getMaxDepthDiff_const(self.as_raw_ICPOdometry()) }.into_result()
+    }
+    
+    pub fn set_max_depth_diff(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_rgbd_ICPOdometry_setMaxDepthDiff_double(self.as_raw_ICPOdometry(), val) }.into_result()
+    }
+    
+    pub fn get_iteration_counts(&self) -> Result<core::Mat> {
+        unsafe { sys::cv_rgbd_ICPOdometry_getIterationCounts_const(self.as_raw_ICPOdometry()) }.into_result().map(|ptr| core::Mat { ptr })
+    }
+    
+    pub fn set_iteration_counts(&mut self, val: &core::Mat) -> Result<()> {
+        unsafe { sys::cv_rgbd_ICPOdometry_setIterationCounts_Mat(self.as_raw_ICPOdometry(), val.as_raw_Mat()) }.into_result()
+    }
+    
+    pub fn get_max_points_part(&self) -> Result<f64> {
+        unsafe { sys::cv_rgbd_ICPOdometry_getMaxPointsPart_const(self.as_raw_ICPOdometry()) }.into_result()
+    }
+    
+    pub fn set_max_points_part(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_rgbd_ICPOdometry_setMaxPointsPart_double(self.as_raw_ICPOdometry(), val) }.into_result()
+    }
+    
+    pub fn get_transform_type(&self) -> Result<i32> {
+        unsafe { sys::cv_rgbd_ICPOdometry_getTransformType_const(self.as_raw_ICPOdometry()) }.into_result()
+    }
+    
+    pub fn set_transform_type(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_rgbd_ICPOdometry_setTransformType_int(self.as_raw_ICPOdometry(), val) }.into_result()
+    }
+    
+    pub fn get_max_translation(&self) -> Result<f64> {
+        unsafe { sys::cv_rgbd_ICPOdometry_getMaxTranslation_const(self.as_raw_ICPOdometry()) }.into_result()
+    }
+    
+    pub fn set_max_translation(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_rgbd_ICPOdometry_setMaxTranslation_double(self.as_raw_ICPOdometry(), val) }.into_result()
+    }
+    
+    pub fn get_max_rotation(&self) -> Result<f64> {
+        unsafe { sys::cv_rgbd_ICPOdometry_getMaxRotation_const(self.as_raw_ICPOdometry()) }.into_result()
+    }
+    
+    pub fn set_max_rotation(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_rgbd_ICPOdometry_setMaxRotation_double(self.as_raw_ICPOdometry(), val) }.into_result()
+    }
+    
+    pub fn get_normals_computer(&self) -> Result<types::PtrOfRgbdNormals> {
+        unsafe { sys::cv_rgbd_ICPOdometry_getNormalsComputer_const(self.as_raw_ICPOdometry()) }.into_result().map(|ptr| types::PtrOfRgbdNormals { ptr })
+    }
+    
+}
+
+// Generating impl for trait crate::rgbd::Odometry
+/// Base class for computation of odometry.
+pub trait Odometry: core::AlgorithmTrait {
+    fn as_raw_Odometry(&self) -> *mut c_void;
+    fn default_min_depth(&mut self) -> Result<f32> {
+        unsafe { sys::cv_rgbd_Odometry_DEFAULT_MIN_DEPTH(self.as_raw_Odometry()) }.into_result()
+    }
+    
+    fn default_max_depth(&mut self) -> Result<f32> {
+        unsafe { sys::cv_rgbd_Odometry_DEFAULT_MAX_DEPTH(self.as_raw_Odometry()) }.into_result()
+    }
+    
+    fn default_max_depth_diff(&mut self) -> Result<f32> {
+        unsafe { sys::cv_rgbd_Odometry_DEFAULT_MAX_DEPTH_DIFF(self.as_raw_Odometry()) }.into_result()
+    }
+    
+    fn default_max_points_part(&mut self) -> Result<f32> {
+        unsafe { sys::cv_rgbd_Odometry_DEFAULT_MAX_POINTS_PART(self.as_raw_Odometry()) }.into_result()
+    }
+    
+    fn default_max_translation(&mut self) -> Result<f32> {
+        unsafe { sys::cv_rgbd_Odometry_DEFAULT_MAX_TRANSLATION(self.as_raw_Odometry()) }.into_result()
+    }
+    
+    fn default_max_rotation(&mut self) -> Result<f32> {
+        unsafe { sys::cv_rgbd_Odometry_DEFAULT_MAX_ROTATION(self.as_raw_Odometry()) }.into_result()
+    }
+    
+    /// Method to compute a transformation from the source frame to the destination one.
+    /// Some odometry algorithms do not used some data of frames (eg. ICP does not use images).
+    /// In such case corresponding arguments can be set as empty Mat.
+    /// The method returns true if all internal computions were possible (e.g. there were enough correspondences,
+    /// system of equations has a solution, etc) and resulting transformation satisfies some test if it's provided
+    /// by the Odometry inheritor implementation (e.g. thresholds for maximum translation and rotation).
+    /// ## Parameters
+    /// * srcImage: Image data of the source frame (CV_8UC1)
+    /// * srcDepth: Depth data of the source frame (CV_32FC1, in meters)
+    /// * srcMask: Mask that sets which pixels have to be used from the source frame (CV_8UC1)
+    /// * dstImage: Image data of the destination frame (CV_8UC1)
+    /// * dstDepth: Depth data of the destination frame (CV_32FC1, in meters)
+    /// * dstMask: Mask that sets which pixels have to be used from the destination frame (CV_8UC1)
+    /// * Rt: Resulting transformation from the source frame to the destination one (rigid body motion):
+    /// dst_p = Rt * src_p, where dst_p is a homogeneous point in the destination frame and src_p is
+    /// homogeneous point in the source frame,
+    /// Rt is 4x4 matrix of CV_64FC1 type.
+    /// * initRt: Initial transformation from the source frame to the destination one (optional)
+    ///
+    /// ## C++ default parameters
+    /// * init_rt: Mat()
+    fn compute(&self, src_image: &core::Mat, src_depth: &core::Mat, src_mask: &core::Mat, dst_image: &core::Mat, dst_depth: &core::Mat, dst_mask: &core::Mat, rt: &mut dyn core::ToOutputArray, init_rt: &core::Mat) -> Result<bool> {
+        output_array_arg!(rt);
+        unsafe { sys::cv_rgbd_Odometry_compute_const_Mat_Mat_Mat_Mat_Mat_Mat__OutputArray_Mat(self.as_raw_Odometry(), src_image.as_raw_Mat(), src_depth.as_raw_Mat(), src_mask.as_raw_Mat(), dst_image.as_raw_Mat(), dst_depth.as_raw_Mat(), dst_mask.as_raw_Mat(), rt.as_raw__OutputArray(), init_rt.as_raw_Mat()) }.into_result()
+    }
+    
+    /// One more method to compute a transformation from the source frame to the destination one.
+    /// It is designed to save on computing the frame data (image pyramids, normals, etc.).
+    ///
+    /// ## C++ default parameters
+    /// * init_rt: Mat()
+    fn compute_1(&self, src_frame: &mut types::PtrOfOdometryFrame, dst_frame: &mut types::PtrOfOdometryFrame, rt: &mut dyn core::ToOutputArray, init_rt: &core::Mat) -> Result<bool> {
+        output_array_arg!(rt);
+        unsafe { sys::cv_rgbd_Odometry_compute_const_PtrOfOdometryFrame_PtrOfOdometryFrame__OutputArray_Mat(self.as_raw_Odometry(), src_frame.as_raw_PtrOfOdometryFrame(), dst_frame.as_raw_PtrOfOdometryFrame(), rt.as_raw__OutputArray(), init_rt.as_raw_Mat()) }.into_result()
+    }
+    
+    /// Prepare a cache for the frame. The function checks the precomputed/passed data (throws the error if this data
+    /// does not satisfy) and computes all remaining cache data needed for the frame. Returned size is a resolution
+    /// of the prepared frame.
+    /// ## Parameters
+    /// * frame: The odometry which will process the frame.
+    /// * cacheType: The cache type: CACHE_SRC, CACHE_DST or CACHE_ALL.
+    fn prepare_frame_cache(&self, frame: &mut types::PtrOfOdometryFrame, cache_type: i32) -> Result<core::Size> {
+        unsafe { sys::cv_rgbd_Odometry_prepareFrameCache_const_PtrOfOdometryFrame_int(self.as_raw_Odometry(), frame.as_raw_PtrOfOdometryFrame(), cache_type) }.into_result()
+    }
+    
+    /// @see setCameraMatrix
+    fn get_camera_matrix(&self) -> Result<core::Mat> {
+        unsafe { sys::cv_rgbd_Odometry_getCameraMatrix_const(self.as_raw_Odometry()) }.into_result().map(|ptr| core::Mat { ptr })
+    }
+    
+    /// @copybrief getCameraMatrix @see getCameraMatrix
+    fn set_camera_matrix(&mut self, val: &core::Mat) -> Result<()> {
+        unsafe { sys::cv_rgbd_Odometry_setCameraMatrix_Mat(self.as_raw_Odometry(), val.as_raw_Mat()) }.into_result()
+    }
+    
+    /// @see setTransformType
+    fn get_transform_type(&self) -> Result<i32> {
+        unsafe { sys::cv_rgbd_Odometry_getTransformType_const(self.as_raw_Odometry()) }.into_result()
+    }
+    
+    /// @copybrief getTransformType @see getTransformType
+    fn set_transform_type(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_rgbd_Odometry_setTransformType_int(self.as_raw_Odometry(), val) }.into_result()
+    }
+    
+}
+
+impl dyn Odometry + '_ {
+    pub fn create(odometry_type: &str) -> Result<types::PtrOfOdometry> {
+        string_arg!(odometry_type);
+        unsafe { sys::cv_rgbd_Odometry_create_String(odometry_type.as_ptr()) }.into_result().map(|ptr| types::PtrOfOdometry { ptr })
+    }
+    
+}
+
+// boxed class cv::rgbd::OdometryFrame
+/// Object that contains a frame data that is possibly needed for the Odometry.
+/// It's used for the efficiency (to pass precomputed/cached data of the frame that participates
+/// in the Odometry processing several times).
+pub struct OdometryFrame {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for OdometryFrame {
+    fn drop(&mut self) {
+        unsafe { sys::cv_OdometryFrame_delete(self.ptr) };
+    }
+}
+
+impl OdometryFrame {
+    #[inline(always)] pub fn as_raw_OdometryFrame(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for OdometryFrame {}
+
+impl crate::rgbd::RgbdFrameTrait for OdometryFrame {
+    #[inline(always)] fn as_raw_RgbdFrame(&self) -> *mut c_void { self.ptr }
+}
+
+impl OdometryFrame {
+    pub fn pyramid_image(&mut self) -> Result<types::VectorOfMat> {
+        unsafe { sys::cv_rgbd_OdometryFrame_pyramidImage(self.as_raw_OdometryFrame()) }.into_result().map(|ptr| unsafe { types::VectorOfMat::from_raw_ptr(ptr) })
+    }
+    
+    pub fn set_pyramid_image(&mut self, val: types::VectorOfMat) -> Result<()> {
+        unsafe { sys::cv_rgbd_OdometryFrame_set_pyramidImage_VectorOfMat(self.as_raw_OdometryFrame(), val.as_raw_VectorOfMat()) }.into_result()
+    }
+    
+    pub fn pyramid_depth(&mut self) -> Result<types::VectorOfMat> {
+        unsafe { sys::cv_rgbd_OdometryFrame_pyramidDepth(self.as_raw_OdometryFrame()) }.into_result().map(|ptr| unsafe { types::VectorOfMat::from_raw_ptr(ptr) })
+    }
+    
+    pub fn set_pyramid_depth(&mut self, val: types::VectorOfMat) -> Result<()> {
+        unsafe { sys::cv_rgbd_OdometryFrame_set_pyramidDepth_VectorOfMat(self.as_raw_OdometryFrame(), val.as_raw_VectorOfMat()) }.into_result()
+    }
+    
+    pub fn pyramid_mask(&mut self) -> Result<types::VectorOfMat> {
+        unsafe { sys::cv_rgbd_OdometryFrame_pyramidMask(self.as_raw_OdometryFrame()) }.into_result().map(|ptr| unsafe { types::VectorOfMat::from_raw_ptr(ptr) })
+    }
+    
+    pub fn set_pyramid_mask(&mut self, val: types::VectorOfMat) -> Result<()> {
+        unsafe { sys::cv_rgbd_OdometryFrame_set_pyramidMask_VectorOfMat(self.as_raw_OdometryFrame(), val.as_raw_VectorOfMat()) }.into_result()
+    }
+    
+    pub fn pyramid_cloud(&mut self) -> Result<types::VectorOfMat> {
+        unsafe { sys::cv_rgbd_OdometryFrame_pyramidCloud(self.as_raw_OdometryFrame()) }.into_result().map(|ptr| unsafe { types::VectorOfMat::from_raw_ptr(ptr) })
+    }
+    
+    pub fn set_pyramid_cloud(&mut self, val: types::VectorOfMat) -> Result<()> {
+        unsafe { sys::cv_rgbd_OdometryFrame_set_pyramidCloud_VectorOfMat(self.as_raw_OdometryFrame(), val.as_raw_VectorOfMat()) }.into_result()
+    }
+    
+    pub fn pyramid_d_i_dx(&mut self) -> Result<types::VectorOfMat> {
+        unsafe { sys::cv_rgbd_OdometryFrame_pyramid_dI_dx(self.as_raw_OdometryFrame()) }.into_result().map(|ptr| unsafe { types::VectorOfMat::from_raw_ptr(ptr) })
+    }
+    
+    pub fn set_pyramid_d_i_dx(&mut self, val: types::VectorOfMat) -> Result<()> {
+        unsafe { sys::cv_rgbd_OdometryFrame_set_pyramid_dI_dx_VectorOfMat(self.as_raw_OdometryFrame(), val.as_raw_VectorOfMat()) }.into_result()
+    }
+    
+    pub fn pyramid_d_i_dy(&mut self) -> Result<types::VectorOfMat> {
+        unsafe { sys::cv_rgbd_OdometryFrame_pyramid_dI_dy(self.as_raw_OdometryFrame()) }.into_result().map(|ptr| unsafe { types::VectorOfMat::from_raw_ptr(ptr) })
+    }
+    
+    pub fn set_pyramid_d_i_dy(&mut self, val: types::VectorOfMat) -> Result<()> {
+        unsafe { sys::cv_rgbd_OdometryFrame_set_pyramid_dI_dy_VectorOfMat(self.as_raw_OdometryFrame(), val.as_raw_VectorOfMat()) }.into_result()
+    }
+    
+    pub fn pyramid_textured_mask(&mut self) -> Result<types::VectorOfMat> {
+        unsafe { sys::cv_rgbd_OdometryFrame_pyramidTexturedMask(self.as_raw_OdometryFrame()) }.into_result().map(|ptr| unsafe { types::VectorOfMat::from_raw_ptr(ptr) })
+    }
+    
+    pub fn set_pyramid_textured_mask(&mut self, val: types::VectorOfMat) -> Result<()> {
+        unsafe { sys::cv_rgbd_OdometryFrame_set_pyramidTexturedMask_VectorOfMat(self.as_raw_OdometryFrame(), val.as_raw_VectorOfMat()) }.into_result()
+    }
+    
+    pub fn pyramid_normals(&mut self) -> Result<types::VectorOfMat> {
+        unsafe { sys::cv_rgbd_OdometryFrame_pyramidNormals(self.as_raw_OdometryFrame()) }.into_result().map(|ptr| unsafe { types::VectorOfMat::from_raw_ptr(ptr) })
+    }
+    
+    pub fn set_pyramid_normals(&mut self, val: types::VectorOfMat) -> Result<()> {
+        unsafe { sys::cv_rgbd_OdometryFrame_set_pyramidNormals_VectorOfMat(self.as_raw_OdometryFrame(), val.as_raw_VectorOfMat()) }.into_result()
+    }
+    
+    pub fn pyramid_normals_mask(&mut self) -> Result<types::VectorOfMat> {
+        unsafe { sys::cv_rgbd_OdometryFrame_pyramidNormalsMask(self.as_raw_OdometryFrame()) }.into_result().map(|ptr| unsafe { types::VectorOfMat::from_raw_ptr(ptr) })
+    }
+    
+    pub fn set_pyramid_normals_mask(&mut self, val: types::VectorOfMat) -> Result<()> {
+        unsafe { sys::cv_rgbd_OdometryFrame_set_pyramidNormalsMask_VectorOfMat(self.as_raw_OdometryFrame(), val.as_raw_VectorOfMat()) }.into_result()
+    }
+    
+    pub fn default() -> Result<crate::rgbd::OdometryFrame> {
+        unsafe { sys::cv_rgbd_OdometryFrame_OdometryFrame() }.into_result().map(|ptr| crate::rgbd::OdometryFrame { ptr })
+    }
+    
+    ///
+    /// ## C++ default parameters
+    /// * mask: Mat()
+    /// * normals: Mat()
+    /// * id: -1
+    pub fn new(image: &core::Mat, depth: &core::Mat, mask: &core::Mat, normals: &core::Mat, id: i32) -> Result<crate::rgbd::OdometryFrame> {
+        unsafe { sys::cv_rgbd_OdometryFrame_OdometryFrame_Mat_Mat_Mat_Mat_int(image.as_raw_Mat(), depth.as_raw_Mat(), mask.as_raw_Mat(), normals.as_raw_Mat(), id) }.into_result().map(|ptr| crate::rgbd::OdometryFrame { ptr })
+    }
+    
+    ///
+    /// ## C++ default parameters
+    /// * image: Mat()
+    /// * depth: Mat()
+    /// * mask: Mat()
+    /// * normals: Mat()
+    /// * id: -1
+    pub fn create(image: &core::Mat, depth: &core::Mat, mask: &core::Mat, normals: &core::Mat, id: i32) -> Result<types::PtrOfOdometryFrame> {
+        unsafe { sys::cv_rgbd_OdometryFrame_create_Mat_Mat_Mat_Mat_int(image.as_raw_Mat(), depth.as_raw_Mat(), mask.as_raw_Mat(), normals.as_raw_Mat(), id) }.into_result().map(|ptr| types::PtrOfOdometryFrame { ptr })
+    }
+    
+    pub fn release(&mut self) -> Result<()> {
+        unsafe { sys::cv_rgbd_OdometryFrame_release(self.as_raw_OdometryFrame()) }.into_result()
+    }
+    
+    pub fn release_pyramids(&mut self) -> Result<()> {
+        unsafe { sys::cv_rgbd_OdometryFrame_releasePyramids(self.as_raw_OdometryFrame()) }.into_result()
+    }
+    
+}
+
+// Generating impl for trait crate::rgbd::RgbdFrame
+/// Object that contains a frame data.
+pub trait RgbdFrameTrait {
+    fn as_raw_RgbdFrame(&self) -> *mut c_void;
+    fn id(&self) -> Result<i32> {
+        unsafe { sys::cv_rgbd_RgbdFrame_ID_const(self.as_raw_RgbdFrame()) }.into_result()
+    }
+    
+    fn set_id(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdFrame_set_ID_int(self.as_raw_RgbdFrame(), val) }.into_result()
+    }
+    
+    fn image(&mut self) -> Result<core::Mat> {
+        unsafe { sys::cv_rgbd_RgbdFrame_image(self.as_raw_RgbdFrame()) }.into_result().map(|ptr| core::Mat { ptr })
+    }
+    
+    fn set_image(&mut self, val: core::Mat) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdFrame_set_image_Mat(self.as_raw_RgbdFrame(), val.as_raw_Mat()) }.into_result()
+    }
+    
+    fn depth(&mut self) -> Result<core::Mat> {
+        unsafe { sys::cv_rgbd_RgbdFrame_depth(self.as_raw_RgbdFrame()) }.into_result().map(|ptr| core::Mat { ptr })
+    }
+    
+    fn set_depth(&mut self, val: core::Mat) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdFrame_set_depth_Mat(self.as_raw_RgbdFrame(), val.as_raw_Mat()) }.into_result()
+    }
+    
+    fn mask(&mut self) -> Result<core::Mat> {
+        unsafe { sys::cv_rgbd_RgbdFrame_mask(self.as_raw_RgbdFrame()) }.into_result().map(|ptr| core::Mat { ptr })
+    }
+    
+    fn set_mask(&mut self, val: core::Mat) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdFrame_set_mask_Mat(self.as_raw_RgbdFrame(), val.as_raw_Mat()) }.into_result()
+    }
+    
+    fn normals(&mut self) -> Result<core::Mat> {
+        unsafe { sys::cv_rgbd_RgbdFrame_normals(self.as_raw_RgbdFrame()) }.into_result().map(|ptr| core::Mat { ptr })
+    }
+    
+    fn set_normals(&mut self, val: core::Mat) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdFrame_set_normals_Mat(self.as_raw_RgbdFrame(), val.as_raw_Mat()) }.into_result()
+    }
+    
+    fn release(&mut self) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdFrame_release(self.as_raw_RgbdFrame()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::rgbd::RgbdFrame
+/// Object that contains a frame data.
+pub struct RgbdFrame {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for RgbdFrame {
+    fn drop(&mut self) {
+        unsafe { sys::cv_RgbdFrame_delete(self.ptr) };
+    }
+}
+
+impl RgbdFrame {
+    #[inline(always)] pub fn as_raw_RgbdFrame(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for RgbdFrame {}
+
+impl crate::rgbd::RgbdFrameTrait for RgbdFrame {
+    #[inline(always)] fn as_raw_RgbdFrame(&self) -> *mut c_void { self.ptr }
+}
+
+impl RgbdFrame {
+    pub fn default() -> Result<crate::rgbd::RgbdFrame> {
+        unsafe { sys::cv_rgbd_RgbdFrame_RgbdFrame() }.into_result().map(|ptr| crate::rgbd::RgbdFrame { ptr })
+    }
+    
+    ///
+    /// ## C++ default parameters
+    /// * mask: Mat()
+    /// * normals: Mat()
+    /// * id: -1
+    pub fn new(image: &core::Mat, depth: &core::Mat, mask: &core::Mat, normals: &core::Mat, id: i32) -> Result<crate::rgbd::RgbdFrame> {
+        unsafe { sys::cv_rgbd_RgbdFrame_RgbdFrame_Mat_Mat_Mat_Mat_int(image.as_raw_Mat(), depth.as_raw_Mat(), mask.as_raw_Mat(), normals.as_raw_Mat(), id) }.into_result().map(|ptr| crate::rgbd::RgbdFrame { ptr })
+    }
+    
+    ///
+    /// ## C++ default parameters
+    /// * image: Mat()
+    /// * depth: Mat()
+    /// * mask: Mat()
+    /// * normals: Mat()
+    /// * id: -1
+    pub fn create(image: &core::Mat, depth: &core::Mat, mask: &core::Mat, normals: &core::Mat, id: i32) -> Result<types::PtrOfRgbdFrame> {
+        unsafe { sys::cv_rgbd_RgbdFrame_create_Mat_Mat_Mat_Mat_int(image.as_raw_Mat(), depth.as_raw_Mat(), mask.as_raw_Mat(), normals.as_raw_Mat(), id) }.into_result().map(|ptr| types::PtrOfRgbdFrame { ptr })
+    }
+    
+}
+
+// boxed class cv::rgbd::RgbdICPOdometry
+/// Odometry that merges RgbdOdometry and ICPOdometry by minimize sum of their energy functions.
+pub struct RgbdICPOdometry {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for RgbdICPOdometry {
+    fn drop(&mut self) {
+        unsafe { sys::cv_RgbdICPOdometry_delete(self.ptr) };
+    }
+}
+
+impl RgbdICPOdometry {
+    #[inline(always)] pub fn as_raw_RgbdICPOdometry(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for RgbdICPOdometry {}
+
+impl core::AlgorithmTrait for RgbdICPOdometry {
+    #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::rgbd::Odometry for RgbdICPOdometry {
+    #[inline(always)] fn as_raw_Odometry(&self) -> *mut c_void { self.ptr }
+}
+
+impl RgbdICPOdometry {
+    pub fn default() -> Result<crate::rgbd::RgbdICPOdometry> {
+        unsafe { sys::cv_rgbd_RgbdICPOdometry_RgbdICPOdometry() }.into_result().map(|ptr| crate::rgbd::RgbdICPOdometry { ptr })
+    }
+    
+    /// Constructor.
+    /// ## Parameters
+    /// * cameraMatrix: Camera matrix
+    /// * minDepth: Pixels with depth less than minDepth will not be used
+    /// * maxDepth: Pixels with depth larger than maxDepth will not be used
+    /// * maxDepthDiff: Correspondences between pixels of two given frames will be filtered out
+    ///      if their depth difference is larger than maxDepthDiff
+    /// * maxPointsPart: The method uses a random pixels subset of size frameWidth x frameHeight x pointsPart
+    /// * iterCounts: Count of iterations on each pyramid level.
+    /// * minGradientMagnitudes: For each pyramid level the pixels will be filtered out
+    ///                              if they have gradient magnitude less than minGradientMagnitudes[level].
+    /// * transformType: Class of trasformation
+    ///
+    /// ## C++ default parameters
+    /// * min_depth: Odometry::DEFAULT_MIN_DEPTH()
+    /// * max_depth: Odometry::DEFAULT_MAX_DEPTH()
+    /// * max_depth_diff: Odometry::DEFAULT_MAX_DEPTH_DIFF()
+    /// * max_points_part: Odometry::DEFAULT_MAX_POINTS_PART()
+    /// * iter_counts: std::vector<int>()
+    /// * min_gradient_magnitudes: std::vector<float>()
+    /// * transform_type: Odometry::RIGID_BODY_MOTION
+    pub fn new(camera_matrix: &core::Mat, min_depth: f32, max_depth: f32, max_depth_diff: f32, max_points_part: f32, iter_counts: &types::VectorOfint, min_gradient_magnitudes: &types::VectorOffloat, transform_type: i32) -> Result<crate::rgbd::RgbdICPOdometry> {
+        unsafe { sys::cv_rgbd_RgbdICPOdometry_RgbdICPOdometry_Mat_float_float_float_float_VectorOfint_VectorOffloat_int(camera_matrix.as_raw_Mat(), min_depth, max_depth, max_depth_diff, max_points_part, iter_counts.as_raw_VectorOfint(), min_gradient_magnitudes.as_raw_VectorOffloat(), transform_type) }.into_result().map(|ptr| crate::rgbd::RgbdICPOdometry { ptr })
+    }
+    
+    ///
+    /// ## C++ default parameters
+    /// * camera_matrix: Mat()
+    /// * min_depth: Odometry::DEFAULT_MIN_DEPTH()
+    /// * max_depth: Odometry::DEFAULT_MAX_DEPTH()
+    /// * max_depth_diff: Odometry::DEFAULT_MAX_DEPTH_DIFF()
+    /// * max_points_part: Odometry::DEFAULT_MAX_POINTS_PART()
+    /// * iter_counts: std::vector<int>()
+    /// * min_gradient_magnitudes: std::vector<float>()
+    /// * transform_type: Odometry::RIGID_BODY_MOTION
+    pub fn create(camera_matrix: &core::Mat, min_depth: f32, max_depth: f32, max_depth_diff: f32, max_points_part: f32, iter_counts: &types::VectorOfint, min_gradient_magnitudes: &types::VectorOffloat, transform_type: i32) -> Result<types::PtrOfRgbdICPOdometry> {
+        unsafe { sys::cv_rgbd_RgbdICPOdometry_create_Mat_float_float_float_float_VectorOfint_VectorOffloat_int(camera_matrix.as_raw_Mat(), min_depth, max_depth, max_depth_diff, max_points_part, iter_counts.as_raw_VectorOfint(), min_gradient_magnitudes.as_raw_VectorOffloat(), transform_type) }.into_result().map(|ptr| types::PtrOfRgbdICPOdometry { ptr })
+    }
+    
+    pub fn prepare_frame_cache(&self, frame: &mut types::PtrOfOdometryFrame, cache_type: i32) -> Result<core::Size> {
+        unsafe { sys::cv_rgbd_RgbdICPOdometry_prepareFrameCache_const_PtrOfOdometryFrame_int(self.as_raw_RgbdICPOdometry(), frame.as_raw_PtrOfOdometryFrame(), cache_type) }.into_result()
+    }
+    
+    pub fn get_camera_matrix(&self) -> Result<core::Mat> {
+        unsafe { sys::cv_rgbd_RgbdICPOdometry_getCameraMatrix_const(self.as_raw_RgbdICPOdometry()) }.into_result().map(|ptr| core::Mat { ptr })
+    }
+    
+    pub fn set_camera_matrix(&mut self, val: &core::Mat) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdICPOdometry_setCameraMatrix_Mat(self.as_raw_RgbdICPOdometry(), val.as_raw_Mat()) }.into_result()
+    }
+    
+    pub fn get_min_depth(&self) -> Result<f64> {
+        unsafe { sys::cv_rgbd_RgbdICPOdometry_getMinDepth_const(self.as_raw_RgbdICPOdometry()) }.into_result()
+    }
+    
+    pub fn set_min_depth(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdICPOdometry_setMinDepth_double(self.as_raw_RgbdICPOdometry(), val) }.into_result()
+    }
+    
+    pub fn get_max_depth(&self) -> Result<f64> {
+        unsafe { sys::cv_rgbd_RgbdICPOdometry_getMaxDepth_const(self.as_raw_RgbdICPOdometry()) }.into_result()
+    }
+    
+    pub fn set_max_depth(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdICPOdometry_setMaxDepth_double(self.as_raw_RgbdICPOdometry(), val) }.into_result()
+    }
+    
+    pub fn get_max_depth_diff(&self) -> Result<f64> {
+        unsafe { sys::cv_rgbd_RgbdICPOdometry_getMaxDepthDiff_const(self.as_raw_RgbdICPOdometry()) }.into_result()
+    }
+    
+    pub fn set_max_depth_diff(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdICPOdometry_setMaxDepthDiff_double(self.as_raw_RgbdICPOdometry(), val) }.into_result()
+    }
+    
+    pub fn get_max_points_part(&self) -> Result<f64> {
+        unsafe { sys::cv_rgbd_RgbdICPOdometry_getMaxPointsPart_const(self.as_raw_RgbdICPOdometry()) }.into_result()
+    }
+    
+    pub fn set_max_points_part(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdICPOdometry_setMaxPointsPart_double(self.as_raw_RgbdICPOdometry(), val) }.into_result()
+    }
+    
+    pub fn get_iteration_counts(&self) -> Result<core::Mat> {
+        unsafe { sys::cv_rgbd_RgbdICPOdometry_getIterationCounts_const(self.as_raw_RgbdICPOdometry()) }.into_result().map(|ptr| core::Mat { ptr })
+    }
+    
+    pub fn set_iteration_counts(&mut self, val: &core::Mat) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdICPOdometry_setIterationCounts_Mat(self.as_raw_RgbdICPOdometry(), val.as_raw_Mat()) }.into_result()
+    }
+    
+    pub fn get_min_gradient_magnitudes(&self) -> Result<core::Mat> {
+        unsafe { sys::cv_rgbd_RgbdICPOdometry_getMinGradientMagnitudes_const(self.as_raw_RgbdICPOdometry()) }.into_result().map(|ptr| core::Mat { ptr })
+    }
+    
+    pub fn set_min_gradient_magnitudes(&mut self, val: &core::Mat) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdICPOdometry_setMinGradientMagnitudes_Mat(self.as_raw_RgbdICPOdometry(), val.as_raw_Mat()) }.into_result()
+    }
+    
+    pub fn get_transform_type(&self) -> Result<i32> {
+        unsafe { sys::cv_rgbd_RgbdICPOdometry_getTransformType_const(self.as_raw_RgbdICPOdometry()) }.into_result()
+    }
+    
+    pub fn set_transform_type(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdICPOdometry_setTransformType_int(self.as_raw_RgbdICPOdometry(), val) }.into_result()
+    }
+    
+    pub fn get_max_translation(&self) -> Result<f64> {
+        unsafe { sys::cv_rgbd_RgbdICPOdometry_getMaxTranslation_const(self.as_raw_RgbdICPOdometry()) }.into_result()
+    }
+    
+    pub fn set_max_translation(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdICPOdometry_setMaxTranslation_double(self.as_raw_RgbdICPOdometry(), val) }.into_result()
+    }
+    
+    pub fn get_max_rotation(&self) -> Result<f64> {
+        unsafe { sys::cv_rgbd_RgbdICPOdometry_getMaxRotation_const(self.as_raw_RgbdICPOdometry()) }.into_result()
+    }
+    
+    pub fn set_max_rotation(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdICPOdometry_setMaxRotation_double(self.as_raw_RgbdICPOdometry(), val) }.into_result()
+    }
+    
+    pub fn get_normals_computer(&self) -> Result<types::PtrOfRgbdNormals> {
+        unsafe { sys::cv_rgbd_RgbdICPOdometry_getNormalsComputer_const(self.as_raw_RgbdICPOdometry()) }.into_result().map(|ptr| types::PtrOfRgbdNormals { ptr })
+    }
+    
+}
+
+// boxed class cv::rgbd::RgbdNormals
+/// Object that can compute the normals in an image.
+/// It is an object as it can cache data for speed efficiency
+/// The implemented methods are either:
+/// - FALS (the fastest) and SRI from
+/// ``Fast and Accurate Computation of Surface Normals from Range Images``
+/// by H. Badino, D. Huber, Y. Park and T. Kanade
+/// - the normals with bilateral filtering on a depth image from
+/// ``Gradient Response Maps for Real-Time Detection of Texture-Less Objects``
+/// by S. Hinterstoisser, C. Cagniart, S. Ilic, P. Sturm, N. Navab, P. Fua, and V. Lepetit
+pub struct RgbdNormals {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for RgbdNormals {
+    fn drop(&mut self) {
+        unsafe { sys::cv_RgbdNormals_delete(self.ptr) };
+    }
+}
+
+impl RgbdNormals {
+    #[inline(always)] pub fn as_raw_RgbdNormals(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for RgbdNormals {}
+
+impl core::AlgorithmTrait for RgbdNormals {
+    #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
+}
+
+impl RgbdNormals {
+    pub fn default() -> Result<crate::rgbd::RgbdNormals> {
+        unsafe { sys::cv_rgbd_RgbdNormals_RgbdNormals() }.into_result().map(|ptr| crate::rgbd::RgbdNormals { ptr })
+    }
+    
+    /// Constructor
+    /// ## Parameters
+    /// * rows: the number of rows of the depth image normals will be computed on
+    /// * cols: the number of cols of the depth image normals will be computed on
+    /// * depth: the depth of the normals (only CV_32F or CV_64F)
+    /// * K: the calibration matrix to use
+    /// * window_size: the window size to compute the normals: can only be 1,3,5 or 7
+    /// * method: one of the methods to use: RGBD_NORMALS_METHOD_SRI, RGBD_NORMALS_METHOD_FALS
+    ///
+    /// ## C++ default parameters
+    /// * window_size: 5
+    /// * method: RgbdNormals::RGBD_NORMALS_METHOD_FALS
+    pub fn new(rows: i32, cols: i32, depth: i32, k: &dyn core::ToInputArray, window_size: i32, method: i32) -> Result<crate::rgbd::RgbdNormals> {
+        input_array_arg!(k);
+        unsafe { sys::cv_rgbd_RgbdNormals_RgbdNormals_int_int_int__InputArray_int_int(rows, cols, depth, k.as_raw__InputArray(), window_size, method) }.into_result().map(|ptr| crate::rgbd::RgbdNormals { ptr })
+    }
+    
+    ///
+    /// ## C++ default parameters
+    /// * window_size: 5
+    /// * method: RgbdNormals::RGBD_NORMALS_METHOD_FALS
+    pub fn create(rows: i32, cols: i32, depth: i32, k: &dyn core::ToInputArray, window_size: i32, method: i32) -> Result<types::PtrOfRgbdNormals> {
+        input_array_arg!(k);
+        unsafe { sys::cv_rgbd_RgbdNormals_create_int_int_int__InputArray_int_int(rows, cols, depth, k.as_raw__InputArray(), window_size, method) }.into_result().map(|ptr| types::PtrOfRgbdNormals { ptr })
+    }
+    
+    /// Given a set of 3d points in a depth image, compute the normals at each point.
+    /// ## Parameters
+    /// * points: a rows x cols x 3 matrix of CV_32F/CV64F or a rows x cols x 1 CV_U16S
+    /// * normals: a rows x cols x 3 matrix
+    pub fn apply(&self, points: &dyn core::ToInputArray, normals: &mut dyn core::ToOutputArray) -> Result<()> {
+        input_array_arg!(points);
+        output_array_arg!(normals);
+        unsafe { sys::cv_rgbd_RgbdNormals_operator_call_const__InputArray__OutputArray(self.as_raw_RgbdNormals(), points.as_raw__InputArray(), normals.as_raw__OutputArray()) }.into_result()
+    }
+    
+    /// Initializes some data that is cached for later computation
+    /// If that function is not called, it will be called the first time normals are computed
+    pub fn initialize(&self) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdNormals_initialize_const(self.as_raw_RgbdNormals()) }.into_result()
+    }
+    
+    pub fn get_rows(&self) -> Result<i32> {
+        unsafe { sys::cv_rgbd_RgbdNormals_getRows_const(self.as_raw_RgbdNormals()) }.into_result()
+    }
+    
+    pub fn set_rows(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdNormals_setRows_int(self.as_raw_RgbdNormals(), val) }.into_result()
+    }
+    
+    pub fn get_cols(&self) -> Result<i32> {
+        unsafe { sys::cv_rgbd_RgbdNormals_getCols_const(self.as_raw_RgbdNormals()) }.into_result()
+    }
+    
+    pub fn set_cols(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdNormals_setCols_int(self.as_raw_RgbdNormals(), val) }.into_result()
+    }
+    
+    pub fn get_window_size(&self) -> Result<i32> {
+        unsafe { sys::cv_rgbd_RgbdNormals_getWindowSize_const(self.as_raw_RgbdNormals()) }.into_result()
+    }
+    
+    pub fn set_window_size(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdNormals_setWindowSize_int(self.as_raw_RgbdNormals(), val) }.into_result()
+    }
+    
+    pub fn get_depth(&self) -> Result<i32> {
+        unsafe { sys::cv_rgbd_RgbdNormals_getDepth_const(self.as_raw_RgbdNormals()) }.into_result()
+    }
+    
+    pub fn set_depth(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdNormals_setDepth_int(self.as_raw_RgbdNormals(), val) }.into_result()
+    }
+    
+    pub fn get_k(&self) -> Result<core::Mat> {
+        unsafe { sys::cv_rgbd_RgbdNormals_getK_const(self.as_raw_RgbdNormals()) }.into_result().map(|ptr| core::Mat { ptr })
+    }
+    
+    pub fn set_k(&mut self, val: &core::Mat) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdNormals_setK_Mat(self.as_raw_RgbdNormals(), val.as_raw_Mat()) }.into_result()
+    }
+    
+    pub fn get_method(&self) -> Result<i32> {
+        unsafe { sys::cv_rgbd_RgbdNormals_getMethod_const(self.as_raw_RgbdNormals()) }.into_result()
+    }
+    
+    pub fn set_method(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdNormals_setMethod_int(self.as_raw_RgbdNormals(), val) }.into_result()
+    }
+    
+}
+
+// boxed class cv::rgbd::RgbdOdometry
+/// Odometry based on the paper "Real-Time Visual Odometry from Dense RGB-D Images",
+/// F. Steinbucker, J. Strum, D. Cremers, ICCV, 2011.
+pub struct RgbdOdometry {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for RgbdOdometry {
+    fn drop(&mut self) {
+        unsafe { sys::cv_RgbdOdometry_delete(self.ptr) };
+    }
+}
+
+impl RgbdOdometry {
+    #[inline(always)] pub fn as_raw_RgbdOdometry(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for RgbdOdometry {}
+
+impl core::AlgorithmTrait for RgbdOdometry {
+    #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::rgbd::Odometry for RgbdOdometry {
+    #[inline(always)] fn as_raw_Odometry(&self) -> *mut c_void { self.ptr }
+}
+
+impl RgbdOdometry {
+    pub fn default() -> Result<crate::rgbd::RgbdOdometry> {
+        unsafe { sys::cv_rgbd_RgbdOdometry_RgbdOdometry() }.into_result().map(|ptr| crate::rgbd::RgbdOdometry { ptr })
+    }
+    
+    /// Constructor.
+    /// ## Parameters
+    /// * cameraMatrix: Camera matrix
+    /// * minDepth: Pixels with depth less than minDepth will not be used (in meters)
+    /// * maxDepth: Pixels with depth larger than maxDepth will not be used (in meters)
+    /// * maxDepthDiff: Correspondences between pixels of two given frames will be filtered out
+    ///      if their depth difference is larger than maxDepthDiff (in meters)
+    /// * iterCounts: Count of iterations on each pyramid level.
+    /// * minGradientMagnitudes: For each pyramid level the pixels will be filtered out
+    ///                              if they have gradient magnitude less than minGradientMagnitudes[level].
+    /// * maxPointsPart: The method uses a random pixels subset of size frameWidth x frameHeight x pointsPart
+    /// * transformType: Class of transformation
+    ///
+    /// ## C++ default parameters
+    /// * min_depth: Odometry::DEFAULT_MIN_DEPTH()
+    /// * max_depth: Odometry::DEFAULT_MAX_DEPTH()
+    /// * max_depth_diff: Odometry::DEFAULT_MAX_DEPTH_DIFF()
+    /// * iter_counts: std::vector<int>()
+    /// * min_gradient_magnitudes: std::vector<float>()
+    /// * max_points_part: Odometry::DEFAULT_MAX_POINTS_PART()
+    /// * transform_type: Odometry::RIGID_BODY_MOTION
+    pub fn new(camera_matrix: &core::Mat, min_depth: f32, max_depth: f32, max_depth_diff: f32, iter_counts: &types::VectorOfint, min_gradient_magnitudes: &types::VectorOffloat, max_points_part: f32, transform_type: i32) -> Result<crate::rgbd::RgbdOdometry> {
+        unsafe { sys::cv_rgbd_RgbdOdometry_RgbdOdometry_Mat_float_float_float_VectorOfint_VectorOffloat_float_int(camera_matrix.as_raw_Mat(), min_depth, max_depth, max_depth_diff, iter_counts.as_raw_VectorOfint(), min_gradient_magnitudes.as_raw_VectorOffloat(), max_points_part, transform_type) }.into_result().map(|ptr| crate::rgbd::RgbdOdometry { ptr })
+    }
+    
+    ///
+    /// ## C++ default parameters
+    /// * camera_matrix: Mat()
+    /// * min_depth: Odometry::DEFAULT_MIN_DEPTH()
+    /// * max_depth: Odometry::DEFAULT_MAX_DEPTH()
+    /// * max_depth_diff: Odometry::DEFAULT_MAX_DEPTH_DIFF()
+    /// * iter_counts: std::vector<int>()
+    /// * min_gradient_magnitudes: std::vector<float>()
+    /// * max_points_part: Odometry::DEFAULT_MAX_POINTS_PART()
+    /// * transform_type: Odometry::RIGID_BODY_MOTION
+    pub fn create(camera_matrix: &core::Mat, min_depth: f32, max_depth: f32, max_depth_diff: f32, iter_counts: &types::VectorOfint, min_gradient_magnitudes: &types::VectorOffloat, max_points_part: f32, transform_type: i32) -> Result<types::PtrOfRgbdOdometry> {
+        unsafe { sys::cv_rgbd_RgbdOdometry_create_Mat_float_float_float_VectorOfint_VectorOffloat_float_int(camera_matrix.as_raw_Mat(), min_depth, max_depth, max_depth_diff, iter_counts.as_raw_VectorOfint(), min_gradient_magnitudes.as_raw_VectorOffloat(), max_points_part, transform_type) }.into_result().map(|ptr| types::PtrOfRgbdOdometry { ptr })
+    }
+    
+    pub fn prepare_frame_cache(&self, frame: &mut types::PtrOfOdometryFrame, cache_type: i32) -> Result<core::Size> {
+        unsafe { sys::cv_rgbd_RgbdOdometry_prepareFrameCache_const_PtrOfOdometryFrame_int(self.as_raw_RgbdOdometry(), frame.as_raw_PtrOfOdometryFrame(), cache_type) }.into_result()
+    }
+    
+    pub fn get_camera_matrix(&self) -> Result<core::Mat> {
+        unsafe { sys::cv_rgbd_RgbdOdometry_getCameraMatrix_const(self.as_raw_RgbdOdometry()) }.into_result().map(|ptr| core::Mat { ptr })
+    }
+    
+    pub fn set_camera_matrix(&mut self, val: &core::Mat) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdOdometry_setCameraMatrix_Mat(self.as_raw_RgbdOdometry(), val.as_raw_Mat()) }.into_result()
+    }
+    
+    pub fn get_min_depth(&self) -> Result<f64> {
+        unsafe { sys::cv_rgbd_RgbdOdometry_getMinDepth_const(self.as_raw_RgbdOdometry()) }.into_result()
+    }
+    
+    pub fn set_min_depth(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdOdometry_setMinDepth_double(self.as_raw_RgbdOdometry(), val) }.into_result()
+    }
+    
+    pub fn get_max_depth(&self) -> Result<f64> {
+        unsafe { sys::cv_rgbd_RgbdOdometry_getMaxDepth_const(self.as_raw_RgbdOdometry()) }.into_result()
+    }
+    
+    pub fn set_max_depth(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdOdometry_setMaxDepth_double(self.as_raw_RgbdOdometry(), val) }.into_result()
+    }
+    
+    pub fn get_max_depth_diff(&self) -> Result<f64> {
+        unsafe { sys::cv_rgbd_RgbdOdometry_getMaxDepthDiff_const(self.as_raw_RgbdOdometry()) }.into_result()
+    }
+    
+    pub fn set_max_depth_diff(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdOdometry_setMaxDepthDiff_double(self.as_raw_RgbdOdometry(), val) }.into_result()
+    }
+    
+    pub fn get_iteration_counts(&self) -> Result<core::Mat> {
+        unsafe { sys::cv_rgbd_RgbdOdometry_getIterationCounts_const(self.as_raw_RgbdOdometry()) }.into_result().map(|ptr| core::Mat { ptr })
+    }
+    
+    pub fn set_iteration_counts(&mut self, val: &core::Mat) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdOdometry_setIterationCounts_Mat(self.as_raw_RgbdOdometry(), val.as_raw_Mat()) }.into_result()
+    }
+    
+    pub fn get_min_gradient_magnitudes(&self) -> Result<core::Mat> {
+        unsafe { sys::cv_rgbd_RgbdOdometry_getMinGradientMagnitudes_const(self.as_raw_RgbdOdometry()) }.into_result().map(|ptr| core::Mat { ptr })
+    }
+    
+    pub fn set_min_gradient_magnitudes(&mut self, val: &core::Mat) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdOdometry_setMinGradientMagnitudes_Mat(self.as_raw_RgbdOdometry(), val.as_raw_Mat()) }.into_result()
+    }
+    
+    pub fn get_max_points_part(&self) -> Result<f64> {
+        unsafe { sys::cv_rgbd_RgbdOdometry_getMaxPointsPart_const(self.as_raw_RgbdOdometry()) }.into_result()
+    }
+    
+    pub fn set_max_points_part(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdOdometry_setMaxPointsPart_double(self.as_raw_RgbdOdometry(), val) }.into_result()
+    }
+    
+    pub fn get_transform_type(&self) -> Result<i32> {
+        unsafe { sys::cv_rgbd_RgbdOdometry_getTransformType_const(self.as_raw_RgbdOdometry()) }.into_result()
+    }
+    
+    pub fn set_transform_type(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdOdometry_setTransformType_int(self.as_raw_RgbdOdometry(), val) }.into_result()
+    }
+    
+    pub fn get_max_translation(&self) -> Result<f64> {
+        unsafe { sys::cv_rgbd_RgbdOdometry_getMaxTranslation_const(self.as_raw_RgbdOdometry()) }.into_result()
+    }
+    
+    pub fn set_max_translation(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdOdometry_setMaxTranslation_double(self.as_raw_RgbdOdometry(), val) }.into_result()
+    }
+    
+    pub fn get_max_rotation(&self) -> Result<f64> {
+        unsafe { sys::cv_rgbd_RgbdOdometry_getMaxRotation_const(self.as_raw_RgbdOdometry()) }.into_result()
+    }
+    
+    pub fn set_max_rotation(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdOdometry_setMaxRotation_double(self.as_raw_RgbdOdometry(), val) }.into_result()
+    }
+    
+}
+
+// boxed class cv::rgbd::RgbdPlane
+/// Object that can compute planes in an image
+pub struct RgbdPlane {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for RgbdPlane {
+    fn drop(&mut self) {
+        unsafe { sys::cv_RgbdPlane_delete(self.ptr) };
+    }
+}
+
+impl RgbdPlane {
+    #[inline(always)] pub fn as_raw_RgbdPlane(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for RgbdPlane {}
+
+impl core::AlgorithmTrait for RgbdPlane {
+    #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
+}
+
+impl RgbdPlane {
+    ///
+    /// ## C++ default parameters
+    /// * method: RgbdPlane::RGBD_PLANE_METHOD_DEFAULT
+    pub fn new(method: i32) -> Result<crate::rgbd::RgbdPlane> {
+        unsafe { sys::cv_rgbd_RgbdPlane_RgbdPlane_int(method) }.into_result().map(|ptr| crate::rgbd::RgbdPlane { ptr })
+    }
+    
+    /// Constructor
+    /// ## Parameters
+    /// * block_size: The size of the blocks to look at for a stable MSE
+    /// * min_size: The minimum size of a cluster to be considered a plane
+    /// * threshold: The maximum distance of a point from a plane to belong to it (in meters)
+    /// * sensor_error_a: coefficient of the sensor error. 0 by default, 0.0075 for a Kinect
+    /// * sensor_error_b: coefficient of the sensor error. 0 by default
+    /// * sensor_error_c: coefficient of the sensor error. 0 by default
+    /// * method: The method to use to compute the planes.
+    ///
+    /// ## C++ default parameters
+    /// * sensor_error_a: 0
+    /// * sensor_error_b: 0
+    /// * sensor_error_c: 0
+    pub fn new_with_params(method: i32, block_size: i32, min_size: i32, threshold: f64, sensor_error_a: f64, sensor_error_b: f64, sensor_error_c: f64) -> Result<crate::rgbd::RgbdPlane> {
+        unsafe { sys::cv_rgbd_RgbdPlane_RgbdPlane_int_int_int_double_double_double_double(method, block_size, min_size, threshold, sensor_error_a, sensor_error_b, sensor_error_c) }.into_result().map(|ptr| crate::rgbd::RgbdPlane { ptr })
+    }
+    
+    ///
+    /// ## C++ default parameters
+    /// * sensor_error_a: 0
+    /// * sensor_error_b: 0
+    /// * sensor_error_c: 0
+    pub fn create(method: i32, block_size: i32, min_size: i32, threshold: f64, sensor_error_a: f64, sensor_error_b: f64, sensor_error_c: f64) -> Result<types::PtrOfRgbdPlane> {
+        unsafe { sys::cv_rgbd_RgbdPlane_create_int_int_int_double_double_double_double(method, block_size, min_size, threshold, sensor_error_a, sensor_error_b, sensor_error_c) }.into_result().map(|ptr| types::PtrOfRgbdPlane { ptr })
+    }
+    
+    /// Find The planes in a depth image
+    /// ## Parameters
+    /// * points3d: the 3d points organized like the depth image: rows x cols with 3 channels
+    /// * normals: the normals for every point in the depth image
+    /// * mask: An image where each pixel is labeled with the plane it belongs to
+    ///        and 255 if it does not belong to any plane
+    /// * plane_coefficients: the coefficients of the corresponding planes (a,b,c,d) such that ax+by+cz+d=0, norm(a,b,c)=1
+    ///        and c < 0 (so that the normal points towards the camera)
+    pub fn apply(&mut self, points3d: &dyn core::ToInputArray, normals: &dyn core::ToInputArray, mask: &mut dyn core::ToOutputArray, plane_coefficients: &mut dyn core::ToOutputArray) -> Result<()> {
+        input_array_arg!(points3d);
+        input_array_arg!(normals);
+        output_array_arg!(mask);
+        output_array_arg!(plane_coefficients);
+        unsafe { sys::cv_rgbd_RgbdPlane_operator_call__InputArray__InputArray__OutputArray__OutputArray(self.as_raw_RgbdPlane(), points3d.as_raw__InputArray(), normals.as_raw__InputArray(), mask.as_raw__OutputArray(), plane_coefficients.as_raw__OutputArray()) }.into_result()
+    }
+    
+    /// Find The planes in a depth image but without doing a normal check, which is faster but less accurate
+    /// ## Parameters
+    /// * points3d: the 3d points organized like the depth image: rows x cols with 3 channels
+    /// * mask: An image where each pixel is labeled with the plane it belongs to
+    ///        and 255 if it does not belong to any plane
+    /// * plane_coefficients: the coefficients of the corresponding planes (a,b,c,d) such that ax+by+cz+d=0
+    pub fn apply_without_normals(&mut self, points3d: &dyn core::ToInputArray, mask: &mut dyn core::ToOutputArray, plane_coefficients: &mut dyn core::ToOutputArray) -> Result<()> {
+        input_array_arg!(points3d);
+        output_array_arg!(mask);
+        output_array_arg!(plane_coefficients);
+        unsafe { sys::cv_rgbd_RgbdPlane_operator_call__InputArray__OutputArray__OutputArray(self.as_raw_RgbdPlane(), points3d.as_raw__InputArray(), mask.as_raw__OutputArray(), plane_coefficients.as_raw__OutputArray()) }.into_result()
+    }
+    
+    pub fn get_block_size(&self) -> Result<i32> {
+        unsafe { sys::cv_rgbd_RgbdPlane_getBlockSize_const(self.as_raw_RgbdPlane()) }.into_result()
+    }
+    
+    pub fn set_block_size(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdPlane_setBlockSize_int(self.as_raw_RgbdPlane(), val) }.into_result()
+    }
+    
+    pub fn get_min_size(&self) -> Result<i32> {
+        unsafe { sys::cv_rgbd_RgbdPlane_getMinSize_const(self.as_raw_RgbdPlane()) }.into_result()
+    }
+    
+    pub fn set_min_size(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdPlane_setMinSize_int(self.as_raw_RgbdPlane(), val) }.into_result()
+    }
+    
+    pub fn get_method(&self) -> Result<i32> {
+        unsafe { sys::cv_rgbd_RgbdPlane_getMethod_const(self.as_raw_RgbdPlane()) }.into_result()
+    }
+    
+    pub fn set_method(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdPlane_setMethod_int(self.as_raw_RgbdPlane(), val) }.into_result()
+    }
+    
+    pub fn get_threshold(&self) -> Result<f64> {
+        unsafe { sys::cv_rgbd_RgbdPlane_getThreshold_const(self.as_raw_RgbdPlane()) }.into_result()
+    }
+    
+    pub fn set_threshold(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdPlane_setThreshold_double(self.as_raw_RgbdPlane(), val) }.into_result()
+    }
+    
+    pub fn get_sensor_error_a(&self) -> Result<f64> {
+        unsafe { sys::cv_rgbd_RgbdPlane_getSensorErrorA_const(self.as_raw_RgbdPlane()) }.into_result()
+    }
+    
+    pub fn set_sensor_error_a(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdPlane_setSensorErrorA_double(self.as_raw_RgbdPlane(), val) }.into_result()
+    }
+    
+    pub fn get_sensor_error_b(&self) -> Result<f64> {
+        unsafe { sys::cv_rgbd_RgbdPlane_getSensorErrorB_const(self.as_raw_RgbdPlane()) }.into_result()
+    }
+    
+    pub fn set_sensor_error_b(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdPlane_setSensorErrorB_double(self.as_raw_RgbdPlane(), val) }.into_result()
+    }
+    
+    pub fn get_sensor_error_c(&self) -> Result<f64> {
+        unsafe { sys::cv_rgbd_RgbdPlane_getSensorErrorC_const(self.as_raw_RgbdPlane()) }.into_result()
+    }
+    
+    pub fn set_sensor_error_c(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_rgbd_RgbdPlane_setSensorErrorC_double(self.as_raw_RgbdPlane(), val) }.into_result()
+    }
+    
+}
+
+pub const OdometryFrame_CACHE_ALL: i32 = 0x3; // 3
@@ -0,0 +1,523 @@
+//! # Saliency API
+//!
+//! Many computer vision applications may benefit from understanding where humans focus given a scene.
+//! Other than cognitively understanding the way human perceive images and scenes, finding salient
+//! regions and objects in the images helps various tasks such as speeding up object detection, object
+//! recognition, object tracking and content-aware image editing.
+//!
+//! About the saliency, there is a rich literature but the development is very fragmented. The principal
+//! purpose of this API is to give a unique interface, a unique framework for use and plug sever
+//! saliency algorithms, also with very different nature and methodology, but they share the same
+//! purpose, organizing algorithms into three main categories:
+//!
+//! **Static Saliency**: algorithms belonging to this category, exploit different image features that
+//! allow to detect salient objects in a non dynamic scenarios.
+//!
+//! **Motion Saliency**: algorithms belonging to this category, are particularly focused to detect
+//! salient objects over time (hence also over frame), then there is a temporal component sealing
+//! cosider that allows to detect "moving" objects as salient, meaning therefore also the more general
+//! sense of detection the changes in the scene.
+//!
+//! **Objectness**: Objectness is usually represented as a value which reflects how likely an image
+//! window covers an object of any category. Algorithms belonging to this category, avoid making
+//! decisions early on, by proposing a small number of category-independent proposals, that are expected
+//! to cover all objects in an image. Being able to perceive objects before identifying them is closely
+//! related to bottom up visual attention (saliency).
+//!
+//! ![Saliency diagram](https://docs.opencv.org/4.2.0/saliency.png)
+//!
+//! To see how API works, try tracker demo:
+//! <https://github.com/fpuja/opencv_contrib/blob/saliencyModuleDevelop/modules/saliency/samples/computeSaliency.cpp>
+//!
+//!
+//! Note: This API has been designed with PlantUML. If you modify this API please change UML.
+use crate::{mod_prelude::*, core, sys, types};
+use crate::core::{_InputArrayTrait, _OutputArrayTrait};
+
+
+// Generating impl for trait crate::saliency::MotionSaliency
+
+pub trait MotionSaliencyTrait: crate::saliency::Saliency {
+    fn as_raw_MotionSaliency(&self) -> *mut c_void;
+}
+
+// boxed class cv::saliency::MotionSaliency
+
+pub struct MotionSaliency {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for MotionSaliency {
+    fn drop(&mut self) {
+        unsafe { sys::cv_MotionSaliency_delete(self.ptr) };
+    }
+}
+
+impl MotionSaliency {
+    #[inline(always)] pub fn as_raw_MotionSaliency(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for MotionSaliency {}
+
+impl core::AlgorithmTrait for MotionSaliency {
+    #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::saliency::MotionSaliencyTrait for MotionSaliency {
+    #[inline(always)] fn as_raw_MotionSaliency(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::saliency::Saliency for MotionSaliency {
+    #[inline(always)] fn as_raw_Saliency(&self) -> *mut c_void { self.ptr }
+}
+
+// boxed class cv::saliency::MotionSaliencyBinWangApr2014
+/// the Fast Self-tuning Background Subtraction Algorithm from [BinWangApr2014](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_BinWangApr2014)
+pub struct MotionSaliencyBinWangApr2014 {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for MotionSaliencyBinWangApr2014 {
+    fn drop(&mut self) {
+        unsafe { sys::cv_MotionSaliencyBinWangApr2014_delete(self.ptr) };
+    }
+}
+
+impl MotionSaliencyBinWangApr2014 {
+    #[inline(always)] pub fn as_raw_MotionSaliencyBinWangApr2014(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for MotionSaliencyBinWangApr2014 {}
+
+impl core::AlgorithmTrait for MotionSaliencyBinWangApr2014 {
+    #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::saliency::MotionSaliencyTrait for MotionSaliencyBinWangApr2014 {
+    #[inline(always)] fn as_raw_MotionSaliency(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::saliency::Saliency for MotionSaliencyBinWangApr2014 {
+    #[inline(always)] fn as_raw_Saliency(&self) -> *mut c_void { self.ptr }
+}
+
+impl MotionSaliencyBinWangApr2014 {
+    pub fn default() -> Result<crate::saliency::MotionSaliencyBinWangApr2014> {
+        unsafe { sys::cv_saliency_MotionSaliencyBinWangApr2014_MotionSaliencyBinWangApr2014() }.into_result().map(|ptr| crate::saliency::MotionSaliencyBinWangApr2014 { ptr })
+    }
+    
+    pub fn create() -> Result<types::PtrOfMotionSaliencyBinWangApr2014> {
+        unsafe { sys::cv_saliency_MotionSaliencyBinWangApr2014_create() }.into_result().map(|ptr| types::PtrOfMotionSaliencyBinWangApr2014 { ptr })
+    }
+    
+    pub fn compute_saliency(&mut self, image: &dyn core::ToInputArray, saliency_map: &mut dyn core::ToOutputArray) -> Result<bool> {
+        input_array_arg!(image);
+        output_array_arg!(saliency_map);
+        unsafe { sys::cv_saliency_MotionSaliencyBinWangApr2014_computeSaliency__InputArray__OutputArray(self.as_raw_MotionSaliencyBinWangApr2014(), image.as_raw__InputArray(), saliency_map.as_raw__OutputArray()) }.into_result()
+    }
+    
+    /// This is a utility function that allows to set the correct size (taken from the input image) in the
+    /// corresponding variables that will be used to size the data structures of the algorithm.
+    /// ## Parameters
+    /// * W: width of input image
+    /// * H: height of input image
+    pub fn set_imagesize(&mut self, w: i32, h: i32) -> Result<()> {
+        unsafe { sys::cv_saliency_MotionSaliencyBinWangApr2014_setImagesize_int_int(self.as_raw_MotionSaliencyBinWangApr2014(), w, h) }.into_result()
+    }
+    
+    /// This function allows the correct initialization of all data structures that will be used by the
+    /// algorithm.
+    pub fn init(&mut self) -> Result<bool> {
+        unsafe { sys::cv_saliency_MotionSaliencyBinWangApr2014_init(self.as_raw_MotionSaliencyBinWangApr2014()) }.into_result()
+    }
+    
+    pub fn get_image_width(&self) -> Result<i32> {
+        unsafe { sys::cv_saliency_MotionSaliencyBinWangApr2014_getImageWidth_const(self.as_raw_MotionSaliencyBinWangApr2014()) }.into_result()
+    }
+    
+    pub fn set_image_width(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_saliency_MotionSaliencyBinWangApr2014_setImageWidth_int(self.as_raw_MotionSaliencyBinWangApr2014(), val) }.into_result()
+    }
+    
+    pub fn get_image_height(&self) -> Result<i32> {
+        unsafe { sys::cv_saliency_MotionSaliencyBinWangApr2014_getImageHeight_const(self.as_raw_MotionSaliencyBinWangApr2014()) }.into_result()
+    }
+    
+    pub fn set_image_height(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_saliency_MotionSaliencyBinWangApr2014_setImageHeight_int(self.as_raw_MotionSaliencyBinWangApr2014(), val) }.into_result()
+    }
+    
+}
+
+// Generating impl for trait crate::saliency::Objectness
+
+pub trait ObjectnessTrait: crate::saliency::Saliency {
+    fn as_raw_Objectness(&self) -> *mut c_void;
+}
+
+// boxed class cv::saliency::Objectness
+
+pub struct Objectness {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Objectness {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Objectness_delete(self.ptr) };
+    }
+}
+
+impl Objectness {
+    #[inline(always)] pub fn as_raw_Objectness(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Objectness {}
+
+impl core::AlgorithmTrait for Objectness {
+    #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::saliency::ObjectnessTrait for Objectness {
+    #[inline(always)] fn as_raw_Objectness(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::saliency::Saliency for Objectness {
+    #[inline(always)] fn as_raw_Saliency(&self) -> *mut c_void { self.ptr }
+}
+
+// boxed class cv::saliency::ObjectnessBING
+/// the Binarized normed gradients algorithm from [BING](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_BING)
+pub struct ObjectnessBING {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for ObjectnessBING {
+    fn drop(&mut self) {
+        unsafe { sys::cv_ObjectnessBING_delete(self.ptr) };
+    }
+}
+
+impl ObjectnessBING {
+    #[inline(always)] pub fn as_raw_ObjectnessBING(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for ObjectnessBING {}
+
+impl core::AlgorithmTrait for ObjectnessBING {
+    #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::saliency::ObjectnessTrait for ObjectnessBING {
+    #[inline(always)] fn as_raw_Objectness(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::saliency::Saliency for ObjectnessBING {
+    #[inline(always)] fn as_raw_Saliency(&self) -> *mut c_void { self.ptr }
+}
+
+impl ObjectnessBING {
+    pub fn default() -> Result<crate::saliency::ObjectnessBING> {
+        unsafe { sys::cv_saliency_ObjectnessBING_ObjectnessBING() }.into_result().map(|ptr| crate::saliency::ObjectnessBING { ptr })
+    }
+    
+    pub fn create() -> Result<types::PtrOfObjectnessBING> {
+        unsafe { sys::cv_saliency_ObjectnessBING_create() }.into_result().map(|ptr| types::PtrOfObjectnessBING { ptr })
+    }
+    
+    pub fn compute_saliency(&mut self, image: &dyn core::ToInputArray, saliency_map: &mut dyn core::ToOutputArray) -> Result<bool> {
+        input_array_arg!(image);
+        output_array_arg!(saliency_map);
+        unsafe { sys::cv_saliency_ObjectnessBING_computeSaliency__InputArray__OutputArray(self.as_raw_ObjectnessBING(), image.as_raw__InputArray(), saliency_map.as_raw__OutputArray()) }.into_result()
+    }
+    
+    pub fn read(&mut self) -> Result<()> {
+        unsafe { sys::cv_saliency_ObjectnessBING_read(self.as_raw_ObjectnessBING()) }.into_result()
+    }
+    
+    pub fn write(&self) -> Result<()> {
+        unsafe { sys::cv_saliency_ObjectnessBING_write_const(self.as_raw_ObjectnessBING()) }.into_result()
+    }
+    
+    /// Return the list of the rectangles' objectness value,
+    ///
+    /// in the same order as the *vector\<Vec4i\> objectnessBoundingBox* returned by the algorithm (in
+    /// computeSaliencyImpl function). The bigger value these scores are, it is more likely to be an
+    /// object window.
+    pub fn get_objectness_values(&mut self) -> Result<types::VectorOffloat> {
+        unsafe { sys::cv_saliency_ObjectnessBING_getobjectnessValues(self.as_raw_ObjectnessBING()) }.into_result().map(|ptr| unsafe { types::VectorOffloat::from_raw_ptr(ptr) })
+    }
+    
+    /// This is a utility function that allows to set the correct path from which the algorithm will load
+    /// the trained model.
+    /// ## Parameters
+    /// * trainingPath: trained model path
+    pub fn set_training_path(&mut self, training_path: &str) -> Result<()> {
+        string_arg!(training_path);
+        unsafe { sys::cv_saliency_ObjectnessBING_setTrainingPath_String(self.as_raw_ObjectnessBING(), training_path.as_ptr()) }.into_result()
+    }
+    
+    /// This is a utility function that allows to set an arbitrary path in which the algorithm will save the
+    /// optional results
+    ///
+    /// (ie writing on file the total number and the list of rectangles returned by objectess, one for
+    /// each row).
+    /// ## Parameters
+    /// * resultsDir: results' folder path
+    pub fn set_bb_res_dir(&mut self, results_dir: &str) -> Result<()> {
+        string_arg!(results_dir);
+        unsafe { sys::cv_saliency_ObjectnessBING_setBBResDir_String(self.as_raw_ObjectnessBING(), results_dir.as_ptr()) }.into_result()
+    }
+    
+    pub fn get_base(&self) -> Result<f64> {
+        unsafe { sys::cv_saliency_ObjectnessBING_getBase_const(self.as_raw_ObjectnessBING()) }.into_result()
+    }
+    
+    pub fn set_base(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_saliency_ObjectnessBING_setBase_double(self.as_raw_ObjectnessBING(), val) }.into_result()
+    }
+    
+    pub fn get_nss(&self) -> Result<i32> {
+        unsafe { sys::cv_saliency_ObjectnessBING_getNSS_const(self.as_raw_ObjectnessBING()) }.into_result()
+    }
+    
+    pub fn set_nss(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_saliency_ObjectnessBING_setNSS_int(self.as_raw_ObjectnessBING(), val) }.into_result()
+    }
+    
+    pub fn get_w(&self) -> Result<i32> {
+        unsafe { sys::cv_saliency_ObjectnessBING_getW_const(self.as_raw_ObjectnessBING()) }.into_result()
+    }
+    
+    pub fn set_w(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_saliency_ObjectnessBING_setW_int(self.as_raw_ObjectnessBING(), val) }.into_result()
+    }
+    
+}
+
+// Generating impl for trait crate::saliency::Saliency
+
+pub trait Saliency: core::AlgorithmTrait {
+    fn as_raw_Saliency(&self) -> *mut c_void;
+    /// \brief Compute the saliency
+    /// \param image        The image.
+    /// \param saliencyMap      The computed saliency map.
+    /// \return true if the saliency map is computed, false otherwise
+    fn compute_saliency(&mut self, image: &dyn core::ToInputArray, saliency_map: &mut dyn core::ToOutputArray) -> Result<bool> {
+        input_array_arg!(image);
+        output_array_arg!(saliency_map);
+        unsafe { sys::cv_saliency_Saliency_computeSaliency__InputArray__OutputArray(self.as_raw_Saliency(), image.as_raw__InputArray(), saliency_map.as_raw__OutputArray()) }.into_result()
+    }
+    
+}
+
+// Generating impl for trait crate::saliency::StaticSaliency
+
+pub trait StaticSaliencyTrait: crate::saliency::Saliency {
+    fn as_raw_StaticSaliency(&self) -> *mut c_void;
+    /// This function perform a binary map of given saliency map. This is obtained in this
+    /// way:
+    ///
+    /// In a first step, to improve the definition of interest areas and facilitate identification of
+    /// targets, a segmentation by clustering is performed, using *K-means algorithm*. Then, to gain a
+    /// binary representation of clustered saliency map, since values of the map can vary according to
+    /// the characteristics of frame under analysis, it is not convenient to use a fixed threshold. So,
+    /// *Otsu's algorithm* is used, which assumes that the image to be thresholded contains two classes
+    /// of pixels or bi-modal histograms (e.g. foreground and back-ground pixels); later on, the
+    /// algorithm calculates the optimal threshold separating those two classes, so that their
+    /// intra-class variance is minimal.
+    ///
+    /// ## Parameters
+    /// * _saliencyMap: the saliency map obtained through one of the specialized algorithms
+    /// * _binaryMap: the binary map
+    fn compute_binary_map(&mut self, _saliency_map: &dyn core::ToInputArray, _binary_map: &mut dyn core::ToOutputArray) -> Result<bool> {
+        input_array_arg!(_saliency_map);
+        output_array_arg!(_binary_map);
+        unsafe { sys::cv_saliency_StaticSaliency_computeBinaryMap__InputArray__OutputArray(self.as_raw_StaticSaliency(), _saliency_map.as_raw__InputArray(), _binary_map.as_raw__OutputArray()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::saliency::StaticSaliency
+
+pub struct StaticSaliency {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for StaticSaliency {
+    fn drop(&mut self) {
+        unsafe { sys::cv_StaticSaliency_delete(self.ptr) };
+    }
+}
+
+impl StaticSaliency {
+    #[inline(always)] pub fn as_raw_StaticSaliency(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for StaticSaliency {}
+
+impl core::AlgorithmTrait for StaticSaliency {
+    #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::saliency::Saliency for StaticSaliency {
+    #[inline(always)] fn as_raw_Saliency(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::saliency::StaticSaliencyTrait for StaticSaliency {
+    #[inline(always)] fn as_raw_StaticSaliency(&self) -> *mut c_void { self.ptr }
+}
+
+// boxed class cv::saliency::StaticSaliencyFineGrained
+/// the Fine Grained Saliency approach from [FGS](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_FGS)
+///
+/// This method calculates saliency based on center-surround differences.
+/// High resolution saliency maps are generated in real time by using integral images.
+pub struct StaticSaliencyFineGrained {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for StaticSaliencyFineGrained {
+    fn drop(&mut self) {
+        unsafe { sys::cv_StaticSaliencyFineGrained_delete(self.ptr) };
+    }
+}
+
+impl StaticSaliencyFineGrained {
+    #[inline(always)] pub fn as_raw_StaticSaliencyFineGrained(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for StaticSaliencyFineGrained {}
+
+impl core::AlgorithmTrait for StaticSaliencyFineGrained {
+    #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::saliency::Saliency for StaticSaliencyFineGrained {
+    #[inline(always)] fn as_raw_Saliency(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::saliency::StaticSaliencyTrait for StaticSaliencyFineGrained {
+    #[inline(always)] fn as_raw_StaticSaliency(&self) -> *mut c_void { self.ptr }
+}
+
+impl StaticSaliencyFineGrained {
+    pub fn default() -> Result<crate::saliency::StaticSaliencyFineGrained> {
+        unsafe { sys::cv_saliency_StaticSaliencyFineGrained_StaticSaliencyFineGrained() }.into_result().map(|ptr| crate::saliency::StaticSaliencyFineGrained { ptr })
+    }
+    
+    pub fn create() -> Result<types::PtrOfStaticSaliencyFineGrained> {
+        unsafe { sys::cv_saliency_StaticSaliencyFineGrained_create() }.into_result().map(|ptr| types::PtrOfStaticSaliencyFineGrained { ptr })
+    }
+    
+    pub fn compute_saliency(&mut self, image: &dyn core::ToInputArray, saliency_map: &mut dyn core::ToOutputArray) -> Result<bool> {
+        input_array_arg!(image);
+        output_array_arg!(saliency_map);
+        unsafe { sys::cv_saliency_StaticSaliencyFineGrained_computeSaliency__InputArray__OutputArray(self.as_raw_StaticSaliencyFineGrained(), image.as_raw__InputArray(), saliency_map.as_raw__OutputArray()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::saliency::StaticSaliencySpectralResidual
+/// the Spectral Residual approach from  [SR](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_SR)
+///
+/// Starting from the principle of natural image statistics, this method simulate the behavior of
+/// pre-attentive visual search. The algorithm analyze the log spectrum of each image and obtain the
+/// spectral residual. Then transform the spectral residual to spatial domain to obtain the saliency
+/// map, which suggests the positions of proto-objects.
+pub struct StaticSaliencySpectralResidual {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for StaticSaliencySpectralResidual {
+    fn drop(&mut self) {
+        unsafe { sys::cv_StaticSaliencySpectralResidual_delete(self.ptr) };
+    }
+}
+
+impl StaticSaliencySpectralResidual {
+    #[inline(always)] pub fn as_raw_StaticSaliencySpectralResidual(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for StaticSaliencySpectralResidual {}
+
+impl core::AlgorithmTrait for StaticSaliencySpectralResidual {
+    #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::saliency::Saliency for StaticSaliencySpectralResidual {
+    #[inline(always)] fn as_raw_Saliency(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::saliency::StaticSaliencyTrait for StaticSaliencySpectralResidual {
+    #[inline(always)] fn as_raw_StaticSaliency(&self) -> *mut c_void { self.ptr }
+}
+
+impl StaticSaliencySpectralResidual {
+    pub fn default() -> Result<crate::saliency::StaticSaliencySpectralResidual> {
+        unsafe { sys::cv_saliency_StaticSaliencySpectralResidual_StaticSaliencySpectralResidual() }.into_result().map(|ptr| crate::saliency::StaticSaliencySpectralResidual { ptr })
+    }
+    
+    pub fn create() -> Result<types::PtrOfStaticSaliencySpectralResidual> {
+        unsafe { sys::cv_saliency_StaticSaliencySpectralResidual_create() }.into_result().map(|ptr| types::PtrOfStaticSaliencySpectralResidual { ptr })
+    }
+    
+    pub fn compute_saliency(&mut self, image: &dyn core::ToInputArray, saliency_map: &mut dyn core::ToOutputArray) -> Result<bool> {
+        input_array_arg!(image);
+        output_array_arg!(saliency_map);
+        unsafe { sys::cv_saliency_StaticSaliencySpectralResidual_computeSaliency__InputArray__OutputArray(self.as_raw_StaticSaliencySpectralResidual(), image.as_raw__InputArray(), saliency_map.as_raw__OutputArray()) }.into_result()
+    }
+    
+    pub fn read(&mut self, _fn: &core::FileNode) -> Result<()> {
+        unsafe { sys::cv_saliency_StaticSaliencySpectralResidual_read_FileNode(self.as_raw_StaticSaliencySpectralResidual(), _fn.as_raw_FileNode()) }.into_result()
+    }
+    
+    pub fn write(&self, fs: &mut core::FileStorage) -> Result<()> {
+        unsafe { sys::cv_saliency_StaticSaliencySpectralResidual_write_const_FileStorage(self.as_raw_StaticSaliencySpectralResidual(), fs.as_raw_FileStorage()) }.into_result()
+    }
+    
+    pub fn get_image_width(&self) -> Result<i32> {
+        unsafe { sys::cv_saliency_StaticSaliencySpectralResidual_getImageWidth_const(self.as_raw_StaticSaliencySpectralResidual()) }.into_result()
+    }
+    
+    pub fn set_image_width(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_saliency_StaticSaliencySpectralResidual_setImageWidth_int(self.as_raw_StaticSaliencySpectralResidual(), val) }.into_result()
+    }
+    
+    pub fn get_image_height(&self) -> Result<i32> {
+        unsafe { sys::cv_saliency_StaticSaliencySpectralResidual_getImageHeight_const(self.as_raw_StaticSaliencySpectralResidual()) }.into_result()
+    }
+    
+    pub fn set_image_height(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_saliency_StaticSaliencySpectralResidual_setImageHeight_int(self.as_raw_StaticSaliencySpectralResidual(), val) }.into_result()
+    }
+    
+}
+
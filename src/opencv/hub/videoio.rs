@@ -697,22 +697,22 @@ pub fn get_backend_name(api: crate::videoio::VideoCaptureAPIs) -> Result<String>
 
 /// Returns list of all available backends
 pub fn get_backends() -> Result<types::VectorOfVideoCaptureAPIs> {
-    unsafe { sys::cv_videoio_registry_getBackends() }.into_result().map(|ptr| types::VectorOfVideoCaptureAPIs { ptr })
+    unsafe { sys::cv_videoio_registry_getBackends() }.into_result().map(|ptr| unsafe { types::VectorOfVideoCaptureAPIs::from_raw_ptr(ptr) })
 }
 
 /// Returns list of available backends which works via `cv::VideoCapture(int index)`
 pub fn get_camera_backends() -> Result<types::VectorOfVideoCaptureAPIs> {
-    unsafe { sys::cv_videoio_registry_getCameraBackends() }.into_result().map(|ptr| types::VectorOfVideoCaptureAPIs { ptr })
+    unsafe { sys::cv_videoio_registry_getCameraBackends() }.into_result().map(|ptr| unsafe { types::VectorOfVideoCaptureAPIs::from_raw_ptr(ptr) })
 }
 
 /// Returns list of available backends which works via `cv::VideoCapture(filename)`
 pub fn get_stream_backends() -> Result<types::VectorOfVideoCaptureAPIs> {
-    unsafe { sys::cv_videoio_registry_getStreamBackends() }.into_result().map(|ptr| types::VectorOfVideoCaptureAPIs { ptr })
+    unsafe { sys::cv_videoio_registry_getStreamBackends() }.into_result().map(|ptr| unsafe { types::VectorOfVideoCaptureAPIs::from_raw_ptr(ptr) })
 }
 
 /// Returns list of available backends which works via `cv::VideoWriter()`
 pub fn get_writer_backends() -> Result<types::VectorOfVideoCaptureAPIs> {
-    unsafe { sys::cv_videoio_registry_getWriterBackends() }.into_result().map(|ptr| types::VectorOfVideoCaptureAPIs { ptr })
+    unsafe { sys::cv_videoio_registry_getWriterBackends() }.into_result().map(|ptr| unsafe { types::VectorOfVideoCaptureAPIs::from_raw_ptr(ptr) })
 }
 
 /// Returns true if backend is available
@@ -1190,3 +1190,4 @@ pub const CAP_OPENNI_GENERATORS_MASK: i32 = 0xe0000000; // -536870912
 pub const CAP_OPENNI_IMAGE_GENERATOR_OUTPUT_MODE: i32 = 0x40000064; // 1073741924
 pub const CAP_OPENNI_IMAGE_GENERATOR_PRESENT: i32 = 0x4000006d; // 1073741933
 pub const CAP_OPENNI_IR_GENERATOR_PRESENT: i32 = 0x2000006d; // 536871021
+pub use crate::manual::videoio::*;
@@ -11,6 +11,8 @@ pub mod ccalib;
 #[cfg(feature = "contrib")]
 pub mod cvv;
 #[cfg(feature = "contrib")]
+pub mod datasets;
+#[cfg(feature = "contrib")]
 pub mod dnn;
 #[cfg(feature = "contrib")]
 pub mod dpm;
@@ -31,17 +33,31 @@ pub mod line_descriptor;
 pub mod ml;
 pub mod objdetect;
 #[cfg(feature = "contrib")]
+pub mod optflow;
+#[cfg(feature = "contrib")]
 pub mod phase_unwrapping;
 pub mod photo;
 #[cfg(feature = "contrib")]
 pub mod plot;
 #[cfg(feature = "contrib")]
+pub mod reg;
+#[cfg(feature = "contrib")]
+pub mod rgbd;
+#[cfg(feature = "contrib")]
+pub mod saliency;
+#[cfg(feature = "contrib")]
 pub mod sfm;
 pub mod shape;
+#[cfg(feature = "contrib")]
+pub mod stereo;
 pub mod stitching;
 #[cfg(feature = "contrib")]
 pub mod structured_light;
 pub mod superres;
+#[cfg(feature = "contrib")]
+pub mod surface_matching;
+#[cfg(feature = "contrib")]
+pub mod text;
 pub mod video;
 pub mod videoio;
 pub mod videostab;
@@ -0,0 +1,1984 @@
+//! # Framework for working with different datasets
+//!
+//! The datasets module includes classes for working with different datasets: load data, evaluate
+//! different algorithms on them, contains benchmarks, etc.
+//!
+//! It is planned to have:
+//!
+//! *   basic: loading code for all datasets to help start work with them.
+//! *   next stage: quick benchmarks for all datasets to show how to solve them using OpenCV and
+//! implement evaluation code.
+//! *   finally: implement on OpenCV state-of-the-art algorithms, which solve these tasks.
+//! # Action Recognition
+//!
+//! ### HMDB: A Large Human Motion Database
+//!
+//! Implements loading dataset:
+//!
+//! "HMDB: A Large Human Motion Database": <http://serre-lab.clps.brown.edu/resource/hmdb-a-large-human-motion-database/>
+//!
+//! Usage:
+//! -# From link above download dataset files: `hmdb51_org.rar` & `test_train_splits.rar`.
+//! -# Unpack them. Unpack all archives from directory: `hmdb51_org/` and remove them.
+//! -# To load data run:
+//! ~~~
+//! ./opencv/build/bin/example_datasets_ar_hmdb -p=/home/user/path_to_unpacked_folders/
+//! ~~~
+//!
+//! #### Benchmark
+//!
+//! For this dataset was implemented benchmark with accuracy: 0.107407 (using precomputed HOG/HOF
+//! "STIP" features from site, averaging for 3 splits)
+//!
+//! To run this benchmark execute:
+//! ~~~
+//! ./opencv/build/bin/example_datasets_ar_hmdb_benchmark -p=/home/user/path_to_unpacked_folders/
+//! ~~~
+//!
+//!
+//! Note:
+//! Precomputed features should be unpacked in the same folder: `/home/user/path_to_unpacked_folders/hmdb51_org_stips/`.
+//! Also unpack all archives from directory: `hmdb51_org_stips/` and remove them.
+//!
+//! ### Sports-1M %Dataset
+//!
+//! Implements loading dataset:
+//!
+//! "Sports-1M Dataset": <http://cs.stanford.edu/people/karpathy/deepvideo/>
+//!
+//! Usage:
+//! -# From link above download dataset files (`git clone https://code.google.com/p/sports-1m-dataset/`).
+//! -# To load data run:
+//! ~~~
+//! ./opencv/build/bin/example_datasets_ar_sports -p=/home/user/path_to_downloaded_folders/
+//! ~~~
+//!
+//! # Face Recognition
+//!
+//! ### Adience
+//!
+//! Implements loading dataset:
+//!
+//! "Adience": <http://www.openu.ac.il/home/hassner/Adience/data.html>
+//!
+//! Usage:
+//! -# From link above download any dataset file: `faces.tar.gz\aligned.tar.gz` and files with splits:
+//! `fold_0_data.txt-fold_4_data.txt`, `fold_frontal_0_data.txt-fold_frontal_4_data.txt`. (For
+//! face recognition task another splits should be created)
+//! -# Unpack dataset file to some folder and place split files into the same folder.
+//! -# To load data run:
+//! ~~~
+//! ./opencv/build/bin/example_datasets_fr_adience -p=/home/user/path_to_created_folder/
+//! ~~~
+//!
+//! ### Labeled Faces in the Wild
+//!
+//! Implements loading dataset:
+//!
+//! "Labeled Faces in the Wild": <http://vis-www.cs.umass.edu/lfw/>
+//!
+//! Usage:
+//! -# From link above download any dataset file:
+//! `lfw.tgz\lfwa.tar.gz\lfw-deepfunneled.tgz\lfw-funneled.tgz` and files with pairs: 10 test
+//! splits: `pairs.txt` and developer train split: `pairsDevTrain.txt`.
+//! -# Unpack dataset file and place `pairs.txt` and `pairsDevTrain.txt` in created folder.
+//! -# To load data run:
+//! ~~~
+//! ./opencv/build/bin/example_datasets_fr_lfw -p=/home/user/path_to_unpacked_folder/lfw2/
+//! ~~~
+//!
+//! #### Benchmark
+//!
+//! For this dataset was implemented benchmark with accuracy: 0.623833 +- 0.005223 (train split:
+//! `pairsDevTrain.txt`, dataset: lfwa)
+//!
+//! To run this benchmark execute:
+//! ~~~
+//! ./opencv/build/bin/example_datasets_fr_lfw_benchmark -p=/home/user/path_to_unpacked_folder/lfw2/
+//! ~~~
+//!
+//! # Gesture Recognition
+//!
+//! ### ChaLearn Looking at People
+//!
+//! Implements loading dataset:
+//!
+//! "ChaLearn Looking at People": <http://gesture.chalearn.org/>
+//!
+//! Usage
+//! -# Follow instruction from site above, download files for dataset "Track 3: Gesture Recognition":
+//! `Train1.zip`-`Train5.zip`, `Validation1.zip`-`Validation3.zip` (Register on site: www.codalab.org and
+//! accept the terms and conditions of competition:
+//! <https://www.codalab.org/competitions/991#learn_the_details> There are three mirrors for
+//! downloading dataset files. When I downloaded data only mirror: "Universitat Oberta de Catalunya"
+//! works).
+//! -# Unpack train archives `Train1.zip`-`Train5.zip` to folder `Train/`, validation archives
+//! `Validation1.zip`-`Validation3.zip` to folder `Validation/`
+//! -# Unpack all archives in `Train/` & `Validation/` in the folders with the same names, for example:
+//! `Sample0001.zip` to `Sample0001/`
+//! -# To load data run:
+//! ~~~
+//! ./opencv/build/bin/example_datasets_gr_chalearn -p=/home/user/path_to_unpacked_folders/
+//! ~~~
+//!
+//! ### Sheffield Kinect Gesture Dataset
+//!
+//! Implements loading dataset:
+//!
+//! "Sheffield Kinect Gesture Dataset": <http://lshao.staff.shef.ac.uk/data/SheffieldKinectGesture.htm>
+//!
+//! Usage:
+//! -# From link above download dataset files: `subject1_dep.7z`-`subject6_dep.7z`, `subject1_rgb.7z`-`subject6_rgb.7z`.
+//! -# Unpack them.
+//! -# To load data run:
+//! ~~~
+//! ./opencv/build/bin/example_datasets_gr_skig -p=/home/user/path_to_unpacked_folders/
+//! ~~~
+//!
+//! # Human Pose Estimation
+//!
+//! ### HumanEva Dataset
+//!
+//! Implements loading dataset:
+//!
+//! "HumanEva Dataset": <http://humaneva.is.tue.mpg.de>
+//!
+//! Usage:
+//! -# From link above download dataset files for `HumanEva-I` (tar) & `HumanEva-II`.
+//! -# Unpack them to `HumanEva_1` & `HumanEva_2` accordingly.
+//! -# To load data run:
+//! ~~~
+//! ./opencv/build/bin/example_datasets_hpe_humaneva -p=/home/user/path_to_unpacked_folders/
+//! ~~~
+//!
+//! ### PARSE Dataset
+//!
+//! Implements loading dataset:
+//!
+//! "PARSE Dataset": <http://www.ics.uci.edu/~dramanan/papers/parse/>
+//!
+//! Usage:
+//! -# From link above download dataset file: `people.zip`.
+//! -# Unpack it.
+//! -# To load data run:
+//! ~~~
+//! ./opencv/build/bin/example_datasets_hpe_parse -p=/home/user/path_to_unpacked_folder/people_all/
+//! ~~~
+//!
+//! # Image Registration
+//!
+//! ### Affine Covariant Regions Datasets
+//!
+//! Implements loading dataset:
+//!
+//! "Affine Covariant Regions Datasets": <http://www.robots.ox.ac.uk/~vgg/data/data-aff.html>
+//!
+//! Usage:
+//! -# From link above download dataset files:
+//! `bark\bikes\boat\graf\leuven\trees\ubc\wall.tar.gz`.
+//! -# Unpack them.
+//! -# To load data, for example, for "bark", run:
+//! ```
+//! ./opencv/build/bin/example_datasets_ir_affine -p=/home/user/path_to_unpacked_folder/bark/
+//! ```
+//!
+//! ### Robot Data Set
+//!
+//! Implements loading dataset:
+//!
+//! "Robot Data Set, Point Feature Data Set – 2010": <http://roboimagedata.compute.dtu.dk/?page_id=24>
+//!
+//! Usage:
+//! -# From link above download dataset files: `SET001_6.tar.gz`-`SET055_60.tar.gz`
+//! -# Unpack them to one folder.
+//! -# To load data run:
+//! ~~~
+//! ./opencv/build/bin/example_datasets_ir_robot -p=/home/user/path_to_unpacked_folder/
+//! ~~~
+//!
+//! # Image Segmentation
+//!
+//! ### The Berkeley Segmentation Dataset and Benchmark
+//!
+//! Implements loading dataset:
+//!
+//! "The Berkeley Segmentation Dataset and Benchmark": <https://www.eecs.berkeley.edu/Research/Projects/CS/vision/bsds/>
+//!
+//! Usage:
+//! -# From link above download dataset files: `BSDS300-human.tgz` & `BSDS300-images.tgz`.
+//! -# Unpack them.
+//! -# To load data run:
+//! ~~~
+//! ./opencv/build/bin/example_datasets_is_bsds -p=/home/user/path_to_unpacked_folder/BSDS300/
+//! ~~~
+//!
+//! ### Weizmann Segmentation Evaluation Database
+//!
+//! Implements loading dataset:
+//!
+//! "Weizmann Segmentation Evaluation Database": <http://www.wisdom.weizmann.ac.il/~vision/Seg_Evaluation_DB/>
+//!
+//! Usage:
+//! -# From link above download dataset files: `Weizmann_Seg_DB_1obj.ZIP` & `Weizmann_Seg_DB_2obj.ZIP`.
+//! -# Unpack them.
+//! -# To load data, for example, for `1 object` dataset, run:
+//! ~~~
+//! ./opencv/build/bin/example_datasets_is_weizmann -p=/home/user/path_to_unpacked_folder/1obj/
+//! ~~~
+//!
+//! # Multiview Stereo Matching
+//!
+//! ### EPFL Multi-View Stereo
+//!
+//! Implements loading dataset:
+//!
+//! "EPFL Multi-View Stereo": <http://cvlab.epfl.ch/data/strechamvs>
+//!
+//! Usage:
+//! -# From link above download dataset files:
+//! `castle_dense\castle_dense_large\castle_entry\fountain\herzjesu_dense\herzjesu_dense_large_bounding\cameras\images\p.tar.gz`.
+//! -# Unpack them in separate folder for each object. For example, for "fountain", in folder `fountain/` :
+//! `fountain_dense_bounding.tar.gz -> bounding/`,
+//! `fountain_dense_cameras.tar.gz -> camera/`,
+//! `fountain_dense_images.tar.gz -> png/`,
+//! `fountain_dense_p.tar.gz -> P/`
+//! -# To load data, for example, for "fountain", run:
+//! ~~~
+//! ./opencv/build/bin/example_datasets_msm_epfl -p=/home/user/path_to_unpacked_folder/fountain/
+//! ~~~
+//!
+//! ### Stereo – Middlebury Computer Vision
+//!
+//! Implements loading dataset:
+//!
+//! "Stereo – Middlebury Computer Vision": <http://vision.middlebury.edu/mview/>
+//!
+//! Usage:
+//! -# From link above download dataset files:
+//! `dino\dinoRing\dinoSparseRing\temple\templeRing\templeSparseRing.zip`
+//! -# Unpack them.
+//! -# To load data, for example "temple" dataset, run:
+//! ~~~
+//! ./opencv/build/bin/example_datasets_msm_middlebury -p=/home/user/path_to_unpacked_folder/temple/
+//! ~~~
+//!
+//! # Object Recognition
+//!
+//! ### ImageNet
+//!
+//! Implements loading dataset:  "ImageNet": <http://www.image-net.org/>
+//!
+//! Usage:
+//! -# From link above download dataset files:
+//! `ILSVRC2010_images_train.tar\ILSVRC2010_images_test.tar\ILSVRC2010_images_val.tar` & devkit:
+//! `ILSVRC2010_devkit-1.0.tar.gz` (Implemented loading of 2010 dataset as only this dataset has ground
+//! truth for test data, but structure for ILSVRC2014 is similar)
+//! -# Unpack them to: `some_folder/train/`, `some_folder/test/`, `some_folder/val` &
+//! `some_folder/ILSVRC2010_validation_ground_truth.txt`,
+//! `some_folder/ILSVRC2010_test_ground_truth.txt`.
+//! -# Create file with labels: `some_folder/labels.txt`, for example, using python script below (each
+//! file's row format: `synset,labelID,description`. For example: "n07751451,18,plum").
+//! -# Unpack all tar files in train.
+//! -# To load data run:
+//! ~~~
+//! ./opencv/build/bin/example_datasets_or_imagenet -p=/home/user/some_folder/
+//! ~~~
+//!
+//! Python script to parse `meta.mat`:
+//! ~~~{py}
+//! import scipy.io
+//! meta_mat = scipy.io.loadmat("devkit-1.0/data/meta.mat")
+//!
+//! labels_dic = dict((m[0][1][0], m[0][0][0][0]-1) for m in meta_mat['synsets']
+//! label_names_dic = dict((m[0][1][0], m[0][2][0]) for m in meta_mat['synsets']
+//!
+//! for label in labels_dic.keys():
+//! print "{0},{1},{2}".format(label, labels_dic[label], label_names_dic[label])
+//! ~~~
+//!
+//! ### MNIST
+//!
+//! Implements loading dataset:
+//!
+//! "MNIST": <http://yann.lecun.com/exdb/mnist/>
+//!
+//! Usage:
+//! -# From link above download dataset files:
+//! `t10k-images-idx3-ubyte.gz`, `t10k-labels-idx1-ubyte.gz`, `train-images-idx3-ubyte.gz`, `train-labels-idx1-ubyte.gz`.
+//! -# Unpack them.
+//! -# To load data run:
+//! ~~~
+//! ./opencv/build/bin/example_datasets_or_mnist -p=/home/user/path_to_unpacked_files/
+//! ~~~
+//!
+//! ### SUN Database
+//!
+//! Implements loading dataset:
+//!
+//! "SUN Database, Scene Recognition Benchmark. SUN397": <http://vision.cs.princeton.edu/projects/2010/SUN/>
+//!
+//! Usage:
+//! -# From link above download dataset file: `SUN397.tar` & file with splits: `Partitions.zip`
+//! -# Unpack `SUN397.tar` into folder: `SUN397/` & `Partitions.zip` into folder: `SUN397/Partitions/`
+//! -# To load data run:
+//! ~~~
+//! ./opencv/build/bin/example_datasets_or_sun -p=/home/user/path_to_unpacked_files/SUN397/
+//! ~~~
+//!
+//! # Pedestrian Detection
+//!
+//! ### Caltech Pedestrian Detection Benchmark
+//!
+//! Implements loading dataset:
+//!
+//! "Caltech Pedestrian Detection Benchmark": <http://www.vision.caltech.edu/Image_Datasets/CaltechPedestrians/>
+//!
+//!
+//! Note: First version of Caltech Pedestrian dataset loading. Code to unpack all frames from seq files
+//! commented as their number is huge! So currently load only meta information without data. Also
+//! ground truth isn't processed, as need to convert it from mat files first.
+//!
+//! Usage:
+//! -# From link above download dataset files: `set00.tar`-`set10.tar`.
+//! -# Unpack them to separate folder.
+//! -# To load data run:
+//! ~~~
+//! ./opencv/build/bin/example_datasets_pd_caltech -p=/home/user/path_to_unpacked_folders/
+//! ~~~
+//!
+//! # SLAM
+//!
+//! ### KITTI Vision Benchmark
+//!
+//! Implements loading dataset:
+//!
+//! "KITTI Vision Benchmark": <http://www.cvlibs.net/datasets/kitti/eval_odometry.php>
+//!
+//! Usage:
+//! -# From link above download "Odometry" dataset files:
+//! `data_odometry_gray\data_odometry_color\data_odometry_velodyne\data_odometry_poses\data_odometry_calib.zip`.
+//! -# Unpack `data_odometry_poses.zip`, it creates folder `dataset/poses/`. After that unpack
+//! `data_odometry_gray.zip`, `data_odometry_color.zip`, `data_odometry_velodyne.zip`. Folder
+//! `dataset/sequences/` will be created with folders `00/..21/`. Each of these folders will contain:
+//! `image_0/`, `image_1/`, `image_2/`, `image_3/`, `velodyne/` and files `calib.txt` & `times.txt`.
+//! These two last files will be replaced after unpacking `data_odometry_calib.zip` at the end.
+//! -# To load data run:
+//! ~~~
+//! ./opencv/build/bin/example_datasets_slam_kitti -p=/home/user/path_to_unpacked_folder/dataset/
+//! ~~~
+//!
+//! ### TUMindoor Dataset
+//!
+//! Implements loading dataset:
+//!
+//! "TUMindoor Dataset": <http://www.navvis.lmt.ei.tum.de/dataset/>
+//!
+//! Usage:
+//! -# From link above download dataset files: `dslr\info\ladybug\pointcloud.tar.bz2` for each dataset:
+//! `11-11-28 (1st floor)\11-12-13 (1st floor N1)\11-12-17a (4th floor)\11-12-17b (3rd floor)\11-12-17c (Ground I)\11-12-18a (Ground II)\11-12-18b (2nd floor)`
+//! -# Unpack them in separate folder for each dataset.
+//! `dslr.tar.bz2 -> dslr/`,
+//! `info.tar.bz2 -> info/`,
+//! `ladybug.tar.bz2 -> ladybug/`,
+//! `pointcloud.tar.bz2 -> pointcloud/`.
+//! -# To load each dataset run:
+//! ~~~
+//! ./opencv/build/bin/example_datasets_slam_tumindoor -p=/home/user/path_to_unpacked_folders/
+//! ~~~
+//!
+//! # Text Recognition
+//!
+//! ### The Chars74K Dataset
+//!
+//! Implements loading dataset:
+//!
+//! "The Chars74K Dataset": <http://www.ee.surrey.ac.uk/CVSSP/demos/chars74k/>
+//!
+//! Usage:
+//! -# From link above download dataset files:
+//! `EnglishFnt\EnglishHnd\EnglishImg\KannadaHnd\KannadaImg.tgz`, `ListsTXT.tgz`.
+//! -# Unpack them.
+//! -# Move `.m` files from folder `ListsTXT/` to appropriate folder. For example,
+//! `English/list_English_Img.m` for `EnglishImg.tgz`.
+//! -# To load data, for example "EnglishImg", run:
+//! ~~~
+//! ./opencv/build/bin/example_datasets_tr_chars -p=/home/user/path_to_unpacked_folder/English/
+//! ~~~
+//!
+//! ### The Street View Text Dataset
+//!
+//! Implements loading dataset:
+//!
+//! "The Street View Text Dataset": <http://vision.ucsd.edu/~kai/svt/>
+//!
+//! Usage:
+//! -# From link above download dataset file: `svt.zip`.
+//! -# Unpack it.
+//! -# To load data run:
+//! ~~~
+//! ./opencv/build/bin/example_datasets_tr_svt -p=/home/user/path_to_unpacked_folder/svt/svt1/
+//! ~~~
+//!
+//! #### Benchmark
+//!
+//! For this dataset was implemented benchmark with accuracy (mean f1): 0.217
+//!
+//! To run benchmark execute:
+//! ~~~
+//! ./opencv/build/bin/example_datasets_tr_svt_benchmark -p=/home/user/path_to_unpacked_folders/svt/svt1/
+//! ~~~
+//!
+//! # Tracking
+//!
+//! ### VOT 2015 Database
+//!
+//! Implements loading dataset:
+//!
+//! "VOT 2015 dataset comprises 60 short sequences showing various objects in challenging backgrounds.
+//! The sequences were chosen from a large pool of sequences including the ALOV dataset, OTB2 dataset,
+//! non-tracking datasets, Computer Vision Online, Professor Bob Fisher’s Image Database, Videezy,
+//! Center for Research in Computer Vision, University of Central Florida, USA, NYU Center for Genomics
+//! and Systems Biology, Data Wrangling, Open Access Directory and Learning and Recognition in Vision
+//! Group, INRIA, France. The VOT sequence selection protocol was applied to obtain a representative
+//! set of challenging sequences.": <http://box.vicos.si/vot/vot2015.zip>
+//!
+//! Usage:
+//! -# From link above download dataset file: `vot2015.zip`
+//! -# Unpack `vot2015.zip` into folder: `VOT2015/`
+//! -# To load data run:
+//! ~~~
+//! ./opencv/build/bin/example_datasets_track_vot -p=/home/user/path_to_unpacked_files/VOT2015/
+//! ~~~
+use crate::{mod_prelude::*, core, sys, types};
+use crate::core::{_InputArrayTrait, _OutputArrayTrait};
+
+pub const LADYBUG: i32 = 0+2;
+pub const LEFT: i32 = 0;
+pub const NEG: i32 = 1;
+pub const POS: i32 = 0;
+pub const RIGHT: i32 = 0+1;
+pub const circle: i32 = 1;
+pub const comehere: i32 = 1+7;
+pub const cross: i32 = 1+6;
+pub const dark: i32 = 1+1;
+pub const female: i32 = 0+1;
+pub const fist: i32 = 1;
+pub const flat: i32 = 1+2;
+pub const humaneva_1: i32 = 1;
+pub const humaneva_2: i32 = 1+1;
+pub const index: i32 = 1+1;
+pub const light: i32 = 1;
+pub const male: i32 = 0;
+pub const none: i32 = 0+2;
+pub const paperWithCharacters: i32 = 1+2;
+pub const pat: i32 = 1+9;
+pub const rightleft: i32 = 1+3;
+pub const triangle: i32 = 1+1;
+pub const turnaround: i32 = 1+8;
+pub const updown: i32 = 1+2;
+pub const wave: i32 = 1+4;
+pub const whitePaper: i32 = 1+1;
+pub const woodenBoard: i32 = 1;
+pub const z: i32 = 1+5;
+
+pub fn create_directory(path: &str) -> Result<()> {
+    string_arg!(path);
+    unsafe { sys::cv_datasets_createDirectory_std_string(path.as_ptr()) }.into_result()
+}
+
+pub fn get_dir_list(dir_name: &str, file_names: &mut types::VectorOfstd_string) -> Result<()> {
+    string_arg!(dir_name);
+    unsafe { sys::cv_datasets_getDirList_std_string_VectorOfstd_string(dir_name.as_ptr(), file_names.as_raw_VectorOfstd_string()) }.into_result()
+}
+
+pub fn split(s: &str, elems: &mut types::VectorOfstd_string, delim: i8) -> Result<()> {
+    string_arg!(s);
+    unsafe { sys::cv_datasets_split_std_string_VectorOfstd_string_char(s.as_ptr(), elems.as_raw_VectorOfstd_string(), delim) }.into_result()
+}
+
+// Generating impl for trait crate::datasets::AR_hmdb
+pub trait AR_hmdb: crate::datasets::Dataset {
+    fn as_raw_AR_hmdb(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_AR_hmdb_load_std_string(self.as_raw_AR_hmdb(), path.as_ptr()) }.into_result()
+    }
+    
+}
+
+impl dyn AR_hmdb + '_ {
+    pub fn create() -> Result<types::PtrOfAR_hmdb> {
+        unsafe { sys::cv_datasets_AR_hmdb_create() }.into_result().map(|ptr| types::PtrOfAR_hmdb { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::AR_hmdbObj
+pub struct AR_hmdbObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for AR_hmdbObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_AR_hmdbObj_delete(self.ptr) };
+    }
+}
+
+impl AR_hmdbObj {
+    #[inline(always)] pub fn as_raw_AR_hmdbObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for AR_hmdbObj {}
+
+impl crate::datasets::ObjectTrait for AR_hmdbObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::AR_sports
+pub trait AR_sports: crate::datasets::Dataset {
+    fn as_raw_AR_sports(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_AR_sports_load_std_string(self.as_raw_AR_sports(), path.as_ptr()) }.into_result()
+    }
+    
+}
+
+impl dyn AR_sports + '_ {
+    pub fn create() -> Result<types::PtrOfAR_sports> {
+        unsafe { sys::cv_datasets_AR_sports_create() }.into_result().map(|ptr| types::PtrOfAR_sports { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::AR_sportsObj
+pub struct AR_sportsObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for AR_sportsObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_AR_sportsObj_delete(self.ptr) };
+    }
+}
+
+impl AR_sportsObj {
+    #[inline(always)] pub fn as_raw_AR_sportsObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for AR_sportsObj {}
+
+impl crate::datasets::ObjectTrait for AR_sportsObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::Dataset
+pub trait Dataset {
+    fn as_raw_Dataset(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_Dataset_load_std_string(self.as_raw_Dataset(), path.as_ptr()) }.into_result()
+    }
+    
+    ///
+    /// ## C++ default parameters
+    /// * split_num: 0
+    fn get_train(&mut self, split_num: i32) -> Result<types::VectorOfPtrOfObject> {
+        unsafe { sys::cv_datasets_Dataset_getTrain_int(self.as_raw_Dataset(), split_num) }.into_result().map(|ptr| unsafe { types::VectorOfPtrOfObject::from_raw_ptr(ptr) })
+    }
+    
+    ///
+    /// ## C++ default parameters
+    /// * split_num: 0
+    fn get_test(&mut self, split_num: i32) -> Result<types::VectorOfPtrOfObject> {
+        unsafe { sys::cv_datasets_Dataset_getTest_int(self.as_raw_Dataset(), split_num) }.into_result().map(|ptr| unsafe { types::VectorOfPtrOfObject::from_raw_ptr(ptr) })
+    }
+    
+    ///
+    /// ## C++ default parameters
+    /// * split_num: 0
+    fn get_validation(&mut self, split_num: i32) -> Result<types::VectorOfPtrOfObject> {
+        unsafe { sys::cv_datasets_Dataset_getValidation_int(self.as_raw_Dataset(), split_num) }.into_result().map(|ptr| unsafe { types::VectorOfPtrOfObject::from_raw_ptr(ptr) })
+    }
+    
+    fn get_num_splits(&self) -> Result<i32> {
+        unsafe { sys::cv_datasets_Dataset_getNumSplits_const(self.as_raw_Dataset()) }.into_result()
+    }
+    
+}
+
+// Generating impl for trait crate::datasets::FR_adience
+pub trait FR_adience: crate::datasets::Dataset {
+    fn as_raw_FR_adience(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_FR_adience_load_std_string(self.as_raw_FR_adience(), path.as_ptr()) }.into_result()
+    }
+    
+}
+
+impl dyn FR_adience + '_ {
+    pub fn create() -> Result<types::PtrOfFR_adience> {
+        unsafe { sys::cv_datasets_FR_adience_create() }.into_result().map(|ptr| types::PtrOfFR_adience { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::FR_adienceObj
+pub struct FR_adienceObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for FR_adienceObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_FR_adienceObj_delete(self.ptr) };
+    }
+}
+
+impl FR_adienceObj {
+    #[inline(always)] pub fn as_raw_FR_adienceObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for FR_adienceObj {}
+
+impl crate::datasets::ObjectTrait for FR_adienceObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::FR_lfw
+pub trait FR_lfw: crate::datasets::Dataset {
+    fn as_raw_FR_lfw(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_FR_lfw_load_std_string(self.as_raw_FR_lfw(), path.as_ptr()) }.into_result()
+    }
+    
+}
+
+impl dyn FR_lfw + '_ {
+    pub fn create() -> Result<types::PtrOfFR_lfw> {
+        unsafe { sys::cv_datasets_FR_lfw_create() }.into_result().map(|ptr| types::PtrOfFR_lfw { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::FR_lfwObj
+pub struct FR_lfwObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for FR_lfwObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_FR_lfwObj_delete(self.ptr) };
+    }
+}
+
+impl FR_lfwObj {
+    #[inline(always)] pub fn as_raw_FR_lfwObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for FR_lfwObj {}
+
+impl crate::datasets::ObjectTrait for FR_lfwObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::GR_chalearn
+pub trait GR_chalearn: crate::datasets::Dataset {
+    fn as_raw_GR_chalearn(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_GR_chalearn_load_std_string(self.as_raw_GR_chalearn(), path.as_ptr()) }.into_result()
+    }
+    
+}
+
+impl dyn GR_chalearn + '_ {
+    pub fn create() -> Result<types::PtrOfGR_chalearn> {
+        unsafe { sys::cv_datasets_GR_chalearn_create() }.into_result().map(|ptr| types::PtrOfGR_chalearn { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::GR_chalearnObj
+pub struct GR_chalearnObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for GR_chalearnObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_GR_chalearnObj_delete(self.ptr) };
+    }
+}
+
+impl GR_chalearnObj {
+    #[inline(always)] pub fn as_raw_GR_chalearnObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for GR_chalearnObj {}
+
+impl crate::datasets::ObjectTrait for GR_chalearnObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::GR_skig
+pub trait GR_skig: crate::datasets::Dataset {
+    fn as_raw_GR_skig(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_GR_skig_load_std_string(self.as_raw_GR_skig(), path.as_ptr()) }.into_result()
+    }
+    
+}
+
+impl dyn GR_skig + '_ {
+    pub fn create() -> Result<types::PtrOfGR_skig> {
+        unsafe { sys::cv_datasets_GR_skig_create() }.into_result().map(|ptr| types::PtrOfGR_skig { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::GR_skigObj
+pub struct GR_skigObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for GR_skigObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_GR_skigObj_delete(self.ptr) };
+    }
+}
+
+impl GR_skigObj {
+    #[inline(always)] pub fn as_raw_GR_skigObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for GR_skigObj {}
+
+impl crate::datasets::ObjectTrait for GR_skigObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::HPE_humaneva
+pub trait HPE_humaneva: crate::datasets::Dataset {
+    fn as_raw_HPE_humaneva(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_HPE_humaneva_load_std_string(self.as_raw_HPE_humaneva(), path.as_ptr()) }.into_result()
+    }
+    
+}
+
+impl dyn HPE_humaneva + '_ {
+    ///
+    /// ## C++ default parameters
+    /// * num: humaneva_1
+    pub fn create(num: i32) -> Result<types::PtrOfHPE_humaneva> {
+        unsafe { sys::cv_datasets_HPE_humaneva_create_int(num) }.into_result().map(|ptr| types::PtrOfHPE_humaneva { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::HPE_humanevaObj
+pub struct HPE_humanevaObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for HPE_humanevaObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_HPE_humanevaObj_delete(self.ptr) };
+    }
+}
+
+impl HPE_humanevaObj {
+    #[inline(always)] pub fn as_raw_HPE_humanevaObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for HPE_humanevaObj {}
+
+impl crate::datasets::ObjectTrait for HPE_humanevaObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::HPE_parse
+pub trait HPE_parse: crate::datasets::Dataset {
+    fn as_raw_HPE_parse(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_HPE_parse_load_std_string(self.as_raw_HPE_parse(), path.as_ptr()) }.into_result()
+    }
+    
+}
+
+impl dyn HPE_parse + '_ {
+    pub fn create() -> Result<types::PtrOfHPE_parse> {
+        unsafe { sys::cv_datasets_HPE_parse_create() }.into_result().map(|ptr| types::PtrOfHPE_parse { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::HPE_parseObj
+pub struct HPE_parseObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for HPE_parseObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_HPE_parseObj_delete(self.ptr) };
+    }
+}
+
+impl HPE_parseObj {
+    #[inline(always)] pub fn as_raw_HPE_parseObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for HPE_parseObj {}
+
+impl crate::datasets::ObjectTrait for HPE_parseObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::IR_affine
+pub trait IR_affine: crate::datasets::Dataset {
+    fn as_raw_IR_affine(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_IR_affine_load_std_string(self.as_raw_IR_affine(), path.as_ptr()) }.into_result()
+    }
+    
+}
+
+impl dyn IR_affine + '_ {
+    pub fn create() -> Result<types::PtrOfIR_affine> {
+        unsafe { sys::cv_datasets_IR_affine_create() }.into_result().map(|ptr| types::PtrOfIR_affine { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::IR_affineObj
+pub struct IR_affineObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for IR_affineObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_IR_affineObj_delete(self.ptr) };
+    }
+}
+
+impl IR_affineObj {
+    #[inline(always)] pub fn as_raw_IR_affineObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for IR_affineObj {}
+
+impl crate::datasets::ObjectTrait for IR_affineObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::IR_robot
+pub trait IR_robot: crate::datasets::Dataset {
+    fn as_raw_IR_robot(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_IR_robot_load_std_string(self.as_raw_IR_robot(), path.as_ptr()) }.into_result()
+    }
+    
+}
+
+impl dyn IR_robot + '_ {
+    pub fn create() -> Result<types::PtrOfIR_robot> {
+        unsafe { sys::cv_datasets_IR_robot_create() }.into_result().map(|ptr| types::PtrOfIR_robot { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::IR_robotObj
+pub struct IR_robotObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for IR_robotObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_IR_robotObj_delete(self.ptr) };
+    }
+}
+
+impl IR_robotObj {
+    #[inline(always)] pub fn as_raw_IR_robotObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for IR_robotObj {}
+
+impl crate::datasets::ObjectTrait for IR_robotObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::IS_bsds
+pub trait IS_bsds: crate::datasets::Dataset {
+    fn as_raw_IS_bsds(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_IS_bsds_load_std_string(self.as_raw_IS_bsds(), path.as_ptr()) }.into_result()
+    }
+    
+}
+
+impl dyn IS_bsds + '_ {
+    pub fn create() -> Result<types::PtrOfIS_bsds> {
+        unsafe { sys::cv_datasets_IS_bsds_create() }.into_result().map(|ptr| types::PtrOfIS_bsds { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::IS_bsdsObj
+pub struct IS_bsdsObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for IS_bsdsObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_IS_bsdsObj_delete(self.ptr) };
+    }
+}
+
+impl IS_bsdsObj {
+    #[inline(always)] pub fn as_raw_IS_bsdsObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for IS_bsdsObj {}
+
+impl crate::datasets::ObjectTrait for IS_bsdsObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::IS_weizmann
+pub trait IS_weizmann: crate::datasets::Dataset {
+    fn as_raw_IS_weizmann(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_IS_weizmann_load_std_string(self.as_raw_IS_weizmann(), path.as_ptr()) }.into_result()
+    }
+    
+}
+
+impl dyn IS_weizmann + '_ {
+    pub fn create() -> Result<types::PtrOfIS_weizmann> {
+        unsafe { sys::cv_datasets_IS_weizmann_create() }.into_result().map(|ptr| types::PtrOfIS_weizmann { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::IS_weizmannObj
+pub struct IS_weizmannObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for IS_weizmannObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_IS_weizmannObj_delete(self.ptr) };
+    }
+}
+
+impl IS_weizmannObj {
+    #[inline(always)] pub fn as_raw_IS_weizmannObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for IS_weizmannObj {}
+
+impl crate::datasets::ObjectTrait for IS_weizmannObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::MSM_epfl
+pub trait MSM_epfl: crate::datasets::Dataset {
+    fn as_raw_MSM_epfl(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_MSM_epfl_load_std_string(self.as_raw_MSM_epfl(), path.as_ptr()) }.into_result()
+    }
+    
+}
+
+impl dyn MSM_epfl + '_ {
+    pub fn create() -> Result<types::PtrOfMSM_epfl> {
+        unsafe { sys::cv_datasets_MSM_epfl_create() }.into_result().map(|ptr| types::PtrOfMSM_epfl { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::MSM_epflObj
+pub struct MSM_epflObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for MSM_epflObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_MSM_epflObj_delete(self.ptr) };
+    }
+}
+
+impl MSM_epflObj {
+    #[inline(always)] pub fn as_raw_MSM_epflObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for MSM_epflObj {}
+
+impl crate::datasets::ObjectTrait for MSM_epflObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::MSM_middlebury
+pub trait MSM_middlebury: crate::datasets::Dataset {
+    fn as_raw_MSM_middlebury(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_MSM_middlebury_load_std_string(self.as_raw_MSM_middlebury(), path.as_ptr()) }.into_result()
+    }
+    
+}
+
+impl dyn MSM_middlebury + '_ {
+    pub fn create() -> Result<types::PtrOfMSM_middlebury> {
+        unsafe { sys::cv_datasets_MSM_middlebury_create() }.into_result().map(|ptr| types::PtrOfMSM_middlebury { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::MSM_middleburyObj
+pub struct MSM_middleburyObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for MSM_middleburyObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_MSM_middleburyObj_delete(self.ptr) };
+    }
+}
+
+impl MSM_middleburyObj {
+    #[inline(always)] pub fn as_raw_MSM_middleburyObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for MSM_middleburyObj {}
+
+impl crate::datasets::ObjectTrait for MSM_middleburyObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::OR_imagenet
+pub trait OR_imagenet: crate::datasets::Dataset {
+    fn as_raw_OR_imagenet(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_OR_imagenet_load_std_string(self.as_raw_OR_imagenet(), path.as_ptr()) }.into_result()
+    }
+    
+}
+
+impl dyn OR_imagenet + '_ {
+    pub fn create() -> Result<types::PtrOfOR_imagenet> {
+        unsafe { sys::cv_datasets_OR_imagenet_create() }.into_result().map(|ptr| types::PtrOfOR_imagenet { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::OR_imagenetObj
+pub struct OR_imagenetObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for OR_imagenetObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_OR_imagenetObj_delete(self.ptr) };
+    }
+}
+
+impl OR_imagenetObj {
+    #[inline(always)] pub fn as_raw_OR_imagenetObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for OR_imagenetObj {}
+
+impl crate::datasets::ObjectTrait for OR_imagenetObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::OR_mnist
+pub trait OR_mnist: crate::datasets::Dataset {
+    fn as_raw_OR_mnist(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_OR_mnist_load_std_string(self.as_raw_OR_mnist(), path.as_ptr()) }.into_result()
+    }
+    
+}
+
+impl dyn OR_mnist + '_ {
+    pub fn create() -> Result<types::PtrOfOR_mnist> {
+        unsafe { sys::cv_datasets_OR_mnist_create() }.into_result().map(|ptr| types::PtrOfOR_mnist { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::OR_mnistObj
+pub struct OR_mnistObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for OR_mnistObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_OR_mnistObj_delete(self.ptr) };
+    }
+}
+
+impl OR_mnistObj {
+    #[inline(always)] pub fn as_raw_OR_mnistObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for OR_mnistObj {}
+
+impl crate::datasets::ObjectTrait for OR_mnistObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::OR_pascal
+pub trait OR_pascal: crate::datasets::Dataset {
+    fn as_raw_OR_pascal(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_OR_pascal_load_std_string(self.as_raw_OR_pascal(), path.as_ptr()) }.into_result()
+    }
+    
+}
+
+impl dyn OR_pascal + '_ {
+    pub fn create() -> Result<types::PtrOfOR_pascal> {
+        unsafe { sys::cv_datasets_OR_pascal_create() }.into_result().map(|ptr| types::PtrOfOR_pascal { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::OR_pascalObj
+pub struct OR_pascalObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for OR_pascalObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_OR_pascalObj_delete(self.ptr) };
+    }
+}
+
+impl OR_pascalObj {
+    #[inline(always)] pub fn as_raw_OR_pascalObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for OR_pascalObj {}
+
+impl crate::datasets::ObjectTrait for OR_pascalObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::OR_sun
+pub trait OR_sun: crate::datasets::Dataset {
+    fn as_raw_OR_sun(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_OR_sun_load_std_string(self.as_raw_OR_sun(), path.as_ptr()) }.into_result()
+    }
+    
+}
+
+impl dyn OR_sun + '_ {
+    pub fn create() -> Result<types::PtrOfOR_sun> {
+        unsafe { sys::cv_datasets_OR_sun_create() }.into_result().map(|ptr| types::PtrOfOR_sun { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::OR_sunObj
+pub struct OR_sunObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for OR_sunObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_OR_sunObj_delete(self.ptr) };
+    }
+}
+
+impl OR_sunObj {
+    #[inline(always)] pub fn as_raw_OR_sunObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for OR_sunObj {}
+
+impl crate::datasets::ObjectTrait for OR_sunObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::Object
+pub trait ObjectTrait {
+    fn as_raw_Object(&self) -> *mut c_void;
+}
+
+// boxed class cv::datasets::Object
+pub struct Object {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Object {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Object_delete(self.ptr) };
+    }
+}
+
+impl Object {
+    #[inline(always)] pub fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Object {}
+
+impl crate::datasets::ObjectTrait for Object {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::PD_caltech
+pub trait PD_caltech: crate::datasets::Dataset {
+    fn as_raw_PD_caltech(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_PD_caltech_load_std_string(self.as_raw_PD_caltech(), path.as_ptr()) }.into_result()
+    }
+    
+}
+
+impl dyn PD_caltech + '_ {
+    pub fn create() -> Result<types::PtrOfPD_caltech> {
+        unsafe { sys::cv_datasets_PD_caltech_create() }.into_result().map(|ptr| types::PtrOfPD_caltech { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::PD_caltechObj
+pub struct PD_caltechObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for PD_caltechObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_PD_caltechObj_delete(self.ptr) };
+    }
+}
+
+impl PD_caltechObj {
+    #[inline(always)] pub fn as_raw_PD_caltechObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for PD_caltechObj {}
+
+impl crate::datasets::ObjectTrait for PD_caltechObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::PD_inria
+pub trait PD_inria: crate::datasets::Dataset {
+    fn as_raw_PD_inria(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_PD_inria_load_std_string(self.as_raw_PD_inria(), path.as_ptr()) }.into_result()
+    }
+    
+}
+
+impl dyn PD_inria + '_ {
+    pub fn create() -> Result<types::PtrOfPD_inria> {
+        unsafe { sys::cv_datasets_PD_inria_create() }.into_result().map(|ptr| types::PtrOfPD_inria { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::PD_inriaObj
+pub struct PD_inriaObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for PD_inriaObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_PD_inriaObj_delete(self.ptr) };
+    }
+}
+
+impl PD_inriaObj {
+    #[inline(always)] pub fn as_raw_PD_inriaObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for PD_inriaObj {}
+
+impl crate::datasets::ObjectTrait for PD_inriaObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// boxed class cv::datasets::PascalObj
+pub struct PascalObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for PascalObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_PascalObj_delete(self.ptr) };
+    }
+}
+
+impl PascalObj {
+    #[inline(always)] pub fn as_raw_PascalObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for PascalObj {}
+
+impl crate::datasets::ObjectTrait for PascalObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::datasets::PascalPartTrait for PascalObj {
+    #[inline(always)] fn as_raw_PascalPart(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::PascalPart
+pub trait PascalPartTrait: crate::datasets::ObjectTrait {
+    fn as_raw_PascalPart(&self) -> *mut c_void;
+}
+
+// boxed class cv::datasets::PascalPart
+pub struct PascalPart {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for PascalPart {
+    fn drop(&mut self) {
+        unsafe { sys::cv_PascalPart_delete(self.ptr) };
+    }
+}
+
+impl PascalPart {
+    #[inline(always)] pub fn as_raw_PascalPart(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for PascalPart {}
+
+impl crate::datasets::ObjectTrait for PascalPart {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::datasets::PascalPartTrait for PascalPart {
+    #[inline(always)] fn as_raw_PascalPart(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::SLAM_kitti
+pub trait SLAM_kitti: crate::datasets::Dataset {
+    fn as_raw_SLAM_kitti(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_SLAM_kitti_load_std_string(self.as_raw_SLAM_kitti(), path.as_ptr()) }.into_result()
+    }
+    
+}
+
+impl dyn SLAM_kitti + '_ {
+    pub fn create() -> Result<types::PtrOfSLAM_kitti> {
+        unsafe { sys::cv_datasets_SLAM_kitti_create() }.into_result().map(|ptr| types::PtrOfSLAM_kitti { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::SLAM_kittiObj
+pub struct SLAM_kittiObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for SLAM_kittiObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_SLAM_kittiObj_delete(self.ptr) };
+    }
+}
+
+impl SLAM_kittiObj {
+    #[inline(always)] pub fn as_raw_SLAM_kittiObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for SLAM_kittiObj {}
+
+impl crate::datasets::ObjectTrait for SLAM_kittiObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::SLAM_tumindoor
+pub trait SLAM_tumindoor: crate::datasets::Dataset {
+    fn as_raw_SLAM_tumindoor(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_SLAM_tumindoor_load_std_string(self.as_raw_SLAM_tumindoor(), path.as_ptr()) }.into_result()
+    }
+    
+}
+
+impl dyn SLAM_tumindoor + '_ {
+    pub fn create() -> Result<types::PtrOfSLAM_tumindoor> {
+        unsafe { sys::cv_datasets_SLAM_tumindoor_create() }.into_result().map(|ptr| types::PtrOfSLAM_tumindoor { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::SLAM_tumindoorObj
+pub struct SLAM_tumindoorObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for SLAM_tumindoorObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_SLAM_tumindoorObj_delete(self.ptr) };
+    }
+}
+
+impl SLAM_tumindoorObj {
+    #[inline(always)] pub fn as_raw_SLAM_tumindoorObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for SLAM_tumindoorObj {}
+
+impl crate::datasets::ObjectTrait for SLAM_tumindoorObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::TRACK_alov
+pub trait TRACK_alov: crate::datasets::Dataset {
+    fn as_raw_TRACK_alov(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_TRACK_alov_load_std_string(self.as_raw_TRACK_alov(), path.as_ptr()) }.into_result()
+    }
+    
+    fn load_annotated_only(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_TRACK_alov_loadAnnotatedOnly_std_string(self.as_raw_TRACK_alov(), path.as_ptr()) }.into_result()
+    }
+    
+    fn get_datasets_num(&mut self) -> Result<i32> {
+        unsafe { sys::cv_datasets_TRACK_alov_getDatasetsNum(self.as_raw_TRACK_alov()) }.into_result()
+    }
+    
+    fn get_dataset_length(&mut self, id: i32) -> Result<i32> {
+        unsafe { sys::cv_datasets_TRACK_alov_getDatasetLength_int(self.as_raw_TRACK_alov(), id) }.into_result()
+    }
+    
+    fn init_dataset(&mut self, id: i32) -> Result<bool> {
+        unsafe { sys::cv_datasets_TRACK_alov_initDataset_int(self.as_raw_TRACK_alov(), id) }.into_result()
+    }
+    
+    fn get_next_frame(&mut self, frame: &mut core::Mat) -> Result<bool> {
+        unsafe { sys::cv_datasets_TRACK_alov_getNextFrame_Mat(self.as_raw_TRACK_alov(), frame.as_raw_Mat()) }.into_result()
+    }
+    
+    fn get_next_gt(&mut self) -> Result<types::VectorOfPoint2f> {
+        unsafe { sys::cv_datasets_TRACK_alov_getNextGT(self.as_raw_TRACK_alov()) }.into_result().map(|ptr| unsafe { types::VectorOfPoint2f::from_raw_ptr(ptr) })
+    }
+    
+    fn get_frame(&mut self, frame: &mut core::Mat, dataset_id: i32, frame_id: i32) -> Result<bool> {
+        unsafe { sys::cv_datasets_TRACK_alov_getFrame_Mat_int_int(self.as_raw_TRACK_alov(), frame.as_raw_Mat(), dataset_id, frame_id) }.into_result()
+    }
+    
+    fn get_gt(&mut self, dataset_id: i32, frame_id: i32) -> Result<types::VectorOfPoint2f> {
+        unsafe { sys::cv_datasets_TRACK_alov_getGT_int_int(self.as_raw_TRACK_alov(), dataset_id, frame_id) }.into_result().map(|ptr| unsafe { types::VectorOfPoint2f::from_raw_ptr(ptr) })
+    }
+    
+}
+
+impl dyn TRACK_alov + '_ {
+    pub fn create() -> Result<types::PtrOfTRACK_alov> {
+        unsafe { sys::cv_datasets_TRACK_alov_create() }.into_result().map(|ptr| types::PtrOfTRACK_alov { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::TRACK_alovObj
+pub struct TRACK_alovObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for TRACK_alovObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_TRACK_alovObj_delete(self.ptr) };
+    }
+}
+
+impl TRACK_alovObj {
+    #[inline(always)] pub fn as_raw_TRACK_alovObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for TRACK_alovObj {}
+
+impl crate::datasets::ObjectTrait for TRACK_alovObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::TRACK_vot
+pub trait TRACK_vot: crate::datasets::Dataset {
+    fn as_raw_TRACK_vot(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_TRACK_vot_load_std_string(self.as_raw_TRACK_vot(), path.as_ptr()) }.into_result()
+    }
+    
+    fn get_datasets_num(&mut self) -> Result<i32> {
+        unsafe { sys::cv_datasets_TRACK_vot_getDatasetsNum(self.as_raw_TRACK_vot()) }.into_result()
+    }
+    
+    fn get_dataset_length(&mut self, id: i32) -> Result<i32> {
+        unsafe { sys::cv_datasets_TRACK_vot_getDatasetLength_int(self.as_raw_TRACK_vot(), id) }.into_result()
+    }
+    
+    fn init_dataset(&mut self, id: i32) -> Result<bool> {
+        unsafe { sys::cv_datasets_TRACK_vot_initDataset_int(self.as_raw_TRACK_vot(), id) }.into_result()
+    }
+    
+    fn get_next_frame(&mut self, frame: &mut core::Mat) -> Result<bool> {
+        unsafe { sys::cv_datasets_TRACK_vot_getNextFrame_Mat(self.as_raw_TRACK_vot(), frame.as_raw_Mat()) }.into_result()
+    }
+    
+    fn get_gt(&mut self) -> Result<types::VectorOfPoint2d> {
+        unsafe { sys::cv_datasets_TRACK_vot_getGT(self.as_raw_TRACK_vot()) }.into_result().map(|ptr| unsafe { types::VectorOfPoint2d::from_raw_ptr(ptr) })
+    }
+    
+}
+
+impl dyn TRACK_vot + '_ {
+    pub fn create() -> Result<types::PtrOfTRACK_vot> {
+        unsafe { sys::cv_datasets_TRACK_vot_create() }.into_result().map(|ptr| types::PtrOfTRACK_vot { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::TRACK_votObj
+pub struct TRACK_votObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for TRACK_votObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_TRACK_votObj_delete(self.ptr) };
+    }
+}
+
+impl TRACK_votObj {
+    #[inline(always)] pub fn as_raw_TRACK_votObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for TRACK_votObj {}
+
+impl crate::datasets::ObjectTrait for TRACK_votObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::TR_chars
+pub trait TR_chars: crate::datasets::Dataset {
+    fn as_raw_TR_chars(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_TR_chars_load_std_string(self.as_raw_TR_chars(), path.as_ptr()) }.into_result()
+    }
+    
+}
+
+impl dyn TR_chars + '_ {
+    pub fn create() -> Result<types::PtrOfTR_chars> {
+        unsafe { sys::cv_datasets_TR_chars_create() }.into_result().map(|ptr| types::PtrOfTR_chars { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::TR_charsObj
+pub struct TR_charsObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for TR_charsObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_TR_charsObj_delete(self.ptr) };
+    }
+}
+
+impl TR_charsObj {
+    #[inline(always)] pub fn as_raw_TR_charsObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for TR_charsObj {}
+
+impl crate::datasets::ObjectTrait for TR_charsObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::TR_icdar
+pub trait TR_icdar: crate::datasets::Dataset {
+    fn as_raw_TR_icdar(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_TR_icdar_load_std_string(self.as_raw_TR_icdar(), path.as_ptr()) }.into_result()
+    }
+    
+}
+
+impl dyn TR_icdar + '_ {
+    pub fn create() -> Result<types::PtrOfTR_icdar> {
+        unsafe { sys::cv_datasets_TR_icdar_create() }.into_result().map(|ptr| types::PtrOfTR_icdar { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::TR_icdarObj
+pub struct TR_icdarObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for TR_icdarObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_TR_icdarObj_delete(self.ptr) };
+    }
+}
+
+impl TR_icdarObj {
+    #[inline(always)] pub fn as_raw_TR_icdarObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for TR_icdarObj {}
+
+impl crate::datasets::ObjectTrait for TR_icdarObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// Generating impl for trait crate::datasets::TR_svt
+pub trait TR_svt: crate::datasets::Dataset {
+    fn as_raw_TR_svt(&self) -> *mut c_void;
+    fn load(&mut self, path: &str) -> Result<()> {
+        string_arg!(path);
+        unsafe { sys::cv_datasets_TR_svt_load_std_string(self.as_raw_TR_svt(), path.as_ptr()) }.into_result()
+    }
+    
+}
+
+impl dyn TR_svt + '_ {
+    pub fn create() -> Result<types::PtrOfTR_svt> {
+        unsafe { sys::cv_datasets_TR_svt_create() }.into_result().map(|ptr| types::PtrOfTR_svt { ptr })
+    }
+    
+}
+
+// boxed class cv::datasets::TR_svtObj
+pub struct TR_svtObj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for TR_svtObj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_TR_svtObj_delete(self.ptr) };
+    }
+}
+
+impl TR_svtObj {
+    #[inline(always)] pub fn as_raw_TR_svtObj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for TR_svtObj {}
+
+impl crate::datasets::ObjectTrait for TR_svtObj {
+    #[inline(always)] fn as_raw_Object(&self) -> *mut c_void { self.ptr }
+}
+
+// boxed class cv::datasets::cameraParam
+pub struct cameraParam {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for cameraParam {
+    fn drop(&mut self) {
+        unsafe { sys::cv_cameraParam_delete(self.ptr) };
+    }
+}
+
+impl cameraParam {
+    #[inline(always)] pub fn as_raw_cameraParam(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for cameraParam {}
+
+// boxed class cv::datasets::cameraPos
+pub struct cameraPos {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for cameraPos {
+    fn drop(&mut self) {
+        unsafe { sys::cv_cameraPos_delete(self.ptr) };
+    }
+}
+
+impl cameraPos {
+    #[inline(always)] pub fn as_raw_cameraPos(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for cameraPos {}
+
+// boxed class cv::datasets::groundTruth
+pub struct groundTruth {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for groundTruth {
+    fn drop(&mut self) {
+        unsafe { sys::cv_groundTruth_delete(self.ptr) };
+    }
+}
+
+impl groundTruth {
+    #[inline(always)] pub fn as_raw_groundTruth(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for groundTruth {}
+
+// boxed class cv::datasets::join
+pub struct join {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for join {
+    fn drop(&mut self) {
+        unsafe { sys::cv_join_delete(self.ptr) };
+    }
+}
+
+impl join {
+    #[inline(always)] pub fn as_raw_join(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for join {}
+
+// boxed class cv::datasets::pose
+pub struct pose {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for pose {
+    fn drop(&mut self) {
+        unsafe { sys::cv_pose_delete(self.ptr) };
+    }
+}
+
+impl pose {
+    #[inline(always)] pub fn as_raw_pose(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for pose {}
+
+// boxed class cv::datasets::skeleton
+pub struct skeleton {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for skeleton {
+    fn drop(&mut self) {
+        unsafe { sys::cv_skeleton_delete(self.ptr) };
+    }
+}
+
+impl skeleton {
+    #[inline(always)] pub fn as_raw_skeleton(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for skeleton {}
+
+// boxed class cv::datasets::tag
+pub struct tag {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for tag {
+    fn drop(&mut self) {
+        unsafe { sys::cv_tag_delete(self.ptr) };
+    }
+}
+
+impl tag {
+    #[inline(always)] pub fn as_raw_tag(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for tag {}
+
+// boxed class cv::datasets::word
+pub struct word {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for word {
+    fn drop(&mut self) {
+        unsafe { sys::cv_word_delete(self.ptr) };
+    }
+}
+
+impl word {
+    #[inline(always)] pub fn as_raw_word(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for word {}
+
@@ -15,6 +15,7 @@ pub const Blob_ALLOC_UMAT: i32 = 1 << 1;
 pub const Blob_HEAD_AT_MAT: i32 = 1 << 0;
 pub const Blob_HEAD_AT_UMAT: i32 = 1 << 1;
 pub const Blob_UNINITIALIZED: i32 = 0;
+pub const EltwiseLayer_MAX: i32 = 2;
 pub const EltwiseLayer_PROD: i32 = 0;
 pub const EltwiseLayer_SUM: i32 = 1;
 pub const LRNLayer_CHANNEL_NRM: i32 = 0;
@@ -1357,7 +1358,7 @@ pub trait Layer {
     
     
     fn allocate_1(&mut self, inputs: &types::VectorOfBlob) -> Result<types::VectorOfBlob> {
-        unsafe { sys::cv_dnn_Layer_allocate_VectorOfBlob(self.as_raw_Layer(), inputs.as_raw_VectorOfBlob()) }.into_result().map(|ptr| types::VectorOfBlob { ptr })
+        unsafe { sys::cv_dnn_Layer_allocate_VectorOfBlob(self.as_raw_Layer(), inputs.as_raw_VectorOfBlob()) }.into_result().map(|ptr| unsafe { types::VectorOfBlob::from_raw_ptr(ptr) })
     }
     
     
@@ -1625,7 +1626,7 @@ impl Net {
     }
     
     pub fn get_layer_names(&self) -> Result<types::VectorOfString> {
-        unsafe { sys::cv_dnn_Net_getLayerNames_const(self.as_raw_Net()) }.into_result().map(|ptr| types::VectorOfString { ptr })
+        unsafe { sys::cv_dnn_Net_getLayerNames_const(self.as_raw_Net()) }.into_result().map(|ptr| unsafe { types::VectorOfString::from_raw_ptr(ptr) })
     }
     
     /// Returns pointer to layer with specified name which the network use.
@@ -175,7 +175,7 @@ pub trait BasicFaceRecognizer: crate::face::FaceRecognizer {
     }
     
     fn get_projections(&self) -> Result<types::VectorOfMat> {
-        unsafe { sys::cv_face_BasicFaceRecognizer_getProjections_const(self.as_raw_BasicFaceRecognizer()) }.into_result().map(|ptr| types::VectorOfMat { ptr })
+        unsafe { sys::cv_face_BasicFaceRecognizer_getProjections_const(self.as_raw_BasicFaceRecognizer()) }.into_result().map(|ptr| unsafe { types::VectorOfMat::from_raw_ptr(ptr) })
     }
     
     fn get_labels(&self) -> Result<core::Mat> {
@@ -517,7 +517,7 @@ pub trait FaceRecognizer: core::AlgorithmTrait {
     /// info.
     fn get_labels_by_string(&self, str: &str) -> Result<types::VectorOfint> {
         string_arg!(str);
-        unsafe { sys::cv_face_FaceRecognizer_getLabelsByString_const_String(self.as_raw_FaceRecognizer(), str.as_ptr()) }.into_result().map(|ptr| types::VectorOfint { ptr })
+        unsafe { sys::cv_face_FaceRecognizer_getLabelsByString_const_String(self.as_raw_FaceRecognizer(), str.as_ptr()) }.into_result().map(|ptr| unsafe { types::VectorOfint::from_raw_ptr(ptr) })
     }
     
     /// threshold parameter accessor - required for default BestMinDist collector
@@ -586,7 +586,7 @@ pub trait LBPHFaceRecognizer: crate::face::FaceRecognizer {
     }
     
     fn get_histograms(&self) -> Result<types::VectorOfMat> {
-        unsafe { sys::cv_face_LBPHFaceRecognizer_getHistograms_const(self.as_raw_LBPHFaceRecognizer()) }.into_result().map(|ptr| types::VectorOfMat { ptr })
+        unsafe { sys::cv_face_LBPHFaceRecognizer_getHistograms_const(self.as_raw_LBPHFaceRecognizer()) }.into_result().map(|ptr| unsafe { types::VectorOfMat::from_raw_ptr(ptr) })
     }
     
     fn get_labels(&self) -> Result<core::Mat> {
@@ -272,3 +272,4 @@ pub fn imwrite(filename: &str, img: &dyn core::ToInputArray, params: &types::Vec
     unsafe { sys::cv_imwrite_String__InputArray_VectorOfint(filename.as_ptr(), img.as_raw__InputArray(), params.as_raw_VectorOfint()) }.into_result()
 }
 
+pub use crate::manual::imgcodecs::*;
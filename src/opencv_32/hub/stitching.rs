@@ -41,6 +41,16 @@
 use crate::{mod_prelude::*, core, sys, types};
 use crate::core::{_InputArrayTrait, _OutputArrayTrait};
 
+pub const Blender_FEATHER: i32 = 1;
+pub const Blender_MULTI_BAND: i32 = 2;
+pub const Blender_NO: i32 = 0;
+pub const DpSeamFinder_COLOR: i32 = 0;
+pub const DpSeamFinder_COLOR_GRAD: i32 = 1;
+pub const ExposureCompensator_GAIN: i32 = 1;
+pub const ExposureCompensator_GAIN_BLOCKS: i32 = 2;
+pub const ExposureCompensator_NO: i32 = 0;
+pub const GraphCutSeamFinderBase_COST_COLOR: i32 = 0;
+pub const GraphCutSeamFinderBase_COST_COLOR_GRAD: i32 = 1;
 pub const Stitcher_ERR_CAMERA_PARAMS_ADJUST_FAIL: i32 = 3;
 pub const Stitcher_ERR_HOMOGRAPHY_EST_FAIL: i32 = 2;
 pub const Stitcher_ERR_NEED_MORE_IMGS: i32 = 1;
@@ -48,6 +58,10 @@ pub const Stitcher_OK: i32 = 0;
 pub const Stitcher_ORIG_RESOL: i32 = -1;
 pub const Stitcher_PANORAMA: i32 = 0;
 pub const Stitcher_SCANS: i32 = 1;
+pub const Timelapser_AS_IS: i32 = 0;
+pub const Timelapser_CROP: i32 = 1;
+pub const WAVE_CORRECT_HORIZ: i32 = 0;
+pub const WAVE_CORRECT_VERT: i32 = 1;
 
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -72,6 +86,106 @@ pub fn create_stitcher(try_use_gpu: bool) -> Result<types::PtrOfStitcher> {
     unsafe { sys::cv_createStitcher_bool(try_use_gpu) }.into_result().map(|ptr| types::PtrOfStitcher { ptr })
 }
 
+pub fn calibrate_rotating_camera(hs: &types::VectorOfMat, k: &mut core::Mat) -> Result<bool> {
+    unsafe { sys::cv_detail_calibrateRotatingCamera_VectorOfMat_Mat(hs.as_raw_VectorOfMat(), k.as_raw_Mat()) }.into_result()
+}
+
+pub fn create_laplace_pyr_gpu(img: &dyn core::ToInputArray, num_levels: i32, pyr: &mut types::VectorOfUMat) -> Result<()> {
+    input_array_arg!(img);
+    unsafe { sys::cv_detail_createLaplacePyrGpu__InputArray_int_VectorOfUMat(img.as_raw__InputArray(), num_levels, pyr.as_raw_VectorOfUMat()) }.into_result()
+}
+
+pub fn create_laplace_pyr(img: &dyn core::ToInputArray, num_levels: i32, pyr: &mut types::VectorOfUMat) -> Result<()> {
+    input_array_arg!(img);
+    unsafe { sys::cv_detail_createLaplacePyr__InputArray_int_VectorOfUMat(img.as_raw__InputArray(), num_levels, pyr.as_raw_VectorOfUMat()) }.into_result()
+}
+
+pub fn create_weight_map(mask: &dyn core::ToInputArray, sharpness: f32, weight: &mut dyn core::ToInputOutputArray) -> Result<()> {
+    input_array_arg!(mask);
+    input_output_array_arg!(weight);
+    unsafe { sys::cv_detail_createWeightMap__InputArray_float__InputOutputArray(mask.as_raw__InputArray(), sharpness, weight.as_raw__InputOutputArray()) }.into_result()
+}
+
+/// Estimates focal lengths for each given camera.
+///
+/// ## Parameters
+/// * features: Features of images.
+/// * pairwise_matches: Matches between all image pairs.
+/// * focals: Estimated focal lengths for each camera.
+pub fn estimate_focal(features: &types::VectorOfDetail_ImageFeatures, pairwise_matches: &types::VectorOfDetail_MatchesInfo, focals: &mut types::VectorOfdouble) -> Result<()> {
+    unsafe { sys::cv_detail_estimateFocal_VectorOfDetail_ImageFeatures_VectorOfDetail_MatchesInfo_VectorOfdouble(features.as_raw_VectorOfDetail_ImageFeatures(), pairwise_matches.as_raw_VectorOfDetail_MatchesInfo(), focals.as_raw_VectorOfdouble()) }.into_result()
+}
+
+pub fn find_max_spanning_tree(num_images: i32, pairwise_matches: &types::VectorOfDetail_MatchesInfo, span_tree: &mut crate::stitching::Detail_Graph, centers: &mut types::VectorOfint) -> Result<()> {
+    unsafe { sys::cv_detail_findMaxSpanningTree_int_VectorOfDetail_MatchesInfo_Detail_Graph_VectorOfint(num_images, pairwise_matches.as_raw_VectorOfDetail_MatchesInfo(), span_tree.as_raw_Detail_Graph(), centers.as_raw_VectorOfint()) }.into_result()
+}
+
+/// Tries to estimate focal lengths from the given homography under the assumption that the camera
+/// undergoes rotations around its centre only.
+///
+/// ## Parameters
+/// * H: Homography.
+/// * f0: Estimated focal length along X axis.
+/// * f1: Estimated focal length along Y axis.
+/// * f0_ok: True, if f0 was estimated successfully, false otherwise.
+/// * f1_ok: True, if f1 was estimated successfully, false otherwise.
+///
+/// See "Construction of Panoramic Image Mosaics with Global and Local Alignment"
+/// by Heung-Yeung Shum and Richard Szeliski.
+pub fn focals_from_homography(h: &core::Mat, f0: &mut f64, f1: &mut f64, f0_ok: &mut bool, f1_ok: &mut bool) -> Result<()> {
+    unsafe { sys::cv_detail_focalsFromHomography_Mat_double_double_bool_bool(h.as_raw_Mat(), f0, f1, f0_ok, f1_ok) }.into_result()
+}
+
+pub fn leave_biggest_component(features: &mut types::VectorOfDetail_ImageFeatures, pairwise_matches: &mut types::VectorOfDetail_MatchesInfo, conf_threshold: f32) -> Result<types::VectorOfint> {
+    unsafe { sys::cv_detail_leaveBiggestComponent_VectorOfDetail_ImageFeatures_VectorOfDetail_MatchesInfo_float(features.as_raw_VectorOfDetail_ImageFeatures(), pairwise_matches.as_raw_VectorOfDetail_MatchesInfo(), conf_threshold) }.into_result().map(|ptr| unsafe { types::VectorOfint::from_raw_ptr(ptr) })
+}
+
+pub fn matches_graph_as_string(pathes: &mut types::VectorOfString, pairwise_matches: &mut types::VectorOfDetail_MatchesInfo, conf_threshold: f32) -> Result<String> {
+    unsafe { sys::cv_detail_matchesGraphAsString_VectorOfString_VectorOfDetail_MatchesInfo_float(pathes.as_raw_VectorOfString(), pairwise_matches.as_raw_VectorOfDetail_MatchesInfo(), conf_threshold) }.into_result().map(crate::templ::receive_string_mut)
+}
+
+pub fn normalize_using_weight_map(weight: &dyn core::ToInputArray, src: &mut dyn core::ToInputOutputArray) -> Result<()> {
+    input_array_arg!(weight);
+    input_output_array_arg!(src);
+    unsafe { sys::cv_detail_normalizeUsingWeightMap__InputArray__InputOutputArray(weight.as_raw__InputArray(), src.as_raw__InputOutputArray()) }.into_result()
+}
+
+pub fn overlap_roi(tl1: core::Point, tl2: core::Point, sz1: core::Size, sz2: core::Size, roi: &mut core::Rect) -> Result<bool> {
+    unsafe { sys::cv_detail_overlapRoi_Point_Point_Size_Size_Rect(tl1, tl2, sz1, sz2, roi) }.into_result()
+}
+
+pub fn restore_image_from_laplace_pyr_gpu(pyr: &mut types::VectorOfUMat) -> Result<()> {
+    unsafe { sys::cv_detail_restoreImageFromLaplacePyrGpu_VectorOfUMat(pyr.as_raw_VectorOfUMat()) }.into_result()
+}
+
+pub fn restore_image_from_laplace_pyr(pyr: &mut types::VectorOfUMat) -> Result<()> {
+    unsafe { sys::cv_detail_restoreImageFromLaplacePyr_VectorOfUMat(pyr.as_raw_VectorOfUMat()) }.into_result()
+}
+
+pub fn result_roi_intersection(corners: &types::VectorOfPoint, sizes: &types::VectorOfSize) -> Result<core::Rect> {
+    unsafe { sys::cv_detail_resultRoiIntersection_VectorOfPoint_VectorOfSize(corners.as_raw_VectorOfPoint(), sizes.as_raw_VectorOfSize()) }.into_result()
+}
+
+pub fn result_roi(corners: &types::VectorOfPoint, sizes: &types::VectorOfSize) -> Result<core::Rect> {
+    unsafe { sys::cv_detail_resultRoi_VectorOfPoint_VectorOfSize(corners.as_raw_VectorOfPoint(), sizes.as_raw_VectorOfSize()) }.into_result()
+}
+
+pub fn result_roi_1(corners: &types::VectorOfPoint, images: &types::VectorOfUMat) -> Result<core::Rect> {
+    unsafe { sys::cv_detail_resultRoi_VectorOfPoint_VectorOfUMat(corners.as_raw_VectorOfPoint(), images.as_raw_VectorOfUMat()) }.into_result()
+}
+
+pub fn result_tl(corners: &types::VectorOfPoint) -> Result<core::Point> {
+    unsafe { sys::cv_detail_resultTl_VectorOfPoint(corners.as_raw_VectorOfPoint()) }.into_result()
+}
+
+pub fn select_random_subset(count: i32, size: i32, subset: &mut types::VectorOfint) -> Result<()> {
+    unsafe { sys::cv_detail_selectRandomSubset_int_int_VectorOfint(count, size, subset.as_raw_VectorOfint()) }.into_result()
+}
+
+pub fn stitching_log_level() -> Result<i32> {
+    unsafe { sys::cv_detail_stitchingLogLevel() }.into_result()
+}
+
 // boxed class cv::AffineWarper
 /// Affine warper factory class.
 /// ## See also
@@ -100,6 +214,17 @@ impl crate::stitching::WarperCreator for AffineWarper {
     #[inline(always)] fn as_raw_WarperCreator(&self) -> *mut c_void { self.ptr }
 }
 
+impl AffineWarper {
+    pub fn create(&self, scale: f32) -> Result<types::PtrOfDetail_RotationWarper> {
+        unsafe { sys::cv_AffineWarper_create_const_float(self.as_raw_AffineWarper(), scale) }.into_result().map(|ptr| types::PtrOfDetail_RotationWarper { ptr })
+    }
+    
+    pub fn default() -> Result<crate::stitching::AffineWarper> {
+        unsafe { sys::cv_AffineWarper_AffineWarper() }.into_result().map(|ptr| crate::stitching::AffineWarper { ptr })
+    }
+    
+}
+
 // boxed class cv::CompressedRectilinearPortraitWarper
 pub struct CompressedRectilinearPortraitWarper {
     #[doc(hidden)] pub(crate) ptr: *mut c_void
@@ -134,6 +259,10 @@ impl CompressedRectilinearPortraitWarper {
         unsafe { sys::cv_CompressedRectilinearPortraitWarper_CompressedRectilinearPortraitWarper_float_float(a, b) }.into_result().map(|ptr| crate::stitching::CompressedRectilinearPortraitWarper { ptr })
     }
     
+    pub fn create(&self, scale: f32) -> Result<types::PtrOfDetail_RotationWarper> {
+        unsafe { sys::cv_CompressedRectilinearPortraitWarper_create_const_float(self.as_raw_CompressedRectilinearPortraitWarper(), scale) }.into_result().map(|ptr| types::PtrOfDetail_RotationWarper { ptr })
+    }
+    
 }
 
 // boxed class cv::CompressedRectilinearWarper
@@ -170,6 +299,10 @@ impl CompressedRectilinearWarper {
         unsafe { sys::cv_CompressedRectilinearWarper_CompressedRectilinearWarper_float_float(a, b) }.into_result().map(|ptr| crate::stitching::CompressedRectilinearWarper { ptr })
     }
     
+    pub fn create(&self, scale: f32) -> Result<types::PtrOfDetail_RotationWarper> {
+        unsafe { sys::cv_CompressedRectilinearWarper_create_const_float(self.as_raw_CompressedRectilinearWarper(), scale) }.into_result().map(|ptr| types::PtrOfDetail_RotationWarper { ptr })
+    }
+    
 }
 
 // boxed class cv::CylindricalWarper
@@ -200,6 +333,17 @@ impl crate::stitching::WarperCreator for CylindricalWarper {
     #[inline(always)] fn as_raw_WarperCreator(&self) -> *mut c_void { self.ptr }
 }
 
+impl CylindricalWarper {
+    pub fn create(&self, scale: f32) -> Result<types::PtrOfDetail_RotationWarper> {
+        unsafe { sys::cv_CylindricalWarper_create_const_float(self.as_raw_CylindricalWarper(), scale) }.into_result().map(|ptr| types::PtrOfDetail_RotationWarper { ptr })
+    }
+    
+    pub fn default() -> Result<crate::stitching::CylindricalWarper> {
+        unsafe { sys::cv_CylindricalWarper_CylindricalWarper() }.into_result().map(|ptr| crate::stitching::CylindricalWarper { ptr })
+    }
+    
+}
+
 // boxed class cv::FisheyeWarper
 pub struct FisheyeWarper {
     #[doc(hidden)] pub(crate) ptr: *mut c_void
@@ -225,6 +369,17 @@ impl crate::stitching::WarperCreator for FisheyeWarper {
     #[inline(always)] fn as_raw_WarperCreator(&self) -> *mut c_void { self.ptr }
 }
 
+impl FisheyeWarper {
+    pub fn create(&self, scale: f32) -> Result<types::PtrOfDetail_RotationWarper> {
+        unsafe { sys::cv_FisheyeWarper_create_const_float(self.as_raw_FisheyeWarper(), scale) }.into_result().map(|ptr| types::PtrOfDetail_RotationWarper { ptr })
+    }
+    
+    pub fn default() -> Result<crate::stitching::FisheyeWarper> {
+        unsafe { sys::cv_FisheyeWarper_FisheyeWarper() }.into_result().map(|ptr| crate::stitching::FisheyeWarper { ptr })
+    }
+    
+}
+
 // boxed class cv::MercatorWarper
 pub struct MercatorWarper {
     #[doc(hidden)] pub(crate) ptr: *mut c_void
@@ -250,6 +405,17 @@ impl crate::stitching::WarperCreator for MercatorWarper {
     #[inline(always)] fn as_raw_WarperCreator(&self) -> *mut c_void { self.ptr }
 }
 
+impl MercatorWarper {
+    pub fn create(&self, scale: f32) -> Result<types::PtrOfDetail_RotationWarper> {
+        unsafe { sys::cv_MercatorWarper_create_const_float(self.as_raw_MercatorWarper(), scale) }.into_result().map(|ptr| types::PtrOfDetail_RotationWarper { ptr })
+    }
+    
+    pub fn default() -> Result<crate::stitching::MercatorWarper> {
+        unsafe { sys::cv_MercatorWarper_MercatorWarper() }.into_result().map(|ptr| crate::stitching::MercatorWarper { ptr })
+    }
+    
+}
+
 // boxed class cv::PaniniPortraitWarper
 pub struct PaniniPortraitWarper {
     #[doc(hidden)] pub(crate) ptr: *mut c_void
@@ -284,6 +450,10 @@ impl PaniniPortraitWarper {
         unsafe { sys::cv_PaniniPortraitWarper_PaniniPortraitWarper_float_float(a, b) }.into_result().map(|ptr| crate::stitching::PaniniPortraitWarper { ptr })
     }
     
+    pub fn create(&self, scale: f32) -> Result<types::PtrOfDetail_RotationWarper> {
+        unsafe { sys::cv_PaniniPortraitWarper_create_const_float(self.as_raw_PaniniPortraitWarper(), scale) }.into_result().map(|ptr| types::PtrOfDetail_RotationWarper { ptr })
+    }
+    
 }
 
 // boxed class cv::PaniniWarper
@@ -320,6 +490,10 @@ impl PaniniWarper {
         unsafe { sys::cv_PaniniWarper_PaniniWarper_float_float(a, b) }.into_result().map(|ptr| crate::stitching::PaniniWarper { ptr })
     }
     
+    pub fn create(&self, scale: f32) -> Result<types::PtrOfDetail_RotationWarper> {
+        unsafe { sys::cv_PaniniWarper_create_const_float(self.as_raw_PaniniWarper(), scale) }.into_result().map(|ptr| types::PtrOfDetail_RotationWarper { ptr })
+    }
+    
 }
 
 // boxed class cv::PlaneWarper
@@ -350,6 +524,17 @@ impl crate::stitching::WarperCreator for PlaneWarper {
     #[inline(always)] fn as_raw_WarperCreator(&self) -> *mut c_void { self.ptr }
 }
 
+impl PlaneWarper {
+    pub fn create(&self, scale: f32) -> Result<types::PtrOfDetail_RotationWarper> {
+        unsafe { sys::cv_PlaneWarper_create_const_float(self.as_raw_PlaneWarper(), scale) }.into_result().map(|ptr| types::PtrOfDetail_RotationWarper { ptr })
+    }
+    
+    pub fn default() -> Result<crate::stitching::PlaneWarper> {
+        unsafe { sys::cv_PlaneWarper_PlaneWarper() }.into_result().map(|ptr| crate::stitching::PlaneWarper { ptr })
+    }
+    
+}
+
 // boxed class cv::SphericalWarper
 /// Spherical warper factory class
 pub struct SphericalWarper {
@@ -376,6 +561,17 @@ impl crate::stitching::WarperCreator for SphericalWarper {
     #[inline(always)] fn as_raw_WarperCreator(&self) -> *mut c_void { self.ptr }
 }
 
+impl SphericalWarper {
+    pub fn create(&self, scale: f32) -> Result<types::PtrOfDetail_RotationWarper> {
+        unsafe { sys::cv_SphericalWarper_create_const_float(self.as_raw_SphericalWarper(), scale) }.into_result().map(|ptr| types::PtrOfDetail_RotationWarper { ptr })
+    }
+    
+    pub fn default() -> Result<crate::stitching::SphericalWarper> {
+        unsafe { sys::cv_SphericalWarper_SphericalWarper() }.into_result().map(|ptr| crate::stitching::SphericalWarper { ptr })
+    }
+    
+}
+
 // boxed class cv::StereographicWarper
 pub struct StereographicWarper {
     #[doc(hidden)] pub(crate) ptr: *mut c_void
@@ -401,6 +597,17 @@ impl crate::stitching::WarperCreator for StereographicWarper {
     #[inline(always)] fn as_raw_WarperCreator(&self) -> *mut c_void { self.ptr }
 }
 
+impl StereographicWarper {
+    pub fn create(&self, scale: f32) -> Result<types::PtrOfDetail_RotationWarper> {
+        unsafe { sys::cv_StereographicWarper_create_const_float(self.as_raw_StereographicWarper(), scale) }.into_result().map(|ptr| types::PtrOfDetail_RotationWarper { ptr })
+    }
+    
+    pub fn default() -> Result<crate::stitching::StereographicWarper> {
+        unsafe { sys::cv_StereographicWarper_StereographicWarper() }.into_result().map(|ptr| crate::stitching::StereographicWarper { ptr })
+    }
+    
+}
+
 // boxed class cv::Stitcher
 /// High level image stitcher.
 ///
@@ -505,6 +712,30 @@ impl Stitcher {
         unsafe { sys::cv_Stitcher_setWaveCorrection_bool(self.as_raw_Stitcher(), flag) }.into_result()
     }
     
+    pub fn features_finder(&mut self) -> Result<types::PtrOfDetail_FeaturesFinder> {
+        unsafe { sys::cv_Stitcher_featuresFinder(self.as_raw_Stitcher()) }.into_result().map(|ptr| types::PtrOfDetail_FeaturesFinder { ptr })
+    }
+    
+    pub fn features_finder_1(&self) -> Result<types::PtrOfDetail_FeaturesFinder> {
+        unsafe { sys::cv_Stitcher_featuresFinder_const(self.as_raw_Stitcher()) }.into_result().map(|ptr| types::PtrOfDetail_FeaturesFinder { ptr })
+    }
+    
+    pub fn set_features_finder(&mut self, features_finder: &types::PtrOfDetail_FeaturesFinder) -> Result<()> {
+        unsafe { sys::cv_Stitcher_setFeaturesFinder_PtrOfDetail_FeaturesFinder(self.as_raw_Stitcher(), features_finder.as_raw_PtrOfDetail_FeaturesFinder()) }.into_result()
+    }
+    
+    pub fn features_matcher(&mut self) -> Result<types::PtrOfDetail_FeaturesMatcher> {
+        unsafe { sys::cv_Stitcher_featuresMatcher(self.as_raw_Stitcher()) }.into_result().map(|ptr| types::PtrOfDetail_FeaturesMatcher { ptr })
+    }
+    
+    pub fn features_matcher_1(&self) -> Result<types::PtrOfDetail_FeaturesMatcher> {
+        unsafe { sys::cv_Stitcher_featuresMatcher_const(self.as_raw_Stitcher()) }.into_result().map(|ptr| types::PtrOfDetail_FeaturesMatcher { ptr })
+    }
+    
+    pub fn set_features_matcher(&mut self, features_matcher: &types::PtrOfDetail_FeaturesMatcher) -> Result<()> {
+        unsafe { sys::cv_Stitcher_setFeaturesMatcher_PtrOfDetail_FeaturesMatcher(self.as_raw_Stitcher(), features_matcher.as_raw_PtrOfDetail_FeaturesMatcher()) }.into_result()
+    }
+    
     pub fn matching_mask(&self) -> Result<core::UMat> {
         unsafe { sys::cv_Stitcher_matchingMask_const(self.as_raw_Stitcher()) }.into_result().map(|ptr| core::UMat { ptr })
     }
@@ -513,6 +744,66 @@ impl Stitcher {
         unsafe { sys::cv_Stitcher_setMatchingMask_UMat(self.as_raw_Stitcher(), mask.as_raw_UMat()) }.into_result()
     }
     
+    pub fn bundle_adjuster(&mut self) -> Result<types::PtrOfDetail_BundleAdjusterBase> {
+        unsafe { sys::cv_Stitcher_bundleAdjuster(self.as_raw_Stitcher()) }.into_result().map(|ptr| types::PtrOfDetail_BundleAdjusterBase { ptr })
+    }
+    
+    pub fn bundle_adjuster_1(&self) -> Result<types::PtrOfDetail_BundleAdjusterBase> {
+        unsafe { sys::cv_Stitcher_bundleAdjuster_const(self.as_raw_Stitcher()) }.into_result().map(|ptr| types::PtrOfDetail_BundleAdjusterBase { ptr })
+    }
+    
+    pub fn set_bundle_adjuster(&mut self, bundle_adjuster: &types::PtrOfDetail_BundleAdjusterBase) -> Result<()> {
+        unsafe { sys::cv_Stitcher_setBundleAdjuster_PtrOfDetail_BundleAdjusterBase(self.as_raw_Stitcher(), bundle_adjuster.as_raw_PtrOfDetail_BundleAdjusterBase()) }.into_result()
+    }
+    
+    pub fn warper(&mut self) -> Result<types::PtrOfWarperCreator> {
+        unsafe { sys::cv_Stitcher_warper(self.as_raw_Stitcher()) }.into_result().map(|ptr| types::PtrOfWarperCreator { ptr })
+    }
+    
+    pub fn warper_1(&self) -> Result<types::PtrOfWarperCreator> {
+        unsafe { sys::cv_Stitcher_warper_const(self.as_raw_Stitcher()) }.into_result().map(|ptr| types::PtrOfWarperCreator { ptr })
+    }
+    
+    pub fn set_warper(&mut self, creator: &types::PtrOfWarperCreator) -> Result<()> {
+        unsafe { sys::cv_Stitcher_setWarper_PtrOfWarperCreator(self.as_raw_Stitcher(), creator.as_raw_PtrOfWarperCreator()) }.into_result()
+    }
+    
+    pub fn exposure_compensator(&mut self) -> Result<types::PtrOfDetail_ExposureCompensator> {
+        unsafe { sys::cv_Stitcher_exposureCompensator(self.as_raw_Stitcher()) }.into_result().map(|ptr| types::PtrOfDetail_ExposureCompensator { ptr })
+    }
+    
+    pub fn exposure_compensator_1(&self) -> Result<types::PtrOfDetail_ExposureCompensator> {
+        unsafe { sys::cv_Stitcher_exposureCompensator_const(self.as_raw_Stitcher()) }.into_result().map(|ptr| types::PtrOfDetail_ExposureCompensator { ptr })
+    }
+    
+    pub fn set_exposure_compensator(&mut self, exposure_comp: &types::PtrOfDetail_ExposureCompensator) -> Result<()> {
+        unsafe { sys::cv_Stitcher_setExposureCompensator_PtrOfDetail_ExposureCompensator(self.as_raw_Stitcher(), exposure_comp.as_raw_PtrOfDetail_ExposureCompensator()) }.into_result()
+    }
+    
+    pub fn seam_finder(&mut self) -> Result<types::PtrOfDetail_SeamFinder> {
+        unsafe { sys::cv_Stitcher_seamFinder(self.as_raw_Stitcher()) }.into_result().map(|ptr| types::PtrOfDetail_SeamFinder { ptr })
+    }
+    
+    pub fn seam_finder_1(&self) -> Result<types::PtrOfDetail_SeamFinder> {
+        unsafe { sys::cv_Stitcher_seamFinder_const(self.as_raw_Stitcher()) }.into_result().map(|ptr| types::PtrOfDetail_SeamFinder { ptr })
+    }
+    
+    pub fn set_seam_finder(&mut self, seam_finder: &types::PtrOfDetail_SeamFinder) -> Result<()> {
+        unsafe { sys::cv_Stitcher_setSeamFinder_PtrOfDetail_SeamFinder(self.as_raw_Stitcher(), seam_finder.as_raw_PtrOfDetail_SeamFinder()) }.into_result()
+    }
+    
+    pub fn blender(&mut self) -> Result<types::PtrOfDetail_Blender> {
+        unsafe { sys::cv_Stitcher_blender(self.as_raw_Stitcher()) }.into_result().map(|ptr| types::PtrOfDetail_Blender { ptr })
+    }
+    
+    pub fn blender_1(&self) -> Result<types::PtrOfDetail_Blender> {
+        unsafe { sys::cv_Stitcher_blender_const(self.as_raw_Stitcher()) }.into_result().map(|ptr| types::PtrOfDetail_Blender { ptr })
+    }
+    
+    pub fn set_blender(&mut self, b: &types::PtrOfDetail_Blender) -> Result<()> {
+        unsafe { sys::cv_Stitcher_setBlender_PtrOfDetail_Blender(self.as_raw_Stitcher(), b.as_raw_PtrOfDetail_Blender()) }.into_result()
+    }
+    
     pub fn estimate_transform(&mut self, images: &dyn core::ToInputArray) -> Result<crate::stitching::Stitcher_Status> {
         input_array_arg!(images);
         unsafe { sys::cv_Stitcher_estimateTransform__InputArray(self.as_raw_Stitcher(), images.as_raw__InputArray()) }.into_result()
@@ -579,7 +870,11 @@ impl Stitcher {
     }
     
     pub fn component(&self) -> Result<types::VectorOfint> {
-        unsafe { sys::cv_Stitcher_component_const(self.as_raw_Stitcher()) }.into_result().map(|ptr| types::VectorOfint { ptr })
+        unsafe { sys::cv_Stitcher_component_const(self.as_raw_Stitcher()) }.into_result().map(|ptr| unsafe { types::VectorOfint::from_raw_ptr(ptr) })
+    }
+    
+    pub fn cameras(&self) -> Result<types::VectorOfDetail_CameraParams> {
+        unsafe { sys::cv_Stitcher_cameras_const(self.as_raw_Stitcher()) }.into_result().map(|ptr| unsafe { types::VectorOfDetail_CameraParams::from_raw_ptr(ptr) })
     }
     
     pub fn work_scale(&self) -> Result<f64> {
@@ -613,9 +908,2351 @@ impl crate::stitching::WarperCreator for TransverseMercatorWarper {
     #[inline(always)] fn as_raw_WarperCreator(&self) -> *mut c_void { self.ptr }
 }
 
+impl TransverseMercatorWarper {
+    pub fn create(&self, scale: f32) -> Result<types::PtrOfDetail_RotationWarper> {
+        unsafe { sys::cv_TransverseMercatorWarper_create_const_float(self.as_raw_TransverseMercatorWarper(), scale) }.into_result().map(|ptr| types::PtrOfDetail_RotationWarper { ptr })
+    }
+    
+    pub fn default() -> Result<crate::stitching::TransverseMercatorWarper> {
+        unsafe { sys::cv_TransverseMercatorWarper_TransverseMercatorWarper() }.into_result().map(|ptr| crate::stitching::TransverseMercatorWarper { ptr })
+    }
+    
+}
+
 // Generating impl for trait crate::stitching::WarperCreator
 /// Image warper factories base class.
 pub trait WarperCreator {
     fn as_raw_WarperCreator(&self) -> *mut c_void;
+    fn create(&self, scale: f32) -> Result<types::PtrOfDetail_RotationWarper> {
+        unsafe { sys::cv_WarperCreator_create_const_float(self.as_raw_WarperCreator(), scale) }.into_result().map(|ptr| types::PtrOfDetail_RotationWarper { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::AKAZEFeaturesFinder
+/// AKAZE features finder. :
+///
+/// ## See also
+/// detail::FeaturesFinder, AKAZE
+pub struct Detail_AKAZEFeaturesFinder {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_AKAZEFeaturesFinder {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_AKAZEFeaturesFinder_delete(self.ptr) };
+    }
+}
+
+impl Detail_AKAZEFeaturesFinder {
+    #[inline(always)] pub fn as_raw_Detail_AKAZEFeaturesFinder(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_AKAZEFeaturesFinder {}
+
+impl Detail_AKAZEFeaturesFinder {
+    ///
+    /// ## C++ default parameters
+    /// * descriptor_type: AKAZE::DESCRIPTOR_MLDB
+    /// * descriptor_size: 0
+    /// * descriptor_channels: 3
+    /// * threshold: 0.001f
+    /// * n_octaves: 4
+    /// * n_octave_layers: 4
+    /// * diffusivity: KAZE::DIFF_PM_G2
+    pub fn new(descriptor_type: i32, descriptor_size: i32, descriptor_channels: i32, threshold: f32, n_octaves: i32, n_octave_layers: i32, diffusivity: i32) -> Result<crate::stitching::Detail_AKAZEFeaturesFinder> {
+        unsafe { sys::cv_detail_AKAZEFeaturesFinder_AKAZEFeaturesFinder_int_int_int_float_int_int_int(descriptor_type, descriptor_size, descriptor_channels, threshold, n_octaves, n_octave_layers, diffusivity) }.into_result().map(|ptr| crate::stitching::Detail_AKAZEFeaturesFinder { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::AffineBasedEstimator
+/// Affine transformation based estimator.
+///
+/// This estimator uses pairwise tranformations estimated by matcher to estimate
+/// final transformation for each camera.
+///
+/// ## See also
+/// cv::detail::HomographyBasedEstimator
+pub struct Detail_AffineBasedEstimator {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_AffineBasedEstimator {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_AffineBasedEstimator_delete(self.ptr) };
+    }
+}
+
+impl Detail_AffineBasedEstimator {
+    #[inline(always)] pub fn as_raw_Detail_AffineBasedEstimator(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_AffineBasedEstimator {}
+
+impl crate::stitching::Detail_Estimator for Detail_AffineBasedEstimator {
+    #[inline(always)] fn as_raw_Detail_Estimator(&self) -> *mut c_void { self.ptr }
+}
+
+impl Detail_AffineBasedEstimator {
+    pub fn default() -> Result<crate::stitching::Detail_AffineBasedEstimator> {
+        unsafe { sys::cv_detail_AffineBasedEstimator_AffineBasedEstimator() }.into_result().map(|ptr| crate::stitching::Detail_AffineBasedEstimator { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::AffineBestOf2NearestMatcher
+/// Features matcher similar to cv::detail::BestOf2NearestMatcher which
+/// finds two best matches for each feature and leaves the best one only if the
+/// ratio between descriptor distances is greater than the threshold match_conf.
+///
+/// Unlike cv::detail::BestOf2NearestMatcher this matcher uses affine
+/// transformation (affine trasformation estimate will be placed in matches_info).
+///
+/// ## See also
+/// cv::detail::FeaturesMatcher cv::detail::BestOf2NearestMatcher
+pub struct Detail_AffineBestOf2NearestMatcher {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_AffineBestOf2NearestMatcher {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_AffineBestOf2NearestMatcher_delete(self.ptr) };
+    }
+}
+
+impl Detail_AffineBestOf2NearestMatcher {
+    #[inline(always)] pub fn as_raw_Detail_AffineBestOf2NearestMatcher(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_AffineBestOf2NearestMatcher {}
+
+impl crate::stitching::Detail_BestOf2NearestMatcherTrait for Detail_AffineBestOf2NearestMatcher {
+    #[inline(always)] fn as_raw_Detail_BestOf2NearestMatcher(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::stitching::Detail_FeaturesMatcher for Detail_AffineBestOf2NearestMatcher {
+    #[inline(always)] fn as_raw_Detail_FeaturesMatcher(&self) -> *mut c_void { self.ptr }
+}
+
+impl Detail_AffineBestOf2NearestMatcher {
+    /// Constructs a "best of 2 nearest" matcher that expects affine trasformation
+    /// between images
+    ///
+    /// ## Parameters
+    /// * full_affine: whether to use full affine transformation with 6 degress of freedom or reduced
+    /// transformation with 4 degrees of freedom using only rotation, translation and uniform scaling
+    /// * try_use_gpu: Should try to use GPU or not
+    /// * match_conf: Match distances ration threshold
+    /// * num_matches_thresh1: Minimum number of matches required for the 2D affine transform
+    /// estimation used in the inliers classification step
+    ///
+    /// ## See also
+    /// cv::estimateAffine2D cv::estimateAffinePartial2D
+    ///
+    /// ## C++ default parameters
+    /// * full_affine: false
+    /// * try_use_gpu: false
+    /// * match_conf: 0.3f
+    /// * num_matches_thresh1: 6
+    pub fn new(full_affine: bool, try_use_gpu: bool, match_conf: f32, num_matches_thresh1: i32) -> Result<crate::stitching::Detail_AffineBestOf2NearestMatcher> {
+        unsafe { sys::cv_detail_AffineBestOf2NearestMatcher_AffineBestOf2NearestMatcher_bool_bool_float_int(full_affine, try_use_gpu, match_conf, num_matches_thresh1) }.into_result().map(|ptr| crate::stitching::Detail_AffineBestOf2NearestMatcher { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::AffineWarper
+/// Affine warper that uses rotations and translations
+///
+/// Uses affine transformation in homogeneous coordinates to represent both rotation and
+/// translation in camera rotation matrix.
+pub struct Detail_AffineWarper {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_AffineWarper {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_AffineWarper_delete(self.ptr) };
+    }
+}
+
+impl Detail_AffineWarper {
+    #[inline(always)] pub fn as_raw_Detail_AffineWarper(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_AffineWarper {}
+
+impl crate::stitching::Detail_PlaneWarperTrait for Detail_AffineWarper {
+    #[inline(always)] fn as_raw_Detail_PlaneWarper(&self) -> *mut c_void { self.ptr }
+}
+
+impl Detail_AffineWarper {
+    /// Construct an instance of the affine warper class.
+    ///
+    /// ## Parameters
+    /// * scale: Projected image scale multiplier
+    ///
+    /// ## C++ default parameters
+    /// * scale: 1.f
+    pub fn new(scale: f32) -> Result<crate::stitching::Detail_AffineWarper> {
+        unsafe { sys::cv_detail_AffineWarper_AffineWarper_float(scale) }.into_result().map(|ptr| crate::stitching::Detail_AffineWarper { ptr })
+    }
+    
+    pub fn warp_point(&mut self, pt: core::Point2f, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray) -> Result<core::Point2f> {
+        input_array_arg!(k);
+        input_array_arg!(r);
+        unsafe { sys::cv_detail_AffineWarper_warpPoint_Point2f__InputArray__InputArray(self.as_raw_Detail_AffineWarper(), pt, k.as_raw__InputArray(), r.as_raw__InputArray()) }.into_result()
+    }
+    
+    pub fn build_maps(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, xmap: &mut dyn core::ToOutputArray, ymap: &mut dyn core::ToOutputArray) -> Result<core::Rect> {
+        input_array_arg!(k);
+        input_array_arg!(r);
+        output_array_arg!(xmap);
+        output_array_arg!(ymap);
+        unsafe { sys::cv_detail_AffineWarper_buildMaps_Size__InputArray__InputArray__OutputArray__OutputArray(self.as_raw_Detail_AffineWarper(), src_size, k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray()) }.into_result()
+    }
+    
+    pub fn warp(&mut self, src: &dyn core::ToInputArray, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, interp_mode: i32, border_mode: i32, dst: &mut dyn core::ToOutputArray) -> Result<core::Point> {
+        input_array_arg!(src);
+        input_array_arg!(k);
+        input_array_arg!(r);
+        output_array_arg!(dst);
+        unsafe { sys::cv_detail_AffineWarper_warp__InputArray__InputArray__InputArray_int_int__OutputArray(self.as_raw_Detail_AffineWarper(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray()) }.into_result()
+    }
+    
+    pub fn warp_roi(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray) -> Result<core::Rect> {
+        input_array_arg!(k);
+        input_array_arg!(r);
+        unsafe { sys::cv_detail_AffineWarper_warpRoi_Size__InputArray__InputArray(self.as_raw_Detail_AffineWarper(), src_size, k.as_raw__InputArray(), r.as_raw__InputArray()) }.into_result()
+    }
+    
+}
+
+// Generating impl for trait crate::stitching::Detail_BestOf2NearestMatcher
+/// Features matcher which finds two best matches for each feature and leaves the best one only if the
+/// ratio between descriptor distances is greater than the threshold match_conf
+///
+/// ## See also
+/// detail::FeaturesMatcher
+pub trait Detail_BestOf2NearestMatcherTrait: crate::stitching::Detail_FeaturesMatcher {
+    fn as_raw_Detail_BestOf2NearestMatcher(&self) -> *mut c_void;
+    fn collect_garbage(&mut self) -> Result<()> {
+        unsafe { sys::cv_detail_BestOf2NearestMatcher_collectGarbage(self.as_raw_Detail_BestOf2NearestMatcher()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::detail::BestOf2NearestMatcher
+/// Features matcher which finds two best matches for each feature and leaves the best one only if the
+/// ratio between descriptor distances is greater than the threshold match_conf
+///
+/// ## See also
+/// detail::FeaturesMatcher
+pub struct Detail_BestOf2NearestMatcher {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_BestOf2NearestMatcher {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_BestOf2NearestMatcher_delete(self.ptr) };
+    }
+}
+
+impl Detail_BestOf2NearestMatcher {
+    #[inline(always)] pub fn as_raw_Detail_BestOf2NearestMatcher(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_BestOf2NearestMatcher {}
+
+impl crate::stitching::Detail_BestOf2NearestMatcherTrait for Detail_BestOf2NearestMatcher {
+    #[inline(always)] fn as_raw_Detail_BestOf2NearestMatcher(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::stitching::Detail_FeaturesMatcher for Detail_BestOf2NearestMatcher {
+    #[inline(always)] fn as_raw_Detail_FeaturesMatcher(&self) -> *mut c_void { self.ptr }
+}
+
+impl Detail_BestOf2NearestMatcher {
+    /// Constructs a "best of 2 nearest" matcher.
+    ///
+    /// ## Parameters
+    /// * try_use_gpu: Should try to use GPU or not
+    /// * match_conf: Match distances ration threshold
+    /// * num_matches_thresh1: Minimum number of matches required for the 2D projective transform
+    /// estimation used in the inliers classification step
+    /// * num_matches_thresh2: Minimum number of matches required for the 2D projective transform
+    /// re-estimation on inliers
+    ///
+    /// ## C++ default parameters
+    /// * try_use_gpu: false
+    /// * match_conf: 0.3f
+    /// * num_matches_thresh1: 6
+    /// * num_matches_thresh2: 6
+    pub fn new(try_use_gpu: bool, match_conf: f32, num_matches_thresh1: i32, num_matches_thresh2: i32) -> Result<crate::stitching::Detail_BestOf2NearestMatcher> {
+        unsafe { sys::cv_detail_BestOf2NearestMatcher_BestOf2NearestMatcher_bool_float_int_int(try_use_gpu, match_conf, num_matches_thresh1, num_matches_thresh2) }.into_result().map(|ptr| crate::stitching::Detail_BestOf2NearestMatcher { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::BestOf2NearestRangeMatcher
+pub struct Detail_BestOf2NearestRangeMatcher {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_BestOf2NearestRangeMatcher {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_BestOf2NearestRangeMatcher_delete(self.ptr) };
+    }
+}
+
+impl Detail_BestOf2NearestRangeMatcher {
+    #[inline(always)] pub fn as_raw_Detail_BestOf2NearestRangeMatcher(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_BestOf2NearestRangeMatcher {}
+
+impl crate::stitching::Detail_BestOf2NearestMatcherTrait for Detail_BestOf2NearestRangeMatcher {
+    #[inline(always)] fn as_raw_Detail_BestOf2NearestMatcher(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::stitching::Detail_FeaturesMatcher for Detail_BestOf2NearestRangeMatcher {
+    #[inline(always)] fn as_raw_Detail_FeaturesMatcher(&self) -> *mut c_void { self.ptr }
+}
+
+impl Detail_BestOf2NearestRangeMatcher {
+    ///
+    /// ## C++ default parameters
+    /// * range_width: 5
+    /// * try_use_gpu: false
+    /// * match_conf: 0.3f
+    /// * num_matches_thresh1: 6
+    /// * num_matches_thresh2: 6
+    pub fn new(range_width: i32, try_use_gpu: bool, match_conf: f32, num_matches_thresh1: i32, num_matches_thresh2: i32) -> Result<crate::stitching::Detail_BestOf2NearestRangeMatcher> {
+        unsafe { sys::cv_detail_BestOf2NearestRangeMatcher_BestOf2NearestRangeMatcher_int_bool_float_int_int(range_width, try_use_gpu, match_conf, num_matches_thresh1, num_matches_thresh2) }.into_result().map(|ptr| crate::stitching::Detail_BestOf2NearestRangeMatcher { ptr })
+    }
+    
+}
+
+// Generating impl for trait crate::stitching::Detail_Blender
+/// Base class for all blenders.
+///
+/// Simple blender which puts one image over another
+pub trait Detail_BlenderTrait {
+    fn as_raw_Detail_Blender(&self) -> *mut c_void;
+    /// Prepares the blender for blending.
+    ///
+    /// ## Parameters
+    /// * corners: Source images top-left corners
+    /// * sizes: Source image sizes
+    fn prepare(&mut self, corners: &types::VectorOfPoint, sizes: &types::VectorOfSize) -> Result<()> {
+        unsafe { sys::cv_detail_Blender_prepare_VectorOfPoint_VectorOfSize(self.as_raw_Detail_Blender(), corners.as_raw_VectorOfPoint(), sizes.as_raw_VectorOfSize()) }.into_result()
+    }
+    
+    fn prepare_1(&mut self, dst_roi: core::Rect) -> Result<()> {
+        unsafe { sys::cv_detail_Blender_prepare_Rect(self.as_raw_Detail_Blender(), dst_roi) }.into_result()
+    }
+    
+    /// Processes the image.
+    ///
+    /// ## Parameters
+    /// * img: Source image
+    /// * mask: Source image mask
+    /// * tl: Source image top-left corners
+    fn feed(&mut self, img: &dyn core::ToInputArray, mask: &dyn core::ToInputArray, tl: core::Point) -> Result<()> {
+        input_array_arg!(img);
+        input_array_arg!(mask);
+        unsafe { sys::cv_detail_Blender_feed__InputArray__InputArray_Point(self.as_raw_Detail_Blender(), img.as_raw__InputArray(), mask.as_raw__InputArray(), tl) }.into_result()
+    }
+    
+    /// Blends and returns the final pano.
+    ///
+    /// ## Parameters
+    /// * dst: Final pano
+    /// * dst_mask: Final pano mask
+    fn blend(&mut self, dst: &mut dyn core::ToInputOutputArray, dst_mask: &mut dyn core::ToInputOutputArray) -> Result<()> {
+        input_output_array_arg!(dst);
+        input_output_array_arg!(dst_mask);
+        unsafe { sys::cv_detail_Blender_blend__InputOutputArray__InputOutputArray(self.as_raw_Detail_Blender(), dst.as_raw__InputOutputArray(), dst_mask.as_raw__InputOutputArray()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::detail::Blender
+/// Base class for all blenders.
+///
+/// Simple blender which puts one image over another
+pub struct Detail_Blender {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_Blender {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_Blender_delete(self.ptr) };
+    }
+}
+
+impl Detail_Blender {
+    #[inline(always)] pub fn as_raw_Detail_Blender(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_Blender {}
+
+impl crate::stitching::Detail_BlenderTrait for Detail_Blender {
+    #[inline(always)] fn as_raw_Detail_Blender(&self) -> *mut c_void { self.ptr }
+}
+
+impl Detail_Blender {
+    ///
+    /// ## C++ default parameters
+    /// * try_gpu: false
+    pub fn create_default(_type: i32, try_gpu: bool) -> Result<types::PtrOfDetail_Blender> {
+        unsafe { sys::cv_detail_Blender_createDefault_int_bool(_type, try_gpu) }.into_result().map(|ptr| types::PtrOfDetail_Blender { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::BlocksGainCompensator
+/// Exposure compensator which tries to remove exposure related artifacts by adjusting image block
+/// intensities, see [UES01](https://docs.opencv.org/3.2.0/d0/de3/citelist.html#CITEREF_UES01) for details.
+pub struct Detail_BlocksGainCompensator {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_BlocksGainCompensator {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_BlocksGainCompensator_delete(self.ptr) };
+    }
+}
+
+impl Detail_BlocksGainCompensator {
+    #[inline(always)] pub fn as_raw_Detail_BlocksGainCompensator(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_BlocksGainCompensator {}
+
+impl crate::stitching::Detail_ExposureCompensator for Detail_BlocksGainCompensator {
+    #[inline(always)] fn as_raw_Detail_ExposureCompensator(&self) -> *mut c_void { self.ptr }
+}
+
+impl Detail_BlocksGainCompensator {
+    ///
+    /// ## C++ default parameters
+    /// * bl_width: 32
+    /// * bl_height: 32
+    pub fn new(bl_width: i32, bl_height: i32) -> Result<crate::stitching::Detail_BlocksGainCompensator> {
+        unsafe { sys::cv_detail_BlocksGainCompensator_BlocksGainCompensator_int_int(bl_width, bl_height) }.into_result().map(|ptr| crate::stitching::Detail_BlocksGainCompensator { ptr })
+    }
+    
+    pub fn apply(&mut self, index: i32, corner: core::Point, image: &mut dyn core::ToInputOutputArray, mask: &dyn core::ToInputArray) -> Result<()> {
+        input_output_array_arg!(image);
+        input_array_arg!(mask);
+        unsafe { sys::cv_detail_BlocksGainCompensator_apply_int_Point__InputOutputArray__InputArray(self.as_raw_Detail_BlocksGainCompensator(), index, corner, image.as_raw__InputOutputArray(), mask.as_raw__InputArray()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::detail::BundleAdjusterAffine
+/// Bundle adjuster that expects affine transformation
+/// represented in homogeneous coordinates in R for each camera param. Implements
+/// camera parameters refinement algorithm which minimizes sum of the reprojection
+/// error squares
+///
+/// It estimates all transformation parameters. Refinement mask is ignored.
+///
+/// ## See also
+/// AffineBasedEstimator AffineBestOf2NearestMatcher BundleAdjusterAffinePartial
+pub struct Detail_BundleAdjusterAffine {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_BundleAdjusterAffine {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_BundleAdjusterAffine_delete(self.ptr) };
+    }
+}
+
+impl Detail_BundleAdjusterAffine {
+    #[inline(always)] pub fn as_raw_Detail_BundleAdjusterAffine(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_BundleAdjusterAffine {}
+
+impl crate::stitching::Detail_BundleAdjusterBase for Detail_BundleAdjusterAffine {
+    #[inline(always)] fn as_raw_Detail_BundleAdjusterBase(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::stitching::Detail_Estimator for Detail_BundleAdjusterAffine {
+    #[inline(always)] fn as_raw_Detail_Estimator(&self) -> *mut c_void { self.ptr }
+}
+
+impl Detail_BundleAdjusterAffine {
+    pub fn default() -> Result<crate::stitching::Detail_BundleAdjusterAffine> {
+        unsafe { sys::cv_detail_BundleAdjusterAffine_BundleAdjusterAffine() }.into_result().map(|ptr| crate::stitching::Detail_BundleAdjusterAffine { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::BundleAdjusterAffinePartial
+/// Bundle adjuster that expects affine transformation with 4 DOF
+/// represented in homogeneous coordinates in R for each camera param. Implements
+/// camera parameters refinement algorithm which minimizes sum of the reprojection
+/// error squares
+///
+/// It estimates all transformation parameters. Refinement mask is ignored.
+///
+/// ## See also
+/// AffineBasedEstimator AffineBestOf2NearestMatcher BundleAdjusterAffine
+pub struct Detail_BundleAdjusterAffinePartial {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_BundleAdjusterAffinePartial {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_BundleAdjusterAffinePartial_delete(self.ptr) };
+    }
+}
+
+impl Detail_BundleAdjusterAffinePartial {
+    #[inline(always)] pub fn as_raw_Detail_BundleAdjusterAffinePartial(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_BundleAdjusterAffinePartial {}
+
+impl crate::stitching::Detail_BundleAdjusterBase for Detail_BundleAdjusterAffinePartial {
+    #[inline(always)] fn as_raw_Detail_BundleAdjusterBase(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::stitching::Detail_Estimator for Detail_BundleAdjusterAffinePartial {
+    #[inline(always)] fn as_raw_Detail_Estimator(&self) -> *mut c_void { self.ptr }
+}
+
+impl Detail_BundleAdjusterAffinePartial {
+    pub fn default() -> Result<crate::stitching::Detail_BundleAdjusterAffinePartial> {
+        unsafe { sys::cv_detail_BundleAdjusterAffinePartial_BundleAdjusterAffinePartial() }.into_result().map(|ptr| crate::stitching::Detail_BundleAdjusterAffinePartial { ptr })
+    }
+    
+}
+
+// Generating impl for trait crate::stitching::Detail_BundleAdjusterBase
+/// Base class for all camera parameters refinement methods.
+pub trait Detail_BundleAdjusterBase: crate::stitching::Detail_Estimator {
+    fn as_raw_Detail_BundleAdjusterBase(&self) -> *mut c_void;
+    fn refinement_mask(&self) -> Result<core::Mat> {
+        unsafe { sys::cv_detail_BundleAdjusterBase_refinementMask_const(self.as_raw_Detail_BundleAdjusterBase()) }.into_result().map(|ptr| core::Mat { ptr })
+    }
+    
+    fn set_refinement_mask(&mut self, mask: &core::Mat) -> Result<()> {
+        unsafe { sys::cv_detail_BundleAdjusterBase_setRefinementMask_Mat(self.as_raw_Detail_BundleAdjusterBase(), mask.as_raw_Mat()) }.into_result()
+    }
+    
+    fn conf_thresh(&self) -> Result<f64> {
+        unsafe { sys::cv_detail_BundleAdjusterBase_confThresh_const(self.as_raw_Detail_BundleAdjusterBase()) }.into_result()
+    }
+    
+    fn set_conf_thresh(&mut self, conf_thresh: f64) -> Result<()> {
+        unsafe { sys::cv_detail_BundleAdjusterBase_setConfThresh_double(self.as_raw_Detail_BundleAdjusterBase(), conf_thresh) }.into_result()
+    }
+    
+    fn term_criteria(&mut self) -> Result<core::TermCriteria> {
+        unsafe { sys::cv_detail_BundleAdjusterBase_termCriteria(self.as_raw_Detail_BundleAdjusterBase()) }.into_result().map(|ptr| core::TermCriteria { ptr })
+    }
+    
+    fn set_term_criteria(&mut self, term_criteria: &core::TermCriteria) -> Result<()> {
+        unsafe { sys::cv_detail_BundleAdjusterBase_setTermCriteria_TermCriteria(self.as_raw_Detail_BundleAdjusterBase(), term_criteria.as_raw_TermCriteria()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::detail::BundleAdjusterRay
+/// Implementation of the camera parameters refinement algorithm which minimizes sum of the distances
+/// between the rays passing through the camera center and a feature. :
+///
+/// It can estimate focal length. It ignores the refinement mask for now.
+pub struct Detail_BundleAdjusterRay {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_BundleAdjusterRay {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_BundleAdjusterRay_delete(self.ptr) };
+    }
+}
+
+impl Detail_BundleAdjusterRay {
+    #[inline(always)] pub fn as_raw_Detail_BundleAdjusterRay(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_BundleAdjusterRay {}
+
+impl crate::stitching::Detail_BundleAdjusterBase for Detail_BundleAdjusterRay {
+    #[inline(always)] fn as_raw_Detail_BundleAdjusterBase(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::stitching::Detail_Estimator for Detail_BundleAdjusterRay {
+    #[inline(always)] fn as_raw_Detail_Estimator(&self) -> *mut c_void { self.ptr }
+}
+
+impl Detail_BundleAdjusterRay {
+    pub fn default() -> Result<crate::stitching::Detail_BundleAdjusterRay> {
+        unsafe { sys::cv_detail_BundleAdjusterRay_BundleAdjusterRay() }.into_result().map(|ptr| crate::stitching::Detail_BundleAdjusterRay { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::BundleAdjusterReproj
+/// Implementation of the camera parameters refinement algorithm which minimizes sum of the reprojection
+/// error squares
+///
+/// It can estimate focal length, aspect ratio, principal point.
+/// You can affect only on them via the refinement mask.
+pub struct Detail_BundleAdjusterReproj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_BundleAdjusterReproj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_BundleAdjusterReproj_delete(self.ptr) };
+    }
+}
+
+impl Detail_BundleAdjusterReproj {
+    #[inline(always)] pub fn as_raw_Detail_BundleAdjusterReproj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_BundleAdjusterReproj {}
+
+impl crate::stitching::Detail_BundleAdjusterBase for Detail_BundleAdjusterReproj {
+    #[inline(always)] fn as_raw_Detail_BundleAdjusterBase(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::stitching::Detail_Estimator for Detail_BundleAdjusterReproj {
+    #[inline(always)] fn as_raw_Detail_Estimator(&self) -> *mut c_void { self.ptr }
+}
+
+impl Detail_BundleAdjusterReproj {
+    pub fn default() -> Result<crate::stitching::Detail_BundleAdjusterReproj> {
+        unsafe { sys::cv_detail_BundleAdjusterReproj_BundleAdjusterReproj() }.into_result().map(|ptr| crate::stitching::Detail_BundleAdjusterReproj { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::CameraParams
+/// Describes camera parameters.
+///
+///
+/// Note: Translation is assumed to be zero during the whole stitching pipeline. :
+pub struct Detail_CameraParams {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_CameraParams {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_CameraParams_delete(self.ptr) };
+    }
+}
+
+impl Detail_CameraParams {
+    #[inline(always)] pub fn as_raw_Detail_CameraParams(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_CameraParams {}
+
+impl Detail_CameraParams {
+    pub fn default() -> Result<crate::stitching::Detail_CameraParams> {
+        unsafe { sys::cv_detail_CameraParams_CameraParams() }.into_result().map(|ptr| crate::stitching::Detail_CameraParams { ptr })
+    }
+    
+    pub fn copy(other: &crate::stitching::Detail_CameraParams) -> Result<crate::stitching::Detail_CameraParams> {
+        unsafe { sys::cv_detail_CameraParams_CameraParams_Detail_CameraParams(other.as_raw_Detail_CameraParams()) }.into_result().map(|ptr| crate::stitching::Detail_CameraParams { ptr })
+    }
+    
+    pub fn k(&self) -> Result<core::Mat> {
+        unsafe { sys::cv_detail_CameraParams_K_const(self.as_raw_Detail_CameraParams()) }.into_result().map(|ptr| core::Mat { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::CompressedRectilinearPortraitWarper
+pub struct Detail_CompressedRectilinearPortraitWarper {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_CompressedRectilinearPortraitWarper {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_CompressedRectilinearPortraitWarper_delete(self.ptr) };
+    }
+}
+
+impl Detail_CompressedRectilinearPortraitWarper {
+    #[inline(always)] pub fn as_raw_Detail_CompressedRectilinearPortraitWarper(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_CompressedRectilinearPortraitWarper {}
+
+impl Detail_CompressedRectilinearPortraitWarper {
+    ///
+    /// ## C++ default parameters
+    /// * a: 1
+    /// * b: 1
+    pub fn new(scale: f32, a: f32, b: f32) -> Result<crate::stitching::Detail_CompressedRectilinearPortraitWarper> {
+        unsafe { sys::cv_detail_CompressedRectilinearPortraitWarper_CompressedRectilinearPortraitWarper_float_float_float(scale, a, b) }.into_result().map(|ptr| crate::stitching::Detail_CompressedRectilinearPortraitWarper { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::CompressedRectilinearWarper
+pub struct Detail_CompressedRectilinearWarper {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_CompressedRectilinearWarper {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_CompressedRectilinearWarper_delete(self.ptr) };
+    }
+}
+
+impl Detail_CompressedRectilinearWarper {
+    #[inline(always)] pub fn as_raw_Detail_CompressedRectilinearWarper(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_CompressedRectilinearWarper {}
+
+impl Detail_CompressedRectilinearWarper {
+    ///
+    /// ## C++ default parameters
+    /// * a: 1
+    /// * b: 1
+    pub fn new(scale: f32, a: f32, b: f32) -> Result<crate::stitching::Detail_CompressedRectilinearWarper> {
+        unsafe { sys::cv_detail_CompressedRectilinearWarper_CompressedRectilinearWarper_float_float_float(scale, a, b) }.into_result().map(|ptr| crate::stitching::Detail_CompressedRectilinearWarper { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::CylindricalPortraitWarper
+pub struct Detail_CylindricalPortraitWarper {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_CylindricalPortraitWarper {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_CylindricalPortraitWarper_delete(self.ptr) };
+    }
+}
+
+impl Detail_CylindricalPortraitWarper {
+    #[inline(always)] pub fn as_raw_Detail_CylindricalPortraitWarper(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_CylindricalPortraitWarper {}
+
+impl Detail_CylindricalPortraitWarper {
+    pub fn new(scale: f32) -> Result<crate::stitching::Detail_CylindricalPortraitWarper> {
+        unsafe { sys::cv_detail_CylindricalPortraitWarper_CylindricalPortraitWarper_float(scale) }.into_result().map(|ptr| crate::stitching::Detail_CylindricalPortraitWarper { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::CylindricalWarper
+/// Warper that maps an image onto the x\*x + z\*z = 1 cylinder.
+pub struct Detail_CylindricalWarper {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_CylindricalWarper {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_CylindricalWarper_delete(self.ptr) };
+    }
+}
+
+impl Detail_CylindricalWarper {
+    #[inline(always)] pub fn as_raw_Detail_CylindricalWarper(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_CylindricalWarper {}
+
+impl Detail_CylindricalWarper {
+    /// Construct an instance of the cylindrical warper class.
+    ///
+    /// ## Parameters
+    /// * scale: Projected image scale multiplier
+    pub fn new(scale: f32) -> Result<crate::stitching::Detail_CylindricalWarper> {
+        unsafe { sys::cv_detail_CylindricalWarper_CylindricalWarper_float(scale) }.into_result().map(|ptr| crate::stitching::Detail_CylindricalWarper { ptr })
+    }
+    
+    pub fn build_maps(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, xmap: &mut dyn core::ToOutputArray, ymap: &mut dyn core::ToOutputArray) -> Result<core::Rect> {
+        input_array_arg!(k);
+        input_array_arg!(r);
+        output_array_arg!(xmap);
+        output_array_arg!(ymap);
+        unsafe { sys::cv_detail_CylindricalWarper_buildMaps_Size__InputArray__InputArray__OutputArray__OutputArray(self.as_raw_Detail_CylindricalWarper(), src_size, k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray()) }.into_result()
+    }
+    
+    pub fn warp(&mut self, src: &dyn core::ToInputArray, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, interp_mode: i32, border_mode: i32, dst: &mut dyn core::ToOutputArray) -> Result<core::Point> {
+        input_array_arg!(src);
+        input_array_arg!(k);
+        input_array_arg!(r);
+        output_array_arg!(dst);
+        unsafe { sys::cv_detail_CylindricalWarper_warp__InputArray__InputArray__InputArray_int_int__OutputArray(self.as_raw_Detail_CylindricalWarper(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::detail::DisjointSets
+pub struct Detail_DisjointSets {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_DisjointSets {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_DisjointSets_delete(self.ptr) };
+    }
+}
+
+impl Detail_DisjointSets {
+    #[inline(always)] pub fn as_raw_Detail_DisjointSets(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_DisjointSets {}
+
+impl Detail_DisjointSets {
+    ///
+    /// ## C++ default parameters
+    /// * elem_count: 0
+    pub fn new(elem_count: i32) -> Result<crate::stitching::Detail_DisjointSets> {
+        unsafe { sys::cv_detail_DisjointSets_DisjointSets_int(elem_count) }.into_result().map(|ptr| crate::stitching::Detail_DisjointSets { ptr })
+    }
+    
+    pub fn create_one_elem_sets(&mut self, elem_count: i32) -> Result<()> {
+        unsafe { sys::cv_detail_DisjointSets_createOneElemSets_int(self.as_raw_Detail_DisjointSets(), elem_count) }.into_result()
+    }
+    
+    pub fn find_set_by_elem(&mut self, elem: i32) -> Result<i32> {
+        unsafe { sys::cv_detail_DisjointSets_findSetByElem_int(self.as_raw_Detail_DisjointSets(), elem) }.into_result()
+    }
+    
+    pub fn merge_sets(&mut self, set1: i32, set2: i32) -> Result<i32> {
+        unsafe { sys::cv_detail_DisjointSets_mergeSets_int_int(self.as_raw_Detail_DisjointSets(), set1, set2) }.into_result()
+    }
+    
+}
+
+// boxed class cv::detail::DpSeamFinder
+pub struct Detail_DpSeamFinder {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_DpSeamFinder {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_DpSeamFinder_delete(self.ptr) };
+    }
+}
+
+impl Detail_DpSeamFinder {
+    #[inline(always)] pub fn as_raw_Detail_DpSeamFinder(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_DpSeamFinder {}
+
+impl crate::stitching::Detail_SeamFinder for Detail_DpSeamFinder {
+    #[inline(always)] fn as_raw_Detail_SeamFinder(&self) -> *mut c_void { self.ptr }
+}
+
+impl Detail_DpSeamFinder {
+    pub fn find(&mut self, src: &types::VectorOfUMat, corners: &types::VectorOfPoint, masks: &mut types::VectorOfUMat) -> Result<()> {
+        unsafe { sys::cv_detail_DpSeamFinder_find_VectorOfUMat_VectorOfPoint_VectorOfUMat(self.as_raw_Detail_DpSeamFinder(), src.as_raw_VectorOfUMat(), corners.as_raw_VectorOfPoint(), masks.as_raw_VectorOfUMat()) }.into_result()
+    }
+    
+}
+
+// Generating impl for trait crate::stitching::Detail_Estimator
+/// Rotation estimator base class.
+///
+/// It takes features of all images, pairwise matches between all images and estimates rotations of all
+/// cameras.
+///
+///
+/// Note: The coordinate system origin is implementation-dependent, but you can always normalize the
+/// rotations in respect to the first camera, for instance. :
+pub trait Detail_Estimator {
+    fn as_raw_Detail_Estimator(&self) -> *mut c_void;
+    /// Estimates camera parameters.
+    ///
+    /// ## Parameters
+    /// * features: Features of images
+    /// * pairwise_matches: Pairwise matches of images
+    /// * cameras: Estimated camera parameters
+    /// ## Returns
+    /// True in case of success, false otherwise
+    fn apply(&mut self, features: &types::VectorOfDetail_ImageFeatures, pairwise_matches: &types::VectorOfDetail_MatchesInfo, cameras: &mut types::VectorOfDetail_CameraParams) -> Result<bool> {
+        unsafe { sys::cv_detail_Estimator_operator_call_VectorOfDetail_ImageFeatures_VectorOfDetail_MatchesInfo_VectorOfDetail_CameraParams(self.as_raw_Detail_Estimator(), features.as_raw_VectorOfDetail_ImageFeatures(), pairwise_matches.as_raw_VectorOfDetail_MatchesInfo(), cameras.as_raw_VectorOfDetail_CameraParams()) }.into_result()
+    }
+    
+}
+
+// Generating impl for trait crate::stitching::Detail_ExposureCompensator
+/// Base class for all exposure compensators.
+pub trait Detail_ExposureCompensator {
+    fn as_raw_Detail_ExposureCompensator(&self) -> *mut c_void;
+    /// ## Parameters
+    /// * corners: Source image top-left corners
+    /// * images: Source images
+    /// * masks: Image masks to update (second value in pair specifies the value which should be used
+    /// to detect where image is)
+    fn feed(&mut self, corners: &types::VectorOfPoint, images: &types::VectorOfUMat, masks: &types::VectorOfUMat) -> Result<()> {
+        unsafe { sys::cv_detail_ExposureCompensator_feed_VectorOfPoint_VectorOfUMat_VectorOfUMat(self.as_raw_Detail_ExposureCompensator(), corners.as_raw_VectorOfPoint(), images.as_raw_VectorOfUMat(), masks.as_raw_VectorOfUMat()) }.into_result()
+    }
+    
+    /// Compensate exposure in the specified image.
+    ///
+    /// ## Parameters
+    /// * index: Image index
+    /// * corner: Image top-left corner
+    /// * image: Image to process
+    /// * mask: Image mask
+    fn apply(&mut self, index: i32, corner: core::Point, image: &mut dyn core::ToInputOutputArray, mask: &dyn core::ToInputArray) -> Result<()> {
+        input_output_array_arg!(image);
+        input_array_arg!(mask);
+        unsafe { sys::cv_detail_ExposureCompensator_apply_int_Point__InputOutputArray__InputArray(self.as_raw_Detail_ExposureCompensator(), index, corner, image.as_raw__InputOutputArray(), mask.as_raw__InputArray()) }.into_result()
+    }
+    
+}
+
+impl dyn Detail_ExposureCompensator + '_ {
+    pub fn create_default(_type: i32) -> Result<types::PtrOfDetail_ExposureCompensator> {
+        unsafe { sys::cv_detail_ExposureCompensator_createDefault_int(_type) }.into_result().map(|ptr| types::PtrOfDetail_ExposureCompensator { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::FeatherBlender
+/// Simple blender which mixes images at its borders.
+pub struct Detail_FeatherBlender {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_FeatherBlender {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_FeatherBlender_delete(self.ptr) };
+    }
+}
+
+impl Detail_FeatherBlender {
+    #[inline(always)] pub fn as_raw_Detail_FeatherBlender(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_FeatherBlender {}
+
+impl crate::stitching::Detail_BlenderTrait for Detail_FeatherBlender {
+    #[inline(always)] fn as_raw_Detail_Blender(&self) -> *mut c_void { self.ptr }
+}
+
+impl Detail_FeatherBlender {
+    ///
+    /// ## C++ default parameters
+    /// * sharpness: 0.02f
+    pub fn new(sharpness: f32) -> Result<crate::stitching::Detail_FeatherBlender> {
+        unsafe { sys::cv_detail_FeatherBlender_FeatherBlender_float(sharpness) }.into_result().map(|ptr| crate::stitching::Detail_FeatherBlender { ptr })
+    }
+    
+    pub fn sharpness(&self) -> Result<f32> {
+        unsafe { sys::cv_detail_FeatherBlender_sharpness_const(self.as_raw_Detail_FeatherBlender()) }.into_result()
+    }
+    
+    pub fn set_sharpness(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_detail_FeatherBlender_setSharpness_float(self.as_raw_Detail_FeatherBlender(), val) }.into_result()
+    }
+    
+    pub fn prepare(&mut self, dst_roi: core::Rect) -> Result<()> {
+        unsafe { sys::cv_detail_FeatherBlender_prepare_Rect(self.as_raw_Detail_FeatherBlender(), dst_roi) }.into_result()
+    }
+    
+    pub fn feed(&mut self, img: &dyn core::ToInputArray, mask: &dyn core::ToInputArray, tl: core::Point) -> Result<()> {
+        input_array_arg!(img);
+        input_array_arg!(mask);
+        unsafe { sys::cv_detail_FeatherBlender_feed__InputArray__InputArray_Point(self.as_raw_Detail_FeatherBlender(), img.as_raw__InputArray(), mask.as_raw__InputArray(), tl) }.into_result()
+    }
+    
+    pub fn blend(&mut self, dst: &mut dyn core::ToInputOutputArray, dst_mask: &mut dyn core::ToInputOutputArray) -> Result<()> {
+        input_output_array_arg!(dst);
+        input_output_array_arg!(dst_mask);
+        unsafe { sys::cv_detail_FeatherBlender_blend__InputOutputArray__InputOutputArray(self.as_raw_Detail_FeatherBlender(), dst.as_raw__InputOutputArray(), dst_mask.as_raw__InputOutputArray()) }.into_result()
+    }
+    
+    /// Creates weight maps for fixed set of source images by their masks and top-left corners.
+    /// Final image can be obtained by simple weighting of the source images.
+    pub fn create_weight_maps(&mut self, masks: &types::VectorOfUMat, corners: &types::VectorOfPoint, weight_maps: &mut types::VectorOfUMat) -> Result<core::Rect> {
+        unsafe { sys::cv_detail_FeatherBlender_createWeightMaps_VectorOfUMat_VectorOfPoint_VectorOfUMat(self.as_raw_Detail_FeatherBlender(), masks.as_raw_VectorOfUMat(), corners.as_raw_VectorOfPoint(), weight_maps.as_raw_VectorOfUMat()) }.into_result()
+    }
+    
+}
+
+// Generating impl for trait crate::stitching::Detail_FeaturesFinder
+/// Feature finders base class
+pub trait Detail_FeaturesFinder {
+    fn as_raw_Detail_FeaturesFinder(&self) -> *mut c_void;
+    fn apply(&mut self, image: &dyn core::ToInputArray, features: &mut crate::stitching::Detail_ImageFeatures) -> Result<()> {
+        input_array_arg!(image);
+        unsafe { sys::cv_detail_FeaturesFinder_operator_call__InputArray_Detail_ImageFeatures(self.as_raw_Detail_FeaturesFinder(), image.as_raw__InputArray(), features.as_raw_Detail_ImageFeatures()) }.into_result()
+    }
+    
+    /// Finds features in the given image.
+    ///
+    /// ## Parameters
+    /// * image: Source image
+    /// * features: Found features
+    /// * rois: Regions of interest
+    ///
+    /// ## See also
+    /// detail::ImageFeatures, Rect_
+    fn apply_rois(&mut self, image: &dyn core::ToInputArray, features: &mut crate::stitching::Detail_ImageFeatures, rois: &types::VectorOfRect) -> Result<()> {
+        input_array_arg!(image);
+        unsafe { sys::cv_detail_FeaturesFinder_operator_call__InputArray_Detail_ImageFeatures_VectorOfRect(self.as_raw_Detail_FeaturesFinder(), image.as_raw__InputArray(), features.as_raw_Detail_ImageFeatures(), rois.as_raw_VectorOfRect()) }.into_result()
+    }
+    
+    /// Finds features in the given images in parallel.
+    ///
+    /// ## Parameters
+    /// * images: Source images
+    /// * features: Found features for each image
+    /// * rois: Regions of interest for each image
+    ///
+    /// ## See also
+    /// detail::ImageFeatures, Rect_
+    fn apply_multiple_rois(&mut self, images: &dyn core::ToInputArray, features: &mut types::VectorOfDetail_ImageFeatures, rois: &types::VectorOfVectorOfRect) -> Result<()> {
+        input_array_arg!(images);
+        unsafe { sys::cv_detail_FeaturesFinder_operator_call__InputArray_VectorOfDetail_ImageFeatures_VectorOfVectorOfRect(self.as_raw_Detail_FeaturesFinder(), images.as_raw__InputArray(), features.as_raw_VectorOfDetail_ImageFeatures(), rois.as_raw_VectorOfVectorOfRect()) }.into_result()
+    }
+    
+    fn apply_multiple(&mut self, images: &dyn core::ToInputArray, features: &mut types::VectorOfDetail_ImageFeatures) -> Result<()> {
+        input_array_arg!(images);
+        unsafe { sys::cv_detail_FeaturesFinder_operator_call__InputArray_VectorOfDetail_ImageFeatures(self.as_raw_Detail_FeaturesFinder(), images.as_raw__InputArray(), features.as_raw_VectorOfDetail_ImageFeatures()) }.into_result()
+    }
+    
+    /// Frees unused memory allocated before if there is any.
+    fn collect_garbage(&mut self) -> Result<()> {
+        unsafe { sys::cv_detail_FeaturesFinder_collectGarbage(self.as_raw_Detail_FeaturesFinder()) }.into_result()
+    }
+    
+}
+
+// Generating impl for trait crate::stitching::Detail_FeaturesMatcher
+/// Feature matchers base class.
+pub trait Detail_FeaturesMatcher {
+    fn as_raw_Detail_FeaturesMatcher(&self) -> *mut c_void;
+    /// ## Parameters
+    /// * features1: First image features
+    /// * features2: Second image features
+    /// * matches_info: Found matches
+    fn apply(&mut self, features1: &crate::stitching::Detail_ImageFeatures, features2: &crate::stitching::Detail_ImageFeatures, matches_info: &mut crate::stitching::Detail_MatchesInfo) -> Result<()> {
+        unsafe { sys::cv_detail_FeaturesMatcher_operator_call_Detail_ImageFeatures_Detail_ImageFeatures_Detail_MatchesInfo(self.as_raw_Detail_FeaturesMatcher(), features1.as_raw_Detail_ImageFeatures(), features2.as_raw_Detail_ImageFeatures(), matches_info.as_raw_Detail_MatchesInfo()) }.into_result()
+    }
+    
+    /// Performs images matching.
+    ///
+    /// ## Parameters
+    /// * features: Features of the source images
+    /// * pairwise_matches: Found pairwise matches
+    /// * mask: Mask indicating which image pairs must be matched
+    ///
+    /// The function is parallelized with the TBB library.
+    ///
+    /// ## See also
+    /// detail::MatchesInfo
+    ///
+    /// ## C++ default parameters
+    /// * mask: cv::UMat()
+    fn apply_with_mask(&mut self, features: &types::VectorOfDetail_ImageFeatures, pairwise_matches: &mut types::VectorOfDetail_MatchesInfo, mask: &core::UMat) -> Result<()> {
+        unsafe { sys::cv_detail_FeaturesMatcher_operator_call_VectorOfDetail_ImageFeatures_VectorOfDetail_MatchesInfo_UMat(self.as_raw_Detail_FeaturesMatcher(), features.as_raw_VectorOfDetail_ImageFeatures(), pairwise_matches.as_raw_VectorOfDetail_MatchesInfo(), mask.as_raw_UMat()) }.into_result()
+    }
+    
+    /// ## Returns
+    /// True, if it's possible to use the same matcher instance in parallel, false otherwise
+    fn is_thread_safe(&self) -> Result<bool> {
+        unsafe { sys::cv_detail_FeaturesMatcher_isThreadSafe_const(self.as_raw_Detail_FeaturesMatcher()) }.into_result()
+    }
+    
+    /// Frees unused memory allocated before if there is any.
+    fn collect_garbage(&mut self) -> Result<()> {
+        unsafe { sys::cv_detail_FeaturesMatcher_collectGarbage(self.as_raw_Detail_FeaturesMatcher()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::detail::FisheyeWarper
+pub struct Detail_FisheyeWarper {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_FisheyeWarper {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_FisheyeWarper_delete(self.ptr) };
+    }
+}
+
+impl Detail_FisheyeWarper {
+    #[inline(always)] pub fn as_raw_Detail_FisheyeWarper(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_FisheyeWarper {}
+
+impl Detail_FisheyeWarper {
+    pub fn new(scale: f32) -> Result<crate::stitching::Detail_FisheyeWarper> {
+        unsafe { sys::cv_detail_FisheyeWarper_FisheyeWarper_float(scale) }.into_result().map(|ptr| crate::stitching::Detail_FisheyeWarper { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::GainCompensator
+/// Exposure compensator which tries to remove exposure related artifacts by adjusting image
+/// intensities, see [BL07](https://docs.opencv.org/3.2.0/d0/de3/citelist.html#CITEREF_BL07) and [WJ10](https://docs.opencv.org/3.2.0/d0/de3/citelist.html#CITEREF_WJ10) for details.
+pub struct Detail_GainCompensator {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_GainCompensator {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_GainCompensator_delete(self.ptr) };
+    }
+}
+
+impl Detail_GainCompensator {
+    #[inline(always)] pub fn as_raw_Detail_GainCompensator(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_GainCompensator {}
+
+impl crate::stitching::Detail_ExposureCompensator for Detail_GainCompensator {
+    #[inline(always)] fn as_raw_Detail_ExposureCompensator(&self) -> *mut c_void { self.ptr }
+}
+
+impl Detail_GainCompensator {
+    pub fn apply(&mut self, index: i32, corner: core::Point, image: &mut dyn core::ToInputOutputArray, mask: &dyn core::ToInputArray) -> Result<()> {
+        input_output_array_arg!(image);
+        input_array_arg!(mask);
+        unsafe { sys::cv_detail_GainCompensator_apply_int_Point__InputOutputArray__InputArray(self.as_raw_Detail_GainCompensator(), index, corner, image.as_raw__InputOutputArray(), mask.as_raw__InputArray()) }.into_result()
+    }
+    
+    pub fn gains(&self) -> Result<types::VectorOfdouble> {
+        unsafe { sys::cv_detail_GainCompensator_gains_const(self.as_raw_Detail_GainCompensator()) }.into_result().map(|ptr| unsafe { types::VectorOfdouble::from_raw_ptr(ptr) })
+    }
+    
+    pub fn default() -> Result<crate::stitching::Detail_GainCompensator> {
+        unsafe { sys::cv_detail_GainCompensator_GainCompensator() }.into_result().map(|ptr| crate::stitching::Detail_GainCompensator { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::Graph
+pub struct Detail_Graph {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_Graph {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_Graph_delete(self.ptr) };
+    }
+}
+
+impl Detail_Graph {
+    #[inline(always)] pub fn as_raw_Detail_Graph(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_Graph {}
+
+impl Detail_Graph {
+    ///
+    /// ## C++ default parameters
+    /// * num_vertices: 0
+    pub fn new(num_vertices: i32) -> Result<crate::stitching::Detail_Graph> {
+        unsafe { sys::cv_detail_Graph_Graph_int(num_vertices) }.into_result().map(|ptr| crate::stitching::Detail_Graph { ptr })
+    }
+    
+    pub fn create(&mut self, num_vertices: i32) -> Result<()> {
+        unsafe { sys::cv_detail_Graph_create_int(self.as_raw_Detail_Graph(), num_vertices) }.into_result()
+    }
+    
+    pub fn num_vertices(&self) -> Result<i32> {
+        unsafe { sys::cv_detail_Graph_numVertices_const(self.as_raw_Detail_Graph()) }.into_result()
+    }
+    
+    pub fn add_edge(&mut self, from: i32, to: i32, weight: f32) -> Result<()> {
+        unsafe { sys::cv_detail_Graph_addEdge_int_int_float(self.as_raw_Detail_Graph(), from, to, weight) }.into_result()
+    }
+    
+}
+
+// boxed class cv::detail::GraphCutSeamFinder
+/// Minimum graph cut-based seam estimator. See details in [V03](https://docs.opencv.org/3.2.0/d0/de3/citelist.html#CITEREF_V03) .
+pub struct Detail_GraphCutSeamFinder {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_GraphCutSeamFinder {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_GraphCutSeamFinder_delete(self.ptr) };
+    }
+}
+
+impl Detail_GraphCutSeamFinder {
+    #[inline(always)] pub fn as_raw_Detail_GraphCutSeamFinder(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_GraphCutSeamFinder {}
+
+impl crate::stitching::Detail_GraphCutSeamFinderBaseTrait for Detail_GraphCutSeamFinder {
+    #[inline(always)] fn as_raw_Detail_GraphCutSeamFinderBase(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::stitching::Detail_SeamFinder for Detail_GraphCutSeamFinder {
+    #[inline(always)] fn as_raw_Detail_SeamFinder(&self) -> *mut c_void { self.ptr }
+}
+
+impl Detail_GraphCutSeamFinder {
+    ///
+    /// ## C++ default parameters
+    /// * cost_type: COST_COLOR_GRAD
+    /// * terminal_cost: 10000.f
+    /// * bad_region_penalty: 1000.f
+    pub fn new(cost_type: i32, terminal_cost: f32, bad_region_penalty: f32) -> Result<crate::stitching::Detail_GraphCutSeamFinder> {
+        unsafe { sys::cv_detail_GraphCutSeamFinder_GraphCutSeamFinder_int_float_float(cost_type, terminal_cost, bad_region_penalty) }.into_result().map(|ptr| crate::stitching::Detail_GraphCutSeamFinder { ptr })
+    }
+    
+    pub fn find(&mut self, src: &types::VectorOfUMat, corners: &types::VectorOfPoint, masks: &mut types::VectorOfUMat) -> Result<()> {
+        unsafe { sys::cv_detail_GraphCutSeamFinder_find_VectorOfUMat_VectorOfPoint_VectorOfUMat(self.as_raw_Detail_GraphCutSeamFinder(), src.as_raw_VectorOfUMat(), corners.as_raw_VectorOfPoint(), masks.as_raw_VectorOfUMat()) }.into_result()
+    }
+    
+}
+
+// Generating impl for trait crate::stitching::Detail_GraphCutSeamFinderBase
+/// Base class for all minimum graph-cut-based seam estimators.
+pub trait Detail_GraphCutSeamFinderBaseTrait {
+    fn as_raw_Detail_GraphCutSeamFinderBase(&self) -> *mut c_void;
+}
+
+// boxed class cv::detail::GraphCutSeamFinderBase
+/// Base class for all minimum graph-cut-based seam estimators.
+pub struct Detail_GraphCutSeamFinderBase {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_GraphCutSeamFinderBase {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_GraphCutSeamFinderBase_delete(self.ptr) };
+    }
+}
+
+impl Detail_GraphCutSeamFinderBase {
+    #[inline(always)] pub fn as_raw_Detail_GraphCutSeamFinderBase(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_GraphCutSeamFinderBase {}
+
+impl crate::stitching::Detail_GraphCutSeamFinderBaseTrait for Detail_GraphCutSeamFinderBase {
+    #[inline(always)] fn as_raw_Detail_GraphCutSeamFinderBase(&self) -> *mut c_void { self.ptr }
+}
+
+// boxed class cv::detail::GraphEdge
+pub struct Detail_GraphEdge {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_GraphEdge {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_GraphEdge_delete(self.ptr) };
+    }
+}
+
+impl Detail_GraphEdge {
+    #[inline(always)] pub fn as_raw_Detail_GraphEdge(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_GraphEdge {}
+
+impl Detail_GraphEdge {
+    pub fn new(from: i32, to: i32, weight: f32) -> Result<crate::stitching::Detail_GraphEdge> {
+        unsafe { sys::cv_detail_GraphEdge_GraphEdge_int_int_float(from, to, weight) }.into_result().map(|ptr| crate::stitching::Detail_GraphEdge { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::HomographyBasedEstimator
+/// Homography based rotation estimator.
+pub struct Detail_HomographyBasedEstimator {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_HomographyBasedEstimator {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_HomographyBasedEstimator_delete(self.ptr) };
+    }
+}
+
+impl Detail_HomographyBasedEstimator {
+    #[inline(always)] pub fn as_raw_Detail_HomographyBasedEstimator(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_HomographyBasedEstimator {}
+
+impl crate::stitching::Detail_Estimator for Detail_HomographyBasedEstimator {
+    #[inline(always)] fn as_raw_Detail_Estimator(&self) -> *mut c_void { self.ptr }
+}
+
+impl Detail_HomographyBasedEstimator {
+    ///
+    /// ## C++ default parameters
+    /// * is_focals_estimated: false
+    pub fn new(is_focals_estimated: bool) -> Result<crate::stitching::Detail_HomographyBasedEstimator> {
+        unsafe { sys::cv_detail_HomographyBasedEstimator_HomographyBasedEstimator_bool(is_focals_estimated) }.into_result().map(|ptr| crate::stitching::Detail_HomographyBasedEstimator { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::ImageFeatures
+/// Structure containing image keypoints and descriptors.
+pub struct Detail_ImageFeatures {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_ImageFeatures {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_ImageFeatures_delete(self.ptr) };
+    }
+}
+
+impl Detail_ImageFeatures {
+    #[inline(always)] pub fn as_raw_Detail_ImageFeatures(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_ImageFeatures {}
+
+impl Detail_ImageFeatures {
+    pub fn default() -> Result<crate::stitching::Detail_ImageFeatures> {
+        unsafe { sys::cv_detail_ImageFeatures_ImageFeatures() }.into_result().map(|ptr| crate::stitching::Detail_ImageFeatures { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::MatchesInfo
+/// Structure containing information about matches between two images.
+///
+/// It's assumed that there is a transformation between those images. Transformation may be
+/// homography or affine transformation based on selected matcher.
+///
+/// ## See also
+/// detail::FeaturesMatcher
+pub struct Detail_MatchesInfo {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_MatchesInfo {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_MatchesInfo_delete(self.ptr) };
+    }
+}
+
+impl Detail_MatchesInfo {
+    #[inline(always)] pub fn as_raw_Detail_MatchesInfo(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_MatchesInfo {}
+
+impl Detail_MatchesInfo {
+    pub fn default() -> Result<crate::stitching::Detail_MatchesInfo> {
+        unsafe { sys::cv_detail_MatchesInfo_MatchesInfo() }.into_result().map(|ptr| crate::stitching::Detail_MatchesInfo { ptr })
+    }
+    
+    pub fn copy(other: &crate::stitching::Detail_MatchesInfo) -> Result<crate::stitching::Detail_MatchesInfo> {
+        unsafe { sys::cv_detail_MatchesInfo_MatchesInfo_Detail_MatchesInfo(other.as_raw_Detail_MatchesInfo()) }.into_result().map(|ptr| crate::stitching::Detail_MatchesInfo { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::MercatorWarper
+pub struct Detail_MercatorWarper {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_MercatorWarper {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_MercatorWarper_delete(self.ptr) };
+    }
+}
+
+impl Detail_MercatorWarper {
+    #[inline(always)] pub fn as_raw_Detail_MercatorWarper(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_MercatorWarper {}
+
+impl Detail_MercatorWarper {
+    pub fn new(scale: f32) -> Result<crate::stitching::Detail_MercatorWarper> {
+        unsafe { sys::cv_detail_MercatorWarper_MercatorWarper_float(scale) }.into_result().map(|ptr| crate::stitching::Detail_MercatorWarper { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::MultiBandBlender
+/// Blender which uses multi-band blending algorithm (see [BA83](https://docs.opencv.org/3.2.0/d0/de3/citelist.html#CITEREF_BA83)).
+pub struct Detail_MultiBandBlender {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_MultiBandBlender {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_MultiBandBlender_delete(self.ptr) };
+    }
+}
+
+impl Detail_MultiBandBlender {
+    #[inline(always)] pub fn as_raw_Detail_MultiBandBlender(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_MultiBandBlender {}
+
+impl crate::stitching::Detail_BlenderTrait for Detail_MultiBandBlender {
+    #[inline(always)] fn as_raw_Detail_Blender(&self) -> *mut c_void { self.ptr }
+}
+
+impl Detail_MultiBandBlender {
+    ///
+    /// ## C++ default parameters
+    /// * try_gpu: false
+    /// * num_bands: 5
+    /// * weight_type: CV_32F
+    pub fn new(try_gpu: i32, num_bands: i32, weight_type: i32) -> Result<crate::stitching::Detail_MultiBandBlender> {
+        unsafe { sys::cv_detail_MultiBandBlender_MultiBandBlender_int_int_int(try_gpu, num_bands, weight_type) }.into_result().map(|ptr| crate::stitching::Detail_MultiBandBlender { ptr })
+    }
+    
+    pub fn num_bands(&self) -> Result<i32> {
+        unsafe { sys::cv_detail_MultiBandBlender_numBands_const(self.as_raw_Detail_MultiBandBlender()) }.into_result()
+    }
+    
+    pub fn set_num_bands(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_detail_MultiBandBlender_setNumBands_int(self.as_raw_Detail_MultiBandBlender(), val) }.into_result()
+    }
+    
+    pub fn prepare(&mut self, dst_roi: core::Rect) -> Result<()> {
+        unsafe { sys::cv_detail_MultiBandBlender_prepare_Rect(self.as_raw_Detail_MultiBandBlender(), dst_roi) }.into_result()
+    }
+    
+    pub fn feed(&mut self, img: &dyn core::ToInputArray, mask: &dyn core::ToInputArray, tl: core::Point) -> Result<()> {
+        input_array_arg!(img);
+        input_array_arg!(mask);
+        unsafe { sys::cv_detail_MultiBandBlender_feed__InputArray__InputArray_Point(self.as_raw_Detail_MultiBandBlender(), img.as_raw__InputArray(), mask.as_raw__InputArray(), tl) }.into_result()
+    }
+    
+    pub fn blend(&mut self, dst: &mut dyn core::ToInputOutputArray, dst_mask: &mut dyn core::ToInputOutputArray) -> Result<()> {
+        input_output_array_arg!(dst);
+        input_output_array_arg!(dst_mask);
+        unsafe { sys::cv_detail_MultiBandBlender_blend__InputOutputArray__InputOutputArray(self.as_raw_Detail_MultiBandBlender(), dst.as_raw__InputOutputArray(), dst_mask.as_raw__InputOutputArray()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::detail::NoBundleAdjuster
+/// Stub bundle adjuster that does nothing.
+pub struct Detail_NoBundleAdjuster {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_NoBundleAdjuster {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_NoBundleAdjuster_delete(self.ptr) };
+    }
+}
+
+impl Detail_NoBundleAdjuster {
+    #[inline(always)] pub fn as_raw_Detail_NoBundleAdjuster(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_NoBundleAdjuster {}
+
+impl crate::stitching::Detail_BundleAdjusterBase for Detail_NoBundleAdjuster {
+    #[inline(always)] fn as_raw_Detail_BundleAdjusterBase(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::stitching::Detail_Estimator for Detail_NoBundleAdjuster {
+    #[inline(always)] fn as_raw_Detail_Estimator(&self) -> *mut c_void { self.ptr }
+}
+
+impl Detail_NoBundleAdjuster {
+    pub fn default() -> Result<crate::stitching::Detail_NoBundleAdjuster> {
+        unsafe { sys::cv_detail_NoBundleAdjuster_NoBundleAdjuster() }.into_result().map(|ptr| crate::stitching::Detail_NoBundleAdjuster { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::NoExposureCompensator
+/// Stub exposure compensator which does nothing.
+pub struct Detail_NoExposureCompensator {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_NoExposureCompensator {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_NoExposureCompensator_delete(self.ptr) };
+    }
+}
+
+impl Detail_NoExposureCompensator {
+    #[inline(always)] pub fn as_raw_Detail_NoExposureCompensator(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_NoExposureCompensator {}
+
+impl crate::stitching::Detail_ExposureCompensator for Detail_NoExposureCompensator {
+    #[inline(always)] fn as_raw_Detail_ExposureCompensator(&self) -> *mut c_void { self.ptr }
+}
+
+impl Detail_NoExposureCompensator {
+    pub fn apply(&mut self, unnamed_arg: i32, unnamed_arg_1: core::Point, unnamed_arg_2: &mut dyn core::ToInputOutputArray, unnamed_arg_3: &dyn core::ToInputArray) -> Result<()> {
+        input_output_array_arg!(unnamed_arg_2);
+        input_array_arg!(unnamed_arg_3);
+        unsafe { sys::cv_detail_NoExposureCompensator_apply_int_Point__InputOutputArray__InputArray(self.as_raw_Detail_NoExposureCompensator(), unnamed_arg, unnamed_arg_1, unnamed_arg_2.as_raw__InputOutputArray(), unnamed_arg_3.as_raw__InputArray()) }.into_result()
+    }
+    
+    pub fn default() -> Result<crate::stitching::Detail_NoExposureCompensator> {
+        unsafe { sys::cv_detail_NoExposureCompensator_NoExposureCompensator() }.into_result().map(|ptr| crate::stitching::Detail_NoExposureCompensator { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::NoSeamFinder
+/// Stub seam estimator which does nothing.
+pub struct Detail_NoSeamFinder {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_NoSeamFinder {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_NoSeamFinder_delete(self.ptr) };
+    }
+}
+
+impl Detail_NoSeamFinder {
+    #[inline(always)] pub fn as_raw_Detail_NoSeamFinder(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_NoSeamFinder {}
+
+impl crate::stitching::Detail_SeamFinder for Detail_NoSeamFinder {
+    #[inline(always)] fn as_raw_Detail_SeamFinder(&self) -> *mut c_void { self.ptr }
+}
+
+impl Detail_NoSeamFinder {
+    pub fn find(&mut self, unnamed_arg: &types::VectorOfUMat, unnamed_arg_1: &types::VectorOfPoint, unnamed_arg_2: &mut types::VectorOfUMat) -> Result<()> {
+        unsafe { sys::cv_detail_NoSeamFinder_find_VectorOfUMat_VectorOfPoint_VectorOfUMat(self.as_raw_Detail_NoSeamFinder(), unnamed_arg.as_raw_VectorOfUMat(), unnamed_arg_1.as_raw_VectorOfPoint(), unnamed_arg_2.as_raw_VectorOfUMat()) }.into_result()
+    }
+    
+    pub fn default() -> Result<crate::stitching::Detail_NoSeamFinder> {
+        unsafe { sys::cv_detail_NoSeamFinder_NoSeamFinder() }.into_result().map(|ptr| crate::stitching::Detail_NoSeamFinder { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::OrbFeaturesFinder
+/// ORB features finder. :
+///
+/// ## See also
+/// detail::FeaturesFinder, ORB
+pub struct Detail_OrbFeaturesFinder {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_OrbFeaturesFinder {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_OrbFeaturesFinder_delete(self.ptr) };
+    }
+}
+
+impl Detail_OrbFeaturesFinder {
+    #[inline(always)] pub fn as_raw_Detail_OrbFeaturesFinder(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_OrbFeaturesFinder {}
+
+impl crate::stitching::Detail_FeaturesFinder for Detail_OrbFeaturesFinder {
+    #[inline(always)] fn as_raw_Detail_FeaturesFinder(&self) -> *mut c_void { self.ptr }
+}
+
+impl Detail_OrbFeaturesFinder {
+    ///
+    /// ## C++ default parameters
+    /// * _grid_size: Size(3,1)
+    /// * nfeatures: 1500
+    /// * scale_factor: 1.3f
+    /// * nlevels: 5
+    pub fn new(_grid_size: core::Size, nfeatures: i32, scale_factor: f32, nlevels: i32) -> Result<crate::stitching::Detail_OrbFeaturesFinder> {
+        unsafe { sys::cv_detail_OrbFeaturesFinder_OrbFeaturesFinder_Size_int_float_int(_grid_size, nfeatures, scale_factor, nlevels) }.into_result().map(|ptr| crate::stitching::Detail_OrbFeaturesFinder { ptr })
+    }
+    
+}
+
+// Generating impl for trait crate::stitching::Detail_PairwiseSeamFinder
+/// Base class for all pairwise seam estimators.
+pub trait Detail_PairwiseSeamFinder: crate::stitching::Detail_SeamFinder {
+    fn as_raw_Detail_PairwiseSeamFinder(&self) -> *mut c_void;
+    fn find(&mut self, src: &types::VectorOfUMat, corners: &types::VectorOfPoint, masks: &mut types::VectorOfUMat) -> Result<()> {
+        unsafe { sys::cv_detail_PairwiseSeamFinder_find_VectorOfUMat_VectorOfPoint_VectorOfUMat(self.as_raw_Detail_PairwiseSeamFinder(), src.as_raw_VectorOfUMat(), corners.as_raw_VectorOfPoint(), masks.as_raw_VectorOfUMat()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::detail::PaniniPortraitWarper
+pub struct Detail_PaniniPortraitWarper {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_PaniniPortraitWarper {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_PaniniPortraitWarper_delete(self.ptr) };
+    }
+}
+
+impl Detail_PaniniPortraitWarper {
+    #[inline(always)] pub fn as_raw_Detail_PaniniPortraitWarper(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_PaniniPortraitWarper {}
+
+impl Detail_PaniniPortraitWarper {
+    ///
+    /// ## C++ default parameters
+    /// * a: 1
+    /// * b: 1
+    pub fn new(scale: f32, a: f32, b: f32) -> Result<crate::stitching::Detail_PaniniPortraitWarper> {
+        unsafe { sys::cv_detail_PaniniPortraitWarper_PaniniPortraitWarper_float_float_float(scale, a, b) }.into_result().map(|ptr| crate::stitching::Detail_PaniniPortraitWarper { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::PaniniWarper
+pub struct Detail_PaniniWarper {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_PaniniWarper {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_PaniniWarper_delete(self.ptr) };
+    }
+}
+
+impl Detail_PaniniWarper {
+    #[inline(always)] pub fn as_raw_Detail_PaniniWarper(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_PaniniWarper {}
+
+impl Detail_PaniniWarper {
+    ///
+    /// ## C++ default parameters
+    /// * a: 1
+    /// * b: 1
+    pub fn new(scale: f32, a: f32, b: f32) -> Result<crate::stitching::Detail_PaniniWarper> {
+        unsafe { sys::cv_detail_PaniniWarper_PaniniWarper_float_float_float(scale, a, b) }.into_result().map(|ptr| crate::stitching::Detail_PaniniWarper { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::PlanePortraitWarper
+pub struct Detail_PlanePortraitWarper {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_PlanePortraitWarper {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_PlanePortraitWarper_delete(self.ptr) };
+    }
+}
+
+impl Detail_PlanePortraitWarper {
+    #[inline(always)] pub fn as_raw_Detail_PlanePortraitWarper(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_PlanePortraitWarper {}
+
+impl Detail_PlanePortraitWarper {
+    pub fn new(scale: f32) -> Result<crate::stitching::Detail_PlanePortraitWarper> {
+        unsafe { sys::cv_detail_PlanePortraitWarper_PlanePortraitWarper_float(scale) }.into_result().map(|ptr| crate::stitching::Detail_PlanePortraitWarper { ptr })
+    }
+    
+}
+
+// Generating impl for trait crate::stitching::Detail_PlaneWarper
+/// Warper that maps an image onto the z = 1 plane.
+pub trait Detail_PlaneWarperTrait {
+    fn as_raw_Detail_PlaneWarper(&self) -> *mut c_void;
+    fn warp_point(&mut self, pt: core::Point2f, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray) -> Result<core::Point2f> {
+        input_array_arg!(k);
+        input_array_arg!(r);
+        unsafe { sys::cv_detail_PlaneWarper_warpPoint_Point2f__InputArray__InputArray(self.as_raw_Detail_PlaneWarper(), pt, k.as_raw__InputArray(), r.as_raw__InputArray()) }.into_result()
+    }
+    
+    fn warp_point_1(&mut self, pt: core::Point2f, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, t: &dyn core::ToInputArray) -> Result<core::Point2f> {
+        input_array_arg!(k);
+        input_array_arg!(r);
+        input_array_arg!(t);
+        unsafe { sys::cv_detail_PlaneWarper_warpPoint_Point2f__InputArray__InputArray__InputArray(self.as_raw_Detail_PlaneWarper(), pt, k.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray()) }.into_result()
+    }
+    
+    fn build_maps(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, t: &dyn core::ToInputArray, xmap: &mut dyn core::ToOutputArray, ymap: &mut dyn core::ToOutputArray) -> Result<core::Rect> {
+        input_array_arg!(k);
+        input_array_arg!(r);
+        input_array_arg!(t);
+        output_array_arg!(xmap);
+        output_array_arg!(ymap);
+        unsafe { sys::cv_detail_PlaneWarper_buildMaps_Size__InputArray__InputArray__InputArray__OutputArray__OutputArray(self.as_raw_Detail_PlaneWarper(), src_size, k.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray()) }.into_result()
+    }
+    
+    fn build_maps_1(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, xmap: &mut dyn core::ToOutputArray, ymap: &mut dyn core::ToOutputArray) -> Result<core::Rect> {
+        input_array_arg!(k);
+        input_array_arg!(r);
+        output_array_arg!(xmap);
+        output_array_arg!(ymap);
+        unsafe { sys::cv_detail_PlaneWarper_buildMaps_Size__InputArray__InputArray__OutputArray__OutputArray(self.as_raw_Detail_PlaneWarper(), src_size, k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray()) }.into_result()
+    }
+    
+    fn warp(&mut self, src: &dyn core::ToInputArray, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, interp_mode: i32, border_mode: i32, dst: &mut dyn core::ToOutputArray) -> Result<core::Point> {
+        input_array_arg!(src);
+        input_array_arg!(k);
+        input_array_arg!(r);
+        output_array_arg!(dst);
+        unsafe { sys::cv_detail_PlaneWarper_warp__InputArray__InputArray__InputArray_int_int__OutputArray(self.as_raw_Detail_PlaneWarper(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray()) }.into_result()
+    }
+    
+    fn warp_1(&mut self, src: &dyn core::ToInputArray, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, t: &dyn core::ToInputArray, interp_mode: i32, border_mode: i32, dst: &mut dyn core::ToOutputArray) -> Result<core::Point> {
+        input_array_arg!(src);
+        input_array_arg!(k);
+        input_array_arg!(r);
+        input_array_arg!(t);
+        output_array_arg!(dst);
+        unsafe { sys::cv_detail_PlaneWarper_warp__InputArray__InputArray__InputArray__InputArray_int_int__OutputArray(self.as_raw_Detail_PlaneWarper(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray()) }.into_result()
+    }
+    
+    fn warp_roi(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray) -> Result<core::Rect> {
+        input_array_arg!(k);
+        input_array_arg!(r);
+        unsafe { sys::cv_detail_PlaneWarper_warpRoi_Size__InputArray__InputArray(self.as_raw_Detail_PlaneWarper(), src_size, k.as_raw__InputArray(), r.as_raw__InputArray()) }.into_result()
+    }
+    
+    fn warp_roi_1(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, t: &dyn core::ToInputArray) -> Result<core::Rect> {
+        input_array_arg!(k);
+        input_array_arg!(r);
+        input_array_arg!(t);
+        unsafe { sys::cv_detail_PlaneWarper_warpRoi_Size__InputArray__InputArray__InputArray(self.as_raw_Detail_PlaneWarper(), src_size, k.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::detail::PlaneWarper
+/// Warper that maps an image onto the z = 1 plane.
+pub struct Detail_PlaneWarper {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_PlaneWarper {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_PlaneWarper_delete(self.ptr) };
+    }
+}
+
+impl Detail_PlaneWarper {
+    #[inline(always)] pub fn as_raw_Detail_PlaneWarper(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_PlaneWarper {}
+
+impl crate::stitching::Detail_PlaneWarperTrait for Detail_PlaneWarper {
+    #[inline(always)] fn as_raw_Detail_PlaneWarper(&self) -> *mut c_void { self.ptr }
+}
+
+impl Detail_PlaneWarper {
+    /// Construct an instance of the plane warper class.
+    ///
+    /// ## Parameters
+    /// * scale: Projected image scale multiplier
+    ///
+    /// ## C++ default parameters
+    /// * scale: 1.f
+    pub fn new(scale: f32) -> Result<crate::stitching::Detail_PlaneWarper> {
+        unsafe { sys::cv_detail_PlaneWarper_PlaneWarper_float(scale) }.into_result().map(|ptr| crate::stitching::Detail_PlaneWarper { ptr })
+    }
+    
+}
+
+// Generating impl for trait crate::stitching::Detail_RotationWarper
+/// Rotation-only model image warper interface.
+pub trait Detail_RotationWarper {
+    fn as_raw_Detail_RotationWarper(&self) -> *mut c_void;
+    /// Projects the image point.
+    ///
+    /// ## Parameters
+    /// * pt: Source point
+    /// * K: Camera intrinsic parameters
+    /// * R: Camera rotation matrix
+    /// ## Returns
+    /// Projected point
+    fn warp_point(&mut self, pt: core::Point2f, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray) -> Result<core::Point2f> {
+        input_array_arg!(k);
+        input_array_arg!(r);
+        unsafe { sys::cv_detail_RotationWarper_warpPoint_Point2f__InputArray__InputArray(self.as_raw_Detail_RotationWarper(), pt, k.as_raw__InputArray(), r.as_raw__InputArray()) }.into_result()
+    }
+    
+    /// Builds the projection maps according to the given camera data.
+    ///
+    /// ## Parameters
+    /// * src_size: Source image size
+    /// * K: Camera intrinsic parameters
+    /// * R: Camera rotation matrix
+    /// * xmap: Projection map for the x axis
+    /// * ymap: Projection map for the y axis
+    /// ## Returns
+    /// Projected image minimum bounding box
+    fn build_maps(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, xmap: &mut dyn core::ToOutputArray, ymap: &mut dyn core::ToOutputArray) -> Result<core::Rect> {
+        input_array_arg!(k);
+        input_array_arg!(r);
+        output_array_arg!(xmap);
+        output_array_arg!(ymap);
+        unsafe { sys::cv_detail_RotationWarper_buildMaps_Size__InputArray__InputArray__OutputArray__OutputArray(self.as_raw_Detail_RotationWarper(), src_size, k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray()) }.into_result()
+    }
+    
+    /// Projects the image.
+    ///
+    /// ## Parameters
+    /// * src: Source image
+    /// * K: Camera intrinsic parameters
+    /// * R: Camera rotation matrix
+    /// * interp_mode: Interpolation mode
+    /// * border_mode: Border extrapolation mode
+    /// * dst: Projected image
+    /// ## Returns
+    /// Project image top-left corner
+    fn warp(&mut self, src: &dyn core::ToInputArray, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, interp_mode: i32, border_mode: i32, dst: &mut dyn core::ToOutputArray) -> Result<core::Point> {
+        input_array_arg!(src);
+        input_array_arg!(k);
+        input_array_arg!(r);
+        output_array_arg!(dst);
+        unsafe { sys::cv_detail_RotationWarper_warp__InputArray__InputArray__InputArray_int_int__OutputArray(self.as_raw_Detail_RotationWarper(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray()) }.into_result()
+    }
+    
+    /// Projects the image backward.
+    ///
+    /// ## Parameters
+    /// * src: Projected image
+    /// * K: Camera intrinsic parameters
+    /// * R: Camera rotation matrix
+    /// * interp_mode: Interpolation mode
+    /// * border_mode: Border extrapolation mode
+    /// * dst_size: Backward-projected image size
+    /// * dst: Backward-projected image
+    fn warp_backward(&mut self, src: &dyn core::ToInputArray, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, interp_mode: i32, border_mode: i32, dst_size: core::Size, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+        input_array_arg!(src);
+        input_array_arg!(k);
+        input_array_arg!(r);
+        output_array_arg!(dst);
+        unsafe { sys::cv_detail_RotationWarper_warpBackward__InputArray__InputArray__InputArray_int_int_Size__OutputArray(self.as_raw_Detail_RotationWarper(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst_size, dst.as_raw__OutputArray()) }.into_result()
+    }
+    
+    /// ## Parameters
+    /// * src_size: Source image bounding box
+    /// * K: Camera intrinsic parameters
+    /// * R: Camera rotation matrix
+    /// ## Returns
+    /// Projected image minimum bounding box
+    fn warp_roi(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray) -> Result<core::Rect> {
+        input_array_arg!(k);
+        input_array_arg!(r);
+        unsafe { sys::cv_detail_RotationWarper_warpRoi_Size__InputArray__InputArray(self.as_raw_Detail_RotationWarper(), src_size, k.as_raw__InputArray(), r.as_raw__InputArray()) }.into_result()
+    }
+    
+    fn get_scale(&self) -> Result<f32> {
+        unsafe { sys::cv_detail_RotationWarper_getScale_const(self.as_raw_Detail_RotationWarper()) }.into_result()
+    }
+    
+    fn set_scale(&mut self, unnamed_arg: f32) -> Result<()> {
+        unsafe { sys::cv_detail_RotationWarper_setScale_float(self.as_raw_Detail_RotationWarper(), unnamed_arg) }.into_result()
+    }
+    
+}
+
+// Generating impl for trait crate::stitching::Detail_SeamFinder
+/// Base class for a seam estimator.
+pub trait Detail_SeamFinder {
+    fn as_raw_Detail_SeamFinder(&self) -> *mut c_void;
+    /// Estimates seams.
+    ///
+    /// ## Parameters
+    /// * src: Source images
+    /// * corners: Source image top-left corners
+    /// * masks: Source image masks to update
+    fn find(&mut self, src: &types::VectorOfUMat, corners: &types::VectorOfPoint, masks: &mut types::VectorOfUMat) -> Result<()> {
+        unsafe { sys::cv_detail_SeamFinder_find_VectorOfUMat_VectorOfPoint_VectorOfUMat(self.as_raw_Detail_SeamFinder(), src.as_raw_VectorOfUMat(), corners.as_raw_VectorOfPoint(), masks.as_raw_VectorOfUMat()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::detail::SphericalPortraitWarper
+pub struct Detail_SphericalPortraitWarper {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_SphericalPortraitWarper {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_SphericalPortraitWarper_delete(self.ptr) };
+    }
+}
+
+impl Detail_SphericalPortraitWarper {
+    #[inline(always)] pub fn as_raw_Detail_SphericalPortraitWarper(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_SphericalPortraitWarper {}
+
+impl Detail_SphericalPortraitWarper {
+    pub fn new(scale: f32) -> Result<crate::stitching::Detail_SphericalPortraitWarper> {
+        unsafe { sys::cv_detail_SphericalPortraitWarper_SphericalPortraitWarper_float(scale) }.into_result().map(|ptr| crate::stitching::Detail_SphericalPortraitWarper { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::SphericalWarper
+/// Warper that maps an image onto the unit sphere located at the origin.
+///
+/// Projects image onto unit sphere with origin at (0, 0, 0) and radius scale, measured in pixels.
+/// A 360° panorama would therefore have a resulting width of 2 * scale * PI pixels.
+/// Poles are located at (0, -1, 0) and (0, 1, 0) points.
+pub struct Detail_SphericalWarper {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_SphericalWarper {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_SphericalWarper_delete(self.ptr) };
+    }
+}
+
+impl Detail_SphericalWarper {
+    #[inline(always)] pub fn as_raw_Detail_SphericalWarper(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_SphericalWarper {}
+
+impl Detail_SphericalWarper {
+    /// Construct an instance of the spherical warper class.
+    ///
+    /// ## Parameters
+    /// * scale: Radius of the projected sphere, in pixels. An image spanning the
+    /// whole sphere will have a width of 2 * scale * PI pixels.
+    pub fn new(scale: f32) -> Result<crate::stitching::Detail_SphericalWarper> {
+        unsafe { sys::cv_detail_SphericalWarper_SphericalWarper_float(scale) }.into_result().map(|ptr| crate::stitching::Detail_SphericalWarper { ptr })
+    }
+    
+    pub fn build_maps(&mut self, src_size: core::Size, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, xmap: &mut dyn core::ToOutputArray, ymap: &mut dyn core::ToOutputArray) -> Result<core::Rect> {
+        input_array_arg!(k);
+        input_array_arg!(r);
+        output_array_arg!(xmap);
+        output_array_arg!(ymap);
+        unsafe { sys::cv_detail_SphericalWarper_buildMaps_Size__InputArray__InputArray__OutputArray__OutputArray(self.as_raw_Detail_SphericalWarper(), src_size, k.as_raw__InputArray(), r.as_raw__InputArray(), xmap.as_raw__OutputArray(), ymap.as_raw__OutputArray()) }.into_result()
+    }
+    
+    pub fn warp(&mut self, src: &dyn core::ToInputArray, k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, interp_mode: i32, border_mode: i32, dst: &mut dyn core::ToOutputArray) -> Result<core::Point> {
+        input_array_arg!(src);
+        input_array_arg!(k);
+        input_array_arg!(r);
+        output_array_arg!(dst);
+        unsafe { sys::cv_detail_SphericalWarper_warp__InputArray__InputArray__InputArray_int_int__OutputArray(self.as_raw_Detail_SphericalWarper(), src.as_raw__InputArray(), k.as_raw__InputArray(), r.as_raw__InputArray(), interp_mode, border_mode, dst.as_raw__OutputArray()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::detail::StereographicWarper
+pub struct Detail_StereographicWarper {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_StereographicWarper {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_StereographicWarper_delete(self.ptr) };
+    }
+}
+
+impl Detail_StereographicWarper {
+    #[inline(always)] pub fn as_raw_Detail_StereographicWarper(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_StereographicWarper {}
+
+impl Detail_StereographicWarper {
+    pub fn new(scale: f32) -> Result<crate::stitching::Detail_StereographicWarper> {
+        unsafe { sys::cv_detail_StereographicWarper_StereographicWarper_float(scale) }.into_result().map(|ptr| crate::stitching::Detail_StereographicWarper { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::SurfFeaturesFinder
+/// SURF features finder.
+///
+/// ## See also
+/// detail::FeaturesFinder, SURF
+pub struct Detail_SurfFeaturesFinder {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_SurfFeaturesFinder {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_SurfFeaturesFinder_delete(self.ptr) };
+    }
+}
+
+impl Detail_SurfFeaturesFinder {
+    #[inline(always)] pub fn as_raw_Detail_SurfFeaturesFinder(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_SurfFeaturesFinder {}
+
+impl crate::stitching::Detail_FeaturesFinder for Detail_SurfFeaturesFinder {
+    #[inline(always)] fn as_raw_Detail_FeaturesFinder(&self) -> *mut c_void { self.ptr }
+}
+
+impl Detail_SurfFeaturesFinder {
+    ///
+    /// ## C++ default parameters
+    /// * hess_thresh: 300.
+    /// * num_octaves: 3
+    /// * num_layers: 4
+    /// * num_octaves_descr: 3
+    /// * num_layers_descr: 4
+    pub fn new(hess_thresh: f64, num_octaves: i32, num_layers: i32, num_octaves_descr: i32, num_layers_descr: i32) -> Result<crate::stitching::Detail_SurfFeaturesFinder> {
+        unsafe { sys::cv_detail_SurfFeaturesFinder_SurfFeaturesFinder_double_int_int_int_int(hess_thresh, num_octaves, num_layers, num_octaves_descr, num_layers_descr) }.into_result().map(|ptr| crate::stitching::Detail_SurfFeaturesFinder { ptr })
+    }
+    
+}
+
+// Generating impl for trait crate::stitching::Detail_Timelapser
+pub trait Detail_TimelapserTrait {
+    fn as_raw_Detail_Timelapser(&self) -> *mut c_void;
+    fn initialize(&mut self, corners: &types::VectorOfPoint, sizes: &types::VectorOfSize) -> Result<()> {
+        unsafe { sys::cv_detail_Timelapser_initialize_VectorOfPoint_VectorOfSize(self.as_raw_Detail_Timelapser(), corners.as_raw_VectorOfPoint(), sizes.as_raw_VectorOfSize()) }.into_result()
+    }
+    
+    fn process(&mut self, img: &dyn core::ToInputArray, mask: &dyn core::ToInputArray, tl: core::Point) -> Result<()> {
+        input_array_arg!(img);
+        input_array_arg!(mask);
+        unsafe { sys::cv_detail_Timelapser_process__InputArray__InputArray_Point(self.as_raw_Detail_Timelapser(), img.as_raw__InputArray(), mask.as_raw__InputArray(), tl) }.into_result()
+    }
+    
+    fn get_dst(&mut self) -> Result<core::UMat> {
+        unsafe { sys::cv_detail_Timelapser_getDst(self.as_raw_Detail_Timelapser()) }.into_result().map(|ptr| core::UMat { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::Timelapser
+pub struct Detail_Timelapser {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_Timelapser {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_Timelapser_delete(self.ptr) };
+    }
+}
+
+impl Detail_Timelapser {
+    #[inline(always)] pub fn as_raw_Detail_Timelapser(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_Timelapser {}
+
+impl crate::stitching::Detail_TimelapserTrait for Detail_Timelapser {
+    #[inline(always)] fn as_raw_Detail_Timelapser(&self) -> *mut c_void { self.ptr }
+}
+
+impl Detail_Timelapser {
+    pub fn create_default(_type: i32) -> Result<types::PtrOfDetail_Timelapser> {
+        unsafe { sys::cv_detail_Timelapser_createDefault_int(_type) }.into_result().map(|ptr| types::PtrOfDetail_Timelapser { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::TimelapserCrop
+pub struct Detail_TimelapserCrop {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_TimelapserCrop {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_TimelapserCrop_delete(self.ptr) };
+    }
+}
+
+impl Detail_TimelapserCrop {
+    #[inline(always)] pub fn as_raw_Detail_TimelapserCrop(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_TimelapserCrop {}
+
+impl crate::stitching::Detail_TimelapserTrait for Detail_TimelapserCrop {
+    #[inline(always)] fn as_raw_Detail_Timelapser(&self) -> *mut c_void { self.ptr }
+}
+
+impl Detail_TimelapserCrop {
+    pub fn initialize(&mut self, corners: &types::VectorOfPoint, sizes: &types::VectorOfSize) -> Result<()> {
+        unsafe { sys::cv_detail_TimelapserCrop_initialize_VectorOfPoint_VectorOfSize(self.as_raw_Detail_TimelapserCrop(), corners.as_raw_VectorOfPoint(), sizes.as_raw_VectorOfSize()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::detail::TransverseMercatorWarper
+pub struct Detail_TransverseMercatorWarper {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_TransverseMercatorWarper {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_TransverseMercatorWarper_delete(self.ptr) };
+    }
+}
+
+impl Detail_TransverseMercatorWarper {
+    #[inline(always)] pub fn as_raw_Detail_TransverseMercatorWarper(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_TransverseMercatorWarper {}
+
+impl Detail_TransverseMercatorWarper {
+    pub fn new(scale: f32) -> Result<crate::stitching::Detail_TransverseMercatorWarper> {
+        unsafe { sys::cv_detail_TransverseMercatorWarper_TransverseMercatorWarper_float(scale) }.into_result().map(|ptr| crate::stitching::Detail_TransverseMercatorWarper { ptr })
+    }
+    
+}
+
+// boxed class cv::detail::VoronoiSeamFinder
+/// Voronoi diagram-based seam estimator.
+pub struct Detail_VoronoiSeamFinder {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for Detail_VoronoiSeamFinder {
+    fn drop(&mut self) {
+        unsafe { sys::cv_Detail_VoronoiSeamFinder_delete(self.ptr) };
+    }
+}
+
+impl Detail_VoronoiSeamFinder {
+    #[inline(always)] pub fn as_raw_Detail_VoronoiSeamFinder(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for Detail_VoronoiSeamFinder {}
+
+impl crate::stitching::Detail_PairwiseSeamFinder for Detail_VoronoiSeamFinder {
+    #[inline(always)] fn as_raw_Detail_PairwiseSeamFinder(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::stitching::Detail_SeamFinder for Detail_VoronoiSeamFinder {
+    #[inline(always)] fn as_raw_Detail_SeamFinder(&self) -> *mut c_void { self.ptr }
+}
+
+impl Detail_VoronoiSeamFinder {
+    pub fn find(&mut self, src: &types::VectorOfUMat, corners: &types::VectorOfPoint, masks: &mut types::VectorOfUMat) -> Result<()> {
+        unsafe { sys::cv_detail_VoronoiSeamFinder_find_VectorOfUMat_VectorOfPoint_VectorOfUMat(self.as_raw_Detail_VoronoiSeamFinder(), src.as_raw_VectorOfUMat(), corners.as_raw_VectorOfPoint(), masks.as_raw_VectorOfUMat()) }.into_result()
+    }
+    
+    pub fn find_1(&mut self, size: &types::VectorOfSize, corners: &types::VectorOfPoint, masks: &mut types::VectorOfUMat) -> Result<()> {
+        unsafe { sys::cv_detail_VoronoiSeamFinder_find_VectorOfSize_VectorOfPoint_VectorOfUMat(self.as_raw_Detail_VoronoiSeamFinder(), size.as_raw_VectorOfSize(), corners.as_raw_VectorOfPoint(), masks.as_raw_VectorOfUMat()) }.into_result()
+    }
+    
+    pub fn default() -> Result<crate::stitching::Detail_VoronoiSeamFinder> {
+        unsafe { sys::cv_detail_VoronoiSeamFinder_VoronoiSeamFinder() }.into_result().map(|ptr| crate::stitching::Detail_VoronoiSeamFinder { ptr })
+    }
+    
 }
 
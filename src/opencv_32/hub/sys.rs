@@ -3,8 +3,10 @@ use crate::{mod_prelude::*, core};
 mod core_sys {
     use super::*;
 
-    pub type cv_return_value_DMatch = cv_return_value<core::DMatch>;
-    pub type cv_return_value_KeyPoint = cv_return_value<core::KeyPoint>;
+    pub type cv_return_value_DMatchWrapper = cv_return_value<core::DMatch>;
+    pub type cv_return_value_KeyPointWrapper = cv_return_value<core::KeyPoint>;
+    pub type cv_return_value_Matx22dWrapper = cv_return_value<core::Matx22d>;
+    pub type cv_return_value_Matx33dWrapper = cv_return_value<core::Matx33d>;
     pub type cv_return_value_Moments = cv_return_value<core::Moments>;
     pub type cv_return_value_Point2dWrapper = cv_return_value<core::Point2d>;
     pub type cv_return_value_Point2fWrapper = cv_return_value<core::Point2f>;
@@ -17,9 +19,9 @@ mod core_sys {
     pub type cv_return_value_ScalarWrapper = cv_return_value<core::Scalar>;
     pub type cv_return_value_Size2fWrapper = cv_return_value<core::Size2f>;
     pub type cv_return_value_SizeWrapper = cv_return_value<core::Size>;
-    pub type cv_return_value_Stitcher_Status = cv_return_value<crate::stitching::Stitcher_Status>;
     pub type cv_return_value_UMatUsageFlags = cv_return_value<core::UMatUsageFlags>;
     pub type cv_return_value_Vec2dWrapper = cv_return_value<core::Vec2d>;
+    pub type cv_return_value_Vec2iWrapper = cv_return_value<core::Vec2i>;
     pub type cv_return_value_Vec3dWrapper = cv_return_value<core::Vec3d>;
     pub type cv_return_value_Vec4fWrapper = cv_return_value<core::Vec4f>;
     pub type cv_return_value_Vec4iWrapper = cv_return_value<core::Vec4i>;
@@ -38,12 +40,14 @@ mod core_sys {
     pub type cv_return_value_int = cv_return_value<i32>;
     pub type cv_return_value_int64 = cv_return_value<i64>;
     pub type cv_return_value_int_X = cv_return_value<*mut i32>;
+    pub type cv_return_value_short = cv_return_value<i16>;
     pub type cv_return_value_std_ptrdiff_t = cv_return_value<ptrdiff_t>;
     pub type cv_return_value_std_size_t = cv_return_value<size_t>;
     pub type cv_return_value_uint64 = cv_return_value<u64>;
     pub type cv_return_value_unsigned_char = cv_return_value<u8>;
     pub type cv_return_value_unsigned_char_X = cv_return_value<*mut u8>;
     pub type cv_return_value_unsigned_int = cv_return_value<u32>;
+    pub type cv_return_value_unsigned_short = cv_return_value<u16>;
     pub type cv_return_value_void = cv_return_value<crate::types::Unit, ()>;
     pub type cv_return_value_void_X = cv_return_value<*mut c_void>;
     extern "C" {
@@ -177,6 +181,7 @@ mod core_sys {
         pub fn cv_ocl_haveAmdFft() -> cv_return_value_bool;
         pub fn cv_ocl_haveOpenCL() -> cv_return_value_bool;
         pub fn cv_ocl_haveSVM() -> cv_return_value_bool;
+        pub fn cv_ocl_initializeContextFromHandle_Context_void_X_void_X_void_X(ctx: *mut c_void, platform: *mut c_void, context: *mut c_void, device: *mut c_void) -> cv_return_value_void;
         pub fn cv_ocl_kernelToStr__InputArray_int_const_char_X(_kernel: *mut c_void, ddepth: i32, name: *const c_char) -> cv_return_value_char_X;
         pub fn cv_ocl_memopTypeToStr_int(t: i32) -> cv_return_value_const_char_X;
         pub fn cv_ocl_predictOptimalVectorWidthMax__InputArray__InputArray__InputArray__InputArray__InputArray__InputArray__InputArray__InputArray__InputArray(src1: *mut c_void, src2: *mut c_void, src3: *mut c_void, src4: *mut c_void, src5: *mut c_void, src6: *mut c_void, src7: *mut c_void, src8: *mut c_void, src9: *mut c_void) -> cv_return_value_int;
@@ -237,6 +242,7 @@ mod core_sys {
         pub fn cv_useOptimized() -> cv_return_value_bool;
         pub fn cv_va_intel_convertFromVASurface_void_X_unsigned_int_Size__OutputArray(display: *mut c_void, surface: u32, size: core::Size, dst: *mut c_void) -> cv_return_value_void;
         pub fn cv_va_intel_convertToVASurface_void_X__InputArray_unsigned_int_Size(display: *mut c_void, src: *mut c_void, surface: u32, size: core::Size) -> cv_return_value_void;
+        pub fn cv_va_intel_ocl_initializeContextFromVA_void_X_bool(display: *mut c_void, try_interop: bool) -> cv_return_value_void_X;
         pub fn cv_vconcat__InputArray__InputArray__OutputArray(src1: *mut c_void, src2: *mut c_void, dst: *mut c_void) -> cv_return_value_void;
         pub fn cv_vconcat__InputArray__OutputArray(src: *mut c_void, dst: *mut c_void) -> cv_return_value_void;
         pub fn cv_writeScalar_FileStorage_String(fs: *mut c_void, value: *const c_char) -> cv_return_value_void;
@@ -276,9 +282,6 @@ mod core_sys {
         pub fn cv_CommandLineParser_printErrors_const(instance: *const c_void) -> cv_return_value_void;
         pub fn cv_ConjGradSolver_delete(ptr : *mut c_void);
         pub fn cv_ConjGradSolver_create_PtrOfFunction_TermCriteria(f: *mut c_void, termcrit: *mut c_void) -> cv_return_value_void_X;
-        pub fn cv_DMatch_DMatch() -> cv_return_value_DMatch;
-        pub fn cv_DMatch_DMatch_int_int_float(_query_idx: i32, _train_idx: i32, _distance: f32) -> cv_return_value_DMatch;
-        pub fn cv_DMatch_DMatch_int_int_int_float(_query_idx: i32, _train_idx: i32, _img_idx: i32, _distance: f32) -> cv_return_value_DMatch;
         pub fn cv_DownhillSolver_getInitStep_const__OutputArray(instance: *const c_void, step: *mut c_void) -> cv_return_value_void;
         pub fn cv_DownhillSolver_setInitStep__InputArray(instance: *mut c_void, step: *mut c_void) -> cv_return_value_void;
         pub fn cv_DownhillSolver_create_PtrOfFunction__InputArray_TermCriteria(f: *mut c_void, init_step: *mut c_void, termcrit: *mut c_void) -> cv_return_value_void_X;
@@ -305,7 +308,11 @@ mod core_sys {
         pub fn cv_FileNode_real_const(instance: *const c_void) -> cv_return_value_double;
         pub fn cv_FileNode_string_const(instance: *const c_void) -> cv_return_value_char_X;
         pub fn cv_FileNode_mat_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_FileNode_begin_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_FileNode_end_const(instance: *const c_void) -> cv_return_value_void_X;
         pub fn cv_FileNodeIterator_delete(ptr : *mut c_void);
+        pub fn cv_FileNodeIterator_FileNodeIterator() -> cv_return_value_void_X;
+        pub fn cv_FileNodeIterator_FileNodeIterator_FileNodeIterator(it: *mut c_void) -> cv_return_value_void_X;
         pub fn cv_FileNodeIterator_readRaw_String_uchar_X_size_t(instance: *mut c_void, fmt: *const c_char, vec: *mut u8, max_count: size_t) -> cv_return_value_void_X;
         pub fn cv_FileNodeIterator_SeqReader_delete(ptr : *mut c_void);
         pub fn cv_FileStorage_delete(ptr : *mut c_void);
@@ -332,9 +339,6 @@ mod core_sys {
         pub fn cv_Formatter_setMultiline_bool(instance: *mut c_void, ml: bool) -> cv_return_value_void;
         pub fn cv_Formatter_get_int(fmt: i32) -> cv_return_value_void_X;
         pub fn cv_Hamming_delete(ptr : *mut c_void);
-        pub fn cv_KeyPoint_KeyPoint() -> cv_return_value_KeyPoint;
-        pub fn cv_KeyPoint_KeyPoint_Point2f_float_float_float_int_int(_pt: core::Point2f, _size: f32, _angle: f32, _response: f32, _octave: i32, _class_id: i32) -> cv_return_value_KeyPoint;
-        pub fn cv_KeyPoint_KeyPoint_float_float_float_float_float_int_int(x: f32, y: f32, _size: f32, _angle: f32, _response: f32, _octave: i32, _class_id: i32) -> cv_return_value_KeyPoint;
         pub fn cv_KeyPoint_hash_const(instance: core::KeyPoint) -> cv_return_value_std_size_t;
         pub fn cv_KeyPoint_convert_VectorOfKeyPoint_VectorOfPoint2f_VectorOfint(keypoints: *mut c_void, points2f: *mut c_void, keypoint_indexes: *mut c_void) -> cv_return_value_void;
         pub fn cv_KeyPoint_convert_VectorOfPoint2f_VectorOfKeyPoint_float_float_int_int(points2f: *mut c_void, keypoints: *mut c_void, size: f32, response: f32, octave: i32, class_id: i32) -> cv_return_value_void;
@@ -533,6 +537,35 @@ mod core_sys {
         pub fn cv_PCA_write_const_FileStorage(instance: *const c_void, fs: *mut c_void) -> cv_return_value_void;
         pub fn cv_PCA_read_FileNode(instance: *mut c_void, _fn: *mut c_void) -> cv_return_value_void;
         pub fn cv_Param_delete(ptr : *mut c_void);
+        pub fn cv_RNG_delete(ptr : *mut c_void);
+        pub fn cv_RNG_RNG() -> cv_return_value_void_X;
+        pub fn cv_RNG_RNG_uint64(state: u64) -> cv_return_value_void_X;
+        pub fn cv_RNG_next(instance: *mut c_void) -> cv_return_value_unsigned_int;
+        pub fn cv_RNG_operator_uchar(instance: *mut c_void) -> cv_return_value_unsigned_char;
+        pub fn cv_RNG_operator_schar(instance: *mut c_void) -> cv_return_value_char;
+        pub fn cv_RNG_operator_ushort(instance: *mut c_void) -> cv_return_value_unsigned_short;
+        pub fn cv_RNG_operator_short(instance: *mut c_void) -> cv_return_value_short;
+        pub fn cv_RNG_operator_unsigned(instance: *mut c_void) -> cv_return_value_unsigned_int;
+        pub fn cv_RNG_operator_int(instance: *mut c_void) -> cv_return_value_int;
+        pub fn cv_RNG_operator_float(instance: *mut c_void) -> cv_return_value_float;
+        pub fn cv_RNG_operator_double(instance: *mut c_void) -> cv_return_value_double;
+        pub fn cv_RNG_uniform_int_int(instance: *mut c_void, a: i32, b: i32) -> cv_return_value_int;
+        pub fn cv_RNG_uniform_float_float(instance: *mut c_void, a: f32, b: f32) -> cv_return_value_float;
+        pub fn cv_RNG_uniform_double_double(instance: *mut c_void, a: f64, b: f64) -> cv_return_value_double;
+        pub fn cv_RNG_fill__InputOutputArray_int__InputArray__InputArray_bool(instance: *mut c_void, mat: *mut c_void, dist_type: i32, a: *mut c_void, b: *mut c_void, saturate_range: bool) -> cv_return_value_void;
+        pub fn cv_RNG_gaussian_double(instance: *mut c_void, sigma: f64) -> cv_return_value_double;
+        pub fn cv_RNG_MT19937_delete(ptr : *mut c_void);
+        pub fn cv_RNG_MT19937_RNG_MT19937() -> cv_return_value_void_X;
+        pub fn cv_RNG_MT19937_RNG_MT19937_unsigned(s: u32) -> cv_return_value_void_X;
+        pub fn cv_RNG_MT19937_seed_unsigned(instance: *mut c_void, s: u32) -> cv_return_value_void;
+        pub fn cv_RNG_MT19937_next(instance: *mut c_void) -> cv_return_value_unsigned_int;
+        pub fn cv_RNG_MT19937_operator_int(instance: *mut c_void) -> cv_return_value_int;
+        pub fn cv_RNG_MT19937_operator_unsigned(instance: *mut c_void) -> cv_return_value_unsigned_int;
+        pub fn cv_RNG_MT19937_operator_float(instance: *mut c_void) -> cv_return_value_float;
+        pub fn cv_RNG_MT19937_operator_double(instance: *mut c_void) -> cv_return_value_double;
+        pub fn cv_RNG_MT19937_uniform_int_int(instance: *mut c_void, a: i32, b: i32) -> cv_return_value_int;
+        pub fn cv_RNG_MT19937_uniform_float_float(instance: *mut c_void, a: f32, b: f32) -> cv_return_value_float;
+        pub fn cv_RNG_MT19937_uniform_double_double(instance: *mut c_void, a: f64, b: f64) -> cv_return_value_double;
         pub fn cv_Range_delete(ptr : *mut c_void);
         pub fn cv_Range_start_const(instance: *const c_void) -> cv_return_value_int;
         pub fn cv_Range_set_start_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
@@ -603,7 +636,14 @@ mod core_sys {
         pub fn cv_SparseMatConstIterator_node_const(instance: *const c_void) -> cv_return_value_const_void_X;
         pub fn cv_SparseMatConstIterator_seekEnd(instance: *mut c_void) -> cv_return_value_void;
         pub fn cv_SparseMatConstIterator_delete(ptr : *mut c_void);
+        pub fn cv_SparseMatConstIterator_SparseMatConstIterator() -> cv_return_value_void_X;
+        pub fn cv_SparseMatConstIterator_SparseMatConstIterator_const_SparseMat(_m: *mut c_void) -> cv_return_value_void_X;
+        pub fn cv_SparseMatConstIterator_SparseMatConstIterator_SparseMatConstIterator(it: *mut c_void) -> cv_return_value_void_X;
         pub fn cv_SparseMatIterator_delete(ptr : *mut c_void);
+        pub fn cv_SparseMatIterator_SparseMatIterator() -> cv_return_value_void_X;
+        pub fn cv_SparseMatIterator_SparseMatIterator_SparseMat(_m: *mut c_void) -> cv_return_value_void_X;
+        pub fn cv_SparseMatIterator_SparseMatIterator_SparseMat_const_int_X(_m: *mut c_void, idx: *const i32) -> cv_return_value_void_X;
+        pub fn cv_SparseMatIterator_SparseMatIterator_SparseMatIterator(it: *mut c_void) -> cv_return_value_void_X;
         pub fn cv_SparseMatIterator_node_const(instance: *const c_void) -> cv_return_value_void_X;
         pub fn cv_TermCriteria_delete(ptr : *mut c_void);
         pub fn cv_TermCriteria_type_const(instance: *const c_void) -> cv_return_value_int;
@@ -803,6 +843,7 @@ mod core_sys {
         pub fn cv_ocl_Context_create_int(instance: *mut c_void, dtype: i32) -> cv_return_value_bool;
         pub fn cv_ocl_Context_ndevices_const(instance: *const c_void) -> cv_return_value_std_size_t;
         pub fn cv_ocl_Context_device_const_size_t(instance: *const c_void, idx: size_t) -> cv_return_value_void_X;
+        pub fn cv_ocl_Context_getDefault_bool(initialize: bool) -> cv_return_value_void_X;
         pub fn cv_ocl_Context_ptr_const(instance: *const c_void) -> cv_return_value_void_X;
         pub fn cv_ocl_Context_useSVM_const(instance: *const c_void) -> cv_return_value_bool;
         pub fn cv_ocl_Context_setUseSVM_bool(instance: *mut c_void, enabled: bool) -> cv_return_value_void;
@@ -934,6 +975,9 @@ mod core_sys {
         pub fn cv_ocl_PlatformInfo_deviceNumber_const(instance: *const c_void) -> cv_return_value_int;
         pub fn cv_ocl_PlatformInfo_getDevice_const_Device_int(instance: *const c_void, device: *mut c_void, d: i32) -> cv_return_value_void;
         pub fn cv_Program_delete(ptr : *mut c_void);
+        pub fn cv_ocl_Program_Program() -> cv_return_value_void_X;
+        pub fn cv_ocl_Program_Program_String(buf: *const c_char) -> cv_return_value_void_X;
+        pub fn cv_ocl_Program_Program_Program(prog: *mut c_void) -> cv_return_value_void_X;
         pub fn cv_ocl_Program_read_String_String(instance: *mut c_void, buf: *const c_char, buildflags: *const c_char) -> cv_return_value_bool;
         pub fn cv_ocl_Program_write_const_String(instance: *const c_void, buf: *mut *mut c_char) -> cv_return_value_bool;
         pub fn cv_ocl_Program_ptr_const(instance: *const c_void) -> cv_return_value_void_X;
@@ -943,6 +987,7 @@ mod core_sys {
         pub fn cv_ProgramSource_delete(ptr : *mut c_void);
         pub fn cv_ocl_ProgramSource_ProgramSource() -> cv_return_value_void_X;
         pub fn cv_ocl_ProgramSource_ProgramSource_String(prog: *const c_char) -> cv_return_value_void_X;
+        pub fn cv_ocl_ProgramSource_ProgramSource_ProgramSource(prog: *mut c_void) -> cv_return_value_void_X;
         pub fn cv_ocl_ProgramSource_source_const(instance: *const c_void) -> cv_return_value_const_char_X;
         pub fn cv_ocl_ProgramSource_hash_const(instance: *const c_void) -> cv_return_value_uint64;
         pub fn cv_Queue_delete(ptr : *mut c_void);
@@ -1202,6 +1247,7 @@ mod calib3d_sys {
         pub fn cv_fisheye_distortPoints__InputArray__OutputArray__InputArray__InputArray_double(undistorted: *mut c_void, distorted: *mut c_void, k: *mut c_void, d: *mut c_void, alpha: f64) -> cv_return_value_void;
         pub fn cv_fisheye_estimateNewCameraMatrixForUndistortRectify__InputArray__InputArray_Size__InputArray__OutputArray_double_Size_double(k: *mut c_void, d: *mut c_void, image_size: core::Size, r: *mut c_void, p: *mut c_void, balance: f64, new_size: core::Size, fov_scale: f64) -> cv_return_value_void;
         pub fn cv_fisheye_initUndistortRectifyMap__InputArray__InputArray__InputArray__InputArray_Size_int__OutputArray__OutputArray(k: *mut c_void, d: *mut c_void, r: *mut c_void, p: *mut c_void, size: core::Size, m1type: i32, map1: *mut c_void, map2: *mut c_void) -> cv_return_value_void;
+        pub fn cv_fisheye_projectPoints__InputArray__OutputArray_Affine3d__InputArray__InputArray_double__OutputArray(object_points: *mut c_void, image_points: *mut c_void, affine: core::Affine3d, k: *mut c_void, d: *mut c_void, alpha: f64, jacobian: *mut c_void) -> cv_return_value_void;
         pub fn cv_fisheye_projectPoints__InputArray__OutputArray__InputArray__InputArray__InputArray__InputArray_double__OutputArray(object_points: *mut c_void, image_points: *mut c_void, rvec: *mut c_void, tvec: *mut c_void, k: *mut c_void, d: *mut c_void, alpha: f64, jacobian: *mut c_void) -> cv_return_value_void;
         pub fn cv_fisheye_stereoCalibrate__InputArray__InputArray__InputArray__InputOutputArray__InputOutputArray__InputOutputArray__InputOutputArray_Size__OutputArray__OutputArray_int_TermCriteria(object_points: *mut c_void, image_points1: *mut c_void, image_points2: *mut c_void, k1: *mut c_void, d1: *mut c_void, k2: *mut c_void, d2: *mut c_void, image_size: core::Size, r: *mut c_void, t: *mut c_void, flags: i32, criteria: *mut c_void) -> cv_return_value_double;
         pub fn cv_fisheye_stereoRectify__InputArray__InputArray__InputArray__InputArray_Size__InputArray__InputArray__OutputArray__OutputArray__OutputArray__OutputArray__OutputArray_int_Size_double_double(k1: *mut c_void, d1: *mut c_void, k2: *mut c_void, d2: *mut c_void, image_size: core::Size, r: *mut c_void, tvec: *mut c_void, r1: *mut c_void, r2: *mut c_void, p1: *mut c_void, p2: *mut c_void, q: *mut c_void, flags: i32, new_image_size: core::Size, balance: f64, fov_scale: f64) -> cv_return_value_void;
@@ -1375,6 +1421,130 @@ mod cvv_sys {
 #[cfg(feature = "contrib")]
 pub use cvv_sys::*;
 
+#[cfg(feature = "contrib")]
+mod datasets_sys {
+    use super::*;
+
+    extern "C" {
+        pub fn cv_datasets_createDirectory_std_string(path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_getDirList_std_string_VectorOfstd_string(dir_name: *const c_char, file_names: *mut c_void) -> cv_return_value_void;
+        pub fn cv_datasets_split_std_string_VectorOfstd_string_char(s: *const c_char, elems: *mut c_void, delim: i8) -> cv_return_value_void;
+        pub fn cv_datasets_AR_hmdb_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_AR_hmdb_create() -> cv_return_value_void_X;
+        pub fn cv_AR_hmdbObj_delete(ptr : *mut c_void);
+        pub fn cv_datasets_AR_sports_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_AR_sports_create() -> cv_return_value_void_X;
+        pub fn cv_AR_sportsObj_delete(ptr : *mut c_void);
+        pub fn cv_datasets_Dataset_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_Dataset_getTrain_int(instance: *mut c_void, split_num: i32) -> cv_return_value_void_X;
+        pub fn cv_datasets_Dataset_getTest_int(instance: *mut c_void, split_num: i32) -> cv_return_value_void_X;
+        pub fn cv_datasets_Dataset_getValidation_int(instance: *mut c_void, split_num: i32) -> cv_return_value_void_X;
+        pub fn cv_datasets_Dataset_getNumSplits_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_datasets_FR_adience_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_FR_adience_create() -> cv_return_value_void_X;
+        pub fn cv_FR_adienceObj_delete(ptr : *mut c_void);
+        pub fn cv_datasets_FR_lfw_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_FR_lfw_create() -> cv_return_value_void_X;
+        pub fn cv_FR_lfwObj_delete(ptr : *mut c_void);
+        pub fn cv_datasets_GR_chalearn_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_GR_chalearn_create() -> cv_return_value_void_X;
+        pub fn cv_GR_chalearnObj_delete(ptr : *mut c_void);
+        pub fn cv_datasets_GR_skig_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_GR_skig_create() -> cv_return_value_void_X;
+        pub fn cv_GR_skigObj_delete(ptr : *mut c_void);
+        pub fn cv_datasets_HPE_humaneva_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_HPE_humaneva_create_int(num: i32) -> cv_return_value_void_X;
+        pub fn cv_HPE_humanevaObj_delete(ptr : *mut c_void);
+        pub fn cv_datasets_HPE_parse_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_HPE_parse_create() -> cv_return_value_void_X;
+        pub fn cv_HPE_parseObj_delete(ptr : *mut c_void);
+        pub fn cv_datasets_IR_affine_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_IR_affine_create() -> cv_return_value_void_X;
+        pub fn cv_IR_affineObj_delete(ptr : *mut c_void);
+        pub fn cv_datasets_IR_robot_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_IR_robot_create() -> cv_return_value_void_X;
+        pub fn cv_IR_robotObj_delete(ptr : *mut c_void);
+        pub fn cv_datasets_IS_bsds_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_IS_bsds_create() -> cv_return_value_void_X;
+        pub fn cv_IS_bsdsObj_delete(ptr : *mut c_void);
+        pub fn cv_datasets_IS_weizmann_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_IS_weizmann_create() -> cv_return_value_void_X;
+        pub fn cv_IS_weizmannObj_delete(ptr : *mut c_void);
+        pub fn cv_datasets_MSM_epfl_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_MSM_epfl_create() -> cv_return_value_void_X;
+        pub fn cv_MSM_epflObj_delete(ptr : *mut c_void);
+        pub fn cv_datasets_MSM_middlebury_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_MSM_middlebury_create() -> cv_return_value_void_X;
+        pub fn cv_MSM_middleburyObj_delete(ptr : *mut c_void);
+        pub fn cv_datasets_OR_imagenet_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_OR_imagenet_create() -> cv_return_value_void_X;
+        pub fn cv_OR_imagenetObj_delete(ptr : *mut c_void);
+        pub fn cv_datasets_OR_mnist_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_OR_mnist_create() -> cv_return_value_void_X;
+        pub fn cv_OR_mnistObj_delete(ptr : *mut c_void);
+        pub fn cv_datasets_OR_pascal_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_OR_pascal_create() -> cv_return_value_void_X;
+        pub fn cv_OR_pascalObj_delete(ptr : *mut c_void);
+        pub fn cv_datasets_OR_sun_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_OR_sun_create() -> cv_return_value_void_X;
+        pub fn cv_OR_sunObj_delete(ptr : *mut c_void);
+        pub fn cv_Object_delete(ptr : *mut c_void);
+        pub fn cv_datasets_PD_caltech_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_PD_caltech_create() -> cv_return_value_void_X;
+        pub fn cv_PD_caltechObj_delete(ptr : *mut c_void);
+        pub fn cv_datasets_PD_inria_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_PD_inria_create() -> cv_return_value_void_X;
+        pub fn cv_PD_inriaObj_delete(ptr : *mut c_void);
+        pub fn cv_PascalObj_delete(ptr : *mut c_void);
+        pub fn cv_PascalPart_delete(ptr : *mut c_void);
+        pub fn cv_datasets_SLAM_kitti_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_SLAM_kitti_create() -> cv_return_value_void_X;
+        pub fn cv_SLAM_kittiObj_delete(ptr : *mut c_void);
+        pub fn cv_datasets_SLAM_tumindoor_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_SLAM_tumindoor_create() -> cv_return_value_void_X;
+        pub fn cv_SLAM_tumindoorObj_delete(ptr : *mut c_void);
+        pub fn cv_datasets_TRACK_alov_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_TRACK_alov_loadAnnotatedOnly_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_TRACK_alov_getDatasetsNum(instance: *mut c_void) -> cv_return_value_int;
+        pub fn cv_datasets_TRACK_alov_getDatasetLength_int(instance: *mut c_void, id: i32) -> cv_return_value_int;
+        pub fn cv_datasets_TRACK_alov_initDataset_int(instance: *mut c_void, id: i32) -> cv_return_value_bool;
+        pub fn cv_datasets_TRACK_alov_getNextFrame_Mat(instance: *mut c_void, frame: *mut c_void) -> cv_return_value_bool;
+        pub fn cv_datasets_TRACK_alov_getNextGT(instance: *mut c_void) -> cv_return_value_void_X;
+        pub fn cv_datasets_TRACK_alov_getFrame_Mat_int_int(instance: *mut c_void, frame: *mut c_void, dataset_id: i32, frame_id: i32) -> cv_return_value_bool;
+        pub fn cv_datasets_TRACK_alov_getGT_int_int(instance: *mut c_void, dataset_id: i32, frame_id: i32) -> cv_return_value_void_X;
+        pub fn cv_datasets_TRACK_alov_create() -> cv_return_value_void_X;
+        pub fn cv_TRACK_alovObj_delete(ptr : *mut c_void);
+        pub fn cv_datasets_TRACK_vot_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_TRACK_vot_getDatasetsNum(instance: *mut c_void) -> cv_return_value_int;
+        pub fn cv_datasets_TRACK_vot_getDatasetLength_int(instance: *mut c_void, id: i32) -> cv_return_value_int;
+        pub fn cv_datasets_TRACK_vot_initDataset_int(instance: *mut c_void, id: i32) -> cv_return_value_bool;
+        pub fn cv_datasets_TRACK_vot_getNextFrame_Mat(instance: *mut c_void, frame: *mut c_void) -> cv_return_value_bool;
+        pub fn cv_datasets_TRACK_vot_getGT(instance: *mut c_void) -> cv_return_value_void_X;
+        pub fn cv_datasets_TRACK_vot_create() -> cv_return_value_void_X;
+        pub fn cv_TRACK_votObj_delete(ptr : *mut c_void);
+        pub fn cv_datasets_TR_chars_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_TR_chars_create() -> cv_return_value_void_X;
+        pub fn cv_TR_charsObj_delete(ptr : *mut c_void);
+        pub fn cv_datasets_TR_icdar_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_TR_icdar_create() -> cv_return_value_void_X;
+        pub fn cv_TR_icdarObj_delete(ptr : *mut c_void);
+        pub fn cv_datasets_TR_svt_load_std_string(instance: *mut c_void, path: *const c_char) -> cv_return_value_void;
+        pub fn cv_datasets_TR_svt_create() -> cv_return_value_void_X;
+        pub fn cv_TR_svtObj_delete(ptr : *mut c_void);
+        pub fn cv_cameraParam_delete(ptr : *mut c_void);
+        pub fn cv_cameraPos_delete(ptr : *mut c_void);
+        pub fn cv_groundTruth_delete(ptr : *mut c_void);
+        pub fn cv_join_delete(ptr : *mut c_void);
+        pub fn cv_pose_delete(ptr : *mut c_void);
+        pub fn cv_skeleton_delete(ptr : *mut c_void);
+        pub fn cv_tag_delete(ptr : *mut c_void);
+        pub fn cv_word_delete(ptr : *mut c_void);
+    
+    }
+}
+#[cfg(feature = "contrib")]
+pub use datasets_sys::*;
+
 #[cfg(feature = "contrib")]
 mod dnn_sys {
     use super::*;
@@ -2556,6 +2726,7 @@ mod objdetect_sys {
         pub fn cv_CascadeClassifier_setMaskGenerator_PtrOfMaskGenerator(instance: *mut c_void, mask_generator: *mut c_void) -> cv_return_value_void;
         pub fn cv_CascadeClassifier_getMaskGenerator(instance: *mut c_void) -> cv_return_value_void_X;
         pub fn cv_DetectionBasedTracker_delete(ptr : *mut c_void);
+        pub fn cv_DetectionBasedTracker_DetectionBasedTracker_PtrOfIDetector_PtrOfIDetector_Parameters(main_detector: *mut c_void, tracking_detector: *mut c_void, params: *mut c_void) -> cv_return_value_void_X;
         pub fn cv_DetectionBasedTracker_run(instance: *mut c_void) -> cv_return_value_bool;
         pub fn cv_DetectionBasedTracker_stop(instance: *mut c_void) -> cv_return_value_void;
         pub fn cv_DetectionBasedTracker_resetTracking(instance: *mut c_void) -> cv_return_value_void;
@@ -2638,6 +2809,103 @@ mod objdetect_sys {
 }
 pub use objdetect_sys::*;
 
+#[cfg(feature = "contrib")]
+mod optflow_sys {
+    use super::*;
+
+    extern "C" {
+        pub fn cv_motempl_calcGlobalOrientation__InputArray__InputArray__InputArray_double_double(orientation: *mut c_void, mask: *mut c_void, mhi: *mut c_void, timestamp: f64, duration: f64) -> cv_return_value_double;
+        pub fn cv_motempl_calcMotionGradient__InputArray__OutputArray__OutputArray_double_double_int(mhi: *mut c_void, mask: *mut c_void, orientation: *mut c_void, delta1: f64, delta2: f64, aperture_size: i32) -> cv_return_value_void;
+        pub fn cv_motempl_segmentMotion__InputArray__OutputArray_VectorOfRect_double_double(mhi: *mut c_void, segmask: *mut c_void, bounding_rects: *mut c_void, timestamp: f64, seg_thresh: f64) -> cv_return_value_void;
+        pub fn cv_motempl_updateMotionHistory__InputArray__InputOutputArray_double_double(silhouette: *mut c_void, mhi: *mut c_void, timestamp: f64, duration: f64) -> cv_return_value_void;
+        pub fn cv_optflow_calcOpticalFlowSF__InputArray__InputArray__OutputArray_int_int_int(from: *mut c_void, to: *mut c_void, flow: *mut c_void, layers: i32, averaging_block_size: i32, max_flow: i32) -> cv_return_value_void;
+        pub fn cv_optflow_calcOpticalFlowSF__InputArray__InputArray__OutputArray_int_int_int_double_double_int_double_double_double_int_double_double_double(from: *mut c_void, to: *mut c_void, flow: *mut c_void, layers: i32, averaging_block_size: i32, max_flow: i32, sigma_dist: f64, sigma_color: f64, postprocess_window: i32, sigma_dist_fix: f64, sigma_color_fix: f64, occ_thr: f64, upscale_averaging_radius: i32, upscale_sigma_dist: f64, upscale_sigma_color: f64, speed_up_thr: f64) -> cv_return_value_void;
+        pub fn cv_optflow_calcOpticalFlowSparseToDense__InputArray__InputArray__OutputArray_int_int_float_bool_float_float(from: *mut c_void, to: *mut c_void, flow: *mut c_void, grid_step: i32, k: i32, sigma: f32, use_post_proc: bool, fgs_lambda: f32, fgs_sigma: f32) -> cv_return_value_void;
+        pub fn cv_optflow_createOptFlow_DIS_int(preset: i32) -> cv_return_value_void_X;
+        pub fn cv_optflow_createOptFlow_DeepFlow() -> cv_return_value_void_X;
+        pub fn cv_optflow_createOptFlow_Farneback() -> cv_return_value_void_X;
+        pub fn cv_optflow_createOptFlow_PCAFlow() -> cv_return_value_void_X;
+        pub fn cv_optflow_createOptFlow_SimpleFlow() -> cv_return_value_void_X;
+        pub fn cv_optflow_createOptFlow_SparseToDense() -> cv_return_value_void_X;
+        pub fn cv_optflow_createVariationalFlowRefinement() -> cv_return_value_void_X;
+        pub fn cv_optflow_readOpticalFlow_String(path: *const c_char) -> cv_return_value_void_X;
+        pub fn cv_optflow_writeOpticalFlow_String__InputArray(path: *const c_char, flow: *mut c_void) -> cv_return_value_bool;
+        pub fn cv_write_FileStorage_String_Node(fs: *mut c_void, name: *const c_char, node: *mut c_void) -> cv_return_value_void;
+        pub fn cv_optflow_DISOpticalFlow_getFinestScale_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_optflow_DISOpticalFlow_setFinestScale_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_optflow_DISOpticalFlow_getPatchSize_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_optflow_DISOpticalFlow_setPatchSize_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_optflow_DISOpticalFlow_getPatchStride_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_optflow_DISOpticalFlow_setPatchStride_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_optflow_DISOpticalFlow_getGradientDescentIterations_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_optflow_DISOpticalFlow_setGradientDescentIterations_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_optflow_DISOpticalFlow_getVariationalRefinementIterations_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_optflow_DISOpticalFlow_setVariationalRefinementIterations_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_optflow_DISOpticalFlow_getVariationalRefinementAlpha_const(instance: *const c_void) -> cv_return_value_float;
+        pub fn cv_optflow_DISOpticalFlow_setVariationalRefinementAlpha_float(instance: *mut c_void, val: f32) -> cv_return_value_void;
+        pub fn cv_optflow_DISOpticalFlow_getVariationalRefinementDelta_const(instance: *const c_void) -> cv_return_value_float;
+        pub fn cv_optflow_DISOpticalFlow_setVariationalRefinementDelta_float(instance: *mut c_void, val: f32) -> cv_return_value_void;
+        pub fn cv_optflow_DISOpticalFlow_getVariationalRefinementGamma_const(instance: *const c_void) -> cv_return_value_float;
+        pub fn cv_optflow_DISOpticalFlow_setVariationalRefinementGamma_float(instance: *mut c_void, val: f32) -> cv_return_value_void;
+        pub fn cv_optflow_DISOpticalFlow_getUseMeanNormalization_const(instance: *const c_void) -> cv_return_value_bool;
+        pub fn cv_optflow_DISOpticalFlow_setUseMeanNormalization_bool(instance: *mut c_void, val: bool) -> cv_return_value_void;
+        pub fn cv_optflow_DISOpticalFlow_getUseSpatialPropagation_const(instance: *const c_void) -> cv_return_value_bool;
+        pub fn cv_optflow_DISOpticalFlow_setUseSpatialPropagation_bool(instance: *mut c_void, val: bool) -> cv_return_value_void;
+        pub fn cv_GPCDetails_delete(ptr : *mut c_void);
+        pub fn cv_optflow_GPCDetails_getAllDescriptorsForImage_const_Mat_VectorOfGPCPatchDescriptor_GPCMatchingParams_int(img_ch: *mut c_void, descr: *mut c_void, mp: *mut c_void, _type: i32) -> cv_return_value_void;
+        pub fn cv_optflow_GPCDetails_getCoordinatesFromIndex_size_t_Size_int_int(index: size_t, sz: core::Size, x: *mut i32, y: *mut i32) -> cv_return_value_void;
+        pub fn cv_GPCMatchingParams_delete(ptr : *mut c_void);
+        pub fn cv_optflow_GPCMatchingParams_GPCMatchingParams_bool(_use_opencl: bool) -> cv_return_value_void_X;
+        pub fn cv_optflow_GPCMatchingParams_GPCMatchingParams_GPCMatchingParams(params: *mut c_void) -> cv_return_value_void_X;
+        pub fn cv_GPCPatchDescriptor_delete(ptr : *mut c_void);
+        pub fn cv_optflow_GPCPatchDescriptor_markAsSeparated(instance: *mut c_void) -> cv_return_value_void;
+        pub fn cv_optflow_GPCPatchDescriptor_isSeparated_const(instance: *const c_void) -> cv_return_value_bool;
+        pub fn cv_GPCPatchSample_delete(ptr : *mut c_void);
+        pub fn cv_GPCTrainingParams_delete(ptr : *mut c_void);
+        pub fn cv_optflow_GPCTrainingParams_GPCTrainingParams_unsigned_int_GPCDescType_bool(_max_tree_depth: u32, _min_number_of_samples: i32, _descriptor_type: crate::optflow::GPCDescType, _print_progress: bool) -> cv_return_value_void_X;
+        pub fn cv_optflow_GPCTrainingParams_GPCTrainingParams_GPCTrainingParams(params: *mut c_void) -> cv_return_value_void_X;
+        pub fn cv_optflow_GPCTrainingParams_check_const(instance: *const c_void) -> cv_return_value_bool;
+        pub fn cv_GPCTrainingSamples_delete(ptr : *mut c_void);
+        pub fn cv_optflow_GPCTrainingSamples_create_VectorOfString_VectorOfString_VectorOfString_int(images_from: *mut c_void, images_to: *mut c_void, gt: *mut c_void, descriptor_type: i32) -> cv_return_value_void_X;
+        pub fn cv_optflow_GPCTrainingSamples_create__InputArray__InputArray__InputArray_int(images_from: *mut c_void, images_to: *mut c_void, gt: *mut c_void, descriptor_type: i32) -> cv_return_value_void_X;
+        pub fn cv_optflow_GPCTrainingSamples_size_const(instance: *const c_void) -> cv_return_value_std_size_t;
+        pub fn cv_optflow_GPCTrainingSamples_type_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_GPCTree_delete(ptr : *mut c_void);
+        pub fn cv_optflow_GPCTree_train_GPCTrainingSamples_GPCTrainingParams(instance: *mut c_void, samples: *mut c_void, params: *mut c_void) -> cv_return_value_void;
+        pub fn cv_optflow_GPCTree_write_const_FileStorage(instance: *const c_void, fs: *mut c_void) -> cv_return_value_void;
+        pub fn cv_optflow_GPCTree_read_FileNode(instance: *mut c_void, _fn: *mut c_void) -> cv_return_value_void;
+        pub fn cv_optflow_GPCTree_findLeafForPatch_const_GPCPatchDescriptor(instance: *const c_void, descr: *mut c_void) -> cv_return_value_unsigned_int;
+        pub fn cv_optflow_GPCTree_create() -> cv_return_value_void_X;
+        pub fn cv_optflow_GPCTree_getDescriptorType_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_GPCTree_Node_delete(ptr : *mut c_void);
+        pub fn cv_OpticalFlowPCAFlow_delete(ptr : *mut c_void);
+        pub fn cv_optflow_OpticalFlowPCAFlow_OpticalFlowPCAFlow_PtrOfPCAPrior_Size_float_float_float_float_float(_prior: *mut c_void, _basis_size: core::Size, _sparse_rate: f32, _retained_corners_fraction: f32, _occlusions_threshold: f32, _damping_factor: f32, _clahe_clip: f32) -> cv_return_value_void_X;
+        pub fn cv_optflow_OpticalFlowPCAFlow_calc__InputArray__InputArray__InputOutputArray(instance: *mut c_void, i0: *mut c_void, i1: *mut c_void, flow: *mut c_void) -> cv_return_value_void;
+        pub fn cv_optflow_OpticalFlowPCAFlow_collectGarbage(instance: *mut c_void) -> cv_return_value_void;
+        pub fn cv_PCAPrior_delete(ptr : *mut c_void);
+        pub fn cv_optflow_PCAPrior_PCAPrior_const_char_X(path_to_prior: *const c_char) -> cv_return_value_void_X;
+        pub fn cv_optflow_PCAPrior_getPadding_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_optflow_PCAPrior_getBasisSize_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_optflow_PCAPrior_fillConstraints_const_float_X_float_X_float_X_float_X(instance: *const c_void, a1: *mut f32, a2: *mut f32, b1: *mut f32, b2: *mut f32) -> cv_return_value_void;
+        pub fn cv_optflow_VariationalRefinement_calcUV__InputArray__InputArray__InputOutputArray__InputOutputArray(instance: *mut c_void, i0: *mut c_void, i1: *mut c_void, flow_u: *mut c_void, flow_v: *mut c_void) -> cv_return_value_void;
+        pub fn cv_optflow_VariationalRefinement_getFixedPointIterations_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_optflow_VariationalRefinement_setFixedPointIterations_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_optflow_VariationalRefinement_getSorIterations_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_optflow_VariationalRefinement_setSorIterations_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_optflow_VariationalRefinement_getOmega_const(instance: *const c_void) -> cv_return_value_float;
+        pub fn cv_optflow_VariationalRefinement_setOmega_float(instance: *mut c_void, val: f32) -> cv_return_value_void;
+        pub fn cv_optflow_VariationalRefinement_getAlpha_const(instance: *const c_void) -> cv_return_value_float;
+        pub fn cv_optflow_VariationalRefinement_setAlpha_float(instance: *mut c_void, val: f32) -> cv_return_value_void;
+        pub fn cv_optflow_VariationalRefinement_getDelta_const(instance: *const c_void) -> cv_return_value_float;
+        pub fn cv_optflow_VariationalRefinement_setDelta_float(instance: *mut c_void, val: f32) -> cv_return_value_void;
+        pub fn cv_optflow_VariationalRefinement_getGamma_const(instance: *const c_void) -> cv_return_value_float;
+        pub fn cv_optflow_VariationalRefinement_setGamma_float(instance: *mut c_void, val: f32) -> cv_return_value_void;
+    
+    }
+}
+#[cfg(feature = "contrib")]
+pub use optflow_sys::*;
+
 #[cfg(feature = "contrib")]
 mod phase_unwrapping_sys {
     use super::*;
@@ -2779,6 +3047,343 @@ mod plot_sys {
 #[cfg(feature = "contrib")]
 pub use plot_sys::*;
 
+#[cfg(feature = "contrib")]
+mod reg_sys {
+    use super::*;
+
+    extern "C" {
+        pub fn cv_reg_Map_warp_const_Mat_Mat(instance: *const c_void, img1: *mut c_void, img2: *mut c_void) -> cv_return_value_void;
+        pub fn cv_reg_Map_inverseWarp_const_Mat_Mat(instance: *const c_void, img1: *mut c_void, img2: *mut c_void) -> cv_return_value_void;
+        pub fn cv_reg_Map_inverseMap_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_reg_Map_compose_Map(instance: *mut c_void, map: *mut c_void) -> cv_return_value_void;
+        pub fn cv_reg_Map_scale_double(instance: *mut c_void, factor: f64) -> cv_return_value_void;
+        pub fn cv_MapAffine_delete(ptr : *mut c_void);
+        pub fn cv_reg_MapAffine_MapAffine() -> cv_return_value_void_X;
+        pub fn cv_reg_MapAffine_MapAffine_Matx22d_Vec2d(lin_tr: core::Matx22d, shift: core::Vec2d) -> cv_return_value_void_X;
+        pub fn cv_reg_MapAffine_inverseWarp_const_Mat_Mat(instance: *const c_void, img1: *mut c_void, img2: *mut c_void) -> cv_return_value_void;
+        pub fn cv_reg_MapAffine_inverseMap_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_reg_MapAffine_compose_Map(instance: *mut c_void, map: *mut c_void) -> cv_return_value_void;
+        pub fn cv_reg_MapAffine_scale_double(instance: *mut c_void, factor: f64) -> cv_return_value_void;
+        pub fn cv_reg_MapAffine_getLinTr_const(instance: *const c_void) -> cv_return_value_Matx22dWrapper;
+        pub fn cv_reg_MapAffine_getShift_const(instance: *const c_void) -> cv_return_value_Vec2dWrapper;
+        pub fn cv_MapProjec_delete(ptr : *mut c_void);
+        pub fn cv_reg_MapProjec_MapProjec() -> cv_return_value_void_X;
+        pub fn cv_reg_MapProjec_MapProjec_Matx33d(proj_tr: core::Matx33d) -> cv_return_value_void_X;
+        pub fn cv_reg_MapProjec_inverseWarp_const_Mat_Mat(instance: *const c_void, img1: *mut c_void, img2: *mut c_void) -> cv_return_value_void;
+        pub fn cv_reg_MapProjec_inverseMap_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_reg_MapProjec_compose_Map(instance: *mut c_void, map: *mut c_void) -> cv_return_value_void;
+        pub fn cv_reg_MapProjec_scale_double(instance: *mut c_void, factor: f64) -> cv_return_value_void;
+        pub fn cv_reg_MapProjec_getProjTr_const(instance: *const c_void) -> cv_return_value_Matx33dWrapper;
+        pub fn cv_reg_MapProjec_normalize(instance: *mut c_void) -> cv_return_value_void;
+        pub fn cv_MapShift_delete(ptr : *mut c_void);
+        pub fn cv_reg_MapShift_MapShift() -> cv_return_value_void_X;
+        pub fn cv_reg_MapShift_MapShift_Vec2d(shift: core::Vec2d) -> cv_return_value_void_X;
+        pub fn cv_reg_MapShift_inverseWarp_const_Mat_Mat(instance: *const c_void, img1: *mut c_void, img2: *mut c_void) -> cv_return_value_void;
+        pub fn cv_reg_MapShift_inverseMap_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_reg_MapShift_compose_Map(instance: *mut c_void, map: *mut c_void) -> cv_return_value_void;
+        pub fn cv_reg_MapShift_scale_double(instance: *mut c_void, factor: f64) -> cv_return_value_void;
+        pub fn cv_reg_MapShift_getShift_const(instance: *const c_void) -> cv_return_value_Vec2dWrapper;
+        pub fn cv_reg_Mapper_calculate_const_Mat_Mat_PtrOfMap(instance: *const c_void, img1: *mut c_void, img2: *mut c_void, res: *mut c_void) -> cv_return_value_void;
+        pub fn cv_reg_Mapper_getMap_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_MapperGradAffine_delete(ptr : *mut c_void);
+        pub fn cv_reg_MapperGradAffine_MapperGradAffine() -> cv_return_value_void_X;
+        pub fn cv_reg_MapperGradAffine_calculate_const_Mat_Mat_PtrOfMap(instance: *const c_void, img1: *mut c_void, img2: *mut c_void, res: *mut c_void) -> cv_return_value_void;
+        pub fn cv_reg_MapperGradAffine_getMap_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_MapperGradEuclid_delete(ptr : *mut c_void);
+        pub fn cv_reg_MapperGradEuclid_MapperGradEuclid() -> cv_return_value_void_X;
+        pub fn cv_reg_MapperGradEuclid_calculate_const_Mat_Mat_PtrOfMap(instance: *const c_void, img1: *mut c_void, img2: *mut c_void, res: *mut c_void) -> cv_return_value_void;
+        pub fn cv_reg_MapperGradEuclid_getMap_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_MapperGradProj_delete(ptr : *mut c_void);
+        pub fn cv_reg_MapperGradProj_MapperGradProj() -> cv_return_value_void_X;
+        pub fn cv_reg_MapperGradProj_calculate_const_Mat_Mat_PtrOfMap(instance: *const c_void, img1: *mut c_void, img2: *mut c_void, res: *mut c_void) -> cv_return_value_void;
+        pub fn cv_reg_MapperGradProj_getMap_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_MapperGradShift_delete(ptr : *mut c_void);
+        pub fn cv_reg_MapperGradShift_MapperGradShift() -> cv_return_value_void_X;
+        pub fn cv_reg_MapperGradShift_calculate_const_Mat_Mat_PtrOfMap(instance: *const c_void, img1: *mut c_void, img2: *mut c_void, res: *mut c_void) -> cv_return_value_void;
+        pub fn cv_reg_MapperGradShift_getMap_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_MapperGradSimilar_delete(ptr : *mut c_void);
+        pub fn cv_reg_MapperGradSimilar_MapperGradSimilar() -> cv_return_value_void_X;
+        pub fn cv_reg_MapperGradSimilar_calculate_const_Mat_Mat_PtrOfMap(instance: *const c_void, img1: *mut c_void, img2: *mut c_void, res: *mut c_void) -> cv_return_value_void;
+        pub fn cv_reg_MapperGradSimilar_getMap_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_MapperPyramid_delete(ptr : *mut c_void);
+        pub fn cv_reg_MapperPyramid_MapperPyramid_Mapper(base_mapper: *mut c_void) -> cv_return_value_void_X;
+        pub fn cv_reg_MapperPyramid_calculate_const_Mat_Mat_PtrOfMap(instance: *const c_void, img1: *mut c_void, img2: *mut c_void, res: *mut c_void) -> cv_return_value_void;
+        pub fn cv_reg_MapperPyramid_getMap_const(instance: *const c_void) -> cv_return_value_void_X;
+    
+    }
+}
+#[cfg(feature = "contrib")]
+pub use reg_sys::*;
+
+#[cfg(feature = "contrib")]
+mod rgbd_sys {
+    use super::*;
+
+    extern "C" {
+        pub fn cv_linemod_colormap_Mat_Mat(quantized: *mut c_void, dst: *mut c_void) -> cv_return_value_void;
+        pub fn cv_linemod_getDefaultLINE() -> cv_return_value_void_X;
+        pub fn cv_linemod_getDefaultLINEMOD() -> cv_return_value_void_X;
+        pub fn cv_rgbd_depthTo3dSparse__InputArray__InputArray__InputArray__OutputArray(depth: *mut c_void, in_k: *mut c_void, in_points: *mut c_void, points3d: *mut c_void) -> cv_return_value_void;
+        pub fn cv_rgbd_depthTo3d__InputArray__InputArray__OutputArray__InputArray(depth: *mut c_void, k: *mut c_void, points3d: *mut c_void, mask: *mut c_void) -> cv_return_value_void;
+        pub fn cv_rgbd_isValidDepth_double(depth: *const f64) -> cv_return_value_bool;
+        pub fn cv_rgbd_isValidDepth_float(depth: *const f32) -> cv_return_value_bool;
+        pub fn cv_rgbd_isValidDepth_int(depth: *const i32) -> cv_return_value_bool;
+        pub fn cv_rgbd_isValidDepth_unsigned_int(depth: *const u32) -> cv_return_value_bool;
+        pub fn cv_rgbd_registerDepth__InputArray__InputArray__InputArray__InputArray__InputArray_Size__OutputArray_bool(unregistered_camera_matrix: *mut c_void, registered_camera_matrix: *mut c_void, registered_dist_coeffs: *mut c_void, rt: *mut c_void, unregistered_depth: *mut c_void, output_image_plane_size: core::Size, registered_depth: *mut c_void, depth_dilation: bool) -> cv_return_value_void;
+        pub fn cv_rgbd_rescaleDepth__InputArray_int__OutputArray(_in: *mut c_void, depth: i32, out: *mut c_void) -> cv_return_value_void;
+        pub fn cv_rgbd_warpFrame_Mat_Mat_Mat_Mat_Mat_Mat_Mat_Mat_Mat(image: *mut c_void, depth: *mut c_void, mask: *mut c_void, rt: *mut c_void, camera_matrix: *mut c_void, dist_coeff: *mut c_void, warped_image: *mut c_void, warped_depth: *mut c_void, warped_mask: *mut c_void) -> cv_return_value_void;
+        pub fn cv_ColorGradient_delete(ptr : *mut c_void);
+        pub fn cv_linemod_ColorGradient_ColorGradient() -> cv_return_value_void_X;
+        pub fn cv_linemod_ColorGradient_ColorGradient_float_size_t_float(weak_threshold: f32, num_features: size_t, strong_threshold: f32) -> cv_return_value_void_X;
+        pub fn cv_linemod_ColorGradient_name_const(instance: *const c_void) -> cv_return_value_char_X;
+        pub fn cv_linemod_ColorGradient_read_FileNode(instance: *mut c_void, _fn: *mut c_void) -> cv_return_value_void;
+        pub fn cv_linemod_ColorGradient_write_const_FileStorage(instance: *const c_void, fs: *mut c_void) -> cv_return_value_void;
+        pub fn cv_DepthNormal_delete(ptr : *mut c_void);
+        pub fn cv_linemod_DepthNormal_DepthNormal() -> cv_return_value_void_X;
+        pub fn cv_linemod_DepthNormal_DepthNormal_int_int_size_t_int(distance_threshold: i32, difference_threshold: i32, num_features: size_t, extract_threshold: i32) -> cv_return_value_void_X;
+        pub fn cv_linemod_DepthNormal_name_const(instance: *const c_void) -> cv_return_value_char_X;
+        pub fn cv_linemod_DepthNormal_read_FileNode(instance: *mut c_void, _fn: *mut c_void) -> cv_return_value_void;
+        pub fn cv_linemod_DepthNormal_write_const_FileStorage(instance: *const c_void, fs: *mut c_void) -> cv_return_value_void;
+        pub fn cv_Detector_delete(ptr : *mut c_void);
+        pub fn cv_linemod_Detector_Detector() -> cv_return_value_void_X;
+        pub fn cv_linemod_Detector_Detector_VectorOfPtrOfModality_VectorOfint(modalities: *mut c_void, t_pyramid: *mut c_void) -> cv_return_value_void_X;
+        pub fn cv_linemod_Detector_match_const_VectorOfMat_float_VectorOfMatch_VectorOfString__OutputArray_VectorOfMat(instance: *const c_void, sources: *mut c_void, threshold: f32, matches: *mut c_void, class_ids: *mut c_void, quantized_images: *mut c_void, masks: *mut c_void) -> cv_return_value_void;
+        pub fn cv_linemod_Detector_addTemplate_VectorOfMat_String_Mat_Rect_X(instance: *mut c_void, sources: *mut c_void, class_id: *const c_char, object_mask: *mut c_void, bounding_box: *mut core::Rect) -> cv_return_value_int;
+        pub fn cv_linemod_Detector_addSyntheticTemplate_VectorOfTemplate_String(instance: *mut c_void, templates: *mut c_void, class_id: *const c_char) -> cv_return_value_int;
+        pub fn cv_linemod_Detector_getModalities_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_linemod_Detector_getT_const_int(instance: *const c_void, pyramid_level: i32) -> cv_return_value_int;
+        pub fn cv_linemod_Detector_pyramidLevels_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_linemod_Detector_getTemplates_const_String_int(instance: *const c_void, class_id: *const c_char, template_id: i32) -> cv_return_value_void_X;
+        pub fn cv_linemod_Detector_numTemplates_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_linemod_Detector_numTemplates_const_String(instance: *const c_void, class_id: *const c_char) -> cv_return_value_int;
+        pub fn cv_linemod_Detector_numClasses_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_linemod_Detector_classIds_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_linemod_Detector_read_FileNode(instance: *mut c_void, _fn: *mut c_void) -> cv_return_value_void;
+        pub fn cv_linemod_Detector_write_const_FileStorage(instance: *const c_void, fs: *mut c_void) -> cv_return_value_void;
+        pub fn cv_linemod_Detector_readClass_FileNode_String(instance: *mut c_void, _fn: *mut c_void, class_id_override: *const c_char) -> cv_return_value_char_X;
+        pub fn cv_linemod_Detector_writeClass_const_String_FileStorage(instance: *const c_void, class_id: *const c_char, fs: *mut c_void) -> cv_return_value_void;
+        pub fn cv_linemod_Detector_readClasses_VectorOfString_String(instance: *mut c_void, class_ids: *mut c_void, format: *const c_char) -> cv_return_value_void;
+        pub fn cv_linemod_Detector_writeClasses_const_String(instance: *const c_void, format: *const c_char) -> cv_return_value_void;
+        pub fn cv_Feature_delete(ptr : *mut c_void);
+        pub fn cv_linemod_Feature_Feature() -> cv_return_value_void_X;
+        pub fn cv_linemod_Feature_Feature_int_int_int(x: i32, y: i32, label: i32) -> cv_return_value_void_X;
+        pub fn cv_linemod_Feature_read_FileNode(instance: *mut c_void, _fn: *mut c_void) -> cv_return_value_void;
+        pub fn cv_linemod_Feature_write_const_FileStorage(instance: *const c_void, fs: *mut c_void) -> cv_return_value_void;
+        pub fn cv_Match_delete(ptr : *mut c_void);
+        pub fn cv_linemod_Match_Match() -> cv_return_value_void_X;
+        pub fn cv_linemod_Match_Match_int_int_float_String_int(x: i32, y: i32, similarity: f32, class_id: *const c_char, template_id: i32) -> cv_return_value_void_X;
+        pub fn cv_linemod_Modality_process_const_Mat_Mat(instance: *const c_void, src: *mut c_void, mask: *mut c_void) -> cv_return_value_void_X;
+        pub fn cv_linemod_Modality_name_const(instance: *const c_void) -> cv_return_value_char_X;
+        pub fn cv_linemod_Modality_read_FileNode(instance: *mut c_void, _fn: *mut c_void) -> cv_return_value_void;
+        pub fn cv_linemod_Modality_write_const_FileStorage(instance: *const c_void, fs: *mut c_void) -> cv_return_value_void;
+        pub fn cv_linemod_Modality_create_String(modality_type: *const c_char) -> cv_return_value_void_X;
+        pub fn cv_linemod_Modality_create_FileNode(_fn: *mut c_void) -> cv_return_value_void_X;
+        pub fn cv_linemod_QuantizedPyramid_quantize_const_Mat(instance: *const c_void, dst: *mut c_void) -> cv_return_value_void;
+        pub fn cv_linemod_QuantizedPyramid_extractTemplate_const_Template(instance: *const c_void, templ: *mut c_void) -> cv_return_value_bool;
+        pub fn cv_linemod_QuantizedPyramid_pyrDown(instance: *mut c_void) -> cv_return_value_void;
+        pub fn cv_Template_delete(ptr : *mut c_void);
+        pub fn cv_linemod_Template_read_FileNode(instance: *mut c_void, _fn: *mut c_void) -> cv_return_value_void;
+        pub fn cv_linemod_Template_write_const_FileStorage(instance: *const c_void, fs: *mut c_void) -> cv_return_value_void;
+        pub fn cv_DepthCleaner_delete(ptr : *mut c_void);
+        pub fn cv_rgbd_DepthCleaner_DepthCleaner() -> cv_return_value_void_X;
+        pub fn cv_rgbd_DepthCleaner_DepthCleaner_int_int_int(depth: i32, window_size: i32, method: i32) -> cv_return_value_void_X;
+        pub fn cv_rgbd_DepthCleaner_operator_call_const__InputArray__OutputArray(instance: *const c_void, points: *mut c_void, depth: *mut c_void) -> cv_return_value_void;
+        pub fn cv_rgbd_DepthCleaner_initialize_const(instance: *const c_void) -> cv_return_value_void;
+        pub fn cv_rgbd_DepthCleaner_getWindowSize_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_rgbd_DepthCleaner_setWindowSize_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_rgbd_DepthCleaner_getDepth_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_rgbd_DepthCleaner_setDepth_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_rgbd_DepthCleaner_getMethod_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_rgbd_DepthCleaner_setMethod_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_ICPOdometry_delete(ptr : *mut c_void);
+        pub fn cv_rgbd_ICPOdometry_ICPOdometry() -> cv_return_value_void_X;
+        pub fn cv_rgbd_ICPOdometry_ICPOdometry_Mat_float_float_float_float_VectorOfint_int(camera_matrix: *mut c_void, min_depth: f32, max_depth: f32, max_depth_diff: f32, max_points_part: f32, iter_counts: *mut c_void, transform_type: i32) -> cv_return_value_void_X;
+        pub fn cv_rgbd_ICPOdometry_prepareFrameCache_const_PtrOfOdometryFrame_int(instance: *const c_void, frame: *mut c_void, cache_type: i32) -> cv_return_value_SizeWrapper;
+        pub fn cv_rgbd_ICPOdometry_getCameraMatrix_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_rgbd_ICPOdometry_setCameraMatrix_Mat(instance: *mut c_void, val: *mut c_void) -> cv_return_value_void;
+        pub fn cv_rgbd_ICPOdometry_getMinDepth_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_rgbd_ICPOdometry_setMinDepth_double(instance: *mut c_void, val: f64) -> cv_return_value_void;
+        pub fn cv_rgbd_ICPOdometry_getMaxDepth_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_rgbd_ICPOdometry_setMaxDepth_double(instance: *mut c_void, val: f64) -> cv_return_value_void;
+        pub fn cv_rgbd_ICPOdometry_getMaxDepthDiff_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_rgbd_ICPOdometry_setMaxDepthDiff_double(instance: *mut c_void, val: f64) -> cv_return_value_void;
+        pub fn cv_rgbd_ICPOdometry_getIterationCounts_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_rgbd_ICPOdometry_setIterationCounts_Mat(instance: *mut c_void, val: *mut c_void) -> cv_return_value_void;
+        pub fn cv_rgbd_ICPOdometry_getMaxPointsPart_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_rgbd_ICPOdometry_setMaxPointsPart_double(instance: *mut c_void, val: f64) -> cv_return_value_void;
+        pub fn cv_rgbd_ICPOdometry_getTransformType_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_rgbd_ICPOdometry_setTransformType_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_rgbd_ICPOdometry_getMaxTranslation_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_rgbd_ICPOdometry_setMaxTranslation_double(instance: *mut c_void, val: f64) -> cv_return_value_void;
+        pub fn cv_rgbd_ICPOdometry_getMaxRotation_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_rgbd_ICPOdometry_setMaxRotation_double(instance: *mut c_void, val: f64) -> cv_return_value_void;
+        pub fn cv_rgbd_ICPOdometry_getNormalsComputer_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_rgbd_Odometry_DEFAULT_MIN_DEPTH(instance: *mut c_void) -> cv_return_value_float;
+        pub fn cv_rgbd_Odometry_DEFAULT_MAX_DEPTH(instance: *mut c_void) -> cv_return_value_float;
+        pub fn cv_rgbd_Odometry_DEFAULT_MAX_DEPTH_DIFF(instance: *mut c_void) -> cv_return_value_float;
+        pub fn cv_rgbd_Odometry_DEFAULT_MAX_POINTS_PART(instance: *mut c_void) -> cv_return_value_float;
+        pub fn cv_rgbd_Odometry_DEFAULT_MAX_TRANSLATION(instance: *mut c_void) -> cv_return_value_float;
+        pub fn cv_rgbd_Odometry_DEFAULT_MAX_ROTATION(instance: *mut c_void) -> cv_return_value_float;
+        pub fn cv_rgbd_Odometry_compute_const_Mat_Mat_Mat_Mat_Mat_Mat_Mat_Mat(instance: *const c_void, src_image: *mut c_void, src_depth: *mut c_void, src_mask: *mut c_void, dst_image: *mut c_void, dst_depth: *mut c_void, dst_mask: *mut c_void, rt: *mut c_void, init_rt: *mut c_void) -> cv_return_value_bool;
+        pub fn cv_rgbd_Odometry_compute_const_PtrOfOdometryFrame_PtrOfOdometryFrame_Mat_Mat(instance: *const c_void, src_frame: *mut c_void, dst_frame: *mut c_void, rt: *mut c_void, init_rt: *mut c_void) -> cv_return_value_bool;
+        pub fn cv_rgbd_Odometry_prepareFrameCache_const_PtrOfOdometryFrame_int(instance: *const c_void, frame: *mut c_void, cache_type: i32) -> cv_return_value_SizeWrapper;
+        pub fn cv_rgbd_Odometry_getCameraMatrix_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_rgbd_Odometry_setCameraMatrix_Mat(instance: *mut c_void, val: *mut c_void) -> cv_return_value_void;
+        pub fn cv_rgbd_Odometry_getTransformType_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_rgbd_Odometry_setTransformType_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_rgbd_Odometry_create_String(odometry_type: *const c_char) -> cv_return_value_void_X;
+        pub fn cv_OdometryFrame_delete(ptr : *mut c_void);
+        pub fn cv_rgbd_OdometryFrame_OdometryFrame() -> cv_return_value_void_X;
+        pub fn cv_rgbd_OdometryFrame_OdometryFrame_Mat_Mat_Mat_Mat_int(image: *mut c_void, depth: *mut c_void, mask: *mut c_void, normals: *mut c_void, id: i32) -> cv_return_value_void_X;
+        pub fn cv_rgbd_OdometryFrame_release(instance: *mut c_void) -> cv_return_value_void;
+        pub fn cv_rgbd_OdometryFrame_releasePyramids(instance: *mut c_void) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdFrame_release(instance: *mut c_void) -> cv_return_value_void;
+        pub fn cv_RgbdFrame_delete(ptr : *mut c_void);
+        pub fn cv_rgbd_RgbdFrame_RgbdFrame() -> cv_return_value_void_X;
+        pub fn cv_rgbd_RgbdFrame_RgbdFrame_Mat_Mat_Mat_Mat_int(image: *mut c_void, depth: *mut c_void, mask: *mut c_void, normals: *mut c_void, id: i32) -> cv_return_value_void_X;
+        pub fn cv_RgbdICPOdometry_delete(ptr : *mut c_void);
+        pub fn cv_rgbd_RgbdICPOdometry_RgbdICPOdometry() -> cv_return_value_void_X;
+        pub fn cv_rgbd_RgbdICPOdometry_RgbdICPOdometry_Mat_float_float_float_float_VectorOfint_VectorOffloat_int(camera_matrix: *mut c_void, min_depth: f32, max_depth: f32, max_depth_diff: f32, max_points_part: f32, iter_counts: *mut c_void, min_gradient_magnitudes: *mut c_void, transform_type: i32) -> cv_return_value_void_X;
+        pub fn cv_rgbd_RgbdICPOdometry_prepareFrameCache_const_PtrOfOdometryFrame_int(instance: *const c_void, frame: *mut c_void, cache_type: i32) -> cv_return_value_SizeWrapper;
+        pub fn cv_rgbd_RgbdICPOdometry_getCameraMatrix_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_rgbd_RgbdICPOdometry_setCameraMatrix_Mat(instance: *mut c_void, val: *mut c_void) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdICPOdometry_getMinDepth_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_rgbd_RgbdICPOdometry_setMinDepth_double(instance: *mut c_void, val: f64) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdICPOdometry_getMaxDepth_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_rgbd_RgbdICPOdometry_setMaxDepth_double(instance: *mut c_void, val: f64) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdICPOdometry_getMaxDepthDiff_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_rgbd_RgbdICPOdometry_setMaxDepthDiff_double(instance: *mut c_void, val: f64) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdICPOdometry_getMaxPointsPart_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_rgbd_RgbdICPOdometry_setMaxPointsPart_double(instance: *mut c_void, val: f64) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdICPOdometry_getIterationCounts_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_rgbd_RgbdICPOdometry_setIterationCounts_Mat(instance: *mut c_void, val: *mut c_void) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdICPOdometry_getMinGradientMagnitudes_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_rgbd_RgbdICPOdometry_setMinGradientMagnitudes_Mat(instance: *mut c_void, val: *mut c_void) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdICPOdometry_getTransformType_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_rgbd_RgbdICPOdometry_setTransformType_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdICPOdometry_getMaxTranslation_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_rgbd_RgbdICPOdometry_setMaxTranslation_double(instance: *mut c_void, val: f64) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdICPOdometry_getMaxRotation_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_rgbd_RgbdICPOdometry_setMaxRotation_double(instance: *mut c_void, val: f64) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdICPOdometry_getNormalsComputer_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_RgbdNormals_delete(ptr : *mut c_void);
+        pub fn cv_rgbd_RgbdNormals_RgbdNormals() -> cv_return_value_void_X;
+        pub fn cv_rgbd_RgbdNormals_RgbdNormals_int_int_int__InputArray_int_int(rows: i32, cols: i32, depth: i32, k: *mut c_void, window_size: i32, method: i32) -> cv_return_value_void_X;
+        pub fn cv_rgbd_RgbdNormals_operator_call_const__InputArray__OutputArray(instance: *const c_void, points: *mut c_void, normals: *mut c_void) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdNormals_initialize_const(instance: *const c_void) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdNormals_getRows_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_rgbd_RgbdNormals_setRows_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdNormals_getCols_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_rgbd_RgbdNormals_setCols_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdNormals_getWindowSize_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_rgbd_RgbdNormals_setWindowSize_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdNormals_getDepth_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_rgbd_RgbdNormals_setDepth_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdNormals_getK_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_rgbd_RgbdNormals_setK_Mat(instance: *mut c_void, val: *mut c_void) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdNormals_getMethod_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_rgbd_RgbdNormals_setMethod_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_RgbdOdometry_delete(ptr : *mut c_void);
+        pub fn cv_rgbd_RgbdOdometry_RgbdOdometry() -> cv_return_value_void_X;
+        pub fn cv_rgbd_RgbdOdometry_RgbdOdometry_Mat_float_float_float_VectorOfint_VectorOffloat_float_int(camera_matrix: *mut c_void, min_depth: f32, max_depth: f32, max_depth_diff: f32, iter_counts: *mut c_void, min_gradient_magnitudes: *mut c_void, max_points_part: f32, transform_type: i32) -> cv_return_value_void_X;
+        pub fn cv_rgbd_RgbdOdometry_prepareFrameCache_const_PtrOfOdometryFrame_int(instance: *const c_void, frame: *mut c_void, cache_type: i32) -> cv_return_value_SizeWrapper;
+        pub fn cv_rgbd_RgbdOdometry_getCameraMatrix_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_rgbd_RgbdOdometry_setCameraMatrix_Mat(instance: *mut c_void, val: *mut c_void) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdOdometry_getMinDepth_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_rgbd_RgbdOdometry_setMinDepth_double(instance: *mut c_void, val: f64) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdOdometry_getMaxDepth_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_rgbd_RgbdOdometry_setMaxDepth_double(instance: *mut c_void, val: f64) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdOdometry_getMaxDepthDiff_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_rgbd_RgbdOdometry_setMaxDepthDiff_double(instance: *mut c_void, val: f64) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdOdometry_getIterationCounts_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_rgbd_RgbdOdometry_setIterationCounts_Mat(instance: *mut c_void, val: *mut c_void) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdOdometry_getMinGradientMagnitudes_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_rgbd_RgbdOdometry_setMinGradientMagnitudes_Mat(instance: *mut c_void, val: *mut c_void) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdOdometry_getMaxPointsPart_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_rgbd_RgbdOdometry_setMaxPointsPart_double(instance: *mut c_void, val: f64) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdOdometry_getTransformType_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_rgbd_RgbdOdometry_setTransformType_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdOdometry_getMaxTranslation_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_rgbd_RgbdOdometry_setMaxTranslation_double(instance: *mut c_void, val: f64) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdOdometry_getMaxRotation_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_rgbd_RgbdOdometry_setMaxRotation_double(instance: *mut c_void, val: f64) -> cv_return_value_void;
+        pub fn cv_RgbdPlane_delete(ptr : *mut c_void);
+        pub fn cv_rgbd_RgbdPlane_operator_call__InputArray__InputArray__OutputArray__OutputArray(instance: *mut c_void, points3d: *mut c_void, normals: *mut c_void, mask: *mut c_void, plane_coefficients: *mut c_void) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdPlane_operator_call__InputArray__OutputArray__OutputArray(instance: *mut c_void, points3d: *mut c_void, mask: *mut c_void, plane_coefficients: *mut c_void) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdPlane_getBlockSize_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_rgbd_RgbdPlane_setBlockSize_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdPlane_getMinSize_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_rgbd_RgbdPlane_setMinSize_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdPlane_getMethod_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_rgbd_RgbdPlane_setMethod_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdPlane_getThreshold_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_rgbd_RgbdPlane_setThreshold_double(instance: *mut c_void, val: f64) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdPlane_getSensorErrorA_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_rgbd_RgbdPlane_setSensorErrorA_double(instance: *mut c_void, val: f64) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdPlane_getSensorErrorB_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_rgbd_RgbdPlane_setSensorErrorB_double(instance: *mut c_void, val: f64) -> cv_return_value_void;
+        pub fn cv_rgbd_RgbdPlane_getSensorErrorC_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_rgbd_RgbdPlane_setSensorErrorC_double(instance: *mut c_void, val: f64) -> cv_return_value_void;
+    
+    }
+}
+#[cfg(feature = "contrib")]
+pub use rgbd_sys::*;
+
+#[cfg(feature = "contrib")]
+mod saliency_sys {
+    use super::*;
+
+    extern "C" {
+        pub fn cv_MotionSaliencyBinWangApr2014_delete(ptr : *mut c_void);
+        pub fn cv_saliency_MotionSaliencyBinWangApr2014_MotionSaliencyBinWangApr2014() -> cv_return_value_void_X;
+        pub fn cv_saliency_MotionSaliencyBinWangApr2014_create() -> cv_return_value_void_X;
+        pub fn cv_saliency_MotionSaliencyBinWangApr2014_computeSaliency__InputArray__OutputArray(instance: *mut c_void, image: *mut c_void, saliency_map: *mut c_void) -> cv_return_value_bool;
+        pub fn cv_saliency_MotionSaliencyBinWangApr2014_setImagesize_int_int(instance: *mut c_void, w: i32, h: i32) -> cv_return_value_void;
+        pub fn cv_saliency_MotionSaliencyBinWangApr2014_init(instance: *mut c_void) -> cv_return_value_bool;
+        pub fn cv_saliency_MotionSaliencyBinWangApr2014_getImageWidth_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_saliency_MotionSaliencyBinWangApr2014_setImageWidth_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_saliency_MotionSaliencyBinWangApr2014_getImageHeight_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_saliency_MotionSaliencyBinWangApr2014_setImageHeight_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_ObjectnessBING_delete(ptr : *mut c_void);
+        pub fn cv_saliency_ObjectnessBING_ObjectnessBING() -> cv_return_value_void_X;
+        pub fn cv_saliency_ObjectnessBING_create() -> cv_return_value_void_X;
+        pub fn cv_saliency_ObjectnessBING_computeSaliency__InputArray__OutputArray(instance: *mut c_void, image: *mut c_void, saliency_map: *mut c_void) -> cv_return_value_bool;
+        pub fn cv_saliency_ObjectnessBING_read(instance: *mut c_void) -> cv_return_value_void;
+        pub fn cv_saliency_ObjectnessBING_write_const(instance: *const c_void) -> cv_return_value_void;
+        pub fn cv_saliency_ObjectnessBING_getobjectnessValues(instance: *mut c_void) -> cv_return_value_void_X;
+        pub fn cv_saliency_ObjectnessBING_setTrainingPath_String(instance: *mut c_void, training_path: *const c_char) -> cv_return_value_void;
+        pub fn cv_saliency_ObjectnessBING_setBBResDir_String(instance: *mut c_void, results_dir: *const c_char) -> cv_return_value_void;
+        pub fn cv_saliency_ObjectnessBING_getBase_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_saliency_ObjectnessBING_setBase_double(instance: *mut c_void, val: f64) -> cv_return_value_void;
+        pub fn cv_saliency_ObjectnessBING_getNSS_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_saliency_ObjectnessBING_setNSS_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_saliency_ObjectnessBING_getW_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_saliency_ObjectnessBING_setW_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_saliency_Saliency_computeSaliency__InputArray__OutputArray(instance: *mut c_void, image: *mut c_void, saliency_map: *mut c_void) -> cv_return_value_bool;
+        pub fn cv_saliency_Saliency_getClassName_const(instance: *const c_void) -> cv_return_value_char_X;
+        pub fn cv_saliency_Saliency_create_String(saliency_type: *const c_char) -> cv_return_value_void_X;
+        pub fn cv_saliency_StaticSaliency_computeBinaryMap__InputArray__OutputArray(instance: *mut c_void, _saliency_map: *mut c_void, _binary_map: *mut c_void) -> cv_return_value_bool;
+        pub fn cv_StaticSaliencyFineGrained_delete(ptr : *mut c_void);
+        pub fn cv_saliency_StaticSaliencyFineGrained_StaticSaliencyFineGrained() -> cv_return_value_void_X;
+        pub fn cv_saliency_StaticSaliencyFineGrained_create() -> cv_return_value_void_X;
+        pub fn cv_saliency_StaticSaliencyFineGrained_computeSaliency__InputArray__OutputArray(instance: *mut c_void, image: *mut c_void, saliency_map: *mut c_void) -> cv_return_value_bool;
+        pub fn cv_StaticSaliencySpectralResidual_delete(ptr : *mut c_void);
+        pub fn cv_saliency_StaticSaliencySpectralResidual_StaticSaliencySpectralResidual() -> cv_return_value_void_X;
+        pub fn cv_saliency_StaticSaliencySpectralResidual_create() -> cv_return_value_void_X;
+        pub fn cv_saliency_StaticSaliencySpectralResidual_computeSaliency__InputArray__OutputArray(instance: *mut c_void, image: *mut c_void, saliency_map: *mut c_void) -> cv_return_value_bool;
+        pub fn cv_saliency_StaticSaliencySpectralResidual_read_FileNode(instance: *mut c_void, _fn: *mut c_void) -> cv_return_value_void;
+        pub fn cv_saliency_StaticSaliencySpectralResidual_write_const_FileStorage(instance: *const c_void, fs: *mut c_void) -> cv_return_value_void;
+        pub fn cv_saliency_StaticSaliencySpectralResidual_getImageWidth_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_saliency_StaticSaliencySpectralResidual_setImageWidth_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_saliency_StaticSaliencySpectralResidual_getImageHeight_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_saliency_StaticSaliencySpectralResidual_setImageHeight_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+    
+    }
+}
+#[cfg(feature = "contrib")]
+pub use saliency_sys::*;
+
 #[cfg(feature = "contrib")]
 mod sfm_sys {
     use super::*;
@@ -2910,26 +3515,136 @@ mod shape_sys {
 }
 pub use shape_sys::*;
 
+#[cfg(feature = "contrib")]
+mod stereo_sys {
+    use super::*;
+
+    extern "C" {
+        pub fn cv_stereo_censusTransform_Mat_Mat_int_Mat_Mat_int(image1: *mut c_void, image2: *mut c_void, kernel_size: i32, dist1: *mut c_void, dist2: *mut c_void, _type: i32) -> cv_return_value_void;
+        pub fn cv_stereo_censusTransform_Mat_int_Mat_int(image1: *mut c_void, kernel_size: i32, dist1: *mut c_void, _type: i32) -> cv_return_value_void;
+        pub fn cv_stereo_modifiedCensusTransform_Mat_Mat_int_Mat_Mat_int_int_Mat_Mat(img1: *mut c_void, img2: *mut c_void, kernel_size: i32, dist1: *mut c_void, dist2: *mut c_void, _type: i32, t: i32, integral_image1: *mut c_void, integral_image2: *mut c_void) -> cv_return_value_void;
+        pub fn cv_stereo_modifiedCensusTransform_Mat_int_Mat_int_int_Mat(img1: *mut c_void, kernel_size: i32, dist: *mut c_void, _type: i32, t: i32, integral_image: *mut c_void) -> cv_return_value_void;
+        pub fn cv_stereo_starCensusTransform_Mat_Mat_int_Mat_Mat(img1: *mut c_void, img2: *mut c_void, kernel_size: i32, dist1: *mut c_void, dist2: *mut c_void) -> cv_return_value_void;
+        pub fn cv_stereo_starCensusTransform_Mat_int_Mat(img1: *mut c_void, kernel_size: i32, dist: *mut c_void) -> cv_return_value_void;
+        pub fn cv_stereo_symetricCensusTransform_Mat_Mat_int_Mat_Mat_int(img1: *mut c_void, img2: *mut c_void, kernel_size: i32, dist1: *mut c_void, dist2: *mut c_void, _type: i32) -> cv_return_value_void;
+        pub fn cv_stereo_symetricCensusTransform_Mat_int_Mat_int(img1: *mut c_void, kernel_size: i32, dist1: *mut c_void, _type: i32) -> cv_return_value_void;
+        pub fn cv_stereo_StereoBinaryBM_getPreFilterType_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_stereo_StereoBinaryBM_setPreFilterType_int(instance: *mut c_void, pre_filter_type: i32) -> cv_return_value_void;
+        pub fn cv_stereo_StereoBinaryBM_getPreFilterSize_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_stereo_StereoBinaryBM_setPreFilterSize_int(instance: *mut c_void, pre_filter_size: i32) -> cv_return_value_void;
+        pub fn cv_stereo_StereoBinaryBM_getPreFilterCap_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_stereo_StereoBinaryBM_setPreFilterCap_int(instance: *mut c_void, pre_filter_cap: i32) -> cv_return_value_void;
+        pub fn cv_stereo_StereoBinaryBM_getTextureThreshold_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_stereo_StereoBinaryBM_setTextureThreshold_int(instance: *mut c_void, texture_threshold: i32) -> cv_return_value_void;
+        pub fn cv_stereo_StereoBinaryBM_getUniquenessRatio_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_stereo_StereoBinaryBM_setUniquenessRatio_int(instance: *mut c_void, uniqueness_ratio: i32) -> cv_return_value_void;
+        pub fn cv_stereo_StereoBinaryBM_getSmallerBlockSize_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_stereo_StereoBinaryBM_setSmallerBlockSize_int(instance: *mut c_void, block_size: i32) -> cv_return_value_void;
+        pub fn cv_stereo_StereoBinaryBM_getScalleFactor_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_stereo_StereoBinaryBM_setScalleFactor_int(instance: *mut c_void, factor: i32) -> cv_return_value_void;
+        pub fn cv_stereo_StereoBinaryBM_getSpekleRemovalTechnique_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_stereo_StereoBinaryBM_setSpekleRemovalTechnique_int(instance: *mut c_void, factor: i32) -> cv_return_value_void;
+        pub fn cv_stereo_StereoBinaryBM_getUsePrefilter_const(instance: *const c_void) -> cv_return_value_bool;
+        pub fn cv_stereo_StereoBinaryBM_setUsePrefilter_bool(instance: *mut c_void, factor: bool) -> cv_return_value_void;
+        pub fn cv_stereo_StereoBinaryBM_getBinaryKernelType_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_stereo_StereoBinaryBM_setBinaryKernelType_int(instance: *mut c_void, value: i32) -> cv_return_value_void;
+        pub fn cv_stereo_StereoBinaryBM_getAgregationWindowSize_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_stereo_StereoBinaryBM_setAgregationWindowSize_int(instance: *mut c_void, value: i32) -> cv_return_value_void;
+        pub fn cv_stereo_StereoBinaryBM_create_int_int(num_disparities: i32, block_size: i32) -> cv_return_value_void_X;
+        pub fn cv_stereo_StereoBinarySGBM_getPreFilterCap_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_stereo_StereoBinarySGBM_setPreFilterCap_int(instance: *mut c_void, pre_filter_cap: i32) -> cv_return_value_void;
+        pub fn cv_stereo_StereoBinarySGBM_getUniquenessRatio_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_stereo_StereoBinarySGBM_setUniquenessRatio_int(instance: *mut c_void, uniqueness_ratio: i32) -> cv_return_value_void;
+        pub fn cv_stereo_StereoBinarySGBM_getP1_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_stereo_StereoBinarySGBM_setP1_int(instance: *mut c_void, p1: i32) -> cv_return_value_void;
+        pub fn cv_stereo_StereoBinarySGBM_getP2_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_stereo_StereoBinarySGBM_setP2_int(instance: *mut c_void, p2: i32) -> cv_return_value_void;
+        pub fn cv_stereo_StereoBinarySGBM_getMode_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_stereo_StereoBinarySGBM_setMode_int(instance: *mut c_void, mode: i32) -> cv_return_value_void;
+        pub fn cv_stereo_StereoBinarySGBM_getSpekleRemovalTechnique_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_stereo_StereoBinarySGBM_setSpekleRemovalTechnique_int(instance: *mut c_void, factor: i32) -> cv_return_value_void;
+        pub fn cv_stereo_StereoBinarySGBM_getBinaryKernelType_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_stereo_StereoBinarySGBM_setBinaryKernelType_int(instance: *mut c_void, value: i32) -> cv_return_value_void;
+        pub fn cv_stereo_StereoBinarySGBM_getSubPixelInterpolationMethod_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_stereo_StereoBinarySGBM_setSubPixelInterpolationMethod_int(instance: *mut c_void, value: i32) -> cv_return_value_void;
+        pub fn cv_stereo_StereoBinarySGBM_create_int_int_int_int_int_int_int_int_int_int_int(min_disparity: i32, num_disparities: i32, block_size: i32, p1: i32, p2: i32, disp12_max_diff: i32, pre_filter_cap: i32, uniqueness_ratio: i32, speckle_window_size: i32, speckle_range: i32, mode: i32) -> cv_return_value_void_X;
+        pub fn cv_stereo_StereoMatcher_compute__InputArray__InputArray__OutputArray(instance: *mut c_void, left: *mut c_void, right: *mut c_void, disparity: *mut c_void) -> cv_return_value_void;
+        pub fn cv_stereo_StereoMatcher_getMinDisparity_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_stereo_StereoMatcher_setMinDisparity_int(instance: *mut c_void, min_disparity: i32) -> cv_return_value_void;
+        pub fn cv_stereo_StereoMatcher_getNumDisparities_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_stereo_StereoMatcher_setNumDisparities_int(instance: *mut c_void, num_disparities: i32) -> cv_return_value_void;
+        pub fn cv_stereo_StereoMatcher_getBlockSize_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_stereo_StereoMatcher_setBlockSize_int(instance: *mut c_void, block_size: i32) -> cv_return_value_void;
+        pub fn cv_stereo_StereoMatcher_getSpeckleWindowSize_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_stereo_StereoMatcher_setSpeckleWindowSize_int(instance: *mut c_void, speckle_window_size: i32) -> cv_return_value_void;
+        pub fn cv_stereo_StereoMatcher_getSpeckleRange_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_stereo_StereoMatcher_setSpeckleRange_int(instance: *mut c_void, speckle_range: i32) -> cv_return_value_void;
+        pub fn cv_stereo_StereoMatcher_getDisp12MaxDiff_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_stereo_StereoMatcher_setDisp12MaxDiff_int(instance: *mut c_void, disp12_max_diff: i32) -> cv_return_value_void;
+    
+    }
+}
+#[cfg(feature = "contrib")]
+pub use stereo_sys::*;
+
 mod stitching_sys {
     use super::*;
 
+    pub type cv_return_value_Stitcher_Status = cv_return_value<crate::stitching::Stitcher_Status>;
     extern "C" {
         pub fn cv_createStitcher_bool(try_use_gpu: bool) -> cv_return_value_void_X;
+        pub fn cv_detail_calibrateRotatingCamera_VectorOfMat_Mat(hs: *mut c_void, k: *mut c_void) -> cv_return_value_bool;
+        pub fn cv_detail_createLaplacePyrGpu__InputArray_int_VectorOfUMat(img: *mut c_void, num_levels: i32, pyr: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_createLaplacePyr__InputArray_int_VectorOfUMat(img: *mut c_void, num_levels: i32, pyr: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_createWeightMap__InputArray_float__InputOutputArray(mask: *mut c_void, sharpness: f32, weight: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_estimateFocal_VectorOfDetail_ImageFeatures_VectorOfDetail_MatchesInfo_VectorOfdouble(features: *mut c_void, pairwise_matches: *mut c_void, focals: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_findMaxSpanningTree_int_VectorOfDetail_MatchesInfo_Detail_Graph_VectorOfint(num_images: i32, pairwise_matches: *mut c_void, span_tree: *mut c_void, centers: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_focalsFromHomography_Mat_double_double_bool_bool(h: *mut c_void, f0: *mut f64, f1: *mut f64, f0_ok: *mut bool, f1_ok: *mut bool) -> cv_return_value_void;
+        pub fn cv_detail_leaveBiggestComponent_VectorOfDetail_ImageFeatures_VectorOfDetail_MatchesInfo_float(features: *mut c_void, pairwise_matches: *mut c_void, conf_threshold: f32) -> cv_return_value_void_X;
+        pub fn cv_detail_matchesGraphAsString_VectorOfString_VectorOfDetail_MatchesInfo_float(pathes: *mut c_void, pairwise_matches: *mut c_void, conf_threshold: f32) -> cv_return_value_char_X;
+        pub fn cv_detail_normalizeUsingWeightMap__InputArray__InputOutputArray(weight: *mut c_void, src: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_overlapRoi_Point_Point_Size_Size_Rect(tl1: core::Point, tl2: core::Point, sz1: core::Size, sz2: core::Size, roi: *mut core::Rect) -> cv_return_value_bool;
+        pub fn cv_detail_restoreImageFromLaplacePyrGpu_VectorOfUMat(pyr: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_restoreImageFromLaplacePyr_VectorOfUMat(pyr: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_resultRoiIntersection_VectorOfPoint_VectorOfSize(corners: *mut c_void, sizes: *mut c_void) -> cv_return_value_RectWrapper;
+        pub fn cv_detail_resultRoi_VectorOfPoint_VectorOfSize(corners: *mut c_void, sizes: *mut c_void) -> cv_return_value_RectWrapper;
+        pub fn cv_detail_resultRoi_VectorOfPoint_VectorOfUMat(corners: *mut c_void, images: *mut c_void) -> cv_return_value_RectWrapper;
+        pub fn cv_detail_resultTl_VectorOfPoint(corners: *mut c_void) -> cv_return_value_PointWrapper;
+        pub fn cv_detail_selectRandomSubset_int_int_VectorOfint(count: i32, size: i32, subset: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_stitchingLogLevel() -> cv_return_value_int;
         pub fn cv_AffineWarper_delete(ptr : *mut c_void);
+        pub fn cv_AffineWarper_create_const_float(instance: *const c_void, scale: f32) -> cv_return_value_void_X;
+        pub fn cv_AffineWarper_AffineWarper() -> cv_return_value_void_X;
         pub fn cv_CompressedRectilinearPortraitWarper_delete(ptr : *mut c_void);
         pub fn cv_CompressedRectilinearPortraitWarper_CompressedRectilinearPortraitWarper_float_float(a: f32, b: f32) -> cv_return_value_void_X;
+        pub fn cv_CompressedRectilinearPortraitWarper_create_const_float(instance: *const c_void, scale: f32) -> cv_return_value_void_X;
         pub fn cv_CompressedRectilinearWarper_delete(ptr : *mut c_void);
         pub fn cv_CompressedRectilinearWarper_CompressedRectilinearWarper_float_float(a: f32, b: f32) -> cv_return_value_void_X;
+        pub fn cv_CompressedRectilinearWarper_create_const_float(instance: *const c_void, scale: f32) -> cv_return_value_void_X;
         pub fn cv_CylindricalWarper_delete(ptr : *mut c_void);
+        pub fn cv_CylindricalWarper_create_const_float(instance: *const c_void, scale: f32) -> cv_return_value_void_X;
+        pub fn cv_CylindricalWarper_CylindricalWarper() -> cv_return_value_void_X;
         pub fn cv_FisheyeWarper_delete(ptr : *mut c_void);
+        pub fn cv_FisheyeWarper_create_const_float(instance: *const c_void, scale: f32) -> cv_return_value_void_X;
+        pub fn cv_FisheyeWarper_FisheyeWarper() -> cv_return_value_void_X;
         pub fn cv_MercatorWarper_delete(ptr : *mut c_void);
+        pub fn cv_MercatorWarper_create_const_float(instance: *const c_void, scale: f32) -> cv_return_value_void_X;
+        pub fn cv_MercatorWarper_MercatorWarper() -> cv_return_value_void_X;
         pub fn cv_PaniniPortraitWarper_delete(ptr : *mut c_void);
         pub fn cv_PaniniPortraitWarper_PaniniPortraitWarper_float_float(a: f32, b: f32) -> cv_return_value_void_X;
+        pub fn cv_PaniniPortraitWarper_create_const_float(instance: *const c_void, scale: f32) -> cv_return_value_void_X;
         pub fn cv_PaniniWarper_delete(ptr : *mut c_void);
         pub fn cv_PaniniWarper_PaniniWarper_float_float(a: f32, b: f32) -> cv_return_value_void_X;
+        pub fn cv_PaniniWarper_create_const_float(instance: *const c_void, scale: f32) -> cv_return_value_void_X;
         pub fn cv_PlaneWarper_delete(ptr : *mut c_void);
+        pub fn cv_PlaneWarper_create_const_float(instance: *const c_void, scale: f32) -> cv_return_value_void_X;
+        pub fn cv_PlaneWarper_PlaneWarper() -> cv_return_value_void_X;
         pub fn cv_SphericalWarper_delete(ptr : *mut c_void);
+        pub fn cv_SphericalWarper_create_const_float(instance: *const c_void, scale: f32) -> cv_return_value_void_X;
+        pub fn cv_SphericalWarper_SphericalWarper() -> cv_return_value_void_X;
         pub fn cv_StereographicWarper_delete(ptr : *mut c_void);
+        pub fn cv_StereographicWarper_create_const_float(instance: *const c_void, scale: f32) -> cv_return_value_void_X;
+        pub fn cv_StereographicWarper_StereographicWarper() -> cv_return_value_void_X;
         pub fn cv_Stitcher_delete(ptr : *mut c_void);
         pub fn cv_Stitcher_createDefault_bool(try_use_gpu: bool) -> cv_return_value_void_X;
         pub fn cv_Stitcher_create_Stitcher_Mode_bool(mode: crate::stitching::Stitcher_Mode, try_use_gpu: bool) -> cv_return_value_void_X;
@@ -2943,8 +3658,29 @@ mod stitching_sys {
         pub fn cv_Stitcher_setPanoConfidenceThresh_double(instance: *mut c_void, conf_thresh: f64) -> cv_return_value_void;
         pub fn cv_Stitcher_waveCorrection_const(instance: *const c_void) -> cv_return_value_bool;
         pub fn cv_Stitcher_setWaveCorrection_bool(instance: *mut c_void, flag: bool) -> cv_return_value_void;
+        pub fn cv_Stitcher_featuresFinder(instance: *mut c_void) -> cv_return_value_void_X;
+        pub fn cv_Stitcher_featuresFinder_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_Stitcher_setFeaturesFinder_PtrOfDetail_FeaturesFinder(instance: *mut c_void, features_finder: *mut c_void) -> cv_return_value_void;
+        pub fn cv_Stitcher_featuresMatcher(instance: *mut c_void) -> cv_return_value_void_X;
+        pub fn cv_Stitcher_featuresMatcher_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_Stitcher_setFeaturesMatcher_PtrOfDetail_FeaturesMatcher(instance: *mut c_void, features_matcher: *mut c_void) -> cv_return_value_void;
         pub fn cv_Stitcher_matchingMask_const(instance: *const c_void) -> cv_return_value_void_X;
         pub fn cv_Stitcher_setMatchingMask_UMat(instance: *mut c_void, mask: *mut c_void) -> cv_return_value_void;
+        pub fn cv_Stitcher_bundleAdjuster(instance: *mut c_void) -> cv_return_value_void_X;
+        pub fn cv_Stitcher_bundleAdjuster_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_Stitcher_setBundleAdjuster_PtrOfDetail_BundleAdjusterBase(instance: *mut c_void, bundle_adjuster: *mut c_void) -> cv_return_value_void;
+        pub fn cv_Stitcher_warper(instance: *mut c_void) -> cv_return_value_void_X;
+        pub fn cv_Stitcher_warper_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_Stitcher_setWarper_PtrOfWarperCreator(instance: *mut c_void, creator: *mut c_void) -> cv_return_value_void;
+        pub fn cv_Stitcher_exposureCompensator(instance: *mut c_void) -> cv_return_value_void_X;
+        pub fn cv_Stitcher_exposureCompensator_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_Stitcher_setExposureCompensator_PtrOfDetail_ExposureCompensator(instance: *mut c_void, exposure_comp: *mut c_void) -> cv_return_value_void;
+        pub fn cv_Stitcher_seamFinder(instance: *mut c_void) -> cv_return_value_void_X;
+        pub fn cv_Stitcher_seamFinder_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_Stitcher_setSeamFinder_PtrOfDetail_SeamFinder(instance: *mut c_void, seam_finder: *mut c_void) -> cv_return_value_void;
+        pub fn cv_Stitcher_blender(instance: *mut c_void) -> cv_return_value_void_X;
+        pub fn cv_Stitcher_blender_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_Stitcher_setBlender_PtrOfDetail_Blender(instance: *mut c_void, b: *mut c_void) -> cv_return_value_void;
         pub fn cv_Stitcher_estimateTransform__InputArray(instance: *mut c_void, images: *mut c_void) -> cv_return_value_Stitcher_Status;
         pub fn cv_Stitcher_estimateTransform__InputArray_VectorOfVectorOfRect(instance: *mut c_void, images: *mut c_void, rois: *mut c_void) -> cv_return_value_Stitcher_Status;
         pub fn cv_Stitcher_composePanorama__OutputArray(instance: *mut c_void, pano: *mut c_void) -> cv_return_value_Stitcher_Status;
@@ -2952,8 +3688,185 @@ mod stitching_sys {
         pub fn cv_Stitcher_stitch__InputArray__OutputArray(instance: *mut c_void, images: *mut c_void, pano: *mut c_void) -> cv_return_value_Stitcher_Status;
         pub fn cv_Stitcher_stitch__InputArray_VectorOfVectorOfRect__OutputArray(instance: *mut c_void, images: *mut c_void, rois: *mut c_void, pano: *mut c_void) -> cv_return_value_Stitcher_Status;
         pub fn cv_Stitcher_component_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_Stitcher_cameras_const(instance: *const c_void) -> cv_return_value_void_X;
         pub fn cv_Stitcher_workScale_const(instance: *const c_void) -> cv_return_value_double;
         pub fn cv_TransverseMercatorWarper_delete(ptr : *mut c_void);
+        pub fn cv_TransverseMercatorWarper_create_const_float(instance: *const c_void, scale: f32) -> cv_return_value_void_X;
+        pub fn cv_TransverseMercatorWarper_TransverseMercatorWarper() -> cv_return_value_void_X;
+        pub fn cv_WarperCreator_create_const_float(instance: *const c_void, scale: f32) -> cv_return_value_void_X;
+        pub fn cv_Detail_AKAZEFeaturesFinder_delete(ptr : *mut c_void);
+        pub fn cv_detail_AKAZEFeaturesFinder_AKAZEFeaturesFinder_int_int_int_float_int_int_int(descriptor_type: i32, descriptor_size: i32, descriptor_channels: i32, threshold: f32, n_octaves: i32, n_octave_layers: i32, diffusivity: i32) -> cv_return_value_void_X;
+        pub fn cv_Detail_AffineBasedEstimator_delete(ptr : *mut c_void);
+        pub fn cv_detail_AffineBasedEstimator_AffineBasedEstimator() -> cv_return_value_void_X;
+        pub fn cv_Detail_AffineBestOf2NearestMatcher_delete(ptr : *mut c_void);
+        pub fn cv_detail_AffineBestOf2NearestMatcher_AffineBestOf2NearestMatcher_bool_bool_float_int(full_affine: bool, try_use_gpu: bool, match_conf: f32, num_matches_thresh1: i32) -> cv_return_value_void_X;
+        pub fn cv_Detail_AffineWarper_delete(ptr : *mut c_void);
+        pub fn cv_detail_AffineWarper_AffineWarper_float(scale: f32) -> cv_return_value_void_X;
+        pub fn cv_detail_AffineWarper_warpPoint_Point2f__InputArray__InputArray(instance: *mut c_void, pt: core::Point2f, k: *mut c_void, r: *mut c_void) -> cv_return_value_Point2fWrapper;
+        pub fn cv_detail_AffineWarper_buildMaps_Size__InputArray__InputArray__OutputArray__OutputArray(instance: *mut c_void, src_size: core::Size, k: *mut c_void, r: *mut c_void, xmap: *mut c_void, ymap: *mut c_void) -> cv_return_value_RectWrapper;
+        pub fn cv_detail_AffineWarper_warp__InputArray__InputArray__InputArray_int_int__OutputArray(instance: *mut c_void, src: *mut c_void, k: *mut c_void, r: *mut c_void, interp_mode: i32, border_mode: i32, dst: *mut c_void) -> cv_return_value_PointWrapper;
+        pub fn cv_detail_AffineWarper_warpRoi_Size__InputArray__InputArray(instance: *mut c_void, src_size: core::Size, k: *mut c_void, r: *mut c_void) -> cv_return_value_RectWrapper;
+        pub fn cv_detail_BestOf2NearestMatcher_collectGarbage(instance: *mut c_void) -> cv_return_value_void;
+        pub fn cv_Detail_BestOf2NearestMatcher_delete(ptr : *mut c_void);
+        pub fn cv_detail_BestOf2NearestMatcher_BestOf2NearestMatcher_bool_float_int_int(try_use_gpu: bool, match_conf: f32, num_matches_thresh1: i32, num_matches_thresh2: i32) -> cv_return_value_void_X;
+        pub fn cv_Detail_BestOf2NearestRangeMatcher_delete(ptr : *mut c_void);
+        pub fn cv_detail_BestOf2NearestRangeMatcher_BestOf2NearestRangeMatcher_int_bool_float_int_int(range_width: i32, try_use_gpu: bool, match_conf: f32, num_matches_thresh1: i32, num_matches_thresh2: i32) -> cv_return_value_void_X;
+        pub fn cv_detail_Blender_prepare_VectorOfPoint_VectorOfSize(instance: *mut c_void, corners: *mut c_void, sizes: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_Blender_prepare_Rect(instance: *mut c_void, dst_roi: core::Rect) -> cv_return_value_void;
+        pub fn cv_detail_Blender_feed__InputArray__InputArray_Point(instance: *mut c_void, img: *mut c_void, mask: *mut c_void, tl: core::Point) -> cv_return_value_void;
+        pub fn cv_detail_Blender_blend__InputOutputArray__InputOutputArray(instance: *mut c_void, dst: *mut c_void, dst_mask: *mut c_void) -> cv_return_value_void;
+        pub fn cv_Detail_Blender_delete(ptr : *mut c_void);
+        pub fn cv_detail_Blender_createDefault_int_bool(_type: i32, try_gpu: bool) -> cv_return_value_void_X;
+        pub fn cv_Detail_BlocksGainCompensator_delete(ptr : *mut c_void);
+        pub fn cv_detail_BlocksGainCompensator_BlocksGainCompensator_int_int(bl_width: i32, bl_height: i32) -> cv_return_value_void_X;
+        pub fn cv_detail_BlocksGainCompensator_apply_int_Point__InputOutputArray__InputArray(instance: *mut c_void, index: i32, corner: core::Point, image: *mut c_void, mask: *mut c_void) -> cv_return_value_void;
+        pub fn cv_Detail_BundleAdjusterAffine_delete(ptr : *mut c_void);
+        pub fn cv_detail_BundleAdjusterAffine_BundleAdjusterAffine() -> cv_return_value_void_X;
+        pub fn cv_Detail_BundleAdjusterAffinePartial_delete(ptr : *mut c_void);
+        pub fn cv_detail_BundleAdjusterAffinePartial_BundleAdjusterAffinePartial() -> cv_return_value_void_X;
+        pub fn cv_detail_BundleAdjusterBase_refinementMask_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_detail_BundleAdjusterBase_setRefinementMask_Mat(instance: *mut c_void, mask: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_BundleAdjusterBase_confThresh_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_detail_BundleAdjusterBase_setConfThresh_double(instance: *mut c_void, conf_thresh: f64) -> cv_return_value_void;
+        pub fn cv_detail_BundleAdjusterBase_termCriteria(instance: *mut c_void) -> cv_return_value_void_X;
+        pub fn cv_detail_BundleAdjusterBase_setTermCriteria_TermCriteria(instance: *mut c_void, term_criteria: *mut c_void) -> cv_return_value_void;
+        pub fn cv_Detail_BundleAdjusterRay_delete(ptr : *mut c_void);
+        pub fn cv_detail_BundleAdjusterRay_BundleAdjusterRay() -> cv_return_value_void_X;
+        pub fn cv_Detail_BundleAdjusterReproj_delete(ptr : *mut c_void);
+        pub fn cv_detail_BundleAdjusterReproj_BundleAdjusterReproj() -> cv_return_value_void_X;
+        pub fn cv_Detail_CameraParams_delete(ptr : *mut c_void);
+        pub fn cv_detail_CameraParams_CameraParams() -> cv_return_value_void_X;
+        pub fn cv_detail_CameraParams_CameraParams_Detail_CameraParams(other: *mut c_void) -> cv_return_value_void_X;
+        pub fn cv_detail_CameraParams_K_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_Detail_CompressedRectilinearPortraitWarper_delete(ptr : *mut c_void);
+        pub fn cv_detail_CompressedRectilinearPortraitWarper_CompressedRectilinearPortraitWarper_float_float_float(scale: f32, a: f32, b: f32) -> cv_return_value_void_X;
+        pub fn cv_Detail_CompressedRectilinearWarper_delete(ptr : *mut c_void);
+        pub fn cv_detail_CompressedRectilinearWarper_CompressedRectilinearWarper_float_float_float(scale: f32, a: f32, b: f32) -> cv_return_value_void_X;
+        pub fn cv_Detail_CylindricalPortraitWarper_delete(ptr : *mut c_void);
+        pub fn cv_detail_CylindricalPortraitWarper_CylindricalPortraitWarper_float(scale: f32) -> cv_return_value_void_X;
+        pub fn cv_Detail_CylindricalWarper_delete(ptr : *mut c_void);
+        pub fn cv_detail_CylindricalWarper_CylindricalWarper_float(scale: f32) -> cv_return_value_void_X;
+        pub fn cv_detail_CylindricalWarper_buildMaps_Size__InputArray__InputArray__OutputArray__OutputArray(instance: *mut c_void, src_size: core::Size, k: *mut c_void, r: *mut c_void, xmap: *mut c_void, ymap: *mut c_void) -> cv_return_value_RectWrapper;
+        pub fn cv_detail_CylindricalWarper_warp__InputArray__InputArray__InputArray_int_int__OutputArray(instance: *mut c_void, src: *mut c_void, k: *mut c_void, r: *mut c_void, interp_mode: i32, border_mode: i32, dst: *mut c_void) -> cv_return_value_PointWrapper;
+        pub fn cv_Detail_DisjointSets_delete(ptr : *mut c_void);
+        pub fn cv_detail_DisjointSets_DisjointSets_int(elem_count: i32) -> cv_return_value_void_X;
+        pub fn cv_detail_DisjointSets_createOneElemSets_int(instance: *mut c_void, elem_count: i32) -> cv_return_value_void;
+        pub fn cv_detail_DisjointSets_findSetByElem_int(instance: *mut c_void, elem: i32) -> cv_return_value_int;
+        pub fn cv_detail_DisjointSets_mergeSets_int_int(instance: *mut c_void, set1: i32, set2: i32) -> cv_return_value_int;
+        pub fn cv_Detail_DpSeamFinder_delete(ptr : *mut c_void);
+        pub fn cv_detail_DpSeamFinder_find_VectorOfUMat_VectorOfPoint_VectorOfUMat(instance: *mut c_void, src: *mut c_void, corners: *mut c_void, masks: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_Estimator_operator_call_VectorOfDetail_ImageFeatures_VectorOfDetail_MatchesInfo_VectorOfDetail_CameraParams(instance: *mut c_void, features: *mut c_void, pairwise_matches: *mut c_void, cameras: *mut c_void) -> cv_return_value_bool;
+        pub fn cv_detail_ExposureCompensator_feed_VectorOfPoint_VectorOfUMat_VectorOfUMat(instance: *mut c_void, corners: *mut c_void, images: *mut c_void, masks: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_ExposureCompensator_apply_int_Point__InputOutputArray__InputArray(instance: *mut c_void, index: i32, corner: core::Point, image: *mut c_void, mask: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_ExposureCompensator_createDefault_int(_type: i32) -> cv_return_value_void_X;
+        pub fn cv_Detail_FeatherBlender_delete(ptr : *mut c_void);
+        pub fn cv_detail_FeatherBlender_FeatherBlender_float(sharpness: f32) -> cv_return_value_void_X;
+        pub fn cv_detail_FeatherBlender_sharpness_const(instance: *const c_void) -> cv_return_value_float;
+        pub fn cv_detail_FeatherBlender_setSharpness_float(instance: *mut c_void, val: f32) -> cv_return_value_void;
+        pub fn cv_detail_FeatherBlender_prepare_Rect(instance: *mut c_void, dst_roi: core::Rect) -> cv_return_value_void;
+        pub fn cv_detail_FeatherBlender_feed__InputArray__InputArray_Point(instance: *mut c_void, img: *mut c_void, mask: *mut c_void, tl: core::Point) -> cv_return_value_void;
+        pub fn cv_detail_FeatherBlender_blend__InputOutputArray__InputOutputArray(instance: *mut c_void, dst: *mut c_void, dst_mask: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_FeatherBlender_createWeightMaps_VectorOfUMat_VectorOfPoint_VectorOfUMat(instance: *mut c_void, masks: *mut c_void, corners: *mut c_void, weight_maps: *mut c_void) -> cv_return_value_RectWrapper;
+        pub fn cv_detail_FeaturesFinder_operator_call__InputArray_Detail_ImageFeatures(instance: *mut c_void, image: *mut c_void, features: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_FeaturesFinder_operator_call__InputArray_Detail_ImageFeatures_VectorOfRect(instance: *mut c_void, image: *mut c_void, features: *mut c_void, rois: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_FeaturesFinder_operator_call__InputArray_VectorOfDetail_ImageFeatures_VectorOfVectorOfRect(instance: *mut c_void, images: *mut c_void, features: *mut c_void, rois: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_FeaturesFinder_operator_call__InputArray_VectorOfDetail_ImageFeatures(instance: *mut c_void, images: *mut c_void, features: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_FeaturesFinder_collectGarbage(instance: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_FeaturesMatcher_operator_call_Detail_ImageFeatures_Detail_ImageFeatures_Detail_MatchesInfo(instance: *mut c_void, features1: *mut c_void, features2: *mut c_void, matches_info: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_FeaturesMatcher_operator_call_VectorOfDetail_ImageFeatures_VectorOfDetail_MatchesInfo_UMat(instance: *mut c_void, features: *mut c_void, pairwise_matches: *mut c_void, mask: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_FeaturesMatcher_isThreadSafe_const(instance: *const c_void) -> cv_return_value_bool;
+        pub fn cv_detail_FeaturesMatcher_collectGarbage(instance: *mut c_void) -> cv_return_value_void;
+        pub fn cv_Detail_FisheyeWarper_delete(ptr : *mut c_void);
+        pub fn cv_detail_FisheyeWarper_FisheyeWarper_float(scale: f32) -> cv_return_value_void_X;
+        pub fn cv_Detail_GainCompensator_delete(ptr : *mut c_void);
+        pub fn cv_detail_GainCompensator_apply_int_Point__InputOutputArray__InputArray(instance: *mut c_void, index: i32, corner: core::Point, image: *mut c_void, mask: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_GainCompensator_gains_const(instance: *const c_void) -> cv_return_value_void_X;
+        pub fn cv_detail_GainCompensator_GainCompensator() -> cv_return_value_void_X;
+        pub fn cv_Detail_Graph_delete(ptr : *mut c_void);
+        pub fn cv_detail_Graph_Graph_int(num_vertices: i32) -> cv_return_value_void_X;
+        pub fn cv_detail_Graph_create_int(instance: *mut c_void, num_vertices: i32) -> cv_return_value_void;
+        pub fn cv_detail_Graph_numVertices_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_detail_Graph_addEdge_int_int_float(instance: *mut c_void, from: i32, to: i32, weight: f32) -> cv_return_value_void;
+        pub fn cv_Detail_GraphCutSeamFinder_delete(ptr : *mut c_void);
+        pub fn cv_detail_GraphCutSeamFinder_GraphCutSeamFinder_int_float_float(cost_type: i32, terminal_cost: f32, bad_region_penalty: f32) -> cv_return_value_void_X;
+        pub fn cv_detail_GraphCutSeamFinder_find_VectorOfUMat_VectorOfPoint_VectorOfUMat(instance: *mut c_void, src: *mut c_void, corners: *mut c_void, masks: *mut c_void) -> cv_return_value_void;
+        pub fn cv_Detail_GraphCutSeamFinderBase_delete(ptr : *mut c_void);
+        pub fn cv_Detail_GraphEdge_delete(ptr : *mut c_void);
+        pub fn cv_detail_GraphEdge_GraphEdge_int_int_float(from: i32, to: i32, weight: f32) -> cv_return_value_void_X;
+        pub fn cv_Detail_HomographyBasedEstimator_delete(ptr : *mut c_void);
+        pub fn cv_detail_HomographyBasedEstimator_HomographyBasedEstimator_bool(is_focals_estimated: bool) -> cv_return_value_void_X;
+        pub fn cv_Detail_ImageFeatures_delete(ptr : *mut c_void);
+        pub fn cv_detail_ImageFeatures_ImageFeatures() -> cv_return_value_void_X;
+        pub fn cv_Detail_MatchesInfo_delete(ptr : *mut c_void);
+        pub fn cv_detail_MatchesInfo_MatchesInfo() -> cv_return_value_void_X;
+        pub fn cv_detail_MatchesInfo_MatchesInfo_Detail_MatchesInfo(other: *mut c_void) -> cv_return_value_void_X;
+        pub fn cv_Detail_MercatorWarper_delete(ptr : *mut c_void);
+        pub fn cv_detail_MercatorWarper_MercatorWarper_float(scale: f32) -> cv_return_value_void_X;
+        pub fn cv_Detail_MultiBandBlender_delete(ptr : *mut c_void);
+        pub fn cv_detail_MultiBandBlender_MultiBandBlender_int_int_int(try_gpu: i32, num_bands: i32, weight_type: i32) -> cv_return_value_void_X;
+        pub fn cv_detail_MultiBandBlender_numBands_const(instance: *const c_void) -> cv_return_value_int;
+        pub fn cv_detail_MultiBandBlender_setNumBands_int(instance: *mut c_void, val: i32) -> cv_return_value_void;
+        pub fn cv_detail_MultiBandBlender_prepare_Rect(instance: *mut c_void, dst_roi: core::Rect) -> cv_return_value_void;
+        pub fn cv_detail_MultiBandBlender_feed__InputArray__InputArray_Point(instance: *mut c_void, img: *mut c_void, mask: *mut c_void, tl: core::Point) -> cv_return_value_void;
+        pub fn cv_detail_MultiBandBlender_blend__InputOutputArray__InputOutputArray(instance: *mut c_void, dst: *mut c_void, dst_mask: *mut c_void) -> cv_return_value_void;
+        pub fn cv_Detail_NoBundleAdjuster_delete(ptr : *mut c_void);
+        pub fn cv_detail_NoBundleAdjuster_NoBundleAdjuster() -> cv_return_value_void_X;
+        pub fn cv_Detail_NoExposureCompensator_delete(ptr : *mut c_void);
+        pub fn cv_detail_NoExposureCompensator_apply_int_Point__InputOutputArray__InputArray(instance: *mut c_void, unnamed_arg: i32, unnamed_arg_1: core::Point, unnamed_arg_2: *mut c_void, unnamed_arg_3: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_NoExposureCompensator_NoExposureCompensator() -> cv_return_value_void_X;
+        pub fn cv_Detail_NoSeamFinder_delete(ptr : *mut c_void);
+        pub fn cv_detail_NoSeamFinder_find_VectorOfUMat_VectorOfPoint_VectorOfUMat(instance: *mut c_void, unnamed_arg: *mut c_void, unnamed_arg_1: *mut c_void, unnamed_arg_2: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_NoSeamFinder_NoSeamFinder() -> cv_return_value_void_X;
+        pub fn cv_Detail_OrbFeaturesFinder_delete(ptr : *mut c_void);
+        pub fn cv_detail_OrbFeaturesFinder_OrbFeaturesFinder_Size_int_float_int(_grid_size: core::Size, nfeatures: i32, scale_factor: f32, nlevels: i32) -> cv_return_value_void_X;
+        pub fn cv_detail_PairwiseSeamFinder_find_VectorOfUMat_VectorOfPoint_VectorOfUMat(instance: *mut c_void, src: *mut c_void, corners: *mut c_void, masks: *mut c_void) -> cv_return_value_void;
+        pub fn cv_Detail_PaniniPortraitWarper_delete(ptr : *mut c_void);
+        pub fn cv_detail_PaniniPortraitWarper_PaniniPortraitWarper_float_float_float(scale: f32, a: f32, b: f32) -> cv_return_value_void_X;
+        pub fn cv_Detail_PaniniWarper_delete(ptr : *mut c_void);
+        pub fn cv_detail_PaniniWarper_PaniniWarper_float_float_float(scale: f32, a: f32, b: f32) -> cv_return_value_void_X;
+        pub fn cv_Detail_PlanePortraitWarper_delete(ptr : *mut c_void);
+        pub fn cv_detail_PlanePortraitWarper_PlanePortraitWarper_float(scale: f32) -> cv_return_value_void_X;
+        pub fn cv_detail_PlaneWarper_warpPoint_Point2f__InputArray__InputArray(instance: *mut c_void, pt: core::Point2f, k: *mut c_void, r: *mut c_void) -> cv_return_value_Point2fWrapper;
+        pub fn cv_detail_PlaneWarper_warpPoint_Point2f__InputArray__InputArray__InputArray(instance: *mut c_void, pt: core::Point2f, k: *mut c_void, r: *mut c_void, t: *mut c_void) -> cv_return_value_Point2fWrapper;
+        pub fn cv_detail_PlaneWarper_buildMaps_Size__InputArray__InputArray__InputArray__OutputArray__OutputArray(instance: *mut c_void, src_size: core::Size, k: *mut c_void, r: *mut c_void, t: *mut c_void, xmap: *mut c_void, ymap: *mut c_void) -> cv_return_value_RectWrapper;
+        pub fn cv_detail_PlaneWarper_buildMaps_Size__InputArray__InputArray__OutputArray__OutputArray(instance: *mut c_void, src_size: core::Size, k: *mut c_void, r: *mut c_void, xmap: *mut c_void, ymap: *mut c_void) -> cv_return_value_RectWrapper;
+        pub fn cv_detail_PlaneWarper_warp__InputArray__InputArray__InputArray_int_int__OutputArray(instance: *mut c_void, src: *mut c_void, k: *mut c_void, r: *mut c_void, interp_mode: i32, border_mode: i32, dst: *mut c_void) -> cv_return_value_PointWrapper;
+        pub fn cv_detail_PlaneWarper_warp__InputArray__InputArray__InputArray__InputArray_int_int__OutputArray(instance: *mut c_void, src: *mut c_void, k: *mut c_void, r: *mut c_void, t: *mut c_void, interp_mode: i32, border_mode: i32, dst: *mut c_void) -> cv_return_value_PointWrapper;
+        pub fn cv_detail_PlaneWarper_warpRoi_Size__InputArray__InputArray(instance: *mut c_void, src_size: core::Size, k: *mut c_void, r: *mut c_void) -> cv_return_value_RectWrapper;
+        pub fn cv_detail_PlaneWarper_warpRoi_Size__InputArray__InputArray__InputArray(instance: *mut c_void, src_size: core::Size, k: *mut c_void, r: *mut c_void, t: *mut c_void) -> cv_return_value_RectWrapper;
+        pub fn cv_Detail_PlaneWarper_delete(ptr : *mut c_void);
+        pub fn cv_detail_PlaneWarper_PlaneWarper_float(scale: f32) -> cv_return_value_void_X;
+        pub fn cv_detail_RotationWarper_warpPoint_Point2f__InputArray__InputArray(instance: *mut c_void, pt: core::Point2f, k: *mut c_void, r: *mut c_void) -> cv_return_value_Point2fWrapper;
+        pub fn cv_detail_RotationWarper_buildMaps_Size__InputArray__InputArray__OutputArray__OutputArray(instance: *mut c_void, src_size: core::Size, k: *mut c_void, r: *mut c_void, xmap: *mut c_void, ymap: *mut c_void) -> cv_return_value_RectWrapper;
+        pub fn cv_detail_RotationWarper_warp__InputArray__InputArray__InputArray_int_int__OutputArray(instance: *mut c_void, src: *mut c_void, k: *mut c_void, r: *mut c_void, interp_mode: i32, border_mode: i32, dst: *mut c_void) -> cv_return_value_PointWrapper;
+        pub fn cv_detail_RotationWarper_warpBackward__InputArray__InputArray__InputArray_int_int_Size__OutputArray(instance: *mut c_void, src: *mut c_void, k: *mut c_void, r: *mut c_void, interp_mode: i32, border_mode: i32, dst_size: core::Size, dst: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_RotationWarper_warpRoi_Size__InputArray__InputArray(instance: *mut c_void, src_size: core::Size, k: *mut c_void, r: *mut c_void) -> cv_return_value_RectWrapper;
+        pub fn cv_detail_RotationWarper_getScale_const(instance: *const c_void) -> cv_return_value_float;
+        pub fn cv_detail_RotationWarper_setScale_float(instance: *mut c_void, unnamed_arg: f32) -> cv_return_value_void;
+        pub fn cv_detail_SeamFinder_find_VectorOfUMat_VectorOfPoint_VectorOfUMat(instance: *mut c_void, src: *mut c_void, corners: *mut c_void, masks: *mut c_void) -> cv_return_value_void;
+        pub fn cv_Detail_SphericalPortraitWarper_delete(ptr : *mut c_void);
+        pub fn cv_detail_SphericalPortraitWarper_SphericalPortraitWarper_float(scale: f32) -> cv_return_value_void_X;
+        pub fn cv_Detail_SphericalWarper_delete(ptr : *mut c_void);
+        pub fn cv_detail_SphericalWarper_SphericalWarper_float(scale: f32) -> cv_return_value_void_X;
+        pub fn cv_detail_SphericalWarper_buildMaps_Size__InputArray__InputArray__OutputArray__OutputArray(instance: *mut c_void, src_size: core::Size, k: *mut c_void, r: *mut c_void, xmap: *mut c_void, ymap: *mut c_void) -> cv_return_value_RectWrapper;
+        pub fn cv_detail_SphericalWarper_warp__InputArray__InputArray__InputArray_int_int__OutputArray(instance: *mut c_void, src: *mut c_void, k: *mut c_void, r: *mut c_void, interp_mode: i32, border_mode: i32, dst: *mut c_void) -> cv_return_value_PointWrapper;
+        pub fn cv_Detail_StereographicWarper_delete(ptr : *mut c_void);
+        pub fn cv_detail_StereographicWarper_StereographicWarper_float(scale: f32) -> cv_return_value_void_X;
+        pub fn cv_Detail_SurfFeaturesFinder_delete(ptr : *mut c_void);
+        pub fn cv_detail_SurfFeaturesFinder_SurfFeaturesFinder_double_int_int_int_int(hess_thresh: f64, num_octaves: i32, num_layers: i32, num_octaves_descr: i32, num_layers_descr: i32) -> cv_return_value_void_X;
+        pub fn cv_detail_Timelapser_initialize_VectorOfPoint_VectorOfSize(instance: *mut c_void, corners: *mut c_void, sizes: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_Timelapser_process__InputArray__InputArray_Point(instance: *mut c_void, img: *mut c_void, mask: *mut c_void, tl: core::Point) -> cv_return_value_void;
+        pub fn cv_detail_Timelapser_getDst(instance: *mut c_void) -> cv_return_value_void_X;
+        pub fn cv_Detail_Timelapser_delete(ptr : *mut c_void);
+        pub fn cv_detail_Timelapser_createDefault_int(_type: i32) -> cv_return_value_void_X;
+        pub fn cv_Detail_TimelapserCrop_delete(ptr : *mut c_void);
+        pub fn cv_detail_TimelapserCrop_initialize_VectorOfPoint_VectorOfSize(instance: *mut c_void, corners: *mut c_void, sizes: *mut c_void) -> cv_return_value_void;
+        pub fn cv_Detail_TransverseMercatorWarper_delete(ptr : *mut c_void);
+        pub fn cv_detail_TransverseMercatorWarper_TransverseMercatorWarper_float(scale: f32) -> cv_return_value_void_X;
+        pub fn cv_Detail_VoronoiSeamFinder_delete(ptr : *mut c_void);
+        pub fn cv_detail_VoronoiSeamFinder_find_VectorOfUMat_VectorOfPoint_VectorOfUMat(instance: *mut c_void, src: *mut c_void, corners: *mut c_void, masks: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_VoronoiSeamFinder_find_VectorOfSize_VectorOfPoint_VectorOfUMat(instance: *mut c_void, size: *mut c_void, corners: *mut c_void, masks: *mut c_void) -> cv_return_value_void;
+        pub fn cv_detail_VoronoiSeamFinder_VoronoiSeamFinder() -> cv_return_value_void_X;
     
     }
 }
@@ -3027,6 +3940,124 @@ mod superres_sys {
 }
 pub use superres_sys::*;
 
+#[cfg(feature = "contrib")]
+mod surface_matching_sys {
+    use super::*;
+
+    extern "C" {
+        pub fn cv_ppf_match_3d_addNoisePC_Mat_double(pc: *mut c_void, scale: f64) -> cv_return_value_void_X;
+        pub fn cv_ppf_match_3d_loadPLYSimple_const_char_X_int(file_name: *const c_char, with_normals: i32) -> cv_return_value_void_X;
+        pub fn cv_ppf_match_3d_normalizePCCoeff_Mat_float_float_X_float_X_float_X_float_X_float_X(pc: *mut c_void, scale: f32, cx: *mut f32, cy: *mut f32, cz: *mut f32, min_val: *mut f32, max_val: *mut f32) -> cv_return_value_void_X;
+        pub fn cv_ppf_match_3d_normalize_pc_Mat_float(pc: *mut c_void, scale: f32) -> cv_return_value_void_X;
+        pub fn cv_ppf_match_3d_samplePCUniformInd_Mat_int_VectorOfint(pc: *mut c_void, sample_step: i32, indices: *mut c_void) -> cv_return_value_void_X;
+        pub fn cv_ppf_match_3d_samplePCUniform_Mat_int(pc: *mut c_void, sample_step: i32) -> cv_return_value_void_X;
+        pub fn cv_ppf_match_3d_transPCCoeff_Mat_float_float_float_float_float_float(pc: *mut c_void, scale: f32, cx: f32, cy: f32, cz: f32, min_val: f32, max_val: f32) -> cv_return_value_void_X;
+        pub fn cv_ppf_match_3d_writePLYVisibleNormals_Mat_const_char_X(pc: *mut c_void, file_name: *const c_char) -> cv_return_value_void;
+        pub fn cv_ppf_match_3d_writePLY_Mat_const_char_X(pc: *mut c_void, file_name: *const c_char) -> cv_return_value_void;
+        pub fn cv_ICP_delete(ptr : *mut c_void);
+        pub fn cv_ppf_match_3d_ICP_ICP() -> cv_return_value_void_X;
+        pub fn cv_ppf_match_3d_ICP_registerModelToScene_Mat_Mat_VectorOfPtrOfPose3D(instance: *mut c_void, src_pc: *mut c_void, dst_pc: *mut c_void, poses: *mut c_void) -> cv_return_value_int;
+        pub fn cv_PPF3DDetector_delete(ptr : *mut c_void);
+        pub fn cv_ppf_match_3d_PPF3DDetector_PPF3DDetector() -> cv_return_value_void_X;
+        pub fn cv_ppf_match_3d_PPF3DDetector_PPF3DDetector_double_double_double(relative_sampling_step: f64, relative_distance_step: f64, num_angles: f64) -> cv_return_value_void_X;
+        pub fn cv_ppf_match_3d_PPF3DDetector_setSearchParams_double_double_bool(instance: *mut c_void, position_threshold: f64, rotation_threshold: f64, use_weighted_clustering: bool) -> cv_return_value_void;
+        pub fn cv_ppf_match_3d_PPF3DDetector_trainModel_Mat(instance: *mut c_void, model: *mut c_void) -> cv_return_value_void;
+        pub fn cv_ppf_match_3d_PPF3DDetector_match_Mat_VectorOfPtrOfPose3D_double_double(instance: *mut c_void, scene: *mut c_void, results: *mut c_void, relative_scene_sample_step: f64, relative_scene_distance: f64) -> cv_return_value_void;
+        pub fn cv_ppf_match_3d_PPF3DDetector_read_FileNode(instance: *mut c_void, _fn: *mut c_void) -> cv_return_value_void;
+        pub fn cv_ppf_match_3d_PPF3DDetector_write_const_FileStorage(instance: *const c_void, fs: *mut c_void) -> cv_return_value_void;
+        pub fn cv_Pose3D_delete(ptr : *mut c_void);
+        pub fn cv_ppf_match_3d_Pose3D_alpha_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_ppf_match_3d_Pose3D_set_alpha_double(instance: *mut c_void, val: f64) -> cv_return_value_void;
+        pub fn cv_ppf_match_3d_Pose3D_residual_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_ppf_match_3d_Pose3D_set_residual_double(instance: *mut c_void, val: f64) -> cv_return_value_void;
+        pub fn cv_ppf_match_3d_Pose3D_modelIndex_const(instance: *const c_void) -> cv_return_value_unsigned_int;
+        pub fn cv_ppf_match_3d_Pose3D_set_modelIndex_unsigned_int(instance: *mut c_void, val: u32) -> cv_return_value_void;
+        pub fn cv_ppf_match_3d_Pose3D_numVotes_const(instance: *const c_void) -> cv_return_value_unsigned_int;
+        pub fn cv_ppf_match_3d_Pose3D_set_numVotes_unsigned_int(instance: *mut c_void, val: u32) -> cv_return_value_void;
+        pub fn cv_ppf_match_3d_Pose3D_angle_const(instance: *const c_void) -> cv_return_value_double;
+        pub fn cv_ppf_match_3d_Pose3D_set_angle_double(instance: *mut c_void, val: f64) -> cv_return_value_void;
+        pub fn cv_ppf_match_3d_Pose3D_Pose3D() -> cv_return_value_void_X;
+        pub fn cv_ppf_match_3d_Pose3D_Pose3D_double_unsigned_int_unsigned_int(alpha: f64, model_index: u32, num_votes: u32) -> cv_return_value_void_X;
+        pub fn cv_ppf_match_3d_Pose3D_printPose(instance: *mut c_void) -> cv_return_value_void;
+        pub fn cv_ppf_match_3d_Pose3D_clone(instance: *mut c_void) -> cv_return_value_void_X;
+        pub fn cv_ppf_match_3d_Pose3D_writePose_std_string(instance: *mut c_void, file_name: *const c_char) -> cv_return_value_int;
+        pub fn cv_ppf_match_3d_Pose3D_readPose_std_string(instance: *mut c_void, file_name: *const c_char) -> cv_return_value_int;
+        pub fn cv_PoseCluster3D_delete(ptr : *mut c_void);
+        pub fn cv_ppf_match_3d_PoseCluster3D_PoseCluster3D() -> cv_return_value_void_X;
+        pub fn cv_ppf_match_3d_PoseCluster3D_PoseCluster3D_PtrOfPose3D(new_pose: *mut c_void) -> cv_return_value_void_X;
+        pub fn cv_ppf_match_3d_PoseCluster3D_PoseCluster3D_PtrOfPose3D_int(new_pose: *mut c_void, new_id: i32) -> cv_return_value_void_X;
+        pub fn cv_ppf_match_3d_PoseCluster3D_addPose_PtrOfPose3D(instance: *mut c_void, new_pose: *mut c_void) -> cv_return_value_void;
+        pub fn cv_ppf_match_3d_PoseCluster3D_writePoseCluster_std_string(instance: *mut c_void, file_name: *const c_char) -> cv_return_value_int;
+        pub fn cv_ppf_match_3d_PoseCluster3D_readPoseCluster_std_string(instance: *mut c_void, file_name: *const c_char) -> cv_return_value_int;
+    
+    }
+}
+#[cfg(feature = "contrib")]
+pub use surface_matching_sys::*;
+
+#[cfg(feature = "contrib")]
+mod text_sys {
+    use super::*;
+
+    extern "C" {
+        pub fn cv_text_MSERsToERStats__InputArray_VectorOfVectorOfPoint_VectorOfVectorOfERStat(image: *mut c_void, contours: *mut c_void, regions: *mut c_void) -> cv_return_value_void;
+        pub fn cv_text_computeNMChannels__InputArray__OutputArray_int(_src: *mut c_void, _channels: *mut c_void, _mode: i32) -> cv_return_value_void;
+        pub fn cv_text_createERFilterNM1_PtrOfERFilter_Callback_int_float_float_float_bool_float(cb: *mut c_void, threshold_delta: i32, min_area: f32, max_area: f32, min_probability: f32, non_max_suppression: bool, min_probability_diff: f32) -> cv_return_value_void_X;
+        pub fn cv_text_createERFilterNM2_PtrOfERFilter_Callback_float(cb: *mut c_void, min_probability: f32) -> cv_return_value_void_X;
+        pub fn cv_text_createOCRHMMTransitionsTable_String_VectorOfString(vocabulary: *const c_char, lexicon: *mut c_void) -> cv_return_value_void_X;
+        pub fn cv_text_createOCRHMMTransitionsTable_std_string_VectorOfstd_string__OutputArray(vocabulary: *mut *mut c_char, lexicon: *mut c_void, transition_probabilities_table: *mut c_void) -> cv_return_value_void;
+        pub fn cv_text_detectRegions__InputArray_PtrOfERFilter_PtrOfERFilter_VectorOfVectorOfPoint(image: *mut c_void, er_filter1: *mut c_void, er_filter2: *mut c_void, regions: *mut c_void) -> cv_return_value_void;
+        pub fn cv_text_erGrouping__InputArray__InputArray_VectorOfVectorOfERStat_VectorOfVectorOfVec2i_VectorOfRect_int_std_string_float(img: *mut c_void, channels: *mut c_void, regions: *mut c_void, groups: *mut c_void, groups_rects: *mut c_void, method: i32, filename: *const c_char, min_probablity: f32) -> cv_return_value_void;
+        pub fn cv_text_erGrouping__InputArray__InputArray_VectorOfVectorOfPoint_VectorOfRect_int_String_float(image: *mut c_void, channel: *mut c_void, regions: *mut c_void, groups_rects: *mut c_void, method: i32, filename: *const c_char, min_probablity: f32) -> cv_return_value_void;
+        pub fn cv_text_loadClassifierNM1_String(filename: *const c_char) -> cv_return_value_void_X;
+        pub fn cv_text_loadClassifierNM2_String(filename: *const c_char) -> cv_return_value_void_X;
+        pub fn cv_text_loadOCRBeamSearchClassifierCNN_String(filename: *const c_char) -> cv_return_value_void_X;
+        pub fn cv_text_loadOCRHMMClassifierCNN_String(filename: *const c_char) -> cv_return_value_void_X;
+        pub fn cv_text_loadOCRHMMClassifierNM_String(filename: *const c_char) -> cv_return_value_void_X;
+        pub fn cv_text_BaseOCR_run_Mat_std_string_VectorOfRect_VectorOfstd_string_VectorOffloat_int(instance: *mut c_void, image: *mut c_void, output_text: *mut *mut c_char, component_rects: *mut c_void, component_texts: *mut c_void, component_confidences: *mut c_void, component_level: i32) -> cv_return_value_void;
+        pub fn cv_text_BaseOCR_run_Mat_Mat_std_string_VectorOfRect_VectorOfstd_string_VectorOffloat_int(instance: *mut c_void, image: *mut c_void, mask: *mut c_void, output_text: *mut *mut c_char, component_rects: *mut c_void, component_texts: *mut c_void, component_confidences: *mut c_void, component_level: i32) -> cv_return_value_void;
+        pub fn cv_text_ERFilter_run__InputArray_VectorOfERStat(instance: *mut c_void, image: *mut c_void, regions: *mut c_void) -> cv_return_value_void;
+        pub fn cv_text_ERFilter_setCallback_PtrOfERFilter_Callback(instance: *mut c_void, cb: *mut c_void) -> cv_return_value_void;
+        pub fn cv_text_ERFilter_setThresholdDelta_int(instance: *mut c_void, threshold_delta: i32) -> cv_return_value_void;
+        pub fn cv_text_ERFilter_setMinArea_float(instance: *mut c_void, min_area: f32) -> cv_return_value_void;
+        pub fn cv_text_ERFilter_setMaxArea_float(instance: *mut c_void, max_area: f32) -> cv_return_value_void;
+        pub fn cv_text_ERFilter_setMinProbability_float(instance: *mut c_void, min_probability: f32) -> cv_return_value_void;
+        pub fn cv_text_ERFilter_setMinProbabilityDiff_float(instance: *mut c_void, min_probability_diff: f32) -> cv_return_value_void;
+        pub fn cv_text_ERFilter_setNonMaxSuppression_bool(instance: *mut c_void, non_max_suppression: bool) -> cv_return_value_void;
+        pub fn cv_text_ERFilter_getNumRejected(instance: *mut c_void) -> cv_return_value_int;
+        pub fn cv_text_ERFilter_Callback_eval_ERStat(instance: *mut c_void, stat: *mut c_void) -> cv_return_value_double;
+        pub fn cv_ERStat_delete(ptr : *mut c_void);
+        pub fn cv_text_ERStat_ERStat_int_int_int_int(level: i32, pixel: i32, x: i32, y: i32) -> cv_return_value_void_X;
+        pub fn cv_OCRBeamSearchDecoder_delete(ptr : *mut c_void);
+        pub fn cv_text_OCRBeamSearchDecoder_run_Mat_std_string_VectorOfRect_VectorOfstd_string_VectorOffloat_int(instance: *mut c_void, image: *mut c_void, output_text: *mut *mut c_char, component_rects: *mut c_void, component_texts: *mut c_void, component_confidences: *mut c_void, component_level: i32) -> cv_return_value_void;
+        pub fn cv_text_OCRBeamSearchDecoder_run_Mat_Mat_std_string_VectorOfRect_VectorOfstd_string_VectorOffloat_int(instance: *mut c_void, image: *mut c_void, mask: *mut c_void, output_text: *mut *mut c_char, component_rects: *mut c_void, component_texts: *mut c_void, component_confidences: *mut c_void, component_level: i32) -> cv_return_value_void;
+        pub fn cv_text_OCRBeamSearchDecoder_run__InputArray_int_int(instance: *mut c_void, image: *mut c_void, min_confidence: i32, component_level: i32) -> cv_return_value_char_X;
+        pub fn cv_text_OCRBeamSearchDecoder_run__InputArray__InputArray_int_int(instance: *mut c_void, image: *mut c_void, mask: *mut c_void, min_confidence: i32, component_level: i32) -> cv_return_value_char_X;
+        pub fn cv_text_OCRBeamSearchDecoder_create_PtrOfOCRBeamSearchDecoder_ClassifierCallback_String__InputArray__InputArray_int_int(classifier: *mut c_void, vocabulary: *const c_char, transition_probabilities_table: *mut c_void, emission_probabilities_table: *mut c_void, mode: i32, beam_size: i32) -> cv_return_value_void_X;
+        pub fn cv_OCRBeamSearchDecoder_ClassifierCallback_delete(ptr : *mut c_void);
+        pub fn cv_text_OCRBeamSearchDecoder_ClassifierCallback_eval__InputArray_VectorOfVectorOfdouble_VectorOfint(instance: *mut c_void, image: *mut c_void, recognition_probabilities: *mut c_void, oversegmentation: *mut c_void) -> cv_return_value_void;
+        pub fn cv_text_OCRBeamSearchDecoder_ClassifierCallback_getWindowSize(instance: *mut c_void) -> cv_return_value_int;
+        pub fn cv_text_OCRBeamSearchDecoder_ClassifierCallback_getStepSize(instance: *mut c_void) -> cv_return_value_int;
+        pub fn cv_OCRHMMDecoder_delete(ptr : *mut c_void);
+        pub fn cv_text_OCRHMMDecoder_run_Mat_std_string_VectorOfRect_VectorOfstd_string_VectorOffloat_int(instance: *mut c_void, image: *mut c_void, output_text: *mut *mut c_char, component_rects: *mut c_void, component_texts: *mut c_void, component_confidences: *mut c_void, component_level: i32) -> cv_return_value_void;
+        pub fn cv_text_OCRHMMDecoder_run_Mat_Mat_std_string_VectorOfRect_VectorOfstd_string_VectorOffloat_int(instance: *mut c_void, image: *mut c_void, mask: *mut c_void, output_text: *mut *mut c_char, component_rects: *mut c_void, component_texts: *mut c_void, component_confidences: *mut c_void, component_level: i32) -> cv_return_value_void;
+        pub fn cv_text_OCRHMMDecoder_run__InputArray_int_int(instance: *mut c_void, image: *mut c_void, min_confidence: i32, component_level: i32) -> cv_return_value_char_X;
+        pub fn cv_text_OCRHMMDecoder_run__InputArray__InputArray_int_int(instance: *mut c_void, image: *mut c_void, mask: *mut c_void, min_confidence: i32, component_level: i32) -> cv_return_value_char_X;
+        pub fn cv_text_OCRHMMDecoder_create_PtrOfOCRHMMDecoder_ClassifierCallback_String__InputArray__InputArray_int(classifier: *mut c_void, vocabulary: *const c_char, transition_probabilities_table: *mut c_void, emission_probabilities_table: *mut c_void, mode: i32) -> cv_return_value_void_X;
+        pub fn cv_OCRHMMDecoder_ClassifierCallback_delete(ptr : *mut c_void);
+        pub fn cv_text_OCRHMMDecoder_ClassifierCallback_eval__InputArray_VectorOfint_VectorOfdouble(instance: *mut c_void, image: *mut c_void, out_class: *mut c_void, out_confidence: *mut c_void) -> cv_return_value_void;
+        pub fn cv_text_OCRTesseract_run_Mat_std_string_VectorOfRect_VectorOfstd_string_VectorOffloat_int(instance: *mut c_void, image: *mut c_void, output_text: *mut *mut c_char, component_rects: *mut c_void, component_texts: *mut c_void, component_confidences: *mut c_void, component_level: i32) -> cv_return_value_void;
+        pub fn cv_text_OCRTesseract_run_Mat_Mat_std_string_VectorOfRect_VectorOfstd_string_VectorOffloat_int(instance: *mut c_void, image: *mut c_void, mask: *mut c_void, output_text: *mut *mut c_char, component_rects: *mut c_void, component_texts: *mut c_void, component_confidences: *mut c_void, component_level: i32) -> cv_return_value_void;
+        pub fn cv_text_OCRTesseract_run__InputArray_int_int(instance: *mut c_void, image: *mut c_void, min_confidence: i32, component_level: i32) -> cv_return_value_char_X;
+        pub fn cv_text_OCRTesseract_run__InputArray__InputArray_int_int(instance: *mut c_void, image: *mut c_void, mask: *mut c_void, min_confidence: i32, component_level: i32) -> cv_return_value_char_X;
+        pub fn cv_text_OCRTesseract_setWhiteList_String(instance: *mut c_void, char_whitelist: *const c_char) -> cv_return_value_void;
+        pub fn cv_text_OCRTesseract_create_const_char_X_const_char_X_const_char_X_int_int(datapath: *const c_char, language: *const c_char, char_whitelist: *const c_char, oem: i32, psmode: i32) -> cv_return_value_void_X;
+    
+    }
+}
+#[cfg(feature = "contrib")]
+pub use text_sys::*;
+
 mod video_sys {
     use super::*;
 
@@ -3338,6 +4369,8 @@ mod videostab_sys {
         pub fn cv_PyrLkOptFlowEstimatorBase_delete(ptr : *mut c_void);
         pub fn cv_videostab_PyrLkOptFlowEstimatorBase_PyrLkOptFlowEstimatorBase() -> cv_return_value_void_X;
         pub fn cv_RansacParams_delete(ptr : *mut c_void);
+        pub fn cv_videostab_RansacParams_RansacParams() -> cv_return_value_void_X;
+        pub fn cv_videostab_RansacParams_RansacParams_int_float_float_float(size: i32, thresh: f32, eps: f32, prob: f32) -> cv_return_value_void_X;
         pub fn cv_videostab_RansacParams_niters_const(instance: *const c_void) -> cv_return_value_int;
         pub fn cv_SparsePyrLkOptFlowEstimator_delete(ptr : *mut c_void);
         pub fn cv_videostab_SparsePyrLkOptFlowEstimator_run__InputArray__InputArray__InputArray__InputOutputArray__OutputArray__OutputArray(instance: *mut c_void, frame0: *mut c_void, frame1: *mut c_void, points0: *mut c_void, points1: *mut c_void, status: *mut c_void, errors: *mut c_void) -> cv_return_value_void;
@@ -3366,6 +4399,8 @@ mod videostab_sys {
         pub fn cv_videostab_TranslationBasedLocalOutlierRejector_TranslationBasedLocalOutlierRejector() -> cv_return_value_void_X;
         pub fn cv_videostab_TranslationBasedLocalOutlierRejector_setCellSize_Size(instance: *mut c_void, val: core::Size) -> cv_return_value_void;
         pub fn cv_videostab_TranslationBasedLocalOutlierRejector_cellSize_const(instance: *const c_void) -> cv_return_value_SizeWrapper;
+        pub fn cv_videostab_TranslationBasedLocalOutlierRejector_setRansacParams_RansacParams(instance: *mut c_void, val: *mut c_void) -> cv_return_value_void;
+        pub fn cv_videostab_TranslationBasedLocalOutlierRejector_ransacParams_const(instance: *const c_void) -> cv_return_value_void_X;
         pub fn cv_videostab_TranslationBasedLocalOutlierRejector_process_Size__InputArray__InputArray__OutputArray(instance: *mut c_void, frame_size: core::Size, points0: *mut c_void, points1: *mut c_void, mask: *mut c_void) -> cv_return_value_void;
         pub fn cv_TwoPassStabilizer_delete(ptr : *mut c_void);
         pub fn cv_videostab_TwoPassStabilizer_TwoPassStabilizer() -> cv_return_value_void_X;
@@ -1089,3 +1089,4 @@ pub const CAP_OPENNI_GENERATORS_MASK: i32 = 0xe0000000; // -536870912
 pub const CAP_OPENNI_IMAGE_GENERATOR_OUTPUT_MODE: i32 = 0x40000064; // 1073741924
 pub const CAP_OPENNI_IMAGE_GENERATOR_PRESENT: i32 = 0x4000006d; // 1073741933
 pub const CAP_OPENNI_IR_GENERATOR_PRESENT: i32 = 0x2000006d; // 536871021
+pub use crate::manual::videoio::*;
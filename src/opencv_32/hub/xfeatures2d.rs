@@ -88,6 +88,10 @@ impl core::AlgorithmTrait for BoostDesc {
     #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
 }
 
+impl crate::features2d::Feature2DTrait for BoostDesc {
+    #[inline(always)] fn as_raw_Feature2D(&self) -> *mut c_void { self.ptr }
+}
+
 impl BoostDesc {
     ///
     /// ## C++ default parameters
@@ -130,6 +134,10 @@ impl core::AlgorithmTrait for BriefDescriptorExtractor {
     #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
 }
 
+impl crate::features2d::Feature2DTrait for BriefDescriptorExtractor {
+    #[inline(always)] fn as_raw_Feature2D(&self) -> *mut c_void { self.ptr }
+}
+
 impl BriefDescriptorExtractor {
     ///
     /// ## C++ default parameters
@@ -289,6 +297,10 @@ impl core::AlgorithmTrait for FREAK {
     #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
 }
 
+impl crate::features2d::Feature2DTrait for FREAK {
+    #[inline(always)] fn as_raw_Feature2D(&self) -> *mut c_void { self.ptr }
+}
+
 impl FREAK {
     /// ## Parameters
     /// * orientationNormalized: Enable orientation normalization.
@@ -349,6 +361,10 @@ impl core::AlgorithmTrait for LATCH {
     #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
 }
 
+impl crate::features2d::Feature2DTrait for LATCH {
+    #[inline(always)] fn as_raw_Feature2D(&self) -> *mut c_void { self.ptr }
+}
+
 impl LATCH {
     ///
     /// ## C++ default parameters
@@ -393,6 +409,10 @@ impl core::AlgorithmTrait for LUCID {
     #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
 }
 
+impl crate::features2d::Feature2DTrait for LUCID {
+    #[inline(always)] fn as_raw_Feature2D(&self) -> *mut c_void { self.ptr }
+}
+
 impl LUCID {
     /// ## Parameters
     /// * lucid_kernel: kernel for descriptor construction, where 1=3x3, 2=5x5, 3=7x7 and so forth
@@ -442,6 +462,10 @@ impl core::AlgorithmTrait for MSDDetector {
     #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
 }
 
+impl crate::features2d::Feature2DTrait for MSDDetector {
+    #[inline(always)] fn as_raw_Feature2D(&self) -> *mut c_void { self.ptr }
+}
+
 impl MSDDetector {
     ///
     /// ## C++ default parameters
@@ -613,7 +637,7 @@ pub trait PCTSignatures: core::AlgorithmTrait {
     /// Initial samples taken from the image.
     ///       These sampled features become the input for clustering.
     fn get_sampling_points(&self) -> Result<types::VectorOfPoint2f> {
-        unsafe { sys::cv_xfeatures2d_PCTSignatures_getSamplingPoints_const(self.as_raw_PCTSignatures()) }.into_result().map(|ptr| types::VectorOfPoint2f { ptr })
+        unsafe { sys::cv_xfeatures2d_PCTSignatures_getSamplingPoints_const(self.as_raw_PCTSignatures()) }.into_result().map(|ptr| unsafe { types::VectorOfPoint2f::from_raw_ptr(ptr) })
     }
     
     /// Weights (multiplicative constants) that linearly stretch individual axes of the feature space.
@@ -697,7 +721,7 @@ pub trait PCTSignatures: core::AlgorithmTrait {
     
     /// Initial seeds (initial number of clusters) for the k-means algorithm.
     fn get_init_seed_indexes(&self) -> Result<types::VectorOfint> {
-        unsafe { sys::cv_xfeatures2d_PCTSignatures_getInitSeedIndexes_const(self.as_raw_PCTSignatures()) }.into_result().map(|ptr| types::VectorOfint { ptr })
+        unsafe { sys::cv_xfeatures2d_PCTSignatures_getInitSeedIndexes_const(self.as_raw_PCTSignatures()) }.into_result().map(|ptr| unsafe { types::VectorOfint::from_raw_ptr(ptr) })
     }
     
     /// Initial seed indexes for the k-means algorithm.
@@ -946,6 +970,10 @@ impl core::AlgorithmTrait for SIFT {
     #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
 }
 
+impl crate::features2d::Feature2DTrait for SIFT {
+    #[inline(always)] fn as_raw_Feature2D(&self) -> *mut c_void { self.ptr }
+}
+
 impl SIFT {
     /// ## Parameters
     /// * nfeatures: The number of best features to retain. The features are ranked by their scores
@@ -1098,6 +1126,10 @@ impl core::AlgorithmTrait for StarDetector {
     #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
 }
 
+impl crate::features2d::Feature2DTrait for StarDetector {
+    #[inline(always)] fn as_raw_Feature2D(&self) -> *mut c_void { self.ptr }
+}
+
 impl StarDetector {
     /// the full constructor
     ///
@@ -454,7 +454,7 @@ pub trait HDF5 {
     /// * dims_flag: HDF5::H5_GETDIMS
     fn dsgetsize(&self, dslabel: &str, dims_flag: i32) -> Result<types::VectorOfint> {
         string_arg!(dslabel);
-        unsafe { sys::cv_hdf_HDF5_dsgetsize_const_String_int(self.as_raw_HDF5(), dslabel.as_ptr(), dims_flag) }.into_result().map(|ptr| types::VectorOfint { ptr })
+        unsafe { sys::cv_hdf_HDF5_dsgetsize_const_String_int(self.as_raw_HDF5(), dslabel.as_ptr(), dims_flag) }.into_result().map(|ptr| unsafe { types::VectorOfint::from_raw_ptr(ptr) })
     }
     
     /// Fetch dataset type
@@ -252,3 +252,4 @@ pub fn imwrite(filename: &str, img: &dyn core::ToInputArray, params: &types::Vec
 }
 
 pub const IMWRITE_EXR_TYPE: i32 = 0x30; // 48
+pub use crate::manual::imgcodecs::*;
@@ -0,0 +1,973 @@
+//! # Optical Flow Algorithms
+//!
+//! Dense optical flow algorithms compute motion for each point:
+//!
+//! - cv::optflow::calcOpticalFlowSF
+//! - cv::optflow::createOptFlow_DeepFlow
+//!
+//! Motion templates is alternative technique for detecting motion and computing its direction.
+//! See samples/motempl.py.
+//!
+//! - cv::motempl::updateMotionHistory
+//! - cv::motempl::calcMotionGradient
+//! - cv::motempl::calcGlobalOrientation
+//! - cv::motempl::segmentMotion
+//!
+//! Functions reading and writing .flo files in "Middlebury" format, see: <http://vision.middlebury.edu/flow/code/flow-code/README.txt>
+//!
+//! - cv::optflow::readOpticalFlow
+//! - cv::optflow::writeOpticalFlow
+use crate::{mod_prelude::*, core, sys, types};
+use crate::core::{_InputArrayTrait, _OutputArrayTrait};
+
+pub const DISOpticalFlow_PRESET_FAST: i32 = 1;
+pub const DISOpticalFlow_PRESET_MEDIUM: i32 = 2;
+pub const DISOpticalFlow_PRESET_ULTRAFAST: i32 = 0;
+/// Better quality but slow
+pub const GPC_DESCRIPTOR_DCT: i32 = 0;
+/// Worse quality but much faster
+pub const GPC_DESCRIPTOR_WHT: i32 = 0+1;
+
+/// Descriptor types for the Global Patch Collider.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum GPCDescType {
+    /// Better quality but slow
+    GPC_DESCRIPTOR_DCT = GPC_DESCRIPTOR_DCT as isize,
+    /// Worse quality but much faster
+    GPC_DESCRIPTOR_WHT = GPC_DESCRIPTOR_WHT as isize,
+}
+
+/// Calculates a global motion orientation in a selected region.
+///
+/// ## Parameters
+/// * orientation: Motion gradient orientation image calculated by the function calcMotionGradient
+/// * mask: Mask image. It may be a conjunction of a valid gradient mask, also calculated by
+/// calcMotionGradient , and the mask of a region whose direction needs to be calculated.
+/// * mhi: Motion history image calculated by updateMotionHistory .
+/// * timestamp: Timestamp passed to updateMotionHistory .
+/// * duration: Maximum duration of a motion track in milliseconds, passed to updateMotionHistory
+///
+/// The function calculates an average motion direction in the selected region and returns the angle
+/// between 0 degrees and 360 degrees. The average direction is computed from the weighted orientation
+/// histogram, where a recent motion has a larger weight and the motion occurred in the past has a
+/// smaller weight, as recorded in mhi .
+pub fn calc_global_orientation(orientation: &dyn core::ToInputArray, mask: &dyn core::ToInputArray, mhi: &dyn core::ToInputArray, timestamp: f64, duration: f64) -> Result<f64> {
+    input_array_arg!(orientation);
+    input_array_arg!(mask);
+    input_array_arg!(mhi);
+    unsafe { sys::cv_motempl_calcGlobalOrientation__InputArray__InputArray__InputArray_double_double(orientation.as_raw__InputArray(), mask.as_raw__InputArray(), mhi.as_raw__InputArray(), timestamp, duration) }.into_result()
+}
+
+/// Calculates a gradient orientation of a motion history image.
+///
+/// ## Parameters
+/// * mhi: Motion history single-channel floating-point image.
+/// * mask: Output mask image that has the type CV_8UC1 and the same size as mhi . Its non-zero
+/// elements mark pixels where the motion gradient data is correct.
+/// * orientation: Output motion gradient orientation image that has the same type and the same
+/// size as mhi . Each pixel of the image is a motion orientation, from 0 to 360 degrees.
+/// * delta1: Minimal (or maximal) allowed difference between mhi values within a pixel
+/// neighborhood.
+/// * delta2: Maximal (or minimal) allowed difference between mhi values within a pixel
+/// neighborhood. That is, the function finds the minimum ( ![inline formula](https://latex.codecogs.com/png.latex?m%28x%2Cy%29) ) and maximum ( ![inline formula](https://latex.codecogs.com/png.latex?M%28x%2Cy%29) ) mhi
+/// values over ![inline formula](https://latex.codecogs.com/png.latex?3%20%5Ctimes%203) neighborhood of each pixel and marks the motion orientation at ![inline formula](https://latex.codecogs.com/png.latex?%28x%2C%20y%29)
+/// as valid only if
+/// ![block formula](https://latex.codecogs.com/png.latex?%5Cmin%20%28%20%5Ctexttt%7Bdelta1%7D%20%20%2C%20%20%5Ctexttt%7Bdelta2%7D%20%20%29%20%20%5Cle%20%20M%28x%2Cy%29-m%28x%2Cy%29%20%20%5Cle%20%20%20%5Cmax%20%28%20%5Ctexttt%7Bdelta1%7D%20%20%2C%20%5Ctexttt%7Bdelta2%7D%20%29.)
+/// * apertureSize: Aperture size of the Sobel operator.
+///
+/// The function calculates a gradient orientation at each pixel ![inline formula](https://latex.codecogs.com/png.latex?%28x%2C%20y%29) as:
+///
+/// ![block formula](https://latex.codecogs.com/png.latex?%5Ctexttt%7Borientation%7D%20%28x%2Cy%29%3D%20%5Carctan%7B%5Cfrac%7Bd%5Ctexttt%7Bmhi%7D%2Fdy%7D%7Bd%5Ctexttt%7Bmhi%7D%2Fdx%7D%7D)
+///
+/// In fact, fastAtan2 and phase are used so that the computed angle is measured in degrees and covers
+/// the full range 0..360. Also, the mask is filled to indicate pixels where the computed angle is
+/// valid.
+///
+///
+/// Note:
+/// *   (Python) An example on how to perform a motion template technique can be found at
+/// opencv_source_code/samples/python2/motempl.py
+///
+/// ## C++ default parameters
+/// * aperture_size: 3
+pub fn calc_motion_gradient(mhi: &dyn core::ToInputArray, mask: &mut dyn core::ToOutputArray, orientation: &mut dyn core::ToOutputArray, delta1: f64, delta2: f64, aperture_size: i32) -> Result<()> {
+    input_array_arg!(mhi);
+    output_array_arg!(mask);
+    output_array_arg!(orientation);
+    unsafe { sys::cv_motempl_calcMotionGradient__InputArray__OutputArray__OutputArray_double_double_int(mhi.as_raw__InputArray(), mask.as_raw__OutputArray(), orientation.as_raw__OutputArray(), delta1, delta2, aperture_size) }.into_result()
+}
+
+/// Splits a motion history image into a few parts corresponding to separate independent motions (for
+/// example, left hand, right hand).
+///
+/// ## Parameters
+/// * mhi: Motion history image.
+/// * segmask: Image where the found mask should be stored, single-channel, 32-bit floating-point.
+/// * boundingRects: Vector containing ROIs of motion connected components.
+/// * timestamp: Current time in milliseconds or other units.
+/// * segThresh: Segmentation threshold that is recommended to be equal to the interval between
+/// motion history "steps" or greater.
+///
+/// The function finds all of the motion segments and marks them in segmask with individual values
+/// (1,2,...). It also computes a vector with ROIs of motion connected components. After that the motion
+/// direction for every component can be calculated with calcGlobalOrientation using the extracted mask
+/// of the particular component.
+pub fn segment_motion(mhi: &dyn core::ToInputArray, segmask: &mut dyn core::ToOutputArray, bounding_rects: &mut types::VectorOfRect, timestamp: f64, seg_thresh: f64) -> Result<()> {
+    input_array_arg!(mhi);
+    output_array_arg!(segmask);
+    unsafe { sys::cv_motempl_segmentMotion__InputArray__OutputArray_VectorOfRect_double_double(mhi.as_raw__InputArray(), segmask.as_raw__OutputArray(), bounding_rects.as_raw_VectorOfRect(), timestamp, seg_thresh) }.into_result()
+}
+
+/// Updates the motion history image by a moving silhouette.
+///
+/// ## Parameters
+/// * silhouette: Silhouette mask that has non-zero pixels where the motion occurs.
+/// * mhi: Motion history image that is updated by the function (single-channel, 32-bit
+/// floating-point).
+/// * timestamp: Current time in milliseconds or other units.
+/// * duration: Maximal duration of the motion track in the same units as timestamp .
+///
+/// The function updates the motion history image as follows:
+///
+/// ![block formula](https://latex.codecogs.com/png.latex?%5Ctexttt%7Bmhi%7D%20%28x%2Cy%29%3D%20%5Cforkthree%7B%5Ctexttt%7Btimestamp%7D%7D%7Bif%20%5C%28%5Ctexttt%7Bsilhouette%7D%28x%2Cy%29%20%5Cne%200%5C%29%7D%7B0%7D%7Bif%20%5C%28%5Ctexttt%7Bsilhouette%7D%28x%2Cy%29%20%3D%200%5C%29%20and%20%5C%28%5Ctexttt%7Bmhi%7D%20%3C%20%28%5Ctexttt%7Btimestamp%7D%20-%20%5Ctexttt%7Bduration%7D%29%5C%29%7D%7B%5Ctexttt%7Bmhi%7D%28x%2Cy%29%7D%7Botherwise%7D)
+///
+/// That is, MHI pixels where the motion occurs are set to the current timestamp , while the pixels
+/// where the motion happened last time a long time ago are cleared.
+///
+/// The function, together with calcMotionGradient and calcGlobalOrientation , implements a motion
+/// templates technique described in [Davis97](https://docs.opencv.org/3.4.9/d0/de3/citelist.html#CITEREF_Davis97) and [Bradski00](https://docs.opencv.org/3.4.9/d0/de3/citelist.html#CITEREF_Bradski00) .
+pub fn update_motion_history(silhouette: &dyn core::ToInputArray, mhi: &mut dyn core::ToInputOutputArray, timestamp: f64, duration: f64) -> Result<()> {
+    input_array_arg!(silhouette);
+    input_output_array_arg!(mhi);
+    unsafe { sys::cv_motempl_updateMotionHistory__InputArray__InputOutputArray_double_double(silhouette.as_raw__InputArray(), mhi.as_raw__InputOutputArray(), timestamp, duration) }.into_result()
+}
+
+/// Calculate an optical flow using "SimpleFlow" algorithm.
+///
+/// ## Parameters
+/// * from: First 8-bit 3-channel image.
+/// * to: Second 8-bit 3-channel image of the same size as prev
+/// * flow: computed flow image that has the same size as prev and type CV_32FC2
+/// * layers: Number of layers
+/// * averaging_block_size: Size of block through which we sum up when calculate cost function
+/// for pixel
+/// * max_flow: maximal flow that we search at each level
+/// * sigma_dist: vector smooth spatial sigma parameter
+/// * sigma_color: vector smooth color sigma parameter
+/// * postprocess_window: window size for postprocess cross bilateral filter
+/// * sigma_dist_fix: spatial sigma for postprocess cross bilateralf filter
+/// * sigma_color_fix: color sigma for postprocess cross bilateral filter
+/// * occ_thr: threshold for detecting occlusions
+/// * upscale_averaging_radius: window size for bilateral upscale operation
+/// * upscale_sigma_dist: spatial sigma for bilateral upscale operation
+/// * upscale_sigma_color: color sigma for bilateral upscale operation
+/// * speed_up_thr: threshold to detect point with irregular flow - where flow should be
+/// recalculated after upscale
+///
+/// See [Tao2012](https://docs.opencv.org/3.4.9/d0/de3/citelist.html#CITEREF_Tao2012) . And site of project - <http://graphics.berkeley.edu/papers/Tao-SAN-2012-05/>.
+///
+///
+/// Note:
+/// *   An example using the simpleFlow algorithm can be found at samples/simpleflow_demo.cpp
+///
+/// ## Overloaded parameters
+pub fn calc_optical_flow_sf(from: &dyn core::ToInputArray, to: &dyn core::ToInputArray, flow: &mut dyn core::ToOutputArray, layers: i32, averaging_block_size: i32, max_flow: i32) -> Result<()> {
+    input_array_arg!(from);
+    input_array_arg!(to);
+    output_array_arg!(flow);
+    unsafe { sys::cv_optflow_calcOpticalFlowSF__InputArray__InputArray__OutputArray_int_int_int(from.as_raw__InputArray(), to.as_raw__InputArray(), flow.as_raw__OutputArray(), layers, averaging_block_size, max_flow) }.into_result()
+}
+
+/// Calculate an optical flow using "SimpleFlow" algorithm.
+///
+/// ## Parameters
+/// * from: First 8-bit 3-channel image.
+/// * to: Second 8-bit 3-channel image of the same size as prev
+/// * flow: computed flow image that has the same size as prev and type CV_32FC2
+/// * layers: Number of layers
+/// * averaging_block_size: Size of block through which we sum up when calculate cost function
+/// for pixel
+/// * max_flow: maximal flow that we search at each level
+/// * sigma_dist: vector smooth spatial sigma parameter
+/// * sigma_color: vector smooth color sigma parameter
+/// * postprocess_window: window size for postprocess cross bilateral filter
+/// * sigma_dist_fix: spatial sigma for postprocess cross bilateralf filter
+/// * sigma_color_fix: color sigma for postprocess cross bilateral filter
+/// * occ_thr: threshold for detecting occlusions
+/// * upscale_averaging_radius: window size for bilateral upscale operation
+/// * upscale_sigma_dist: spatial sigma for bilateral upscale operation
+/// * upscale_sigma_color: color sigma for bilateral upscale operation
+/// * speed_up_thr: threshold to detect point with irregular flow - where flow should be
+/// recalculated after upscale
+///
+/// See [Tao2012](https://docs.opencv.org/3.4.9/d0/de3/citelist.html#CITEREF_Tao2012) . And site of project - <http://graphics.berkeley.edu/papers/Tao-SAN-2012-05/>.
+///
+///
+/// Note:
+/// *   An example using the simpleFlow algorithm can be found at samples/simpleflow_demo.cpp
+pub fn calc_optical_flow_sf_1(from: &dyn core::ToInputArray, to: &dyn core::ToInputArray, flow: &mut dyn core::ToOutputArray, layers: i32, averaging_block_size: i32, max_flow: i32, sigma_dist: f64, sigma_color: f64, postprocess_window: i32, sigma_dist_fix: f64, sigma_color_fix: f64, occ_thr: f64, upscale_averaging_radius: i32, upscale_sigma_dist: f64, upscale_sigma_color: f64, speed_up_thr: f64) -> Result<()> {
+    input_array_arg!(from);
+    input_array_arg!(to);
+    output_array_arg!(flow);
+    unsafe { sys::cv_optflow_calcOpticalFlowSF__InputArray__InputArray__OutputArray_int_int_int_double_double_int_double_double_double_int_double_double_double(from.as_raw__InputArray(), to.as_raw__InputArray(), flow.as_raw__OutputArray(), layers, averaging_block_size, max_flow, sigma_dist, sigma_color, postprocess_window, sigma_dist_fix, sigma_color_fix, occ_thr, upscale_averaging_radius, upscale_sigma_dist, upscale_sigma_color, speed_up_thr) }.into_result()
+}
+
+/// Fast dense optical flow based on PyrLK sparse matches interpolation.
+///
+/// ## Parameters
+/// * from: first 8-bit 3-channel or 1-channel image.
+/// * to: second 8-bit 3-channel or 1-channel image of the same size as from
+/// * flow: computed flow image that has the same size as from and CV_32FC2 type
+/// * grid_step: stride used in sparse match computation. Lower values usually
+/// result in higher quality but slow down the algorithm.
+/// * k: number of nearest-neighbor matches considered, when fitting a locally affine
+/// model. Lower values can make the algorithm noticeably faster at the cost of
+/// some quality degradation.
+/// * sigma: parameter defining how fast the weights decrease in the locally-weighted affine
+/// fitting. Higher values can help preserve fine details, lower values can help to get rid
+/// of the noise in the output flow.
+/// * use_post_proc: defines whether the ximgproc::fastGlobalSmootherFilter() is used
+/// for post-processing after interpolation
+/// * fgs_lambda: see the respective parameter of the ximgproc::fastGlobalSmootherFilter()
+/// * fgs_sigma: see the respective parameter of the ximgproc::fastGlobalSmootherFilter()
+///
+/// ## C++ default parameters
+/// * grid_step: 8
+/// * k: 128
+/// * sigma: 0.05f
+/// * use_post_proc: true
+/// * fgs_lambda: 500.0f
+/// * fgs_sigma: 1.5f
+pub fn calc_optical_flow_sparse_to_dense(from: &dyn core::ToInputArray, to: &dyn core::ToInputArray, flow: &mut dyn core::ToOutputArray, grid_step: i32, k: i32, sigma: f32, use_post_proc: bool, fgs_lambda: f32, fgs_sigma: f32) -> Result<()> {
+    input_array_arg!(from);
+    input_array_arg!(to);
+    output_array_arg!(flow);
+    unsafe { sys::cv_optflow_calcOpticalFlowSparseToDense__InputArray__InputArray__OutputArray_int_int_float_bool_float_float(from.as_raw__InputArray(), to.as_raw__InputArray(), flow.as_raw__OutputArray(), grid_step, k, sigma, use_post_proc, fgs_lambda, fgs_sigma) }.into_result()
+}
+
+/// Creates an instance of DISOpticalFlow
+///
+/// ## Parameters
+/// * preset: one of PRESET_ULTRAFAST, PRESET_FAST and PRESET_MEDIUM
+///
+/// ## C++ default parameters
+/// * preset: DISOpticalFlow::PRESET_FAST
+pub fn create_opt_flow_dis(preset: i32) -> Result<types::PtrOfDISOpticalFlow> {
+    unsafe { sys::cv_optflow_createOptFlow_DIS_int(preset) }.into_result().map(|ptr| types::PtrOfDISOpticalFlow { ptr })
+}
+
+/// DeepFlow optical flow algorithm implementation.
+///
+/// The class implements the DeepFlow optical flow algorithm described in [Weinzaepfel2013](https://docs.opencv.org/3.4.9/d0/de3/citelist.html#CITEREF_Weinzaepfel2013) . See
+/// also <http://lear.inrialpes.fr/src/deepmatching/> .
+/// Parameters - class fields - that may be modified after creating a class instance:
+/// *   member float alpha
+/// Smoothness assumption weight
+/// *   member float delta
+/// Color constancy assumption weight
+/// *   member float gamma
+/// Gradient constancy weight
+/// *   member float sigma
+/// Gaussian smoothing parameter
+/// *   member int minSize
+/// Minimal dimension of an image in the pyramid (next, smaller images in the pyramid are generated
+/// until one of the dimensions reaches this size)
+/// *   member float downscaleFactor
+/// Scaling factor in the image pyramid (must be \< 1)
+/// *   member int fixedPointIterations
+/// How many iterations on each level of the pyramid
+/// *   member int sorIterations
+/// Iterations of Succesive Over-Relaxation (solver)
+/// *   member float omega
+/// Relaxation factor in SOR
+pub fn create_opt_flow_deep_flow() -> Result<types::PtrOfDenseOpticalFlow> {
+    unsafe { sys::cv_optflow_createOptFlow_DeepFlow() }.into_result().map(|ptr| types::PtrOfDenseOpticalFlow { ptr })
+}
+
+/// Additional interface to the Farneback's algorithm - calcOpticalFlowFarneback()
+pub fn create_opt_flow_farneback() -> Result<types::PtrOfDenseOpticalFlow> {
+    unsafe { sys::cv_optflow_createOptFlow_Farneback() }.into_result().map(|ptr| types::PtrOfDenseOpticalFlow { ptr })
+}
+
+/// Creates an instance of PCAFlow
+pub fn create_opt_flow_pca_flow() -> Result<types::PtrOfDenseOpticalFlow> {
+    unsafe { sys::cv_optflow_createOptFlow_PCAFlow() }.into_result().map(|ptr| types::PtrOfDenseOpticalFlow { ptr })
+}
+
+/// Additional interface to the SimpleFlow algorithm - calcOpticalFlowSF()
+pub fn create_opt_flow_simple_flow() -> Result<types::PtrOfDenseOpticalFlow> {
+    unsafe { sys::cv_optflow_createOptFlow_SimpleFlow() }.into_result().map(|ptr| types::PtrOfDenseOpticalFlow { ptr })
+}
+
+/// Additional interface to the SparseToDenseFlow algorithm - calcOpticalFlowSparseToDense()
+pub fn create_opt_flow_sparse_to_dense() -> Result<types::PtrOfDenseOpticalFlow> {
+    unsafe { sys::cv_optflow_createOptFlow_SparseToDense() }.into_result().map(|ptr| types::PtrOfDenseOpticalFlow { ptr })
+}
+
+/// Creates an instance of VariationalRefinement
+pub fn create_variational_flow_refinement() -> Result<types::PtrOfVariationalRefinement> {
+    unsafe { sys::cv_optflow_createVariationalFlowRefinement() }.into_result().map(|ptr| types::PtrOfVariationalRefinement { ptr })
+}
+
+/// Read a .flo file
+///
+/// ## Parameters
+/// * path: Path to the file to be loaded
+///
+/// The function readOpticalFlow loads a flow field from a file and returns it as a single matrix.
+/// Resulting Mat has a type CV_32FC2 - floating-point, 2-channel. First channel corresponds to the
+/// flow in the horizontal direction (u), second - vertical (v).
+pub fn read_optical_flow(path: &str) -> Result<core::Mat> {
+    string_arg!(path);
+    unsafe { sys::cv_optflow_readOpticalFlow_String(path.as_ptr()) }.into_result().map(|ptr| core::Mat { ptr })
+}
+
+/// Write a .flo to disk
+///
+/// ## Parameters
+/// * path: Path to the file to be written
+/// * flow: Flow field to be stored
+///
+/// The function stores a flow field in a file, returns true on success, false otherwise.
+/// The flow field must be a 2-channel, floating-point matrix (CV_32FC2). First channel corresponds
+/// to the flow in the horizontal direction (u), second - vertical (v).
+pub fn write_optical_flow(path: &str, flow: &dyn core::ToInputArray) -> Result<bool> {
+    string_arg!(path);
+    input_array_arg!(flow);
+    unsafe { sys::cv_optflow_writeOpticalFlow_String__InputArray(path.as_ptr(), flow.as_raw__InputArray()) }.into_result()
+}
+
+pub fn write(fs: &mut core::FileStorage, name: &str, node: &crate::optflow::GPCTree_Node) -> Result<()> {
+    string_arg!(name);
+    unsafe { sys::cv_write_FileStorage_String_Node(fs.as_raw_FileStorage(), name.as_ptr(), node.as_raw_GPCTree_Node()) }.into_result()
+}
+
+// Generating impl for trait crate::optflow::DISOpticalFlow
+/// DIS optical flow algorithm.
+///
+/// This class implements the Dense Inverse Search (DIS) optical flow algorithm. More
+/// details about the algorithm can be found at [Kroeger2016](https://docs.opencv.org/3.4.9/d0/de3/citelist.html#CITEREF_Kroeger2016) . Includes three presets with preselected
+/// parameters to provide reasonable trade-off between speed and quality. However, even the slowest preset is
+/// still relatively fast, use DeepFlow if you need better quality and don't care about speed.
+///
+/// This implementation includes several additional features compared to the algorithm described in the paper,
+/// including spatial propagation of flow vectors (@ref getUseSpatialPropagation), as well as an option to
+/// utilize an initial flow approximation passed to @ref calc (which is, essentially, temporal propagation,
+/// if the previous frame's flow field is passed).
+pub trait DISOpticalFlow: crate::video::DenseOpticalFlow {
+    fn as_raw_DISOpticalFlow(&self) -> *mut c_void;
+    /// Finest level of the Gaussian pyramid on which the flow is computed (zero level
+    /// corresponds to the original image resolution). The final flow is obtained by bilinear upscaling.
+    /// @see setFinestScale
+    fn get_finest_scale(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_DISOpticalFlow_getFinestScale_const(self.as_raw_DISOpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getFinestScale @see getFinestScale
+    fn set_finest_scale(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_optflow_DISOpticalFlow_setFinestScale_int(self.as_raw_DISOpticalFlow(), val) }.into_result()
+    }
+    
+    /// Size of an image patch for matching (in pixels). Normally, default 8x8 patches work well
+    /// enough in most cases.
+    /// @see setPatchSize
+    fn get_patch_size(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_DISOpticalFlow_getPatchSize_const(self.as_raw_DISOpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getPatchSize @see getPatchSize
+    fn set_patch_size(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_optflow_DISOpticalFlow_setPatchSize_int(self.as_raw_DISOpticalFlow(), val) }.into_result()
+    }
+    
+    /// Stride between neighbor patches. Must be less than patch size. Lower values correspond
+    /// to higher flow quality.
+    /// @see setPatchStride
+    fn get_patch_stride(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_DISOpticalFlow_getPatchStride_const(self.as_raw_DISOpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getPatchStride @see getPatchStride
+    fn set_patch_stride(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_optflow_DISOpticalFlow_setPatchStride_int(self.as_raw_DISOpticalFlow(), val) }.into_result()
+    }
+    
+    /// Maximum number of gradient descent iterations in the patch inverse search stage. Higher values
+    /// may improve quality in some cases.
+    /// @see setGradientDescentIterations
+    fn get_gradient_descent_iterations(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_DISOpticalFlow_getGradientDescentIterations_const(self.as_raw_DISOpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getGradientDescentIterations @see getGradientDescentIterations
+    fn set_gradient_descent_iterations(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_optflow_DISOpticalFlow_setGradientDescentIterations_int(self.as_raw_DISOpticalFlow(), val) }.into_result()
+    }
+    
+    /// Number of fixed point iterations of variational refinement per scale. Set to zero to
+    /// disable variational refinement completely. Higher values will typically result in more smooth and
+    /// high-quality flow.
+    /// @see setGradientDescentIterations
+    fn get_variational_refinement_iterations(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_DISOpticalFlow_getVariationalRefinementIterations_const(self.as_raw_DISOpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getGradientDescentIterations @see getGradientDescentIterations
+    fn set_variational_refinement_iterations(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_optflow_DISOpticalFlow_setVariationalRefinementIterations_int(self.as_raw_DISOpticalFlow(), val) }.into_result()
+    }
+    
+    /// Weight of the smoothness term
+    /// @see setVariationalRefinementAlpha
+    fn get_variational_refinement_alpha(&self) -> Result<f32> {
+        unsafe { sys::cv_optflow_DISOpticalFlow_getVariationalRefinementAlpha_const(self.as_raw_DISOpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getVariationalRefinementAlpha @see getVariationalRefinementAlpha
+    fn set_variational_refinement_alpha(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_optflow_DISOpticalFlow_setVariationalRefinementAlpha_float(self.as_raw_DISOpticalFlow(), val) }.into_result()
+    }
+    
+    /// Weight of the color constancy term
+    /// @see setVariationalRefinementDelta
+    fn get_variational_refinement_delta(&self) -> Result<f32> {
+        unsafe { sys::cv_optflow_DISOpticalFlow_getVariationalRefinementDelta_const(self.as_raw_DISOpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getVariationalRefinementDelta @see getVariationalRefinementDelta
+    fn set_variational_refinement_delta(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_optflow_DISOpticalFlow_setVariationalRefinementDelta_float(self.as_raw_DISOpticalFlow(), val) }.into_result()
+    }
+    
+    /// Weight of the gradient constancy term
+    /// @see setVariationalRefinementGamma
+    fn get_variational_refinement_gamma(&self) -> Result<f32> {
+        unsafe { sys::cv_optflow_DISOpticalFlow_getVariationalRefinementGamma_const(self.as_raw_DISOpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getVariationalRefinementGamma @see getVariationalRefinementGamma
+    fn set_variational_refinement_gamma(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_optflow_DISOpticalFlow_setVariationalRefinementGamma_float(self.as_raw_DISOpticalFlow(), val) }.into_result()
+    }
+    
+    /// Whether to use mean-normalization of patches when computing patch distance. It is turned on
+    /// by default as it typically provides a noticeable quality boost because of increased robustness to
+    /// illumination variations. Turn it off if you are certain that your sequence doesn't contain any changes
+    /// in illumination.
+    /// @see setUseMeanNormalization
+    fn get_use_mean_normalization(&self) -> Result<bool> {
+        unsafe { sys::cv_optflow_DISOpticalFlow_getUseMeanNormalization_const(self.as_raw_DISOpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getUseMeanNormalization @see getUseMeanNormalization
+    fn set_use_mean_normalization(&mut self, val: bool) -> Result<()> {
+        unsafe { sys::cv_optflow_DISOpticalFlow_setUseMeanNormalization_bool(self.as_raw_DISOpticalFlow(), val) }.into_result()
+    }
+    
+    /// Whether to use spatial propagation of good optical flow vectors. This option is turned on by
+    /// default, as it tends to work better on average and can sometimes help recover from major errors
+    /// introduced by the coarse-to-fine scheme employed by the DIS optical flow algorithm. Turning this
+    /// option off can make the output flow field a bit smoother, however.
+    /// @see setUseSpatialPropagation
+    fn get_use_spatial_propagation(&self) -> Result<bool> {
+        unsafe { sys::cv_optflow_DISOpticalFlow_getUseSpatialPropagation_const(self.as_raw_DISOpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getUseSpatialPropagation @see getUseSpatialPropagation
+    fn set_use_spatial_propagation(&mut self, val: bool) -> Result<()> {
+        unsafe { sys::cv_optflow_DISOpticalFlow_setUseSpatialPropagation_bool(self.as_raw_DISOpticalFlow(), val) }.into_result()
+    }
+    
+}
+
+// boxed class cv::optflow::GPCDetails
+/// Find correspondences between two images.
+/// ## Parameters
+/// * imgFrom: First image in a sequence.
+/// * imgTo: Second image in a sequence.
+/// * corr: [out] Output vector with pairs of corresponding points.
+/// * params: Additional matching parameters for fine-tuning.
+pub struct GPCDetails {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for GPCDetails {
+    fn drop(&mut self) {
+        unsafe { sys::cv_GPCDetails_delete(self.ptr) };
+    }
+}
+
+impl GPCDetails {
+    #[inline(always)] pub fn as_raw_GPCDetails(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for GPCDetails {}
+
+impl GPCDetails {
+    pub fn get_all_descriptors_for_image(img_ch: &core::Mat, descr: &mut types::VectorOfGPCPatchDescriptor, mp: &crate::optflow::GPCMatchingParams, _type: i32) -> Result<()> {
+        unsafe { sys::cv_optflow_GPCDetails_getAllDescriptorsForImage_const_Mat_VectorOfGPCPatchDescriptor_GPCMatchingParams_int(img_ch.as_raw_Mat(), descr.as_raw_VectorOfGPCPatchDescriptor(), mp.as_raw_GPCMatchingParams(), _type) }.into_result()
+    }
+    
+    pub fn get_coordinates_from_index(index: size_t, sz: core::Size, x: &mut i32, y: &mut i32) -> Result<()> {
+        unsafe { sys::cv_optflow_GPCDetails_getCoordinatesFromIndex_size_t_Size_int_int(index, sz, x, y) }.into_result()
+    }
+    
+}
+
+// boxed class cv::optflow::GPCMatchingParams
+/// Class encapsulating matching parameters.
+pub struct GPCMatchingParams {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for GPCMatchingParams {
+    fn drop(&mut self) {
+        unsafe { sys::cv_GPCMatchingParams_delete(self.ptr) };
+    }
+}
+
+impl GPCMatchingParams {
+    #[inline(always)] pub fn as_raw_GPCMatchingParams(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for GPCMatchingParams {}
+
+impl GPCMatchingParams {
+    ///
+    /// ## C++ default parameters
+    /// * _use_opencl: false
+    pub fn new(_use_opencl: bool) -> Result<crate::optflow::GPCMatchingParams> {
+        unsafe { sys::cv_optflow_GPCMatchingParams_GPCMatchingParams_bool(_use_opencl) }.into_result().map(|ptr| crate::optflow::GPCMatchingParams { ptr })
+    }
+    
+    pub fn copy(params: &crate::optflow::GPCMatchingParams) -> Result<crate::optflow::GPCMatchingParams> {
+        unsafe { sys::cv_optflow_GPCMatchingParams_GPCMatchingParams_GPCMatchingParams(params.as_raw_GPCMatchingParams()) }.into_result().map(|ptr| crate::optflow::GPCMatchingParams { ptr })
+    }
+    
+}
+
+// boxed class cv::optflow::GPCPatchDescriptor
+pub struct GPCPatchDescriptor {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for GPCPatchDescriptor {
+    fn drop(&mut self) {
+        unsafe { sys::cv_GPCPatchDescriptor_delete(self.ptr) };
+    }
+}
+
+impl GPCPatchDescriptor {
+    #[inline(always)] pub fn as_raw_GPCPatchDescriptor(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for GPCPatchDescriptor {}
+
+impl GPCPatchDescriptor {
+    pub fn mark_as_separated(&mut self) -> Result<()> {
+        unsafe { sys::cv_optflow_GPCPatchDescriptor_markAsSeparated(self.as_raw_GPCPatchDescriptor()) }.into_result()
+    }
+    
+    pub fn is_separated(&self) -> Result<bool> {
+        unsafe { sys::cv_optflow_GPCPatchDescriptor_isSeparated_const(self.as_raw_GPCPatchDescriptor()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::optflow::GPCPatchSample
+pub struct GPCPatchSample {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for GPCPatchSample {
+    fn drop(&mut self) {
+        unsafe { sys::cv_GPCPatchSample_delete(self.ptr) };
+    }
+}
+
+impl GPCPatchSample {
+    #[inline(always)] pub fn as_raw_GPCPatchSample(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for GPCPatchSample {}
+
+// boxed class cv::optflow::GPCTrainingParams
+/// Class encapsulating training parameters.
+pub struct GPCTrainingParams {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for GPCTrainingParams {
+    fn drop(&mut self) {
+        unsafe { sys::cv_GPCTrainingParams_delete(self.ptr) };
+    }
+}
+
+impl GPCTrainingParams {
+    #[inline(always)] pub fn as_raw_GPCTrainingParams(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for GPCTrainingParams {}
+
+impl GPCTrainingParams {
+    ///
+    /// ## C++ default parameters
+    /// * _max_tree_depth: 20
+    /// * _min_number_of_samples: 3
+    /// * _descriptor_type: GPC_DESCRIPTOR_DCT
+    /// * _print_progress: true
+    pub fn new(_max_tree_depth: u32, _min_number_of_samples: i32, _descriptor_type: crate::optflow::GPCDescType, _print_progress: bool) -> Result<crate::optflow::GPCTrainingParams> {
+        unsafe { sys::cv_optflow_GPCTrainingParams_GPCTrainingParams_unsigned_int_GPCDescType_bool(_max_tree_depth, _min_number_of_samples, _descriptor_type, _print_progress) }.into_result().map(|ptr| crate::optflow::GPCTrainingParams { ptr })
+    }
+    
+    pub fn check(&self) -> Result<bool> {
+        unsafe { sys::cv_optflow_GPCTrainingParams_check_const(self.as_raw_GPCTrainingParams()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::optflow::GPCTrainingSamples
+/// Class encapsulating training samples.
+pub struct GPCTrainingSamples {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for GPCTrainingSamples {
+    fn drop(&mut self) {
+        unsafe { sys::cv_GPCTrainingSamples_delete(self.ptr) };
+    }
+}
+
+impl GPCTrainingSamples {
+    #[inline(always)] pub fn as_raw_GPCTrainingSamples(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for GPCTrainingSamples {}
+
+impl GPCTrainingSamples {
+    /// This function can be used to extract samples from a pair of images and a ground truth flow.
+    /// Sizes of all the provided vectors must be equal.
+    pub fn create(images_from: &types::VectorOfString, images_to: &types::VectorOfString, gt: &types::VectorOfString, descriptor_type: i32) -> Result<types::PtrOfGPCTrainingSamples> {
+        unsafe { sys::cv_optflow_GPCTrainingSamples_create_VectorOfString_VectorOfString_VectorOfString_int(images_from.as_raw_VectorOfString(), images_to.as_raw_VectorOfString(), gt.as_raw_VectorOfString(), descriptor_type) }.into_result().map(|ptr| types::PtrOfGPCTrainingSamples { ptr })
+    }
+    
+    pub fn create_1(images_from: &dyn core::ToInputArray, images_to: &dyn core::ToInputArray, gt: &dyn core::ToInputArray, descriptor_type: i32) -> Result<types::PtrOfGPCTrainingSamples> {
+        input_array_arg!(images_from);
+        input_array_arg!(images_to);
+        input_array_arg!(gt);
+        unsafe { sys::cv_optflow_GPCTrainingSamples_create__InputArray__InputArray__InputArray_int(images_from.as_raw__InputArray(), images_to.as_raw__InputArray(), gt.as_raw__InputArray(), descriptor_type) }.into_result().map(|ptr| types::PtrOfGPCTrainingSamples { ptr })
+    }
+    
+    pub fn size(&self) -> Result<size_t> {
+        unsafe { sys::cv_optflow_GPCTrainingSamples_size_const(self.as_raw_GPCTrainingSamples()) }.into_result()
+    }
+    
+    pub fn _type(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_GPCTrainingSamples_type_const(self.as_raw_GPCTrainingSamples()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::optflow::GPCTree
+/// Class for individual tree.
+pub struct GPCTree {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for GPCTree {
+    fn drop(&mut self) {
+        unsafe { sys::cv_GPCTree_delete(self.ptr) };
+    }
+}
+
+impl GPCTree {
+    #[inline(always)] pub fn as_raw_GPCTree(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for GPCTree {}
+
+impl core::AlgorithmTrait for GPCTree {
+    #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
+}
+
+impl GPCTree {
+    ///
+    /// ## C++ default parameters
+    /// * params: GPCTrainingParams()
+    pub fn train(&mut self, samples: &mut crate::optflow::GPCTrainingSamples, params: &crate::optflow::GPCTrainingParams) -> Result<()> {
+        unsafe { sys::cv_optflow_GPCTree_train_GPCTrainingSamples_GPCTrainingParams(self.as_raw_GPCTree(), samples.as_raw_GPCTrainingSamples(), params.as_raw_GPCTrainingParams()) }.into_result()
+    }
+    
+    pub fn write(&self, fs: &mut core::FileStorage) -> Result<()> {
+        unsafe { sys::cv_optflow_GPCTree_write_const_FileStorage(self.as_raw_GPCTree(), fs.as_raw_FileStorage()) }.into_result()
+    }
+    
+    pub fn read(&mut self, _fn: &core::FileNode) -> Result<()> {
+        unsafe { sys::cv_optflow_GPCTree_read_FileNode(self.as_raw_GPCTree(), _fn.as_raw_FileNode()) }.into_result()
+    }
+    
+    pub fn find_leaf_for_patch(&self, descr: &crate::optflow::GPCPatchDescriptor) -> Result<u32> {
+        unsafe { sys::cv_optflow_GPCTree_findLeafForPatch_const_GPCPatchDescriptor(self.as_raw_GPCTree(), descr.as_raw_GPCPatchDescriptor()) }.into_result()
+    }
+    
+    pub fn create() -> Result<types::PtrOfGPCTree> {
+        unsafe { sys::cv_optflow_GPCTree_create() }.into_result().map(|ptr| types::PtrOfGPCTree { ptr })
+    }
+    
+    pub fn get_descriptor_type(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_GPCTree_getDescriptorType_const(self.as_raw_GPCTree()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::optflow::GPCTree::Node
+pub struct GPCTree_Node {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for GPCTree_Node {
+    fn drop(&mut self) {
+        unsafe { sys::cv_GPCTree_Node_delete(self.ptr) };
+    }
+}
+
+impl GPCTree_Node {
+    #[inline(always)] pub fn as_raw_GPCTree_Node(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for GPCTree_Node {}
+
+// boxed class cv::optflow::OpticalFlowPCAFlow
+/// PCAFlow algorithm.
+pub struct OpticalFlowPCAFlow {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for OpticalFlowPCAFlow {
+    fn drop(&mut self) {
+        unsafe { sys::cv_OpticalFlowPCAFlow_delete(self.ptr) };
+    }
+}
+
+impl OpticalFlowPCAFlow {
+    #[inline(always)] pub fn as_raw_OpticalFlowPCAFlow(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for OpticalFlowPCAFlow {}
+
+impl core::AlgorithmTrait for OpticalFlowPCAFlow {
+    #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::video::DenseOpticalFlow for OpticalFlowPCAFlow {
+    #[inline(always)] fn as_raw_DenseOpticalFlow(&self) -> *mut c_void { self.ptr }
+}
+
+impl OpticalFlowPCAFlow {
+    /// Creates an instance of PCAFlow algorithm.
+    /// ## Parameters
+    /// * _prior: Learned prior or no prior (default). @see cv::optflow::PCAPrior
+    /// * _basisSize: Number of basis vectors.
+    /// * _sparseRate: Controls density of sparse matches.
+    /// * _retainedCornersFraction: Retained corners fraction.
+    /// * _occlusionsThreshold: Occlusion threshold.
+    /// * _dampingFactor: Regularization term for solving least-squares. It is not related to the prior regularization.
+    /// * _claheClip: Clip parameter for CLAHE.
+    ///
+    /// ## C++ default parameters
+    /// * _prior: Ptr<const PCAPrior>()
+    /// * _basis_size: Size( 18, 14 )
+    /// * _sparse_rate: 0.024
+    /// * _retained_corners_fraction: 0.2
+    /// * _occlusions_threshold: 0.0003
+    /// * _damping_factor: 0.00002
+    /// * _clahe_clip: 14
+    pub fn new(_prior: &types::PtrOfPCAPrior, _basis_size: core::Size, _sparse_rate: f32, _retained_corners_fraction: f32, _occlusions_threshold: f32, _damping_factor: f32, _clahe_clip: f32) -> Result<crate::optflow::OpticalFlowPCAFlow> {
+        unsafe { sys::cv_optflow_OpticalFlowPCAFlow_OpticalFlowPCAFlow_PtrOfPCAPrior_Size_float_float_float_float_float(_prior.as_raw_PtrOfPCAPrior(), _basis_size, _sparse_rate, _retained_corners_fraction, _occlusions_threshold, _damping_factor, _clahe_clip) }.into_result().map(|ptr| crate::optflow::OpticalFlowPCAFlow { ptr })
+    }
+    
+    pub fn calc(&mut self, i0: &dyn core::ToInputArray, i1: &dyn core::ToInputArray, flow: &mut dyn core::ToInputOutputArray) -> Result<()> {
+        input_array_arg!(i0);
+        input_array_arg!(i1);
+        input_output_array_arg!(flow);
+        unsafe { sys::cv_optflow_OpticalFlowPCAFlow_calc__InputArray__InputArray__InputOutputArray(self.as_raw_OpticalFlowPCAFlow(), i0.as_raw__InputArray(), i1.as_raw__InputArray(), flow.as_raw__InputOutputArray()) }.into_result()
+    }
+    
+    pub fn collect_garbage(&mut self) -> Result<()> {
+        unsafe { sys::cv_optflow_OpticalFlowPCAFlow_collectGarbage(self.as_raw_OpticalFlowPCAFlow()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::optflow::PCAPrior
+/// This class can be used for imposing a learned prior on the resulting optical flow.
+/// Solution will be regularized according to this prior.
+/// You need to generate appropriate prior file with "learn_prior.py" script beforehand.
+pub struct PCAPrior {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for PCAPrior {
+    fn drop(&mut self) {
+        unsafe { sys::cv_PCAPrior_delete(self.ptr) };
+    }
+}
+
+impl PCAPrior {
+    #[inline(always)] pub fn as_raw_PCAPrior(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for PCAPrior {}
+
+impl PCAPrior {
+    pub fn new(path_to_prior: &str) -> Result<crate::optflow::PCAPrior> {
+        string_arg!(path_to_prior);
+        unsafe { sys::cv_optflow_PCAPrior_PCAPrior_const_char_X(path_to_prior.as_ptr()) }.into_result().map(|ptr| crate::optflow::PCAPrior { ptr })
+    }
+    
+    pub fn get_padding(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_PCAPrior_getPadding_const(self.as_raw_PCAPrior()) }.into_result()
+    }
+    
+    pub fn get_basis_size(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_PCAPrior_getBasisSize_const(self.as_raw_PCAPrior()) }.into_result()
+    }
+    
+    pub fn fill_constraints(&self, a1: &mut f32, a2: &mut f32, b1: &mut f32, b2: &mut f32) -> Result<()> {
+        unsafe { sys::cv_optflow_PCAPrior_fillConstraints_const_float_X_float_X_float_X_float_X(self.as_raw_PCAPrior(), a1, a2, b1, b2) }.into_result()
+    }
+    
+}
+
+// Generating impl for trait crate::optflow::VariationalRefinement
+/// Variational optical flow refinement
+///
+/// This class implements variational refinement of the input flow field, i.e.
+/// it uses input flow to initialize the minimization of the following functional:
+/// ![inline formula](https://latex.codecogs.com/png.latex?E%28U%29%20%3D%20%5Cint_%7B%5COmega%7D%20%5Cdelta%20%5CPsi%28E_I%29%20%2B%20%5Cgamma%20%5CPsi%28E_G%29%20%2B%20%5Calpha%20%5CPsi%28E_S%29%20),
+/// where ![inline formula](https://latex.codecogs.com/png.latex?E_I%2CE_G%2CE_S) are color constancy, gradient constancy and smoothness terms
+/// respectively. ![inline formula](https://latex.codecogs.com/png.latex?%5CPsi%28s%5E2%29%3D%5Csqrt%7Bs%5E2%2B%5Cepsilon%5E2%7D) is a robust penalizer to limit the
+/// influence of outliers. A complete formulation and a description of the minimization
+/// procedure can be found in [Brox2004](https://docs.opencv.org/3.4.9/d0/de3/citelist.html#CITEREF_Brox2004)
+pub trait VariationalRefinement: crate::video::DenseOpticalFlow {
+    fn as_raw_VariationalRefinement(&self) -> *mut c_void;
+    /// @ref calc function overload to handle separate horizontal (u) and vertical (v) flow components
+    /// (to avoid extra splits/merges)
+    fn calc_uv(&mut self, i0: &dyn core::ToInputArray, i1: &dyn core::ToInputArray, flow_u: &mut dyn core::ToInputOutputArray, flow_v: &mut dyn core::ToInputOutputArray) -> Result<()> {
+        input_array_arg!(i0);
+        input_array_arg!(i1);
+        input_output_array_arg!(flow_u);
+        input_output_array_arg!(flow_v);
+        unsafe { sys::cv_optflow_VariationalRefinement_calcUV__InputArray__InputArray__InputOutputArray__InputOutputArray(self.as_raw_VariationalRefinement(), i0.as_raw__InputArray(), i1.as_raw__InputArray(), flow_u.as_raw__InputOutputArray(), flow_v.as_raw__InputOutputArray()) }.into_result()
+    }
+    
+    /// Number of outer (fixed-point) iterations in the minimization procedure.
+    /// @see setFixedPointIterations
+    fn get_fixed_point_iterations(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_VariationalRefinement_getFixedPointIterations_const(self.as_raw_VariationalRefinement()) }.into_result()
+    }
+    
+    /// @copybrief getFixedPointIterations @see getFixedPointIterations
+    fn set_fixed_point_iterations(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_optflow_VariationalRefinement_setFixedPointIterations_int(self.as_raw_VariationalRefinement(), val) }.into_result()
+    }
+    
+    /// Number of inner successive over-relaxation (SOR) iterations
+    /// in the minimization procedure to solve the respective linear system.
+    /// @see setSorIterations
+    fn get_sor_iterations(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_VariationalRefinement_getSorIterations_const(self.as_raw_VariationalRefinement()) }.into_result()
+    }
+    
+    /// @copybrief getSorIterations @see getSorIterations
+    fn set_sor_iterations(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_optflow_VariationalRefinement_setSorIterations_int(self.as_raw_VariationalRefinement(), val) }.into_result()
+    }
+    
+    /// Relaxation factor in SOR
+    /// @see setOmega
+    fn get_omega(&self) -> Result<f32> {
+        unsafe { sys::cv_optflow_VariationalRefinement_getOmega_const(self.as_raw_VariationalRefinement()) }.into_result()
+    }
+    
+    /// @copybrief getOmega @see getOmega
+    fn set_omega(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_optflow_VariationalRefinement_setOmega_float(self.as_raw_VariationalRefinement(), val) }.into_result()
+    }
+    
+    /// Weight of the smoothness term
+    /// @see setAlpha
+    fn get_alpha(&self) -> Result<f32> {
+        unsafe { sys::cv_optflow_VariationalRefinement_getAlpha_const(self.as_raw_VariationalRefinement()) }.into_result()
+    }
+    
+    /// @copybrief getAlpha @see getAlpha
+    fn set_alpha(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_optflow_VariationalRefinement_setAlpha_float(self.as_raw_VariationalRefinement(), val) }.into_result()
+    }
+    
+    /// Weight of the color constancy term
+    /// @see setDelta
+    fn get_delta(&self) -> Result<f32> {
+        unsafe { sys::cv_optflow_VariationalRefinement_getDelta_const(self.as_raw_VariationalRefinement()) }.into_result()
+    }
+    
+    /// @copybrief getDelta @see getDelta
+    fn set_delta(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_optflow_VariationalRefinement_setDelta_float(self.as_raw_VariationalRefinement(), val) }.into_result()
+    }
+    
+    /// Weight of the gradient constancy term
+    /// @see setGamma
+    fn get_gamma(&self) -> Result<f32> {
+        unsafe { sys::cv_optflow_VariationalRefinement_getGamma_const(self.as_raw_VariationalRefinement()) }.into_result()
+    }
+    
+    /// @copybrief getGamma @see getGamma
+    fn set_gamma(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_optflow_VariationalRefinement_setGamma_float(self.as_raw_VariationalRefinement(), val) }.into_result()
+    }
+    
+}
+
@@ -0,0 +1,398 @@
+//! # Stereo Correspondance Algorithms
+use crate::{mod_prelude::*, core, sys, types};
+use crate::core::{_InputArrayTrait, _OutputArrayTrait};
+
+pub const CV_CS_CENSUS: i32 = 2;
+pub const CV_DENSE_CENSUS: i32 = 0;
+pub const CV_MEAN_VARIATION: i32 = 5;
+pub const CV_MODIFIED_CENSUS_TRANSFORM: i32 = 4;
+pub const CV_MODIFIED_CS_CENSUS: i32 = 3;
+pub const CV_QUADRATIC_INTERPOLATION: i32 = 0;
+pub const CV_SIMETRICV_INTERPOLATION: i32 = 1;
+pub const CV_SPARSE_CENSUS: i32 = 1;
+pub const CV_SPECKLE_REMOVAL_ALGORITHM: i32 = 0;
+pub const CV_SPECKLE_REMOVAL_AVG_ALGORITHM: i32 = 1;
+pub const CV_STAR_KERNEL: i32 = 6;
+pub const StereoBinaryBM_PREFILTER_NORMALIZED_RESPONSE: i32 = 0;
+pub const StereoBinaryBM_PREFILTER_XSOBEL: i32 = 1;
+pub const StereoBinarySGBM_MODE_HH: i32 = 1;
+pub const StereoBinarySGBM_MODE_SGBM: i32 = 0;
+pub const StereoMatcher_DISP_SHIFT: i32 = 4;
+
+/// Two variations of census applied on input images
+/// Implementation of a census transform which is taking into account just the some pixels from the census kernel thus allowing for larger block sizes
+pub fn census_transform(image1: &core::Mat, image2: &core::Mat, kernel_size: i32, dist1: &mut core::Mat, dist2: &mut core::Mat, _type: i32) -> Result<()> {
+    unsafe { sys::cv_stereo_censusTransform_Mat_Mat_int_Mat_Mat_int(image1.as_raw_Mat(), image2.as_raw_Mat(), kernel_size, dist1.as_raw_Mat(), dist2.as_raw_Mat(), _type) }.into_result()
+}
+
+pub fn census_transform_single(image1: &core::Mat, kernel_size: i32, dist1: &mut core::Mat, _type: i32) -> Result<()> {
+    unsafe { sys::cv_stereo_censusTransform_Mat_int_Mat_int(image1.as_raw_Mat(), kernel_size, dist1.as_raw_Mat(), _type) }.into_result()
+}
+
+/// STANDARD_MCT - Modified census which is memorizing for each pixel 2 bits and includes a tolerance to the pixel comparison
+/// MCT_MEAN_VARIATION - Implementation of a modified census transform which is also taking into account the variation to the mean of the window not just the center pixel
+///
+/// ## C++ default parameters
+/// * t: 0
+/// * integral_image1: cv::Mat::zeros(100,100,CV_8UC1)
+/// * integral_image2: cv::Mat::zeros(100,100,CV_8UC1)
+pub fn modified_census_transform(img1: &core::Mat, img2: &core::Mat, kernel_size: i32, dist1: &mut core::Mat, dist2: &mut core::Mat, _type: i32, t: i32, integral_image1: &core::Mat, integral_image2: &core::Mat) -> Result<()> {
+    unsafe { sys::cv_stereo_modifiedCensusTransform_Mat_Mat_int_Mat_Mat_int_int_Mat_Mat(img1.as_raw_Mat(), img2.as_raw_Mat(), kernel_size, dist1.as_raw_Mat(), dist2.as_raw_Mat(), _type, t, integral_image1.as_raw_Mat(), integral_image2.as_raw_Mat()) }.into_result()
+}
+
+///
+/// ## C++ default parameters
+/// * t: 0
+/// * integral_image: cv::Mat::zeros(100,100,CV_8UC1)
+pub fn modified_census_transform_single(img1: &core::Mat, kernel_size: i32, dist: &mut core::Mat, _type: i32, t: i32, integral_image: &core::Mat) -> Result<()> {
+    unsafe { sys::cv_stereo_modifiedCensusTransform_Mat_int_Mat_int_int_Mat(img1.as_raw_Mat(), kernel_size, dist.as_raw_Mat(), _type, t, integral_image.as_raw_Mat()) }.into_result()
+}
+
+pub fn star_census_transform(img1: &core::Mat, img2: &core::Mat, kernel_size: i32, dist1: &mut core::Mat, dist2: &mut core::Mat) -> Result<()> {
+    unsafe { sys::cv_stereo_starCensusTransform_Mat_Mat_int_Mat_Mat(img1.as_raw_Mat(), img2.as_raw_Mat(), kernel_size, dist1.as_raw_Mat(), dist2.as_raw_Mat()) }.into_result()
+}
+
+pub fn star_census_transform_single(img1: &core::Mat, kernel_size: i32, dist: &mut core::Mat) -> Result<()> {
+    unsafe { sys::cv_stereo_starCensusTransform_Mat_int_Mat(img1.as_raw_Mat(), kernel_size, dist.as_raw_Mat()) }.into_result()
+}
+
+/// The classical center symetric census
+/// A modified version of cs census which is comparing a pixel with its correspondent after the center
+pub fn symetric_census_transform(img1: &core::Mat, img2: &core::Mat, kernel_size: i32, dist1: &mut core::Mat, dist2: &mut core::Mat, _type: i32) -> Result<()> {
+    unsafe { sys::cv_stereo_symetricCensusTransform_Mat_Mat_int_Mat_Mat_int(img1.as_raw_Mat(), img2.as_raw_Mat(), kernel_size, dist1.as_raw_Mat(), dist2.as_raw_Mat(), _type) }.into_result()
+}
+
+pub fn symetric_census_transform_single(img1: &core::Mat, kernel_size: i32, dist1: &mut core::Mat, _type: i32) -> Result<()> {
+    unsafe { sys::cv_stereo_symetricCensusTransform_Mat_int_Mat_int(img1.as_raw_Mat(), kernel_size, dist1.as_raw_Mat(), _type) }.into_result()
+}
+
+// Generating impl for trait crate::stereo::StereoBinaryBM
+/// Class for computing stereo correspondence using the block matching algorithm, introduced and
+/// contributed to OpenCV by K. Konolige.
+pub trait StereoBinaryBM: crate::stereo::StereoMatcher {
+    fn as_raw_StereoBinaryBM(&self) -> *mut c_void;
+    fn get_pre_filter_type(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_getPreFilterType_const(self.as_raw_StereoBinaryBM()) }.into_result()
+    }
+    
+    fn set_pre_filter_type(&mut self, pre_filter_type: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_setPreFilterType_int(self.as_raw_StereoBinaryBM(), pre_filter_type) }.into_result()
+    }
+    
+    fn get_pre_filter_size(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_getPreFilterSize_const(self.as_raw_StereoBinaryBM()) }.into_result()
+    }
+    
+    fn set_pre_filter_size(&mut self, pre_filter_size: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_setPreFilterSize_int(self.as_raw_StereoBinaryBM(), pre_filter_size) }.into_result()
+    }
+    
+    fn get_pre_filter_cap(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_getPreFilterCap_const(self.as_raw_StereoBinaryBM()) }.into_result()
+    }
+    
+    fn set_pre_filter_cap(&mut self, pre_filter_cap: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_setPreFilterCap_int(self.as_raw_StereoBinaryBM(), pre_filter_cap) }.into_result()
+    }
+    
+    fn get_texture_threshold(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_getTextureThreshold_const(self.as_raw_StereoBinaryBM()) }.into_result()
+    }
+    
+    fn set_texture_threshold(&mut self, texture_threshold: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_setTextureThreshold_int(self.as_raw_StereoBinaryBM(), texture_threshold) }.into_result()
+    }
+    
+    fn get_uniqueness_ratio(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_getUniquenessRatio_const(self.as_raw_StereoBinaryBM()) }.into_result()
+    }
+    
+    fn set_uniqueness_ratio(&mut self, uniqueness_ratio: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_setUniquenessRatio_int(self.as_raw_StereoBinaryBM(), uniqueness_ratio) }.into_result()
+    }
+    
+    fn get_smaller_block_size(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_getSmallerBlockSize_const(self.as_raw_StereoBinaryBM()) }.into_result()
+    }
+    
+    fn set_smaller_block_size(&mut self, block_size: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_setSmallerBlockSize_int(self.as_raw_StereoBinaryBM(), block_size) }.into_result()
+    }
+    
+    fn get_scalle_factor(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_getScalleFactor_const(self.as_raw_StereoBinaryBM()) }.into_result()
+    }
+    
+    fn set_scalle_factor(&mut self, factor: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_setScalleFactor_int(self.as_raw_StereoBinaryBM(), factor) }.into_result()
+    }
+    
+    fn get_spekle_removal_technique(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_getSpekleRemovalTechnique_const(self.as_raw_StereoBinaryBM()) }.into_result()
+    }
+    
+    fn set_spekle_removal_technique(&mut self, factor: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_setSpekleRemovalTechnique_int(self.as_raw_StereoBinaryBM(), factor) }.into_result()
+    }
+    
+    fn get_use_prefilter(&self) -> Result<bool> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_getUsePrefilter_const(self.as_raw_StereoBinaryBM()) }.into_result()
+    }
+    
+    fn set_use_prefilter(&mut self, factor: bool) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_setUsePrefilter_bool(self.as_raw_StereoBinaryBM(), factor) }.into_result()
+    }
+    
+    fn get_binary_kernel_type(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_getBinaryKernelType_const(self.as_raw_StereoBinaryBM()) }.into_result()
+    }
+    
+    fn set_binary_kernel_type(&mut self, value: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_setBinaryKernelType_int(self.as_raw_StereoBinaryBM(), value) }.into_result()
+    }
+    
+    fn get_agregation_window_size(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_getAgregationWindowSize_const(self.as_raw_StereoBinaryBM()) }.into_result()
+    }
+    
+    fn set_agregation_window_size(&mut self, value: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_setAgregationWindowSize_int(self.as_raw_StereoBinaryBM(), value) }.into_result()
+    }
+    
+}
+
+impl dyn StereoBinaryBM + '_ {
+    /// Creates StereoBM object
+    ///
+    /// ## Parameters
+    /// * numDisparities: the disparity search range. For each pixel algorithm will find the best
+    /// disparity from 0 (default minimum disparity) to numDisparities. The search range can then be
+    /// shifted by changing the minimum disparity.
+    /// * blockSize: the linear size of the blocks compared by the algorithm. The size should be odd
+    /// (as the block is centered at the current pixel). Larger block size implies smoother, though less
+    /// accurate disparity map. Smaller block size gives more detailed disparity map, but there is higher
+    /// chance for algorithm to find a wrong correspondence.
+    ///
+    /// The function create StereoBM object. You can then call StereoBM::compute() to compute disparity for
+    /// a specific stereo pair.
+    ///
+    /// ## C++ default parameters
+    /// * num_disparities: 0
+    /// * block_size: 9
+    pub fn create(num_disparities: i32, block_size: i32) -> Result<types::PtrOfStereoBinaryBM> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_create_int_int(num_disparities, block_size) }.into_result().map(|ptr| types::PtrOfStereoBinaryBM { ptr })
+    }
+    
+}
+
+// Generating impl for trait crate::stereo::StereoBinarySGBM
+/// The class implements the modified H. Hirschmuller algorithm [HH08](https://docs.opencv.org/3.4.9/d0/de3/citelist.html#CITEREF_HH08) that differs from the original
+/// one as follows:
+///
+/// *   By default, the algorithm is single-pass, which means that you consider only 5 directions
+/// instead of 8. Set mode=StereoSGBM::MODE_HH in createStereoSGBM to run the full variant of the
+/// algorithm but beware that it may consume a lot of memory.
+/// *   The algorithm matches blocks, not individual pixels. Though, setting blockSize=1 reduces the
+/// blocks to single pixels.
+/// *   Mutual information cost function is not implemented. Instead, a simpler Birchfield-Tomasi
+/// sub-pixel metric from [BT98](https://docs.opencv.org/3.4.9/d0/de3/citelist.html#CITEREF_BT98) is used. Though, the color images are supported as well.
+/// *   Some pre- and post- processing steps from K. Konolige algorithm StereoBM are included, for
+/// example: pre-filtering (StereoBM::PREFILTER_XSOBEL type) and post-filtering (uniqueness
+/// check, quadratic interpolation and speckle filtering).
+///
+///
+/// Note:
+/// *   (Python) An example illustrating the use of the StereoSGBM matching algorithm can be found
+/// at opencv_source_code/samples/python2/stereo_match.py
+pub trait StereoBinarySGBM: crate::stereo::StereoMatcher {
+    fn as_raw_StereoBinarySGBM(&self) -> *mut c_void;
+    fn get_pre_filter_cap(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_getPreFilterCap_const(self.as_raw_StereoBinarySGBM()) }.into_result()
+    }
+    
+    fn set_pre_filter_cap(&mut self, pre_filter_cap: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_setPreFilterCap_int(self.as_raw_StereoBinarySGBM(), pre_filter_cap) }.into_result()
+    }
+    
+    fn get_uniqueness_ratio(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_getUniquenessRatio_const(self.as_raw_StereoBinarySGBM()) }.into_result()
+    }
+    
+    fn set_uniqueness_ratio(&mut self, uniqueness_ratio: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_setUniquenessRatio_int(self.as_raw_StereoBinarySGBM(), uniqueness_ratio) }.into_result()
+    }
+    
+    fn get_p1(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_getP1_const(self.as_raw_StereoBinarySGBM()) }.into_result()
+    }
+    
+    fn set_p1(&mut self, p1: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_setP1_int(self.as_raw_StereoBinarySGBM(), p1) }.into_result()
+    }
+    
+    fn get_p2(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_getP2_const(self.as_raw_StereoBinarySGBM()) }.into_result()
+    }
+    
+    fn set_p2(&mut self, p2: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_setP2_int(self.as_raw_StereoBinarySGBM(), p2) }.into_result()
+    }
+    
+    fn get_mode(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_getMode_const(self.as_raw_StereoBinarySGBM()) }.into_result()
+    }
+    
+    fn set_mode(&mut self, mode: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_setMode_int(self.as_raw_StereoBinarySGBM(), mode) }.into_result()
+    }
+    
+    fn get_spekle_removal_technique(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_getSpekleRemovalTechnique_const(self.as_raw_StereoBinarySGBM()) }.into_result()
+    }
+    
+    fn set_spekle_removal_technique(&mut self, factor: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_setSpekleRemovalTechnique_int(self.as_raw_StereoBinarySGBM(), factor) }.into_result()
+    }
+    
+    fn get_binary_kernel_type(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_getBinaryKernelType_const(self.as_raw_StereoBinarySGBM()) }.into_result()
+    }
+    
+    fn set_binary_kernel_type(&mut self, value: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_setBinaryKernelType_int(self.as_raw_StereoBinarySGBM(), value) }.into_result()
+    }
+    
+    fn get_sub_pixel_interpolation_method(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_getSubPixelInterpolationMethod_const(self.as_raw_StereoBinarySGBM()) }.into_result()
+    }
+    
+    fn set_sub_pixel_interpolation_method(&mut self, value: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_setSubPixelInterpolationMethod_int(self.as_raw_StereoBinarySGBM(), value) }.into_result()
+    }
+    
+}
+
+impl dyn StereoBinarySGBM + '_ {
+    /// Creates StereoSGBM object
+    ///
+    /// ## Parameters
+    /// * minDisparity: Minimum possible disparity value. Normally, it is zero but sometimes
+    /// rectification algorithms can shift images, so this parameter needs to be adjusted accordingly.
+    /// * numDisparities: Maximum disparity minus minimum disparity. The value is always greater than
+    /// zero. In the current implementation, this parameter must be divisible by 16.
+    /// * blockSize: Matched block size. It must be an odd number \>=1 . Normally, it should be
+    /// somewhere in the 3..11 range.
+    /// * P1: The first parameter controlling the disparity smoothness.This parameter is used for the case of slanted surfaces (not fronto parallel).
+    /// * P2: The second parameter controlling the disparity smoothness.This parameter is used for "solving" the depth discontinuities problem.
+    /// The larger the values are, the smoother the disparity is. P1 is the penalty on the disparity change by plus or minus 1
+    /// between neighbor pixels. P2 is the penalty on the disparity change by more than 1 between neighbor
+    /// pixels. The algorithm requires P2 \> P1 . See stereo_match.cpp sample where some reasonably good
+    /// P1 and P2 values are shown (like 8\*number_of_image_channels\*SADWindowSize\*SADWindowSize and
+    /// 32\*number_of_image_channels\*SADWindowSize\*SADWindowSize , respectively).
+    /// * disp12MaxDiff: Maximum allowed difference (in integer pixel units) in the left-right
+    /// disparity check. Set it to a non-positive value to disable the check.
+    /// * preFilterCap: Truncation value for the prefiltered image pixels. The algorithm first
+    /// computes x-derivative at each pixel and clips its value by [-preFilterCap, preFilterCap] interval.
+    /// The result values are passed to the Birchfield-Tomasi pixel cost function.
+    /// * uniquenessRatio: Margin in percentage by which the best (minimum) computed cost function
+    /// value should "win" the second best value to consider the found match correct. Normally, a value
+    /// within the 5-15 range is good enough.
+    /// * speckleWindowSize: Maximum size of smooth disparity regions to consider their noise speckles
+    /// and invalidate. Set it to 0 to disable speckle filtering. Otherwise, set it somewhere in the
+    /// 50-200 range.
+    /// * speckleRange: Maximum disparity variation within each connected component. If you do speckle
+    /// filtering, set the parameter to a positive value, it will be implicitly multiplied by 16.
+    /// Normally, 1 or 2 is good enough.
+    /// * mode: Set it to StereoSGBM::MODE_HH to run the full-scale two-pass dynamic programming
+    /// algorithm. It will consume O(W\*H\*numDisparities) bytes, which is large for 640x480 stereo and
+    /// huge for HD-size pictures. By default, it is set to false .
+    ///
+    /// The first constructor initializes StereoSGBM with all the default parameters. So, you only have to
+    /// set StereoSGBM::numDisparities at minimum. The second constructor enables you to set each parameter
+    /// to a custom value.
+    ///
+    /// ## C++ default parameters
+    /// * p1: 100
+    /// * p2: 1000
+    /// * disp12_max_diff: 1
+    /// * pre_filter_cap: 0
+    /// * uniqueness_ratio: 5
+    /// * speckle_window_size: 400
+    /// * speckle_range: 200
+    /// * mode: StereoBinarySGBM::MODE_SGBM
+    pub fn create(min_disparity: i32, num_disparities: i32, block_size: i32, p1: i32, p2: i32, disp12_max_diff: i32, pre_filter_cap: i32, uniqueness_ratio: i32, speckle_window_size: i32, speckle_range: i32, mode: i32) -> Result<types::PtrOfStereoBinarySGBM> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_create_int_int_int_int_int_int_int_int_int_int_int(min_disparity, num_disparities, block_size, p1, p2, disp12_max_diff, pre_filter_cap, uniqueness_ratio, speckle_window_size, speckle_range, mode) }.into_result().map(|ptr| types::PtrOfStereoBinarySGBM { ptr })
+    }
+    
+}
+
+// Generating impl for trait crate::stereo::StereoMatcher
+/// The base class for stereo correspondence algorithms.
+pub trait StereoMatcher: core::AlgorithmTrait {
+    fn as_raw_StereoMatcher(&self) -> *mut c_void;
+    /// Computes disparity map for the specified stereo pair
+    ///
+    /// ## Parameters
+    /// * left: Left 8-bit single-channel image.
+    /// * right: Right image of the same size and the same type as the left one.
+    /// * disparity: Output disparity map. It has the same size as the input images. Some algorithms,
+    /// like StereoBM or StereoSGBM compute 16-bit fixed-point disparity map (where each disparity value
+    /// has 4 fractional bits), whereas other algorithms output 32-bit floating-point disparity map.
+    fn compute(&mut self, left: &dyn core::ToInputArray, right: &dyn core::ToInputArray, disparity: &mut dyn core::ToOutputArray) -> Result<()> {
+        input_array_arg!(left);
+        input_array_arg!(right);
+        output_array_arg!(disparity);
+        unsafe { sys::cv_stereo_StereoMatcher_compute__InputArray__InputArray__OutputArray(self.as_raw_StereoMatcher(), left.as_raw__InputArray(), right.as_raw__InputArray(), disparity.as_raw__OutputArray()) }.into_result()
+    }
+    
+    fn get_min_disparity(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoMatcher_getMinDisparity_const(self.as_raw_StereoMatcher()) }.into_result()
+    }
+    
+    fn set_min_disparity(&mut self, min_disparity: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoMatcher_setMinDisparity_int(self.as_raw_StereoMatcher(), min_disparity) }.into_result()
+    }
+    
+    fn get_num_disparities(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoMatcher_getNumDisparities_const(self.as_raw_StereoMatcher()) }.into_result()
+    }
+    
+    fn set_num_disparities(&mut self, num_disparities: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoMatcher_setNumDisparities_int(self.as_raw_StereoMatcher(), num_disparities) }.into_result()
+    }
+    
+    fn get_block_size(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoMatcher_getBlockSize_const(self.as_raw_StereoMatcher()) }.into_result()
+    }
+    
+    fn set_block_size(&mut self, block_size: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoMatcher_setBlockSize_int(self.as_raw_StereoMatcher(), block_size) }.into_result()
+    }
+    
+    fn get_speckle_window_size(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoMatcher_getSpeckleWindowSize_const(self.as_raw_StereoMatcher()) }.into_result()
+    }
+    
+    fn set_speckle_window_size(&mut self, speckle_window_size: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoMatcher_setSpeckleWindowSize_int(self.as_raw_StereoMatcher(), speckle_window_size) }.into_result()
+    }
+    
+    fn get_speckle_range(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoMatcher_getSpeckleRange_const(self.as_raw_StereoMatcher()) }.into_result()
+    }
+    
+    fn set_speckle_range(&mut self, speckle_range: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoMatcher_setSpeckleRange_int(self.as_raw_StereoMatcher(), speckle_range) }.into_result()
+    }
+    
+    fn get_disp12_max_diff(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoMatcher_getDisp12MaxDiff_const(self.as_raw_StereoMatcher()) }.into_result()
+    }
+    
+    fn set_disp12_max_diff(&mut self, disp12_max_diff: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoMatcher_setDisp12MaxDiff_int(self.as_raw_StereoMatcher(), disp12_max_diff) }.into_result()
+    }
+    
+}
+
+pub const StereoMatcher_DISP_SCALE: i32 = 0x10; // 16
@@ -62,11 +62,11 @@ pub const SHADING_PHONG: i32 = 2;
 pub const WTrajectory_FRAMES: i32 = 1;
 pub const WTrajectory_PATH: i32 = 2;
 
-pub type KeyboardCallback = dyn FnMut(crate::viz::KeyboardEvent, &mut c_void) + Send + Sync + 'static;
-#[doc(hidden)] pub type KeyboardCallbackExtern = Option<extern "C" fn(unnamed_arg: *mut c_void, unnamed_arg_1: *mut c_void)>;
+pub type Viz3d_KeyboardCallback = dyn FnMut(&crate::viz::KeyboardEvent) + Send + Sync + 'static;
+#[doc(hidden)] pub type Viz3d_KeyboardCallbackExtern = Option<extern "C" fn(unnamed_arg: *mut c_void, userdata: *mut c_void)>;
 
-pub type MouseCallback = dyn FnMut(crate::viz::MouseEvent, &mut c_void) + Send + Sync + 'static;
-#[doc(hidden)] pub type MouseCallbackExtern = Option<extern "C" fn(unnamed_arg: *mut c_void, unnamed_arg_1: *mut c_void)>;
+pub type Viz3d_MouseCallback = dyn FnMut(&crate::viz::MouseEvent) + Send + Sync + 'static;
+#[doc(hidden)] pub type Viz3d_MouseCallbackExtern = Option<extern "C" fn(unnamed_arg: *mut c_void, userdata: *mut c_void)>;
 
 pub fn vec3b() -> Result<()> {
     unsafe { sys::cv_Vec3b_const() }.into_result()
@@ -130,6 +130,39 @@ pub fn is_nan_1(x: f32) -> Result<bool> {
     unsafe { sys::cv_viz_isNan_float(x) }.into_result()
 }
 
+/// Constructs camera pose from position, focal_point and up_vector (see gluLookAt() for more
+/// information).
+///
+/// ## Parameters
+/// * position: Position of the camera in global coordinate frame.
+/// * focal_point: Focal point of the camera in global coordinate frame.
+/// * y_dir: Up vector of the camera in global coordinate frame.
+///
+/// This function returns pose of the camera in global coordinate frame.
+pub fn make_camera_pose(position: core::Vec3d, focal_point: core::Vec3d, y_dir: core::Vec3d) -> Result<core::Affine3d> {
+    unsafe { sys::cv_viz_makeCameraPose_Vec3d_Vec3d_Vec3d(position, focal_point, y_dir) }.into_result()
+}
+
+/// Takes coordinate frame data and builds transform to global coordinate frame.
+///
+/// ## Parameters
+/// * axis_x: X axis vector in global coordinate frame.
+/// * axis_y: Y axis vector in global coordinate frame.
+/// * axis_z: Z axis vector in global coordinate frame.
+/// * origin: Origin of the coordinate frame in global coordinate frame.
+///
+/// ## Returns
+/// An affine transform that describes transformation between global coordinate frame
+/// and a given coordinate frame.
+/// The returned transforms can transform a point in the given coordinate frame to the global
+/// coordinate frame.
+///
+/// ## C++ default parameters
+/// * origin: Vec3d::all(0)
+pub fn make_transform_to_global(axis_x: core::Vec3d, axis_y: core::Vec3d, axis_z: core::Vec3d, origin: core::Vec3d) -> Result<core::Affine3d> {
+    unsafe { sys::cv_viz_makeTransformToGlobal_Vec3d_Vec3d_Vec3d_Vec3d(axis_x, axis_y, axis_z, origin) }.into_result()
+}
+
 /// ## Parameters
 /// * file: Filename with extension. Supported formats: PLY, XYZ, OBJ and STL.
 /// * colors: Used by PLY and STL formats only.
@@ -153,6 +186,19 @@ pub fn read_mesh(file: &str) -> Result<crate::viz::Mesh> {
     unsafe { sys::cv_viz_readMesh_String(file.as_ptr()) }.into_result().map(|ptr| crate::viz::Mesh { ptr })
 }
 
+/// ## Parameters
+/// * file: Filename of type supported by cv::FileStorage.
+/// * pose: Output matrix.
+/// * tag: Name of the pose in the file.
+///
+/// ## C++ default parameters
+/// * tag: "pose"
+pub fn read_pose(file: &str, pose: &mut core::Affine3d, tag: &str) -> Result<bool> {
+    string_arg!(file);
+    string_arg!(tag);
+    unsafe { sys::cv_viz_readPose_String_Affine3d_String(file.as_ptr(), pose, tag.as_ptr()) }.into_result()
+}
+
 /// takes vector<Affine3<T>> with T = float/dobule and loads poses from sequence of files
 ///
 /// ## Parameters
@@ -202,6 +248,19 @@ pub fn write_cloud(file: &str, cloud: &dyn core::ToInputArray, colors: &dyn core
     unsafe { sys::cv_viz_writeCloud_String__InputArray__InputArray__InputArray_bool(file.as_ptr(), cloud.as_raw__InputArray(), colors.as_raw__InputArray(), normals.as_raw__InputArray(), binary) }.into_result()
 }
 
+/// ## Parameters
+/// * file: Filename.
+/// * pose: Input matrix.
+/// * tag: Name of the pose to be saved into the given file.
+///
+/// ## C++ default parameters
+/// * tag: "pose"
+pub fn write_pose(file: &str, pose: core::Affine3d, tag: &str) -> Result<()> {
+    string_arg!(file);
+    string_arg!(tag);
+    unsafe { sys::cv_viz_writePose_String_Affine3d_String(file.as_ptr(), pose, tag.as_ptr()) }.into_result()
+}
+
 /// takes vector<Affine3<T>> with T = float/dobule and writes to a sequence of files with given filename format
 /// ## Parameters
 /// * traj: Trajectory containing a list of poses. It can be
@@ -271,6 +330,25 @@ impl Camera {
         unsafe { sys::cv_viz_Camera_Camera_Vec2d_Size(fov, window_size) }.into_result().map(|ptr| crate::viz::Camera { ptr })
     }
     
+    /// ## Parameters
+    /// * K: Intrinsic matrix of the camera with the following form
+    /// ![block formula](https://latex.codecogs.com/png.latex?%0A%5Cbegin%7Bbmatrix%7D%0Af_x%20%26%20%20%200%20%26%20c_x%5C%5C%0A0%20%26%20f_y%20%26%20c_y%5C%5C%0A0%20%26%20%20%200%20%26%20%20%201%5C%5C%0A%5Cend%7Bbmatrix%7D%0A)
+    /// * window_size: Size of the window. This together with intrinsic matrix determines
+    /// the field of view.
+    pub fn new_intrinsics(k: core::Matx33d, window_size: core::Size) -> Result<crate::viz::Camera> {
+        unsafe { sys::cv_viz_Camera_Camera_Matx33d_Size(k, window_size) }.into_result().map(|ptr| crate::viz::Camera { ptr })
+    }
+    
+    /// ## Parameters
+    /// * proj: Projection matrix of the camera with the following form
+    /// ![block formula](https://latex.codecogs.com/png.latex?%0A%5Cbegin%7Bbmatrix%7D%0A%5Cfrac%7B2n%7D%7Br-l%7D%20%26%20%20%20%20%20%20%20%200%20%20%20%20%20%20%20%26%20%5Cfrac%7Br%2Bl%7D%7Br-l%7D%20%20%26%200%5C%5C%0A0%20%20%20%20%20%20%20%20%26%20%5Cfrac%7B2n%7D%7Bt-b%7D%20%26%20%5Cfrac%7Bt%2Bb%7D%7Bt-b%7D%20%20%26%200%5C%5C%0A0%20%20%20%20%20%20%20%20%26%20%20%20%20%20%20%20%200%20%20%20%20%20%20%20%26%20-%5Cfrac%7Bf%2Bn%7D%7Bf-n%7D%20%26%20-%5Cfrac%7B2fn%7D%7Bf-n%7D%5C%5C%0A0%20%20%20%20%20%20%20%20%26%20%20%20%20%20%20%20%200%20%20%20%20%20%20%20%26%20-1%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%26%200%5C%5C%0A%5Cend%7Bbmatrix%7D%0A)
+    ///
+    /// * window_size: Size of the window. This together with projection matrix determines
+    /// the field of view.
+    pub fn new_projection(proj: core::Matx44d, window_size: core::Size) -> Result<crate::viz::Camera> {
+        unsafe { sys::cv_viz_Camera_Camera_Matx44d_Size(proj, window_size) }.into_result().map(|ptr| crate::viz::Camera { ptr })
+    }
+    
     pub fn get_clip(&self) -> Result<core::Vec2d> {
         unsafe { sys::cv_viz_Camera_getClip_const(self.as_raw_Camera()) }.into_result()
     }
@@ -303,6 +381,16 @@ impl Camera {
         unsafe { sys::cv_viz_Camera_getFocalLength_const(self.as_raw_Camera()) }.into_result()
     }
     
+    /// Computes projection matrix using intrinsic parameters of the camera.
+    ///
+    ///
+    /// ## Parameters
+    /// * proj: Output projection matrix with the following form
+    /// ![block formula](https://latex.codecogs.com/png.latex?%0A%5Cbegin%7Bbmatrix%7D%0A%5Cfrac%7B2n%7D%7Br-l%7D%20%26%20%20%20%20%20%20%20%200%20%20%20%20%20%20%20%26%20%5Cfrac%7Br%2Bl%7D%7Br-l%7D%20%20%26%200%5C%5C%0A0%20%20%20%20%20%20%20%20%26%20%5Cfrac%7B2n%7D%7Bt-b%7D%20%26%20%5Cfrac%7Bt%2Bb%7D%7Bt-b%7D%20%20%26%200%5C%5C%0A0%20%20%20%20%20%20%20%20%26%20%20%20%20%20%20%20%200%20%20%20%20%20%20%20%26%20-%5Cfrac%7Bf%2Bn%7D%7Bf-n%7D%20%26%20-%5Cfrac%7B2fn%7D%7Bf-n%7D%5C%5C%0A0%20%20%20%20%20%20%20%20%26%20%20%20%20%20%20%20%200%20%20%20%20%20%20%20%26%20-1%20%20%20%20%20%20%20%20%20%20%20%20%20%20%20%26%200%5C%5C%0A%5Cend%7Bbmatrix%7D%0A)
+    pub fn compute_projection_matrix(&self, proj: &mut core::Matx44d) -> Result<()> {
+        unsafe { sys::cv_viz_Camera_computeProjectionMatrix_const_Matx44d(self.as_raw_Camera(), proj) }.into_result()
+    }
+    
     /// Creates a Kinect Camera with
     /// - fx = fy = 525
     /// - cx = 320
@@ -525,6 +613,25 @@ impl KeyboardEvent {
 
 unsafe impl Send for KeyboardEvent {}
 
+impl KeyboardEvent {
+    pub fn action(&self) -> Result<i32> {
+        unsafe { sys::cv_viz_KeyboardEvent_action_const(self.as_raw_KeyboardEvent()) }.into_result()
+    }
+    
+    pub fn symbol(&self) -> Result<String> {
+        unsafe { sys::cv_viz_KeyboardEvent_symbol_const(self.as_raw_KeyboardEvent()) }.into_result().map(crate::templ::receive_string)
+    }
+    
+    pub fn code(&self) -> Result<u8> {
+        unsafe { sys::cv_viz_KeyboardEvent_code_const(self.as_raw_KeyboardEvent()) }.into_result()
+    }
+    
+    pub fn modifiers(&self) -> Result<i32> {
+        unsafe { sys::cv_viz_KeyboardEvent_modifiers_const(self.as_raw_KeyboardEvent()) }.into_result()
+    }
+    
+}
+
 // boxed class cv::viz::Mesh
 /// This class wraps mesh attributes, and it can load a mesh from a ply file. :
 pub struct Mesh {
@@ -589,18 +696,31 @@ impl MouseEvent {
 
 unsafe impl Send for MouseEvent {}
 
+impl MouseEvent {
+    pub fn typ(&self) -> Result<i32> {
+        unsafe { sys::cv_viz_MouseEvent_type_const(self.as_raw_MouseEvent()) }.into_result()
+    }
+    
+    pub fn button(&self) -> Result<i32> {
+        unsafe { sys::cv_viz_MouseEvent_button_const(self.as_raw_MouseEvent()) }.into_result()
+    }
+    
+    pub fn pointer(&self) -> Result<core::Point> {
+        unsafe { sys::cv_viz_MouseEvent_pointer_const(self.as_raw_MouseEvent()) }.into_result()
+    }
+    
+    pub fn modifiers(&self) -> Result<i32> {
+        unsafe { sys::cv_viz_MouseEvent_modifiers_const(self.as_raw_MouseEvent()) }.into_result()
+    }
+    
+}
+
 // boxed class cv::viz::Viz3d
 /// The Viz3d class represents a 3D visualizer window. This class is implicitly shared.
 pub struct Viz3d {
     #[doc(hidden)] pub(crate) ptr: *mut c_void
 }
 
-impl Drop for Viz3d {
-    fn drop(&mut self) {
-        unsafe { sys::cv_Viz3d_delete(self.ptr) };
-    }
-}
-
 impl Viz3d {
     #[inline(always)] pub fn as_raw_Viz3d(&self) -> *mut c_void { self.ptr }
 
@@ -628,6 +748,19 @@ impl Viz3d {
         unsafe { sys::cv_viz_Viz3d_Viz3d_Viz3d(unnamed_arg.as_raw_Viz3d()) }.into_result().map(|ptr| crate::viz::Viz3d { ptr })
     }
     
+    /// Shows a widget in the window.
+    ///
+    /// ## Parameters
+    /// * id: A unique id for the widget. @param widget The widget to be displayed in the window.
+    /// * pose: Pose of the widget.
+    ///
+    /// ## C++ default parameters
+    /// * pose: Affine3d::Identity()
+    pub fn show_widget(&mut self, id: &str, widget: &dyn crate::viz::WidgetTrait, pose: core::Affine3d) -> Result<()> {
+        string_arg!(id);
+        unsafe { sys::cv_viz_Viz3d_showWidget_String_Widget_Affine3d(self.as_raw_Viz3d(), id.as_ptr(), widget.as_raw_Widget(), pose) }.into_result()
+    }
+    
     /// Removes a widget from the window.
     ///
     /// ## Parameters
@@ -667,6 +800,34 @@ impl Viz3d {
         unsafe { sys::cv_viz_Viz3d_showImage__InputArray_Size(self.as_raw_Viz3d(), image.as_raw__InputArray(), window_size) }.into_result()
     }
     
+    /// Sets pose of a widget in the window.
+    ///
+    /// ## Parameters
+    /// * id: The id of the widget whose pose will be set. @param pose The new pose of the widget.
+    pub fn set_widget_pose(&mut self, id: &str, pose: core::Affine3d) -> Result<()> {
+        string_arg!(id);
+        unsafe { sys::cv_viz_Viz3d_setWidgetPose_String_Affine3d(self.as_raw_Viz3d(), id.as_ptr(), pose) }.into_result()
+    }
+    
+    /// Updates pose of a widget in the window by pre-multiplying its current pose.
+    ///
+    /// ## Parameters
+    /// * id: The id of the widget whose pose will be updated. @param pose The pose that the current
+    /// pose of the widget will be pre-multiplied by.
+    pub fn update_widget_pose(&mut self, id: &str, pose: core::Affine3d) -> Result<()> {
+        string_arg!(id);
+        unsafe { sys::cv_viz_Viz3d_updateWidgetPose_String_Affine3d(self.as_raw_Viz3d(), id.as_ptr(), pose) }.into_result()
+    }
+    
+    /// Returns the current pose of a widget in the window.
+    ///
+    /// ## Parameters
+    /// * id: The id of the widget whose pose will be returned.
+    pub fn get_widget_pose(&self, id: &str) -> Result<core::Affine3d> {
+        string_arg!(id);
+        unsafe { sys::cv_viz_Viz3d_getWidgetPose_const_String(self.as_raw_Viz3d(), id.as_ptr()) }.into_result()
+    }
+    
     /// Sets the intrinsic parameters of the viewer using Camera.
     ///
     /// ## Parameters
@@ -680,6 +841,19 @@ impl Viz3d {
         unsafe { sys::cv_viz_Viz3d_getCamera_const(self.as_raw_Viz3d()) }.into_result().map(|ptr| crate::viz::Camera { ptr })
     }
     
+    /// Returns the current pose of the viewer.
+    pub fn get_viewer_pose(&mut self) -> Result<core::Affine3d> {
+        unsafe { sys::cv_viz_Viz3d_getViewerPose(self.as_raw_Viz3d()) }.into_result()
+    }
+    
+    /// Sets pose of the viewer.
+    ///
+    /// ## Parameters
+    /// * pose: The new pose of the viewer.
+    pub fn set_viewer_pose(&mut self, pose: core::Affine3d) -> Result<()> {
+        unsafe { sys::cv_viz_Viz3d_setViewerPose_Affine3d(self.as_raw_Viz3d(), pose) }.into_result()
+    }
+    
     /// Resets camera viewpoint to a 3D widget in the scene.
     ///
     /// ## Parameters
@@ -1015,6 +1189,23 @@ impl WCameraPosition {
         unsafe { sys::cv_viz_WCameraPosition_WCameraPosition_double(scale) }.into_result().map(|ptr| crate::viz::WCameraPosition { ptr })
     }
     
+    /// Display the viewing frustum
+    /// ## Parameters
+    /// * K: Intrinsic matrix of the camera.
+    /// * scale: Scale of the frustum.
+    /// * color: Color of the frustum.
+    ///
+    /// Creates viewing frustum of the camera based on its intrinsic matrix K.
+    ///
+    /// ![Camera viewing frustum](https://docs.opencv.org/3.4.9/images/cpw2.png)
+    ///
+    /// ## C++ default parameters
+    /// * scale: 1.0
+    /// * color: Color::white()
+    pub fn new_intrinsics(k: core::Matx33d, scale: f64, color: &crate::viz::Color) -> Result<crate::viz::WCameraPosition> {
+        unsafe { sys::cv_viz_WCameraPosition_WCameraPosition_Matx33d_double_Color(k, scale, color.as_raw_Color()) }.into_result().map(|ptr| crate::viz::WCameraPosition { ptr })
+    }
+    
     /// Display the viewing frustum
     /// ## Parameters
     /// * fov: Field of view of the camera (horizontal, vertical).
@@ -1032,6 +1223,27 @@ impl WCameraPosition {
         unsafe { sys::cv_viz_WCameraPosition_WCameraPosition_Vec2d_double_Color(fov, scale, color.as_raw_Color()) }.into_result().map(|ptr| crate::viz::WCameraPosition { ptr })
     }
     
+    /// Display image on the far plane of the viewing frustum
+    ///
+    /// ## Parameters
+    /// * K: Intrinsic matrix of the camera.
+    /// * image: BGR or Gray-Scale image that is going to be displayed on the far plane of the frustum.
+    /// * scale: Scale of the frustum and image.
+    /// * color: Color of the frustum.
+    ///
+    /// Creates viewing frustum of the camera based on its intrinsic matrix K, and displays image on
+    /// the far end plane.
+    ///
+    /// ![Camera viewing frustum with image](https://docs.opencv.org/3.4.9/images/cpw3.png)
+    ///
+    /// ## C++ default parameters
+    /// * scale: 1.0
+    /// * color: Color::white()
+    pub fn new_intrinsics_image(k: core::Matx33d, image: &dyn core::ToInputArray, scale: f64, color: &crate::viz::Color) -> Result<crate::viz::WCameraPosition> {
+        input_array_arg!(image);
+        unsafe { sys::cv_viz_WCameraPosition_WCameraPosition_Matx33d__InputArray_double_Color(k, image.as_raw__InputArray(), scale, color.as_raw_Color()) }.into_result().map(|ptr| crate::viz::WCameraPosition { ptr })
+    }
+    
     /// Display image on the far plane of the viewing frustum
     ///
     /// ## Parameters
@@ -1246,6 +1458,36 @@ impl WCloudCollection {
         unsafe { sys::cv_viz_WCloudCollection_WCloudCollection() }.into_result().map(|ptr| crate::viz::WCloudCollection { ptr })
     }
     
+    /// Adds a cloud to the collection.
+    ///
+    /// ## Parameters
+    /// * cloud: Point set which can be of type: CV_32FC3, CV_32FC4, CV_64FC3, CV_64FC4.
+    /// * colors: Set of colors. It has to be of the same size with cloud.
+    /// * pose: Pose of the cloud. Points in the cloud belong to mask when they are set to (NaN, NaN, NaN).
+    ///
+    /// ## C++ default parameters
+    /// * pose: Affine3d::Identity()
+    pub fn add_cloud(&mut self, cloud: &dyn core::ToInputArray, colors: &dyn core::ToInputArray, pose: core::Affine3d) -> Result<()> {
+        input_array_arg!(cloud);
+        input_array_arg!(colors);
+        unsafe { sys::cv_viz_WCloudCollection_addCloud__InputArray__InputArray_Affine3d(self.as_raw_WCloudCollection(), cloud.as_raw__InputArray(), colors.as_raw__InputArray(), pose) }.into_result()
+    }
+    
+    /// Adds a cloud to the collection.
+    ///
+    /// ## Parameters
+    /// * cloud: Point set which can be of type: CV_32FC3, CV_32FC4, CV_64FC3, CV_64FC4.
+    /// * color: A single Color for the whole cloud.
+    /// * pose: Pose of the cloud. Points in the cloud belong to mask when they are set to (NaN, NaN, NaN).
+    ///
+    /// ## C++ default parameters
+    /// * color: Color::white()
+    /// * pose: Affine3d::Identity()
+    pub fn add_cloud_1(&mut self, cloud: &dyn core::ToInputArray, color: &crate::viz::Color, pose: core::Affine3d) -> Result<()> {
+        input_array_arg!(cloud);
+        unsafe { sys::cv_viz_WCloudCollection_addCloud__InputArray_Color_Affine3d(self.as_raw_WCloudCollection(), cloud.as_raw__InputArray(), color.as_raw_Color(), pose) }.into_result()
+    }
+    
     /// Finalizes cloud data by repacking to single cloud.
     ///
     /// Useful for large cloud collections to reduce memory usage
@@ -2231,6 +2473,24 @@ impl crate::viz::Widget3DTrait for WTrajectoryFrustums {
 }
 
 impl WTrajectoryFrustums {
+    /// Constructs a WTrajectoryFrustums.
+    ///
+    /// ## Parameters
+    /// * path: List of poses on a trajectory. Takes std::vector\<Affine\<T\>\> with T == [float | double]
+    /// * K: Intrinsic matrix of the camera.
+    /// * scale: Scale of the frustums.
+    /// * color: Color of the frustums.
+    ///
+    /// Displays frustums at each pose of the trajectory.
+    ///
+    /// ## C++ default parameters
+    /// * scale: 1.
+    /// * color: Color::white()
+    pub fn new_intrinsics(path: &dyn core::ToInputArray, k: core::Matx33d, scale: f64, color: &crate::viz::Color) -> Result<crate::viz::WTrajectoryFrustums> {
+        input_array_arg!(path);
+        unsafe { sys::cv_viz_WTrajectoryFrustums_WTrajectoryFrustums__InputArray_Matx33d_double_Color(path.as_raw__InputArray(), k, scale, color.as_raw_Color()) }.into_result().map(|ptr| crate::viz::WTrajectoryFrustums { ptr })
+    }
+    
     /// Constructs a WTrajectoryFrustums.
     ///
     /// ## Parameters
@@ -2346,6 +2606,14 @@ impl WWidgetMerger {
         unsafe { sys::cv_viz_WWidgetMerger_WWidgetMerger() }.into_result().map(|ptr| crate::viz::WWidgetMerger { ptr })
     }
     
+    /// Add widget to merge with optional position change
+    ///
+    /// ## C++ default parameters
+    /// * pose: Affine3d::Identity()
+    pub fn add_widget(&mut self, widget: &dyn crate::viz::Widget3DTrait, pose: core::Affine3d) -> Result<()> {
+        unsafe { sys::cv_viz_WWidgetMerger_addWidget_Widget3D_Affine3d(self.as_raw_WWidgetMerger(), widget.as_raw_Widget3D(), pose) }.into_result()
+    }
+    
     /// Repacks internal structure to single widget
     pub fn finalize(&mut self) -> Result<()> {
         unsafe { sys::cv_viz_WWidgetMerger_finalize(self.as_raw_WWidgetMerger()) }.into_result()
@@ -2518,6 +2786,35 @@ impl Widget2D {
 /// Base class of all 3D widgets.
 pub trait Widget3DTrait: crate::viz::WidgetTrait {
     fn as_raw_Widget3D(&self) -> *mut c_void;
+    /// Sets pose of the widget.
+    ///
+    /// ## Parameters
+    /// * pose: The new pose of the widget.
+    fn set_pose(&mut self, pose: core::Affine3d) -> Result<()> {
+        unsafe { sys::cv_viz_Widget3D_setPose_Affine3d(self.as_raw_Widget3D(), pose) }.into_result()
+    }
+    
+    /// Updates pose of the widget by pre-multiplying its current pose.
+    ///
+    /// ## Parameters
+    /// * pose: The pose that the current pose of the widget will be pre-multiplied by.
+    fn update_pose(&mut self, pose: core::Affine3d) -> Result<()> {
+        unsafe { sys::cv_viz_Widget3D_updatePose_Affine3d(self.as_raw_Widget3D(), pose) }.into_result()
+    }
+    
+    /// Returns the current pose of the widget.
+    fn get_pose(&self) -> Result<core::Affine3d> {
+        unsafe { sys::cv_viz_Widget3D_getPose_const(self.as_raw_Widget3D()) }.into_result()
+    }
+    
+    /// Transforms internal widget data (i.e. points, normals) using the given transform.
+    ///
+    /// ## Parameters
+    /// * transform: Specified transformation to apply.
+    fn apply_transform(&mut self, transform: core::Affine3d) -> Result<()> {
+        unsafe { sys::cv_viz_Widget3D_applyTransform_Affine3d(self.as_raw_Widget3D(), transform) }.into_result()
+    }
+    
     /// Sets the color of the widget.
     ///
     /// ## Parameters
@@ -2566,3 +2863,4 @@ impl Widget3D {
 }
 
 pub const WTrajectory_BOTH: i32 = 0x3; // 3
+pub use crate::manual::viz::*;
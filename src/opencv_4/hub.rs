@@ -10,6 +10,8 @@ pub mod calib3d;
 pub mod ccalib;
 #[cfg(feature = "contrib")]
 pub mod cvv;
+#[cfg(feature = "contrib")]
+pub mod datasets;
 pub mod dnn;
 pub mod dnn_superres;
 #[cfg(feature = "contrib")]
@@ -23,6 +25,8 @@ pub mod freetype;
 pub mod fuzzy;
 #[cfg(feature = "contrib")]
 pub mod hdf;
+#[cfg(feature = "contrib")]
+pub mod hfs;
 pub mod highgui;
 #[cfg(feature = "contrib")]
 pub mod img_hash;
@@ -33,19 +37,35 @@ pub mod line_descriptor;
 pub mod ml;
 pub mod objdetect;
 #[cfg(feature = "contrib")]
+pub mod optflow;
+#[cfg(feature = "contrib")]
 pub mod phase_unwrapping;
 pub mod photo;
 #[cfg(feature = "contrib")]
 pub mod plot;
 #[cfg(feature = "contrib")]
+pub mod quality;
+#[cfg(feature = "contrib")]
+pub mod reg;
+#[cfg(feature = "contrib")]
+pub mod rgbd;
+#[cfg(feature = "contrib")]
+pub mod saliency;
+#[cfg(feature = "contrib")]
 pub mod sfm;
 #[cfg(feature = "contrib")]
 pub mod shape;
+#[cfg(feature = "contrib")]
+pub mod stereo;
 pub mod stitching;
 #[cfg(feature = "contrib")]
 pub mod structured_light;
 #[cfg(feature = "contrib")]
 pub mod superres;
+#[cfg(feature = "contrib")]
+pub mod surface_matching;
+#[cfg(feature = "contrib")]
+pub mod text;
 pub mod video;
 pub mod videoio;
 #[cfg(feature = "contrib")]
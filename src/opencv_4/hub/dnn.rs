@@ -205,7 +205,7 @@ pub fn clamp(ax: i32, dims: i32) -> Result<i32> {
 }
 
 pub fn get_available_targets(be: crate::dnn::Backend) -> Result<types::VectorOfTarget> {
-    unsafe { sys::cv_dnn_getAvailableTargets_Backend(be) }.into_result().map(|ptr| types::VectorOfTarget { ptr })
+    unsafe { sys::cv_dnn_getAvailableTargets_Backend(be) }.into_result().map(|ptr| unsafe { types::VectorOfTarget::from_raw_ptr(ptr) })
 }
 
 /// Returns Inference Engine internal backend API.
@@ -587,15 +587,15 @@ pub fn set_inference_engine_backend_type(new_backend_type: &str) -> Result<Strin
 }
 
 pub fn shape(mat: &core::Mat) -> Result<types::VectorOfint> {
-    unsafe { sys::cv_dnn_shape_Mat(mat.as_raw_Mat()) }.into_result().map(|ptr| types::VectorOfint { ptr })
+    unsafe { sys::cv_dnn_shape_Mat(mat.as_raw_Mat()) }.into_result().map(|ptr| unsafe { types::VectorOfint::from_raw_ptr(ptr) })
 }
 
 pub fn shape_umat(mat: &core::UMat) -> Result<types::VectorOfint> {
-    unsafe { sys::cv_dnn_shape_UMat(mat.as_raw_UMat()) }.into_result().map(|ptr| types::VectorOfint { ptr })
+    unsafe { sys::cv_dnn_shape_UMat(mat.as_raw_UMat()) }.into_result().map(|ptr| unsafe { types::VectorOfint::from_raw_ptr(ptr) })
 }
 
 pub fn shape_nd(dims: &i32, n: i32) -> Result<types::VectorOfint> {
-    unsafe { sys::cv_dnn_shape_const_int_X_int(dims, n) }.into_result().map(|ptr| types::VectorOfint { ptr })
+    unsafe { sys::cv_dnn_shape_const_int_X_int(dims, n) }.into_result().map(|ptr| unsafe { types::VectorOfint::from_raw_ptr(ptr) })
 }
 
 ///
@@ -604,7 +604,7 @@ pub fn shape_nd(dims: &i32, n: i32) -> Result<types::VectorOfint> {
 /// * a2: -1
 /// * a3: -1
 pub fn shape_3d(a0: i32, a1: i32, a2: i32, a3: i32) -> Result<types::VectorOfint> {
-    unsafe { sys::cv_dnn_shape_int_int_int_int(a0, a1, a2, a3) }.into_result().map(|ptr| types::VectorOfint { ptr })
+    unsafe { sys::cv_dnn_shape_int_int_int_int(a0, a1, a2, a3) }.into_result().map(|ptr| unsafe { types::VectorOfint::from_raw_ptr(ptr) })
 }
 
 /// Convert all weights of Caffe network to half precision floating point.
@@ -1786,7 +1786,7 @@ impl KeypointsModel {
     /// * thresh: 0.5
     pub fn estimate(&mut self, frame: &dyn core::ToInputArray, thresh: f32) -> Result<types::VectorOfPoint2f> {
         input_array_arg!(frame);
-        unsafe { sys::cv_dnn_KeypointsModel_estimate__InputArray_float(self.as_raw_KeypointsModel(), frame.as_raw__InputArray(), thresh) }.into_result().map(|ptr| types::VectorOfPoint2f { ptr })
+        unsafe { sys::cv_dnn_KeypointsModel_estimate__InputArray_float(self.as_raw_KeypointsModel(), frame.as_raw__InputArray(), thresh) }.into_result().map(|ptr| unsafe { types::VectorOfPoint2f::from_raw_ptr(ptr) })
     }
     
 }
@@ -1927,7 +1927,7 @@ pub trait LayerTrait: core::AlgorithmTrait {
     fn as_raw_Layer(&self) -> *mut c_void;
     /// List of learned parameters must be stored here to allow read them by using Net::getParam().
     fn blobs(&mut self) -> Result<types::VectorOfMat> {
-        unsafe { sys::cv_dnn_Layer_blobs(self.as_raw_Layer()) }.into_result().map(|ptr| types::VectorOfMat { ptr })
+        unsafe { sys::cv_dnn_Layer_blobs(self.as_raw_Layer()) }.into_result().map(|ptr| unsafe { types::VectorOfMat::from_raw_ptr(ptr) })
     }
     
     /// List of learned parameters must be stored here to allow read them by using Net::getParam().
@@ -2007,7 +2007,7 @@ pub trait LayerTrait: core::AlgorithmTrait {
     /// **Deprecated**: Use Layer::finalize(InputArrayOfArrays, OutputArrayOfArrays) instead
     #[deprecated = "Use Layer::finalize(InputArrayOfArrays, OutputArrayOfArrays) instead"]
     fn finalize_mat(&mut self, inputs: &types::VectorOfMat) -> Result<types::VectorOfMat> {
-        unsafe { sys::cv_dnn_Layer_finalize_VectorOfMat(self.as_raw_Layer(), inputs.as_raw_VectorOfMat()) }.into_result().map(|ptr| types::VectorOfMat { ptr })
+        unsafe { sys::cv_dnn_Layer_finalize_VectorOfMat(self.as_raw_Layer(), inputs.as_raw_VectorOfMat()) }.into_result().map(|ptr| unsafe { types::VectorOfMat::from_raw_ptr(ptr) })
     }
     
     /// Allocates layer and computes output.
@@ -2263,7 +2263,7 @@ impl crate::dnn::DictTrait for LayerParams {
 impl LayerParams {
     /// List of learned parameters stored as blobs.
     pub fn blobs(&mut self) -> Result<types::VectorOfMat> {
-        unsafe { sys::cv_dnn_LayerParams_blobs(self.as_raw_LayerParams()) }.into_result().map(|ptr| types::VectorOfMat { ptr })
+        unsafe { sys::cv_dnn_LayerParams_blobs(self.as_raw_LayerParams()) }.into_result().map(|ptr| unsafe { types::VectorOfMat::from_raw_ptr(ptr) })
     }
     
     /// List of learned parameters stored as blobs.
@@ -2625,7 +2625,7 @@ pub trait NetTrait {
     }
     
     fn get_layer_names(&self) -> Result<types::VectorOfString> {
-        unsafe { sys::cv_dnn_Net_getLayerNames_const(self.as_raw_Net()) }.into_result().map(|ptr| types::VectorOfString { ptr })
+        unsafe { sys::cv_dnn_Net_getLayerNames_const(self.as_raw_Net()) }.into_result().map(|ptr| unsafe { types::VectorOfString::from_raw_ptr(ptr) })
     }
     
     /// Returns pointer to layer with specified id or name which the network use.
@@ -2635,7 +2635,7 @@ pub trait NetTrait {
     
     /// Returns pointers to input layers of specific layer.
     fn get_layer_inputs(&mut self, layer_id: &crate::dnn::DictValue) -> Result<types::VectorOfPtrOfLayer> {
-        unsafe { sys::cv_dnn_Net_getLayerInputs_DictValue(self.as_raw_Net(), layer_id.as_raw_DictValue()) }.into_result().map(|ptr| types::VectorOfPtrOfLayer { ptr })
+        unsafe { sys::cv_dnn_Net_getLayerInputs_DictValue(self.as_raw_Net(), layer_id.as_raw_DictValue()) }.into_result().map(|ptr| unsafe { types::VectorOfPtrOfLayer::from_raw_ptr(ptr) })
     }
     
     /// Connects output of the first layer to input of the second layer.
@@ -2814,12 +2814,12 @@ pub trait NetTrait {
     
     /// Returns indexes of layers with unconnected outputs.
     fn get_unconnected_out_layers(&self) -> Result<types::VectorOfint> {
-        unsafe { sys::cv_dnn_Net_getUnconnectedOutLayers_const(self.as_raw_Net()) }.into_result().map(|ptr| types::VectorOfint { ptr })
+        unsafe { sys::cv_dnn_Net_getUnconnectedOutLayers_const(self.as_raw_Net()) }.into_result().map(|ptr| unsafe { types::VectorOfint::from_raw_ptr(ptr) })
     }
     
     /// Returns names of layers with unconnected outputs.
     fn get_unconnected_out_layers_names(&self) -> Result<types::VectorOfString> {
-        unsafe { sys::cv_dnn_Net_getUnconnectedOutLayersNames_const(self.as_raw_Net()) }.into_result().map(|ptr| types::VectorOfString { ptr })
+        unsafe { sys::cv_dnn_Net_getUnconnectedOutLayersNames_const(self.as_raw_Net()) }.into_result().map(|ptr| unsafe { types::VectorOfString::from_raw_ptr(ptr) })
     }
     
     /// Returns input and output shapes for all layers in loaded model;
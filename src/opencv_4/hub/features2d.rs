@@ -40,7 +40,9 @@ pub const AKAZE_DESCRIPTOR_MLDB_UPRIGHT: i32 = 4;
 pub const AgastFeatureDetector_AGAST_5_8: i32 = 0;
 pub const AgastFeatureDetector_AGAST_7_12d: i32 = 1;
 pub const AgastFeatureDetector_AGAST_7_12s: i32 = 2;
+pub const AgastFeatureDetector_NONMAX_SUPPRESSION: i32 = 10001;
 pub const AgastFeatureDetector_OAST_9_16: i32 = 3;
+pub const AgastFeatureDetector_THRESHOLD: i32 = 10000;
 pub const CV_HAL_TYPE_5_8: i32 = 0;
 pub const CV_HAL_TYPE_7_12: i32 = 1;
 pub const CV_HAL_TYPE_9_16: i32 = 2;
@@ -790,7 +792,7 @@ pub trait BOWTrainer {
     
     /// Returns a training set of descriptors.
     fn get_descriptors(&self) -> Result<types::VectorOfMat> {
-        unsafe { sys::cv_BOWTrainer_getDescriptors_const(self.as_raw_BOWTrainer()) }.into_result().map(|ptr| types::VectorOfMat { ptr })
+        unsafe { sys::cv_BOWTrainer_getDescriptors_const(self.as_raw_BOWTrainer()) }.into_result().map(|ptr| unsafe { types::VectorOfMat::from_raw_ptr(ptr) })
     }
     
     /// Returns the count of all descriptors stored in the training set.
@@ -962,7 +964,7 @@ pub trait DescriptorMatcher: core::AlgorithmTrait {
     
     /// Returns a constant link to the train descriptor collection trainDescCollection .
     fn get_train_descriptors(&self) -> Result<types::VectorOfMat> {
-        unsafe { sys::cv_DescriptorMatcher_getTrainDescriptors_const(self.as_raw_DescriptorMatcher()) }.into_result().map(|ptr| types::VectorOfMat { ptr })
+        unsafe { sys::cv_DescriptorMatcher_getTrainDescriptors_const(self.as_raw_DescriptorMatcher()) }.into_result().map(|ptr| unsafe { types::VectorOfMat::from_raw_ptr(ptr) })
     }
     
     /// Clears the train descriptor collections.
@@ -2025,3 +2027,4 @@ impl SimpleBlobDetector_Params {
     
 }
 
+pub use crate::manual::features2d::*;
@@ -146,7 +146,7 @@ impl BlockMeanHash {
     }
     
     pub fn get_mean(&self) -> Result<types::VectorOfdouble> {
-        unsafe { sys::cv_img_hash_BlockMeanHash_getMean_const(self.as_raw_BlockMeanHash()) }.into_result().map(|ptr| types::VectorOfdouble { ptr })
+        unsafe { sys::cv_img_hash_BlockMeanHash_getMean_const(self.as_raw_BlockMeanHash()) }.into_result().map(|ptr| unsafe { types::VectorOfdouble::from_raw_ptr(ptr) })
     }
     
     ///
@@ -396,7 +396,7 @@ impl RadialVarianceHash {
     }
     
     pub fn get_features(&mut self) -> Result<types::VectorOfdouble> {
-        unsafe { sys::cv_img_hash_RadialVarianceHash_getFeatures(self.as_raw_RadialVarianceHash()) }.into_result().map(|ptr| types::VectorOfdouble { ptr })
+        unsafe { sys::cv_img_hash_RadialVarianceHash_getFeatures(self.as_raw_RadialVarianceHash()) }.into_result().map(|ptr| unsafe { types::VectorOfdouble::from_raw_ptr(ptr) })
     }
     
     pub fn get_hash(&mut self) -> Result<core::Mat> {
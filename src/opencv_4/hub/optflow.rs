@@ -0,0 +1,1516 @@
+//! # Optical Flow Algorithms
+//!
+//! Dense optical flow algorithms compute motion for each point:
+//!
+//! - cv::optflow::calcOpticalFlowSF
+//! - cv::optflow::createOptFlow_DeepFlow
+//!
+//! Motion templates is alternative technique for detecting motion and computing its direction.
+//! See samples/motempl.py.
+//!
+//! - cv::motempl::updateMotionHistory
+//! - cv::motempl::calcMotionGradient
+//! - cv::motempl::calcGlobalOrientation
+//! - cv::motempl::segmentMotion
+//!
+//! Functions reading and writing .flo files in "Middlebury" format, see: <http://vision.middlebury.edu/flow/code/flow-code/README.txt>
+//!
+//! - cv::optflow::readOpticalFlow
+//! - cv::optflow::writeOpticalFlow
+use crate::{mod_prelude::*, core, sys, types};
+use crate::core::{_InputArrayTrait, _OutputArrayTrait};
+
+/// Better quality but slow
+pub const GPC_DESCRIPTOR_DCT: i32 = 0;
+/// Worse quality but much faster
+pub const GPC_DESCRIPTOR_WHT: i32 = 0+1;
+pub const INTERP_EPIC: i32 = 1;
+pub const INTERP_GEO: i32 = 0;
+pub const INTERP_RIC: i32 = 2;
+pub const SR_CROSS: i32 = 1;
+pub const SR_FIXED: i32 = 0;
+pub const ST_BILINEAR: i32 = 1;
+pub const ST_STANDART: i32 = 0;
+
+/// Descriptor types for the Global Patch Collider.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum GPCDescType {
+    /// Better quality but slow
+    GPC_DESCRIPTOR_DCT = GPC_DESCRIPTOR_DCT as isize,
+    /// Worse quality but much faster
+    GPC_DESCRIPTOR_WHT = GPC_DESCRIPTOR_WHT as isize,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum InterpolationType {
+    INTERP_GEO = INTERP_GEO as isize,
+    INTERP_EPIC = INTERP_EPIC as isize,
+    INTERP_RIC = INTERP_RIC as isize,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SolverType {
+    ST_STANDART = ST_STANDART as isize,
+    ST_BILINEAR = ST_BILINEAR as isize,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SupportRegionType {
+    SR_FIXED = SR_FIXED as isize,
+    SR_CROSS = SR_CROSS as isize,
+}
+
+/// Calculates a global motion orientation in a selected region.
+///
+/// ## Parameters
+/// * orientation: Motion gradient orientation image calculated by the function calcMotionGradient
+/// * mask: Mask image. It may be a conjunction of a valid gradient mask, also calculated by
+/// calcMotionGradient , and the mask of a region whose direction needs to be calculated.
+/// * mhi: Motion history image calculated by updateMotionHistory .
+/// * timestamp: Timestamp passed to updateMotionHistory .
+/// * duration: Maximum duration of a motion track in milliseconds, passed to updateMotionHistory
+///
+/// The function calculates an average motion direction in the selected region and returns the angle
+/// between 0 degrees and 360 degrees. The average direction is computed from the weighted orientation
+/// histogram, where a recent motion has a larger weight and the motion occurred in the past has a
+/// smaller weight, as recorded in mhi .
+pub fn calc_global_orientation(orientation: &dyn core::ToInputArray, mask: &dyn core::ToInputArray, mhi: &dyn core::ToInputArray, timestamp: f64, duration: f64) -> Result<f64> {
+    input_array_arg!(orientation);
+    input_array_arg!(mask);
+    input_array_arg!(mhi);
+    unsafe { sys::cv_motempl_calcGlobalOrientation__InputArray__InputArray__InputArray_double_double(orientation.as_raw__InputArray(), mask.as_raw__InputArray(), mhi.as_raw__InputArray(), timestamp, duration) }.into_result()
+}
+
+/// Calculates a gradient orientation of a motion history image.
+///
+/// ## Parameters
+/// * mhi: Motion history single-channel floating-point image.
+/// * mask: Output mask image that has the type CV_8UC1 and the same size as mhi . Its non-zero
+/// elements mark pixels where the motion gradient data is correct.
+/// * orientation: Output motion gradient orientation image that has the same type and the same
+/// size as mhi . Each pixel of the image is a motion orientation, from 0 to 360 degrees.
+/// * delta1: Minimal (or maximal) allowed difference between mhi values within a pixel
+/// neighborhood.
+/// * delta2: Maximal (or minimal) allowed difference between mhi values within a pixel
+/// neighborhood. That is, the function finds the minimum ( ![inline formula](https://latex.codecogs.com/png.latex?m%28x%2Cy%29) ) and maximum ( ![inline formula](https://latex.codecogs.com/png.latex?M%28x%2Cy%29) ) mhi
+/// values over ![inline formula](https://latex.codecogs.com/png.latex?3%20%5Ctimes%203) neighborhood of each pixel and marks the motion orientation at ![inline formula](https://latex.codecogs.com/png.latex?%28x%2C%20y%29)
+/// as valid only if
+/// ![block formula](https://latex.codecogs.com/png.latex?%5Cmin%20%28%20%5Ctexttt%7Bdelta1%7D%20%20%2C%20%20%5Ctexttt%7Bdelta2%7D%20%20%29%20%20%5Cle%20%20M%28x%2Cy%29-m%28x%2Cy%29%20%20%5Cle%20%20%20%5Cmax%20%28%20%5Ctexttt%7Bdelta1%7D%20%20%2C%20%5Ctexttt%7Bdelta2%7D%20%29.)
+/// * apertureSize: Aperture size of the Sobel operator.
+///
+/// The function calculates a gradient orientation at each pixel ![inline formula](https://latex.codecogs.com/png.latex?%28x%2C%20y%29) as:
+///
+/// ![block formula](https://latex.codecogs.com/png.latex?%5Ctexttt%7Borientation%7D%20%28x%2Cy%29%3D%20%5Carctan%7B%5Cfrac%7Bd%5Ctexttt%7Bmhi%7D%2Fdy%7D%7Bd%5Ctexttt%7Bmhi%7D%2Fdx%7D%7D)
+///
+/// In fact, fastAtan2 and phase are used so that the computed angle is measured in degrees and covers
+/// the full range 0..360. Also, the mask is filled to indicate pixels where the computed angle is
+/// valid.
+///
+///
+/// Note:
+/// *   (Python) An example on how to perform a motion template technique can be found at
+/// opencv_source_code/samples/python2/motempl.py
+///
+/// ## C++ default parameters
+/// * aperture_size: 3
+pub fn calc_motion_gradient(mhi: &dyn core::ToInputArray, mask: &mut dyn core::ToOutputArray, orientation: &mut dyn core::ToOutputArray, delta1: f64, delta2: f64, aperture_size: i32) -> Result<()> {
+    input_array_arg!(mhi);
+    output_array_arg!(mask);
+    output_array_arg!(orientation);
+    unsafe { sys::cv_motempl_calcMotionGradient__InputArray__OutputArray__OutputArray_double_double_int(mhi.as_raw__InputArray(), mask.as_raw__OutputArray(), orientation.as_raw__OutputArray(), delta1, delta2, aperture_size) }.into_result()
+}
+
+/// Splits a motion history image into a few parts corresponding to separate independent motions (for
+/// example, left hand, right hand).
+///
+/// ## Parameters
+/// * mhi: Motion history image.
+/// * segmask: Image where the found mask should be stored, single-channel, 32-bit floating-point.
+/// * boundingRects: Vector containing ROIs of motion connected components.
+/// * timestamp: Current time in milliseconds or other units.
+/// * segThresh: Segmentation threshold that is recommended to be equal to the interval between
+/// motion history "steps" or greater.
+///
+/// The function finds all of the motion segments and marks them in segmask with individual values
+/// (1,2,...). It also computes a vector with ROIs of motion connected components. After that the motion
+/// direction for every component can be calculated with calcGlobalOrientation using the extracted mask
+/// of the particular component.
+pub fn segment_motion(mhi: &dyn core::ToInputArray, segmask: &mut dyn core::ToOutputArray, bounding_rects: &mut types::VectorOfRect, timestamp: f64, seg_thresh: f64) -> Result<()> {
+    input_array_arg!(mhi);
+    output_array_arg!(segmask);
+    unsafe { sys::cv_motempl_segmentMotion__InputArray__OutputArray_VectorOfRect_double_double(mhi.as_raw__InputArray(), segmask.as_raw__OutputArray(), bounding_rects.as_raw_VectorOfRect(), timestamp, seg_thresh) }.into_result()
+}
+
+/// Updates the motion history image by a moving silhouette.
+///
+/// ## Parameters
+/// * silhouette: Silhouette mask that has non-zero pixels where the motion occurs.
+/// * mhi: Motion history image that is updated by the function (single-channel, 32-bit
+/// floating-point).
+/// * timestamp: Current time in milliseconds or other units.
+/// * duration: Maximal duration of the motion track in the same units as timestamp .
+///
+/// The function updates the motion history image as follows:
+///
+/// ![block formula](https://latex.codecogs.com/png.latex?%5Ctexttt%7Bmhi%7D%20%28x%2Cy%29%3D%20%5Cforkthree%7B%5Ctexttt%7Btimestamp%7D%7D%7Bif%20%5C%28%5Ctexttt%7Bsilhouette%7D%28x%2Cy%29%20%5Cne%200%5C%29%7D%7B0%7D%7Bif%20%5C%28%5Ctexttt%7Bsilhouette%7D%28x%2Cy%29%20%3D%200%5C%29%20and%20%5C%28%5Ctexttt%7Bmhi%7D%20%3C%20%28%5Ctexttt%7Btimestamp%7D%20-%20%5Ctexttt%7Bduration%7D%29%5C%29%7D%7B%5Ctexttt%7Bmhi%7D%28x%2Cy%29%7D%7Botherwise%7D)
+///
+/// That is, MHI pixels where the motion occurs are set to the current timestamp , while the pixels
+/// where the motion happened last time a long time ago are cleared.
+///
+/// The function, together with calcMotionGradient and calcGlobalOrientation , implements a motion
+/// templates technique described in [Davis97](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Davis97) and [Bradski00](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Bradski00) .
+pub fn update_motion_history(silhouette: &dyn core::ToInputArray, mhi: &mut dyn core::ToInputOutputArray, timestamp: f64, duration: f64) -> Result<()> {
+    input_array_arg!(silhouette);
+    input_output_array_arg!(mhi);
+    unsafe { sys::cv_motempl_updateMotionHistory__InputArray__InputOutputArray_double_double(silhouette.as_raw__InputArray(), mhi.as_raw__InputOutputArray(), timestamp, duration) }.into_result()
+}
+
+/// Fast dense optical flow computation based on robust local optical flow (RLOF) algorithms and sparse-to-dense interpolation scheme.
+///
+/// The RLOF is a fast local optical flow approach described in [Senst2012](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2012) [Senst2013](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2013) [Senst2014](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2014)
+/// and [Senst2016](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2016) similar to the pyramidal iterative Lucas-Kanade method as
+/// proposed by [Bouguet00](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Bouguet00). More details and experiments can be found in the following thesis [Senst2019](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2019).
+/// The implementation is derived from optflow::calcOpticalFlowPyrLK().
+///
+/// The sparse-to-dense interpolation scheme allows for fast computation of dense optical flow using RLOF (see [Geistert2016](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Geistert2016)).
+/// For this scheme the following steps are applied:
+/// -# motion vector seeded at a regular sampled grid are computed. The sparsity of this grid can be configured with setGridStep
+/// -# (optinally) errornous motion vectors are filter based on the forward backward confidence. The threshold can be configured
+/// with setForwardBackward. The filter is only applied if the threshold >0 but than the runtime is doubled due to the estimation
+/// of the backward flow.
+/// -# Vector field interpolation is applied to the motion vector set to obtain a dense vector field.
+///
+/// ## Parameters
+/// * I0: first 8-bit input image. If The cross-based RLOF is used (by selecting optflow::RLOFOpticalFlowParameter::supportRegionType
+/// = SupportRegionType::SR_CROSS) image has to be a 8-bit 3 channel image.
+/// * I1: second 8-bit input image. If The cross-based RLOF is used (by selecting optflow::RLOFOpticalFlowParameter::supportRegionType
+/// = SupportRegionType::SR_CROSS) image has to be a 8-bit 3 channel image.
+/// * flow: computed flow image that has the same size as I0 and type CV_32FC2.
+/// * rlofParam: see optflow::RLOFOpticalFlowParameter
+/// * forwardBackwardThreshold: Threshold for the forward backward confidence check.
+/// For each grid point ![inline formula](https://latex.codecogs.com/png.latex?%20%5Cmathbf%7Bx%7D%20) a motion vector ![inline formula](https://latex.codecogs.com/png.latex?%20d_%7BI0%2CI1%7D%28%5Cmathbf%7Bx%7D%29%20) is computed.
+/// If the forward backward error ![block formula](https://latex.codecogs.com/png.latex?%20EP_%7BFB%7D%20%3D%20%7C%7C%20d_%7BI0%2CI1%7D%20%2B%20d_%7BI1%2CI0%7D%20%7C%7C%20)
+/// is larger than threshold given by this function then the motion vector will not be used by the following
+/// vector field interpolation. ![inline formula](https://latex.codecogs.com/png.latex?%20d_%7BI1%2CI0%7D%20) denotes the backward flow. Note, the forward backward test
+///    will only be applied if the threshold > 0. This may results into a doubled runtime for the motion estimation.
+/// * gridStep: Size of the grid to spawn the motion vectors. For each grid point a motion vector is computed.
+/// Some motion vectors will be removed due to the forwatd backward threshold (if set >0). The rest will be the
+/// base of the vector field interpolation.
+/// * interp_type: interpolation method used to compute the dense optical flow. Two interpolation algorithms are
+/// supported:
+/// - **INTERP_GEO** applies the fast geodesic interpolation, see [Geistert2016](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Geistert2016).
+/// - **INTERP_EPIC_RESIDUAL** applies the edge-preserving interpolation, see [Revaud2015](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Revaud2015),Geistert2016.
+/// * epicK: see ximgproc::EdgeAwareInterpolator sets the respective parameter.
+/// * epicSigma: see ximgproc::EdgeAwareInterpolator sets the respective parameter.
+/// * epicLambda: see ximgproc::EdgeAwareInterpolator sets the respective parameter.
+/// * ricSPSize: see ximgproc::RICInterpolator sets the respective parameter.
+/// * ricSLICType: see ximgproc::RICInterpolator sets the respective parameter.
+/// * use_post_proc: enables ximgproc::fastGlobalSmootherFilter() parameter.
+/// * fgsLambda: sets the respective ximgproc::fastGlobalSmootherFilter() parameter.
+/// * fgsSigma: sets the respective ximgproc::fastGlobalSmootherFilter() parameter.
+/// * use_variational_refinement: enables VariationalRefinement
+///
+/// Parameters have been described in [Senst2012](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2012), [Senst2013](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2013), [Senst2014](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2014), [Senst2016](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2016).
+/// For the RLOF configuration see optflow::RLOFOpticalFlowParameter for further details.
+///
+/// Note: If the grid size is set to (1,1) and the forward backward threshold <= 0 that the dense optical flow field is purely
+/// computed with the RLOF.
+///
+///
+/// Note: SIMD parallelization is only available when compiling with SSE4.1.
+///
+/// ## See also
+/// optflow::DenseRLOFOpticalFlow, optflow::RLOFOpticalFlowParameter
+///
+/// ## C++ default parameters
+/// * rlof_param: Ptr<RLOFOpticalFlowParameter>()
+/// * forward_backward_threshold: 0
+/// * grid_step: Size(6, 6)
+/// * interp_type: InterpolationType::INTERP_EPIC
+/// * epic_k: 128
+/// * epic_sigma: 0.05f
+/// * epic_lambda: 100.f
+/// * ric_sp_size: 15
+/// * ric_slic_type: 100
+/// * use_post_proc: true
+/// * fgs_lambda: 500.0f
+/// * fgs_sigma: 1.5f
+/// * use_variational_refinement: false
+pub fn calc_optical_flow_dense_rlof(i0: &dyn core::ToInputArray, i1: &dyn core::ToInputArray, flow: &mut dyn core::ToInputOutputArray, rlof_param: &types::PtrOfRLOFOpticalFlowParameter, forward_backward_threshold: f32, grid_step: core::Size, interp_type: crate::optflow::InterpolationType, epic_k: i32, epic_sigma: f32, epic_lambda: f32, ric_sp_size: i32, ric_slic_type: i32, use_post_proc: bool, fgs_lambda: f32, fgs_sigma: f32, use_variational_refinement: bool) -> Result<()> {
+    input_array_arg!(i0);
+    input_array_arg!(i1);
+    input_output_array_arg!(flow);
+    unsafe { sys::cv_optflow_calcOpticalFlowDenseRLOF__InputArray__InputArray__InputOutputArray_PtrOfRLOFOpticalFlowParameter_float_Size_InterpolationType_int_float_float_int_int_bool_float_float_bool(i0.as_raw__InputArray(), i1.as_raw__InputArray(), flow.as_raw__InputOutputArray(), rlof_param.as_raw_PtrOfRLOFOpticalFlowParameter(), forward_backward_threshold, grid_step, interp_type, epic_k, epic_sigma, epic_lambda, ric_sp_size, ric_slic_type, use_post_proc, fgs_lambda, fgs_sigma, use_variational_refinement) }.into_result()
+}
+
+/// Calculate an optical flow using "SimpleFlow" algorithm.
+///
+/// ## Parameters
+/// * from: First 8-bit 3-channel image.
+/// * to: Second 8-bit 3-channel image of the same size as prev
+/// * flow: computed flow image that has the same size as prev and type CV_32FC2
+/// * layers: Number of layers
+/// * averaging_block_size: Size of block through which we sum up when calculate cost function
+/// for pixel
+/// * max_flow: maximal flow that we search at each level
+/// * sigma_dist: vector smooth spatial sigma parameter
+/// * sigma_color: vector smooth color sigma parameter
+/// * postprocess_window: window size for postprocess cross bilateral filter
+/// * sigma_dist_fix: spatial sigma for postprocess cross bilateralf filter
+/// * sigma_color_fix: color sigma for postprocess cross bilateral filter
+/// * occ_thr: threshold for detecting occlusions
+/// * upscale_averaging_radius: window size for bilateral upscale operation
+/// * upscale_sigma_dist: spatial sigma for bilateral upscale operation
+/// * upscale_sigma_color: color sigma for bilateral upscale operation
+/// * speed_up_thr: threshold to detect point with irregular flow - where flow should be
+/// recalculated after upscale
+///
+/// See [Tao2012](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Tao2012) . And site of project - <http://graphics.berkeley.edu/papers/Tao-SAN-2012-05/>.
+///
+///
+/// Note:
+/// *   An example using the simpleFlow algorithm can be found at samples/simpleflow_demo.cpp
+///
+/// ## Overloaded parameters
+pub fn calc_optical_flow_sf(from: &dyn core::ToInputArray, to: &dyn core::ToInputArray, flow: &mut dyn core::ToOutputArray, layers: i32, averaging_block_size: i32, max_flow: i32) -> Result<()> {
+    input_array_arg!(from);
+    input_array_arg!(to);
+    output_array_arg!(flow);
+    unsafe { sys::cv_optflow_calcOpticalFlowSF__InputArray__InputArray__OutputArray_int_int_int(from.as_raw__InputArray(), to.as_raw__InputArray(), flow.as_raw__OutputArray(), layers, averaging_block_size, max_flow) }.into_result()
+}
+
+/// Calculate an optical flow using "SimpleFlow" algorithm.
+///
+/// ## Parameters
+/// * from: First 8-bit 3-channel image.
+/// * to: Second 8-bit 3-channel image of the same size as prev
+/// * flow: computed flow image that has the same size as prev and type CV_32FC2
+/// * layers: Number of layers
+/// * averaging_block_size: Size of block through which we sum up when calculate cost function
+/// for pixel
+/// * max_flow: maximal flow that we search at each level
+/// * sigma_dist: vector smooth spatial sigma parameter
+/// * sigma_color: vector smooth color sigma parameter
+/// * postprocess_window: window size for postprocess cross bilateral filter
+/// * sigma_dist_fix: spatial sigma for postprocess cross bilateralf filter
+/// * sigma_color_fix: color sigma for postprocess cross bilateral filter
+/// * occ_thr: threshold for detecting occlusions
+/// * upscale_averaging_radius: window size for bilateral upscale operation
+/// * upscale_sigma_dist: spatial sigma for bilateral upscale operation
+/// * upscale_sigma_color: color sigma for bilateral upscale operation
+/// * speed_up_thr: threshold to detect point with irregular flow - where flow should be
+/// recalculated after upscale
+///
+/// See [Tao2012](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Tao2012) . And site of project - <http://graphics.berkeley.edu/papers/Tao-SAN-2012-05/>.
+///
+///
+/// Note:
+/// *   An example using the simpleFlow algorithm can be found at samples/simpleflow_demo.cpp
+pub fn calc_optical_flow_sf_1(from: &dyn core::ToInputArray, to: &dyn core::ToInputArray, flow: &mut dyn core::ToOutputArray, layers: i32, averaging_block_size: i32, max_flow: i32, sigma_dist: f64, sigma_color: f64, postprocess_window: i32, sigma_dist_fix: f64, sigma_color_fix: f64, occ_thr: f64, upscale_averaging_radius: i32, upscale_sigma_dist: f64, upscale_sigma_color: f64, speed_up_thr: f64) -> Result<()> {
+    input_array_arg!(from);
+    input_array_arg!(to);
+    output_array_arg!(flow);
+    unsafe { sys::cv_optflow_calcOpticalFlowSF__InputArray__InputArray__OutputArray_int_int_int_double_double_int_double_double_double_int_double_double_double(from.as_raw__InputArray(), to.as_raw__InputArray(), flow.as_raw__OutputArray(), layers, averaging_block_size, max_flow, sigma_dist, sigma_color, postprocess_window, sigma_dist_fix, sigma_color_fix, occ_thr, upscale_averaging_radius, upscale_sigma_dist, upscale_sigma_color, speed_up_thr) }.into_result()
+}
+
+/// Calculates fast optical flow for a sparse feature set using the robust local optical flow (RLOF) similar
+/// to optflow::calcOpticalFlowPyrLK().
+///
+/// The RLOF is a fast local optical flow approach described in [Senst2012](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2012) [Senst2013](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2013) [Senst2014](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2014)
+/// and [Senst2016](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2016) similar to the pyramidal iterative Lucas-Kanade method as
+/// proposed by [Bouguet00](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Bouguet00). More details and experiments can be found in the following thesis [Senst2019](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2019).
+/// The implementation is derived from optflow::calcOpticalFlowPyrLK().
+///
+/// ## Parameters
+/// * prevImg: first 8-bit input image. If The cross-based RLOF is used (by selecting optflow::RLOFOpticalFlowParameter::supportRegionType
+/// = SupportRegionType::SR_CROSS) image has to be a 8-bit 3 channel image.
+/// * nextImg: second 8-bit input image. If The cross-based RLOF is used (by selecting optflow::RLOFOpticalFlowParameter::supportRegionType
+/// = SupportRegionType::SR_CROSS) image has to be a 8-bit 3 channel image.
+/// * prevPts: vector of 2D points for which the flow needs to be found; point coordinates must be single-precision
+/// floating-point numbers.
+/// * nextPts: output vector of 2D points (with single-precision floating-point coordinates) containing the calculated
+/// new positions of input features in the second image; when optflow::RLOFOpticalFlowParameter::useInitialFlow variable is true  the vector must
+/// have the same size as in the input and contain the initialization point correspondences.
+/// * status: output status vector (of unsigned chars); each element of the vector is set to 1 if the flow for the
+/// corresponding features has passed the forward backward check.
+/// * err: output vector of errors; each element of the vector is set to the forward backward error for the corresponding feature.
+/// * rlofParam: see optflow::RLOFOpticalFlowParameter
+/// * forwardBackwardThreshold: Threshold for the forward backward confidence check. If forewardBackwardThreshold <=0 the forward
+///
+///
+/// Note: SIMD parallelization is only available when compiling with SSE4.1.
+///
+/// Parameters have been described in [Senst2012](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2012), [Senst2013](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2013), [Senst2014](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2014) and [Senst2016](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2016).
+/// For the RLOF configuration see optflow::RLOFOpticalFlowParameter for further details.
+///
+/// ## C++ default parameters
+/// * rlof_param: Ptr<RLOFOpticalFlowParameter>()
+/// * forward_backward_threshold: 0
+pub fn calc_optical_flow_sparse_rlof(prev_img: &dyn core::ToInputArray, next_img: &dyn core::ToInputArray, prev_pts: &dyn core::ToInputArray, next_pts: &mut dyn core::ToInputOutputArray, status: &mut dyn core::ToOutputArray, err: &mut dyn core::ToOutputArray, rlof_param: &types::PtrOfRLOFOpticalFlowParameter, forward_backward_threshold: f32) -> Result<()> {
+    input_array_arg!(prev_img);
+    input_array_arg!(next_img);
+    input_array_arg!(prev_pts);
+    input_output_array_arg!(next_pts);
+    output_array_arg!(status);
+    output_array_arg!(err);
+    unsafe { sys::cv_optflow_calcOpticalFlowSparseRLOF__InputArray__InputArray__InputArray__InputOutputArray__OutputArray__OutputArray_PtrOfRLOFOpticalFlowParameter_float(prev_img.as_raw__InputArray(), next_img.as_raw__InputArray(), prev_pts.as_raw__InputArray(), next_pts.as_raw__InputOutputArray(), status.as_raw__OutputArray(), err.as_raw__OutputArray(), rlof_param.as_raw_PtrOfRLOFOpticalFlowParameter(), forward_backward_threshold) }.into_result()
+}
+
+/// Fast dense optical flow based on PyrLK sparse matches interpolation.
+///
+/// ## Parameters
+/// * from: first 8-bit 3-channel or 1-channel image.
+/// * to: second 8-bit 3-channel or 1-channel image of the same size as from
+/// * flow: computed flow image that has the same size as from and CV_32FC2 type
+/// * grid_step: stride used in sparse match computation. Lower values usually
+/// result in higher quality but slow down the algorithm.
+/// * k: number of nearest-neighbor matches considered, when fitting a locally affine
+/// model. Lower values can make the algorithm noticeably faster at the cost of
+/// some quality degradation.
+/// * sigma: parameter defining how fast the weights decrease in the locally-weighted affine
+/// fitting. Higher values can help preserve fine details, lower values can help to get rid
+/// of the noise in the output flow.
+/// * use_post_proc: defines whether the ximgproc::fastGlobalSmootherFilter() is used
+/// for post-processing after interpolation
+/// * fgs_lambda: see the respective parameter of the ximgproc::fastGlobalSmootherFilter()
+/// * fgs_sigma: see the respective parameter of the ximgproc::fastGlobalSmootherFilter()
+///
+/// ## C++ default parameters
+/// * grid_step: 8
+/// * k: 128
+/// * sigma: 0.05f
+/// * use_post_proc: true
+/// * fgs_lambda: 500.0f
+/// * fgs_sigma: 1.5f
+pub fn calc_optical_flow_sparse_to_dense(from: &dyn core::ToInputArray, to: &dyn core::ToInputArray, flow: &mut dyn core::ToOutputArray, grid_step: i32, k: i32, sigma: f32, use_post_proc: bool, fgs_lambda: f32, fgs_sigma: f32) -> Result<()> {
+    input_array_arg!(from);
+    input_array_arg!(to);
+    output_array_arg!(flow);
+    unsafe { sys::cv_optflow_calcOpticalFlowSparseToDense__InputArray__InputArray__OutputArray_int_int_float_bool_float_float(from.as_raw__InputArray(), to.as_raw__InputArray(), flow.as_raw__OutputArray(), grid_step, k, sigma, use_post_proc, fgs_lambda, fgs_sigma) }.into_result()
+}
+
+/// DeepFlow optical flow algorithm implementation.
+///
+/// The class implements the DeepFlow optical flow algorithm described in [Weinzaepfel2013](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Weinzaepfel2013) . See
+/// also <http://lear.inrialpes.fr/src/deepmatching/> .
+/// Parameters - class fields - that may be modified after creating a class instance:
+/// *   member float alpha
+/// Smoothness assumption weight
+/// *   member float delta
+/// Color constancy assumption weight
+/// *   member float gamma
+/// Gradient constancy weight
+/// *   member float sigma
+/// Gaussian smoothing parameter
+/// *   member int minSize
+/// Minimal dimension of an image in the pyramid (next, smaller images in the pyramid are generated
+/// until one of the dimensions reaches this size)
+/// *   member float downscaleFactor
+/// Scaling factor in the image pyramid (must be \< 1)
+/// *   member int fixedPointIterations
+/// How many iterations on each level of the pyramid
+/// *   member int sorIterations
+/// Iterations of Succesive Over-Relaxation (solver)
+/// *   member float omega
+/// Relaxation factor in SOR
+pub fn create_opt_flow_deep_flow() -> Result<types::PtrOfDenseOpticalFlow> {
+    unsafe { sys::cv_optflow_createOptFlow_DeepFlow() }.into_result().map(|ptr| types::PtrOfDenseOpticalFlow { ptr })
+}
+
+/// Additional interface to the Dense RLOF algorithm - optflow::calcOpticalFlowDenseRLOF()
+pub fn create_opt_flow_dense_rlof() -> Result<types::PtrOfDenseOpticalFlow> {
+    unsafe { sys::cv_optflow_createOptFlow_DenseRLOF() }.into_result().map(|ptr| types::PtrOfDenseOpticalFlow { ptr })
+}
+
+/// Creates instance of cv::DenseOpticalFlow
+pub fn create_opt_flow_dual_tvl1() -> Result<types::PtrOfDualTVL1OpticalFlow> {
+    unsafe { sys::cv_optflow_createOptFlow_DualTVL1() }.into_result().map(|ptr| types::PtrOfDualTVL1OpticalFlow { ptr })
+}
+
+/// Additional interface to the Farneback's algorithm - calcOpticalFlowFarneback()
+pub fn create_opt_flow_farneback() -> Result<types::PtrOfDenseOpticalFlow> {
+    unsafe { sys::cv_optflow_createOptFlow_Farneback() }.into_result().map(|ptr| types::PtrOfDenseOpticalFlow { ptr })
+}
+
+/// Creates an instance of PCAFlow
+pub fn create_opt_flow_pca_flow() -> Result<types::PtrOfDenseOpticalFlow> {
+    unsafe { sys::cv_optflow_createOptFlow_PCAFlow() }.into_result().map(|ptr| types::PtrOfDenseOpticalFlow { ptr })
+}
+
+/// Additional interface to the SimpleFlow algorithm - calcOpticalFlowSF()
+pub fn create_opt_flow_simple_flow() -> Result<types::PtrOfDenseOpticalFlow> {
+    unsafe { sys::cv_optflow_createOptFlow_SimpleFlow() }.into_result().map(|ptr| types::PtrOfDenseOpticalFlow { ptr })
+}
+
+/// Additional interface to the Sparse RLOF algorithm - optflow::calcOpticalFlowSparseRLOF()
+pub fn create_opt_flow_sparse_rlof() -> Result<types::PtrOfSparseOpticalFlow> {
+    unsafe { sys::cv_optflow_createOptFlow_SparseRLOF() }.into_result().map(|ptr| types::PtrOfSparseOpticalFlow { ptr })
+}
+
+/// Additional interface to the SparseToDenseFlow algorithm - calcOpticalFlowSparseToDense()
+pub fn create_opt_flow_sparse_to_dense() -> Result<types::PtrOfDenseOpticalFlow> {
+    unsafe { sys::cv_optflow_createOptFlow_SparseToDense() }.into_result().map(|ptr| types::PtrOfDenseOpticalFlow { ptr })
+}
+
+pub fn write(fs: &mut core::FileStorage, name: &str, node: &crate::optflow::GPCTree_Node) -> Result<()> {
+    string_arg!(name);
+    unsafe { sys::cv_write_FileStorage_String_Node(fs.as_raw_FileStorage(), name.as_ptr(), node.as_raw_GPCTree_Node()) }.into_result()
+}
+
+// Generating impl for trait crate::optflow::DenseRLOFOpticalFlow
+/// Fast dense optical flow computation based on robust local optical flow (RLOF) algorithms and sparse-to-dense interpolation
+/// scheme.
+///
+/// The RLOF is a fast local optical flow approach described in [Senst2012](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2012) [Senst2013](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2013) [Senst2014](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2014)
+/// and [Senst2016](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2016) similar to the pyramidal iterative Lucas-Kanade method as
+/// proposed by [Bouguet00](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Bouguet00). More details and experiments can be found in the following thesis [Senst2019](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2019).
+/// The implementation is derived from optflow::calcOpticalFlowPyrLK().
+///
+/// The sparse-to-dense interpolation scheme allows for fast computation of dense optical flow using RLOF (see [Geistert2016](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Geistert2016)).
+/// For this scheme the following steps are applied:
+/// -# motion vector seeded at a regular sampled grid are computed. The sparsity of this grid can be configured with setGridStep
+/// -# (optinally) errornous motion vectors are filter based on the forward backward confidence. The threshold can be configured
+/// with setForwardBackward. The filter is only applied if the threshold >0 but than the runtime is doubled due to the estimation
+/// of the backward flow.
+/// -# Vector field interpolation is applied to the motion vector set to obtain a dense vector field.
+///
+/// For the RLOF configuration see optflow::RLOFOpticalFlowParameter for further details.
+/// Parameters have been described in [Senst2012](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2012) [Senst2013](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2013) [Senst2014](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2014) and [Senst2016](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2016).
+///
+///
+/// Note: SIMD parallelization is only available when compiling with SSE4.1. If the grid size is set to (1,1) and the
+/// forward backward threshold <= 0 that the dense optical flow field is purely.
+/// computed with the RLOF.
+///
+/// @see optflow::calcOpticalFlowDenseRLOF(), optflow::RLOFOpticalFlowParameter
+pub trait DenseRLOFOpticalFlow: crate::video::DenseOpticalFlow {
+    fn as_raw_DenseRLOFOpticalFlow(&self) -> *mut c_void;
+    /// @see optflow::RLOFOpticalFlowParameter, getRLOFOpticalFlowParameter
+    fn set_rlof_optical_flow_parameter(&mut self, val: &types::PtrOfRLOFOpticalFlowParameter) -> Result<()> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_setRLOFOpticalFlowParameter_PtrOfRLOFOpticalFlowParameter(self.as_raw_DenseRLOFOpticalFlow(), val.as_raw_PtrOfRLOFOpticalFlowParameter()) }.into_result()
+    }
+    
+    /// @copybrief setRLOFOpticalFlowParameter
+    /// @see optflow::RLOFOpticalFlowParameter, setRLOFOpticalFlowParameter
+    fn get_rlof_optical_flow_parameter(&self) -> Result<types::PtrOfRLOFOpticalFlowParameter> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_getRLOFOpticalFlowParameter_const(self.as_raw_DenseRLOFOpticalFlow()) }.into_result().map(|ptr| types::PtrOfRLOFOpticalFlowParameter { ptr })
+    }
+    
+    /// For each grid point ![inline formula](https://latex.codecogs.com/png.latex?%20%5Cmathbf%7Bx%7D%20) a motion vector ![inline formula](https://latex.codecogs.com/png.latex?%20d_%7BI0%2CI1%7D%28%5Cmathbf%7Bx%7D%29%20) is computed.
+    ///  If the forward backward error ![block formula](https://latex.codecogs.com/png.latex?%20EP_%7BFB%7D%20%3D%20%7C%7C%20d_%7BI0%2CI1%7D%20%2B%20d_%7BI1%2CI0%7D%20%7C%7C%20)
+    ///  is larger than threshold given by this function then the motion vector will not be used by the following
+    ///    vector field interpolation. ![inline formula](https://latex.codecogs.com/png.latex?%20d_%7BI1%2CI0%7D%20) denotes the backward flow. Note, the forward backward test
+    ///    will only be applied if the threshold > 0. This may results into a doubled runtime for the motion estimation.
+    ///    @see getForwardBackward, setGridStep
+    fn set_forward_backward(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_setForwardBackward_float(self.as_raw_DenseRLOFOpticalFlow(), val) }.into_result()
+    }
+    
+    /// @copybrief setForwardBackward
+    /// @see setForwardBackward
+    fn get_forward_backward(&self) -> Result<f32> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_getForwardBackward_const(self.as_raw_DenseRLOFOpticalFlow()) }.into_result()
+    }
+    
+    /// For each grid point a motion vector is computed. Some motion vectors will be removed due to the forwatd backward
+    ///  threshold (if set >0). The rest will be the base of the vector field interpolation.
+    ///    @see getForwardBackward, setGridStep
+    fn get_grid_step(&self) -> Result<core::Size> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_getGridStep_const(self.as_raw_DenseRLOFOpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getGridStep
+    ///    @see getGridStep
+    fn set_grid_step(&mut self, val: core::Size) -> Result<()> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_setGridStep_Size(self.as_raw_DenseRLOFOpticalFlow(), val) }.into_result()
+    }
+    
+    /// Two interpolation algorithms are supported
+    /// - **INTERP_GEO** applies the fast geodesic interpolation, see [Geistert2016](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Geistert2016).
+    /// - **INTERP_EPIC_RESIDUAL** applies the edge-preserving interpolation, see [Revaud2015](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Revaud2015),Geistert2016.
+    /// @see ximgproc::EdgeAwareInterpolator, getInterpolation
+    fn set_interpolation(&mut self, val: crate::optflow::InterpolationType) -> Result<()> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_setInterpolation_InterpolationType(self.as_raw_DenseRLOFOpticalFlow(), val) }.into_result()
+    }
+    
+    /// @copybrief setInterpolation
+    ///    @see ximgproc::EdgeAwareInterpolator, setInterpolation
+    fn get_interpolation(&self) -> Result<crate::optflow::InterpolationType> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_getInterpolation_const(self.as_raw_DenseRLOFOpticalFlow()) }.into_result()
+    }
+    
+    /// K is a number of nearest-neighbor matches considered, when fitting a locally affine
+    ///    model. Usually it should be around 128. However, lower values would make the interpolation noticeably faster.
+    ///    @see ximgproc::EdgeAwareInterpolator,  setEPICK
+    fn get_epick(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_getEPICK_const(self.as_raw_DenseRLOFOpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getEPICK
+    ///    @see ximgproc::EdgeAwareInterpolator, getEPICK
+    fn set_epick(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_setEPICK_int(self.as_raw_DenseRLOFOpticalFlow(), val) }.into_result()
+    }
+    
+    /// Sigma is a parameter defining how fast the weights decrease in the locally-weighted affine
+    ///  fitting. Higher values can help preserve fine details, lower values can help to get rid of noise in the
+    ///  output flow.
+    ///    @see ximgproc::EdgeAwareInterpolator, setEPICSigma
+    fn get_epic_sigma(&self) -> Result<f32> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_getEPICSigma_const(self.as_raw_DenseRLOFOpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getEPICSigma
+    ///  @see ximgproc::EdgeAwareInterpolator, getEPICSigma
+    fn set_epic_sigma(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_setEPICSigma_float(self.as_raw_DenseRLOFOpticalFlow(), val) }.into_result()
+    }
+    
+    /// Lambda is a parameter defining the weight of the edge-aware term in geodesic distance,
+    ///    should be in the range of 0 to 1000.
+    ///    @see ximgproc::EdgeAwareInterpolator, setEPICSigma
+    fn get_epic_lambda(&self) -> Result<f32> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_getEPICLambda_const(self.as_raw_DenseRLOFOpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getEPICLambda
+    ///    @see ximgproc::EdgeAwareInterpolator, getEPICLambda
+    fn set_epic_lambda(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_setEPICLambda_float(self.as_raw_DenseRLOFOpticalFlow(), val) }.into_result()
+    }
+    
+    /// Sets the respective fastGlobalSmootherFilter() parameter.
+    ///    @see ximgproc::EdgeAwareInterpolator, setFgsLambda
+    fn get_fgs_lambda(&self) -> Result<f32> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_getFgsLambda_const(self.as_raw_DenseRLOFOpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getFgsLambda
+    ///    @see ximgproc::EdgeAwareInterpolator, ximgproc::fastGlobalSmootherFilter, getFgsLambda
+    fn set_fgs_lambda(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_setFgsLambda_float(self.as_raw_DenseRLOFOpticalFlow(), val) }.into_result()
+    }
+    
+    /// Sets the respective fastGlobalSmootherFilter() parameter.
+    ///    @see ximgproc::EdgeAwareInterpolator, ximgproc::fastGlobalSmootherFilter, setFgsSigma
+    fn get_fgs_sigma(&self) -> Result<f32> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_getFgsSigma_const(self.as_raw_DenseRLOFOpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getFgsSigma
+    ///    @see ximgproc::EdgeAwareInterpolator, ximgproc::fastGlobalSmootherFilter, getFgsSigma
+    fn set_fgs_sigma(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_setFgsSigma_float(self.as_raw_DenseRLOFOpticalFlow(), val) }.into_result()
+    }
+    
+    /// @see getUsePostProc
+    fn set_use_post_proc(&mut self, val: bool) -> Result<()> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_setUsePostProc_bool(self.as_raw_DenseRLOFOpticalFlow(), val) }.into_result()
+    }
+    
+    /// @copybrief setUsePostProc
+    ///    @see ximgproc::fastGlobalSmootherFilter, setUsePostProc
+    fn get_use_post_proc(&self) -> Result<bool> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_getUsePostProc_const(self.as_raw_DenseRLOFOpticalFlow()) }.into_result()
+    }
+    
+    /// @see getUseVariationalRefinement
+    fn set_use_variational_refinement(&mut self, val: bool) -> Result<()> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_setUseVariationalRefinement_bool(self.as_raw_DenseRLOFOpticalFlow(), val) }.into_result()
+    }
+    
+    /// @copybrief setUseVariationalRefinement
+    ///    @see ximgproc::fastGlobalSmootherFilter, setUsePostProc
+    fn get_use_variational_refinement(&self) -> Result<bool> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_getUseVariationalRefinement_const(self.as_raw_DenseRLOFOpticalFlow()) }.into_result()
+    }
+    
+    /// @see cv::ximgproc::createSuperpixelSLIC, cv::ximgproc::RICInterpolator
+    fn set_ricsp_size(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_setRICSPSize_int(self.as_raw_DenseRLOFOpticalFlow(), val) }.into_result()
+    }
+    
+    /// @copybrief setRICSPSize
+    ///    @see setRICSPSize
+    fn get_ricsp_size(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_getRICSPSize_const(self.as_raw_DenseRLOFOpticalFlow()) }.into_result()
+    }
+    
+    /// Parameter to choose superpixel algorithm variant to use:
+    /// - cv::ximgproc::SLICType SLIC segments image using a desired region_size (value: 100)
+    /// - cv::ximgproc::SLICType SLICO will optimize using adaptive compactness factor (value: 101)
+    /// - cv::ximgproc::SLICType MSLIC will optimize using manifold methods resulting in more content-sensitive superpixels (value: 102).
+    ///  @see cv::ximgproc::createSuperpixelSLIC, cv::ximgproc::RICInterpolator
+    fn set_ricslic_type(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_setRICSLICType_int(self.as_raw_DenseRLOFOpticalFlow(), val) }.into_result()
+    }
+    
+    /// @copybrief setRICSLICType
+    ///    @see setRICSLICType
+    fn get_ricslic_type(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_getRICSLICType_const(self.as_raw_DenseRLOFOpticalFlow()) }.into_result()
+    }
+    
+}
+
+impl dyn DenseRLOFOpticalFlow + '_ {
+    /// ## Parameters
+    /// * rlofParam: see optflow::RLOFOpticalFlowParameter
+    /// * forwardBackwardThreshold: see setForwardBackward
+    /// * gridStep: see setGridStep
+    /// * interp_type: see setInterpolation
+    /// * epicK: see setEPICK
+    /// * epicSigma: see setEPICSigma
+    /// * epicLambda: see setEPICLambda
+    /// * ricSPSize: see setRICSPSize
+    /// * ricSLICType: see setRICSLICType
+    /// * use_post_proc: see setUsePostProc
+    /// * fgsLambda: see setFgsLambda
+    /// * fgsSigma: see setFgsSigma
+    /// * use_variational_refinement: see setUseVariationalRefinement
+    ///
+    /// ## C++ default parameters
+    /// * rlof_param: Ptr<RLOFOpticalFlowParameter>()
+    /// * forward_backward_threshold: 1.f
+    /// * grid_step: Size(6, 6)
+    /// * interp_type: InterpolationType::INTERP_EPIC
+    /// * epic_k: 128
+    /// * epic_sigma: 0.05f
+    /// * epic_lambda: 999.0f
+    /// * ric_sp_size: 15
+    /// * ric_slic_type: 100
+    /// * use_post_proc: true
+    /// * fgs_lambda: 500.0f
+    /// * fgs_sigma: 1.5f
+    /// * use_variational_refinement: false
+    pub fn create(rlof_param: &types::PtrOfRLOFOpticalFlowParameter, forward_backward_threshold: f32, grid_step: core::Size, interp_type: crate::optflow::InterpolationType, epic_k: i32, epic_sigma: f32, epic_lambda: f32, ric_sp_size: i32, ric_slic_type: i32, use_post_proc: bool, fgs_lambda: f32, fgs_sigma: f32, use_variational_refinement: bool) -> Result<types::PtrOfDenseRLOFOpticalFlow> {
+        unsafe { sys::cv_optflow_DenseRLOFOpticalFlow_create_PtrOfRLOFOpticalFlowParameter_float_Size_InterpolationType_int_float_float_int_int_bool_float_float_bool(rlof_param.as_raw_PtrOfRLOFOpticalFlowParameter(), forward_backward_threshold, grid_step, interp_type, epic_k, epic_sigma, epic_lambda, ric_sp_size, ric_slic_type, use_post_proc, fgs_lambda, fgs_sigma, use_variational_refinement) }.into_result().map(|ptr| types::PtrOfDenseRLOFOpticalFlow { ptr })
+    }
+    
+}
+
+// Generating impl for trait crate::optflow::DualTVL1OpticalFlow
+/// "Dual TV L1" Optical Flow Algorithm.
+///
+/// The class implements the "Dual TV L1" optical flow algorithm described in [Zach2007](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Zach2007) and
+/// [Javier2012](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Javier2012) .
+/// Here are important members of the class that control the algorithm, which you can set after
+/// constructing the class instance:
+///
+/// *   member double tau
+/// Time step of the numerical scheme.
+///
+/// *   member double lambda
+/// Weight parameter for the data term, attachment parameter. This is the most relevant
+/// parameter, which determines the smoothness of the output. The smaller this parameter is,
+/// the smoother the solutions we obtain. It depends on the range of motions of the images, so
+/// its value should be adapted to each image sequence.
+///
+/// *   member double theta
+/// Weight parameter for (u - v)\^2, tightness parameter. It serves as a link between the
+/// attachment and the regularization terms. In theory, it should have a small value in order
+/// to maintain both parts in correspondence. The method is stable for a large range of values
+/// of this parameter.
+///
+/// *   member int nscales
+/// Number of scales used to create the pyramid of images.
+///
+/// *   member int warps
+/// Number of warpings per scale. Represents the number of times that I1(x+u0) and grad(
+/// I1(x+u0) ) are computed per scale. This is a parameter that assures the stability of the
+/// method. It also affects the running time, so it is a compromise between speed and
+/// accuracy.
+///
+/// *   member double epsilon
+/// Stopping criterion threshold used in the numerical scheme, which is a trade-off between
+/// precision and running time. A small value will yield more accurate solutions at the
+/// expense of a slower convergence.
+///
+/// *   member int iterations
+/// Stopping criterion iterations number used in the numerical scheme.
+///
+/// C. Zach, T. Pock and H. Bischof, "A Duality Based Approach for Realtime TV-L1 Optical Flow".
+/// Javier Sanchez, Enric Meinhardt-Llopis and Gabriele Facciolo. "TV-L1 Optical Flow Estimation".
+pub trait DualTVL1OpticalFlow: crate::video::DenseOpticalFlow {
+    fn as_raw_DualTVL1OpticalFlow(&self) -> *mut c_void;
+    /// @see setTau
+    fn get_tau(&self) -> Result<f64> {
+        unsafe { sys::cv_optflow_DualTVL1OpticalFlow_getTau_const(self.as_raw_DualTVL1OpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getTau @see getTau
+    fn set_tau(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_optflow_DualTVL1OpticalFlow_setTau_double(self.as_raw_DualTVL1OpticalFlow(), val) }.into_result()
+    }
+    
+    /// @see setLambda
+    fn get_lambda(&self) -> Result<f64> {
+        unsafe { sys::cv_optflow_DualTVL1OpticalFlow_getLambda_const(self.as_raw_DualTVL1OpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getLambda @see getLambda
+    fn set_lambda(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_optflow_DualTVL1OpticalFlow_setLambda_double(self.as_raw_DualTVL1OpticalFlow(), val) }.into_result()
+    }
+    
+    /// @see setTheta
+    fn get_theta(&self) -> Result<f64> {
+        unsafe { sys::cv_optflow_DualTVL1OpticalFlow_getTheta_const(self.as_raw_DualTVL1OpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getTheta @see getTheta
+    fn set_theta(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_optflow_DualTVL1OpticalFlow_setTheta_double(self.as_raw_DualTVL1OpticalFlow(), val) }.into_result()
+    }
+    
+    /// @see setGamma
+    fn get_gamma(&self) -> Result<f64> {
+        unsafe { sys::cv_optflow_DualTVL1OpticalFlow_getGamma_const(self.as_raw_DualTVL1OpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getGamma @see getGamma
+    fn set_gamma(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_optflow_DualTVL1OpticalFlow_setGamma_double(self.as_raw_DualTVL1OpticalFlow(), val) }.into_result()
+    }
+    
+    /// @see setScalesNumber
+    fn get_scales_number(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_DualTVL1OpticalFlow_getScalesNumber_const(self.as_raw_DualTVL1OpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getScalesNumber @see getScalesNumber
+    fn set_scales_number(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_optflow_DualTVL1OpticalFlow_setScalesNumber_int(self.as_raw_DualTVL1OpticalFlow(), val) }.into_result()
+    }
+    
+    /// @see setWarpingsNumber
+    fn get_warpings_number(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_DualTVL1OpticalFlow_getWarpingsNumber_const(self.as_raw_DualTVL1OpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getWarpingsNumber @see getWarpingsNumber
+    fn set_warpings_number(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_optflow_DualTVL1OpticalFlow_setWarpingsNumber_int(self.as_raw_DualTVL1OpticalFlow(), val) }.into_result()
+    }
+    
+    /// @see setEpsilon
+    fn get_epsilon(&self) -> Result<f64> {
+        unsafe { sys::cv_optflow_DualTVL1OpticalFlow_getEpsilon_const(self.as_raw_DualTVL1OpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getEpsilon @see getEpsilon
+    fn set_epsilon(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_optflow_DualTVL1OpticalFlow_setEpsilon_double(self.as_raw_DualTVL1OpticalFlow(), val) }.into_result()
+    }
+    
+    /// @see setInnerIterations
+    fn get_inner_iterations(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_DualTVL1OpticalFlow_getInnerIterations_const(self.as_raw_DualTVL1OpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getInnerIterations @see getInnerIterations
+    fn set_inner_iterations(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_optflow_DualTVL1OpticalFlow_setInnerIterations_int(self.as_raw_DualTVL1OpticalFlow(), val) }.into_result()
+    }
+    
+    /// @see setOuterIterations
+    fn get_outer_iterations(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_DualTVL1OpticalFlow_getOuterIterations_const(self.as_raw_DualTVL1OpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getOuterIterations @see getOuterIterations
+    fn set_outer_iterations(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_optflow_DualTVL1OpticalFlow_setOuterIterations_int(self.as_raw_DualTVL1OpticalFlow(), val) }.into_result()
+    }
+    
+    /// @see setUseInitialFlow
+    fn get_use_initial_flow(&self) -> Result<bool> {
+        unsafe { sys::cv_optflow_DualTVL1OpticalFlow_getUseInitialFlow_const(self.as_raw_DualTVL1OpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getUseInitialFlow @see getUseInitialFlow
+    fn set_use_initial_flow(&mut self, val: bool) -> Result<()> {
+        unsafe { sys::cv_optflow_DualTVL1OpticalFlow_setUseInitialFlow_bool(self.as_raw_DualTVL1OpticalFlow(), val) }.into_result()
+    }
+    
+    /// @see setScaleStep
+    fn get_scale_step(&self) -> Result<f64> {
+        unsafe { sys::cv_optflow_DualTVL1OpticalFlow_getScaleStep_const(self.as_raw_DualTVL1OpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getScaleStep @see getScaleStep
+    fn set_scale_step(&mut self, val: f64) -> Result<()> {
+        unsafe { sys::cv_optflow_DualTVL1OpticalFlow_setScaleStep_double(self.as_raw_DualTVL1OpticalFlow(), val) }.into_result()
+    }
+    
+    /// @see setMedianFiltering
+    fn get_median_filtering(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_DualTVL1OpticalFlow_getMedianFiltering_const(self.as_raw_DualTVL1OpticalFlow()) }.into_result()
+    }
+    
+    /// @copybrief getMedianFiltering @see getMedianFiltering
+    fn set_median_filtering(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_optflow_DualTVL1OpticalFlow_setMedianFiltering_int(self.as_raw_DualTVL1OpticalFlow(), val) }.into_result()
+    }
+    
+}
+
+impl dyn DualTVL1OpticalFlow + '_ {
+    /// Creates instance of cv::DualTVL1OpticalFlow
+    ///
+    /// ## C++ default parameters
+    /// * tau: 0.25
+    /// * lambda: 0.15
+    /// * theta: 0.3
+    /// * nscales: 5
+    /// * warps: 5
+    /// * epsilon: 0.01
+    /// * innner_iterations: 30
+    /// * outer_iterations: 10
+    /// * scale_step: 0.8
+    /// * gamma: 0.0
+    /// * median_filtering: 5
+    /// * use_initial_flow: false
+    pub fn create(tau: f64, lambda: f64, theta: f64, nscales: i32, warps: i32, epsilon: f64, innner_iterations: i32, outer_iterations: i32, scale_step: f64, gamma: f64, median_filtering: i32, use_initial_flow: bool) -> Result<types::PtrOfDualTVL1OpticalFlow> {
+        unsafe { sys::cv_optflow_DualTVL1OpticalFlow_create_double_double_double_int_int_double_int_int_double_double_int_bool(tau, lambda, theta, nscales, warps, epsilon, innner_iterations, outer_iterations, scale_step, gamma, median_filtering, use_initial_flow) }.into_result().map(|ptr| types::PtrOfDualTVL1OpticalFlow { ptr })
+    }
+    
+}
+
+// boxed class cv::optflow::GPCDetails
+/// Find correspondences between two images.
+/// ## Parameters
+/// * imgFrom: First image in a sequence.
+/// * imgTo: Second image in a sequence.
+/// * corr: [out] Output vector with pairs of corresponding points.
+/// * params: Additional matching parameters for fine-tuning.
+pub struct GPCDetails {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for GPCDetails {
+    fn drop(&mut self) {
+        unsafe { sys::cv_GPCDetails_delete(self.ptr) };
+    }
+}
+
+impl GPCDetails {
+    #[inline(always)] pub fn as_raw_GPCDetails(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for GPCDetails {}
+
+impl GPCDetails {
+    pub fn get_all_descriptors_for_image(img_ch: &core::Mat, descr: &mut types::VectorOfGPCPatchDescriptor, mp: &crate::optflow::GPCMatchingParams, _type: i32) -> Result<()> {
+        unsafe { sys::cv_optflow_GPCDetails_getAllDescriptorsForImage_const_Mat_VectorOfGPCPatchDescriptor_GPCMatchingParams_int(img_ch.as_raw_Mat(), descr.as_raw_VectorOfGPCPatchDescriptor(), mp.as_raw_GPCMatchingParams(), _type) }.into_result()
+    }
+    
+    pub fn get_coordinates_from_index(index: size_t, sz: core::Size, x: &mut i32, y: &mut i32) -> Result<()> {
+        unsafe { sys::cv_optflow_GPCDetails_getCoordinatesFromIndex_size_t_Size_int_int(index, sz, x, y) }.into_result()
+    }
+    
+}
+
+// boxed class cv::optflow::GPCMatchingParams
+/// Class encapsulating matching parameters.
+pub struct GPCMatchingParams {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for GPCMatchingParams {
+    fn drop(&mut self) {
+        unsafe { sys::cv_GPCMatchingParams_delete(self.ptr) };
+    }
+}
+
+impl GPCMatchingParams {
+    #[inline(always)] pub fn as_raw_GPCMatchingParams(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for GPCMatchingParams {}
+
+impl GPCMatchingParams {
+    ///
+    /// ## C++ default parameters
+    /// * _use_opencl: false
+    pub fn new(_use_opencl: bool) -> Result<crate::optflow::GPCMatchingParams> {
+        unsafe { sys::cv_optflow_GPCMatchingParams_GPCMatchingParams_bool(_use_opencl) }.into_result().map(|ptr| crate::optflow::GPCMatchingParams { ptr })
+    }
+    
+    pub fn copy(params: &crate::optflow::GPCMatchingParams) -> Result<crate::optflow::GPCMatchingParams> {
+        unsafe { sys::cv_optflow_GPCMatchingParams_GPCMatchingParams_GPCMatchingParams(params.as_raw_GPCMatchingParams()) }.into_result().map(|ptr| crate::optflow::GPCMatchingParams { ptr })
+    }
+    
+}
+
+// boxed class cv::optflow::GPCPatchDescriptor
+pub struct GPCPatchDescriptor {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for GPCPatchDescriptor {
+    fn drop(&mut self) {
+        unsafe { sys::cv_GPCPatchDescriptor_delete(self.ptr) };
+    }
+}
+
+impl GPCPatchDescriptor {
+    #[inline(always)] pub fn as_raw_GPCPatchDescriptor(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for GPCPatchDescriptor {}
+
+impl GPCPatchDescriptor {
+    pub fn mark_as_separated(&mut self) -> Result<()> {
+        unsafe { sys::cv_optflow_GPCPatchDescriptor_markAsSeparated(self.as_raw_GPCPatchDescriptor()) }.into_result()
+    }
+    
+    pub fn is_separated(&self) -> Result<bool> {
+        unsafe { sys::cv_optflow_GPCPatchDescriptor_isSeparated_const(self.as_raw_GPCPatchDescriptor()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::optflow::GPCPatchSample
+pub struct GPCPatchSample {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for GPCPatchSample {
+    fn drop(&mut self) {
+        unsafe { sys::cv_GPCPatchSample_delete(self.ptr) };
+    }
+}
+
+impl GPCPatchSample {
+    #[inline(always)] pub fn as_raw_GPCPatchSample(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for GPCPatchSample {}
+
+// boxed class cv::optflow::GPCTrainingParams
+/// Class encapsulating training parameters.
+pub struct GPCTrainingParams {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for GPCTrainingParams {
+    fn drop(&mut self) {
+        unsafe { sys::cv_GPCTrainingParams_delete(self.ptr) };
+    }
+}
+
+impl GPCTrainingParams {
+    #[inline(always)] pub fn as_raw_GPCTrainingParams(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for GPCTrainingParams {}
+
+impl GPCTrainingParams {
+    ///
+    /// ## C++ default parameters
+    /// * _max_tree_depth: 20
+    /// * _min_number_of_samples: 3
+    /// * _descriptor_type: GPC_DESCRIPTOR_DCT
+    /// * _print_progress: true
+    pub fn new(_max_tree_depth: u32, _min_number_of_samples: i32, _descriptor_type: crate::optflow::GPCDescType, _print_progress: bool) -> Result<crate::optflow::GPCTrainingParams> {
+        unsafe { sys::cv_optflow_GPCTrainingParams_GPCTrainingParams_unsigned_int_GPCDescType_bool(_max_tree_depth, _min_number_of_samples, _descriptor_type, _print_progress) }.into_result().map(|ptr| crate::optflow::GPCTrainingParams { ptr })
+    }
+    
+    pub fn check(&self) -> Result<bool> {
+        unsafe { sys::cv_optflow_GPCTrainingParams_check_const(self.as_raw_GPCTrainingParams()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::optflow::GPCTrainingSamples
+/// Class encapsulating training samples.
+pub struct GPCTrainingSamples {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for GPCTrainingSamples {
+    fn drop(&mut self) {
+        unsafe { sys::cv_GPCTrainingSamples_delete(self.ptr) };
+    }
+}
+
+impl GPCTrainingSamples {
+    #[inline(always)] pub fn as_raw_GPCTrainingSamples(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for GPCTrainingSamples {}
+
+impl GPCTrainingSamples {
+    /// This function can be used to extract samples from a pair of images and a ground truth flow.
+    /// Sizes of all the provided vectors must be equal.
+    pub fn create(images_from: &types::VectorOfString, images_to: &types::VectorOfString, gt: &types::VectorOfString, descriptor_type: i32) -> Result<types::PtrOfGPCTrainingSamples> {
+        unsafe { sys::cv_optflow_GPCTrainingSamples_create_VectorOfString_VectorOfString_VectorOfString_int(images_from.as_raw_VectorOfString(), images_to.as_raw_VectorOfString(), gt.as_raw_VectorOfString(), descriptor_type) }.into_result().map(|ptr| types::PtrOfGPCTrainingSamples { ptr })
+    }
+    
+    pub fn create_1(images_from: &dyn core::ToInputArray, images_to: &dyn core::ToInputArray, gt: &dyn core::ToInputArray, descriptor_type: i32) -> Result<types::PtrOfGPCTrainingSamples> {
+        input_array_arg!(images_from);
+        input_array_arg!(images_to);
+        input_array_arg!(gt);
+        unsafe { sys::cv_optflow_GPCTrainingSamples_create__InputArray__InputArray__InputArray_int(images_from.as_raw__InputArray(), images_to.as_raw__InputArray(), gt.as_raw__InputArray(), descriptor_type) }.into_result().map(|ptr| types::PtrOfGPCTrainingSamples { ptr })
+    }
+    
+    pub fn size(&self) -> Result<size_t> {
+        unsafe { sys::cv_optflow_GPCTrainingSamples_size_const(self.as_raw_GPCTrainingSamples()) }.into_result()
+    }
+    
+    pub fn _type(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_GPCTrainingSamples_type_const(self.as_raw_GPCTrainingSamples()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::optflow::GPCTree
+/// Class for individual tree.
+pub struct GPCTree {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for GPCTree {
+    fn drop(&mut self) {
+        unsafe { sys::cv_GPCTree_delete(self.ptr) };
+    }
+}
+
+impl GPCTree {
+    #[inline(always)] pub fn as_raw_GPCTree(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for GPCTree {}
+
+impl core::AlgorithmTrait for GPCTree {
+    #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
+}
+
+impl GPCTree {
+    ///
+    /// ## C++ default parameters
+    /// * params: GPCTrainingParams()
+    pub fn train(&mut self, samples: &mut crate::optflow::GPCTrainingSamples, params: &crate::optflow::GPCTrainingParams) -> Result<()> {
+        unsafe { sys::cv_optflow_GPCTree_train_GPCTrainingSamples_GPCTrainingParams(self.as_raw_GPCTree(), samples.as_raw_GPCTrainingSamples(), params.as_raw_GPCTrainingParams()) }.into_result()
+    }
+    
+    pub fn write(&self, fs: &mut core::FileStorage) -> Result<()> {
+        unsafe { sys::cv_optflow_GPCTree_write_const_FileStorage(self.as_raw_GPCTree(), fs.as_raw_FileStorage()) }.into_result()
+    }
+    
+    pub fn read(&mut self, _fn: &core::FileNode) -> Result<()> {
+        unsafe { sys::cv_optflow_GPCTree_read_FileNode(self.as_raw_GPCTree(), _fn.as_raw_FileNode()) }.into_result()
+    }
+    
+    pub fn find_leaf_for_patch(&self, descr: &crate::optflow::GPCPatchDescriptor) -> Result<u32> {
+        unsafe { sys::cv_optflow_GPCTree_findLeafForPatch_const_GPCPatchDescriptor(self.as_raw_GPCTree(), descr.as_raw_GPCPatchDescriptor()) }.into_result()
+    }
+    
+    pub fn create() -> Result<types::PtrOfGPCTree> {
+        unsafe { sys::cv_optflow_GPCTree_create() }.into_result().map(|ptr| types::PtrOfGPCTree { ptr })
+    }
+    
+    pub fn get_descriptor_type(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_GPCTree_getDescriptorType_const(self.as_raw_GPCTree()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::optflow::GPCTree::Node
+pub struct GPCTree_Node {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for GPCTree_Node {
+    fn drop(&mut self) {
+        unsafe { sys::cv_GPCTree_Node_delete(self.ptr) };
+    }
+}
+
+impl GPCTree_Node {
+    #[inline(always)] pub fn as_raw_GPCTree_Node(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for GPCTree_Node {}
+
+// boxed class cv::optflow::OpticalFlowPCAFlow
+/// PCAFlow algorithm.
+pub struct OpticalFlowPCAFlow {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for OpticalFlowPCAFlow {
+    fn drop(&mut self) {
+        unsafe { sys::cv_OpticalFlowPCAFlow_delete(self.ptr) };
+    }
+}
+
+impl OpticalFlowPCAFlow {
+    #[inline(always)] pub fn as_raw_OpticalFlowPCAFlow(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for OpticalFlowPCAFlow {}
+
+impl core::AlgorithmTrait for OpticalFlowPCAFlow {
+    #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
+}
+
+impl crate::video::DenseOpticalFlow for OpticalFlowPCAFlow {
+    #[inline(always)] fn as_raw_DenseOpticalFlow(&self) -> *mut c_void { self.ptr }
+}
+
+impl OpticalFlowPCAFlow {
+    /// Creates an instance of PCAFlow algorithm.
+    /// ## Parameters
+    /// * _prior: Learned prior or no prior (default). @see cv::optflow::PCAPrior
+    /// * _basisSize: Number of basis vectors.
+    /// * _sparseRate: Controls density of sparse matches.
+    /// * _retainedCornersFraction: Retained corners fraction.
+    /// * _occlusionsThreshold: Occlusion threshold.
+    /// * _dampingFactor: Regularization term for solving least-squares. It is not related to the prior regularization.
+    /// * _claheClip: Clip parameter for CLAHE.
+    ///
+    /// ## C++ default parameters
+    /// * _prior: Ptr<const PCAPrior>()
+    /// * _basis_size: Size( 18, 14 )
+    /// * _sparse_rate: 0.024
+    /// * _retained_corners_fraction: 0.2
+    /// * _occlusions_threshold: 0.0003
+    /// * _damping_factor: 0.00002
+    /// * _clahe_clip: 14
+    pub fn new(_prior: &types::PtrOfPCAPrior, _basis_size: core::Size, _sparse_rate: f32, _retained_corners_fraction: f32, _occlusions_threshold: f32, _damping_factor: f32, _clahe_clip: f32) -> Result<crate::optflow::OpticalFlowPCAFlow> {
+        unsafe { sys::cv_optflow_OpticalFlowPCAFlow_OpticalFlowPCAFlow_PtrOfPCAPrior_Size_float_float_float_float_float(_prior.as_raw_PtrOfPCAPrior(), _basis_size, _sparse_rate, _retained_corners_fraction, _occlusions_threshold, _damping_factor, _clahe_clip) }.into_result().map(|ptr| crate::optflow::OpticalFlowPCAFlow { ptr })
+    }
+    
+    pub fn calc(&mut self, i0: &dyn core::ToInputArray, i1: &dyn core::ToInputArray, flow: &mut dyn core::ToInputOutputArray) -> Result<()> {
+        input_array_arg!(i0);
+        input_array_arg!(i1);
+        input_output_array_arg!(flow);
+        unsafe { sys::cv_optflow_OpticalFlowPCAFlow_calc__InputArray__InputArray__InputOutputArray(self.as_raw_OpticalFlowPCAFlow(), i0.as_raw__InputArray(), i1.as_raw__InputArray(), flow.as_raw__InputOutputArray()) }.into_result()
+    }
+    
+    pub fn collect_garbage(&mut self) -> Result<()> {
+        unsafe { sys::cv_optflow_OpticalFlowPCAFlow_collectGarbage(self.as_raw_OpticalFlowPCAFlow()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::optflow::PCAPrior
+/// This class can be used for imposing a learned prior on the resulting optical flow.
+/// Solution will be regularized according to this prior.
+/// You need to generate appropriate prior file with "learn_prior.py" script beforehand.
+pub struct PCAPrior {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for PCAPrior {
+    fn drop(&mut self) {
+        unsafe { sys::cv_PCAPrior_delete(self.ptr) };
+    }
+}
+
+impl PCAPrior {
+    #[inline(always)] pub fn as_raw_PCAPrior(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for PCAPrior {}
+
+impl PCAPrior {
+    pub fn new(path_to_prior: &str) -> Result<crate::optflow::PCAPrior> {
+        string_arg!(path_to_prior);
+        unsafe { sys::cv_optflow_PCAPrior_PCAPrior_const_char_X(path_to_prior.as_ptr()) }.into_result().map(|ptr| crate::optflow::PCAPrior { ptr })
+    }
+    
+    pub fn get_padding(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_PCAPrior_getPadding_const(self.as_raw_PCAPrior()) }.into_result()
+    }
+    
+    pub fn get_basis_size(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_PCAPrior_getBasisSize_const(self.as_raw_PCAPrior()) }.into_result()
+    }
+    
+    pub fn fill_constraints(&self, a1: &mut f32, a2: &mut f32, b1: &mut f32, b2: &mut f32) -> Result<()> {
+        unsafe { sys::cv_optflow_PCAPrior_fillConstraints_const_float_X_float_X_float_X_float_X(self.as_raw_PCAPrior(), a1, a2, b1, b2) }.into_result()
+    }
+    
+}
+
+// boxed class cv::optflow::RLOFOpticalFlowParameter
+/// This is used store and set up the parameters of the robust local optical flow (RLOF) algoritm.
+///
+/// The RLOF is a fast local optical flow approach described in [Senst2012](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2012) [Senst2013](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2013) [Senst2014](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2014)
+/// and [Senst2016](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2016) similar to the pyramidal iterative Lucas-Kanade method as
+/// proposed by [Bouguet00](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Bouguet00). More details and experiments can be found in the following thesis [Senst2019](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2019).
+/// The implementation is derived from optflow::calcOpticalFlowPyrLK().
+/// This RLOF implementation can be seen as an improved pyramidal iterative Lucas-Kanade and includes
+/// a set of improving modules. The main improvements in respect to the pyramidal iterative Lucas-Kanade
+/// are:
+///  - A more robust redecending M-estimator framework (see [Senst2012](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2012)) to improve the accuracy at
+///  motion boundaries and appearing and disappearing pixels.
+///  - an adaptive support region strategies to improve the accuracy at motion boundaries to reduce the
+///  corona effect, i.e oversmoothing of the PLK at motion/object boundaries. The cross-based segementation
+///  strategy (SR_CROSS) proposed in [Senst2014](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2014) uses a simple segmenation approach to obtain the optimal
+///  shape of the support region.
+///  - To deal with illumination changes (outdoor sequences and shadow) the intensity constancy assumption
+///  based optical flow equation has been adopt with the Gennert and Negahdaripour illumination model
+///  (see [Senst2016](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2016)). This model can be switched on/off with the useIlluminationModel variable.
+///  - By using a global motion prior initialization (see [Senst2016](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2016)) of the iterative refinement
+///  the accuracy could be significantly improved for large displacements. This initialization can be
+///  switched on and of with useGlobalMotionPrior variable.
+///
+/// The RLOF can be computed with the SparseOpticalFlow class or function interface to track a set of features
+/// or with the DenseOpticalFlow class or function interface to compute dense optical flow.
+///
+/// @see optflow::DenseRLOFOpticalFlow, optflow::calcOpticalFlowDenseRLOF(), optflow::SparseRLOFOpticalFlow, optflow::calcOpticalFlowSparseRLOF()
+pub struct RLOFOpticalFlowParameter {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for RLOFOpticalFlowParameter {
+    fn drop(&mut self) {
+        unsafe { sys::cv_RLOFOpticalFlowParameter_delete(self.ptr) };
+    }
+}
+
+impl RLOFOpticalFlowParameter {
+    #[inline(always)] pub fn as_raw_RLOFOpticalFlowParameter(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for RLOFOpticalFlowParameter {}
+
+impl RLOFOpticalFlowParameter {
+    pub fn default() -> Result<crate::optflow::RLOFOpticalFlowParameter> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_RLOFOpticalFlowParameter() }.into_result().map(|ptr| crate::optflow::RLOFOpticalFlowParameter { ptr })
+    }
+    
+    /// < To apply the global motion prior motion vectors will be computed on a regulary sampled which
+    ///   are the basis for Homography estimation using RANSAC. The reprojection threshold is based on
+    ///   n-th percentil (given by this value [0 ... 100]) of the motion vectors magnitude.
+    ///   See [Senst2016](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2016) for more details.
+    pub fn set_solver_type(&mut self, val: crate::optflow::SolverType) -> Result<()> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setSolverType_SolverType(self.as_raw_RLOFOpticalFlowParameter(), val) }.into_result()
+    }
+    
+    pub fn get_solver_type(&self) -> Result<crate::optflow::SolverType> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getSolverType_const(self.as_raw_RLOFOpticalFlowParameter()) }.into_result()
+    }
+    
+    pub fn set_support_region_type(&mut self, val: crate::optflow::SupportRegionType) -> Result<()> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setSupportRegionType_SupportRegionType(self.as_raw_RLOFOpticalFlowParameter(), val) }.into_result()
+    }
+    
+    pub fn get_support_region_type(&self) -> Result<crate::optflow::SupportRegionType> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getSupportRegionType_const(self.as_raw_RLOFOpticalFlowParameter()) }.into_result()
+    }
+    
+    pub fn set_norm_sigma0(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setNormSigma0_float(self.as_raw_RLOFOpticalFlowParameter(), val) }.into_result()
+    }
+    
+    pub fn get_norm_sigma0(&self) -> Result<f32> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getNormSigma0_const(self.as_raw_RLOFOpticalFlowParameter()) }.into_result()
+    }
+    
+    pub fn set_norm_sigma1(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setNormSigma1_float(self.as_raw_RLOFOpticalFlowParameter(), val) }.into_result()
+    }
+    
+    pub fn get_norm_sigma1(&self) -> Result<f32> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getNormSigma1_const(self.as_raw_RLOFOpticalFlowParameter()) }.into_result()
+    }
+    
+    pub fn set_small_win_size(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setSmallWinSize_int(self.as_raw_RLOFOpticalFlowParameter(), val) }.into_result()
+    }
+    
+    pub fn get_small_win_size(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getSmallWinSize_const(self.as_raw_RLOFOpticalFlowParameter()) }.into_result()
+    }
+    
+    pub fn set_large_win_size(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setLargeWinSize_int(self.as_raw_RLOFOpticalFlowParameter(), val) }.into_result()
+    }
+    
+    pub fn get_large_win_size(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getLargeWinSize_const(self.as_raw_RLOFOpticalFlowParameter()) }.into_result()
+    }
+    
+    pub fn set_cross_segmentation_threshold(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setCrossSegmentationThreshold_int(self.as_raw_RLOFOpticalFlowParameter(), val) }.into_result()
+    }
+    
+    pub fn get_cross_segmentation_threshold(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getCrossSegmentationThreshold_const(self.as_raw_RLOFOpticalFlowParameter()) }.into_result()
+    }
+    
+    pub fn set_max_level(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setMaxLevel_int(self.as_raw_RLOFOpticalFlowParameter(), val) }.into_result()
+    }
+    
+    pub fn get_max_level(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getMaxLevel_const(self.as_raw_RLOFOpticalFlowParameter()) }.into_result()
+    }
+    
+    pub fn set_use_initial_flow(&mut self, val: bool) -> Result<()> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setUseInitialFlow_bool(self.as_raw_RLOFOpticalFlowParameter(), val) }.into_result()
+    }
+    
+    pub fn get_use_initial_flow(&self) -> Result<bool> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getUseInitialFlow_const(self.as_raw_RLOFOpticalFlowParameter()) }.into_result()
+    }
+    
+    pub fn set_use_illumination_model(&mut self, val: bool) -> Result<()> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setUseIlluminationModel_bool(self.as_raw_RLOFOpticalFlowParameter(), val) }.into_result()
+    }
+    
+    pub fn get_use_illumination_model(&self) -> Result<bool> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getUseIlluminationModel_const(self.as_raw_RLOFOpticalFlowParameter()) }.into_result()
+    }
+    
+    pub fn set_use_global_motion_prior(&mut self, val: bool) -> Result<()> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setUseGlobalMotionPrior_bool(self.as_raw_RLOFOpticalFlowParameter(), val) }.into_result()
+    }
+    
+    pub fn get_use_global_motion_prior(&self) -> Result<bool> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getUseGlobalMotionPrior_const(self.as_raw_RLOFOpticalFlowParameter()) }.into_result()
+    }
+    
+    pub fn set_max_iteration(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setMaxIteration_int(self.as_raw_RLOFOpticalFlowParameter(), val) }.into_result()
+    }
+    
+    pub fn get_max_iteration(&self) -> Result<i32> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getMaxIteration_const(self.as_raw_RLOFOpticalFlowParameter()) }.into_result()
+    }
+    
+    pub fn set_min_eigen_value(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setMinEigenValue_float(self.as_raw_RLOFOpticalFlowParameter(), val) }.into_result()
+    }
+    
+    pub fn get_min_eigen_value(&self) -> Result<f32> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getMinEigenValue_const(self.as_raw_RLOFOpticalFlowParameter()) }.into_result()
+    }
+    
+    pub fn set_global_motion_ransac_threshold(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_setGlobalMotionRansacThreshold_float(self.as_raw_RLOFOpticalFlowParameter(), val) }.into_result()
+    }
+    
+    pub fn get_global_motion_ransac_threshold(&self) -> Result<f32> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_getGlobalMotionRansacThreshold_const(self.as_raw_RLOFOpticalFlowParameter()) }.into_result()
+    }
+    
+    /// Creates instance of optflow::RLOFOpticalFlowParameter
+    pub fn create() -> Result<types::PtrOfRLOFOpticalFlowParameter> {
+        unsafe { sys::cv_optflow_RLOFOpticalFlowParameter_create() }.into_result().map(|ptr| types::PtrOfRLOFOpticalFlowParameter { ptr })
+    }
+    
+}
+
+// Generating impl for trait crate::optflow::SparseRLOFOpticalFlow
+/// Class used for calculation sparse optical flow and feature tracking with robust local optical flow (RLOF) algorithms.
+///
+/// The RLOF is a fast local optical flow approach described in [Senst2012](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2012) [Senst2013](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2013) [Senst2014](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2014)
+/// and [Senst2016](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2016) similar to the pyramidal iterative Lucas-Kanade method as
+/// proposed by [Bouguet00](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Bouguet00). More details and experiments can be found in the following thesis [Senst2019](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2019).
+/// The implementation is derived from optflow::calcOpticalFlowPyrLK().
+///
+/// For the RLOF configuration see optflow::RLOFOpticalFlowParameter for further details.
+/// Parameters have been described in [Senst2012](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2012), [Senst2013](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2013), [Senst2014](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2014) and [Senst2016](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Senst2016).
+///
+///
+/// Note: SIMD parallelization is only available when compiling with SSE4.1.
+/// @see optflow::calcOpticalFlowSparseRLOF(), optflow::RLOFOpticalFlowParameter
+pub trait SparseRLOFOpticalFlow: crate::video::SparseOpticalFlow {
+    fn as_raw_SparseRLOFOpticalFlow(&self) -> *mut c_void;
+    /// @copydoc DenseRLOFOpticalFlow::setRLOFOpticalFlowParameter
+    fn set_rlof_optical_flow_parameter(&mut self, val: &types::PtrOfRLOFOpticalFlowParameter) -> Result<()> {
+        unsafe { sys::cv_optflow_SparseRLOFOpticalFlow_setRLOFOpticalFlowParameter_PtrOfRLOFOpticalFlowParameter(self.as_raw_SparseRLOFOpticalFlow(), val.as_raw_PtrOfRLOFOpticalFlowParameter()) }.into_result()
+    }
+    
+    /// @copybrief setRLOFOpticalFlowParameter
+    ///    @see setRLOFOpticalFlowParameter
+    fn get_rlof_optical_flow_parameter(&self) -> Result<types::PtrOfRLOFOpticalFlowParameter> {
+        unsafe { sys::cv_optflow_SparseRLOFOpticalFlow_getRLOFOpticalFlowParameter_const(self.as_raw_SparseRLOFOpticalFlow()) }.into_result().map(|ptr| types::PtrOfRLOFOpticalFlowParameter { ptr })
+    }
+    
+    /// For each feature point a motion vector ![inline formula](https://latex.codecogs.com/png.latex?%20d_%7BI0%2CI1%7D%28%5Cmathbf%7Bx%7D%29%20) is computed.
+    ///  If the forward backward error ![block formula](https://latex.codecogs.com/png.latex?%20EP_%7BFB%7D%20%3D%20%7C%7C%20d_%7BI0%2CI1%7D%20%2B%20d_%7BI1%2CI0%7D%20%7C%7C%20)
+    ///  is larger than threshold given by this function then the status  will not be used by the following
+    ///    vector field interpolation. ![inline formula](https://latex.codecogs.com/png.latex?%20d_%7BI1%2CI0%7D%20) denotes the backward flow. Note, the forward backward test
+    ///    will only be applied if the threshold > 0. This may results into a doubled runtime for the motion estimation.
+    ///    @see setForwardBackward
+    fn set_forward_backward(&mut self, val: f32) -> Result<()> {
+        unsafe { sys::cv_optflow_SparseRLOFOpticalFlow_setForwardBackward_float(self.as_raw_SparseRLOFOpticalFlow(), val) }.into_result()
+    }
+    
+    /// @copybrief setForwardBackward
+    ///    @see setForwardBackward
+    fn get_forward_backward(&self) -> Result<f32> {
+        unsafe { sys::cv_optflow_SparseRLOFOpticalFlow_getForwardBackward_const(self.as_raw_SparseRLOFOpticalFlow()) }.into_result()
+    }
+    
+}
+
+impl dyn SparseRLOFOpticalFlow + '_ {
+    /// ## Parameters
+    /// * rlofParam: see setRLOFOpticalFlowParameter
+    /// * forwardBackwardThreshold: see setForwardBackward
+    ///
+    /// ## C++ default parameters
+    /// * rlof_param: Ptr<RLOFOpticalFlowParameter>()
+    /// * forward_backward_threshold: 1.f
+    pub fn create(rlof_param: &types::PtrOfRLOFOpticalFlowParameter, forward_backward_threshold: f32) -> Result<types::PtrOfSparseRLOFOpticalFlow> {
+        unsafe { sys::cv_optflow_SparseRLOFOpticalFlow_create_PtrOfRLOFOpticalFlowParameter_float(rlof_param.as_raw_PtrOfRLOFOpticalFlowParameter(), forward_backward_threshold) }.into_result().map(|ptr| types::PtrOfSparseRLOFOpticalFlow { ptr })
+    }
+    
+}
+
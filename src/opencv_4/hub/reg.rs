@@ -0,0 +1,654 @@
+//! # Image Registration
+//!
+//! The Registration module implements parametric image registration. The implemented method is direct
+//! alignment, that is, it uses directly the pixel values for calculating the registration between a
+//! pair of images, as opposed to feature-based registration. The implementation follows essentially the
+//! corresponding part of [Szeliski06](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Szeliski06) .
+//!
+//! Feature based methods have some advantages over pixel based methods when we are trying to register
+//! pictures that have been shoot under different lighting conditions or exposition times, or when the
+//! images overlap only partially. On the other hand, the main advantage of pixel-based methods when
+//! compared to feature based methods is their better precision for some pictures (those shoot under
+//! similar lighting conditions and that have a significative overlap), due to the fact that we are
+//! using all the information available in the image, which allows us to achieve subpixel accuracy. This
+//! is particularly important for certain applications like multi-frame denoising or super-resolution.
+//!
+//! In fact, pixel and feature registration methods can complement each other: an application could
+//! first obtain a coarse registration using features and then refine the registration using a pixel
+//! based method on the overlapping area of the images. The code developed allows this use case.
+//!
+//! The module implements classes derived from the abstract classes cv::reg::Map or cv::reg::Mapper. The
+//! former models a coordinate transformation between two reference frames, while the later encapsulates
+//! a way of invoking a method that calculates a Map between two images. Although the objective has been
+//! to implement pixel based methods, the module can be extended to support other methods that can
+//! calculate transformations between images (feature methods, optical flow, etc.).
+//!
+//! Each class derived from Map implements a motion model, as follows:
+//!
+//! *   MapShift: Models a simple translation
+//! *   MapAffine: Models an affine transformation
+//! *   MapProjec: Models a projective transformation
+//!
+//! MapProject can also be used to model affine motion or translations, but some operations on it are
+//! more costly, and that is the reason for defining the other two classes.
+//!
+//! The classes derived from Mapper are
+//!
+//! *   MapperGradShift: Gradient based alignment for calculating translations. It produces a MapShift
+//! (two parameters that correspond to the shift vector).
+//! *   MapperGradEuclid: Gradient based alignment for euclidean motions, that is, rotations and
+//! translations. It calculates three parameters (angle and shift vector), although the result is
+//! stored in a MapAffine object for convenience.
+//! *   MapperGradSimilar: Gradient based alignment for calculating similarities, which adds scaling to
+//! the euclidean motion. It calculates four parameters (two for the anti-symmetric matrix and two
+//! for the shift vector), although the result is stored in a MapAffine object for better
+//! convenience.
+//! *   MapperGradAffine: Gradient based alignment for an affine motion model. The number of parameters
+//! is six and the result is stored in a MapAffine object.
+//! *   MapperGradProj: Gradient based alignment for calculating projective transformations. The number
+//! of parameters is eight and the result is stored in a MapProject object.
+//! *   MapperPyramid: It implements hyerarchical motion estimation using a Gaussian pyramid. Its
+//! constructor accepts as argument any other object that implements the Mapper interface, and it is
+//! that mapper the one called by MapperPyramid for each scale of the pyramid.
+//!
+//! If the motion between the images is not very small, the normal way of using these classes is to
+//! create a MapperGrad\* object and use it as input to create a MapperPyramid, which in turn is called
+//! to perform the calculation. However, if the motion between the images is small enough, we can use
+//! directly the MapperGrad\* classes. Another possibility is to use first a feature based method to
+//! perform a coarse registration and then do a refinement through MapperPyramid or directly a
+//! MapperGrad\* object. The "calculate" method of the mappers accepts an initial estimation of the
+//! motion as input.
+//!
+//! When deciding which MapperGrad to use we must take into account that mappers with more parameters
+//! can handle more complex motions, but involve more calculations and are therefore slower. Also, if we
+//! are confident on the motion model that is followed by the sequence, increasing the number of
+//! parameters beyond what we need will decrease the accuracy: it is better to use the least number of
+//! degrees of freedom that we can.
+//!
+//! In the module tests there are examples that show how to register a pair of images using any of the
+//! implemented mappers.
+use crate::{mod_prelude::*, core, sys, types};
+use crate::core::{_InputArrayTrait, _OutputArrayTrait};
+
+
+// Generating impl for trait crate::reg::Map
+/// Base class for modelling a Map between two images.
+///
+/// The class is only used to define the common interface for any possible map.
+pub trait Map {
+    fn as_raw_Map(&self) -> *mut c_void;
+    fn warp(&self, img1: &dyn core::ToInputArray, img2: &mut dyn core::ToOutputArray) -> Result<()> {
+        input_array_arg!(img1);
+        output_array_arg!(img2);
+        unsafe { sys::cv_reg_Map_warp_const__InputArray__OutputArray(self.as_raw_Map(), img1.as_raw__InputArray(), img2.as_raw__OutputArray()) }.into_result()
+    }
+    
+    fn inverse_warp(&self, img1: &dyn core::ToInputArray, img2: &mut dyn core::ToOutputArray) -> Result<()> {
+        input_array_arg!(img1);
+        output_array_arg!(img2);
+        unsafe { sys::cv_reg_Map_inverseWarp_const__InputArray__OutputArray(self.as_raw_Map(), img1.as_raw__InputArray(), img2.as_raw__OutputArray()) }.into_result()
+    }
+    
+    fn inverse_map(&self) -> Result<types::PtrOfMap> {
+        unsafe { sys::cv_reg_Map_inverseMap_const(self.as_raw_Map()) }.into_result().map(|ptr| types::PtrOfMap { ptr })
+    }
+    
+    fn compose(&mut self, map: &types::PtrOfMap) -> Result<()> {
+        unsafe { sys::cv_reg_Map_compose_PtrOfMap(self.as_raw_Map(), map.as_raw_PtrOfMap()) }.into_result()
+    }
+    
+    fn scale(&mut self, factor: f64) -> Result<()> {
+        unsafe { sys::cv_reg_Map_scale_double(self.as_raw_Map(), factor) }.into_result()
+    }
+    
+}
+
+// boxed class cv::reg::MapAffine
+pub struct MapAffine {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for MapAffine {
+    fn drop(&mut self) {
+        unsafe { sys::cv_MapAffine_delete(self.ptr) };
+    }
+}
+
+impl MapAffine {
+    #[inline(always)] pub fn as_raw_MapAffine(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for MapAffine {}
+
+impl crate::reg::Map for MapAffine {
+    #[inline(always)] fn as_raw_Map(&self) -> *mut c_void { self.ptr }
+}
+
+impl MapAffine {
+    pub fn default() -> Result<crate::reg::MapAffine> {
+        unsafe { sys::cv_reg_MapAffine_MapAffine() }.into_result().map(|ptr| crate::reg::MapAffine { ptr })
+    }
+    
+    pub fn new(lin_tr: &dyn core::ToInputArray, shift: &dyn core::ToInputArray) -> Result<crate::reg::MapAffine> {
+        input_array_arg!(lin_tr);
+        input_array_arg!(shift);
+        unsafe { sys::cv_reg_MapAffine_MapAffine__InputArray__InputArray(lin_tr.as_raw__InputArray(), shift.as_raw__InputArray()) }.into_result().map(|ptr| crate::reg::MapAffine { ptr })
+    }
+    
+    pub fn inverse_warp(&self, img1: &dyn core::ToInputArray, img2: &mut dyn core::ToOutputArray) -> Result<()> {
+        input_array_arg!(img1);
+        output_array_arg!(img2);
+        unsafe { sys::cv_reg_MapAffine_inverseWarp_const__InputArray__OutputArray(self.as_raw_MapAffine(), img1.as_raw__InputArray(), img2.as_raw__OutputArray()) }.into_result()
+    }
+    
+    pub fn inverse_map(&self) -> Result<types::PtrOfMap> {
+        unsafe { sys::cv_reg_MapAffine_inverseMap_const(self.as_raw_MapAffine()) }.into_result().map(|ptr| types::PtrOfMap { ptr })
+    }
+    
+    pub fn compose(&mut self, map: &types::PtrOfMap) -> Result<()> {
+        unsafe { sys::cv_reg_MapAffine_compose_PtrOfMap(self.as_raw_MapAffine(), map.as_raw_PtrOfMap()) }.into_result()
+    }
+    
+    pub fn scale(&mut self, factor: f64) -> Result<()> {
+        unsafe { sys::cv_reg_MapAffine_scale_double(self.as_raw_MapAffine(), factor) }.into_result()
+    }
+    
+    pub fn get_lin_tr(&self) -> Result<core::Matx22d> {
+        unsafe { sys::cv_reg_MapAffine_getLinTr_const(self.as_raw_MapAffine()) }.into_result()
+    }
+    
+    pub fn get_lin_tr_to(&self, lin_tr: &mut dyn core::ToOutputArray) -> Result<()> {
+        output_array_arg!(lin_tr);
+        unsafe { sys::cv_reg_MapAffine_getLinTr_const__OutputArray(self.as_raw_MapAffine(), lin_tr.as_raw__OutputArray()) }.into_result()
+    }
+    
+    pub fn get_shift(&self) -> Result<core::Vec2d> {
+        unsafe { sys::cv_reg_MapAffine_getShift_const(self.as_raw_MapAffine()) }.into_result()
+    }
+    
+    pub fn get_shift_to(&self, shift: &mut dyn core::ToOutputArray) -> Result<()> {
+        output_array_arg!(shift);
+        unsafe { sys::cv_reg_MapAffine_getShift_const__OutputArray(self.as_raw_MapAffine(), shift.as_raw__OutputArray()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::reg::MapProjec
+pub struct MapProjec {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for MapProjec {
+    fn drop(&mut self) {
+        unsafe { sys::cv_MapProjec_delete(self.ptr) };
+    }
+}
+
+impl MapProjec {
+    #[inline(always)] pub fn as_raw_MapProjec(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for MapProjec {}
+
+impl crate::reg::Map for MapProjec {
+    #[inline(always)] fn as_raw_Map(&self) -> *mut c_void { self.ptr }
+}
+
+impl MapProjec {
+    pub fn default() -> Result<crate::reg::MapProjec> {
+        unsafe { sys::cv_reg_MapProjec_MapProjec() }.into_result().map(|ptr| crate::reg::MapProjec { ptr })
+    }
+    
+    pub fn new(proj_tr: &dyn core::ToInputArray) -> Result<crate::reg::MapProjec> {
+        input_array_arg!(proj_tr);
+        unsafe { sys::cv_reg_MapProjec_MapProjec__InputArray(proj_tr.as_raw__InputArray()) }.into_result().map(|ptr| crate::reg::MapProjec { ptr })
+    }
+    
+    pub fn inverse_warp(&self, img1: &dyn core::ToInputArray, img2: &mut dyn core::ToOutputArray) -> Result<()> {
+        input_array_arg!(img1);
+        output_array_arg!(img2);
+        unsafe { sys::cv_reg_MapProjec_inverseWarp_const__InputArray__OutputArray(self.as_raw_MapProjec(), img1.as_raw__InputArray(), img2.as_raw__OutputArray()) }.into_result()
+    }
+    
+    pub fn inverse_map(&self) -> Result<types::PtrOfMap> {
+        unsafe { sys::cv_reg_MapProjec_inverseMap_const(self.as_raw_MapProjec()) }.into_result().map(|ptr| types::PtrOfMap { ptr })
+    }
+    
+    pub fn compose(&mut self, map: &types::PtrOfMap) -> Result<()> {
+        unsafe { sys::cv_reg_MapProjec_compose_PtrOfMap(self.as_raw_MapProjec(), map.as_raw_PtrOfMap()) }.into_result()
+    }
+    
+    pub fn scale(&mut self, factor: f64) -> Result<()> {
+        unsafe { sys::cv_reg_MapProjec_scale_double(self.as_raw_MapProjec(), factor) }.into_result()
+    }
+    
+    pub fn get_proj_tr(&self) -> Result<core::Matx33d> {
+        unsafe { sys::cv_reg_MapProjec_getProjTr_const(self.as_raw_MapProjec()) }.into_result()
+    }
+    
+    pub fn get_proj_tr_to(&self, proj_tr: &mut dyn core::ToOutputArray) -> Result<()> {
+        output_array_arg!(proj_tr);
+        unsafe { sys::cv_reg_MapProjec_getProjTr_const__OutputArray(self.as_raw_MapProjec(), proj_tr.as_raw__OutputArray()) }.into_result()
+    }
+    
+    pub fn normalize(&mut self) -> Result<()> {
+        unsafe { sys::cv_reg_MapProjec_normalize(self.as_raw_MapProjec()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::reg::MapShift
+pub struct MapShift {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for MapShift {
+    fn drop(&mut self) {
+        unsafe { sys::cv_MapShift_delete(self.ptr) };
+    }
+}
+
+impl MapShift {
+    #[inline(always)] pub fn as_raw_MapShift(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for MapShift {}
+
+impl crate::reg::Map for MapShift {
+    #[inline(always)] fn as_raw_Map(&self) -> *mut c_void { self.ptr }
+}
+
+impl MapShift {
+    pub fn default() -> Result<crate::reg::MapShift> {
+        unsafe { sys::cv_reg_MapShift_MapShift() }.into_result().map(|ptr| crate::reg::MapShift { ptr })
+    }
+    
+    pub fn new(shift: &dyn core::ToInputArray) -> Result<crate::reg::MapShift> {
+        input_array_arg!(shift);
+        unsafe { sys::cv_reg_MapShift_MapShift__InputArray(shift.as_raw__InputArray()) }.into_result().map(|ptr| crate::reg::MapShift { ptr })
+    }
+    
+    pub fn inverse_warp(&self, img1: &dyn core::ToInputArray, img2: &mut dyn core::ToOutputArray) -> Result<()> {
+        input_array_arg!(img1);
+        output_array_arg!(img2);
+        unsafe { sys::cv_reg_MapShift_inverseWarp_const__InputArray__OutputArray(self.as_raw_MapShift(), img1.as_raw__InputArray(), img2.as_raw__OutputArray()) }.into_result()
+    }
+    
+    pub fn inverse_map(&self) -> Result<types::PtrOfMap> {
+        unsafe { sys::cv_reg_MapShift_inverseMap_const(self.as_raw_MapShift()) }.into_result().map(|ptr| types::PtrOfMap { ptr })
+    }
+    
+    pub fn compose(&mut self, map: &types::PtrOfMap) -> Result<()> {
+        unsafe { sys::cv_reg_MapShift_compose_PtrOfMap(self.as_raw_MapShift(), map.as_raw_PtrOfMap()) }.into_result()
+    }
+    
+    pub fn scale(&mut self, factor: f64) -> Result<()> {
+        unsafe { sys::cv_reg_MapShift_scale_double(self.as_raw_MapShift(), factor) }.into_result()
+    }
+    
+    pub fn get_shift(&self) -> Result<core::Vec2d> {
+        unsafe { sys::cv_reg_MapShift_getShift_const(self.as_raw_MapShift()) }.into_result()
+    }
+    
+    pub fn get_shift_to(&self, shift: &mut dyn core::ToOutputArray) -> Result<()> {
+        output_array_arg!(shift);
+        unsafe { sys::cv_reg_MapShift_getShift_const__OutputArray(self.as_raw_MapShift(), shift.as_raw__OutputArray()) }.into_result()
+    }
+    
+}
+
+// boxed class cv::reg::MapTypeCaster
+pub struct MapTypeCaster {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for MapTypeCaster {
+    fn drop(&mut self) {
+        unsafe { sys::cv_MapTypeCaster_delete(self.ptr) };
+    }
+}
+
+impl MapTypeCaster {
+    #[inline(always)] pub fn as_raw_MapTypeCaster(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for MapTypeCaster {}
+
+impl MapTypeCaster {
+    pub fn to_affine(source_map: &types::PtrOfMap) -> Result<types::PtrOfMapAffine> {
+        unsafe { sys::cv_reg_MapTypeCaster_toAffine_PtrOfMap(source_map.as_raw_PtrOfMap()) }.into_result().map(|ptr| types::PtrOfMapAffine { ptr })
+    }
+    
+    pub fn to_shift(source_map: &types::PtrOfMap) -> Result<types::PtrOfMapShift> {
+        unsafe { sys::cv_reg_MapTypeCaster_toShift_PtrOfMap(source_map.as_raw_PtrOfMap()) }.into_result().map(|ptr| types::PtrOfMapShift { ptr })
+    }
+    
+    pub fn to_projec(source_map: &types::PtrOfMap) -> Result<types::PtrOfMapProjec> {
+        unsafe { sys::cv_reg_MapTypeCaster_toProjec_PtrOfMap(source_map.as_raw_PtrOfMap()) }.into_result().map(|ptr| types::PtrOfMapProjec { ptr })
+    }
+    
+}
+
+// Generating impl for trait crate::reg::Mapper
+/// Base class for modelling an algorithm for calculating a map
+///
+/// The class is only used to define the common interface for any possible mapping algorithm.
+pub trait Mapper {
+    fn as_raw_Mapper(&self) -> *mut c_void;
+    ///
+    /// ## C++ default parameters
+    /// * init: cv::Ptr<Map>()
+    fn calculate(&self, img1: &dyn core::ToInputArray, img2: &dyn core::ToInputArray, init: &types::PtrOfMap) -> Result<types::PtrOfMap> {
+        input_array_arg!(img1);
+        input_array_arg!(img2);
+        unsafe { sys::cv_reg_Mapper_calculate_const__InputArray__InputArray_PtrOfMap(self.as_raw_Mapper(), img1.as_raw__InputArray(), img2.as_raw__InputArray(), init.as_raw_PtrOfMap()) }.into_result().map(|ptr| types::PtrOfMap { ptr })
+    }
+    
+    fn get_map(&self) -> Result<types::PtrOfMap> {
+        unsafe { sys::cv_reg_Mapper_getMap_const(self.as_raw_Mapper()) }.into_result().map(|ptr| types::PtrOfMap { ptr })
+    }
+    
+}
+
+// boxed class cv::reg::MapperGradAffine
+pub struct MapperGradAffine {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for MapperGradAffine {
+    fn drop(&mut self) {
+        unsafe { sys::cv_MapperGradAffine_delete(self.ptr) };
+    }
+}
+
+impl MapperGradAffine {
+    #[inline(always)] pub fn as_raw_MapperGradAffine(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for MapperGradAffine {}
+
+impl crate::reg::Mapper for MapperGradAffine {
+    #[inline(always)] fn as_raw_Mapper(&self) -> *mut c_void { self.ptr }
+}
+
+impl MapperGradAffine {
+    pub fn default() -> Result<crate::reg::MapperGradAffine> {
+        unsafe { sys::cv_reg_MapperGradAffine_MapperGradAffine() }.into_result().map(|ptr| crate::reg::MapperGradAffine { ptr })
+    }
+    
+    ///
+    /// ## C++ default parameters
+    /// * init: cv::Ptr<Map>()
+    pub fn calculate(&self, img1: &dyn core::ToInputArray, img2: &dyn core::ToInputArray, init: &types::PtrOfMap) -> Result<types::PtrOfMap> {
+        input_array_arg!(img1);
+        input_array_arg!(img2);
+        unsafe { sys::cv_reg_MapperGradAffine_calculate_const__InputArray__InputArray_PtrOfMap(self.as_raw_MapperGradAffine(), img1.as_raw__InputArray(), img2.as_raw__InputArray(), init.as_raw_PtrOfMap()) }.into_result().map(|ptr| types::PtrOfMap { ptr })
+    }
+    
+    pub fn get_map(&self) -> Result<types::PtrOfMap> {
+        unsafe { sys::cv_reg_MapperGradAffine_getMap_const(self.as_raw_MapperGradAffine()) }.into_result().map(|ptr| types::PtrOfMap { ptr })
+    }
+    
+}
+
+// boxed class cv::reg::MapperGradEuclid
+pub struct MapperGradEuclid {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for MapperGradEuclid {
+    fn drop(&mut self) {
+        unsafe { sys::cv_MapperGradEuclid_delete(self.ptr) };
+    }
+}
+
+impl MapperGradEuclid {
+    #[inline(always)] pub fn as_raw_MapperGradEuclid(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for MapperGradEuclid {}
+
+impl crate::reg::Mapper for MapperGradEuclid {
+    #[inline(always)] fn as_raw_Mapper(&self) -> *mut c_void { self.ptr }
+}
+
+impl MapperGradEuclid {
+    pub fn default() -> Result<crate::reg::MapperGradEuclid> {
+        unsafe { sys::cv_reg_MapperGradEuclid_MapperGradEuclid() }.into_result().map(|ptr| crate::reg::MapperGradEuclid { ptr })
+    }
+    
+    ///
+    /// ## C++ default parameters
+    /// * init: cv::Ptr<Map>()
+    pub fn calculate(&self, img1: &dyn core::ToInputArray, img2: &dyn core::ToInputArray, init: &types::PtrOfMap) -> Result<types::PtrOfMap> {
+        input_array_arg!(img1);
+        input_array_arg!(img2);
+        unsafe { sys::cv_reg_MapperGradEuclid_calculate_const__InputArray__InputArray_PtrOfMap(self.as_raw_MapperGradEuclid(), img1.as_raw__InputArray(), img2.as_raw__InputArray(), init.as_raw_PtrOfMap()) }.into_result().map(|ptr| types::PtrOfMap { ptr })
+    }
+    
+    pub fn get_map(&self) -> Result<types::PtrOfMap> {
+        unsafe { sys::cv_reg_MapperGradEuclid_getMap_const(self.as_raw_MapperGradEuclid()) }.into_result().map(|ptr| types::PtrOfMap { ptr })
+    }
+    
+}
+
+// boxed class cv::reg::MapperGradProj
+pub struct MapperGradProj {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for MapperGradProj {
+    fn drop(&mut self) {
+        unsafe { sys::cv_MapperGradProj_delete(self.ptr) };
+    }
+}
+
+impl MapperGradProj {
+    #[inline(always)] pub fn as_raw_MapperGradProj(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for MapperGradProj {}
+
+impl crate::reg::Mapper for MapperGradProj {
+    #[inline(always)] fn as_raw_Mapper(&self) -> *mut c_void { self.ptr }
+}
+
+impl MapperGradProj {
+    pub fn default() -> Result<crate::reg::MapperGradProj> {
+        unsafe { sys::cv_reg_MapperGradProj_MapperGradProj() }.into_result().map(|ptr| crate::reg::MapperGradProj { ptr })
+    }
+    
+    ///
+    /// ## C++ default parameters
+    /// * init: cv::Ptr<Map>()
+    pub fn calculate(&self, img1: &dyn core::ToInputArray, img2: &dyn core::ToInputArray, init: &types::PtrOfMap) -> Result<types::PtrOfMap> {
+        input_array_arg!(img1);
+        input_array_arg!(img2);
+        unsafe { sys::cv_reg_MapperGradProj_calculate_const__InputArray__InputArray_PtrOfMap(self.as_raw_MapperGradProj(), img1.as_raw__InputArray(), img2.as_raw__InputArray(), init.as_raw_PtrOfMap()) }.into_result().map(|ptr| types::PtrOfMap { ptr })
+    }
+    
+    pub fn get_map(&self) -> Result<types::PtrOfMap> {
+        unsafe { sys::cv_reg_MapperGradProj_getMap_const(self.as_raw_MapperGradProj()) }.into_result().map(|ptr| types::PtrOfMap { ptr })
+    }
+    
+}
+
+// boxed class cv::reg::MapperGradShift
+pub struct MapperGradShift {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for MapperGradShift {
+    fn drop(&mut self) {
+        unsafe { sys::cv_MapperGradShift_delete(self.ptr) };
+    }
+}
+
+impl MapperGradShift {
+    #[inline(always)] pub fn as_raw_MapperGradShift(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for MapperGradShift {}
+
+impl crate::reg::Mapper for MapperGradShift {
+    #[inline(always)] fn as_raw_Mapper(&self) -> *mut c_void { self.ptr }
+}
+
+impl MapperGradShift {
+    pub fn default() -> Result<crate::reg::MapperGradShift> {
+        unsafe { sys::cv_reg_MapperGradShift_MapperGradShift() }.into_result().map(|ptr| crate::reg::MapperGradShift { ptr })
+    }
+    
+    ///
+    /// ## C++ default parameters
+    /// * init: cv::Ptr<Map>()
+    pub fn calculate(&self, img1: &dyn core::ToInputArray, img2: &dyn core::ToInputArray, init: &types::PtrOfMap) -> Result<types::PtrOfMap> {
+        input_array_arg!(img1);
+        input_array_arg!(img2);
+        unsafe { sys::cv_reg_MapperGradShift_calculate_const__InputArray__InputArray_PtrOfMap(self.as_raw_MapperGradShift(), img1.as_raw__InputArray(), img2.as_raw__InputArray(), init.as_raw_PtrOfMap()) }.into_result().map(|ptr| types::PtrOfMap { ptr })
+    }
+    
+    pub fn get_map(&self) -> Result<types::PtrOfMap> {
+        unsafe { sys::cv_reg_MapperGradShift_getMap_const(self.as_raw_MapperGradShift()) }.into_result().map(|ptr| types::PtrOfMap { ptr })
+    }
+    
+}
+
+// boxed class cv::reg::MapperGradSimilar
+pub struct MapperGradSimilar {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for MapperGradSimilar {
+    fn drop(&mut self) {
+        unsafe { sys::cv_MapperGradSimilar_delete(self.ptr) };
+    }
+}
+
+impl MapperGradSimilar {
+    #[inline(always)] pub fn as_raw_MapperGradSimilar(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for MapperGradSimilar {}
+
+impl crate::reg::Mapper for MapperGradSimilar {
+    #[inline(always)] fn as_raw_Mapper(&self) -> *mut c_void { self.ptr }
+}
+
+impl MapperGradSimilar {
+    pub fn default() -> Result<crate::reg::MapperGradSimilar> {
+        unsafe { sys::cv_reg_MapperGradSimilar_MapperGradSimilar() }.into_result().map(|ptr| crate::reg::MapperGradSimilar { ptr })
+    }
+    
+    ///
+    /// ## C++ default parameters
+    /// * init: cv::Ptr<Map>()
+    pub fn calculate(&self, img1: &dyn core::ToInputArray, img2: &dyn core::ToInputArray, init: &types::PtrOfMap) -> Result<types::PtrOfMap> {
+        input_array_arg!(img1);
+        input_array_arg!(img2);
+        unsafe { sys::cv_reg_MapperGradSimilar_calculate_const__InputArray__InputArray_PtrOfMap(self.as_raw_MapperGradSimilar(), img1.as_raw__InputArray(), img2.as_raw__InputArray(), init.as_raw_PtrOfMap()) }.into_result().map(|ptr| types::PtrOfMap { ptr })
+    }
+    
+    pub fn get_map(&self) -> Result<types::PtrOfMap> {
+        unsafe { sys::cv_reg_MapperGradSimilar_getMap_const(self.as_raw_MapperGradSimilar()) }.into_result().map(|ptr| types::PtrOfMap { ptr })
+    }
+    
+}
+
+// boxed class cv::reg::MapperPyramid
+pub struct MapperPyramid {
+    #[doc(hidden)] pub(crate) ptr: *mut c_void
+}
+
+impl Drop for MapperPyramid {
+    fn drop(&mut self) {
+        unsafe { sys::cv_MapperPyramid_delete(self.ptr) };
+    }
+}
+
+impl MapperPyramid {
+    #[inline(always)] pub fn as_raw_MapperPyramid(&self) -> *mut c_void { self.ptr }
+
+    pub unsafe fn from_raw_ptr(ptr: *mut c_void) -> Self {
+        Self { ptr }
+    }
+}
+
+unsafe impl Send for MapperPyramid {}
+
+impl crate::reg::Mapper for MapperPyramid {
+    #[inline(always)] fn as_raw_Mapper(&self) -> *mut c_void { self.ptr }
+}
+
+impl MapperPyramid {
+    pub fn num_lev_(&self) -> Result<i32> {
+        unsafe { sys::cv_reg_MapperPyramid_numLev__const(self.as_raw_MapperPyramid()) }.into_result()
+    }
+    
+    pub fn set_num_lev_(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_reg_MapperPyramid_set_numLev__int(self.as_raw_MapperPyramid(), val) }.into_result()
+    }
+    
+    pub fn num_iter_per_scale_(&self) -> Result<i32> {
+        unsafe { sys::cv_reg_MapperPyramid_numIterPerScale__const(self.as_raw_MapperPyramid()) }.into_result()
+    }
+    
+    pub fn set_num_iter_per_scale_(&mut self, val: i32) -> Result<()> {
+        unsafe { sys::cv_reg_MapperPyramid_set_numIterPerScale__int(self.as_raw_MapperPyramid(), val) }.into_result()
+    }
+    
+    pub fn new(base_mapper: &types::PtrOfMapper) -> Result<crate::reg::MapperPyramid> {
+        unsafe { sys::cv_reg_MapperPyramid_MapperPyramid_PtrOfMapper(base_mapper.as_raw_PtrOfMapper()) }.into_result().map(|ptr| crate::reg::MapperPyramid { ptr })
+    }
+    
+    ///
+    /// ## C++ default parameters
+    /// * init: cv::Ptr<Map>()
+    pub fn calculate(&self, img1: &dyn core::ToInputArray, img2: &dyn core::ToInputArray, init: &types::PtrOfMap) -> Result<types::PtrOfMap> {
+        input_array_arg!(img1);
+        input_array_arg!(img2);
+        unsafe { sys::cv_reg_MapperPyramid_calculate_const__InputArray__InputArray_PtrOfMap(self.as_raw_MapperPyramid(), img1.as_raw__InputArray(), img2.as_raw__InputArray(), init.as_raw_PtrOfMap()) }.into_result().map(|ptr| types::PtrOfMap { ptr })
+    }
+    
+    pub fn get_map(&self) -> Result<types::PtrOfMap> {
+        unsafe { sys::cv_reg_MapperPyramid_getMap_const(self.as_raw_MapperPyramid()) }.into_result().map(|ptr| types::PtrOfMap { ptr })
+    }
+    
+}
+
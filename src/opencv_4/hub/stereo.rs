@@ -0,0 +1,579 @@
+//! # Stereo Correspondance Algorithms
+use crate::{mod_prelude::*, core, sys, types};
+use crate::core::{_InputArrayTrait, _OutputArrayTrait};
+
+pub const CV_CS_CENSUS: i32 = 2;
+pub const CV_DENSE_CENSUS: i32 = 0;
+pub const CV_MEAN_VARIATION: i32 = 5;
+pub const CV_MODIFIED_CENSUS_TRANSFORM: i32 = 4;
+pub const CV_MODIFIED_CS_CENSUS: i32 = 3;
+pub const CV_QUADRATIC_INTERPOLATION: i32 = 0;
+pub const CV_SIMETRICV_INTERPOLATION: i32 = 1;
+pub const CV_SPARSE_CENSUS: i32 = 1;
+pub const CV_SPECKLE_REMOVAL_ALGORITHM: i32 = 0;
+pub const CV_SPECKLE_REMOVAL_AVG_ALGORITHM: i32 = 1;
+pub const CV_STAR_KERNEL: i32 = 6;
+pub const StereoBinaryBM_PREFILTER_NORMALIZED_RESPONSE: i32 = 0;
+pub const StereoBinaryBM_PREFILTER_XSOBEL: i32 = 1;
+pub const StereoBinarySGBM_MODE_HH: i32 = 1;
+pub const StereoBinarySGBM_MODE_SGBM: i32 = 0;
+pub const StereoMatcher_DISP_SHIFT: i32 = 4;
+
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Match {
+    pub p0: core::Point2i,
+    pub p1: core::Point2i,
+    pub corr: f32,
+}
+
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PropagationParameters {
+    pub corr_win_size_x: i32,
+    pub corr_win_size_y: i32,
+    pub border_x: i32,
+    pub border_y: i32,
+    pub correlation_threshold: f32,
+    pub textrure_threshold: f32,
+    pub neighborhood_size: i32,
+    pub disparity_gradient: i32,
+    pub lk_template_size: i32,
+    pub lk_pyr_lvl: i32,
+    pub lk_term_param1: i32,
+    pub lk_term_param2: f32,
+    pub gft_quality_thres: f32,
+    pub gft_min_seperation_dist: i32,
+    pub gft_max_num_features: i32,
+}
+
+/// Two variations of census applied on input images
+/// Implementation of a census transform which is taking into account just the some pixels from the census kernel thus allowing for larger block sizes
+pub fn census_transform(image1: &core::Mat, image2: &core::Mat, kernel_size: i32, dist1: &mut core::Mat, dist2: &mut core::Mat, _type: i32) -> Result<()> {
+    unsafe { sys::cv_stereo_censusTransform_Mat_Mat_int_Mat_Mat_int(image1.as_raw_Mat(), image2.as_raw_Mat(), kernel_size, dist1.as_raw_Mat(), dist2.as_raw_Mat(), _type) }.into_result()
+}
+
+pub fn census_transform_single(image1: &core::Mat, kernel_size: i32, dist1: &mut core::Mat, _type: i32) -> Result<()> {
+    unsafe { sys::cv_stereo_censusTransform_Mat_int_Mat_int(image1.as_raw_Mat(), kernel_size, dist1.as_raw_Mat(), _type) }.into_result()
+}
+
+/// STANDARD_MCT - Modified census which is memorizing for each pixel 2 bits and includes a tolerance to the pixel comparison
+/// MCT_MEAN_VARIATION - Implementation of a modified census transform which is also taking into account the variation to the mean of the window not just the center pixel
+///
+/// ## C++ default parameters
+/// * t: 0
+/// * integral_image1: cv::Mat::zeros(100,100,CV_8UC1)
+/// * integral_image2: cv::Mat::zeros(100,100,CV_8UC1)
+pub fn modified_census_transform(img1: &core::Mat, img2: &core::Mat, kernel_size: i32, dist1: &mut core::Mat, dist2: &mut core::Mat, _type: i32, t: i32, integral_image1: &core::Mat, integral_image2: &core::Mat) -> Result<()> {
+    unsafe { sys::cv_stereo_modifiedCensusTransform_Mat_Mat_int_Mat_Mat_int_int_Mat_Mat(img1.as_raw_Mat(), img2.as_raw_Mat(), kernel_size, dist1.as_raw_Mat(), dist2.as_raw_Mat(), _type, t, integral_image1.as_raw_Mat(), integral_image2.as_raw_Mat()) }.into_result()
+}
+
+///
+/// ## C++ default parameters
+/// * t: 0
+/// * integral_image: cv::Mat::zeros(100,100,CV_8UC1)
+pub fn modified_census_transform_single(img1: &core::Mat, kernel_size: i32, dist: &mut core::Mat, _type: i32, t: i32, integral_image: &core::Mat) -> Result<()> {
+    unsafe { sys::cv_stereo_modifiedCensusTransform_Mat_int_Mat_int_int_Mat(img1.as_raw_Mat(), kernel_size, dist.as_raw_Mat(), _type, t, integral_image.as_raw_Mat()) }.into_result()
+}
+
+pub fn star_census_transform(img1: &core::Mat, img2: &core::Mat, kernel_size: i32, dist1: &mut core::Mat, dist2: &mut core::Mat) -> Result<()> {
+    unsafe { sys::cv_stereo_starCensusTransform_Mat_Mat_int_Mat_Mat(img1.as_raw_Mat(), img2.as_raw_Mat(), kernel_size, dist1.as_raw_Mat(), dist2.as_raw_Mat()) }.into_result()
+}
+
+pub fn star_census_transform_single(img1: &core::Mat, kernel_size: i32, dist: &mut core::Mat) -> Result<()> {
+    unsafe { sys::cv_stereo_starCensusTransform_Mat_int_Mat(img1.as_raw_Mat(), kernel_size, dist.as_raw_Mat()) }.into_result()
+}
+
+/// The classical center symetric census
+/// A modified version of cs census which is comparing a pixel with its correspondent after the center
+pub fn symetric_census_transform(img1: &core::Mat, img2: &core::Mat, kernel_size: i32, dist1: &mut core::Mat, dist2: &mut core::Mat, _type: i32) -> Result<()> {
+    unsafe { sys::cv_stereo_symetricCensusTransform_Mat_Mat_int_Mat_Mat_int(img1.as_raw_Mat(), img2.as_raw_Mat(), kernel_size, dist1.as_raw_Mat(), dist2.as_raw_Mat(), _type) }.into_result()
+}
+
+pub fn symetric_census_transform_single(img1: &core::Mat, kernel_size: i32, dist1: &mut core::Mat, _type: i32) -> Result<()> {
+    unsafe { sys::cv_stereo_symetricCensusTransform_Mat_int_Mat_int(img1.as_raw_Mat(), kernel_size, dist1.as_raw_Mat(), _type) }.into_result()
+}
+
+// Generating impl for trait crate::stereo::QuasiDenseStereo
+/// Class containing the methods needed for Quasi Dense Stereo computation.
+///
+/// This module contains the code to perform quasi dense stereo matching.
+/// The method initially starts with a sparse 3D reconstruction based on feature matching across a
+/// stereo image pair and subsequently propagates the structure into neighboring image regions.
+/// To obtain initial seed correspondences, the algorithm locates Shi and Tomashi features in the
+/// left image of the stereo pair and then tracks them using pyramidal Lucas-Kanade in the right image.
+/// To densify the sparse correspondences, the algorithm computes the zero-mean normalized
+/// cross-correlation (ZNCC) in small patches around every seed pair and uses it as a quality metric
+/// for each match. In this code, we introduce a custom structure to store the location and ZNCC value
+/// of correspondences called "Match". Seed Matches are stored in a priority queue sorted according to
+/// their ZNCC value, allowing for the best quality Match to be readily available. The algorithm pops
+/// Matches and uses them to extract new matches around them. This is done by considering a small
+/// neighboring area around each Seed and retrieving correspondences above a certain texture threshold
+/// that are not previously computed. New matches are stored in the seed priority queue and used as seeds.
+/// The propagation process ends when no additional matches can be retrieved.
+///
+///
+/// ## See also
+/// This code represents the work presented in [Stoyanov2010](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Stoyanov2010).
+/// If this code is useful for your work please cite [Stoyanov2010](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Stoyanov2010).
+///
+/// Also the original growing scheme idea is described in [Lhuillier2000](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_Lhuillier2000)
+pub trait QuasiDenseStereo {
+    fn as_raw_QuasiDenseStereo(&self) -> *mut c_void;
+    fn param(&self) -> Result<crate::stereo::PropagationParameters> {
+        unsafe { sys::cv_stereo_QuasiDenseStereo_Param_const(self.as_raw_QuasiDenseStereo()) }.into_result()
+    }
+    
+    fn set_param(&mut self, val: crate::stereo::PropagationParameters) -> Result<()> {
+        unsafe { sys::cv_stereo_QuasiDenseStereo_set_Param_PropagationParameters(self.as_raw_QuasiDenseStereo(), val) }.into_result()
+    }
+    
+    /// Load a file containing the configuration parameters of the class.
+    /// ## Parameters
+    /// * filepath: The location of the .YAML file containing the configuration parameters.
+    ///
+    /// Note: default value is an empty string in which case the default parameters will be loaded.
+    /// @retval 1: If the path is not empty and the program loaded the parameters successfully.
+    /// @retval 0: If the path is empty and the program loaded default parameters.
+    /// @retval -1: If the file location is not valid or the program could not open the file and
+    /// loaded default parameters from defaults.hpp.
+    ///
+    /// Note: The method is automatically called in the constructor and configures the class.
+    ///
+    /// Note: Loading different parameters will have an effect on the output. This is useful for tuning
+    /// in case of video processing.
+    /// ## See also
+    /// loadParameters
+    fn load_parameters(&mut self, filepath: &str) -> Result<i32> {
+        string_arg!(mut filepath);
+        unsafe { sys::cv_stereo_QuasiDenseStereo_loadParameters_String(self.as_raw_QuasiDenseStereo(), filepath.as_ptr() as _) }.into_result()
+    }
+    
+    /// Save a file containing all the configuration parameters the class is currently set to.
+    /// ## Parameters
+    /// * filepath: The location to store the parameters file.
+    ///
+    /// Note: Calling this method with no arguments will result in storing class parameters to a file
+    /// names "qds_parameters.yaml" in the root project folder.
+    ///
+    /// Note: This method can be used to generate a template file for tuning the class.
+    /// ## See also
+    /// loadParameters
+    fn save_parameters(&mut self, filepath: &str) -> Result<i32> {
+        string_arg!(mut filepath);
+        unsafe { sys::cv_stereo_QuasiDenseStereo_saveParameters_String(self.as_raw_QuasiDenseStereo(), filepath.as_ptr() as _) }.into_result()
+    }
+    
+    /// Get The sparse corresponding points.
+    /// ## Parameters
+    /// * sMatches: [out] A vector containing all sparse correspondences.
+    ///
+    /// Note: The method clears the sMatches vector.
+    ///
+    /// Note: The returned Match elements inside the sMatches vector, do not use corr member.
+    fn get_sparse_matches(&mut self, s_matches: &mut types::VectorOfStereo_Match) -> Result<()> {
+        unsafe { sys::cv_stereo_QuasiDenseStereo_getSparseMatches_VectorOfStereo_Match(self.as_raw_QuasiDenseStereo(), s_matches.as_raw_VectorOfStereo_Match()) }.into_result()
+    }
+    
+    /// Get The dense corresponding points.
+    /// ## Parameters
+    /// * denseMatches: [out] A vector containing all dense matches.
+    ///
+    /// Note: The method clears the denseMatches vector.
+    ///
+    /// Note: The returned Match elements inside the sMatches vector, do not use corr member.
+    fn get_dense_matches(&mut self, dense_matches: &mut types::VectorOfStereo_Match) -> Result<()> {
+        unsafe { sys::cv_stereo_QuasiDenseStereo_getDenseMatches_VectorOfStereo_Match(self.as_raw_QuasiDenseStereo(), dense_matches.as_raw_VectorOfStereo_Match()) }.into_result()
+    }
+    
+    /// Main process of the algorithm. This method computes the sparse seeds and then densifies them.
+    ///
+    /// Initially input images are converted to gray-scale and then the sparseMatching method
+    /// is called to obtain the sparse stereo. Finally quasiDenseMatching is called to densify the corresponding
+    /// points.
+    /// ## Parameters
+    /// * imgLeft: The left Channel of a stereo image pair.
+    /// * imgRight: The right Channel of a stereo image pair.
+    ///
+    /// Note: If input images are in color, the method assumes that are BGR and converts them to grayscale.
+    /// ## See also
+    /// sparseMatching
+    ///  quasiDenseMatching
+    fn process(&mut self, img_left: &core::Mat, img_right: &core::Mat) -> Result<()> {
+        unsafe { sys::cv_stereo_QuasiDenseStereo_process_Mat_Mat(self.as_raw_QuasiDenseStereo(), img_left.as_raw_Mat(), img_right.as_raw_Mat()) }.into_result()
+    }
+    
+    /// Specify pixel coordinates in the left image and get its corresponding location in the right image.
+    /// ## Parameters
+    /// * x: The x pixel coordinate in the left image channel.
+    /// * y: The y pixel coordinate in the left image channel.
+    /// @retval cv::Point(x, y) The location of the corresponding pixel in the right image.
+    /// @retval cv::Point(0, 0) (NO_MATCH)  if no match is found in the right image for the specified pixel location in the left image.
+    ///
+    /// Note: This method should be always called after process, otherwise the matches will not be correct.
+    fn get_match(&mut self, x: i32, y: i32) -> Result<core::Point2f> {
+        unsafe { sys::cv_stereo_QuasiDenseStereo_getMatch_int_int(self.as_raw_QuasiDenseStereo(), x, y) }.into_result()
+    }
+    
+    /// Compute and return the disparity map based on the correspondences found in the "process" method.
+    /// ## Parameters
+    /// * disparityLvls: The level of detail in output disparity image.
+    ///
+    /// Note: Default level is 50
+    /// ## Returns
+    /// cv::Mat containing a the disparity image in grayscale.
+    /// ## See also
+    /// computeDisparity
+    ///  quantizeDisparity
+    ///
+    /// ## C++ default parameters
+    /// * disparity_lvls: 50
+    fn get_disparity(&mut self, disparity_lvls: u8) -> Result<core::Mat> {
+        unsafe { sys::cv_stereo_QuasiDenseStereo_getDisparity_uint8_t(self.as_raw_QuasiDenseStereo(), disparity_lvls) }.into_result().map(|ptr| core::Mat { ptr })
+    }
+    
+}
+
+impl dyn QuasiDenseStereo + '_ {
+    ///
+    /// ## C++ default parameters
+    /// * param_filepath: cv::String()
+    pub fn create(mono_img_size: core::Size, param_filepath: &str) -> Result<types::PtrOfQuasiDenseStereo> {
+        string_arg!(mut param_filepath);
+        unsafe { sys::cv_stereo_QuasiDenseStereo_create_Size_String(mono_img_size, param_filepath.as_ptr() as _) }.into_result().map(|ptr| types::PtrOfQuasiDenseStereo { ptr })
+    }
+    
+}
+
+// Generating impl for trait crate::stereo::StereoBinaryBM
+/// Class for computing stereo correspondence using the block matching algorithm, introduced and
+/// contributed to OpenCV by K. Konolige.
+pub trait StereoBinaryBM: crate::stereo::StereoMatcher {
+    fn as_raw_StereoBinaryBM(&self) -> *mut c_void;
+    fn get_pre_filter_type(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_getPreFilterType_const(self.as_raw_StereoBinaryBM()) }.into_result()
+    }
+    
+    fn set_pre_filter_type(&mut self, pre_filter_type: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_setPreFilterType_int(self.as_raw_StereoBinaryBM(), pre_filter_type) }.into_result()
+    }
+    
+    fn get_pre_filter_size(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_getPreFilterSize_const(self.as_raw_StereoBinaryBM()) }.into_result()
+    }
+    
+    fn set_pre_filter_size(&mut self, pre_filter_size: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_setPreFilterSize_int(self.as_raw_StereoBinaryBM(), pre_filter_size) }.into_result()
+    }
+    
+    fn get_pre_filter_cap(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_getPreFilterCap_const(self.as_raw_StereoBinaryBM()) }.into_result()
+    }
+    
+    fn set_pre_filter_cap(&mut self, pre_filter_cap: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_setPreFilterCap_int(self.as_raw_StereoBinaryBM(), pre_filter_cap) }.into_result()
+    }
+    
+    fn get_texture_threshold(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_getTextureThreshold_const(self.as_raw_StereoBinaryBM()) }.into_result()
+    }
+    
+    fn set_texture_threshold(&mut self, texture_threshold: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_setTextureThreshold_int(self.as_raw_StereoBinaryBM(), texture_threshold) }.into_result()
+    }
+    
+    fn get_uniqueness_ratio(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_getUniquenessRatio_const(self.as_raw_StereoBinaryBM()) }.into_result()
+    }
+    
+    fn set_uniqueness_ratio(&mut self, uniqueness_ratio: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_setUniquenessRatio_int(self.as_raw_StereoBinaryBM(), uniqueness_ratio) }.into_result()
+    }
+    
+    fn get_smaller_block_size(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_getSmallerBlockSize_const(self.as_raw_StereoBinaryBM()) }.into_result()
+    }
+    
+    fn set_smaller_block_size(&mut self, block_size: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_setSmallerBlockSize_int(self.as_raw_StereoBinaryBM(), block_size) }.into_result()
+    }
+    
+    fn get_scalle_factor(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_getScalleFactor_const(self.as_raw_StereoBinaryBM()) }.into_result()
+    }
+    
+    fn set_scalle_factor(&mut self, factor: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_setScalleFactor_int(self.as_raw_StereoBinaryBM(), factor) }.into_result()
+    }
+    
+    fn get_spekle_removal_technique(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_getSpekleRemovalTechnique_const(self.as_raw_StereoBinaryBM()) }.into_result()
+    }
+    
+    fn set_spekle_removal_technique(&mut self, factor: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_setSpekleRemovalTechnique_int(self.as_raw_StereoBinaryBM(), factor) }.into_result()
+    }
+    
+    fn get_use_prefilter(&self) -> Result<bool> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_getUsePrefilter_const(self.as_raw_StereoBinaryBM()) }.into_result()
+    }
+    
+    fn set_use_prefilter(&mut self, factor: bool) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_setUsePrefilter_bool(self.as_raw_StereoBinaryBM(), factor) }.into_result()
+    }
+    
+    fn get_binary_kernel_type(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_getBinaryKernelType_const(self.as_raw_StereoBinaryBM()) }.into_result()
+    }
+    
+    fn set_binary_kernel_type(&mut self, value: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_setBinaryKernelType_int(self.as_raw_StereoBinaryBM(), value) }.into_result()
+    }
+    
+    fn get_agregation_window_size(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_getAgregationWindowSize_const(self.as_raw_StereoBinaryBM()) }.into_result()
+    }
+    
+    fn set_agregation_window_size(&mut self, value: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_setAgregationWindowSize_int(self.as_raw_StereoBinaryBM(), value) }.into_result()
+    }
+    
+}
+
+impl dyn StereoBinaryBM + '_ {
+    /// Creates StereoBM object
+    ///
+    /// ## Parameters
+    /// * numDisparities: the disparity search range. For each pixel algorithm will find the best
+    /// disparity from 0 (default minimum disparity) to numDisparities. The search range can then be
+    /// shifted by changing the minimum disparity.
+    /// * blockSize: the linear size of the blocks compared by the algorithm. The size should be odd
+    /// (as the block is centered at the current pixel). Larger block size implies smoother, though less
+    /// accurate disparity map. Smaller block size gives more detailed disparity map, but there is higher
+    /// chance for algorithm to find a wrong correspondence.
+    ///
+    /// The function create StereoBM object. You can then call StereoBM::compute() to compute disparity for
+    /// a specific stereo pair.
+    ///
+    /// ## C++ default parameters
+    /// * num_disparities: 0
+    /// * block_size: 9
+    pub fn create(num_disparities: i32, block_size: i32) -> Result<types::PtrOfStereoBinaryBM> {
+        unsafe { sys::cv_stereo_StereoBinaryBM_create_int_int(num_disparities, block_size) }.into_result().map(|ptr| types::PtrOfStereoBinaryBM { ptr })
+    }
+    
+}
+
+// Generating impl for trait crate::stereo::StereoBinarySGBM
+/// The class implements the modified H. Hirschmuller algorithm [HH08](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_HH08) that differs from the original
+/// one as follows:
+///
+/// *   By default, the algorithm is single-pass, which means that you consider only 5 directions
+/// instead of 8. Set mode=StereoSGBM::MODE_HH in createStereoSGBM to run the full variant of the
+/// algorithm but beware that it may consume a lot of memory.
+/// *   The algorithm matches blocks, not individual pixels. Though, setting blockSize=1 reduces the
+/// blocks to single pixels.
+/// *   Mutual information cost function is not implemented. Instead, a simpler Birchfield-Tomasi
+/// sub-pixel metric from [BT98](https://docs.opencv.org/4.2.0/d0/de3/citelist.html#CITEREF_BT98) is used. Though, the color images are supported as well.
+/// *   Some pre- and post- processing steps from K. Konolige algorithm StereoBM are included, for
+/// example: pre-filtering (StereoBM::PREFILTER_XSOBEL type) and post-filtering (uniqueness
+/// check, quadratic interpolation and speckle filtering).
+///
+///
+/// Note:
+/// *   (Python) An example illustrating the use of the StereoSGBM matching algorithm can be found
+/// at opencv_source_code/samples/python2/stereo_match.py
+pub trait StereoBinarySGBM: crate::stereo::StereoMatcher {
+    fn as_raw_StereoBinarySGBM(&self) -> *mut c_void;
+    fn get_pre_filter_cap(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_getPreFilterCap_const(self.as_raw_StereoBinarySGBM()) }.into_result()
+    }
+    
+    fn set_pre_filter_cap(&mut self, pre_filter_cap: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_setPreFilterCap_int(self.as_raw_StereoBinarySGBM(), pre_filter_cap) }.into_result()
+    }
+    
+    fn get_uniqueness_ratio(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_getUniquenessRatio_const(self.as_raw_StereoBinarySGBM()) }.into_result()
+    }
+    
+    fn set_uniqueness_ratio(&mut self, uniqueness_ratio: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_setUniquenessRatio_int(self.as_raw_StereoBinarySGBM(), uniqueness_ratio) }.into_result()
+    }
+    
+    fn get_p1(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_getP1_const(self.as_raw_StereoBinarySGBM()) }.into_result()
+    }
+    
+    fn set_p1(&mut self, p1: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_setP1_int(self.as_raw_StereoBinarySGBM(), p1) }.into_result()
+    }
+    
+    fn get_p2(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_getP2_const(self.as_raw_StereoBinarySGBM()) }.into_result()
+    }
+    
+    fn set_p2(&mut self, p2: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_setP2_int(self.as_raw_StereoBinarySGBM(), p2) }.into_result()
+    }
+    
+    fn get_mode(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_getMode_const(self.as_raw_StereoBinarySGBM()) }.into_result()
+    }
+    
+    fn set_mode(&mut self, mode: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_setMode_int(self.as_raw_StereoBinarySGBM(), mode) }.into_result()
+    }
+    
+    fn get_spekle_removal_technique(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_getSpekleRemovalTechnique_const(self.as_raw_StereoBinarySGBM()) }.into_result()
+    }
+    
+    fn set_spekle_removal_technique(&mut self, factor: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_setSpekleRemovalTechnique_int(self.as_raw_StereoBinarySGBM(), factor) }.into_result()
+    }
+    
+    fn get_binary_kernel_type(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_getBinaryKernelType_const(self.as_raw_StereoBinarySGBM()) }.into_result()
+    }
+    
+    fn set_binary_kernel_type(&mut self, value: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_setBinaryKernelType_int(self.as_raw_StereoBinarySGBM(), value) }.into_result()
+    }
+    
+    fn get_sub_pixel_interpolation_method(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_getSubPixelInterpolationMethod_const(self.as_raw_StereoBinarySGBM()) }.into_result()
+    }
+    
+    fn set_sub_pixel_interpolation_method(&mut self, value: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_setSubPixelInterpolationMethod_int(self.as_raw_StereoBinarySGBM(), value) }.into_result()
+    }
+    
+}
+
+impl dyn StereoBinarySGBM + '_ {
+    /// Creates StereoSGBM object
+    ///
+    /// ## Parameters
+    /// * minDisparity: Minimum possible disparity value. Normally, it is zero but sometimes
+    /// rectification algorithms can shift images, so this parameter needs to be adjusted accordingly.
+    /// * numDisparities: Maximum disparity minus minimum disparity. The value is always greater than
+    /// zero. In the current implementation, this parameter must be divisible by 16.
+    /// * blockSize: Matched block size. It must be an odd number \>=1 . Normally, it should be
+    /// somewhere in the 3..11 range.
+    /// * P1: The first parameter controlling the disparity smoothness.This parameter is used for the case of slanted surfaces (not fronto parallel).
+    /// * P2: The second parameter controlling the disparity smoothness.This parameter is used for "solving" the depth discontinuities problem.
+    /// The larger the values are, the smoother the disparity is. P1 is the penalty on the disparity change by plus or minus 1
+    /// between neighbor pixels. P2 is the penalty on the disparity change by more than 1 between neighbor
+    /// pixels. The algorithm requires P2 \> P1 . See stereo_match.cpp sample where some reasonably good
+    /// P1 and P2 values are shown (like 8\*number_of_image_channels\*SADWindowSize\*SADWindowSize and
+    /// 32\*number_of_image_channels\*SADWindowSize\*SADWindowSize , respectively).
+    /// * disp12MaxDiff: Maximum allowed difference (in integer pixel units) in the left-right
+    /// disparity check. Set it to a non-positive value to disable the check.
+    /// * preFilterCap: Truncation value for the prefiltered image pixels. The algorithm first
+    /// computes x-derivative at each pixel and clips its value by [-preFilterCap, preFilterCap] interval.
+    /// The result values are passed to the Birchfield-Tomasi pixel cost function.
+    /// * uniquenessRatio: Margin in percentage by which the best (minimum) computed cost function
+    /// value should "win" the second best value to consider the found match correct. Normally, a value
+    /// within the 5-15 range is good enough.
+    /// * speckleWindowSize: Maximum size of smooth disparity regions to consider their noise speckles
+    /// and invalidate. Set it to 0 to disable speckle filtering. Otherwise, set it somewhere in the
+    /// 50-200 range.
+    /// * speckleRange: Maximum disparity variation within each connected component. If you do speckle
+    /// filtering, set the parameter to a positive value, it will be implicitly multiplied by 16.
+    /// Normally, 1 or 2 is good enough.
+    /// * mode: Set it to StereoSGBM::MODE_HH to run the full-scale two-pass dynamic programming
+    /// algorithm. It will consume O(W\*H\*numDisparities) bytes, which is large for 640x480 stereo and
+    /// huge for HD-size pictures. By default, it is set to false .
+    ///
+    /// The first constructor initializes StereoSGBM with all the default parameters. So, you only have to
+    /// set StereoSGBM::numDisparities at minimum. The second constructor enables you to set each parameter
+    /// to a custom value.
+    ///
+    /// ## C++ default parameters
+    /// * p1: 100
+    /// * p2: 1000
+    /// * disp12_max_diff: 1
+    /// * pre_filter_cap: 0
+    /// * uniqueness_ratio: 5
+    /// * speckle_window_size: 400
+    /// * speckle_range: 200
+    /// * mode: StereoBinarySGBM::MODE_SGBM
+    pub fn create(min_disparity: i32, num_disparities: i32, block_size: i32, p1: i32, p2: i32, disp12_max_diff: i32, pre_filter_cap: i32, uniqueness_ratio: i32, speckle_window_size: i32, speckle_range: i32, mode: i32) -> Result<types::PtrOfStereoBinarySGBM> {
+        unsafe { sys::cv_stereo_StereoBinarySGBM_create_int_int_int_int_int_int_int_int_int_int_int(min_disparity, num_disparities, block_size, p1, p2, disp12_max_diff, pre_filter_cap, uniqueness_ratio, speckle_window_size, speckle_range, mode) }.into_result().map(|ptr| types::PtrOfStereoBinarySGBM { ptr })
+    }
+    
+}
+
+// Generating impl for trait crate::stereo::StereoMatcher
+/// The base class for stereo correspondence algorithms.
+pub trait StereoMatcher: core::AlgorithmTrait {
+    fn as_raw_StereoMatcher(&self) -> *mut c_void;
+    /// Computes disparity map for the specified stereo pair
+    ///
+    /// ## Parameters
+    /// * left: Left 8-bit single-channel image.
+    /// * right: Right image of the same size and the same type as the left one.
+    /// * disparity: Output disparity map. It has the same size as the input images. Some algorithms,
+    /// like StereoBM or StereoSGBM compute 16-bit fixed-point disparity map (where each disparity value
+    /// has 4 fractional bits), whereas other algorithms output 32-bit floating-point disparity map.
+    fn compute(&mut self, left: &dyn core::ToInputArray, right: &dyn core::ToInputArray, disparity: &mut dyn core::ToOutputArray) -> Result<()> {
+        input_array_arg!(left);
+        input_array_arg!(right);
+        output_array_arg!(disparity);
+        unsafe { sys::cv_stereo_StereoMatcher_compute__InputArray__InputArray__OutputArray(self.as_raw_StereoMatcher(), left.as_raw__InputArray(), right.as_raw__InputArray(), disparity.as_raw__OutputArray()) }.into_result()
+    }
+    
+    fn get_min_disparity(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoMatcher_getMinDisparity_const(self.as_raw_StereoMatcher()) }.into_result()
+    }
+    
+    fn set_min_disparity(&mut self, min_disparity: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoMatcher_setMinDisparity_int(self.as_raw_StereoMatcher(), min_disparity) }.into_result()
+    }
+    
+    fn get_num_disparities(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoMatcher_getNumDisparities_const(self.as_raw_StereoMatcher()) }.into_result()
+    }
+    
+    fn set_num_disparities(&mut self, num_disparities: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoMatcher_setNumDisparities_int(self.as_raw_StereoMatcher(), num_disparities) }.into_result()
+    }
+    
+    fn get_block_size(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoMatcher_getBlockSize_const(self.as_raw_StereoMatcher()) }.into_result()
+    }
+    
+    fn set_block_size(&mut self, block_size: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoMatcher_setBlockSize_int(self.as_raw_StereoMatcher(), block_size) }.into_result()
+    }
+    
+    fn get_speckle_window_size(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoMatcher_getSpeckleWindowSize_const(self.as_raw_StereoMatcher()) }.into_result()
+    }
+    
+    fn set_speckle_window_size(&mut self, speckle_window_size: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoMatcher_setSpeckleWindowSize_int(self.as_raw_StereoMatcher(), speckle_window_size) }.into_result()
+    }
+    
+    fn get_speckle_range(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoMatcher_getSpeckleRange_const(self.as_raw_StereoMatcher()) }.into_result()
+    }
+    
+    fn set_speckle_range(&mut self, speckle_range: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoMatcher_setSpeckleRange_int(self.as_raw_StereoMatcher(), speckle_range) }.into_result()
+    }
+    
+    fn get_disp12_max_diff(&self) -> Result<i32> {
+        unsafe { sys::cv_stereo_StereoMatcher_getDisp12MaxDiff_const(self.as_raw_StereoMatcher()) }.into_result()
+    }
+    
+    fn set_disp12_max_diff(&mut self, disp12_max_diff: i32) -> Result<()> {
+        unsafe { sys::cv_stereo_StereoMatcher_setDisp12MaxDiff_int(self.as_raw_StereoMatcher(), disp12_max_diff) }.into_result()
+    }
+    
+}
+
+pub const StereoMatcher_DISP_SCALE: i32 = 0x10; // 16
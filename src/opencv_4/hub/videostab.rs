@@ -224,7 +224,7 @@ pub trait DeblurerBase {
     }
     
     fn frames(&self) -> Result<types::VectorOfMat> {
-        unsafe { sys::cv_videostab_DeblurerBase_frames_const(self.as_raw_DeblurerBase()) }.into_result().map(|ptr| types::VectorOfMat { ptr })
+        unsafe { sys::cv_videostab_DeblurerBase_frames_const(self.as_raw_DeblurerBase()) }.into_result().map(|ptr| unsafe { types::VectorOfMat::from_raw_ptr(ptr) })
     }
     
     fn set_motions(&mut self, val: &types::VectorOfMat) -> Result<()> {
@@ -232,7 +232,7 @@ pub trait DeblurerBase {
     }
     
     fn motions(&self) -> Result<types::VectorOfMat> {
-        unsafe { sys::cv_videostab_DeblurerBase_motions_const(self.as_raw_DeblurerBase()) }.into_result().map(|ptr| types::VectorOfMat { ptr })
+        unsafe { sys::cv_videostab_DeblurerBase_motions_const(self.as_raw_DeblurerBase()) }.into_result().map(|ptr| unsafe { types::VectorOfMat::from_raw_ptr(ptr) })
     }
     
     fn set_blurriness_rates(&mut self, val: &types::VectorOffloat) -> Result<()> {
@@ -240,7 +240,7 @@ pub trait DeblurerBase {
     }
     
     fn blurriness_rates(&self) -> Result<types::VectorOffloat> {
-        unsafe { sys::cv_videostab_DeblurerBase_blurrinessRates_const(self.as_raw_DeblurerBase()) }.into_result().map(|ptr| types::VectorOffloat { ptr })
+        unsafe { sys::cv_videostab_DeblurerBase_blurrinessRates_const(self.as_raw_DeblurerBase()) }.into_result().map(|ptr| unsafe { types::VectorOffloat::from_raw_ptr(ptr) })
     }
     
 }
@@ -479,7 +479,7 @@ pub trait InpainterBase {
     }
     
     fn frames(&self) -> Result<types::VectorOfMat> {
-        unsafe { sys::cv_videostab_InpainterBase_frames_const(self.as_raw_InpainterBase()) }.into_result().map(|ptr| types::VectorOfMat { ptr })
+        unsafe { sys::cv_videostab_InpainterBase_frames_const(self.as_raw_InpainterBase()) }.into_result().map(|ptr| unsafe { types::VectorOfMat::from_raw_ptr(ptr) })
     }
     
     fn set_motions(&mut self, val: &types::VectorOfMat) -> Result<()> {
@@ -487,7 +487,7 @@ pub trait InpainterBase {
     }
     
     fn motions(&self) -> Result<types::VectorOfMat> {
-        unsafe { sys::cv_videostab_InpainterBase_motions_const(self.as_raw_InpainterBase()) }.into_result().map(|ptr| types::VectorOfMat { ptr })
+        unsafe { sys::cv_videostab_InpainterBase_motions_const(self.as_raw_InpainterBase()) }.into_result().map(|ptr| unsafe { types::VectorOfMat::from_raw_ptr(ptr) })
     }
     
     fn set_stabilized_frames(&mut self, val: &types::VectorOfMat) -> Result<()> {
@@ -495,7 +495,7 @@ pub trait InpainterBase {
     }
     
     fn stabilized_frames(&self) -> Result<types::VectorOfMat> {
-        unsafe { sys::cv_videostab_InpainterBase_stabilizedFrames_const(self.as_raw_InpainterBase()) }.into_result().map(|ptr| types::VectorOfMat { ptr })
+        unsafe { sys::cv_videostab_InpainterBase_stabilizedFrames_const(self.as_raw_InpainterBase()) }.into_result().map(|ptr| unsafe { types::VectorOfMat::from_raw_ptr(ptr) })
     }
     
     fn set_stabilization_motions(&mut self, val: &types::VectorOfMat) -> Result<()> {
@@ -503,7 +503,7 @@ pub trait InpainterBase {
     }
     
     fn stabilization_motions(&self) -> Result<types::VectorOfMat> {
-        unsafe { sys::cv_videostab_InpainterBase_stabilizationMotions_const(self.as_raw_InpainterBase()) }.into_result().map(|ptr| types::VectorOfMat { ptr })
+        unsafe { sys::cv_videostab_InpainterBase_stabilizationMotions_const(self.as_raw_InpainterBase()) }.into_result().map(|ptr| unsafe { types::VectorOfMat::from_raw_ptr(ptr) })
     }
     
 }
@@ -1381,6 +1381,20 @@ impl RansacParams {
 unsafe impl Send for RansacParams {}
 
 impl RansacParams {
+    pub fn default() -> Result<crate::videostab::RansacParams> {
+        unsafe { sys::cv_videostab_RansacParams_RansacParams() }.into_result().map(|ptr| crate::videostab::RansacParams { ptr })
+    }
+    
+    /// Constructor
+    /// ## Parameters
+    /// * size: Subset size.
+    /// * thresh: Maximum re-projection error value to classify as inlier.
+    /// * eps: Maximum ratio of incorrect correspondences.
+    /// * prob: Required success probability.
+    pub fn new(size: i32, thresh: f32, eps: f32, prob: f32) -> Result<crate::videostab::RansacParams> {
+        unsafe { sys::cv_videostab_RansacParams_RansacParams_int_float_float_float(size, thresh, eps, prob) }.into_result().map(|ptr| crate::videostab::RansacParams { ptr })
+    }
+    
     /// ## Returns
     /// Number of iterations that'll be performed by RANSAC method.
     pub fn niters(&self) -> Result<i32> {
@@ -1599,6 +1613,14 @@ impl TranslationBasedLocalOutlierRejector {
         unsafe { sys::cv_videostab_TranslationBasedLocalOutlierRejector_cellSize_const(self.as_raw_TranslationBasedLocalOutlierRejector()) }.into_result()
     }
     
+    pub fn set_ransac_params(&mut self, val: &crate::videostab::RansacParams) -> Result<()> {
+        unsafe { sys::cv_videostab_TranslationBasedLocalOutlierRejector_setRansacParams_RansacParams(self.as_raw_TranslationBasedLocalOutlierRejector(), val.as_raw_RansacParams()) }.into_result()
+    }
+    
+    pub fn ransac_params(&self) -> Result<crate::videostab::RansacParams> {
+        unsafe { sys::cv_videostab_TranslationBasedLocalOutlierRejector_ransacParams_const(self.as_raw_TranslationBasedLocalOutlierRejector()) }.into_result().map(|ptr| crate::videostab::RansacParams { ptr })
+    }
+    
     pub fn process(&mut self, frame_size: core::Size, points0: &dyn core::ToInputArray, points1: &dyn core::ToInputArray, mask: &mut dyn core::ToOutputArray) -> Result<()> {
         input_array_arg!(points0);
         input_array_arg!(points1);
@@ -1800,7 +1822,7 @@ pub trait WobbleSuppressorBase {
     }
     
     fn motions(&self) -> Result<types::VectorOfMat> {
-        unsafe { sys::cv_videostab_WobbleSuppressorBase_motions_const(self.as_raw_WobbleSuppressorBase()) }.into_result().map(|ptr| types::VectorOfMat { ptr })
+        unsafe { sys::cv_videostab_WobbleSuppressorBase_motions_const(self.as_raw_WobbleSuppressorBase()) }.into_result().map(|ptr| unsafe { types::VectorOfMat::from_raw_ptr(ptr) })
     }
     
     fn set_motions2(&mut self, val: &types::VectorOfMat) -> Result<()> {
@@ -1808,7 +1830,7 @@ pub trait WobbleSuppressorBase {
     }
     
     fn motions2(&self) -> Result<types::VectorOfMat> {
-        unsafe { sys::cv_videostab_WobbleSuppressorBase_motions2_const(self.as_raw_WobbleSuppressorBase()) }.into_result().map(|ptr| types::VectorOfMat { ptr })
+        unsafe { sys::cv_videostab_WobbleSuppressorBase_motions2_const(self.as_raw_WobbleSuppressorBase()) }.into_result().map(|ptr| unsafe { types::VectorOfMat::from_raw_ptr(ptr) })
     }
     
     fn set_stabilization_motions(&mut self, val: &types::VectorOfMat) -> Result<()> {
@@ -1816,7 +1838,7 @@ pub trait WobbleSuppressorBase {
     }
     
     fn stabilization_motions(&self) -> Result<types::VectorOfMat> {
-        unsafe { sys::cv_videostab_WobbleSuppressorBase_stabilizationMotions_const(self.as_raw_WobbleSuppressorBase()) }.into_result().map(|ptr| types::VectorOfMat { ptr })
+        unsafe { sys::cv_videostab_WobbleSuppressorBase_stabilizationMotions_const(self.as_raw_WobbleSuppressorBase()) }.into_result().map(|ptr| unsafe { types::VectorOfMat::from_raw_ptr(ptr) })
     }
     
 }
@@ -230,6 +230,10 @@ impl core::AlgorithmTrait for BriefDescriptorExtractor {
     #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
 }
 
+impl crate::features2d::Feature2DTrait for BriefDescriptorExtractor {
+    #[inline(always)] fn as_raw_Feature2D(&self) -> *mut c_void { self.ptr }
+}
+
 impl BriefDescriptorExtractor {
     ///
     /// ## C++ default parameters
@@ -422,6 +426,10 @@ impl core::AlgorithmTrait for FREAK {
     #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
 }
 
+impl crate::features2d::Feature2DTrait for FREAK {
+    #[inline(always)] fn as_raw_Feature2D(&self) -> *mut c_void { self.ptr }
+}
+
 impl FREAK {
     /// ## Parameters
     /// * orientationNormalized: Enable orientation normalization.
@@ -468,6 +476,10 @@ impl core::AlgorithmTrait for HarrisLaplaceFeatureDetector {
     #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
 }
 
+impl crate::features2d::Feature2DTrait for HarrisLaplaceFeatureDetector {
+    #[inline(always)] fn as_raw_Feature2D(&self) -> *mut c_void { self.ptr }
+}
+
 impl HarrisLaplaceFeatureDetector {
     /// Creates a new implementation instance.
     ///
@@ -531,6 +543,10 @@ impl core::AlgorithmTrait for LATCH {
     #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
 }
 
+impl crate::features2d::Feature2DTrait for LATCH {
+    #[inline(always)] fn as_raw_Feature2D(&self) -> *mut c_void { self.ptr }
+}
+
 impl LATCH {
     ///
     /// ## C++ default parameters
@@ -576,6 +592,10 @@ impl core::AlgorithmTrait for LUCID {
     #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
 }
 
+impl crate::features2d::Feature2DTrait for LUCID {
+    #[inline(always)] fn as_raw_Feature2D(&self) -> *mut c_void { self.ptr }
+}
+
 impl LUCID {
     /// ## Parameters
     /// * lucid_kernel: kernel for descriptor construction, where 1=3x3, 2=5x5, 3=7x7 and so forth
@@ -625,6 +645,10 @@ impl core::AlgorithmTrait for MSDDetector {
     #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
 }
 
+impl crate::features2d::Feature2DTrait for MSDDetector {
+    #[inline(always)] fn as_raw_Feature2D(&self) -> *mut c_void { self.ptr }
+}
+
 impl MSDDetector {
     ///
     /// ## C++ default parameters
@@ -796,7 +820,7 @@ pub trait PCTSignatures: core::AlgorithmTrait {
     /// Initial samples taken from the image.
     ///       These sampled features become the input for clustering.
     fn get_sampling_points(&self) -> Result<types::VectorOfPoint2f> {
-        unsafe { sys::cv_xfeatures2d_PCTSignatures_getSamplingPoints_const(self.as_raw_PCTSignatures()) }.into_result().map(|ptr| types::VectorOfPoint2f { ptr })
+        unsafe { sys::cv_xfeatures2d_PCTSignatures_getSamplingPoints_const(self.as_raw_PCTSignatures()) }.into_result().map(|ptr| unsafe { types::VectorOfPoint2f::from_raw_ptr(ptr) })
     }
     
     /// Weights (multiplicative constants) that linearly stretch individual axes of the feature space.
@@ -880,7 +904,7 @@ pub trait PCTSignatures: core::AlgorithmTrait {
     
     /// Initial seeds (initial number of clusters) for the k-means algorithm.
     fn get_init_seed_indexes(&self) -> Result<types::VectorOfint> {
-        unsafe { sys::cv_xfeatures2d_PCTSignatures_getInitSeedIndexes_const(self.as_raw_PCTSignatures()) }.into_result().map(|ptr| types::VectorOfint { ptr })
+        unsafe { sys::cv_xfeatures2d_PCTSignatures_getInitSeedIndexes_const(self.as_raw_PCTSignatures()) }.into_result().map(|ptr| unsafe { types::VectorOfint::from_raw_ptr(ptr) })
     }
     
     /// Initial seed indexes for the k-means algorithm.
@@ -1129,6 +1153,10 @@ impl core::AlgorithmTrait for SIFT {
     #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
 }
 
+impl crate::features2d::Feature2DTrait for SIFT {
+    #[inline(always)] fn as_raw_Feature2D(&self) -> *mut c_void { self.ptr }
+}
+
 impl SIFT {
     /// ## Parameters
     /// * nfeatures: The number of best features to retain. The features are ranked by their scores
@@ -1281,6 +1309,10 @@ impl core::AlgorithmTrait for StarDetector {
     #[inline(always)] fn as_raw_Algorithm(&self) -> *mut c_void { self.ptr }
 }
 
+impl crate::features2d::Feature2DTrait for StarDetector {
+    #[inline(always)] fn as_raw_Feature2D(&self) -> *mut c_void { self.ptr }
+}
+
 impl StarDetector {
     /// the full constructor
     ///
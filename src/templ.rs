@@ -1,11 +1,16 @@
+//! Names of the vector types before they moved to `core`, kept for compatibility
+
 use std::{
     ffi::CStr,
     os::raw::c_char,
 };
 
-pub use vector::*;
-
-mod vector;
+#[deprecated = "Use core::VectorTrait"]
+pub use crate::core::VectorTrait as Vector;
+#[deprecated = "Use core::VectorIterator"]
+pub use crate::core::VectorIterator;
+#[deprecated = "Use core::VectorRefIterator"]
+pub use crate::core::VectorRefIterator;
 
 macro_rules! string_arg {
     (mut $name: ident) => {
@@ -64,6 +69,7 @@ macro_rules! input_output_array_arg {
     };
 }
 
+#[doc(hidden)]
 #[inline]
 pub fn receive_string(s: *const c_char) -> String {
     let out = unsafe { CStr::from_ptr(s) }.to_string_lossy().into_owned();
@@ -71,6 +77,7 @@ pub fn receive_string(s: *const c_char) -> String {
     out
 }
 
+#[doc(hidden)]
 #[inline]
 pub fn receive_string_mut(s: *mut c_char) -> String {
     let out = unsafe { CStr::from_ptr(s as _) }.to_string_lossy().into_owned();
@@ -0,0 +1,45 @@
+use std::f64::consts::FRAC_PI_2;
+
+use opencv::{
+    core::{Affine3d, Affine3f, Matx33d, Vec3d},
+    prelude::*,
+    Result,
+};
+
+fn assert_vec_eq(expected: Vec3d, actual: Vec3d) {
+    for (e, a) in expected.iter().zip(actual.iter()) {
+        assert!((e - a).abs() < 1e-9, "expected: {:?}, actual: {:?}", expected, actual);
+    }
+}
+
+#[test]
+fn affine3() -> Result<()> {
+    let rot_z = Matx33d::from([
+        [0., -1., 0.],
+        [1., 0., 0.],
+        [0., 0., 1.],
+    ]);
+    let pose = Affine3d::new(rot_z, Vec3d::from([1., 2., 3.]));
+    assert_eq!(rot_z, pose.rotation());
+    assert_eq!(Vec3d::from([1., 2., 3.]), pose.translation());
+    assert_eq!(Affine3d::identity(), Affine3d::default());
+    assert_eq!(pose, Affine3d::identity() * pose);
+
+    assert_vec_eq(Vec3d::from([1., 3., 3.]), pose * Vec3d::from([1., 0., 0.]));
+    let shift = Affine3d::new(Matx33d::eye(), Vec3d::from([0., 0., 1.]));
+    assert_vec_eq(Vec3d::from([1., 3., 4.]), (shift * pose) * Vec3d::from([1., 0., 0.]));
+    assert_vec_eq(Vec3d::from([1., 2., 4.]), (pose * shift) * Vec3d::from([0., 0., 0.]));
+
+    let inv = pose.inv().unwrap();
+    assert_vec_eq(Vec3d::from([4., 5., 6.]), inv * (pose * Vec3d::from([4., 5., 6.])));
+    assert!(Affine3d::new(Matx33d::zeros(), Vec3d::all(0.)).inv().is_none());
+
+    let from_rvec = Affine3d::from_rvec_tvec(Vec3d::from([0., 0., FRAC_PI_2]), Vec3d::from([1., 2., 3.]))?;
+    assert_vec_eq(Vec3d::from([1., 3., 3.]), from_rvec * Vec3d::from([1., 0., 0.]));
+    assert_vec_eq(Vec3d::from([0., 0., FRAC_PI_2]), from_rvec.rvec()?);
+
+    let traj = Mat::from_slice(&[Affine3f::identity(), Affine3f::identity()])?;
+    assert_eq!(16, traj.channels()?);
+    assert_eq!(Affine3f::identity(), *traj.at::<Affine3f>(1)?);
+    Ok(())
+}
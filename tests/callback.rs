@@ -21,3 +21,47 @@ fn callback() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+#[cfg(not(feature = "opencv-32"))]
+fn viz_callback() -> Result<()> {
+    use opencv::viz;
+
+    // only run under X11 on linux
+    if cfg!(target_os = "linux") && option_env!("DISPLAY").is_some() {
+        let keys = Arc::new(Mutex::new(Vec::<String>::new()));
+        let mut window = viz::Viz3d::new("viz callback test")?;
+        window.register_keyboard_callback(Some(Box::new({
+            let keys = keys.clone();
+            move |event| {
+                keys.lock().unwrap().push(event.symbol().unwrap());
+            }
+        })))?;
+        window.register_mouse_callback(Some(Box::new({
+            let keys = keys.clone();
+            move |event| {
+                let _ = event.pointer();
+                keys.lock().unwrap().clear();
+            }
+        })))?;
+        window.spin_once(1, false)?;
+        assert_eq!(3, Arc::strong_count(&keys));
+
+        // the window keeps one callback, registering through a copy replaces the original one
+        let mut copy = viz::Viz3d::copy(&window)?;
+        copy.register_keyboard_callback(Some(Box::new({
+            let keys = keys.clone();
+            move |event| {
+                keys.lock().unwrap().push(format!("{}", event.code().unwrap()));
+            }
+        })))?;
+        assert_eq!(3, Arc::strong_count(&keys));
+
+        window.register_mouse_callback(None)?;
+        assert_eq!(2, Arc::strong_count(&keys));
+        drop(copy);
+        assert_eq!(1, Arc::strong_count(&keys));
+        drop(window);
+    }
+    Ok(())
+}
@@ -0,0 +1,13 @@
+use std::path::PathBuf;
+
+use opencv::{
+    core::Mat,
+    imgcodecs,
+    Result,
+};
+
+/// Loads `tests/blox.jpg` with the specified `imgcodecs::IMREAD_*` flags
+pub fn blox(flags: i32) -> Result<Mat> {
+    let blox_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/blox.jpg");
+    imgcodecs::imread(blox_path.to_str().unwrap(), flags)
+}
@@ -0,0 +1,20 @@
+#![cfg(feature = "contrib")]
+
+use opencv::{
+    datasets::{AR_hmdb, Dataset, FR_lfw, OR_mnist},
+    Result,
+};
+
+#[test]
+fn create_loaders() -> Result<()> {
+    // no dataset is available for the tests, so only check that the loaders are usable through the common trait
+    let loaders: Vec<Box<dyn Dataset>> = vec![
+        Box::new(<dyn AR_hmdb>::create()?),
+        Box::new(<dyn FR_lfw>::create()?),
+        Box::new(<dyn OR_mnist>::create()?),
+    ];
+    for loader in &loaders {
+        assert_eq!(0, loader.get_num_splits()?);
+    }
+    Ok(())
+}
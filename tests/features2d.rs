@@ -1,9 +1,12 @@
 use std::path::PathBuf;
 
+use matches::assert_matches;
+
 use opencv::{
-    core::Size,
-    features2d::{Feature2DTrait, ORB},
+    core::{self, Point2f, Scalar, Size},
+    features2d::{Feature2DTrait, ImageMatcher, MatchError, MatchModel, ORB},
     imgcodecs,
+    imgproc,
     prelude::*,
     Result,
     types::{PtrOfORB, VectorOfKeyPoint}
@@ -22,3 +25,57 @@ fn orb() -> Result<()> {
     assert_eq!(Size::new(32, size as i32), des.size()?);
     Ok(())
 }
+
+#[test]
+fn match_images() -> std::result::Result<(), MatchError> {
+    let blox_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/blox.jpg");
+    let img = imgcodecs::imread(blox_path.to_str().unwrap(), imgcodecs::IMREAD_GRAYSCALE)?;
+    let h = Mat::from_slice_2d(&[
+        [0.9, 0.05, 10.],
+        [-0.05, 0.95, 5.],
+        [0.0001, 0., 1.],
+    ])?;
+    let mut warped = Mat::default()?;
+    imgproc::warp_perspective(&img, &mut warped, &h, img.size()?, imgproc::INTER_LINEAR, core::BORDER_CONSTANT, Scalar::default())?;
+
+    let mut matcher = ImageMatcher::orb(1000)?;
+    let matches = matcher.match_images(&img, &warped)?;
+    assert_eq!(matches.points1.len(), matches.points2.len());
+    assert_eq!(matches.points1.len(), matches.inliers.len());
+    assert!(matches.inlier_count() >= 10);
+    assert_eq!(Size::new(3, 3), matches.model.size()?);
+    let scale = *matches.model.at_2d::<f64>(2, 2)?;
+    for row in 0..2 {
+        for col in 0..3 {
+            let expected = *h.at_2d::<f64>(row, col)?;
+            let actual = *matches.model.at_2d::<f64>(row, col)? / scale;
+            assert!((expected - actual).abs() < if col == 2 { 1. } else { 0.02 }, "H[{}][{}]: expected {}, got {}", row, col, expected, actual);
+        }
+    }
+    for (p1, p2) in matches.inlier_pairs() {
+        let w = 0.0001 * p1.x as f64 + 1.;
+        let projected = Point2f::new(
+            ((0.9 * p1.x as f64 + 0.05 * p1.y as f64 + 10.) / w) as f32,
+            ((-0.05 * p1.x as f64 + 0.95 * p1.y as f64 + 5.) / w) as f32,
+        );
+        assert!((projected - p2).norm() < 3.5);
+    }
+
+    let mut matcher = matcher.with_model(MatchModel::Fundamental { ransac_reproj_threshold: 3., confidence: 0.99 });
+    let matches = matcher.match_images(&img, &warped)?;
+    assert_eq!(Size::new(3, 3), matches.model.size()?);
+    Ok(())
+}
+
+#[test]
+fn match_images_degenerate() -> Result<()> {
+    let blank = Mat::new_rows_cols_with_default(100, 100, u8::typ(), Scalar::all(127.))?;
+    let mut matcher = ImageMatcher::orb(500)?;
+    assert_matches!(matcher.match_images(&blank, &blank), Err(MatchError::TooFewKeypoints { image: 1, found: 0, required: 4 }));
+    assert_matches!(matcher.match_images(&blank, &Mat::default()?), Err(MatchError::EmptyImage { image: 2 }));
+    let mut matcher = matcher.with_model(MatchModel::Fundamental { ransac_reproj_threshold: 3., confidence: 0.99 });
+    assert_matches!(matcher.match_images(&blank, &blank), Err(MatchError::TooFewKeypoints { required: 8, .. }));
+    let mut matcher = matcher.with_ratio(1.5);
+    assert_matches!(matcher.match_images(&blank, &blank), Err(MatchError::InvalidRatio(_)));
+    Ok(())
+}
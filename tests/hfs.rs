@@ -0,0 +1,31 @@
+#![cfg(all(feature = "contrib", not(feature = "opencv-32")))]
+
+use opencv::{
+    core::{self, Point},
+    hfs::HfsSegment,
+    imgcodecs,
+    Result,
+};
+
+mod common;
+
+#[test]
+fn segment_cpu() -> Result<()> {
+    let img = common::blox(imgcodecs::IMREAD_COLOR)?;
+    let mut hfs = <dyn HfsSegment>::create(img.rows()?, img.cols()?, 0.08, 100, 0.28, 200, 0.6, 8, 5)?;
+    hfs.set_min_region_size_ii(150)?;
+    assert_eq!(150, hfs.get_min_region_size_ii()?);
+
+    let labels = hfs.perform_segment_cpu(&img, false)?;
+    assert_eq!(img.size()?, labels.size()?);
+    assert_eq!(core::CV_16U, labels.typ()?);
+    let mut min = 0.;
+    let mut max = 0.;
+    core::min_max_loc(&labels, &mut min, &mut max, &mut Point::default(), &mut Point::default(), &core::no_array()?)?;
+    assert!(max > min, "the image must be split into several regions");
+
+    let drawn = hfs.perform_segment_cpu(&img, true)?;
+    assert_eq!(img.size()?, drawn.size()?);
+    assert_eq!(img.typ()?, drawn.typ()?);
+    Ok(())
+}
@@ -30,3 +30,162 @@ fn decode() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn pages() -> Result<()> {
+    {
+        let mut pages = imgcodecs::imdecode_pages(PIXEL, imgcodecs::IMREAD_COLOR)?;
+        assert_eq!(1, pages.len());
+        let page = pages.next().unwrap()?;
+        assert_eq!(*page.at_2d::<Vec3b>(0, 0)?, Vec3b::from([56u8, 56, 191]));
+        assert!(pages.next().is_none());
+    }
+
+    #[cfg(not(feature = "opencv-32"))]
+    {
+        use std::{env, fs};
+        use opencv::{core::Scalar, prelude::*, types::{VectorOfint, VectorOfMat}};
+
+        let sizes = [Size::new(8, 4), Size::new(16, 8), Size::new(4, 2)];
+        let mut mats = VectorOfMat::new();
+        for (i, size) in sizes.iter().enumerate() {
+            mats.push(Mat::new_size_with_default(*size, core::CV_8UC3, Scalar::all(i as f64 * 50.))?);
+        }
+        let path = env::temp_dir().join("opencv-rust-pages.tiff");
+        let path = path.to_str().unwrap();
+        assert!(imgcodecs::imwrite_multi(path, &mats, &VectorOfint::new())?);
+
+        let mut read = VectorOfMat::new();
+        assert!(imgcodecs::imreadmulti(path, &mut read, imgcodecs::IMREAD_ANYCOLOR)?);
+        assert_eq!(sizes.len(), read.len());
+
+        let buf = fs::read(path).unwrap();
+        let mut decoded = VectorOfMat::new();
+        assert!(imgcodecs::imdecode_multi(&buf, &mut decoded, imgcodecs::IMREAD_ANYCOLOR)?);
+        assert_eq!(sizes.len(), decoded.len());
+        for (mat, size) in decoded.iter().zip(sizes.iter()) {
+            assert_eq!(*size, mat.size()?);
+        }
+
+        let mut pages = imgcodecs::imread_pages(path, imgcodecs::IMREAD_ANYCOLOR)?;
+        assert_eq!(sizes.len(), pages.len());
+        let last = pages.next_back().unwrap()?;
+        assert_eq!(sizes[2], last.size()?);
+        assert_eq!(Vec3b::all(100), *last.at_2d::<Vec3b>(0, 0)?);
+        let first = pages.next().unwrap()?;
+        assert_eq!(sizes[0], first.size()?);
+        assert_eq!(1, pages.len());
+
+        let mut decoded = VectorOfMat::new();
+        assert!(imgcodecs::imdecode_multi(&buf[..buf.len() / 2], &mut decoded, imgcodecs::IMREAD_ANYCOLOR).is_err());
+    }
+    Ok(())
+}
+
+#[test]
+fn encode_params() -> Result<()> {
+    use opencv::{core::Scalar, imgcodecs::{JpegParams, PamParams, PamTupleType, PngParams, PngStrategy}};
+
+    let color = Mat::new_rows_cols_with_default(16, 16, core::CV_8UC3, Scalar::new(10., 100., 200., 0.))?;
+    let gray = Mat::new_rows_cols_with_default(16, 16, core::CV_8UC1, Scalar::all(255.))?;
+
+    let jpeg = imgcodecs::imencode_with(".jpg", &color, &JpegParams::new().quality(90).progressive(true).optimize(true))?;
+    assert_eq!(&[0xFF, 0xD8], &jpeg[..2]);
+    let decoded = imgcodecs::imdecode(&Mat::from_slice(&jpeg)?, imgcodecs::IMREAD_COLOR)?;
+    assert_eq!(Size::new(16, 16), decoded.size()?);
+
+    let png = imgcodecs::imencode_with(".PNG", &gray, &PngParams::new().compression(9).strategy(PngStrategy::Filtered).bilevel(true))?;
+    assert_eq!(b"\x89PNG", &png[..4]);
+    let decoded = imgcodecs::imdecode(&Mat::from_slice(&png)?, imgcodecs::IMREAD_GRAYSCALE)?;
+    assert_eq!(255, *decoded.at_2d::<u8>(8, 8)?);
+
+    // format mismatch
+    assert!(imgcodecs::imencode_with(".jpg", &color, &PngParams::new()).is_err());
+    // out of range
+    assert!(imgcodecs::imencode_with(".jpg", &color, &JpegParams::new().quality(101)).is_err());
+    assert!(imgcodecs::imencode_with(".png", &color, &PngParams::new().compression(10)).is_err());
+    // unsupported by the image
+    assert!(imgcodecs::imencode_with(".png", &color, &PngParams::new().bilevel(true)).is_err());
+    assert!(imgcodecs::imencode_with(".pam", &color, &PamParams::new().tuple_type(PamTupleType::Grayscale)).is_err());
+    let pam = imgcodecs::imencode_with(".pam", &color, &PamParams::new().tuple_type(PamTupleType::Rgb))?;
+    assert_eq!(b"P7", &pam[..2]);
+
+    #[cfg(not(feature = "opencv-32"))]
+    {
+        use opencv::imgcodecs::{EncodeParams, ExrParams, ExrType};
+
+        // OpenEXR support is optional in OpenCV builds, so only the option checks are tested
+        assert!(ExrParams::new().validate(core::CV_8U, 3).is_ok());
+        assert!(ExrParams::new().typ(ExrType::Half).validate(core::CV_8U, 3).is_err());
+        assert!(ExrParams::new().typ(ExrType::Half).validate(core::CV_32F, 3).is_ok());
+    }
+    Ok(())
+}
+
+#[test]
+fn info() -> Result<()> {
+    use opencv::{core::Scalar, imgcodecs::{ExifOrientation, ImageFormat, JpegParams}, prelude::*, types::{VectorOfint, VectorOfuchar}};
+
+    let info = imgcodecs::imdecode_info(PIXEL)?;
+    assert_eq!(ImageFormat::Png, info.format);
+    assert_eq!((1, 1, 1), (info.width, info.height, info.page_count));
+    assert_eq!(None, info.orientation);
+
+    let color = Mat::new_rows_cols_with_default(12, 20, core::CV_8UC3, Scalar::all(128.))?;
+    let gray = Mat::new_rows_cols_with_default(12, 20, core::CV_16UC1, Scalar::all(1000.))?;
+    // stored as 8-bit with a gray palette
+    let gray_8u = Mat::new_rows_cols_with_default(12, 20, core::CV_8UC1, Scalar::all(100.))?;
+    for &(ext, mat, format, channels, bit_depth) in &[
+        (".jpg", &color, ImageFormat::Jpeg, 3, 8),
+        (".png", &color, ImageFormat::Png, 3, 8),
+        (".png", &gray, ImageFormat::Png, 1, 16),
+        (".bmp", &color, ImageFormat::Bmp, 3, 8),
+        (".bmp", &gray_8u, ImageFormat::Bmp, 1, 8),
+        (".ppm", &color, ImageFormat::Pxm, 3, 8),
+        (".pgm", &gray, ImageFormat::Pxm, 1, 16),
+        (".pam", &color, ImageFormat::Pam, 3, 8),
+    ] {
+        let mut buf = VectorOfuchar::new();
+        assert!(imgcodecs::imencode(ext, mat, &mut buf, &VectorOfint::new())?);
+        let info = imgcodecs::imdecode_info(buf.as_slice())?;
+        assert_eq!(format, info.format, "{}", ext);
+        assert_eq!((20, 12), (info.width, info.height), "{}", ext);
+        assert_eq!((channels, bit_depth), (info.channels, info.bit_depth), "{}", ext);
+    }
+
+    // insert an EXIF segment with orientation right after the start of image marker
+    let jpeg = imgcodecs::imencode_with(".jpg", &color, &JpegParams::new())?;
+    let exif: &[u8] = b"\xFF\xE1\x00\x22Exif\0\0II*\0\x08\0\0\0\x01\0\x12\x01\x03\0\x01\0\0\0\x06\0\0\0\0\0\0\0";
+    let rotated = [&jpeg[..2], exif, &jpeg[2..]].concat();
+    let info = imgcodecs::imdecode_info(&rotated)?;
+    assert_eq!(Some(ExifOrientation::RightTop), info.orientation);
+    assert_eq!((20, 12), (info.width, info.height));
+    let decoded = imgcodecs::imdecode(&Mat::from_slice(&rotated)?, imgcodecs::IMREAD_COLOR)?;
+    assert_eq!(Size::new(12, 20), decoded.size()?);
+    let decoded = imgcodecs::imdecode(&Mat::from_slice(&rotated)?, imgcodecs::IMREAD_COLOR | imgcodecs::IMREAD_IGNORE_ORIENTATION)?;
+    assert_eq!(Size::new(20, 12), decoded.size()?);
+
+    #[cfg(not(feature = "opencv-32"))]
+    {
+        use std::env;
+        use opencv::types::VectorOfMat;
+
+        let mut mats = VectorOfMat::new();
+        mats.push(color.clone()?);
+        mats.push(color.clone()?);
+        let path = env::temp_dir().join("opencv-rust-info.tiff");
+        let path = path.to_str().unwrap();
+        assert!(imgcodecs::imwrite_multi(path, &mats, &VectorOfint::new())?);
+        let info = imgcodecs::imread_info(path)?;
+        assert_eq!(ImageFormat::Tiff, info.format);
+        assert_eq!((20, 12, 3, 8, 2), (info.width, info.height, info.channels, info.bit_depth, info.page_count));
+    }
+
+    assert!(imgcodecs::imdecode_info(b"not an image").is_err());
+    assert!(imgcodecs::imdecode_info(&PIXEL[..20]).is_err());
+    // PNG width that doesn't fit into i32
+    let mut huge = PIXEL.to_vec();
+    huge[16..20].copy_from_slice(&0x8000_0000u32.to_be_bytes());
+    assert_eq!(core::StsOutOfRange, imgcodecs::imdecode_info(&huge).unwrap_err().code);
+    Ok(())
+}
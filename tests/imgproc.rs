@@ -1,10 +1,14 @@
 use opencv::{
     prelude::*,
-    core::{Point, Point2f, Scalar, Size, Vec2f},
+    core::{self, Point, Point2f, Scalar, Size, Vec2f},
     imgproc,
     Result,
-    types::VectorOfPoint,
+    types::{VectorOfPoint, VectorOfVectorOfPoint},
 };
+#[cfg(not(feature = "opencv-4"))]
+use opencv::core::{FILLED, LINE_8};
+#[cfg(feature = "opencv-4")]
+use opencv::imgproc::{FILLED, LINE_8};
 
 #[test]
 fn min_enclosing() -> Result<()> {
@@ -39,3 +43,43 @@ fn get_rotation_matrix_2d() -> Result<()> {
     assert_eq!(-*mat.at_2d::<f64>(0, 1)?, *mat.at_2d::<f64>(1, 0)?);
     Ok(())
 }
+
+#[test]
+fn draw_and_find_contours() -> Result<()> {
+    let contours = vec![
+        vec![Point::new(10, 10), Point::new(10, 30), Point::new(40, 30), Point::new(40, 10)],
+        vec![Point::new(60, 60), Point::new(60, 90), Point::new(90, 90), Point::new(90, 60)],
+    ];
+    let mut img = Mat::new_rows_cols_with_default(100, 100, u8::typ(), Scalar::all(0.))?;
+    imgproc::draw_contours(&mut img, &contours, -1, Scalar::all(255.), FILLED, LINE_8, &core::no_array()?, i32::max_value(), Point::default())?;
+    assert_eq!(255, *img.at_2d::<u8>(20, 20)?);
+    assert_eq!(255, *img.at_2d::<u8>(75, 75)?);
+    assert_eq!(0, *img.at_2d::<u8>(50, 50)?);
+
+    let mut first_only = Mat::new_rows_cols_with_default(100, 100, u8::typ(), Scalar::all(0.))?;
+    imgproc::draw_contours(&mut first_only, &&contours[..1], -1, Scalar::all(255.), FILLED, LINE_8, &core::no_array()?, i32::max_value(), Point::default())?;
+    assert_eq!(255, *first_only.at_2d::<u8>(20, 20)?);
+    assert_eq!(0, *first_only.at_2d::<u8>(75, 75)?);
+
+    let mut found = VectorOfVectorOfPoint::new();
+    // OpenCV 3.2 modifies the source image
+    let mut src = img.clone()?;
+    imgproc::find_contours(&mut src, &mut found, imgproc::RETR_EXTERNAL, imgproc::CHAIN_APPROX_SIMPLE, Point::default())?;
+    assert_eq!(2, found.len());
+    for contour in found.iter_ref() {
+        assert_eq!(4, contour.len());
+        let s = contour.as_slice();
+        assert!(s.contains(&Point::new(10, 10)) || s.contains(&Point::new(60, 60)));
+    }
+    let first = found.get_ref(0)?;
+    assert_eq!(first.as_slice(), found.get(0)?.as_slice());
+    assert!(found.get_ref(2).is_err());
+
+    let mut found = found.to_vec_of_vec();
+    found.iter_mut().for_each(|c| c.sort_by_key(|p| (p.x, p.y)));
+    found.sort_by_key(|c| c[0].x);
+    assert_eq!(vec![Point::new(10, 10), Point::new(10, 30), Point::new(40, 10), Point::new(40, 30)], found[0]);
+    let back = VectorOfVectorOfPoint::from(found.clone());
+    assert_eq!(found, Vec::from(back));
+    Ok(())
+}
@@ -137,8 +137,8 @@ fn mat_at_1d() -> Result<()> {
     ];
 
     {
-        let mat = Mat::from_slice_2d(&s)?;
-        let mut mat = mat.reshape(1, 1)?;
+        let mut mat = Mat::from_slice_2d(&s)?;
+        let mut mat = mat.reshape_mut(1, 1)?;
         assert_eq!(1, mat.rows()?);
         assert_eq!(9, mat.cols()?);
         assert_matches!(mat.at::<f32>(-1), Err(Error { code: core::StsOutOfRange, ..}));
@@ -153,8 +153,8 @@ fn mat_at_1d() -> Result<()> {
     }
 
     {
-        let mat = Mat::from_slice_2d(&s)?;
-        let mut mat = mat.reshape(1, 9)?;
+        let mut mat = Mat::from_slice_2d(&s)?;
+        let mut mat = mat.reshape_mut(1, 9)?;
         assert_eq!(9, mat.rows()?);
         assert_eq!(1, mat.cols()?);
         assert_matches!(mat.at::<f32>(-1), Err(Error { code: core::StsOutOfRange, ..}));
@@ -468,3 +468,101 @@ fn mat_locate_roi() -> Result<()> {
     assert_eq!(ofs, Point::new(1, 0));
     Ok(())
 }
+
+#[test]
+fn mat_views() -> Result<()> {
+    let mut mat = Mat::new_rows_cols_with_default(4, 6, i32::typ(), Scalar::all(0.))?;
+    {
+        let mut roi = Mat::roi_mut(&mut mat, Rect::new(1, 1, 2, 2))?;
+        roi.set(Scalar::all(5.))?;
+        *roi.at_2d_mut::<i32>(1, 1)? = 7;
+    }
+    assert_eq!(5, *mat.at_2d::<i32>(1, 1)?);
+    assert_eq!(7, *mat.at_2d::<i32>(2, 2)?);
+    assert_eq!(0, *mat.at_2d::<i32>(3, 3)?);
+    {
+        let row = mat.row(2)?;
+        assert_eq!(&[0, 5, 7, 0, 0, 0], row.at_row::<i32>(0)?);
+        let cols = mat.col_bounds(1, 3)?;
+        assert_eq!(Size::new(2, 4), cols.size()?);
+        let diag = mat.diag(0)?;
+        assert_eq!(7, *diag.at::<i32>(2)?);
+        let part = Mat::rowscols(&mat, &core::Range::new(1, 3)?, &core::Range::all()?)?;
+        assert_eq!(Size::new(6, 2), part.size()?);
+        assert_eq!(22., core::sum(&part)?[0]);
+    }
+
+    {
+        let (mut top, mut bottom) = mat.split_rows_mut(2)?;
+        assert_eq!(2, top.rows()?);
+        assert_eq!(2, bottom.rows()?);
+        top.set(Scalar::all(1.))?;
+        bottom.set(Scalar::all(2.))?;
+        top.row_mut(0)?.set(Scalar::all(3.))?;
+    }
+    assert_eq!(&[3; 6], mat.at_row::<i32>(0)?);
+    assert_eq!(&[1; 6], mat.at_row::<i32>(1)?);
+    assert_eq!(&[2; 6], mat.at_row::<i32>(3)?);
+
+    {
+        let (mut left, mut right) = mat.split_cols_mut(4)?;
+        assert_eq!(Size::new(4, 4), left.size()?);
+        assert_eq!(Size::new(2, 4), right.size()?);
+        // the rows of a non-continuous view are interleaved with the data of the other view
+        assert_matches!(left.data_typed::<i32>(), Err(Error { code: core::StsBadArg, .. }));
+        assert_matches!(unsafe { left.data_typed_unchecked::<i32>() }, Err(Error { code: core::StsBadArg, .. }));
+        assert_matches!(right.data_typed_mut::<i32>(), Err(Error { code: core::StsBadArg, .. }));
+        assert_eq!(&[1; 2], right.at_row_mut::<i32>(1)?);
+        let (right_0, mut right_1) = right.split_cols_mut(1)?;
+        core::add_weighted(&left.col(0)?, 1., &right_0, 1., 0., &mut right_1, -1)?;
+        left.col_mut(0)?.set(Scalar::all(0.))?;
+    }
+    assert_eq!(&[0, 3, 3, 3, 3, 6], mat.at_row::<i32>(0)?);
+    assert_eq!(&[0, 2, 2, 2, 2, 4], mat.at_row::<i32>(3)?);
+
+    {
+        let mut rois = mat.rois_mut(&[Rect::new(0, 0, 3, 2), Rect::new(3, 0, 3, 2), Rect::new(0, 2, 6, 2)])?;
+        assert_eq!(3, rois.len());
+        for (i, roi) in rois.iter_mut().enumerate() {
+            roi.set(Scalar::all(i as f64))?;
+        }
+    }
+    assert_eq!(&[0, 0, 0, 1, 1, 1], mat.at_row::<i32>(1)?);
+    assert_eq!(&[2; 6], mat.at_row::<i32>(2)?);
+    assert_matches!(mat.rois_mut(&[Rect::new(0, 0, 3, 2), Rect::new(2, 1, 3, 2)]), Err(Error { code: core::StsBadArg, .. }));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn mat_par_rows() -> Result<()> {
+    use rayon::prelude::*;
+
+    let mut mat = Mat::new_rows_cols_with_default(64, 48, f64::typ(), Scalar::all(0.))?;
+    mat.par_rows_mut::<f64>()?
+        .enumerate()
+        .for_each(|(i, row)| row.iter_mut().enumerate().for_each(|(j, x)| *x = (i * j) as f64));
+    let expected = (0..64).map(|i| (0..48).map(|j| (i * j) as f64).sum::<f64>()).sum::<f64>();
+    // cv::sum() is bound as core::sum() for every OpenCV version, sumElems is only its name in the Python and Java bindings
+    assert_eq!(expected, core::sum(&mat)?[0]);
+
+    {
+        let mut roi = Mat::roi_mut(&mut mat, Rect::new(8, 4, 16, 32))?;
+        assert!(!roi.is_continuous()?);
+        roi.par_chunks_mut::<f64>(5)?
+            .for_each(|rows| {
+                assert!(rows.len() <= 5);
+                rows.into_iter().for_each(|row| row.iter_mut().for_each(|x| *x = -1.));
+            });
+        assert_eq!(-16. * 32., core::sum(&*roi)?[0]);
+    }
+    assert_eq!(0., *mat.at_2d::<f64>(3, 8)?);
+    assert_eq!(-1., *mat.at_2d::<f64>(4, 8)?);
+    assert_eq!(-1., *mat.at_2d::<f64>(35, 23)?);
+    assert_eq!(6. * 24., *mat.at_2d::<f64>(6, 24)?);
+
+    assert_eq!(64, mat.par_chunks_mut::<f64>(1)?.count());
+    assert_matches!(mat.par_chunks_mut::<f64>(0).err(), Some(Error { code: core::StsBadArg, .. }));
+    assert_matches!(mat.par_rows_mut::<i32>().err(), Some(Error { code: core::StsUnmatchedFormats, .. }));
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use opencv::{
+    core::{self, Matx, Matx22f, Matx33d, Matx44d, Vec3d},
+    prelude::*,
+    Result,
+};
+
+#[test]
+fn matx_arithmetic() -> Result<()> {
+    let a = Matx33d::from([
+        [3., 0., 1.],
+        [1., 3., 2.],
+        [1., 1., 1.],
+    ]);
+    assert_eq!(Matx33d::eye(), a * Matx33d::eye());
+    assert_eq!(Matx33d::all(2.), Matx33d::ones() + Matx33d::ones());
+    assert_eq!(Matx33d::zeros(), a - a);
+    assert_eq!(a * 2., a + a);
+    assert_eq!(-a, a * -1.);
+    assert_eq!(7., a.trace());
+    assert!((a.determinant() - 1.).abs() < 1e-12);
+    assert_eq!(a.0[0][2], a.transpose().0[2][0]);
+    assert_eq!(9., a.mul_elem(&a)[1][1]);
+    assert_eq!(a.mul_elem(&a).0.iter().flatten().sum::<f64>(), a.dot(&a));
+
+    let ab = Matx::<f64, 2, 3>::from([[1., 2., 3.], [4., 5., 6.]]) * Matx::<f64, 3, 2>::from([[1., 0.], [0., 1.], [1., 1.]]);
+    assert_eq!(Matx::<f64, 2, 2>::from([[4., 5.], [10., 11.]]), ab);
+
+    let inv = a.inv().unwrap();
+    let id = a * inv;
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((id[i][j] - if i == j { 1. } else { 0. }).abs() < 1e-12);
+        }
+    }
+    assert!(Matx22f::from([[1., 2.], [2., 4.]]).inv().is_none());
+    assert_eq!(0., Matx22f::from([[1., 2.], [2., 4.]]).determinant());
+    // singular up to the rounding error
+    assert!(Matx22f::from([[1., 2.], [2., 4.000_000_5]]).inv().is_none());
+    assert_eq!(0., Matx22f::from([[1., 2.], [2., 4.000_000_5]]).determinant());
+    // tolerance is relative to the magnitude of the elements
+    let tiny = 2f32.powi(-100);
+    assert_eq!(Matx22f::diag([1. / tiny; 2]), Matx22f::diag([tiny; 2]).inv().unwrap());
+
+    let k = Matx33d::from([
+        [500., 0., 320.],
+        [0., 500., 240.],
+        [0., 0., 1.],
+    ]);
+    assert_eq!(Vec3d::from([570., 490., 1.]), k * Vec3d::from([0.5, 0.5, 1.]));
+    Ok(())
+}
+
+#[test]
+fn matx_opencv() -> Result<()> {
+    let a = Matx33d::from([
+        [4., 7., 2.],
+        [3., 6., 1.],
+        [2., 5., 3.],
+    ]);
+    assert!((a.determinant() - core::determinant(&a)?).abs() < 1e-9);
+    let mut inv = Mat::default()?;
+    core::invert(&a, &mut inv, core::DECOMP_LU)?;
+    let expected = a.inv().unwrap();
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((expected[i][j] - *inv.at_2d::<f64>(i as i32, j as i32)?).abs() < 1e-12);
+        }
+    }
+
+    let poses = Mat::from_slice(&[Matx44d::eye(), Matx44d::eye() * 2.])?;
+    assert_eq!(16, poses.channels()?);
+    assert_eq!(Matx44d::eye() * 2., *poses.at::<Matx44d>(1)?);
+    Ok(())
+}
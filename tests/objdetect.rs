@@ -1,11 +1,19 @@
-use std::path::PathBuf;
+use std::{
+    path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
 
 use opencv::{
+    core::{self, Point, Rect, Scalar, Size},
+    Error,
     imgcodecs,
-    objdetect,
+    objdetect::{self, DetectionBasedTracker, DetectionBasedTracker_IDetector, DetectionBasedTracker_Parameters},
     prelude::*,
     Result,
-    types::VectorOfPoint,
+    types::{PtrOfIDetector, VectorOfPoint, VectorOfRect},
 };
 
 #[test]
@@ -54,3 +62,61 @@ fn qr_code() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn custom_detector() -> Result<()> {
+    let mut detector = PtrOfIDetector::from_detector(|image: &Mat, objects: &mut VectorOfRect| {
+        objects.push(Rect::new(1, 2, image.cols()? / 2, image.rows()? / 2));
+        Ok(())
+    })?;
+    let image = Mat::new_rows_cols_with_default(64, 32, core::CV_8UC1, Scalar::all(0.))?;
+    let mut objects = VectorOfRect::new();
+    detector.detect(&image, &mut objects)?;
+    assert_eq!(1, objects.len());
+    assert_eq!(Rect::new(1, 2, 16, 32), objects.get(0)?);
+
+    let mut failing = PtrOfIDetector::from_detector(|_: &Mat, _: &mut VectorOfRect| Err(Error::new(core::StsBadArg, "no objects".to_string())))?;
+    let err = failing.detect(&image, &mut objects).unwrap_err();
+    assert_eq!(core::StsBadArg, err.code);
+    assert!(err.message.contains("no objects"));
+
+    let mut panicking = PtrOfIDetector::from_detector(|_: &Mat, _: &mut VectorOfRect| -> Result<()> { panic!("detector panic") })?;
+    let err = panicking.detect(&image, &mut objects).unwrap_err();
+    assert_eq!(core::StsError, err.code);
+    assert!(err.message.contains("detector panic"));
+    Ok(())
+}
+
+#[test]
+fn detection_based_tracker() -> Result<()> {
+    // location of the object in the full image, the tracking detector is called with sub-images around it
+    let object = Rect::new(16, 16, 16, 16);
+    let calls = Arc::new(AtomicUsize::new(0));
+    let detector = || {
+        let calls = Arc::clone(&calls);
+        PtrOfIDetector::from_detector(move |image: &Mat, objects: &mut VectorOfRect| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            let mut whole_size = Size::default();
+            let mut ofs = Point::default();
+            image.locate_roi(&mut whole_size, &mut ofs)?;
+            objects.push(Rect::new(object.x - ofs.x, object.y - ofs.y, object.width, object.height));
+            Ok(())
+        })
+    };
+    let mut tracker = DetectionBasedTracker::new(&detector()?, &detector()?, &DetectionBasedTracker_Parameters::default()?)?;
+    tracker.add_object(object)?;
+    let image = Mat::new_rows_cols_with_default(64, 64, core::CV_8UC1, Scalar::all(0.))?;
+    let mut objects = VectorOfRect::new();
+    // the tracked object is only reported after it was detected in several consecutive frames
+    for _ in 0..20 {
+        tracker.process(&image)?;
+        tracker.get_objects(&mut objects)?;
+        if !objects.is_empty() {
+            break;
+        }
+    }
+    tracker.stop()?;
+    assert!(calls.load(Ordering::SeqCst) > 0);
+    assert!(objects.as_slice().contains(&object), "{:?}", objects.to_vec());
+    Ok(())
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "contrib")]
+
+use opencv::{
+    core::{self, Mat, Rect, Scalar},
+    imgcodecs,
+    imgproc,
+    optflow,
+    prelude::*,
+    Result,
+    types::{PtrOfDenseOpticalFlow, VectorOfRect},
+    video::DenseOpticalFlow,
+};
+
+mod common;
+
+const SHIFT_X: f64 = 3.;
+const SHIFT_Y: f64 = 2.;
+
+/// Returns the test image and its copy translated by (SHIFT_X, SHIFT_Y)
+fn translated_pair() -> Result<(Mat, Mat)> {
+    let img = common::blox(imgcodecs::IMREAD_GRAYSCALE)?;
+    let m = Mat::from_slice_2d(&[
+        [1., 0., SHIFT_X],
+        [0., 1., SHIFT_Y],
+    ])?;
+    let mut shifted = Mat::default()?;
+    imgproc::warp_affine(&img, &mut shifted, &m, img.size()?, imgproc::INTER_LINEAR, core::BORDER_REPLICATE, Scalar::default())?;
+    Ok((img, shifted))
+}
+
+/// Mean flow away from the image borders
+fn assert_mean_flow(flow: &Mat) -> Result<()> {
+    assert_eq!(core::CV_32FC2, flow.typ()?);
+    let size = flow.size()?;
+    let inner = Mat::roi(flow, Rect::new(size.width / 4, size.height / 4, size.width / 2, size.height / 2))?;
+    let mean = core::mean(&inner, &core::no_array()?)?;
+    assert!((mean[0] - SHIFT_X).abs() < 0.5, "flow x: {}", mean[0]);
+    assert!((mean[1] - SHIFT_Y).abs() < 0.5, "flow y: {}", mean[1]);
+    Ok(())
+}
+
+#[test]
+fn dense_optical_flow() -> Result<()> {
+    let (from, to) = translated_pair()?;
+
+    let mut algos: Vec<PtrOfDenseOpticalFlow> = vec![
+        optflow::create_opt_flow_sparse_to_dense()?,
+        optflow::create_opt_flow_pca_flow()?,
+    ];
+    for algo in &mut algos {
+        let mut flow = Mat::default()?;
+        algo.calc(&from, &to, &mut flow)?;
+        assert_mean_flow(&flow)?;
+        algo.collect_garbage()?;
+    }
+
+    let mut flow = Mat::default()?;
+    optflow::calc_optical_flow_sparse_to_dense(&from, &to, &mut flow, 8, 128, 0.05, true, 500., 1.5)?;
+    assert_mean_flow(&flow)
+}
+
+#[test]
+fn motion_templates() -> Result<()> {
+    let mut silhouette = Mat::new_rows_cols_with_default(64, 64, core::CV_8UC1, Scalar::all(0.))?;
+    let mut mhi = Mat::new_rows_cols_with_default(64, 64, core::CV_32FC1, Scalar::all(0.))?;
+    for timestamp in 1..=5 {
+        silhouette.set_to(&Scalar::all(0.), &core::no_array()?)?;
+        let x = 10 + timestamp * 4;
+        Mat::roi_mut(&mut silhouette, Rect::new(x, 20, 8, 24))?.set_to(&Scalar::all(255.), &core::no_array()?)?;
+        optflow::update_motion_history(&silhouette, &mut mhi, timestamp as f64, 10.)?;
+    }
+    assert_eq!(5., *mhi.at_2d::<f32>(30, 34)?);
+    assert_eq!(1., *mhi.at_2d::<f32>(30, 14)?);
+    assert_eq!(0., *mhi.at_2d::<f32>(5, 5)?);
+
+    let mut mask = Mat::default()?;
+    let mut orientation = Mat::default()?;
+    optflow::calc_motion_gradient(&mhi, &mut mask, &mut orientation, 0.5, 2., 3)?;
+    assert_eq!(mhi.size()?, orientation.size()?);
+
+    let mut segmask = Mat::default()?;
+    let mut rects = VectorOfRect::new();
+    optflow::segment_motion(&mhi, &mut segmask, &mut rects, 5., 1.5)?;
+    assert!(!rects.is_empty());
+    Ok(())
+}
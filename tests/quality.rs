@@ -0,0 +1,110 @@
+#![cfg(all(feature = "contrib", feature = "opencv-4"))]
+
+use opencv::{
+    core::{self, Mat},
+    imgcodecs,
+    quality::{QualityBRISQUE, QualityGMSD, QualityMSE, QualityPSNR, QualitySSIM},
+    Result,
+};
+
+mod common;
+
+// Reference values for the noise added by `with_noise()`, computed independently of OpenCV from the grayscale
+// `tests/blox.jpg` decoded by libjpeg following the algorithms of the quality module
+const SSIM: f64 = 0.751283;
+const GMSD: f64 = 0.026832;
+/// Scale 1 followed by scale 2: AGGD shape and variance of the MSCN coefficients, then shape, mean, left and right
+/// variance of each of the horizontal, vertical and two diagonal pairwise products
+const BRISQUE_FEATURES: [f64; 36] = [
+    3.620920, 0.564856,
+    1.228001, -0.182400, 0.272958, 0.076232,
+    1.080994, -0.207529, 0.357204, 0.095855,
+    1.060993, 0.068322, 0.382312, 0.509169,
+    0.951991, -0.200391, 0.456840, 0.151244,
+    3.116956, 0.401383,
+    0.950991, -0.186199, 0.319626, 0.089166,
+    0.932991, 0.000722, 0.136312, 0.137080,
+    0.740994, 0.020452, 0.177070, 0.204658,
+    0.975991, -0.050499, 0.161119, 0.108596,
+];
+
+/// Grayscale `tests/blox.jpg` as `CV_32F` so that the added noise doesn't saturate
+fn blox_f32() -> Result<Mat> {
+    let mut out = Mat::default()?;
+    common::blox(imgcodecs::IMREAD_GRAYSCALE)?.convert_to(&mut out, core::CV_32F, 1., 0.)?;
+    Ok(out)
+}
+
+/// Adds deterministic noise in `[-10; 10]` to the image, returns the noisy image and its mean squared error
+fn with_noise(img: &Mat) -> Result<(Mat, f64)> {
+    let mut out = img.clone()?;
+    let mut sum_sq = 0.;
+    for row in 0..out.rows()? {
+        for col in 0..out.cols()? {
+            let noise = ((row * 7 + col * 13) % 21 - 10) as f32;
+            *out.at_2d_mut::<f32>(row, col)? += noise;
+            sum_sq += f64::from(noise * noise);
+        }
+    }
+    let mse = sum_sq / f64::from(out.rows()? * out.cols()?);
+    Ok((out, mse))
+}
+
+#[test]
+fn mse_psnr() -> Result<()> {
+    let img = blox_f32()?;
+    let (noisy, expected_mse) = with_noise(&img)?;
+
+    let mut quality_map = Mat::default()?;
+    let mse = QualityMSE::compute_with_ref(&img, &noisy, &mut quality_map)?;
+    assert!((mse[0] - expected_mse).abs() < 1e-3, "{} != {}", mse[0], expected_mse);
+    assert_eq!(img.size()?, quality_map.size()?);
+    assert_eq!(0., QualityMSE::compute_with_ref(&img, &img, &mut quality_map)?[0]);
+
+    let expected_psnr = 10. * (255. * 255. / expected_mse).log10();
+    let psnr = QualityPSNR::compute_with_ref(&img, &noisy, &mut quality_map, 255.)?;
+    assert!((psnr[0] - expected_psnr).abs() < 1e-3, "{} != {}", psnr[0], expected_psnr);
+
+    let mut psnr_alg = QualityPSNR::create(&img, 255.)?;
+    assert_eq!(255., psnr_alg.get().get_max_pixel_value()?);
+    let psnr_inst = psnr_alg.get_mut().compute(&noisy)?;
+    assert!((psnr_inst[0] - psnr[0]).abs() < 1e-6);
+    Ok(())
+}
+
+#[test]
+fn ssim_gmsd() -> Result<()> {
+    let img = blox_f32()?;
+    let (noisy, _) = with_noise(&img)?;
+    let mut quality_map = Mat::default()?;
+
+    let same = QualitySSIM::compute_with_ref(&img, &img, &mut quality_map)?;
+    assert!((same[0] - 1.).abs() < 1e-6, "{}", same[0]);
+    let ssim = QualitySSIM::compute_with_ref(&img, &noisy, &mut quality_map)?;
+    assert!((ssim[0] - SSIM).abs() < 1e-4, "{} != {}", ssim[0], SSIM);
+    assert_eq!(img.size()?, quality_map.size()?);
+    let mut ssim_alg = QualitySSIM::create(&img)?;
+    assert!((ssim_alg.get_mut().compute(&noisy)?[0] - ssim[0]).abs() < 1e-6);
+
+    let same = QualityGMSD::compute_with_ref(&img, &img, &mut quality_map)?;
+    assert!(same[0].abs() < 1e-6, "{}", same[0]);
+    let gmsd = QualityGMSD::compute_with_ref(&img, &noisy, &mut quality_map)?;
+    assert!((gmsd[0] - GMSD).abs() < 1e-4, "{} != {}", gmsd[0], GMSD);
+    Ok(())
+}
+
+#[test]
+fn brisque_features() -> Result<()> {
+    let (noisy, _) = with_noise(&blox_f32()?)?;
+    let mut features = Mat::default()?;
+    QualityBRISQUE::compute_features(&noisy, &mut features)?;
+    assert_eq!(1, features.rows()?);
+    assert_eq!(36, features.cols()?);
+    let mut features_f64 = Mat::default()?;
+    features.convert_to(&mut features_f64, core::CV_64F, 1., 0.)?;
+    for (i, (&feature, &expected)) in features_f64.data_typed::<f64>()?.iter().zip(BRISQUE_FEATURES.iter()).enumerate() {
+        // the shape parameters are searched with the step of 0.001, allow for the neighbouring value
+        assert!((feature - expected).abs() < 2e-3, "feature {}: {} != {}", i, feature, expected);
+    }
+    Ok(())
+}
@@ -0,0 +1,100 @@
+#![cfg(feature = "contrib")]
+
+use opencv::{
+    core::{self, Mat, Vec2d},
+    imgcodecs,
+    reg::{Map, MapShift, Mapper, MapperGradAffine, MapperGradEuclid, MapperGradProj, MapperGradShift, MapperGradSimilar},
+    Result,
+    types::PtrOfMap,
+};
+
+mod common;
+
+/// Grayscale `tests/blox.jpg` as `CV_64F` in `[0; 1]`, the format the gradient mappers work with
+fn blox_f64() -> Result<Mat> {
+    let mut out = Mat::default()?;
+    common::blox(imgcodecs::IMREAD_GRAYSCALE)?.convert_to(&mut out, core::CV_64F, 1. / 255., 0.)?;
+    Ok(out)
+}
+
+#[cfg(not(feature = "opencv-32"))]
+fn shift_map(shift: Vec2d) -> Result<MapShift> {
+    MapShift::new(&core::Matx::<f64, 2, 1>::from(shift))
+}
+
+#[cfg(feature = "opencv-32")]
+fn shift_map(shift: Vec2d) -> Result<MapShift> {
+    MapShift::new(shift)
+}
+
+/// Registers `img2` against `img1` starting from the identity map of the given mapper
+#[cfg(not(feature = "opencv-32"))]
+fn calculate(mapper: &dyn Mapper, img1: &Mat, img2: &Mat) -> Result<PtrOfMap> {
+    mapper.calculate(img1, img2, &mapper.get_map()?)
+}
+
+#[cfg(feature = "opencv-32")]
+fn calculate(mapper: &dyn Mapper, img1: &Mat, img2: &Mat) -> Result<PtrOfMap> {
+    let mut res = mapper.get_map()?;
+    mapper.calculate(img1, img2, &mut res)?;
+    Ok(res)
+}
+
+#[test]
+fn shift_map_scale() -> Result<()> {
+    let mut map = shift_map(Vec2d::from([3., -2.]))?;
+    assert_eq!([3., -2.], map.get_shift()?.0);
+    map.scale(2.)?;
+    assert_eq!([6., -4.], map.get_shift()?.0);
+    Ok(())
+}
+
+#[test]
+fn mappers_identity() -> Result<()> {
+    let img = blox_f64()?;
+    let mappers: Vec<Box<dyn Mapper>> = vec![
+        Box::new(MapperGradShift::default()?),
+        Box::new(MapperGradEuclid::default()?),
+        Box::new(MapperGradSimilar::default()?),
+        Box::new(MapperGradAffine::default()?),
+        Box::new(MapperGradProj::default()?),
+    ];
+    for mapper in &mappers {
+        // registering the image against itself must give the map that doesn't move anything
+        let map = calculate(mapper.as_ref(), &img, &img)?;
+        let mut warped = Mat::default()?;
+        map.warp(&img, &mut warped)?;
+        assert_eq!(img.size()?, warped.size()?);
+        let diff = core::norm2(&img, &warped, core::NORM_L1, &core::no_array()?)?;
+        assert!(diff / (img.total()? as f64) < 1e-3, "images differ too much: {}", diff);
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(not(feature = "opencv-32"))]
+fn pyramid_shift() -> Result<()> {
+    use opencv::{
+        reg::{MapperPyramid, MapTypeCaster},
+        types::PtrOfMapper,
+    };
+
+    let img = blox_f64()?;
+    let shift = Vec2d::from([3., -2.]);
+    let mut shifted = Mat::default()?;
+    shift_map(shift)?.warp(&img, &mut shifted)?;
+
+    let mapper = MapperPyramid::new(&PtrOfMapper::from(MapperGradShift::default()?))?;
+    let mut map = calculate(&mapper, &img, &shifted)?;
+    let estimated = MapTypeCaster::to_shift(&map)?.get().get_shift()?;
+    for (expected, actual) in shift.iter().zip(estimated.iter()) {
+        assert!((expected - actual).abs() < 0.1, "{:?} != {:?}", shift, estimated);
+    }
+
+    // composing the map with its inverse must give back the identity
+    let inverse = map.inverse_map()?;
+    map.compose(&inverse)?;
+    let identity = MapTypeCaster::to_shift(&map)?.get().get_shift()?;
+    assert!(identity.iter().all(|x| x.abs() < 1e-6), "{:?}", identity);
+    Ok(())
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "contrib")]
+
+use opencv::{
+    core::{self, Mat, Scalar, Vec3f},
+    Result,
+    rgbd::{self, RgbdNormals},
+};
+
+const ROWS: i32 = 48;
+const COLS: i32 = 64;
+const FOCAL: f32 = 60.;
+/// Slope of the plane `z = DISTANCE + SLOPE * x`
+const SLOPE: f32 = 0.5;
+const DISTANCE: f32 = 2.;
+
+fn camera_matrix() -> Result<Mat> {
+    Mat::from_slice_2d(&[
+        [FOCAL, 0., (COLS - 1) as f32 / 2.],
+        [0., FOCAL, (ROWS - 1) as f32 / 2.],
+        [0., 0., 1.],
+    ])
+}
+
+/// Depth image in meters of a plane tilted around the y axis
+fn plane_depth() -> Result<Mat> {
+    let mut depth = Mat::new_rows_cols_with_default(ROWS, COLS, core::CV_32FC1, Scalar::all(0.))?;
+    let cx = (COLS - 1) as f32 / 2.;
+    for row in 0..ROWS {
+        for col in 0..COLS {
+            // z = DISTANCE + SLOPE * z * (col - cx) / FOCAL
+            *depth.at_2d_mut::<f32>(row, col)? = DISTANCE / (1. - SLOPE * (col as f32 - cx) / FOCAL);
+        }
+    }
+    Ok(depth)
+}
+
+#[test]
+fn plane_normals() -> Result<()> {
+    let k = camera_matrix()?;
+    let mut points = Mat::default()?;
+    rgbd::depth_to_3d(&plane_depth()?, &k, &mut points, &core::no_array()?)?;
+    assert_eq!(core::CV_32FC3, points.typ()?);
+    let center = *points.at_2d::<Vec3f>(ROWS / 2, COLS / 2)?;
+    assert!((center[2] - (DISTANCE + SLOPE * center[0])).abs() < 1e-4, "point off the plane: {:?}", center);
+
+    let norm = (SLOPE * SLOPE + 1.).sqrt();
+    let expected = [SLOPE / norm, 0., -1. / norm];
+    for &method in &[rgbd::RgbdNormals_RGBD_NORMALS_METHOD_FALS, rgbd::RgbdNormals_RGBD_NORMALS_METHOD_SRI] {
+        let normals_computer = RgbdNormals::new(ROWS, COLS, core::CV_32F, &k, 5, method)?;
+        let mut normals = Mat::default()?;
+        normals_computer.apply(&points, &mut normals)?;
+        assert_eq!(points.size()?, normals.size()?);
+        // away from the borders where the window is incomplete
+        for &(row, col) in &[(ROWS / 2, COLS / 2), (ROWS / 4, COLS / 4), (3 * ROWS / 4, 3 * COLS / 4)] {
+            let normal = *normals.at_2d::<Vec3f>(row, col)?;
+            // the orientation of the normal is up to the method
+            let dot = normal[0] * expected[0] + normal[1] * expected[1] + normal[2] * expected[2];
+            assert!(dot.abs() > 0.99, "method {}, normal at ({}, {}): {:?}", method, row, col, normal);
+        }
+    }
+    Ok(())
+}
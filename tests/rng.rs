@@ -0,0 +1,49 @@
+use opencv::{
+    core::{self, Mat, RNG, RNG_MT19937, Scalar},
+    Result,
+};
+
+#[test]
+fn rng() -> Result<()> {
+    let mut rng = RNG::new(42)?;
+    assert_eq!(42, rng.state());
+    let first = (rng.next()?, rng.uniform(0, 10)?, rng.uniform_f64(-1., 1.)?, rng.gaussian(2.)?);
+    assert_ne!(42, rng.state());
+    rng.set_state(42);
+    let second = (rng.next()?, rng.uniform(0, 10)?, rng.uniform_f64(-1., 1.)?, rng.gaussian(2.)?);
+    assert_eq!(first, second);
+    assert!(first.1 >= 0 && first.1 < 10);
+    assert!(first.2 >= -1. && first.2 < 1.);
+
+    let mut mat = Mat::new_rows_cols_with_default(16, 16, core::CV_8UC1, Scalar::all(0.))?;
+    rng.fill(&mut mat, core::RNG_UNIFORM, &10., &20., false)?;
+    let mut min = 0.;
+    let mut max = 0.;
+    core::min_max_loc(&mat, &mut min, &mut max, &mut core::Point::default(), &mut core::Point::default(), &core::no_array()?)?;
+    assert!(min >= 10. && max < 20.);
+
+    let mut mt = RNG_MT19937::new(42)?;
+    let first = (mt.next()?, mt.uniform_f32(0., 1.)?);
+    mt.seed(42)?;
+    assert_eq!(first, (mt.next()?, mt.uniform_f32(0., 1.)?));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "rand")]
+fn rng_core() -> Result<()> {
+    use rand_core::RngCore;
+
+    let mut rng = RNG::new(42)?;
+    let expected = (rng.next()?, rng.next()?);
+    rng.set_state(42);
+    assert_eq!(expected.0, rng.next_u32());
+    assert_eq!(expected.1, rng.next_u32());
+
+    rng.set_state(42);
+    let mut bytes = [0; 6];
+    rng.fill_bytes(&mut bytes);
+    assert_eq!(expected.0.to_le_bytes(), bytes[..4]);
+    assert_eq!(expected.1.to_le_bytes()[..2], bytes[4..]);
+    Ok(())
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "contrib")]
+
+use opencv::{
+    core::{self, Mat, Point},
+    imgcodecs,
+    Result,
+    saliency::{MotionSaliencyBinWangApr2014, StaticSaliencyFineGrained, StaticSaliencySpectralResidual},
+};
+
+mod common;
+
+#[test]
+fn static_saliency() -> Result<()> {
+    let img = common::blox(imgcodecs::IMREAD_GRAYSCALE)?;
+
+    let mut spectral = StaticSaliencySpectralResidual::default()?;
+    let mut saliency_map = Mat::default()?;
+    assert!(spectral.compute_saliency(&img, &mut saliency_map)?);
+    assert_eq!(img.size()?, saliency_map.size()?);
+    assert_eq!(core::CV_32FC1, saliency_map.typ()?);
+    let mut min = 0.;
+    let mut max = 0.;
+    let mut max_loc = Point::default();
+    core::min_max_loc(&saliency_map, &mut min, &mut max, &mut Point::default(), &mut max_loc, &core::no_array()?)?;
+    assert!(min < max);
+    assert!(max_loc.x < img.cols()? && max_loc.y < img.rows()?);
+
+    let mut fine_grained = StaticSaliencyFineGrained::default()?;
+    let mut saliency_map = Mat::default()?;
+    assert!(fine_grained.compute_saliency(&img, &mut saliency_map)?);
+    assert_eq!(img.size()?, saliency_map.size()?);
+
+    // an empty image is refused instead of raising an error
+    assert!(!spectral.compute_saliency(&Mat::default()?, &mut saliency_map)?);
+    Ok(())
+}
+
+#[test]
+fn motion_saliency() -> Result<()> {
+    let img = common::blox(imgcodecs::IMREAD_GRAYSCALE)?;
+    let mut motion = MotionSaliencyBinWangApr2014::default()?;
+    motion.set_imagesize(img.cols()?, img.rows()?)?;
+    assert!(motion.init()?);
+    let mut saliency_map = Mat::default()?;
+    for _ in 0..3 {
+        assert!(motion.compute_saliency(&img, &mut saliency_map)?);
+    }
+    assert_eq!(img.size()?, saliency_map.size()?);
+    Ok(())
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "contrib")]
+
+use opencv::{
+    core::{self, Mat, Point, Scalar},
+    imgcodecs,
+    Result,
+    stereo::{self, StereoBinaryBM, StereoBinarySGBM, StereoMatcher},
+};
+
+mod common;
+
+/// Horizontal shift in pixels between the left and the right images
+const SHIFT: i32 = 8;
+
+/// Rectified stereo pair made of grayscale `tests/blox.jpg` and its copy shifted left by `SHIFT` pixels
+fn stereo_pair() -> Result<(Mat, Mat)> {
+    let left = common::blox(imgcodecs::IMREAD_GRAYSCALE)?;
+    let mut right = left.clone()?;
+    let cols = left.cols()?;
+    for row in 0..left.rows()? {
+        for col in 0..cols {
+            *right.at_2d_mut::<u8>(row, col)? = *left.at_2d::<u8>(row, (col + SHIFT).min(cols - 1))?;
+        }
+    }
+    Ok((left, right))
+}
+
+#[test]
+fn census() -> Result<()> {
+    let (left, _) = stereo_pair()?;
+    let mut dist = Mat::new_rows_cols_with_default(left.rows()?, left.cols()?, core::CV_32SC1, Scalar::all(0.))?;
+    stereo::census_transform_single(&left, 5, &mut dist, stereo::CV_DENSE_CENSUS)?;
+    let mut min = 0.;
+    let mut max = 0.;
+    core::min_max_loc(&dist, &mut min, &mut max, &mut Point::default(), &mut Point::default(), &core::no_array()?)?;
+    assert!(max > 0.);
+    Ok(())
+}
+
+#[test]
+fn binary_matchers() -> Result<()> {
+    let (left, right) = stereo_pair()?;
+
+    let mut bm = <dyn StereoBinaryBM>::create(32, 9)?;
+    assert_eq!(32, bm.get_num_disparities()?);
+    let mut disparity = Mat::default()?;
+    bm.compute(&left, &right, &mut disparity)?;
+    assert_eq!(left.size()?, disparity.size()?);
+
+    let mut sgbm = <dyn StereoBinarySGBM>::create(0, 32, 9, 100, 1000, 1, 0, 5, 400, 200, stereo::StereoBinarySGBM_MODE_SGBM)?;
+    assert_eq!(100, sgbm.get_p1()?);
+    let mut disparity = Mat::default()?;
+    sgbm.compute(&left, &right, &mut disparity)?;
+    assert_eq!(left.size()?, disparity.size()?);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "opencv-4")]
+fn quasi_dense() -> Result<()> {
+    use opencv::{prelude::*, stereo::QuasiDenseStereo, types::VectorOfStereo_Match};
+
+    let (left, right) = stereo_pair()?;
+    let mut qds = <dyn QuasiDenseStereo>::create(left.size()?, "")?;
+    let mut params = qds.param()?;
+    params.border_x = 20;
+    qds.set_param(params)?;
+    assert_eq!(20, qds.param()?.border_x);
+
+    qds.process(&left, &right)?;
+    let mut matches = VectorOfStereo_Match::new();
+    qds.get_sparse_matches(&mut matches)?;
+    assert!(!matches.is_empty());
+    // the right image is the left one shifted, so the typical match has the same offset
+    let mut dx = matches.iter().map(|m| m.p1.x - m.p0.x).collect::<Vec<_>>();
+    dx.sort_unstable();
+    assert_eq!(-SHIFT, dx[dx.len() / 2]);
+    Ok(())
+}
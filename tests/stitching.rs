@@ -0,0 +1,156 @@
+use opencv::{
+    core::{self, Point, Rect, Scalar, Size, UMat, UMatUsageFlags},
+    imgcodecs,
+    prelude::*,
+    Result,
+    stitching::{
+        Detail_BestOf2NearestMatcher,
+        Detail_BlenderTrait,
+        Detail_Estimator,
+        Detail_FeaturesMatcher,
+        Detail_HomographyBasedEstimator,
+        Detail_MatchesInfo,
+        Detail_MultiBandBlender,
+        Detail_NoBundleAdjuster,
+        Detail_NoExposureCompensator,
+        Detail_NoSeamFinder,
+        PlaneWarper,
+        Stitcher,
+        Stitcher_Mode,
+    },
+    types::{
+        PtrOfDetail_Blender,
+        PtrOfDetail_BundleAdjusterBase,
+        PtrOfDetail_ExposureCompensator,
+        PtrOfDetail_SeamFinder,
+        PtrOfWarperCreator,
+        VectorOfDetail_CameraParams,
+        VectorOfDetail_ImageFeatures,
+        VectorOfDetail_MatchesInfo,
+        VectorOfMat,
+    },
+};
+
+mod common;
+
+/// Left and right parts of `tests/blox.jpg` overlapping by a third of the image width
+fn blox_parts() -> Result<VectorOfMat> {
+    let img = common::blox(imgcodecs::IMREAD_COLOR)?;
+    let size = img.size()?;
+    let width = size.width * 2 / 3;
+    let mut out = VectorOfMat::new();
+    out.push(Mat::roi(&img, Rect::new(0, 0, width, size.height))?.clone()?);
+    out.push(Mat::roi(&img, Rect::new(size.width - width, 0, width, size.height))?.clone()?);
+    Ok(out)
+}
+
+#[cfg(feature = "opencv-4")]
+fn find_features(images: &VectorOfMat) -> Result<VectorOfDetail_ImageFeatures> {
+    use opencv::stitching::{self, Detail_ImageFeatures};
+
+    // Feature2D implementations can't be passed by value, so use the ORB finder of the panorama stitcher
+    let finder = Stitcher::create(Stitcher_Mode::PANORAMA)?.get_mut().features_finder()?;
+    let mut out = VectorOfDetail_ImageFeatures::new();
+    for (i, image) in images.iter().enumerate() {
+        let mut features = Detail_ImageFeatures::default()?;
+        stitching::compute_image_features(&finder, &image, &mut features, &core::no_array()?)?;
+        features.set_img_idx(i as i32)?;
+        out.push(features);
+    }
+    Ok(out)
+}
+
+#[cfg(not(feature = "opencv-4"))]
+fn find_features(images: &VectorOfMat) -> Result<VectorOfDetail_ImageFeatures> {
+    use opencv::stitching::{Detail_FeaturesFinder, Detail_OrbFeaturesFinder};
+
+    let mut finder = Detail_OrbFeaturesFinder::new(Size::new(3, 1), 1500, 1.3, 5)?;
+    let mut out = VectorOfDetail_ImageFeatures::new();
+    finder.apply_multiple(images, &mut out)?;
+    finder.collect_garbage()?;
+    Ok(out)
+}
+
+#[test]
+fn stitcher_components() -> Result<()> {
+    #[cfg(feature = "opencv-4")]
+    let mut stitcher = Stitcher::create(Stitcher_Mode::PANORAMA)?;
+    #[cfg(not(feature = "opencv-4"))]
+    let mut stitcher = Stitcher::create(Stitcher_Mode::PANORAMA, false)?;
+    let mut stitcher = stitcher.get_mut();
+    stitcher.set_warper(&PtrOfWarperCreator::from(PlaneWarper::default()?))?;
+    stitcher.set_blender(&PtrOfDetail_Blender::from(Detail_MultiBandBlender::new(0, 3, core::CV_32F)?))?;
+    stitcher.set_seam_finder(&PtrOfDetail_SeamFinder::from(Detail_NoSeamFinder::default()?))?;
+    stitcher.set_exposure_compensator(&PtrOfDetail_ExposureCompensator::from(Detail_NoExposureCompensator::default()?))?;
+    stitcher.set_bundle_adjuster(&PtrOfDetail_BundleAdjusterBase::from(Detail_NoBundleAdjuster::default()?))?;
+    Ok(())
+}
+
+#[test]
+fn multi_band_blender() -> Result<()> {
+    let mut blender = Detail_MultiBandBlender::new(0, 2, core::CV_32F)?;
+    blender.prepare_1(Rect::new(0, 0, 16, 8))?;
+    let img = Mat::new_rows_cols_with_default(8, 8, core::CV_16SC3, Scalar::all(100.))?;
+    let mask = Mat::new_rows_cols_with_default(8, 8, core::CV_8UC1, Scalar::all(255.))?;
+    blender.feed(&img, &mask, Point::new(0, 0))?;
+    blender.feed(&img, &mask, Point::new(8, 0))?;
+    let mut dst = Mat::default()?;
+    let mut dst_mask = Mat::default()?;
+    blender.blend(&mut dst, &mut dst_mask)?;
+    assert_eq!(Size::new(16, 8), dst.size()?);
+    assert_eq!(Size::new(16, 8), dst_mask.size()?);
+    Ok(())
+}
+
+#[test]
+fn detail_pipeline() -> Result<()> {
+    let images = blox_parts()?;
+    let features = find_features(&images)?;
+    assert_eq!(2, features.len());
+
+    let mut matcher = Detail_BestOf2NearestMatcher::new(false, 0.3, 6, 6)?;
+    let mut matches_info = Detail_MatchesInfo::default()?;
+    matcher.apply(&features.get(0)?, &features.get(1)?, &mut matches_info)?;
+    let mut pairwise_matches = VectorOfDetail_MatchesInfo::new();
+    matcher.apply_with_mask(&features, &mut pairwise_matches, &UMat::new(UMatUsageFlags::USAGE_DEFAULT)?)?;
+    matcher.collect_garbage()?;
+    // every ordered pair of the images, including the pairs of an image with itself
+    assert_eq!(4, pairwise_matches.len());
+
+    let mut estimator = Detail_HomographyBasedEstimator::new(false)?;
+    let mut cameras = VectorOfDetail_CameraParams::new();
+    assert!(estimator.apply(&features, &pairwise_matches, &mut cameras)?);
+    assert_eq!(2, cameras.len());
+
+    // CameraParams fields are only exposed in OpenCV 4, the bundle adjuster needs the rotations converted to CV_32F
+    #[cfg(feature = "opencv-4")]
+    {
+        use opencv::stitching::{Detail_BundleAdjusterBase, Detail_BundleAdjusterRay};
+
+        // the parts share the middle third of the image, so there must be enough consistent matches
+        assert!(matches_info.num_inliers()? > 6);
+        assert!(!matches_info.h()?.empty()?);
+        let mut pair = pairwise_matches.get(1)?;
+        assert_eq!(0, pair.src_img_idx()?);
+        assert_eq!(1, pair.dst_img_idx()?);
+        assert_eq!(matches_info.num_inliers()?, pair.num_inliers()?);
+        assert!(!pair.h()?.empty()?);
+
+        for i in 0..cameras.len() {
+            let mut camera = cameras.get(i)?;
+            assert!(camera.focal()? > 0.);
+            let mut r = Mat::default()?;
+            camera.r()?.convert_to(&mut r, core::CV_32F, 1., 0.)?;
+            camera.set_r(r)?;
+            cameras.set(i, camera)?;
+        }
+        let mut adjuster = Detail_BundleAdjusterRay::default()?;
+        adjuster.set_conf_thresh(0.)?;
+        assert!(adjuster.apply(&features, &pairwise_matches, &mut cameras)?);
+        assert_eq!(2, cameras.len());
+        for camera in &cameras {
+            assert!(camera.focal()?.is_finite());
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "contrib")]
+
+use std::env;
+
+use opencv::{
+    core::Mat,
+    prelude::*,
+    Result,
+    surface_matching::{self, ICP, PPF3DDetector},
+    types::VectorOfPtrOfPose3D,
+};
+
+/// Point cloud with normals (`N x 6` rows of `x, y, z, nx, ny, nz`) of the asymmetric surface
+/// `z = 0.1 * sin(3 * x) + 0.05 * x * y` sampled over `[-0.5; 0.5]²`
+fn surface_cloud() -> Result<Mat> {
+    const STEPS: usize = 30;
+    let mut rows = Vec::with_capacity(STEPS * STEPS);
+    for i in 0..STEPS {
+        for j in 0..STEPS {
+            let x = i as f32 / (STEPS - 1) as f32 - 0.5;
+            let y = j as f32 / (STEPS - 1) as f32 - 0.5;
+            let z = 0.1 * (3. * x).sin() + 0.05 * x * y;
+            let (dx, dy) = (0.3 * (3. * x).cos() + 0.05 * y, 0.05 * x);
+            let norm = (dx * dx + dy * dy + 1.).sqrt();
+            rows.push([x, y, z, -dx / norm, -dy / norm, 1. / norm]);
+        }
+    }
+    Mat::from_slice_2d(&rows)
+}
+
+#[test]
+fn ply_round_trip() -> Result<()> {
+    let cloud = surface_cloud()?;
+    let path = env::temp_dir().join("opencv-rust-surface.ply");
+    let path = path.to_str().unwrap();
+    surface_matching::write_ply(&cloud, path)?;
+    let loaded = surface_matching::load_ply_simple(path, 1)?;
+    assert_eq!(cloud.size()?, loaded.size()?);
+    for &row in &[0, cloud.rows()? / 2, cloud.rows()? - 1] {
+        for col in 0..6 {
+            let (expected, actual) = (*cloud.at_2d::<f32>(row, col)?, *loaded.at_2d::<f32>(row, col)?);
+            assert!((expected - actual).abs() < 1e-4, "({}, {}): {} != {}", row, col, expected, actual);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn detect_and_refine() -> Result<()> {
+    let model = surface_cloud()?;
+    let mut detector = PPF3DDetector::new(0.05, 0.05, 30.)?;
+    detector.train_model(&model)?;
+
+    let mut results = VectorOfPtrOfPose3D::new();
+    detector._match(&model, &mut results, 1. / 5., 0.03)?;
+    assert!(!results.is_empty());
+    for pose in &results {
+        assert!(pose.get().to_value()?.num_votes > 0);
+    }
+    // the scene is the model itself, so the best pose must be close to the identity
+    let best = results.get(0)?.get().to_value()?;
+    assert_eq!([0., 0., 0., 1.], best.pose.0[3]);
+    for i in 0..3 {
+        assert_eq!(best.pose.0[i][3], best.t.0[i]);
+    }
+    let translation = best.t.0.iter().map(|x| x.powi(2)).sum::<f64>().sqrt();
+    assert!(translation < 0.05, "pose too far from the identity: {:?}", best);
+
+    let mut icp = ICP::default()?;
+    icp.register_model_to_scene_poses(&model, &model, &mut results)?;
+    for pose in results.iter() {
+        let residual = pose.get().to_value()?.residual;
+        assert!(residual.is_finite() && residual >= 0.);
+    }
+    Ok(())
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "contrib")]
+
+use std::sync::{Arc, Mutex};
+
+use opencv::{
+    core::{self, Mat, Rect, Scalar},
+    Error,
+    prelude::*,
+    Result,
+    text::{self, OCRHMMDecoder},
+    types::{PtrOfOCRHMMDecoder_ClassifierCallback, VectorOfdouble, VectorOfint, VectorOfMat},
+};
+
+/// Binary image with two separate blobs to be recognized as characters
+fn two_blobs() -> Result<Mat> {
+    let mut image = Mat::new_rows_cols_with_default(60, 120, core::CV_8UC1, Scalar::all(0.))?;
+    for &x in &[15, 70] {
+        Mat::roi_mut(&mut image, Rect::new(x, 15, 30, 30))?.set_to(&Scalar::all(255.), &core::no_array()?)?;
+    }
+    Ok(image)
+}
+
+#[test]
+fn custom_classifier() -> Result<()> {
+    let mut classifier = PtrOfOCRHMMDecoder_ClassifierCallback::from_classifier(|image: &Mat, out_class: &mut VectorOfint, out_confidence: &mut VectorOfdouble| {
+        out_class.push(image.cols()?);
+        out_confidence.push(1.);
+        Ok(())
+    })?;
+    let image = Mat::new_rows_cols_with_default(8, 5, core::CV_8UC1, Scalar::all(255.))?;
+    let mut out_class = VectorOfint::new();
+    let mut out_confidence = VectorOfdouble::new();
+    classifier.get_mut().eval(&image, &mut out_class, &mut out_confidence)?;
+    assert_eq!(vec![5], out_class.to_vec());
+    assert_eq!(vec![1.], out_confidence.to_vec());
+
+    let mut failing = PtrOfOCRHMMDecoder_ClassifierCallback::from_classifier(|_: &Mat, _: &mut VectorOfint, _: &mut VectorOfdouble| Err(Error::new(core::StsBadArg, "unknown character".to_string())))?;
+    let err = failing.get_mut().eval(&image, &mut out_class, &mut out_confidence).unwrap_err();
+    assert_eq!(core::StsBadArg, err.code);
+    assert!(err.message.contains("unknown character"));
+
+    let mut panicking = PtrOfOCRHMMDecoder_ClassifierCallback::from_classifier(|_: &Mat, _: &mut VectorOfint, _: &mut VectorOfdouble| -> Result<()> { panic!("classifier panic") })?;
+    let err = panicking.get_mut().eval(&image, &mut out_class, &mut out_confidence).unwrap_err();
+    assert_eq!(core::StsError, err.code);
+    assert!(err.message.contains("classifier panic"));
+    Ok(())
+}
+
+#[test]
+fn hmm_decoder() -> Result<()> {
+    let calls = Arc::new(Mutex::new(0));
+    let classifier = PtrOfOCRHMMDecoder_ClassifierCallback::from_classifier({
+        let calls = calls.clone();
+        move |_: &Mat, out_class: &mut VectorOfint, out_confidence: &mut VectorOfdouble| {
+            *calls.lock().unwrap() += 1;
+            out_class.push(0);
+            out_confidence.push(1.);
+            Ok(())
+        }
+    })?;
+    let transitions = Mat::from_slice_2d(&[
+        [0.5, 0.5],
+        [0.5, 0.5],
+    ])?;
+    let emissions = Mat::from_slice_2d(&[
+        [1., 0.],
+        [0., 1.],
+    ])?;
+    let mut decoder = OCRHMMDecoder::create(&classifier, "ab", &transitions, &emissions, text::OCR_DECODER_VITERBI)?;
+    let output = decoder.get_mut().run_2(&two_blobs()?, 0, text::OCR_LEVEL_WORD)?;
+    assert!(*calls.lock().unwrap() > 0);
+    assert!(output.chars().all(|c| c == 'a' || c.is_whitespace()), "unexpected output: {}", output);
+    Ok(())
+}
+
+#[test]
+fn nm_channels() -> Result<()> {
+    let image = Mat::new_rows_cols_with_default(32, 32, core::CV_8UC3, Scalar::new(10., 100., 200., 0.))?;
+    let mut channels = VectorOfMat::new();
+    text::compute_nm_channels(&image, &mut channels, text::ERFILTER_NM_RGBLGrad)?;
+    assert_eq!(5, channels.len());
+    for channel in &channels {
+        assert_eq!(image.size()?, channel.size()?);
+    }
+    Ok(())
+}
@@ -1,18 +1,22 @@
 use matches::assert_matches;
 
 use opencv::{
-    core::{self, Point2d, Point3i, Scalar},
+    core::{self, DMatch, KeyPoint, Point2d, Point2f, Point3i, Rect, Scalar, Vector, VectorElement, VectorTrait},
     Error,
     prelude::*,
     Result,
     types::{
         VectorOfbool,
         VectorOfchar,
+        VectorOfDMatch,
         VectorOfdouble,
         VectorOfint,
+        VectorOfKeyPoint,
         VectorOfMat,
         VectorOfPoint2d,
+        VectorOfPoint2f,
         VectorOfPoint3i,
+        VectorOfRect,
         VectorOfString,
         VectorOfuchar,
         VectorOfVectorOfPoint3i,
@@ -116,6 +120,40 @@ fn simple_struct() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn keypoint_slice() -> Result<()> {
+    let mut vec = VectorOfKeyPoint::from(vec![
+        KeyPoint::new_coords(10., 20., 5., -1., 0.5, 0, -1),
+        KeyPoint::new_point(Point2f::new(30., 40.), 7., 90., 0.9, 1, -1),
+        KeyPoint::default(),
+    ]);
+    assert_eq!(3, vec.len());
+    assert_eq!(Point2f::new(30., 40.), vec.get(1)?.pt);
+    assert_eq!(-1., vec.as_slice()[2].angle);
+    vec.as_mut_slice().sort_by(|a, b| b.response.partial_cmp(&a.response).unwrap());
+    assert_eq!(0.9, vec.get(0)?.response);
+    assert_eq!(0., vec.get(2)?.response);
+    vec.as_mut_slice()[2].size = 3.;
+    assert_eq!(3., vec.get(2)?.size);
+    let vec: Vec<KeyPoint> = vec.into();
+    assert_eq!(vec![7., 5., 3.], vec.iter().map(|kp| kp.size).collect::<Vec<_>>());
+    Ok(())
+}
+
+#[test]
+fn dmatch_slice() -> Result<()> {
+    let mut vec = VectorOfDMatch::from_slice(&[DMatch::new(0, 1, 10.), DMatch::new_index(1, 2, 3, 5.)]);
+    vec.push(DMatch::default());
+    assert_eq!(3, vec.len());
+    assert_eq!(3, vec.as_slice()[1].img_idx);
+    assert_eq!(-1, vec.as_slice()[0].img_idx);
+    assert_eq!(-1, vec.as_slice()[2].query_idx);
+    vec.as_mut_slice().iter_mut().for_each(|m| m.distance /= 5.);
+    assert_eq!(2., vec.get(0)?.distance);
+    assert_eq!(vec![DMatch::new(0, 1, 2.), DMatch::new_index(1, 2, 3, 1.)], Vec::from(vec)[..2].to_vec());
+    Ok(())
+}
+
 #[test]
 fn vector_of_vector_simple_struct() -> Result<()> {
     #[inline(never)]
@@ -317,6 +355,15 @@ fn from_iter() -> Result<()> {
         assert_eq!(3, vec.get(2)?);
     }
 
+    {
+        #[allow(deprecated)]
+        fn from_iter_trait<V: for<'i> VectorTrait<'i, Arg=i32>>(s: Vec<i32>) -> V {
+            V::from_iter(s)
+        }
+        let vec: VectorOfint = from_iter_trait(vec![1, 2, 3]);
+        assert_eq!(&[1, 2, 3], vec.as_slice());
+    }
+
     Ok(())
 }
 
@@ -346,6 +393,7 @@ fn iter() -> Result<()> {
 }
 
 #[test]
+#[allow(deprecated)]
 fn to_slice() -> Result<()> {
     {
         let vec = VectorOfuchar::from_iter(vec![1, 2, 3, 4, 5]);
@@ -377,3 +425,98 @@ fn to_vec() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn as_mut_slice() -> Result<()> {
+    {
+        let mut vec = VectorOfuchar::from_slice(&[5, 3, 4, 1, 2]);
+        vec.as_mut_slice().sort();
+        assert_eq!(&[1, 2, 3, 4, 5], vec.as_slice());
+        vec.as_mut_slice()[0] = 10;
+        assert_eq!(10, vec.get(0)?);
+    }
+    {
+        let mut vec = VectorOfRect::new();
+        assert!(vec.as_mut_slice().is_empty());
+        vec.push(Rect::new(1, 2, 3, 4));
+        vec.as_mut_slice()[0].width = 30;
+        assert_eq!(Rect::new(1, 2, 30, 4), vec.get(0)?);
+    }
+    Ok(())
+}
+
+#[test]
+fn pod_std_traits() -> Result<()> {
+    {
+        let vec: VectorOfPoint2f = (0..4).map(|x| Point2f::new(x as f32, -x as f32)).collect();
+        assert_eq!(4, vec.len());
+        assert_eq!(Point2f::new(3., -3.), vec.get(3)?);
+        let cloned = vec.clone();
+        assert_eq!(vec, cloned);
+        let v: Vec<Point2f> = cloned.into();
+        assert_eq!(vec.as_slice(), v.as_slice());
+        assert_eq!(vec, VectorOfPoint2f::from(v));
+        assert_eq!(format!("{:?}", vec.as_slice()), format!("{:?}", vec));
+    }
+    {
+        let mut vec = VectorOfint::from(vec![1, 2]);
+        vec.extend(vec![3, 4]);
+        vec.extend(&[5, 6]);
+        vec.extend_from_slice(&[]);
+        assert_eq!(&[1, 2, 3, 4, 5, 6], vec.as_slice());
+        assert_ne!(vec, VectorOfint::default());
+        assert_eq!(vec![2, 4, 6, 8, 10, 12], vec.iter().map(|x| x * 2).collect::<Vec<_>>());
+        assert_eq!(21, vec.into_iter().sum::<i32>());
+    }
+    Ok(())
+}
+
+fn total_area(v: &Vector<Rect>) -> i32 {
+    v.iter().map(|r| r.area()).sum()
+}
+
+fn non_empty_count<T: VectorElement>(v: &Vector<T>, is_empty: impl Fn(&T) -> bool) -> usize {
+    v.iter().filter(|x| !is_empty(x)).count()
+}
+
+#[test]
+fn generic() -> Result<()> {
+    let rects = VectorOfRect::from(vec![Rect::new(0, 0, 10, 20), Rect::new(5, 5, 3, 3), Rect::default()]);
+    assert_eq!(209, total_area(&rects));
+    assert_eq!(2, non_empty_count(&rects, |r| r.area() == 0));
+
+    let mut mats = Vector::<Mat>::new();
+    mats.push(Mat::new_rows_cols_with_default(1, 3, u8::typ(), Scalar::all(1.))?);
+    mats.push(Mat::default()?);
+    assert_eq!(1, non_empty_count(&mats, |m| m.empty().unwrap()));
+
+    let strings: VectorOfString = vec!["", "a", "bc"].into_iter().collect();
+    assert_eq!(2, non_empty_count(&strings, |s| s.is_empty()));
+    assert_eq!(vec!["", "a", "bc"], strings.to_vec());
+
+    let nested = Vector::<VectorOfPoint3i>::from_iter(vec![VectorOfPoint3i::from(vec![Point3i::new(1, 2, 3)]), VectorOfPoint3i::new()]);
+    assert_eq!(1, non_empty_count(&nested, |v| v.is_empty()));
+    Ok(())
+}
+
+#[test]
+#[allow(deprecated)]
+fn templ_compat() -> Result<()> {
+    use opencv::templ::{Vector as _, VectorRefIterator};
+
+    fn assert_send<T: Send>(_: &T) {}
+
+    let vec = VectorOfRect::from(vec![Rect::new(1, 2, 3, 4)]);
+    assert_send(&vec);
+    assert_send(&Vector::<VectorOfPoint3i>::new());
+    let iter: VectorRefIterator<Rect> = vec.iter();
+    assert_eq!(vec![Rect::new(1, 2, 3, 4)], iter.collect::<Vec<_>>());
+    assert_send(&vec.clone().into_iter());
+
+    // the trait was named Vector in the prelude too
+    fn prelude_len<V: for<'i> opencv::prelude::Vector<'i>>(v: &V) -> usize {
+        v.len()
+    }
+    assert_eq!(1, prelude_len(&vec));
+    Ok(())
+}
@@ -0,0 +1,157 @@
+use std::env;
+
+use opencv::{
+    core::{self, Scalar, Size},
+    prelude::*,
+    Result,
+    videoio::{self, VideoCapture, VideoWriter},
+};
+
+const FRAME_COUNT: usize = 10;
+
+/// Writes a short MJPEG video to a temporary file and returns its path
+#[cfg(any(feature = "futures", feature = "opencv-4"))]
+fn write_video(name: &str) -> Result<String> {
+    let path = env::temp_dir().join(name).to_string_lossy().into_owned();
+    let fourcc = VideoWriter::fourcc('M' as i8, 'J' as i8, 'P' as i8, 'G' as i8)?;
+    let mut writer = VideoWriter::new(&path, fourcc, 25., Size::new(64, 48), true)?;
+    assert!(writer.is_opened()?);
+    for i in 0..FRAME_COUNT {
+        let frame = Mat::new_rows_cols_with_default(48, 64, core::CV_8UC3, Scalar::all(i as f64 * 20.))?;
+        writer.write(&frame)?;
+    }
+    writer.release()?;
+    Ok(path)
+}
+
+#[test]
+#[cfg(feature = "futures")]
+fn frame_stream() -> Result<()> {
+    use futures::executor::block_on_stream;
+    use opencv::videoio::{FrameDropPolicy, FrameStream};
+
+    let path = write_video("opencv-rust-frame-stream.avi")?;
+
+    let capture = VideoCapture::new_from_file_with_backend(&path, videoio::CAP_ANY)?;
+    assert!(capture.is_opened()?);
+    let stream = FrameStream::new(capture, FRAME_COUNT, FrameDropPolicy::DropNewest)?;
+    let frames = block_on_stream(stream).collect::<Result<Vec<_>>>()?;
+    assert_eq!(FRAME_COUNT, frames.len());
+    for frame in frames {
+        assert_eq!(Size::new(64, 48), frame.size()?);
+    }
+
+    let capture = VideoCapture::new_from_file_with_backend(&path, videoio::CAP_ANY)?;
+    let stream = FrameStream::new(capture, 1, FrameDropPolicy::DropOldest)?;
+    let capture = stream.stop()?;
+    assert!(capture.is_opened()?);
+
+    let capture = VideoCapture::new_from_file_with_backend(&path, videoio::CAP_ANY)?;
+    assert!(FrameStream::new(capture, 0, FrameDropPolicy::DropOldest).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "futures")]
+fn frame_stream_drop_policy() -> Result<()> {
+    use std::{thread, time::Duration};
+    use futures::executor::block_on_stream;
+    use opencv::videoio::{FrameDropPolicy, FrameStream};
+
+    let path = write_video("opencv-rust-frame-stream-drop.avi")?;
+    // frame i is filled with i * 20, the value survives the MJPEG compression closely enough to get the index back
+    let frame_index = |frame: &Mat| -> Result<usize> {
+        Ok((core::mean(frame, &core::no_array()?)?[0] / 20.).round() as usize)
+    };
+    for &(policy, expected) in &[(FrameDropPolicy::DropOldest, FRAME_COUNT - 1), (FrameDropPolicy::DropNewest, 0)] {
+        let capture = VideoCapture::new_from_file_with_backend(&path, videoio::CAP_ANY)?;
+        let stream = FrameStream::new(capture, 1, policy)?;
+        // let the grabber read the whole file before consuming anything
+        thread::sleep(Duration::from_millis(500));
+        let frames = block_on_stream(stream)
+            .map(|frame| frame.and_then(|frame| frame_index(&frame)))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(vec![expected], frames, "{:?}", policy);
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "opencv-4")]
+fn capture_group() -> Result<()> {
+    use opencv::videoio::CaptureGroup;
+
+    let path = write_video("opencv-rust-capture-group.avi")?;
+    let captures = vec![
+        VideoCapture::new_from_file_with_backend(&path, videoio::CAP_ANY)?,
+        VideoCapture::new_from_file_with_backend(&path, videoio::CAP_ANY)?,
+    ];
+    let group = CaptureGroup::new(captures)?;
+    assert_eq!(2, group.len());
+    for capture in group.captures() {
+        assert!(capture.is_opened()?);
+        assert_eq!(FRAME_COUNT as f64, capture.get(videoio::CAP_PROP_FRAME_COUNT)?);
+    }
+    let mut captures = group.into_captures();
+    let mut frame = Mat::default()?;
+    assert!(captures[1].read(&mut frame)?);
+    assert_eq!(Size::new(64, 48), frame.size()?);
+
+    assert!(CaptureGroup::new(vec![]).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "opencv-4")]
+fn capture_group_wait() -> Result<()> {
+    use opencv::videoio::CaptureGroup;
+
+    let path = write_video("opencv-rust-capture-group-wait.avi")?;
+    let captures = vec![
+        VideoCapture::new_from_file_with_backend(&path, videoio::CAP_ANY)?,
+        VideoCapture::new_from_file_with_backend(&path, videoio::CAP_ANY)?,
+    ];
+    let mut group = CaptureGroup::new(captures)?;
+    let mut frames = 0;
+    loop {
+        let ready = group.wait(0)?;
+        // waiting without a timeout, so nothing is ready only after both files ended
+        if ready.is_empty() {
+            break;
+        }
+        assert_eq!(vec![0, 1], ready.iter().map(|&(index, _, _)| index).collect::<Vec<_>>());
+        for (_, frame, _) in ready {
+            assert_eq!(Size::new(64, 48), frame.size()?);
+        }
+        frames += 1;
+    }
+    assert_eq!(FRAME_COUNT, frames);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "opencv-4")]
+fn capture_group_wait_synced() -> Result<()> {
+    use opencv::videoio::{CaptureGroup, SyncedFrames};
+
+    let path = write_video("opencv-rust-capture-group-wait-synced.avi")?;
+    let captures = vec![
+        VideoCapture::new_from_file_with_backend(&path, videoio::CAP_ANY)?,
+        VideoCapture::new_from_file_with_backend(&path, videoio::CAP_ANY)?,
+    ];
+    let mut group = CaptureGroup::new(captures)?;
+    let mut frames = 0;
+    let end = loop {
+        match group.wait_synced(1., 0)? {
+            SyncedFrames::Frames(synced) => {
+                assert_eq!(2, synced.len());
+                assert_eq!(synced[0].1, synced[1].1);
+                frames += 1;
+            }
+            res => break res,
+        }
+    };
+    assert!(matches!(end, SyncedFrames::EndOfStream(0)), "{:?}", end);
+    assert_eq!(FRAME_COUNT, frames);
+    Ok(())
+}